@@ -1,7 +1,10 @@
 //! Cryptographic signature keys storage API
 
 use namada_core::storage;
-use namada_storage::{Result, ResultExt, StorageRead, StorageWrite};
+use namada_core::token::Amount;
+use namada_storage::{
+    Error, OptionExt, Result, ResultExt, StorageRead, StorageWrite,
+};
 
 use super::*;
 
@@ -120,3 +123,130 @@ where
     }
     Ok(())
 }
+
+/// The number of seconds in an allowance day
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Get the daily cap of a spender's allowance of a token on an account
+pub fn read_allowance<S>(
+    storage: &S,
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+) -> Result<Option<Amount>>
+where
+    S: StorageRead,
+{
+    storage.read(&allowance_key(owner, spender, token))
+}
+
+/// Get all the allowances of an account, as triples of spender, token and
+/// daily cap
+pub fn read_allowances<S>(
+    storage: &S,
+    owner: &Address,
+) -> Result<Vec<(Address, Address, Amount)>>
+where
+    S: StorageRead,
+{
+    namada_storage::iter_prefix(storage, &allowances_prefix(owner))?
+        .filter_map(|result| match result {
+            Ok((key, cap)) => {
+                is_allowance_key(&key).map(|[_owner, spender, token]| {
+                    Ok((spender.clone(), token.clone(), cap))
+                })
+            }
+            Err(err) => Some(Err(err)),
+        })
+        .collect()
+}
+
+/// Set the daily cap of a spender's allowance of a token on an account, or
+/// remove the allowance together with its spending record if `None`
+pub fn write_allowance<S>(
+    storage: &mut S,
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+    daily_cap: Option<Amount>,
+) -> Result<()>
+where
+    S: StorageWrite + StorageRead,
+{
+    let key = allowance_key(owner, spender, token);
+    match daily_cap {
+        Some(daily_cap) => storage.write(&key, daily_cap),
+        None => {
+            storage.delete(&key)?;
+            storage.delete(&allowance_spending_key(owner, spender, token))
+        }
+    }
+}
+
+/// Get the spending record of a spender's allowance of a token on an account
+pub fn read_allowance_spending<S>(
+    storage: &S,
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+) -> Result<AllowanceSpending>
+where
+    S: StorageRead,
+{
+    storage
+        .read(&allowance_spending_key(owner, spender, token))
+        .map(Option::unwrap_or_default)
+}
+
+/// Get the current allowance day, counted in days since the Unix epoch from
+/// the time of the current block
+pub fn current_allowance_day<S>(storage: &S) -> Result<u64>
+where
+    S: StorageRead,
+{
+    let height = storage.get_block_height()?;
+    let header = storage
+        .get_block_header(height)?
+        .ok_or_err_msg("The current block header is not available")?;
+    let day = header.time.0.timestamp().div_euclid(SECONDS_PER_DAY);
+    u64::try_from(day).into_storage_result()
+}
+
+/// Record the spending of an amount of a spender's allowance of a token on an
+/// account on the given day. Fails if there is no allowance or if the daily
+/// cap would be exceeded.
+pub fn spend_allowance<S>(
+    storage: &mut S,
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+    amount: Amount,
+    day: u64,
+) -> Result<()>
+where
+    S: StorageWrite + StorageRead,
+{
+    let daily_cap = read_allowance(storage, owner, spender, token)?
+        .ok_or_else(|| {
+            Error::new_alloc(format!(
+                "{spender} has no allowance of {token} on {owner}"
+            ))
+        })?;
+    let spending = read_allowance_spending(storage, owner, spender, token)?;
+    let spent = spending
+        .spent_on(day)
+        .checked_add(amount)
+        .ok_or_err_msg("Allowance spending overflow")?;
+    if spent > daily_cap {
+        return Err(Error::new_alloc(format!(
+            "Spending {} of {token} on {owner} would exceed the daily cap {} \
+             of {spender}",
+            amount.to_string_native(),
+            daily_cap.to_string_native(),
+        )));
+    }
+    storage.write(
+        &allowance_spending_key(owner, spender, token),
+        AllowanceSpending { day, spent },
+    )
+}
@@ -11,6 +11,8 @@ struct Keys {
     public_keys: &'static str,
     threshold: &'static str,
     protocol_public_keys: &'static str,
+    allowances: &'static str,
+    allowance_spending: &'static str,
 }
 
 /// Obtain a storage key for user's public key.
@@ -89,3 +91,78 @@ pub fn is_protocol_pk_key(key: &storage::Key) -> Option<&Address> {
         _ => None,
     }
 }
+
+/// Obtain the storage key prefix of all the allowances of an account
+pub fn allowances_prefix(owner: &Address) -> storage::Key {
+    storage::Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.allowances.to_string()),
+        ],
+    }
+}
+
+/// Obtain the storage key of a spender's daily cap of a token on an account
+pub fn allowance_key(
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+) -> storage::Key {
+    storage::Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.allowances.to_string()),
+            DbKeySeg::AddressSeg(spender.to_owned()),
+            DbKeySeg::AddressSeg(token.to_owned()),
+        ],
+    }
+}
+
+/// Obtain the storage key of the amount a spender has spent of its allowance
+/// of a token on an account
+pub fn allowance_spending_key(
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+) -> storage::Key {
+    storage::Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.allowance_spending.to_string()),
+            DbKeySeg::AddressSeg(spender.to_owned()),
+            DbKeySeg::AddressSeg(token.to_owned()),
+        ],
+    }
+}
+
+/// Check if the given storage key is an allowance key. If it is, returns the
+/// owner, the spender and the token.
+pub fn is_allowance_key(key: &storage::Key) -> Option<[&Address; 3]> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(owner),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::AddressSeg(spender),
+            DbKeySeg::AddressSeg(token),
+        ] if prefix.as_str() == Keys::VALUES.allowances => {
+            Some([owner, spender, token])
+        }
+        _ => None,
+    }
+}
+
+/// Check if the given storage key is an allowance spending key. If it is,
+/// returns the owner, the spender and the token.
+pub fn is_allowance_spending_key(key: &storage::Key) -> Option<[&Address; 3]> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(owner),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::AddressSeg(spender),
+            DbKeySeg::AddressSeg(token),
+        ] if prefix.as_str() == Keys::VALUES.allowance_spending => {
+            Some([owner, spender, token])
+        }
+        _ => None,
+    }
+}
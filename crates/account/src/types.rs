@@ -2,6 +2,7 @@ use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::hash::Hash;
 use namada_core::key::common;
+use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -55,6 +56,90 @@ pub struct UpdateAccount {
     pub threshold: Option<u8>,
 }
 
+/// A tx data type to set or remove the daily spending cap of a spender on an
+/// account
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct UpdateAllowance {
+    /// The account granting the allowance
+    pub owner: Address,
+    /// The address allowed to spend from the account
+    pub spender: Address,
+    /// The token the allowance applies to
+    pub token: Address,
+    /// The maximum amount the spender may spend per day. `None` removes the
+    /// allowance.
+    pub daily_cap: Option<token::Amount>,
+}
+
+/// A tx data type for a spender to transfer tokens out of an account within
+/// its allowance
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct SpendAllowance {
+    /// The account granting the allowance
+    pub owner: Address,
+    /// The address spending from the account
+    pub spender: Address,
+    /// The recipient of the tokens
+    pub target: Address,
+    /// The token to transfer
+    pub token: Address,
+    /// The amount to transfer
+    pub amount: token::Amount,
+}
+
+/// The amount of an allowance already spent on a given day
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct AllowanceSpending {
+    /// The day of the spending, counted in days since the Unix epoch
+    pub day: u64,
+    /// The total amount spent on that day
+    pub spent: token::Amount,
+}
+
+impl AllowanceSpending {
+    /// The amount already spent on the given day
+    pub fn spent_on(&self, day: u64) -> token::Amount {
+        if self.day == day {
+            self.spent
+        } else {
+            token::Amount::zero()
+        }
+    }
+}
+
 #[allow(clippy::cast_possible_truncation)]
 #[cfg(any(test, feature = "testing"))]
 /// Tests and strategies for accounts
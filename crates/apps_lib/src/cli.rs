@@ -242,6 +242,8 @@ pub mod cmds {
                 .subcommand(TxMetadataChange::def().display_order(2))
                 // Ethereum bridge transactions
                 .subcommand(AddToEthBridgePool::def().display_order(3))
+                .subcommand(CancelFromEthBridgePool::def().display_order(3))
+                .subcommand(TopUpEthBridgePool::def().display_order(3))
                 // PGF transactions
                 .subcommand(TxUpdateStewardCommission::def().display_order(4))
                 .subcommand(TxResignSteward::def().display_order(4))
@@ -352,6 +354,10 @@ pub mod cmds {
             let query_metadata = Self::parse_with_ctx(matches, QueryMetaData);
             let add_to_eth_bridge_pool =
                 Self::parse_with_ctx(matches, AddToEthBridgePool);
+            let cancel_from_eth_bridge_pool =
+                Self::parse_with_ctx(matches, CancelFromEthBridgePool);
+            let top_up_eth_bridge_pool =
+                Self::parse_with_ctx(matches, TopUpEthBridgePool);
             let sign_tx = Self::parse_with_ctx(matches, SignTx);
             let shielded_sync = Self::parse_with_ctx(matches, ShieldedSync);
             let utils = SubCmd::parse(matches).map(Self::WithoutContext);
@@ -377,6 +383,8 @@ pub mod cmds {
                 .or(redelegate)
                 .or(claim_rewards)
                 .or(add_to_eth_bridge_pool)
+                .or(cancel_from_eth_bridge_pool)
+                .or(top_up_eth_bridge_pool)
                 .or(tx_update_steward_commission)
                 .or(tx_resign_steward)
                 .or(query_epoch)
@@ -465,6 +473,8 @@ pub mod cmds {
         ClaimRewards(ClaimRewards),
         Redelegate(Redelegate),
         AddToEthBridgePool(AddToEthBridgePool),
+        CancelFromEthBridgePool(CancelFromEthBridgePool),
+        TopUpEthBridgePool(TopUpEthBridgePool),
         TxUpdateStewardCommission(TxUpdateStewardCommission),
         TxResignSteward(TxResignSteward),
         QueryEpoch(QueryEpoch),
@@ -2546,6 +2556,8 @@ pub mod cmds {
         /// Check the confirmation status of `TransferToEthereum`
         /// events.
         QueryRelays(QueryRelayProgress),
+        /// Check that Bridge pool proofs can be relayed.
+        HealthCheck(BridgePoolHealth),
     }
 
     impl Cmd for EthBridgePool {
@@ -2556,6 +2568,7 @@ pub mod cmds {
                 .subcommand(QueryEthBridgePool::def().display_order(1))
                 .subcommand(QuerySignedBridgePool::def().display_order(1))
                 .subcommand(QueryRelayProgress::def().display_order(1))
+                .subcommand(BridgePoolHealth::def().display_order(1))
         }
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
@@ -2569,6 +2582,7 @@ pub mod cmds {
             let query_pool = Self::parse_without_ctx(matches, QueryPool);
             let query_signed = Self::parse_without_ctx(matches, QuerySigned);
             let query_relays = Self::parse_without_ctx(matches, QueryRelays);
+            let health_check = Self::parse_without_ctx(matches, HealthCheck);
 
             construct_proof
                 .or(recommend)
@@ -2576,6 +2590,7 @@ pub mod cmds {
                 .or(query_pool)
                 .or(query_signed)
                 .or(query_relays)
+                .or(health_check)
         }
     }
 
@@ -2618,6 +2633,7 @@ pub mod cmds {
                 .subcommand(QueryEthBridgePool::def().display_order(1))
                 .subcommand(QuerySignedBridgePool::def().display_order(1))
                 .subcommand(QueryRelayProgress::def().display_order(1))
+                .subcommand(BridgePoolHealth::def().display_order(1))
         }
     }
 
@@ -2641,6 +2657,56 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct CancelFromEthBridgePool(
+        pub args::CancelEthereumTransfer<args::CliTypes>,
+    );
+
+    impl SubCmd for CancelFromEthBridgePool {
+        const CMD: &'static str = "cancel-erc20-transfer";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::CancelEthereumTransfer::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Cancel a pending transfer in the Ethereum Bridge pool, \
+                     and get its escrowed gas fees and tokens refunded."
+                ))
+                .arg_required_else_help(true)
+                .add_args::<args::CancelEthereumTransfer<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TopUpEthBridgePool(
+        pub args::TopUpEthereumTransfer<args::CliTypes>,
+    );
+
+    impl SubCmd for TopUpEthBridgePool {
+        const CMD: &'static str = "top-up-erc20-transfer";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::TopUpEthereumTransfer::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Top up the gas fees of a pending transfer in the \
+                     Ethereum Bridge pool, paid by its original gas payer."
+                ))
+                .arg_required_else_help(true)
+                .add_args::<args::TopUpEthereumTransfer<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct ConstructProof(pub args::BridgePoolProof<args::CliTypes>);
 
@@ -2772,6 +2838,33 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct BridgePoolHealth(
+        pub args::BridgePoolHealthCheck<args::CliTypes>,
+    );
+
+    impl SubCmd for BridgePoolHealth {
+        const CMD: &'static str = "health";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::BridgePoolHealthCheck::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Check that the Namada and Ethereum nodes are reachable \
+                     and synchronized, that the Bridge contract can be \
+                     resolved and that its nonce matches Namada's. Prints a \
+                     json report, and exits with an error if any check \
+                     failed."
+                ))
+                .add_args::<args::BridgePoolHealthCheck<args::CliTypes>>()
+        }
+    }
+
     /// Used as sub-commands (`SubCmd` instance) in `namadar` binary.
     #[derive(Clone, Debug)]
     pub enum ValidatorSet {
@@ -2989,11 +3082,12 @@ pub mod args {
     pub use namada_sdk::args::*;
     pub use namada_sdk::tx::{
         TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
-        TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
-        TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
-        TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
-        TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
-        TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
+        TX_CANCEL_BRIDGE_POOL_WASM, TX_CHANGE_COMMISSION_WASM,
+        TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
+        TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM,
+        TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM,
+        TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK,
+        TX_TOP_UP_BRIDGE_POOL_WASM, TX_TRANSFER_WASM, TX_UNBOND_WASM,
         TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
         TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
         VP_USER_WASM,
@@ -3019,6 +3113,7 @@ pub mod args {
     pub const ALLOW_DUPLICATE_IP: ArgFlag = flag("allow-duplicate-ip");
     pub const AMOUNT: Arg<token::DenominatedAmount> = arg("amount");
     pub const ARCHIVE_DIR: ArgOpt<PathBuf> = arg_opt("archive-dir");
+    pub const AUTO_CONFIRM: ArgFlag = flag("auto-confirm");
     pub const AVATAR_OPT: ArgOpt<String> = arg_opt("avatar");
     pub const BALANCE_OWNER: Arg<WalletBalanceOwner> = arg("owner");
     pub const BASE_DIR: ArgDefault<PathBuf> = arg_default(
@@ -3030,6 +3125,7 @@ pub mod args {
     );
     pub const BATCH_SIZE_OPT: ArgDefault<u64> =
         arg_default("batch-size", DefaultFn(|| 1));
+    pub const BIRTHDAY: ArgOpt<BlockHeight> = arg_opt("birthday");
     pub const BLOCK_HEIGHT: Arg<BlockHeight> = arg("block-height");
     pub const BLOCK_HEIGHT_OPT: ArgOpt<BlockHeight> = arg_opt("height");
     pub const BLOCK_HEIGHT_FROM_OPT: ArgOpt<BlockHeight> =
@@ -3054,6 +3150,7 @@ pub mod args {
         DefaultFn(|| "".parse().unwrap()),
     );
     pub const BRIDGE_POOL_TARGET: Arg<EthAddress> = arg("target");
+    pub const BRIDGE_POOL_TRANSFER_HASH: Arg<KeccakHash> = arg("transfer-hash");
     pub const BROADCAST_ONLY: ArgFlag = flag("broadcast-only");
     pub const CHAIN_ID: Arg<ChainId> = arg("chain-id");
     pub const CHAIN_ID_OPT: ArgOpt<ChainId> = CHAIN_ID.opt();
@@ -3063,6 +3160,7 @@ pub mod args {
     pub const CODE_PATH_OPT: ArgOpt<PathBuf> = CODE_PATH.opt();
     pub const COMMISSION_RATE: Arg<Dec> = arg("commission-rate");
     pub const COMMISSION_RATE_OPT: ArgOpt<Dec> = COMMISSION_RATE.opt();
+    pub const CONFIRM: ArgFlag = flag("confirm");
     pub const CONSENSUS_TIMEOUT_COMMIT: ArgDefault<Timeout> = arg_default(
         "consensus-timeout-commit",
         DefaultFn(|| Timeout::from_str("1s").unwrap()),
@@ -3103,6 +3201,10 @@ pub mod args {
     );
     pub const ETH_SYNC: ArgFlag = flag("sync");
     pub const EXPIRATION_OPT: ArgOpt<DateTimeUtc> = arg_opt("expiration");
+    pub const EXTRA_AMOUNTS: ArgMulti<token::DenominatedAmount, GlobStar> =
+        arg_multi("extra-amounts");
+    pub const EXTRA_VALIDATORS: ArgMulti<WalletAddress, GlobStar> =
+        arg_multi("extra-validators");
     pub const EMAIL: Arg<String> = arg("email");
     pub const EMAIL_OPT: ArgOpt<String> = EMAIL.opt();
     pub const FEE_AMOUNT_OPT: ArgOpt<token::DenominatedAmount> =
@@ -3160,6 +3262,8 @@ pub mod args {
     pub const LIST_FIND_ADDRESSES_ONLY: ArgFlag = flag("addr");
     pub const LIST_FIND_KEYS_ONLY: ArgFlag = flag("keys");
     pub const LOCALHOST: ArgFlag = flag("localhost");
+    pub const MASP_REBUILD_RETRIES: ArgDefault<u8> =
+        arg_default("masp-rebuild-retries", DefaultFn(|| 1));
     pub const MAX_COMMISSION_RATE_CHANGE: Arg<Dec> =
         arg("max-commission-rate-change");
     pub const MAX_ETH_GAS: ArgOpt<u64> = arg_opt("max_eth-gas");
@@ -3183,6 +3287,7 @@ pub mod args {
     );
     pub const PRE_GENESIS: ArgFlag = flag("pre-genesis");
     pub const PROPOSAL_ETH: ArgFlag = flag("eth");
+    pub const PROPOSAL_PARAMS: ArgFlag = flag("params");
     pub const PROPOSAL_PGF_STEWARD: ArgFlag = flag("pgf-stewards");
     pub const PROPOSAL_PGF_FUNDING: ArgFlag = flag("pgf-funding");
     pub const PROTOCOL_KEY: ArgOpt<WalletPublicKey> = arg_opt("protocol-key");
@@ -3225,6 +3330,7 @@ pub mod args {
     pub const SIGNING_KEYS: ArgMulti<WalletPublicKey, GlobStar> =
         arg_multi("signing-keys");
     pub const SIGNATURES: ArgMulti<PathBuf, GlobStar> = arg_multi("signatures");
+    pub const SKIP_IF_APPLIED: ArgFlag = flag("skip-if-applied");
     pub const SOURCE: Arg<WalletAddress> = arg("source");
     pub const SOURCE_OPT: ArgOpt<WalletAddress> = SOURCE.opt();
     pub const SPENDING_KEYS: ArgMulti<WalletSpendingKey, GlobStar> =
@@ -3232,6 +3338,7 @@ pub mod args {
     pub const STEWARD: Arg<WalletAddress> = arg("steward");
     pub const SOURCE_VALIDATOR: Arg<WalletAddress> = arg("source-validator");
     pub const STORAGE_KEY: Arg<storage::Key> = arg("storage-key");
+    pub const SUBSCRIBE_EVENTS: ArgFlag = flag("subscribe-events");
     pub const SUSPEND_ACTION: ArgFlag = flag("suspend");
     pub const TEMPLATES_PATH: Arg<PathBuf> = arg("templates-path");
     pub const TIMEOUT_HEIGHT: ArgOpt<u64> = arg_opt("timeout-height");
@@ -3698,6 +3805,90 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<CancelEthereumTransfer<SdkTypes>>
+        for CancelEthereumTransfer<CliTypes>
+    {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<CancelEthereumTransfer<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            Ok(CancelEthereumTransfer::<SdkTypes> {
+                tx,
+                transfer: self.transfer,
+                code_path: self.code_path,
+            })
+        }
+    }
+
+    impl Args for CancelEthereumTransfer<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let transfer = BRIDGE_POOL_TRANSFER_HASH.parse(matches);
+            let code_path = PathBuf::from(TX_CANCEL_BRIDGE_POOL_WASM);
+            Self {
+                tx,
+                transfer,
+                code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>().arg(
+                BRIDGE_POOL_TRANSFER_HASH.def().help(wrap!(
+                    "The keccak hash of the Bridge pool transfer to cancel."
+                )),
+            )
+        }
+    }
+
+    impl CliToSdk<TopUpEthereumTransfer<SdkTypes>>
+        for TopUpEthereumTransfer<CliTypes>
+    {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TopUpEthereumTransfer<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            Ok(TopUpEthereumTransfer::<SdkTypes> {
+                tx,
+                transfer: self.transfer,
+                amount: self.amount,
+                code_path: self.code_path,
+            })
+        }
+    }
+
+    impl Args for TopUpEthereumTransfer<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let transfer = BRIDGE_POOL_TRANSFER_HASH.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let code_path = PathBuf::from(TX_TOP_UP_BRIDGE_POOL_WASM);
+            Self {
+                tx,
+                transfer,
+                amount,
+                code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(BRIDGE_POOL_TRANSFER_HASH.def().help(wrap!(
+                    "The keccak hash of the Bridge pool transfer to top up."
+                )))
+                .arg(AMOUNT.def().help(wrap!(
+                    "The amount of gas fees to add to the transfer, \
+                     denominated in its gas fee token."
+                )))
+        }
+    }
+
     impl CliToSdk<RecommendBatch<SdkTypes>> for RecommendBatch<CliTypes> {
         type Error = std::convert::Infallible;
 
@@ -3783,6 +3974,7 @@ pub mod args {
                 ledger_address: self.ledger_address,
                 transfers: self.transfers,
                 relayer: self.relayer,
+                auto_confirm: self.auto_confirm,
             }
         }
     }
@@ -3792,6 +3984,7 @@ pub mod args {
             let ledger_address = LEDGER_ADDRESS.parse(matches);
             let hashes = HASH_LIST.parse(matches);
             let relayer = RELAYER.parse(matches);
+            let auto_confirm = AUTO_CONFIRM.parse(matches);
             Self {
                 ledger_address,
                 transfers: hashes
@@ -3807,6 +4000,7 @@ pub mod args {
                     })
                     .collect(),
                 relayer,
+                auto_confirm,
             }
         }
 
@@ -3819,6 +4013,11 @@ pub mod args {
                 .arg(RELAYER.def().help(wrap!(
                     "The rewards address for relaying this proof."
                 )))
+                .arg(AUTO_CONFIRM.def().help(wrap!(
+                    "Don't ask for confirmation if some transfers have likely \
+                     been relayed already. These transfers are reported in \
+                     the output instead."
+                )))
         }
     }
 
@@ -3837,6 +4036,7 @@ pub mod args {
                 eth_addr: self.eth_addr,
                 sync: self.sync,
                 safe_mode: self.safe_mode,
+                auto_confirm: self.auto_confirm,
             }
         }
     }
@@ -3853,6 +4053,7 @@ pub mod args {
             let eth_addr = ETH_ADDRESS_OPT.parse(matches);
             let confirmations = ETH_CONFIRMATIONS.parse(matches);
             let sync = ETH_SYNC.parse(matches);
+            let auto_confirm = AUTO_CONFIRM.parse(matches);
             Self {
                 ledger_address,
                 sync,
@@ -3875,6 +4076,7 @@ pub mod args {
                 eth_addr,
                 confirmations,
                 safe_mode,
+                auto_confirm,
             }
         }
 
@@ -3884,6 +4086,10 @@ pub mod args {
                     "Safe mode overrides keyboard interrupt signals, to \
                      ensure Ethereum transfers aren't canceled midway through."
                 )))
+                .arg(AUTO_CONFIRM.def().help(wrap!(
+                    "Don't ask for confirmation if some transfers have likely \
+                     been relayed already, and relay them anyway."
+                )))
                 .arg(HASH_LIST.def().help(wrap!(
                     "Whitespace separated Keccak hash list of transfers in \
                      the Bridge pool."
@@ -3916,6 +4122,26 @@ pub mod args {
         }
     }
 
+    impl Args for BridgePoolHealthCheck<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let eth_rpc_endpoint = ETH_RPC_ENDPOINT.parse(matches);
+            Self {
+                ledger_address,
+                eth_rpc_endpoint,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(
+                    ETH_RPC_ENDPOINT
+                        .def()
+                        .help(wrap!("The Ethereum RPC endpoint.")),
+                )
+        }
+    }
+
     impl CliToSdkCtxless<BridgeValidatorSet<SdkTypes>>
         for BridgeValidatorSet<CliTypes>
     {
@@ -4191,6 +4417,7 @@ pub mod args {
                 target: chain_ctx.get(&self.target),
                 token: chain_ctx.get(&self.token),
                 amount: self.amount,
+                masp_rebuild_retries: self.masp_rebuild_retries,
                 tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
@@ -4203,6 +4430,7 @@ pub mod args {
             let target = TRANSFER_TARGET.parse(matches);
             let token = TOKEN.parse(matches);
             let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let masp_rebuild_retries = MASP_REBUILD_RETRIES.parse(matches);
             let tx_code_path = PathBuf::from(TX_TRANSFER_WASM);
             Self {
                 tx,
@@ -4210,6 +4438,7 @@ pub mod args {
                 target,
                 token,
                 amount,
+                masp_rebuild_retries,
                 tx_code_path,
             }
         }
@@ -4230,6 +4459,11 @@ pub mod args {
                         .def()
                         .help(wrap!("The amount to transfer in decimal.")),
                 )
+                .arg(MASP_REBUILD_RETRIES.def().help(wrap!(
+                    "The number of times a shielded transfer is rebuilt if \
+                     the epoch changes before it is applied, invalidating the \
+                     conversions it was built with."
+                )))
         }
     }
 
@@ -4779,6 +5013,13 @@ pub mod args {
                 validator: chain_ctx.get(&self.validator),
                 amount: self.amount,
                 source: self.source.map(|x| chain_ctx.get(&x)),
+                extra_bonds: self
+                    .extra_bonds
+                    .into_iter()
+                    .map(|(validator, amount)| {
+                        (chain_ctx.get(&validator), amount)
+                    })
+                    .collect(),
                 tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
@@ -4788,22 +5029,45 @@ pub mod args {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
             let validator = VALIDATOR.parse(matches);
-            let amount = AMOUNT.parse(matches);
-            let amount = amount
-                .canonical()
-                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
-                .unwrap_or_else(|e| {
-                    println!("Could not parse bond amount: {:?}", e);
-                    safe_exit(1);
-                })
-                .amount();
+            let native_amount = |amount: token::DenominatedAmount| {
+                amount
+                    .canonical()
+                    .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                    .unwrap_or_else(|e| {
+                        println!("Could not parse bond amount: {:?}", e);
+                        safe_exit(1);
+                    })
+                    .amount()
+            };
+            let amount = native_amount(AMOUNT.parse(matches));
             let source = SOURCE_OPT.parse(matches);
+            let extra_validators = EXTRA_VALIDATORS.parse(matches);
+            let extra_amounts = EXTRA_AMOUNTS.parse(matches);
+            if extra_validators.len() != extra_amounts.len() {
+                // Report it like the other invalid arguments caught by clap
+                let _ = clap::Error::raw(
+                    clap::error::ErrorKind::WrongNumberOfValues,
+                    format!(
+                        "The number of extra validators ({}) must match the \
+                         number of extra amounts ({}).\n",
+                        extra_validators.len(),
+                        extra_amounts.len()
+                    ),
+                )
+                .print();
+                safe_exit(2);
+            }
+            let extra_bonds = extra_validators
+                .into_iter()
+                .zip(extra_amounts.into_iter().map(native_amount))
+                .collect();
             let tx_code_path = PathBuf::from(TX_BOND_WASM);
             Self {
                 tx,
                 validator,
                 amount,
                 source,
+                extra_bonds,
                 tx_code_path,
             }
         }
@@ -4820,6 +5084,28 @@ pub mod args {
                     "Source address for delegations. For self-bonds, the \
                      validator is also the source."
                 )))
+                .arg(
+                    EXTRA_VALIDATORS
+                        .def()
+                        .help(wrap!(
+                            "Comma-separated list of additional validator \
+                             addresses to bond to in the same transaction, \
+                             which is applied atomically. Its gas limit must \
+                             cover all the bonds. Requires a delegation \
+                             source."
+                        ))
+                        .requires(EXTRA_AMOUNTS.name)
+                        .requires(SOURCE_OPT.name),
+                )
+                .arg(
+                    EXTRA_AMOUNTS
+                        .def()
+                        .help(wrap!(
+                            "Comma-separated list of the amounts of tokens to \
+                             stake with each of the additional validators."
+                        ))
+                        .requires(EXTRA_VALIDATORS.name),
+                )
         }
     }
 
@@ -5044,6 +5330,8 @@ pub mod args {
                 proposal_data,
                 is_pgf_stewards: self.is_pgf_stewards,
                 is_pgf_funding: self.is_pgf_funding,
+                is_eth_bridge_params: self.is_eth_bridge_params,
+                is_parameter_changes: self.is_parameter_changes,
                 tx_code_path: self.tx_code_path,
             })
         }
@@ -5055,6 +5343,8 @@ pub mod args {
             let proposal_data = DATA_PATH.parse(matches);
             let is_pgf_stewards = PROPOSAL_PGF_STEWARD.parse(matches);
             let is_pgf_funding = PROPOSAL_PGF_FUNDING.parse(matches);
+            let is_eth_bridge_params = PROPOSAL_ETH.parse(matches);
+            let is_parameter_changes = PROPOSAL_PARAMS.parse(matches);
             let tx_code_path = PathBuf::from(TX_INIT_PROPOSAL);
 
             Self {
@@ -5063,6 +5353,8 @@ pub mod args {
                 tx_code_path,
                 is_pgf_stewards,
                 is_pgf_funding,
+                is_eth_bridge_params,
+                is_parameter_changes,
             }
         }
 
@@ -5074,8 +5366,25 @@ pub mod args {
                 .arg(
                     PROPOSAL_ETH
                         .def()
-                        .help(wrap!("Flag if the proposal is of type eth."))
+                        .help(wrap!(
+                            "Flag if the proposal is of type eth. Used to \
+                             update the Ethereum bridge parameters."
+                        ))
+                        .conflicts_with_all([
+                            PROPOSAL_PARAMS.name,
+                            PROPOSAL_PGF_FUNDING.name,
+                            PROPOSAL_PGF_STEWARD.name,
+                        ]),
+                )
+                .arg(
+                    PROPOSAL_PARAMS
+                        .def()
+                        .help(wrap!(
+                            "Flag if the proposal is of type params. Used to \
+                             change protocol parameters."
+                        ))
                         .conflicts_with_all([
+                            PROPOSAL_ETH.name,
                             PROPOSAL_PGF_FUNDING.name,
                             PROPOSAL_PGF_STEWARD.name,
                         ]),
@@ -5089,6 +5398,7 @@ pub mod args {
                         ))
                         .conflicts_with_all([
                             PROPOSAL_ETH.name,
+                            PROPOSAL_PARAMS.name,
                             PROPOSAL_PGF_FUNDING.name,
                         ]),
                 )
@@ -5101,6 +5411,7 @@ pub mod args {
                         ))
                         .conflicts_with_all([
                             PROPOSAL_ETH.name,
+                            PROPOSAL_PARAMS.name,
                             PROPOSAL_PGF_STEWARD.name,
                         ]),
                 )
@@ -5525,6 +5836,7 @@ pub mod args {
                 owner: chain_ctx.get_cached(&self.owner),
                 token: chain_ctx.get(&self.token),
                 no_conversions: self.no_conversions,
+                height: self.height,
             })
         }
     }
@@ -5535,11 +5847,13 @@ pub mod args {
             let owner = BALANCE_OWNER.parse(matches);
             let token = TOKEN.parse(matches);
             let no_conversions = NO_CONVERSIONS.parse(matches);
+            let height = BLOCK_HEIGHT_OPT.parse(matches);
             Self {
                 query,
                 owner,
                 token,
                 no_conversions,
+                height,
             }
         }
 
@@ -5558,6 +5872,11 @@ pub mod args {
                 .arg(NO_CONVERSIONS.def().help(wrap!(
                     "Whether not to automatically perform conversions."
                 )))
+                .arg(BLOCK_HEIGHT_OPT.def().help(wrap!(
+                    "The block height at which to query the balance. Only \
+                     supported for transparent balances. Defaults to the \
+                     last committed block."
+                )))
         }
     }
 
@@ -6396,6 +6715,7 @@ pub mod args {
             Ok(QueryRawBytes::<SdkTypes> {
                 query: self.query.to_sdk(ctx)?,
                 storage_key: self.storage_key,
+                height: self.height,
             })
         }
     }
@@ -6403,13 +6723,22 @@ pub mod args {
     impl Args for QueryRawBytes<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let storage_key = STORAGE_KEY.parse(matches);
+            let height = BLOCK_HEIGHT_OPT.parse(matches);
             let query = Query::parse(matches);
-            Self { storage_key, query }
+            Self {
+                storage_key,
+                height,
+                query,
+            }
         }
 
         fn def(app: App) -> App {
             app.add_args::<Query<CliTypes>>()
                 .arg(STORAGE_KEY.def().help(wrap!("Storage key")))
+                .arg(BLOCK_HEIGHT_OPT.def().help(wrap!(
+                    "The block height at which to query the key. Defaults to \
+                     the last committed block."
+                )))
         }
     }
 
@@ -6450,6 +6779,9 @@ pub mod args {
                 output_folder: self.output_folder,
                 force: self.force,
                 broadcast_only: self.broadcast_only,
+                skip_if_applied: self.skip_if_applied,
+                confirm: self.confirm,
+                subscribe_events: self.subscribe_events,
                 ledger_address: ctx.get(&self.ledger_address),
                 initialized_account_alias: self.initialized_account_alias,
                 wallet_alias_force: self.wallet_alias_force,
@@ -6516,6 +6848,21 @@ pub mod args {
                 "Do not wait for the transaction to be applied. This will \
                  return once the transaction is added to the mempool."
             )))
+            .arg(SKIP_IF_APPLIED.def().help(wrap!(
+                "Check whether the transaction was already applied on chain \
+                 before submitting it, and skip the submission if so. Useful \
+                 when retrying the submission of a signed transaction."
+            )))
+            .arg(CONFIRM.def().help(wrap!(
+                "Display a human-readable summary of the transaction, \
+                 including its fees and signer, and ask for confirmation \
+                 before submitting it."
+            )))
+            .arg(SUBSCRIBE_EVENTS.def().help(wrap!(
+                "Wait for the transaction to be applied over the websocket \
+                 event subscription of the ledger node, rather than polling \
+                 it. Falls back to polling if the subscription fails."
+            )))
             .arg(
                 CONFIG_RPC_LEDGER_ADDRESS
                     .def()
@@ -6599,7 +6946,9 @@ pub mod args {
                     .help(wrap!(
                         "The implicit address of the gas payer. It defaults \
                          to the address associated to the first key passed to \
-                         --signing-keys."
+                         --signing-keys. The gas payer only signs the wrapper \
+                         of the tx, whose inner tx is still signed by its \
+                         default signer or the keys passed to --signing-keys."
                     ))
                     .conflicts_with(DISPOSABLE_SIGNING_KEY.name),
             )
@@ -6620,6 +6969,9 @@ pub mod args {
             let dump_tx = DUMP_TX.parse(matches);
             let force = FORCE.parse(matches);
             let broadcast_only = BROADCAST_ONLY.parse(matches);
+            let skip_if_applied = SKIP_IF_APPLIED.parse(matches);
+            let confirm = CONFIRM.parse(matches);
+            let subscribe_events = SUBSCRIBE_EVENTS.parse(matches);
             let ledger_address = CONFIG_RPC_LEDGER_ADDRESS.parse(matches);
             let initialized_account_alias = ALIAS_OPT.parse(matches);
             let fee_amount =
@@ -6654,6 +7006,9 @@ pub mod args {
                 dump_tx,
                 force,
                 broadcast_only,
+                skip_if_applied,
+                confirm,
+                subscribe_events,
                 ledger_address,
                 initialized_account_alias,
                 wallet_alias_force,
@@ -6887,6 +7242,7 @@ pub mod args {
                 HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.parse(matches);
             let prompt_bip39_passphrase =
                 HD_PROMPT_BIP39_PASSPHRASE.parse(matches);
+            let birthday = BIRTHDAY.parse(matches);
             Self {
                 scheme,
                 shielded,
@@ -6897,6 +7253,7 @@ pub mod args {
                 derivation_path,
                 allow_non_compliant,
                 prompt_bip39_passphrase,
+                birthday,
             }
         }
 
@@ -6955,6 +7312,11 @@ pub mod args {
             .arg(HD_PROMPT_BIP39_PASSPHRASE.def().help(wrap!(
                 "Use an additional passphrase for HD-key generation."
             )))
+            .arg(BIRTHDAY.def().requires(SHIELDED.name).help(wrap!(
+                "The block height before which the generated spending key \
+                 cannot have received any notes. Shielded syncs will skip \
+                 scanning the preceding blocks for this key."
+            )))
         }
     }
 
@@ -7098,11 +7460,13 @@ pub mod args {
             let alias_force = ALIAS_FORCE.parse(matches);
             let value = VALUE.parse(matches);
             let unsafe_dont_encrypt = UNSAFE_DONT_ENCRYPT.parse(matches);
+            let birthday = BIRTHDAY.parse(matches);
             Self {
                 alias,
                 alias_force,
                 value,
                 unsafe_dont_encrypt,
+                birthday,
             }
         }
 
@@ -7125,6 +7489,12 @@ pub mod args {
                 "UNSAFE: Do not encrypt the added keys. Do not use this for \
                  keys used in a live network."
             )))
+            .arg(BIRTHDAY.def().help(wrap!(
+                "The block height before which the added shielded pool \
+                 spending or viewing key cannot have received any notes. \
+                 Shielded syncs will skip scanning the preceding blocks for \
+                 this key."
+            )))
         }
     }
 
@@ -322,6 +322,18 @@ impl CliApi {
                             .into_iter()
                             .map(|sk| sk.into())
                             .collect::<Vec<_>>();
+                        let birthdays = chain_ctx
+                            .wallet
+                            .get_viewing_keys()
+                            .into_iter()
+                            .filter_map(|(alias, vk)| {
+                                let birthday =
+                                    chain_ctx.wallet.find_birthday(alias)?;
+                                let vk =
+                                    ExtendedFullViewingKey::from(vk).fvk.vk;
+                                Some((vk, birthday))
+                            })
+                            .collect();
                         crate::client::masp::syncing(
                             chain_ctx.shielded,
                             &client,
@@ -331,6 +343,7 @@ impl CliApi {
                             args.last_query_height,
                             &sks,
                             &vks,
+                            &birthdays,
                         )
                         .await?;
                     }
@@ -355,6 +368,48 @@ impl CliApi {
                             "The Namada Ethereum bridge is disabled"
                         );
                     }
+                    #[cfg(feature = "namada-eth-bridge")]
+                    Sub::CancelFromEthBridgePool(args) => {
+                        let args = args.0;
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_cancel_bridge_pool_tx(&namada, args).await?;
+                    }
+                    #[cfg(not(feature = "namada-eth-bridge"))]
+                    Sub::CancelFromEthBridgePool(_) => {
+                        display_line!(
+                            &io,
+                            "The Namada Ethereum bridge is disabled"
+                        );
+                    }
+                    #[cfg(feature = "namada-eth-bridge")]
+                    Sub::TopUpEthBridgePool(args) => {
+                        let args = args.0;
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_top_up_bridge_pool_tx(&namada, args).await?;
+                    }
+                    #[cfg(not(feature = "namada-eth-bridge"))]
+                    Sub::TopUpEthBridgePool(_) => {
+                        display_line!(
+                            &io,
+                            "The Namada Ethereum bridge is disabled"
+                        );
+                    }
                     Sub::TxUnjailValidator(TxUnjailValidator(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -106,6 +106,24 @@ impl CliApi {
                     client.wait_until_node_is_synced(&io).await?;
                     bridge_pool::query_relay_progress(&client, &io).await?;
                 }
+                EthBridgePoolWithoutCtx::HealthCheck(BridgePoolHealth(
+                    args,
+                )) => {
+                    // NOTE: we don't wait for the node to be synced here,
+                    // since its sync state is one of the reported checks
+                    let client = client.unwrap_or_else(|| {
+                        C::from_tendermint_address(&args.ledger_address)
+                    });
+                    let eth_client =
+                        get_eth_rpc_client(&args.eth_rpc_endpoint).await;
+                    let report = bridge_pool::check_relayer_health(
+                        eth_client, &client, &io,
+                    )
+                    .await?;
+                    if !report.healthy {
+                        cli::safe_exit(1)
+                    }
+                }
             },
             cli::NamadaRelayer::ValidatorSet(sub) => match sub {
                 ValidatorSet::BridgeValidatorSet(BridgeValidatorSet(args)) => {
@@ -15,6 +15,7 @@ use masp_primitives::zip32::ExtendedFullViewingKey;
 use namada::core::address::{Address, DecodeError};
 use namada::core::key::*;
 use namada::core::masp::{ExtendedSpendingKey, MaspValue, PaymentAddress};
+use namada::core::storage::BlockHeight;
 use namada::io::Io;
 use namada_sdk::masp::find_valid_diversifier;
 use namada_sdk::wallet::{
@@ -255,6 +256,7 @@ fn shielded_key_gen(
         derivation_path,
         allow_non_compliant,
         prompt_bip39_passphrase,
+        birthday,
         ..
     }: args::KeyGen,
 ) {
@@ -296,6 +298,9 @@ fn shielded_key_gen(
         println!("No changes are persisted. Exiting.");
         cli::safe_exit(1);
     });
+    if let Some(birthday) = birthday {
+        wallet.insert_birthday(&alias, birthday);
+    }
 
     wallet
         .save()
@@ -349,6 +354,7 @@ fn shielded_key_address_add(
     alias_force: bool,
     masp_value: MaspValue,
     unsafe_dont_encrypt: bool,
+    birthday: Option<BlockHeight>,
 ) {
     let alias = alias.to_lowercase();
     let mut wallet = load_wallet(ctx);
@@ -389,6 +395,16 @@ fn shielded_key_address_add(
             (alias, "payment address")
         }
     };
+    if let Some(birthday) = birthday {
+        if !wallet.insert_birthday(&alias, birthday) {
+            edisplay_line!(
+                io,
+                "A birthday can only be set for spending and viewing keys"
+            );
+            display_line!(io, "No changes are persisted. Exiting.");
+            cli::safe_exit(1);
+        }
+    }
     wallet.save().unwrap_or_else(|err| eprintln!("{}", err));
     display_line!(
         io,
@@ -798,6 +814,7 @@ fn add_key_or_address(
     alias_force: bool,
     value: KeyAddrAddValue,
     unsafe_dont_encrypt: bool,
+    birthday: Option<BlockHeight>,
 ) {
     match value {
         KeyAddrAddValue::TranspValue(TransparentValue::TranspSecretKey(sk)) => {
@@ -823,6 +840,7 @@ fn add_key_or_address(
             alias_force,
             masp_value,
             unsafe_dont_encrypt,
+            birthday,
         ),
     }
 }
@@ -836,7 +854,7 @@ fn key_address_add(
         alias_force,
         value,
         unsafe_dont_encrypt,
-        ..
+        birthday,
     }: args::KeyAddressAdd,
 ) {
     let value = KeyAddrAddValue::from_str(&value).unwrap_or_else(|err| {
@@ -844,7 +862,15 @@ fn key_address_add(
         display_line!(io, "No changes are persisted. Exiting.");
         cli::safe_exit(1)
     });
-    add_key_or_address(ctx, io, alias, alias_force, value, unsafe_dont_encrypt)
+    add_key_or_address(
+        ctx,
+        io,
+        alias,
+        alias_force,
+        value,
+        unsafe_dont_encrypt,
+        birthday,
+    )
 }
 
 /// Remove keys and addresses
@@ -12,6 +12,7 @@ use masp_primitives::transaction::components::I128Sum;
 use masp_primitives::zip32::ExtendedFullViewingKey;
 use namada::core::address::{Address, InternalAddress, MASP};
 use namada::core::collections::{HashMap, HashSet};
+use namada::core::dec::Dec;
 use namada::core::hash::Hash;
 use namada::core::key::*;
 use namada::core::masp::BalanceOwner;
@@ -156,7 +157,7 @@ pub async fn query_raw_bytes<N: Namada>(
             .storage_value(
                 context.client(),
                 None,
-                None,
+                args.height,
                 false,
                 &args.storage_key,
             )
@@ -184,6 +185,14 @@ pub async fn query_balance(context: &impl Namada, args: args::QueryBalance) {
             query_transparent_balance(context, args).await
         }
         BalanceOwner::FullViewingKey(_) => {
+            if args.height.is_some() {
+                edisplay_line!(
+                    context.io(),
+                    "Querying shielded balances at a past block height is \
+                     not supported"
+                );
+                cli::safe_exit(1)
+            }
             query_shielded_balance(context, args).await
         }
     }
@@ -199,6 +208,8 @@ async fn query_transparent_balance(
         owner,
         // The token to query
         token,
+        // The block height at which to query the balance
+        height,
         ..
     } = args;
 
@@ -207,9 +218,21 @@ async fn query_transparent_balance(
         .expect("Balance owner should have been a transparent address");
 
     let token_alias = lookup_token_alias(context, &token, &owner).await;
-    let token_balance_result =
-        namada_sdk::rpc::get_token_balance(context.client(), &token, &owner)
-            .await;
+    let token_balance_result = match height {
+        Some(height) => {
+            namada_sdk::rpc::get_token_balance_at_height(
+                context.client(),
+                &token,
+                &owner,
+                height,
+            )
+            .await
+        }
+        None => {
+            namada_sdk::rpc::get_token_balance(context.client(), &token, &owner)
+                .await
+        }
+    };
 
     match token_balance_result {
         Ok(balance) => {
@@ -233,7 +256,14 @@ async fn lookup_token_alias(
 ) -> String {
     match token {
         Address::Internal(InternalAddress::Erc20(eth_addr)) => {
-            eth_addr.to_canonical()
+            // Prefer the symbol registered on chain for the ERC20 asset
+            RPC.shell()
+                .eth_bridge()
+                .get_erc20_metadata(context.client(), eth_addr)
+                .await
+                .ok()
+                .and_then(|metadata| metadata.symbol)
+                .unwrap_or_else(|| eth_addr.to_canonical())
         }
         Address::Internal(InternalAddress::IbcToken(_)) => {
             let ibc_denom =
@@ -583,6 +613,12 @@ pub async fn query_protocol_parameters(
         "",
         governance_parameters.min_proposal_grace_epochs
     );
+    display_line!(
+        context.io(),
+        "{:4}Proposal funds policy: {:?}",
+        "",
+        governance_parameters.proposal_funds_policy
+    );
 
     let pgf_parameters = query_pgf_parameters(context.client()).await;
     display_line!(context.io(), "\nPublic Goods Funding Parameters");
@@ -674,6 +710,29 @@ pub async fn query_protocol_parameters(
         display_line!(context.io(), "{:8}{}: {:?}", "", token, gas_cost);
     }
 
+    // The fee conversion rates and price feed are unset on chains that never
+    // priced fee tokens by conversion
+    let key = param_storage::get_fee_conversion_rates_key();
+    let fee_conversion_rates: BTreeMap<Address, Dec> =
+        query_storage_value(context.client(), &key)
+            .await
+            .unwrap_or_default();
+    display_line!(context.io(), "{:4}Fee conversion rates:", "");
+    for (token, rate) in fee_conversion_rates {
+        display_line!(context.io(), "{:8}{}: {}", "", token, rate);
+    }
+    let key = param_storage::get_fee_price_feed_key();
+    let fee_price_feed: Option<Address> =
+        query_storage_value(context.client(), &key).await.ok();
+    if let Some(fee_price_feed) = fee_price_feed {
+        display_line!(
+            context.io(),
+            "{:4}Fee price feed: {}",
+            "",
+            fee_price_feed
+        );
+    }
+
     display_line!(context.io(), "PoS parameters");
     let pos_params = query_pos_parameters(context.client()).await;
     display_line!(
@@ -1173,17 +1232,6 @@ pub async fn query_validator_state<
     )
 }
 
-/// Query and return the available reward tokens corresponding to the bond
-pub async fn query_rewards<C: namada::ledger::queries::Client + Sync>(
-    client: &C,
-    source: &Option<Address>,
-    validator: &Address,
-) -> token::Amount {
-    unwrap_client_response::<C, token::Amount>(
-        RPC.vp().pos().rewards(client, validator, source).await,
-    )
-}
-
 /// Query a validator's state information
 pub async fn query_and_print_validator_state(
     context: &impl Namada,
@@ -1530,7 +1578,12 @@ pub async fn query_and_print_rewards<N: Namada>(
 ) {
     let (source, validator) = (args.source, args.validator);
 
-    let rewards = query_rewards(context.client(), &source, &validator).await;
+    let rewards = rpc::query_rewards(context.client(), &source, &validator)
+        .await
+        .unwrap_or_else(|err| {
+            edisplay_line!(context.io(), "Error in the query: {err}");
+            cli::safe_exit(1)
+        });
     display_line!(
         context.io(),
         "Current rewards available for claim: {} NAM",
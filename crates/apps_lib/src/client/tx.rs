@@ -8,13 +8,16 @@ use ledger_transport_hid::TransportNativeHID;
 use namada::core::address::{Address, ImplicitAddress};
 use namada::core::collections::HashSet;
 use namada::core::key::*;
+use namada::core::masp::ExtendedSpendingKey;
 use namada::governance::cli::onchain::{
-    DefaultProposal, PgfFundingProposal, PgfStewardProposal,
+    DefaultProposal, EthBridgeParamsProposal, ParameterChangesProposal,
+    PgfFundingProposal, PgfStewardProposal,
 };
 use namada::io::Io;
 use namada::state::EPOCH_SWITCH_BLOCKS_DELAY;
 use namada::tx::{CompressedAuthorization, Section, Signer, Tx};
 use namada_sdk::args::TxBecomeValidator;
+use namada_sdk::masp::ShieldedContext;
 use namada_sdk::rpc::{InnerTxResult, TxBroadcastData, TxResponse};
 use namada_sdk::wallet::alias::{validator_address, validator_consensus_key};
 use namada_sdk::wallet::{Wallet, WalletIo};
@@ -25,7 +28,7 @@ use tokio::sync::RwLock;
 use super::rpc;
 use crate::cli::{args, safe_exit};
 use crate::client::tx::signing::{default_sign, SigningTxData};
-use crate::client::tx::tx::ProcessTxResponse;
+use crate::client::tx::tx::{InitializedAccount, ProcessTxResponse};
 use crate::config::TendermintMode;
 use crate::facade::tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use crate::tendermint_node;
@@ -241,6 +244,42 @@ pub async fn submit_bridge_pool_tx<N: Namada>(
     Ok(())
 }
 
+pub async fn submit_cancel_bridge_pool_tx<N: Namada>(
+    namada: &N,
+    args: args::CancelEthereumTransfer,
+) -> Result<(), error::Error> {
+    let tx_args = args.tx.clone();
+    let (mut tx, signing_data) = args.clone().build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &tx_args, signing_data).await?;
+
+        namada.submit(tx, &tx_args).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_top_up_bridge_pool_tx<N: Namada>(
+    namada: &N,
+    args: args::TopUpEthereumTransfer,
+) -> Result<(), error::Error> {
+    let tx_args = args.tx.clone();
+    let (mut tx, signing_data) = args.clone().build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &tx_args, signing_data).await?;
+
+        namada.submit(tx, &tx_args).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_custom<N: Namada>(
     namada: &N,
     args: args::TxCustom,
@@ -283,10 +322,12 @@ where
     Ok(())
 }
 
+/// Submit a tx to initialize an account. Returns the initialized account
+/// along with the alias it was saved under in the wallet, if any.
 pub async fn submit_init_account<N: Namada>(
     namada: &N,
     args: args::TxInitAccount,
-) -> Result<Option<Address>, error::Error>
+) -> Result<Option<InitializedAccount>, error::Error>
 where
     <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
 {
@@ -299,8 +340,8 @@ where
 
         let cmt = tx.first_commitments().unwrap().to_owned();
         let response = namada.submit(tx, &args.tx).await?;
-        if let Some(result) = response.is_applied_and_valid(&cmt) {
-            return Ok(result.initialized_accounts.first().cloned());
+        if response.is_applied_and_valid(&cmt).is_some() {
+            return Ok(response.initialized_accounts().first().cloned());
         }
     }
 
@@ -704,7 +745,7 @@ pub async fn submit_init_validator(
         );
         safe_exit(1)
     }
-    let address = address.unwrap_or_else(|| {
+    let address = address.map(|account| account.address).unwrap_or_else(|| {
         eprintln!(
             "Something went wrong with transaction to initialize an account \
              as no address has been created. Cannot proceed to become \
@@ -739,11 +780,70 @@ pub async fn submit_init_validator(
     .await
 }
 
-pub async fn submit_transfer(
-    namada: &impl Namada,
+/// Check that the notes of the source spending key can fund a shielded
+/// transfer before generating any proofs, displaying the missing amounts
+/// otherwise.
+async fn check_shielded_transfer_funds<N: Namada>(
+    namada: &N,
+    args: &args::TxTransfer,
+    spending_key: ExtendedSpendingKey,
+) -> Result<(), error::Error> {
+    let amount = namada_sdk::rpc::validate_amount(
+        namada,
+        args.amount,
+        &args.token,
+        args.tx.force,
+    )
+    .await?;
+    let estimate =
+        ShieldedContext::<N::ShieldedUtils>::estimate_shielded_transfer(
+            namada,
+            &spending_key.into(),
+            &args.token,
+            amount,
+        )
+        .await?;
+    if estimate.is_sufficient() {
+        return Ok(());
+    }
+
+    let (shortfall, _) = namada
+        .shielded_mut()
+        .await
+        .decode_combine_sum_to_epoch(
+            namada.client(),
+            estimate.shortfall,
+            estimate.epoch,
+        )
+        .await;
+    edisplay_line!(
+        namada.io(),
+        "The shielded balance of the source is insufficient for this \
+         transfer, after selecting {} notes and {} conversions. Missing:",
+        estimate.notes,
+        estimate.conversions,
+    );
+    for (token, change) in shortfall.components() {
+        let missing = namada.format_amount(token, (*change).into()).await;
+        edisplay_line!(namada.io(), "  {}: {}", token, missing);
+    }
+    Err(error::TxSubmitError::InsufficientShieldedFunds(
+        amount.amount().to_string_native(),
+        Box::new(args.token.clone()),
+    )
+    .into())
+}
+
+pub async fn submit_transfer<N: Namada>(
+    namada: &N,
     args: args::TxTransfer,
 ) -> Result<(), error::Error> {
-    for _ in 0..2 {
+    if let Some(spending_key) = args.source.spending_key() {
+        check_shielded_transfer_funds(namada, &args, spending_key).await?;
+    }
+
+    let mut retries_left = args.masp_rebuild_retries;
+    loop {
         submit_reveal_aux(
             namada,
             args.tx.clone(),
@@ -759,16 +859,39 @@ pub async fn submit_transfer(
             break;
         } else {
             sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+            // If the epoch changed while a shielded transaction was being
+            // built and signed, the conversions it uses have expired. Rebuild
+            // it against the new ones rather than paying for a rejected tx.
+            if let Some(tx_epoch) = tx_epoch {
+                let current_epoch =
+                    namada_sdk::rpc::query_epoch(namada.client()).await?;
+                if tx_epoch != current_epoch {
+                    if let Some(left) = retries_left.checked_sub(1) {
+                        retries_left = left;
+                        edisplay_line!(
+                            namada.io(),
+                            "The epoch changed from {tx_epoch} to \
+                             {current_epoch} while building the MASP \
+                             transaction. Rebuilding it with the updated \
+                             conversions.",
+                        );
+                        continue;
+                    }
+                }
+            }
             let cmt_hash = tx.first_commitments().unwrap().get_hash();
 
             let result = namada.submit(tx, &args.tx).await?;
 
             match result {
-                ProcessTxResponse::Applied(resp) if
+                ProcessTxResponse::Applied(resp, _) if
                     // If a transaction is shielded
                     tx_epoch.is_some() &&
                     // And it is rejected by a VP
-                    matches!(resp.batch_result().get(&cmt_hash), Some(InnerTxResult::VpsRejected(_))) =>
+                    matches!(resp.batch_result().get(&cmt_hash), Some(InnerTxResult::VpsRejected(_))) &&
+                    // And we can still rebuild it
+                    retries_left > 0 =>
                 {
                     let submission_epoch = rpc::query_and_print_epoch(namada).await;
                     // And its submission epoch doesn't match construction epoch
@@ -778,8 +901,10 @@ pub async fn submit_transfer(
                             "MASP transaction rejected and this may be due to the \
                             epoch changing. Attempting to resubmit transaction.",
                         );
+                        retries_left = retries_left.saturating_sub(1);
                         continue;
                     }
+                    break;
                 },
                 // Otherwise either the transaction was successful or it will not
                 // benefit from resubmission
@@ -877,6 +1002,68 @@ where
             .await?;
 
         tx::build_pgf_stewards_proposal(namada, &args, proposal).await?
+    } else if args.is_eth_bridge_params {
+        let proposal =
+            EthBridgeParamsProposal::try_from(args.proposal_data.as_ref())
+                .map_err(|e| {
+                    error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                        e.to_string(),
+                    )
+                })?;
+        let author_balance = namada_sdk::rpc::get_token_balance(
+            namada.client(),
+            &namada.native_token(),
+            &proposal.proposal.author,
+        )
+        .await
+        .unwrap();
+        let proposal = proposal
+            .validate(
+                &governance_parameters,
+                current_epoch,
+                author_balance,
+                args.tx.force,
+            )
+            .map_err(|e| {
+                error::TxSubmitError::InvalidProposal(e.to_string())
+            })?;
+
+        submit_reveal_aux(namada, args.tx.clone(), &proposal.proposal.author)
+            .await?;
+
+        tx::build_eth_bridge_params_proposal(namada, &args, proposal).await?
+    } else if args.is_parameter_changes {
+        let proposal =
+            ParameterChangesProposal::try_from(args.proposal_data.as_ref())
+                .map_err(|e| {
+                    error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                        e.to_string(),
+                    )
+                })?;
+        let native_token = namada.native_token();
+        let author_balance = namada_sdk::rpc::get_token_balance(
+            namada.client(),
+            &native_token,
+            &proposal.proposal.author,
+        )
+        .await
+        .unwrap();
+        let proposal = proposal
+            .validate(
+                &governance_parameters,
+                current_epoch,
+                author_balance,
+                &native_token,
+                args.tx.force,
+            )
+            .map_err(|e| {
+                error::TxSubmitError::InvalidProposal(e.to_string())
+            })?;
+
+        submit_reveal_aux(namada, args.tx.clone(), &proposal.proposal.author)
+            .await?;
+
+        tx::build_parameter_changes_proposal(namada, &args, proposal).await?
     } else {
         let proposal = DefaultProposal::try_from(args.proposal_data.as_ref())
             .map_err(|e| {
@@ -1286,7 +1473,7 @@ pub async fn save_initialized_accounts(
     namada: &impl Namada,
     args: &args::Tx,
     initialized_accounts: Vec<Address>,
-) {
+) -> Vec<InitializedAccount> {
     tx::save_initialized_accounts(namada, args, initialized_accounts).await
 }
 
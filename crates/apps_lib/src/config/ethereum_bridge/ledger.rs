@@ -10,6 +10,14 @@ pub const DEFAULT_ORACLE_RPC_ENDPOINT: &str = "http://127.0.0.1:8545";
 /// the oracle and the shell can hold.
 pub const ORACLE_CHANNEL_BUFFER_SIZE: usize = 1000;
 
+/// The default number of seconds the oracle may fail to query its Ethereum
+/// JSON-RPC endpoint before it is reported as degraded.
+pub const ORACLE_STALL_THRESHOLD_SECS: u64 = 120;
+
+/// The default maximum number of Ethereum blocks the oracle may query for
+/// events in a single JSON-RPC request.
+pub const ORACLE_MAX_BLOCK_RANGE: u64 = 100;
+
 /// The mode in which to run the Ethereum bridge.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Mode {
@@ -37,6 +45,39 @@ pub struct Config {
     /// ledger subprocesses. This is the number of Ethereum events that
     /// can be held in the channel. The default is 1000.
     pub channel_buffer_size: usize,
+    /// The number of seconds the Ethereum event oracle may fail to query
+    /// its JSON-RPC endpoint before it is reported as degraded. The default
+    /// is 120.
+    #[serde(default = "default_oracle_stall_threshold")]
+    pub oracle_stall_threshold: u64,
+    /// The maximum number of Ethereum blocks the oracle may query for events
+    /// in a single `eth_getLogs` request. The oracle falls back to smaller
+    /// ranges if the endpoint rejects a request. The default is 100.
+    #[serde(default = "default_oracle_max_block_range")]
+    pub oracle_max_block_range: u64,
+    /// Hex-encoded secret shared with the clients of the self-hosted events
+    /// endpoint. When set, every request must carry a strictly increasing
+    /// nonce and an HMAC-SHA256 of the request keyed with this secret,
+    /// otherwise it is rejected. Nonces are UNIX timestamps in nanoseconds,
+    /// which must be later than the start of the node and at most a minute
+    /// ahead of its clock, so requests captured before a restart of the node
+    /// can't be replayed. When unset, requests are not authenticated.
+    #[serde(default)]
+    pub events_endpoint_secret: Option<String>,
+    /// The Ethereum block height from which the oracle should scan for
+    /// events, overriding the last processed block persisted in storage.
+    /// Only meant for manual re-scans and should be unset again once the
+    /// node has restarted, otherwise every restart rescans from this height.
+    #[serde(default)]
+    pub oracle_rescan_from: Option<u64>,
+}
+
+fn default_oracle_stall_threshold() -> u64 {
+    ORACLE_STALL_THRESHOLD_SECS
+}
+
+fn default_oracle_max_block_range() -> u64 {
+    ORACLE_MAX_BLOCK_RANGE
 }
 
 impl Default for Config {
@@ -45,6 +86,10 @@ impl Default for Config {
             mode: Mode::RemoteEndpoint,
             oracle_rpc_endpoint: DEFAULT_ORACLE_RPC_ENDPOINT.to_owned(),
             channel_buffer_size: ORACLE_CHANNEL_BUFFER_SIZE,
+            oracle_stall_threshold: ORACLE_STALL_THRESHOLD_SECS,
+            oracle_max_block_range: ORACLE_MAX_BLOCK_RANGE,
+            events_endpoint_secret: None,
+            oracle_rescan_from: None,
         }
     }
 }
@@ -371,6 +371,8 @@ pub fn make_dev_genesis(
     genesis.parameters.eth_bridge_params = Some(templates::EthBridgeParams {
         eth_start_height: Default::default(),
         min_confirmations: Default::default(),
+        event_confirmations: Default::default(),
+        bridge_pool_cancel_delay: Default::default(),
         contracts: Contracts {
             native_erc20: wnam(),
             bridge: UpgradeableContract {
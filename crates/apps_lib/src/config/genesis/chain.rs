@@ -296,6 +296,8 @@ impl Finalized {
     ) -> namada::ledger::parameters::Parameters {
         let templates::ChainParams {
             min_num_of_blocks,
+            min_epoch_duration,
+            epoch_switch_policy,
             max_expected_time_per_block,
             max_proposal_bytes,
             vp_allowlist,
@@ -307,6 +309,7 @@ impl Finalized {
             max_block_gas,
             minimum_gas_price,
             max_tx_bytes,
+            max_code_bytes,
             is_native_token_transferable,
             ..
         } = self.parameters.parameters.clone();
@@ -323,10 +326,13 @@ impl Finalized {
                 .ok()
                 .map(Hash::sha256);
 
-        let epy_i64 = i64::try_from(epochs_per_year)
-            .expect("`epochs_per_year` must not exceed `i64::MAX`");
-        #[allow(clippy::arithmetic_side_effects)]
-        let min_duration: i64 = 60 * 60 * 24 * 365 / epy_i64;
+        let min_duration: i64 = min_epoch_duration.unwrap_or_else(|| {
+            let epy_i64 = i64::try_from(epochs_per_year)
+                .expect("`epochs_per_year` must not exceed `i64::MAX`");
+            #[allow(clippy::arithmetic_side_effects)]
+            let min_duration = 60 * 60 * 24 * 365 / epy_i64;
+            min_duration
+        });
         let epoch_duration = EpochDuration {
             min_num_of_blocks,
             min_duration: namada::core::time::Duration::seconds(min_duration)
@@ -360,6 +366,8 @@ impl Finalized {
                 })
                 .collect(),
             is_native_token_transferable,
+            epoch_switch_policy,
+            max_code_bytes,
         }
     }
 
@@ -419,6 +427,7 @@ impl Finalized {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            proposal_funds_policy,
         } = self.parameters.gov_params.clone();
         namada::governance::parameters::GovernanceParameters {
             min_proposal_fund: Amount::native_whole(min_proposal_fund),
@@ -428,6 +437,7 @@ impl Finalized {
             min_proposal_grace_epochs,
             min_proposal_voting_period,
             max_proposal_latency,
+            proposal_funds_policy,
         }
     }
 
@@ -443,6 +453,8 @@ impl Finalized {
         if let Some(templates::EthBridgeParams {
             eth_start_height,
             min_confirmations,
+            event_confirmations,
+            bridge_pool_cancel_delay,
             contracts,
             erc20_whitelist,
         }) = self.parameters.eth_bridge_params.clone()
@@ -450,6 +462,8 @@ impl Finalized {
             Some(namada::ledger::eth_bridge::EthereumBridgeParams {
                 eth_start_height,
                 min_confirmations,
+                event_confirmations,
+                bridge_pool_cancel_delay,
                 erc20_whitelist,
                 contracts,
             })
@@ -9,12 +9,15 @@ use namada::core::address::Address;
 use namada::core::chain::ProposalBytes;
 use namada::core::dec::Dec;
 use namada::core::ethereum_structs;
+use namada::core::parameters::{EpochSwitchPolicy, DEFAULT_MAX_CODE_BYTES};
 use namada::core::token::{
     Amount, DenominatedAmount, Denomination, NATIVE_MAX_DECIMAL_PLACES,
 };
 use namada::eth_bridge::storage::parameters::{
-    Contracts, Erc20WhitelistEntry, MinimumConfirmations,
+    BridgePoolCancelDelay, Contracts, Erc20WhitelistEntry, EventConfirmations,
+    MinimumConfirmations,
 };
+use namada::governance::parameters::ProposalFundsPolicy;
 use namada::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
@@ -257,6 +260,10 @@ pub struct ChainParams<T: TemplateValidation> {
     /// Max payload size, in bytes, for a tx decided through
     /// the consensus protocol.
     pub max_tx_bytes: u32,
+    /// Max size, in bytes, of the wasm code embedded in a tx. Defaults to
+    /// 1 MiB.
+    #[serde(default = "default_max_code_bytes")]
+    pub max_code_bytes: u32,
     /// Name of the native token - this must one of the tokens from
     /// `tokens.toml` file
     pub native_token: Alias,
@@ -265,6 +272,16 @@ pub struct ChainParams<T: TemplateValidation> {
     /// Minimum number of blocks per epoch.
     // NB: u64 only works with values up to i64::MAX with toml-rs!
     pub min_num_of_blocks: u64,
+    /// Minimum duration per epoch (in seconds). Defaults to a year divided
+    /// by `epochs_per_year`.
+    // NB: this is i64 because datetime wants it
+    #[serde(default)]
+    pub min_epoch_duration: Option<i64>,
+    /// The conditions under which a new epoch begins. Defaults to requiring
+    /// both the minimum number of blocks and the minimum duration of an
+    /// epoch to have passed.
+    #[serde(default)]
+    pub epoch_switch_policy: EpochSwitchPolicy,
     /// Maximum duration per block (in seconds).
     // NB: this is i64 because datetime wants it
     pub max_expected_time_per_block: i64,
@@ -303,6 +320,10 @@ pub struct ChainParams<T: TemplateValidation> {
     pub minimum_gas_price: T::GasMinimums,
 }
 
+fn default_max_code_bytes() -> u32 {
+    DEFAULT_MAX_CODE_BYTES
+}
+
 impl ChainParams<Unvalidated> {
     pub fn denominate(
         self,
@@ -310,9 +331,12 @@ impl ChainParams<Unvalidated> {
     ) -> eyre::Result<ChainParams<Validated>> {
         let ChainParams {
             max_tx_bytes,
+            max_code_bytes,
             native_token,
             is_native_token_transferable,
             min_num_of_blocks,
+            min_epoch_duration,
+            epoch_switch_policy,
             max_expected_time_per_block,
             max_proposal_bytes,
             vp_allowlist,
@@ -355,9 +379,12 @@ impl ChainParams<Unvalidated> {
 
         Ok(ChainParams {
             max_tx_bytes,
+            max_code_bytes,
             native_token,
             is_native_token_transferable,
             min_num_of_blocks,
+            min_epoch_duration,
+            epoch_switch_policy,
             max_expected_time_per_block,
             max_proposal_bytes,
             vp_allowlist,
@@ -451,6 +478,9 @@ pub struct GovernanceParams {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epochs
     pub max_proposal_latency: u64,
+    /// Policy deciding where the funds locked by a proposal end up
+    #[serde(default)]
+    pub proposal_funds_policy: ProposalFundsPolicy,
 }
 
 #[derive(
@@ -497,6 +527,14 @@ pub struct EthBridgeParams {
     /// Minimum number of confirmations needed to trust an Ethereum branch.
     /// This must be at least one.
     pub min_confirmations: MinimumConfirmations,
+    /// Minimum number of confirmations of specific types of Ethereum
+    /// events, overriding `min_confirmations`.
+    #[serde(default)]
+    pub event_confirmations: EventConfirmations,
+    /// Number of blocks after which the sender of a Bridge pool
+    /// transfer may cancel it, and get refunded.
+    #[serde(default)]
+    pub bridge_pool_cancel_delay: BridgePoolCancelDelay,
     /// List of ERC20 token types whitelisted at genesis time.
     pub erc20_whitelist: Vec<Erc20WhitelistEntry>,
     /// The addresses of the Ethereum contracts that need to be directly known
@@ -888,6 +926,14 @@ pub fn validate_parameters(
             );
         }
     }
+    if !parameters.gov_params.proposal_funds_policy.is_valid() {
+        eprintln!(
+            "The governance proposal funds policy {:?} is invalid: the spam \
+             threshold must be within [0, 1]",
+            parameters.gov_params.proposal_funds_policy
+        );
+        is_valid = false;
+    }
     let Parameters {
         parameters,
         pos_params,
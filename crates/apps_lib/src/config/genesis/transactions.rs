@@ -78,6 +78,9 @@ fn get_tx_args(use_device: bool) -> TxArgs {
         output_folder: None,
         force: false,
         broadcast_only: false,
+        skip_if_applied: false,
+        confirm: false,
+        subscribe_events: false,
         ledger_address: tendermint_rpc::Url::from_str("http://127.0.0.1:26657")
             .unwrap(),
         initialized_account_alias: None,
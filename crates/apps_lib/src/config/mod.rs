@@ -7,6 +7,7 @@ pub mod utils;
 
 use std::fs::{create_dir_all, File};
 use std::io::Write;
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 
 use directories::ProjectDirs;
@@ -35,6 +36,8 @@ pub const FILENAME: &str = "config.toml";
 pub const COMETBFT_DIR: &str = "cometbft";
 /// Chain-specific Namada DB. Nested in chain dirs.
 pub const DB_DIR: &str = "db";
+/// Chain-specific state sync snapshots. Nested in chain dirs.
+pub const SNAPSHOTS_DIR: &str = "snapshots";
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Config {
@@ -86,6 +89,20 @@ pub struct ActionAtHeight {
     pub action: Action,
 }
 
+/// Pruning of the data of old blocks from the DB.
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq,
+)]
+pub enum PruningMode {
+    /// Keep the data of all the blocks.
+    #[default]
+    Archive,
+    /// Keep the data of the given number of most recent blocks.
+    KeepBlocks(u64),
+    /// Keep the data of the given number of most recent epochs.
+    KeepEpochs(u64),
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ledger {
     pub genesis_time: Rfc3339String,
@@ -109,8 +126,16 @@ pub struct Shell {
     /// When not set, defaults to 1/6 of the available memory.
     pub tx_wasm_compilation_cache_bytes: Option<u64>,
     /// When set, will limit the how many block heights in the past can the
-    /// storage be queried for reading values.
+    /// storage be queried for reading values. When not set, the node runs in
+    /// archive mode: it keeps the Merkle tree stores of all the past heights
+    /// and serves proofs of the state at any height.
     pub storage_read_past_height_limit: Option<u64>,
+    /// The number of Merkle trees rebuilt at past heights that are kept in
+    /// memory to serve further proofs at the same heights, which caps the
+    /// memory used to serve proofs on archive nodes. When not set, the trees
+    /// are rebuilt for every proof.
+    #[serde(default)]
+    pub merkle_tree_cache_size: Option<usize>,
     /// Use the [`Ledger::db_dir()`] method to read the value.
     db_dir: PathBuf,
     /// Use the [`Ledger::cometbft_dir()`] method to read the value.
@@ -119,6 +144,29 @@ pub struct Shell {
     pub action_at_height: Option<ActionAtHeight>,
     /// Specify if tendermint is started as validator, fullnode or seednode
     pub tendermint_mode: TendermintMode,
+    /// When set, a state sync snapshot of the DB is taken every given number
+    /// of blocks and offered to new nodes joining the network.
+    pub snapshot_interval: Option<u64>,
+    /// How many of the most recent state sync snapshots to keep on disk.
+    /// When not set, defaults to 2.
+    pub snapshots_to_keep: Option<u64>,
+    /// Pruning of the data of old blocks. The data needed to read the state
+    /// within `storage_read_past_height_limit` is never pruned.
+    #[serde(default)]
+    pub pruning: PruningMode,
+    /// When enabled, the node maintains a local index of the transactions
+    /// touching each address, which can be queried for explorer purposes.
+    #[serde(default)]
+    pub tx_index: bool,
+    /// When set, the node serves its Prometheus metrics at the `/metrics`
+    /// endpoint of this address.
+    #[serde(default)]
+    pub metrics_address: Option<SocketAddr>,
+    /// When set, the node stops processing blocks once the block at this
+    /// height has been committed, as it does at an upgrade height scheduled
+    /// by governance, and writes the upgrade marker to the chain directory.
+    #[serde(default)]
+    pub halt_at_height: Option<BlockHeight>,
 }
 
 impl Ledger {
@@ -143,10 +191,17 @@ impl Ledger {
                 tx_wasm_compilation_cache_bytes: None,
                 // Default corresponds to 1 hour of past blocks at 1 block/sec
                 storage_read_past_height_limit: Some(3600),
+                merkle_tree_cache_size: None,
                 db_dir: DB_DIR.into(),
                 cometbft_dir: COMETBFT_DIR.into(),
                 action_at_height: None,
                 tendermint_mode: mode,
+                snapshot_interval: None,
+                snapshots_to_keep: None,
+                pruning: PruningMode::default(),
+                tx_index: false,
+                metrics_address: None,
+                halt_at_height: None,
             },
             cometbft: tendermint_config,
             ethereum_bridge: ethereum_bridge::ledger::Config::default(),
@@ -181,6 +236,11 @@ impl Shell {
             .join(chain_id.as_str())
             .join(&self.cometbft_dir)
     }
+
+    /// Get the directory path to the state sync snapshots
+    pub fn snapshots_dir(&self, chain_id: &ChainId) -> PathBuf {
+        self.base_dir.join(chain_id.as_str()).join(SNAPSHOTS_DIR)
+    }
 }
 
 #[derive(Error, Debug)]
@@ -0,0 +1,69 @@
+use std::time::{Duration, Instant};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use namada_apps_lib::config::TendermintMode;
+use namada_node::bench_utils::{BenchShell, BenchShieldedCtx};
+
+// Number of txs in the mempool of each produced block
+const TRANSPARENT_BATCH_SIZES: [u64; 3] = [1, 5, 10];
+const SHIELDED_BATCH_SIZES: [u64; 2] = [1, 5];
+
+fn transparent_block(c: &mut Criterion) {
+    let mut group = c.benchmark_group("block_production_transparent");
+    let mut shell = BenchShell::new(TendermintMode::Validator);
+
+    for batch_size in TRANSPARENT_BATCH_SIZES {
+        group.bench_function(format!("{batch_size}_transfers"), |b| {
+            b.iter_custom(|iters| {
+                let mut elapsed = Duration::ZERO;
+                for _ in 0..iters {
+                    // The mempool must be regenerated for every block to
+                    // avoid the replay protection
+                    let mempool = shell.generate_transfer_batch(batch_size);
+                    #[allow(clippy::disallowed_methods)]
+                    let start = Instant::now();
+                    let num_txs = shell.produce_block(mempool);
+                    elapsed += start.elapsed();
+                    assert_eq!(num_txs as u64, batch_size);
+                }
+                elapsed
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn shielded_block(c: &mut Criterion) {
+    let mut group = c.benchmark_group("block_production_shielded");
+    // Generating the MASP proofs is slow, so keep the number of samples low
+    group.sample_size(10);
+    let mut shielded_ctx =
+        Some(BenchShieldedCtx::new(TendermintMode::Validator));
+
+    for batch_size in SHIELDED_BATCH_SIZES {
+        group.bench_function(format!("{batch_size}_shieldings"), |b| {
+            b.iter_custom(|iters| {
+                let mut elapsed = Duration::ZERO;
+                for _ in 0..iters {
+                    let (mut ctx, mempool) = shielded_ctx
+                        .take()
+                        .unwrap()
+                        .generate_shielding_batch(batch_size);
+                    #[allow(clippy::disallowed_methods)]
+                    let start = Instant::now();
+                    let num_txs = ctx.shell.produce_block(mempool);
+                    elapsed += start.elapsed();
+                    shielded_ctx = Some(ctx);
+                    assert_eq!(num_txs as u64, batch_size);
+                }
+                elapsed
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(block_production, transparent_block, shielded_block);
+criterion_main!(block_production);
@@ -21,7 +21,7 @@ use crate::ethereum_events::{
     EthAddress, TransferToEthereum as TransferToEthereumEvent,
 };
 use crate::hash::Hash as HashDigest;
-use crate::storage::{DbKeySeg, Key};
+use crate::storage::{DbKeySeg, Epoch, Key};
 use crate::token::Amount;
 
 /// The main address of the Ethereum bridge pool
@@ -35,6 +35,11 @@ pub struct Segments {
     pub signed_root: &'static str,
     /// Bridge pool nonce storage key
     pub bridge_pool_nonce: &'static str,
+    /// Sub-space of the amounts of ERC20 assets queued in the pool during
+    /// the current epoch
+    pub epoch_utilization: &'static str,
+    /// Sub-space of the gas fees topped up on pending transfers
+    pub fee_top_up: &'static str,
 }
 
 /// Check if a key is for a pending transfer
@@ -65,6 +70,98 @@ pub fn get_key_from_hash(hash: &KeccakHash) -> Key {
     }
 }
 
+/// Get the storage key of the amount of an ERC20 asset queued in the pool
+/// during the current epoch
+pub fn get_epoch_utilization_key(asset: &EthAddress) -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(BRIDGE_POOL_ADDRESS),
+            DbKeySeg::StringSeg(Segments::VALUES.epoch_utilization.into()),
+            DbKeySeg::StringSeg(asset.to_canonical()),
+        ],
+    }
+}
+
+/// Get the storage key of the gas fees topped up on the pending transfer
+/// with the given hash
+pub fn get_fee_top_up_key(hash: &KeccakHash) -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(BRIDGE_POOL_ADDRESS),
+            DbKeySeg::StringSeg(Segments::VALUES.fee_top_up.into()),
+            hash.to_db_key(),
+        ],
+    }
+}
+
+/// The amount of an ERC20 asset queued in the Bridge pool during some
+/// epoch. The amount queued per epoch may be capped by governance.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct EpochUtilization {
+    /// The last epoch in which the asset was queued
+    pub epoch: Epoch,
+    /// The amount queued during `epoch`
+    pub amount: Amount,
+}
+
+impl EpochUtilization {
+    /// Get the amount queued during the given epoch
+    pub fn queued_at(&self, epoch: Epoch) -> Amount {
+        if self.epoch == epoch {
+            self.amount
+        } else {
+            Amount::zero()
+        }
+    }
+
+    /// Queue some amount during the given epoch. Returns [`None`] on
+    /// overflow.
+    pub fn queue(&self, epoch: Epoch, amount: Amount) -> Option<Self> {
+        Some(Self {
+            epoch,
+            amount: self.queued_at(epoch).checked_add(amount)?,
+        })
+    }
+}
+
+/// A request to increase the gas fees of a transfer pending in the
+/// Bridge pool.
+///
+/// The topped up fees are paid in the same token, and by the same
+/// payer, as the original gas fees of the transfer. They are kept
+/// separately from the transfer, such that its hash remains unchanged.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+)]
+pub struct TransferFeeTopUp {
+    /// The keccak hash of the pending transfer.
+    pub transfer: KeccakHash,
+    /// The amount of gas fees to add.
+    pub amount: Amount,
+}
+
 /// A version used in our Ethereuem smart contracts
 const VERSION: u8 = 1;
 
@@ -463,4 +560,35 @@ mod test_eth_bridge_pool_types {
         let event: TransferToEthereumEvent = (&pending).into();
         assert_eq!(pending.keccak256(), event.keccak256());
     }
+
+    /// Test that the utilization of an asset is reset on new epochs.
+    #[test]
+    fn test_epoch_utilization() {
+        let utilization = EpochUtilization::default()
+            .queue(Epoch(1), Amount::from_u64(10))
+            .unwrap()
+            .queue(Epoch(1), Amount::from_u64(5))
+            .unwrap();
+        assert_eq!(utilization.queued_at(Epoch(1)), Amount::from_u64(15));
+        assert_eq!(utilization.queued_at(Epoch(2)), Amount::zero());
+
+        let utilization =
+            utilization.queue(Epoch(2), Amount::from_u64(3)).unwrap();
+        assert_eq!(utilization.queued_at(Epoch(2)), Amount::from_u64(3));
+        assert!(utilization.queue(Epoch(2), Amount::max()).is_none());
+
+        // the utilization keys are not pending transfer keys
+        let key = get_epoch_utilization_key(&EthAddress([0xaa; 20]));
+        assert!(!is_pending_transfer_key(&key));
+    }
+
+    /// Test that the keys of the topped up gas fees are not pending
+    /// transfer keys.
+    #[test]
+    fn test_fee_top_up_key() {
+        let hash = KeccakHash([0xbb; 32]);
+        let key = get_fee_top_up_key(&hash);
+        assert!(!is_pending_transfer_key(&key));
+        assert_ne!(key, get_key_from_hash(&hash));
+    }
 }
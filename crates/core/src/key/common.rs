@@ -12,6 +12,7 @@ use namada_migrations::*;
 use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use zeroize::Zeroizing;
 
 use super::{
     ed25519, secp256k1, ParsePublicKeyError, ParseSecretKeyError,
@@ -248,22 +249,20 @@ impl super::SecretKey for SecretKey {
     fn try_from_sk<SK: super::SecretKey>(
         sk: &SK,
     ) -> Result<Self, ParseSecretKeyError> {
+        // Scrub the serialized secret key once it has been parsed
+        let bytes = Zeroizing::new(sk.serialize_to_vec());
         if SK::TYPE == Self::TYPE {
-            Self::try_from_slice(sk.serialize_to_vec().as_ref())
+            Self::try_from_slice(bytes.as_slice())
                 .map_err(ParseSecretKeyError::InvalidEncoding)
         } else if SK::TYPE == ed25519::SecretKey::TYPE {
             Ok(Self::Ed25519(
-                ed25519::SecretKey::try_from_slice(
-                    sk.serialize_to_vec().as_ref(),
-                )
-                .map_err(ParseSecretKeyError::InvalidEncoding)?,
+                ed25519::SecretKey::try_from_slice(bytes.as_slice())
+                    .map_err(ParseSecretKeyError::InvalidEncoding)?,
             ))
         } else if SK::TYPE == secp256k1::SecretKey::TYPE {
             Ok(Self::Secp256k1(
-                secp256k1::SecretKey::try_from_slice(
-                    sk.serialize_to_vec().as_ref(),
-                )
-                .map_err(ParseSecretKeyError::InvalidEncoding)?,
+                secp256k1::SecretKey::try_from_slice(bytes.as_slice())
+                    .map_err(ParseSecretKeyError::InvalidEncoding)?,
             ))
         } else {
             Err(ParseSecretKeyError::MismatchedScheme)
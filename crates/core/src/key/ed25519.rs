@@ -16,7 +16,7 @@ use namada_migrations::*;
 #[cfg(any(test, feature = "rand"))]
 use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 use super::{
     ParsePublicKeyError, ParseSecretKeyError, ParseSignatureError, RefTo,
@@ -158,7 +158,8 @@ impl super::SecretKey for SecretKey {
                 _ => Err(ParseSecretKeyError::MismatchedScheme),
             })
         } else if PK::TYPE == Self::TYPE {
-            Self::try_from_slice(pk.serialize_to_vec().as_slice())
+            let bytes = Zeroizing::new(pk.serialize_to_vec());
+            Self::try_from_slice(bytes.as_slice())
                 .map_err(ParseSecretKeyError::InvalidEncoding)
         } else {
             Err(ParseSecretKeyError::MismatchedScheme)
@@ -174,9 +175,8 @@ impl RefTo<PublicKey> for SecretKey {
 
 impl Clone for SecretKey {
     fn clone(&self) -> SecretKey {
-        SecretKey(Box::new(ed25519_consensus::SigningKey::from(
-            self.0.to_bytes(),
-        )))
+        let bytes = Zeroizing::new(self.0.to_bytes());
+        SecretKey(Box::new(ed25519_consensus::SigningKey::from(*bytes)))
     }
 }
 
@@ -198,7 +198,7 @@ impl BorshDeserialize for SecretKey {
 
 impl BorshSerialize for SecretKey {
     fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        BorshSerialize::serialize(&self.0.to_bytes(), writer)
+        BorshSerialize::serialize(&*Zeroizing::new(self.0.to_bytes()), writer)
     }
 }
 
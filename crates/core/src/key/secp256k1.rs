@@ -22,6 +22,7 @@ use rand::{CryptoRng, RngCore};
 use serde::de::{Error, SeqAccess, Visitor};
 use serde::ser::SerializeTuple;
 use serde::{Deserialize, Serialize, Serializer};
+use zeroize::Zeroizing;
 
 use super::{
     ParsePublicKeyError, ParseSecretKeyError, ParseSignatureError, RefTo,
@@ -190,7 +191,8 @@ impl super::SecretKey for SecretKey {
                 _ => Err(ParseSecretKeyError::MismatchedScheme),
             })
         } else if PK::TYPE == Self::TYPE {
-            Self::try_from_slice(pk.serialize_to_vec().as_slice())
+            let bytes = Zeroizing::new(pk.serialize_to_vec());
+            Self::try_from_slice(bytes.as_slice())
                 .map_err(ParseSecretKeyError::InvalidEncoding)
         } else {
             Err(ParseSecretKeyError::MismatchedScheme)
@@ -203,8 +205,9 @@ impl Serialize for SecretKey {
     where
         S: Serializer,
     {
-        let arr: [u8; SECRET_KEY_SIZE] = self.0.to_bytes().into();
-        serde::Serialize::serialize(&arr, serializer)
+        let arr: Zeroizing<[u8; SECRET_KEY_SIZE]> =
+            Zeroizing::new(self.0.to_bytes().into());
+        serde::Serialize::serialize(&*arr, serializer)
     }
 }
 
@@ -213,10 +216,10 @@ impl<'de> Deserialize<'de> for SecretKey {
     where
         D: serde::Deserializer<'de>,
     {
-        let arr_res: [u8; SECRET_KEY_SIZE] =
-            serde::Deserialize::deserialize(deserializer)?;
+        let arr_res: Zeroizing<[u8; SECRET_KEY_SIZE]> =
+            Zeroizing::new(serde::Deserialize::deserialize(deserializer)?);
         let key =
-            k256::SecretKey::from_slice(&arr_res).map_err(D::Error::custom);
+            k256::SecretKey::from_slice(&*arr_res).map_err(D::Error::custom);
         Ok(SecretKey(Box::new(key.unwrap())))
     }
 }
@@ -224,9 +227,9 @@ impl<'de> Deserialize<'de> for SecretKey {
 impl BorshDeserialize for SecretKey {
     fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
         // deserialize the bytes first
-        let bytes: [u8; SECRET_KEY_SIZE] =
-            BorshDeserialize::deserialize_reader(reader)?;
-        let sk = k256::SecretKey::from_slice(&bytes).map_err(|e| {
+        let bytes: Zeroizing<[u8; SECRET_KEY_SIZE]> =
+            Zeroizing::new(BorshDeserialize::deserialize_reader(reader)?);
+        let sk = k256::SecretKey::from_slice(&*bytes).map_err(|e| {
             std::io::Error::new(
                 ErrorKind::InvalidInput,
                 format!("Error decoding secp256k1 secret key: {}", e),
@@ -238,8 +241,9 @@ impl BorshDeserialize for SecretKey {
 
 impl BorshSerialize for SecretKey {
     fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        let bytes: [u8; SECRET_KEY_SIZE] = self.0.to_bytes().into();
-        BorshSerialize::serialize(&bytes, writer)
+        let bytes: Zeroizing<[u8; SECRET_KEY_SIZE]> =
+            Zeroizing::new(self.0.to_bytes().into());
+        BorshSerialize::serialize(&*bytes, writer)
     }
 }
 
@@ -468,6 +472,53 @@ impl Signature {
         let v = v + Self::V_FIX;
         (r.to_bytes().into(), s.to_bytes().into(), v)
     }
+
+    /// Parse a signature from its 65 bytes `r || s || v` Ethereum encoding,
+    /// as produced by Ethereum wallets. The `v` parameter may be given
+    /// either in the range [0, 1] or [27, 28].
+    pub fn from_eth_rsv_bytes(
+        sig: &[u8; SIGNATURE_SIZE],
+    ) -> Result<Self, ParseSignatureError> {
+        let mut sig = *sig;
+        if let Some(v) = sig[64].checked_sub(Self::V_FIX) {
+            sig[64] = v;
+        }
+        Self::try_from(&sig)
+    }
+
+    /// Recover the Ethereum address of the key that signed the given
+    /// message with `personal_sign` (i.e. over the [EIP-191] hash of the
+    /// message).
+    ///
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    pub fn recover_eth_signer(
+        &self,
+        message: &[u8],
+    ) -> Result<EthAddress, VerifySigError> {
+        let prehash = eth_personal_message_hash(message);
+        let vrf_key = k256::ecdsa::VerifyingKey::recover_from_prehash(
+            prehash.as_ref(),
+            &self.0,
+            self.1,
+        )
+        .map_err(|e| {
+            VerifySigError::SigVerifyError(format!(
+                "Error recovering secp256k1 signer: {}",
+                e
+            ))
+        })?;
+        let pk = PublicKey(k256::PublicKey::from(&vrf_key));
+        Ok((&pk).into())
+    }
+}
+
+/// Hash a message the way Ethereum wallets do before signing it with
+/// `personal_sign`
+fn eth_personal_message_hash(message: &[u8]) -> crate::keccak::KeccakHash {
+    let mut eth_message =
+        format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    eth_message.extend_from_slice(message);
+    crate::keccak::keccak_hash(eth_message)
 }
 
 impl Encode<1> for Signature {
@@ -628,6 +679,37 @@ mod test {
         assert_eq!(expected_eth_addr_hex, eth_addr_hex);
     }
 
+    /// Test that we can recover the Ethereum address of the signer of a
+    /// `personal_sign` message.
+    #[test]
+    fn test_recover_eth_signer() {
+        let sk_bytes = HEXLOWER.decode(SECRET_KEY_HEX.as_bytes()).unwrap();
+        let sk = SecretKey::try_from_slice(&sk_bytes[..]).unwrap();
+        let pk: PublicKey = sk.ref_to();
+        let expected_eth_addr: EthAddress = (&pk).into();
+
+        let message = b"authorize namada action";
+        let prehash = eth_personal_message_hash(message);
+        let (sig, recovery_id) = k256::ecdsa::SigningKey::from(sk.0.as_ref())
+            .sign_prehash_recoverable(prehash.as_ref())
+            .unwrap();
+
+        // Encode the signature as an Ethereum wallet would
+        let mut sig_bytes = [0u8; SIGNATURE_SIZE];
+        sig_bytes[..64].copy_from_slice(&sig.to_bytes());
+        sig_bytes[64] = if recovery_id.is_y_odd() { 28 } else { 27 };
+        let signature = Signature::from_eth_rsv_bytes(&sig_bytes).unwrap();
+
+        assert_eq!(
+            signature.recover_eth_signer(message).unwrap(),
+            expected_eth_addr
+        );
+        assert_ne!(
+            signature.recover_eth_signer(b"another message").ok(),
+            Some(expected_eth_addr)
+        );
+    }
+
     /// Test serializing and then de-serializing a signature
     /// with Serde is idempotent.
     #[test]
@@ -12,6 +12,7 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
 
 use crate::address::{Address, DecodeError, HASH_HEX_LEN, MASP};
 use crate::impl_display_and_from_str_via_format;
@@ -298,7 +299,13 @@ impl<'de> serde::Deserialize<'de> for PaymentAddress {
     }
 }
 
-/// Wrapper for masp_primitive's ExtendedSpendingKey
+/// Wrapper for masp_primitive's ExtendedSpendingKey.
+///
+/// Note that, unlike the other secret keys, spending keys are not zeroized
+/// once dropped: the MASP key is `Copy`, does not implement `Zeroize` and its
+/// fields are private, so copies of it may remain in memory. Only the
+/// buffers it is encoded to are scrubbed, and the wallet keeps its decrypted
+/// spending keys as such buffers.
 #[derive(
     Clone, Debug, Copy, BorshSerialize, BorshDeserialize, BorshDeserializer,
 )]
@@ -310,7 +317,7 @@ impl string_encoding::Format for ExtendedSpendingKey {
     const HRP: &'static str = MASP_EXT_SPENDING_KEY_HRP;
 
     fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = [0; 169];
+        let mut bytes = Zeroizing::new([0; 169]);
         self.0
             .write(&mut &mut bytes[..])
             .expect("should be able to serialize an ExtendedSpendingKey");
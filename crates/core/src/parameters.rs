@@ -5,14 +5,20 @@ use std::collections::BTreeMap;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
+use serde::{Deserialize, Serialize};
 
 use super::address::Address;
 use super::chain::ProposalBytes;
+use super::dec::Dec;
 use super::hash::Hash;
-use super::time::DurationSecs;
+use super::storage::BlockHeight;
+use super::time::{DateTimeUtc, DurationSecs};
 use super::token;
 use crate::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 
+/// The default max size, in bytes, of the wasm code embedded in a tx.
+pub const DEFAULT_MAX_CODE_BYTES: u32 = 1024 * 1024;
+
 /// Protocol parameters
 #[derive(
     Clone,
@@ -54,6 +60,30 @@ pub struct Parameters {
     pub minimum_gas_price: BTreeMap<Address, token::Amount>,
     /// Enable the native token transfer if it is true
     pub is_native_token_transferable: bool,
+    /// The conditions under which a new epoch begins (read only)
+    pub epoch_switch_policy: EpochSwitchPolicy,
+    /// Max size, in bytes, of the wasm code embedded in a tx.
+    pub max_code_bytes: u32,
+}
+
+/// Tx data of an update of the fee conversion rates by the fee price feed
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct UpdateFeeConversionRates {
+    /// The address of the fee price feed, which must authorize the update
+    pub price_feed: Address,
+    /// The new conversion rates of the updated fee tokens
+    pub rates: BTreeMap<Address, Dec>,
 }
 
 /// Epoch duration. A new epoch begins as soon as both the `min_num_of_blocks`
@@ -77,3 +107,53 @@ pub struct EpochDuration {
     /// Minimum duration of an epoch
     pub min_duration: DurationSecs,
 }
+
+/// The conditions under which a new epoch begins, with respect to the
+/// [`EpochDuration`] parameter.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub enum EpochSwitchPolicy {
+    /// A new epoch begins once both the minimum number of blocks and the
+    /// minimum duration of the current epoch have passed.
+    #[default]
+    BlocksAndDuration,
+    /// A new epoch begins once the minimum number of blocks of the current
+    /// epoch have passed, regardless of its duration. This yields
+    /// deterministic epochs, e.g. for test networks.
+    Blocks,
+}
+
+impl EpochSwitchPolicy {
+    /// Check if the current epoch may end at the given block height and time,
+    /// given the minimum height and time at which the next epoch may start
+    pub fn is_epoch_over(
+        &self,
+        height: BlockHeight,
+        time: DateTimeUtc,
+        next_epoch_min_start_height: BlockHeight,
+        next_epoch_min_start_time: DateTimeUtc,
+    ) -> bool {
+        match self {
+            Self::BlocksAndDuration => {
+                height >= next_epoch_min_start_height
+                    && time >= next_epoch_min_start_time
+            }
+            Self::Blocks => height >= next_epoch_min_start_height,
+        }
+    }
+}
@@ -113,8 +113,12 @@ pub const ROLLBACK_CF: &str = "rollback";
 pub const STATE_CF: &str = "state";
 /// Block column family name
 pub const BLOCK_CF: &str = "block";
+/// Merkle tree stores column family name
+pub const MERKLE_TREE_CF: &str = "merkle_tree";
 /// Replay protection column family name
 pub const REPLAY_PROTECTION_CF: &str = "replay_protection";
+/// Transaction index column family name
+pub const TX_INDEX_CF: &str = "tx_index";
 
 impl DbColFam {
     /// Get the name of the column family
@@ -1,15 +1,20 @@
 //! Configuration for an oracle.
 use std::num::NonZeroU64;
 
-use namada_core::ethereum_events::EthAddress;
+use namada_core::ethereum_events::{EthAddress, EthereumEvent};
 use namada_core::ethereum_structs;
 
+use crate::storage::parameters::EventConfirmations;
+
 /// Configuration for an oracle.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Config {
     /// The minimum number of block confirmations an Ethereum block must have
     /// before it will be checked for bridge events.
     pub min_confirmations: NonZeroU64,
+    /// The minimum number of block confirmations of specific types of
+    /// Ethereum events, overriding `min_confirmations`.
+    pub event_confirmations: EventConfirmations,
     /// The Ethereum address of the current bridge contract.
     pub bridge_contract: EthAddress,
     /// The earliest Ethereum block from which events may be processed.
@@ -18,6 +23,25 @@ pub struct Config {
     pub active: bool,
 }
 
+impl Config {
+    /// The minimum number of block confirmations the given Ethereum event
+    /// must have before it may be acted on.
+    pub fn min_confirmations_for(&self, event: &EthereumEvent) -> NonZeroU64 {
+        self.event_confirmations
+            .get(event)
+            .map_or(self.min_confirmations, NonZeroU64::from)
+    }
+
+    /// The lowest number of block confirmations any Ethereum event
+    /// must have before it may be acted on.
+    pub fn lowest_min_confirmations(&self) -> NonZeroU64 {
+        self.event_confirmations
+            .iter()
+            .map(NonZeroU64::from)
+            .fold(self.min_confirmations, std::cmp::min)
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
 impl std::default::Default for Config {
     fn default() -> Self {
@@ -25,6 +49,7 @@ impl std::default::Default for Config {
             // SAFETY: we must always call NonZeroU64::new_unchecked here with a
             // value that is >= 1
             min_confirmations: unsafe { NonZeroU64::new_unchecked(100) },
+            event_confirmations: EventConfirmations::default(),
             bridge_contract: EthAddress([0; 20]),
             start_block: 0.into(),
             active: true,
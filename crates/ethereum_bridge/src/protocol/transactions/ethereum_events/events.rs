@@ -28,7 +28,8 @@ use token::{burn_tokens, decrement_total_supply, increment_total_supply};
 
 use crate::event::EthBridgeEvent;
 use crate::storage::bridge_pool::{
-    get_nonce_key, is_pending_transfer_key, BRIDGE_POOL_ADDRESS,
+    get_fee_top_up_key, get_nonce_key, is_pending_transfer_key,
+    BRIDGE_POOL_ADDRESS,
 };
 use crate::storage::eth_bridge_queries::{EthAssetMint, EthBridgeQueries};
 use crate::storage::parameters::read_native_erc20_address;
@@ -274,7 +275,12 @@ where
             (pending, key)
         } else {
             hints::cold();
-            unreachable!("The transfer should exist in the bridge pool");
+            tracing::error!(
+                ?event,
+                "Relayed transfer to Ethereum not found in the Bridge pool, \
+                 skipping it"
+            );
+            continue;
         };
         tracing::debug!(
             ?pending_transfer,
@@ -289,14 +295,16 @@ where
             balance_key(&pending_transfer.gas_fee.token, &BRIDGE_POOL_ADDRESS);
         let relayer_rewards_key =
             balance_key(&pending_transfer.gas_fee.token, relayer);
-        // give the relayer the gas fee for this transfer and remove it from
-        // escrow.
+        // give the relayer the gas fee for this transfer, including any
+        // topped up fees, and remove it from escrow.
+        let gas_fee =
+            take_gas_fee(state, &pending_transfer, &mut changed_keys)?;
         token::transfer(
             state,
             &pending_transfer.gas_fee.token,
             &BRIDGE_POOL_ADDRESS,
             relayer,
-            pending_transfer.gas_fee.amount,
+            gas_fee,
         )?;
 
         state.delete(&key)?;
@@ -407,12 +415,13 @@ where
     let pool_balance_key =
         balance_key(&transfer.gas_fee.token, &BRIDGE_POOL_ADDRESS);
 
+    let gas_fee = take_gas_fee(state, transfer, &mut changed_keys)?;
     token::transfer(
         state,
         &transfer.gas_fee.token,
         &BRIDGE_POOL_ADDRESS,
         &transfer.gas_fee.payer,
-        transfer.gas_fee.amount,
+        gas_fee,
     )?;
 
     tracing::debug!(?transfer, "Refunded Bridge pool transfer fees");
@@ -421,6 +430,30 @@ where
     Ok(changed_keys)
 }
 
+/// Get the total gas fees escrowed for a pending transfer, including
+/// any topped up fees, which are removed from storage.
+fn take_gas_fee<D, H>(
+    state: &mut WlState<D, H>,
+    transfer: &PendingTransfer,
+    changed_keys: &mut BTreeSet<Key>,
+) -> Result<token::Amount>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let fee_top_up_key = get_fee_top_up_key(&transfer.keccak256());
+    let Some(fee_top_up) = state.read::<token::Amount>(&fee_top_up_key)? else {
+        return Ok(transfer.gas_fee.amount);
+    };
+    state.delete(&fee_top_up_key)?;
+    _ = changed_keys.insert(fee_top_up_key);
+    transfer
+        .gas_fee
+        .amount
+        .checked_add(fee_top_up)
+        .ok_or_else(|| eyre::eyre!("Overflow in the gas fees of a transfer"))
+}
+
 fn refund_transferred_assets<D, H>(
     state: &mut WlState<D, H>,
     transfer: &PendingTransfer,
@@ -1030,6 +1063,104 @@ mod tests {
         assert_eq!(bp_erc_balance_post, Amount::from(0));
     }
 
+    #[test]
+    /// Test that the gas fees topped up on pending transfers are paid
+    /// to the relayer of a transfer, or refunded if a transfer expires.
+    fn test_act_on_transfers_to_eth_with_fee_top_ups() {
+        let mut state = TestState::default();
+        test_utils::bootstrap_ethereum_bridge(&mut state);
+        state.commit_block().expect("Test failed");
+        init_storage(&mut state);
+        let pending_transfers = init_bridge_pool(&mut state);
+        init_balance(&mut state, &pending_transfers);
+        // top up the gas fees of both transfers
+        for transfer in &pending_transfers {
+            state
+                .write(
+                    &get_fee_top_up_key(&transfer.keccak256()),
+                    Amount::from(4),
+                )
+                .expect("Test failed");
+            increment_balance(
+                &mut state,
+                &nam(),
+                &BRIDGE_POOL_ADDRESS,
+                Amount::from(4),
+            )
+            .expect("Test failed");
+        }
+        state.commit_block().expect("Test failed");
+        // the second transfer times out
+        state.in_mem_mut().block.height += 10 + 1;
+
+        let relayer = gen_established_address("random");
+        let relayed = &pending_transfers[0];
+        let expired = &pending_transfers[1];
+        let event = EthereumEvent::TransfersToEthereum {
+            nonce: arbitrary_nonce(),
+            transfers: vec![relayed.into()],
+            relayer: relayer.clone(),
+        };
+        let (changed_keys, _) = act_on(&mut state, event).unwrap();
+
+        for transfer in &pending_transfers {
+            let key = get_fee_top_up_key(&transfer.keccak256());
+            assert!(changed_keys.contains(&key));
+            assert!(!state.has_key(&key).expect("Test failed"));
+        }
+        let relayer_balance: Amount = state
+            .read(&balance_key(&nam(), &relayer))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(relayer_balance, relayed.gas_fee.amount + Amount::from(4));
+        let payer_balance: Amount = state
+            .read(&balance_key(&nam(), &expired.gas_fee.payer))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(payer_balance, expired.gas_fee.amount + Amount::from(4));
+        let pool_balance: Amount = state
+            .read(&balance_key(&nam(), &BRIDGE_POOL_ADDRESS))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(pool_balance, Amount::zero());
+    }
+
+    #[test]
+    /// Test that relayed transfers which are no longer in the Bridge
+    /// pool are skipped, instead of halting the chain.
+    fn test_act_on_missing_transfers_to_eth() {
+        let mut state = TestState::default();
+        test_utils::bootstrap_ethereum_bridge(&mut state);
+        state.commit_block().expect("Test failed");
+        init_storage(&mut state);
+        let pending_transfers = init_bridge_pool(&mut state);
+        init_balance(&mut state, &pending_transfers);
+        let relayed = &pending_transfers[0];
+        let mut missing = relayed.clone();
+        missing.transfer.recipient = EthAddress([0xab; 20]);
+
+        let relayer = gen_established_address("random");
+        let event = EthereumEvent::TransfersToEthereum {
+            nonce: arbitrary_nonce(),
+            transfers: vec![(&missing).into(), relayed.into()],
+            relayer: relayer.clone(),
+        };
+        let (changed_keys, _) = act_on(&mut state, event).unwrap();
+
+        assert!(changed_keys.contains(&get_nonce_key()));
+        assert!(changed_keys.contains(&get_pending_key(relayed)));
+        assert!(
+            !state
+                .has_key(&get_pending_key(relayed))
+                .expect("Test failed")
+        );
+        let relayer_balance: Amount = state
+            .read(&balance_key(&nam(), &relayer))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(relayer_balance, relayed.gas_fee.amount);
+    }
+
     #[test]
     /// Test that the transfers time out in the bridge pool then the refund when
     /// we act on a TransfersToEthereum
@@ -478,6 +478,60 @@ where
             .expect("Reading from storage should not fail")
     }
 
+    /// Fetch the maximum amount of the asset associated with the given
+    /// [`EthAddress`] that can be queued in the Bridge pool per epoch.
+    ///
+    /// If the asset is not capped per epoch, return [`None`].
+    pub fn get_token_epoch_cap(
+        self,
+        &token: &EthAddress,
+    ) -> Option<token::Amount> {
+        let key = whitelist::Key {
+            asset: token,
+            suffix: whitelist::KeyType::EpochCap,
+        }
+        .into();
+
+        self.state
+            .read(&key)
+            .expect("Reading from storage should not fail")
+    }
+
+    /// Fetch the ticker symbol of the asset associated with the given
+    /// [`EthAddress`].
+    ///
+    /// If no symbol has been registered for the asset, return [`None`].
+    pub fn get_token_symbol(self, &token: &EthAddress) -> Option<String> {
+        let key = whitelist::Key {
+            asset: token,
+            suffix: whitelist::KeyType::Symbol,
+        }
+        .into();
+
+        self.state
+            .read(&key)
+            .expect("Reading from storage should not fail")
+    }
+
+    /// Fetch the denomination of the asset associated with the given
+    /// [`EthAddress`].
+    ///
+    /// If the asset has never been whitelisted, return [`None`].
+    pub fn get_token_denom(
+        self,
+        &token: &EthAddress,
+    ) -> Option<token::Denomination> {
+        let key = whitelist::Key {
+            asset: token,
+            suffix: whitelist::KeyType::Denomination,
+        }
+        .into();
+
+        self.state
+            .read(&key)
+            .expect("Reading from storage should not fail")
+    }
+
     /// Fetch the token supply of the asset associated with the given
     /// [`EthAddress`].
     ///
@@ -564,6 +618,15 @@ where
             .zip(Some(pending_key))
     }
 
+    /// Get the gas fees topped up on the pending transfer with the
+    /// given hash.
+    pub fn get_transfer_fee_top_up(self, hash: &KeccakHash) -> token::Amount {
+        self.state
+            .read(&bridge_pool::get_fee_top_up_key(hash))
+            .expect("Reading from storage should not fail")
+            .unwrap_or_default()
+    }
+
     /// Valdidate an [`EthereumEvent`]'s nonce against the current
     /// state of the ledger.
     ///
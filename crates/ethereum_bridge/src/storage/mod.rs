@@ -61,11 +61,35 @@ pub fn min_confirmations_key() -> Key {
     get_min_confirmations_key_at_addr(PARAM_ADDRESS)
 }
 
+/// Storage key for the minimum confirmations of specific types of Ethereum
+/// events.
+pub fn event_confirmations_key() -> Key {
+    get_event_confirmations_key_at_addr(PARAM_ADDRESS)
+}
+
 /// Storage key for the Ethereum address of the bridge contract.
 pub fn bridge_contract_key() -> Key {
     get_bridge_contract_address_key_at_addr(PARAM_ADDRESS)
 }
 
+/// Storage key for the Bridge pool transfer cancellation delay parameter.
+pub fn bridge_pool_cancel_delay_key() -> Key {
+    get_bridge_pool_cancel_delay_key_at_addr(PARAM_ADDRESS)
+}
+
+/// Check if the given `key` holds a parameter that the Ethereum oracle is
+/// configured with.
+pub fn is_oracle_config_key(key: &Key) -> bool {
+    [
+        active_key(),
+        min_confirmations_key(),
+        event_confirmations_key(),
+        bridge_contract_key(),
+        eth_start_height_key(),
+    ]
+    .contains(key)
+}
+
 #[cfg(test)]
 mod test {
     use namada_core::address;
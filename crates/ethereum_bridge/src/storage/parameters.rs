@@ -2,7 +2,7 @@
 use std::num::NonZeroU64;
 
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
-use namada_core::ethereum_events::EthAddress;
+use namada_core::ethereum_events::{EthAddress, EthereumEvent};
 use namada_core::ethereum_structs;
 use namada_core::storage::Key;
 use namada_core::token::{DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
@@ -23,7 +23,6 @@ use crate::storage::vp;
 /// An ERC20 token whitelist entry.
 #[derive(
     Clone,
-    Copy,
     Eq,
     PartialEq,
     Debug,
@@ -38,6 +37,14 @@ pub struct Erc20WhitelistEntry {
     pub token_address: EthAddress,
     /// The token cap of the whitelisted ERC20 token.
     pub token_cap: DenominatedAmount,
+    /// The maximum amount of the ERC20 token that can be queued in the
+    /// Bridge pool per epoch. Uncapped if not set.
+    #[serde(default)]
+    pub epoch_cap: Option<DenominatedAmount>,
+    /// The ticker symbol of the ERC20 token, displayed by clients in place
+    /// of the address of its wrapped token in Namada.
+    #[serde(default)]
+    pub symbol: Option<String>,
 }
 
 /// Represents a configuration value for the minimum number of
@@ -47,6 +54,9 @@ pub struct Erc20WhitelistEntry {
     Copy,
     Eq,
     PartialEq,
+    Hash,
+    Ord,
+    PartialOrd,
     Debug,
     Deserialize,
     Serialize,
@@ -77,6 +87,99 @@ impl From<MinimumConfirmations> for NonZeroU64 {
     }
 }
 
+/// Overrides of the minimum number of confirmations for specific types of
+/// Ethereum events. The types of events without an override require the
+/// global [`MinimumConfirmations`].
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Ord,
+    PartialOrd,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub struct EventConfirmations {
+    /// Minimum confirmations of transfers from Ethereum to Namada.
+    #[serde(default)]
+    pub transfers_to_namada: Option<MinimumConfirmations>,
+    /// Minimum confirmations of transfers from Namada to Ethereum.
+    #[serde(default)]
+    pub transfers_to_ethereum: Option<MinimumConfirmations>,
+    /// Minimum confirmations of validator set updates.
+    #[serde(default)]
+    pub validator_set_update: Option<MinimumConfirmations>,
+}
+
+impl EventConfirmations {
+    /// Get the override of the minimum confirmations of the given type of
+    /// Ethereum event, if any.
+    pub fn get(&self, event: &EthereumEvent) -> Option<MinimumConfirmations> {
+        match event {
+            EthereumEvent::TransfersToNamada { .. } => self.transfers_to_namada,
+            EthereumEvent::TransfersToEthereum { .. } => {
+                self.transfers_to_ethereum
+            }
+            EthereumEvent::ValidatorSetUpdate { .. } => {
+                self.validator_set_update
+            }
+        }
+    }
+
+    /// Iterate over all the overrides that have been set.
+    pub fn iter(&self) -> impl Iterator<Item = MinimumConfirmations> {
+        [
+            self.transfers_to_namada,
+            self.transfers_to_ethereum,
+            self.validator_set_update,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// Represents a configuration value for the number of blocks a transfer
+/// must remain in the Bridge pool before its sender may cancel it.
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+#[repr(transparent)]
+pub struct BridgePoolCancelDelay(u64);
+
+impl Default for BridgePoolCancelDelay {
+    fn default() -> Self {
+        Self(100)
+    }
+}
+
+impl From<u64> for BridgePoolCancelDelay {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<BridgePoolCancelDelay> for u64 {
+    fn from(value: BridgePoolCancelDelay) -> Self {
+        value.0
+    }
+}
+
 /// Represents a configuration value for the version of a contract that can be
 /// upgraded. Starts from 1.
 #[derive(
@@ -103,6 +206,18 @@ impl Default for ContractVersion {
     }
 }
 
+impl From<NonZeroU64> for ContractVersion {
+    fn from(value: NonZeroU64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ContractVersion> for NonZeroU64 {
+    fn from(value: ContractVersion) -> Self {
+        value.0
+    }
+}
+
 /// Represents an Ethereum contract that may be upgraded.
 #[derive(
     Copy,
@@ -163,6 +278,14 @@ pub struct EthereumBridgeParams {
     /// Minimum number of confirmations needed to trust an Ethereum branch.
     /// This must be at least one.
     pub min_confirmations: MinimumConfirmations,
+    /// Minimum number of confirmations of specific types of Ethereum
+    /// events, overriding `min_confirmations`.
+    #[serde(default)]
+    pub event_confirmations: EventConfirmations,
+    /// Number of blocks after which the sender of a Bridge pool
+    /// transfer may cancel it, and get refunded.
+    #[serde(default)]
+    pub bridge_pool_cancel_delay: BridgePoolCancelDelay,
     /// List of ERC20 token types whitelisted at genesis time.
     pub erc20_whitelist: Vec<Erc20WhitelistEntry>,
     /// The addresses of the Ethereum contracts that need to be directly known
@@ -184,6 +307,8 @@ impl EthereumBridgeParams {
             erc20_whitelist,
             eth_start_height,
             min_confirmations,
+            event_confirmations,
+            bridge_pool_cancel_delay,
             contracts:
                 Contracts {
                     native_erc20,
@@ -192,6 +317,9 @@ impl EthereumBridgeParams {
         } = self;
         let active_key = bridge_storage::active_key();
         let min_confirmations_key = bridge_storage::min_confirmations_key();
+        let event_confirmations_key = bridge_storage::event_confirmations_key();
+        let bridge_pool_cancel_delay_key =
+            bridge_storage::bridge_pool_cancel_delay_key();
         let native_erc20_key = bridge_storage::native_erc20_key();
         let bridge_contract_key = bridge_storage::bridge_contract_key();
         let eth_start_height_key = bridge_storage::eth_start_height_key();
@@ -204,6 +332,12 @@ impl EthereumBridgeParams {
         state
             .write(&min_confirmations_key, min_confirmations)
             .unwrap();
+        state
+            .write(&event_confirmations_key, event_confirmations)
+            .unwrap();
+        state
+            .write(&bridge_pool_cancel_delay_key, bridge_pool_cancel_delay)
+            .unwrap();
         state.write(&native_erc20_key, native_erc20).unwrap();
         state.write(&bridge_contract_key, bridge).unwrap();
         state
@@ -212,6 +346,8 @@ impl EthereumBridgeParams {
         for Erc20WhitelistEntry {
             token_address: addr,
             token_cap,
+            epoch_cap,
+            symbol,
         } in erc20_whitelist
         {
             let cap = token_cap.amount();
@@ -244,6 +380,24 @@ impl EthereumBridgeParams {
             }
             .into();
             state.write(&key, denom).unwrap();
+
+            if let Some(epoch_cap) = epoch_cap {
+                let key = whitelist::Key {
+                    asset: *addr,
+                    suffix: whitelist::KeyType::EpochCap,
+                }
+                .into();
+                state.write(&key, epoch_cap.amount()).unwrap();
+            }
+
+            if let Some(symbol) = symbol {
+                let key = whitelist::Key {
+                    asset: *addr,
+                    suffix: whitelist::KeyType::Symbol,
+                }
+                .into();
+                state.write(&key, symbol).unwrap();
+            }
         }
         // Initialize the storage for the Ethereum Bridge VP.
         vp::ethereum_bridge::init_storage(state);
@@ -261,6 +415,9 @@ pub struct EthereumOracleConfig {
     /// Minimum number of confirmations needed to trust an Ethereum branch.
     /// This must be at least one.
     pub min_confirmations: MinimumConfirmations,
+    /// Minimum number of confirmations of specific types of Ethereum
+    /// events, overriding `min_confirmations`.
+    pub event_confirmations: EventConfirmations,
     /// The addresses of the Ethereum contracts that need to be directly known
     /// by validators.
     pub contracts: Contracts,
@@ -271,12 +428,14 @@ impl From<EthereumBridgeParams> for EthereumOracleConfig {
         let EthereumBridgeParams {
             eth_start_height,
             min_confirmations,
+            event_confirmations,
             contracts,
             ..
         } = config;
         Self {
             eth_start_height,
             min_confirmations,
+            event_confirmations,
             contracts,
         }
     }
@@ -313,10 +472,15 @@ impl EthereumOracleConfig {
         let native_erc20 = must_read_key(state, &native_erc20_key);
         let bridge_contract = must_read_key(state, &bridge_contract_key);
         let eth_start_height = must_read_key(state, &eth_start_height_key);
+        let event_confirmations = read_event_confirmations(state)
+            .unwrap_or_else(|err| {
+                panic!("Could not read the event confirmations: {err:?}")
+            });
 
         Some(Self {
             eth_start_height,
             min_confirmations,
+            event_confirmations,
             contracts: Contracts {
                 native_erc20,
                 bridge: bridge_contract,
@@ -337,6 +501,33 @@ where
     })
 }
 
+/// Get the Bridge pool transfer cancellation delay from storage.
+///
+/// Chains which were initialized before this parameter existed
+/// fall back to its default value.
+pub fn read_bridge_pool_cancel_delay<S>(
+    storage: &S,
+) -> Result<BridgePoolCancelDelay>
+where
+    S: StorageRead,
+{
+    let key = bridge_storage::bridge_pool_cancel_delay_key();
+    Ok(storage.read(&key)?.unwrap_or_default())
+}
+
+/// Get the overrides of the minimum confirmations of specific types of
+/// Ethereum events from storage.
+///
+/// Chains which were initialized before this parameter existed
+/// fall back to its default value, i.e. no overrides.
+pub fn read_event_confirmations<S>(storage: &S) -> Result<EventConfirmations>
+where
+    S: StorageRead,
+{
+    let key = bridge_storage::event_confirmations_key();
+    Ok(storage.read(&key)?.unwrap_or_default())
+}
+
 /// Reads the value of `key` from `storage` and deserializes it, or panics
 /// otherwise.
 fn must_read_key<D, H, T: BorshDeserialize>(
@@ -377,6 +568,8 @@ mod tests {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: MinimumConfirmations::default(),
+            event_confirmations: EventConfirmations::default(),
+            bridge_pool_cancel_delay: BridgePoolCancelDelay::default(),
             contracts: Contracts {
                 native_erc20: EthAddress([42; 20]),
                 bridge: UpgradeableContract {
@@ -406,6 +599,8 @@ mod tests {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: MinimumConfirmations::default(),
+            event_confirmations: EventConfirmations::default(),
+            bridge_pool_cancel_delay: BridgePoolCancelDelay::default(),
             contracts: Contracts {
                 native_erc20: EthAddress([42; 20]),
                 bridge: UpgradeableContract {
@@ -439,6 +634,8 @@ mod tests {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: MinimumConfirmations::default(),
+            event_confirmations: EventConfirmations::default(),
+            bridge_pool_cancel_delay: BridgePoolCancelDelay::default(),
             contracts: Contracts {
                 native_erc20: EthAddress([42; 20]),
                 bridge: UpgradeableContract {
@@ -29,6 +29,11 @@ mod segments {
         pub whitelisted: &'static str,
         /// The token cap of an ERC20 asset.
         pub cap: &'static str,
+        /// The maximum amount of an ERC20 asset that can be queued in the
+        /// Bridge pool per epoch.
+        pub epoch_cap: &'static str,
+        /// The ticker symbol of an ERC20 asset.
+        pub symbol: &'static str,
     }
 
     /// All the values of the generated [`Segments`].
@@ -45,11 +50,16 @@ pub enum KeyType {
     Whitelisted,
     /// The token cap of an ERC20 asset.
     Cap,
+    /// The maximum amount of an ERC20 asset that can be queued in the
+    /// Bridge pool per epoch.
+    EpochCap,
     /// The current supply of a wrapped ERC20 asset,
     /// circulating in Namada.
     WrappedSupply,
     /// The denomination of the ERC20 asset.
     Denomination,
+    /// The ticker symbol of the ERC20 asset.
+    Symbol,
 }
 
 /// Whitelisted ERC20 token storage sub-space.
@@ -85,6 +95,12 @@ impl From<&Key> for storage::Key {
             KeyType::Cap => whitelist_prefix(&key.asset)
                 .push(&segments::VALUES.cap.to_owned())
                 .expect("Should be able to push a storage key segment"),
+            KeyType::EpochCap => whitelist_prefix(&key.asset)
+                .push(&segments::VALUES.epoch_cap.to_owned())
+                .expect("Should be able to push a storage key segment"),
+            KeyType::Symbol => whitelist_prefix(&key.asset)
+                .push(&segments::VALUES.symbol.to_owned())
+                .expect("Should be able to push a storage key segment"),
             KeyType::WrappedSupply => {
                 let token = erc20_token_address(&key.asset);
                 minted_balance_key(&token)
@@ -98,7 +114,8 @@ impl From<&Key> for storage::Key {
 }
 
 /// Check if some [`storage::Key`] is an Ethereum bridge whitelist key
-/// of type [`KeyType::Cap`] or [`KeyType::Whitelisted`].
+/// of type [`KeyType::Cap`], [`KeyType::EpochCap`], [`KeyType::Symbol`]
+/// or [`KeyType::Whitelisted`].
 pub fn is_cap_or_whitelisted_key(key: &storage::Key) -> bool {
     match &key.segments[..] {
         [
@@ -153,6 +170,20 @@ mod tests {
         .into();
         assert!(is_cap_or_whitelisted_key(&cap_key));
 
+        let epoch_cap_key: storage::Key = Key {
+            asset: DAI_ERC20_ETH_ADDRESS,
+            suffix: KeyType::EpochCap,
+        }
+        .into();
+        assert!(is_cap_or_whitelisted_key(&epoch_cap_key));
+
+        let symbol_key: storage::Key = Key {
+            asset: DAI_ERC20_ETH_ADDRESS,
+            suffix: KeyType::Symbol,
+        }
+        .into();
+        assert!(is_cap_or_whitelisted_key(&symbol_key));
+
         let unexpected_key = {
             let mut k: storage::Key = Key {
                 asset: DAI_ERC20_ETH_ADDRESS,
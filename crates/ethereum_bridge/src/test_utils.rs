@@ -115,6 +115,8 @@ pub fn bootstrap_ethereum_bridge(
             // of this type using 0 as argument.
             NonZeroU64::new_unchecked(10)
         }),
+        event_confirmations: Default::default(),
+        bridge_pool_cancel_delay: Default::default(),
         contracts: Contracts {
             native_erc20: wnam(),
             bridge: UpgradeableContract {
@@ -802,4 +802,55 @@ mod event_composition_tests {
 
         assert!(found_info && !found_log);
     }
+
+    /// Test that events survive a round trip through their ABCI encoding,
+    /// including events whose domain is encoded in the attributes.
+    #[test]
+    fn test_event_abci_roundtrip() {
+        let plain_event: Event = DummyEvent.with(Height(300.into())).into();
+        let mut domain_event: Event = DummyEvent.with(Log("ibc".into())).into();
+        domain_event.extend(event_domain_of::<DummyEvent>());
+
+        for event in [plain_event, domain_event] {
+            let abci_event =
+                namada_core::tendermint::abci::Event::from(event.clone());
+            assert!(
+                abci_event
+                    .attributes
+                    .iter_attributes()
+                    .any(|(key, value)| key == EVENT_SCHEMA_VERSION_KEY
+                        && value == EVENT_SCHEMA_VERSION.to_string())
+            );
+            assert_eq!(Event::try_from(abci_event).unwrap(), event);
+        }
+    }
+
+    /// Test decoding ABCI events with and without a schema version.
+    #[test]
+    fn test_event_abci_schema_versions() {
+        let event: Event = DummyEvent.into();
+
+        // events emitted before schema versions were introduced
+        let mut legacy =
+            namada_core::tendermint::abci::Event::from(event.clone());
+        legacy
+            .attributes
+            .retain(|attr| attr.key_str().unwrap() != EVENT_SCHEMA_VERSION_KEY);
+        assert_eq!(Event::try_from(legacy).unwrap(), event);
+
+        // events from a newer, unknown schema
+        let mut newer = namada_core::tendermint::abci::Event::from(event);
+        newer
+            .attributes
+            .retain(|attr| attr.key_str().unwrap() != EVENT_SCHEMA_VERSION_KEY);
+        let next_version = EVENT_SCHEMA_VERSION + 1;
+        newer.attributes.insert_attribute(
+            EVENT_SCHEMA_VERSION_KEY,
+            next_version.to_string(),
+        );
+        assert!(matches!(
+            Event::try_from(newer),
+            Err(EventError::UnsupportedSchemaVersion(v)) if v == next_version
+        ));
+    }
 }
@@ -66,6 +66,18 @@ macro_rules! event_type {
     };
 }
 
+/// Version of the schema used to encode an [`Event`] as an ABCI event.
+///
+/// Bumped whenever the ABCI encoding of events changes, so that
+/// indexers can tell apart events emitted by different protocol versions.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Key of the ABCI event attribute holding the [`EventLevel`].
+pub const EVENT_LEVEL_KEY: &str = "event-level";
+
+/// Key of the ABCI event attribute holding the [`EVENT_SCHEMA_VERSION`].
+pub const EVENT_SCHEMA_VERSION_KEY: &str = "event-version";
+
 /// An event to be emitted in Namada.
 pub trait EventToEmit: Into<Event> {
     /// The domain of the event to emit.
@@ -147,6 +159,18 @@ impl Display for EventLevel {
     }
 }
 
+impl FromStr for EventLevel {
+    type Err = EventError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "block" => Ok(EventLevel::Block),
+            "tx" => Ok(EventLevel::Tx),
+            _ => Err(EventError::InvalidEventLevel(s.to_owned())),
+        }
+    }
+}
+
 /// ABCI event type.
 ///
 /// It is comprised of an event domain and sub-domain, plus any other
@@ -326,6 +350,12 @@ pub enum EventError {
     /// Missing value in attributes.
     #[error("Attributes missing value: {0}")]
     MissingValue(String),
+    /// Invalid event level.
+    #[error("Invalid event level: {0}")]
+    InvalidEventLevel(String),
+    /// The event was encoded with an unknown schema version.
+    #[error("Unsupported event schema version {0}")]
+    UnsupportedSchemaVersion(u32),
 }
 
 impl Event {
@@ -489,13 +519,18 @@ impl From<Event> for namada_core::tendermint_proto::v0_37::abci::Event {
                         index: true,
                     }
                 })
-                .chain(std::iter::once_with(|| {
+                .chain([
                     namada_core::tendermint_proto::v0_37::abci::EventAttribute {
-                        key: "event-level".to_string(),
+                        key: EVENT_LEVEL_KEY.to_string(),
                         value: event.level.to_string(),
                         index: true,
-                    }
-                }))
+                    },
+                    namada_core::tendermint_proto::v0_37::abci::EventAttribute {
+                        key: EVENT_SCHEMA_VERSION_KEY.to_string(),
+                        value: EVENT_SCHEMA_VERSION.to_string(),
+                        index: true,
+                    },
+                ])
                 .collect(),
         }
     }
@@ -520,10 +555,66 @@ impl From<Event> for namada_core::tendermint::abci::Event {
                 .attributes
                 .into_iter()
                 .map(|(key, value)| (key, value, true).into())
-                .chain(std::iter::once_with(|| {
-                    ("event-level", event.level.to_string(), true).into()
-                }))
+                .chain([
+                    (EVENT_LEVEL_KEY, event.level.to_string(), true).into(),
+                    (
+                        EVENT_SCHEMA_VERSION_KEY,
+                        EVENT_SCHEMA_VERSION.to_string(),
+                        true,
+                    )
+                        .into(),
+                ])
                 .collect(),
         }
     }
 }
+
+impl TryFrom<namada_core::tendermint::abci::Event> for Event {
+    type Error = EventError;
+
+    fn try_from(
+        abci_event: namada_core::tendermint::abci::Event,
+    ) -> Result<Self, Self::Error> {
+        use extend::{AttributesMap, Domain, EventAttributeEntry};
+
+        let mut attributes: BTreeMap<String, String> = abci_event
+            .attributes
+            .iter_attributes()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect();
+
+        // NB: events emitted before the introduction of schema versions
+        // have no version attribute, but are otherwise encoded the same
+        let version = attributes
+            .remove(EVENT_SCHEMA_VERSION_KEY)
+            .map(|version| {
+                version.parse::<u32>().map_err(|err| {
+                    EventError::AttributeEncoding(err.to_string())
+                })
+            })
+            .transpose()?
+            .unwrap_or(0);
+        if version > EVENT_SCHEMA_VERSION {
+            return Err(EventError::UnsupportedSchemaVersion(version));
+        }
+
+        let level = attributes
+            .remove(EVENT_LEVEL_KEY)
+            .ok_or(EventError::MissingAttribute(EVENT_LEVEL_KEY))?
+            .parse()?;
+
+        // undo the encoding of the event domain in the attributes
+        let event_type = match attributes.get(Domain::<Event>::KEY) {
+            Some(domain) => EventTypeBuilder::new_with_type(domain)
+                .with_segment(&abci_event.kind)
+                .build(),
+            None => abci_event.kind.parse()?,
+        };
+
+        Ok(Self {
+            level,
+            event_type,
+            attributes,
+        })
+    }
+}
@@ -0,0 +1,437 @@
+//! Versioned table of the gas costs charged by the gas metering injected in
+//! the wasm code of transactions and validity predicates, and by the host
+//! functions they call.
+//!
+//! The table is stored as a protocol parameter, such that the costs can be
+//! tuned by a governance proposal. Since the costs are baked into the wasm
+//! code at instrumentation time, every update of the table must bump its
+//! version, which invalidates the compiled modules of the wasm caches. The
+//! host functions read their costs from the table held by the wasm caches.
+//!
+//! The costs of the storage accesses and of the native VPs (e.g.
+//! [`crate::IBC_ACTION_VALIDATE_GAS`]) are still constants of this crate.
+
+use std::collections::BTreeMap;
+
+use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use namada_macros::BorshDeserializer;
+#[cfg(feature = "migrations")]
+use namada_migrations::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{VERIFY_ETH_SIG_GAS, VERIFY_TX_SIG_GAS, WASM_MEMORY_PAGE_GAS};
+
+/// The version of the default gas cost table
+pub const DEFAULT_GAS_COST_TABLE_VERSION: u64 = 1;
+
+/// The default gas cost of each wasm opcode, by name.
+///
+/// NOTE: these costs are taken from the benchmarks crate. None of them
+/// should be zero
+const DEFAULT_WASM_OPCODE_COSTS: [(&str, u32); 177] = [
+    ("Unreachable", 129_358),
+    // Just a flag, aribitrary cost of 1
+    ("End", 1),
+    // Just a flag, aribitrary cost of 1
+    ("Else", 1),
+    ("Nop", 1),
+    ("Block", 1),
+    ("Loop", 1),
+    ("If", 4),
+    ("Br", 27),
+    ("BrIf", 36),
+    ("BrTable", 70),
+    ("Return", 7),
+    ("Call", 43),
+    ("CallIndirect", 140),
+    ("Drop", 1),
+    ("Select", 37),
+    ("GetLocal", 2),
+    ("SetLocal", 2),
+    ("TeeLocal", 2),
+    ("GetGlobal", 3),
+    ("SetGlobal", 4),
+    ("I32Load", 5),
+    ("I64Load", 5),
+    ("F32Load", 6),
+    ("F64Load", 6),
+    ("I32Load8S", 5),
+    ("I32Load8U", 5),
+    ("I32Load16S", 5),
+    ("I32Load16U", 5),
+    ("I64Load8S", 5),
+    ("I64Load8U", 5),
+    ("I64Load16S", 5),
+    ("I64Load16U", 5),
+    ("I64Load32S", 5),
+    ("I64Load32U", 5),
+    ("I32Store", 5),
+    ("I64Store", 7),
+    ("F32Store", 5),
+    ("F64Store", 6),
+    ("I32Store8", 5),
+    ("I32Store16", 15),
+    ("I64Store8", 5),
+    ("I64Store16", 15),
+    ("I64Store32", 6),
+    ("CurrentMemory", 108),
+    ("GrowMemory", 394),
+    ("I32Const", 1),
+    ("I64Const", 1),
+    ("F32Const", 1),
+    ("F64Const", 1),
+    ("I32Eqz", 6),
+    ("I32Eq", 6),
+    ("I32Ne", 6),
+    ("I32LtS", 6),
+    ("I32LtU", 6),
+    ("I32GtS", 6),
+    ("I32GtU", 6),
+    ("I32LeS", 6),
+    ("I32LeU", 6),
+    ("I32GeS", 6),
+    ("I32GeU", 6),
+    ("I64Eqz", 7),
+    ("I64Eq", 7),
+    ("I64Ne", 7),
+    ("I64LtS", 7),
+    ("I64LtU", 7),
+    ("I64GtS", 7),
+    ("I64GtU", 7),
+    ("I64LeS", 7),
+    ("I64LeU", 7),
+    ("I64GeS", 7),
+    ("I64GeU", 7),
+    ("F32Eq", 8),
+    ("F32Ne", 8),
+    ("F32Lt", 8),
+    ("F32Gt", 8),
+    ("F32Le", 8),
+    ("F32Ge", 8),
+    ("F64Eq", 10),
+    ("F64Ne", 10),
+    ("F64Lt", 9),
+    ("F64Gt", 9),
+    ("F64Le", 9),
+    ("F64Ge", 9),
+    ("I32Clz", 35),
+    ("I32Ctz", 34),
+    ("I32Popcnt", 3),
+    ("I32Add", 3),
+    ("I32Sub", 3),
+    ("I32Mul", 5),
+    ("I32DivS", 17),
+    ("I32DivU", 17),
+    ("I32RemS", 41),
+    ("I32RemU", 17),
+    ("I32And", 3),
+    ("I32Or", 3),
+    ("I32Xor", 3),
+    ("I32Shl", 3),
+    ("I32ShrS", 3),
+    ("I32ShrU", 3),
+    ("I32Rotl", 3),
+    ("I32Rotr", 3),
+    ("I64Clz", 35),
+    ("I64Ctz", 34),
+    ("I64Popcnt", 3),
+    ("I64Add", 5),
+    ("I64Sub", 5),
+    ("I64Mul", 6),
+    ("I64DivS", 28),
+    ("I64DivU", 28),
+    ("I64RemS", 46),
+    ("I64RemU", 28),
+    ("I64And", 5),
+    ("I64Or", 5),
+    ("I64Xor", 5),
+    ("I64Shl", 4),
+    ("I64ShrS", 4),
+    ("I64ShrU", 4),
+    ("I64Rotl", 4),
+    ("I64Rotr", 4),
+    ("F32Abs", 4),
+    ("F32Neg", 3),
+    ("F32Ceil", 6),
+    ("F32Floor", 6),
+    ("F32Trunc", 6),
+    ("F32Nearest", 6),
+    ("F32Sqrt", 9),
+    ("F32Add", 6),
+    ("F32Sub", 6),
+    ("F32Mul", 6),
+    ("F32Div", 9),
+    ("F32Min", 50),
+    ("F32Max", 47),
+    ("F32Copysign", 6),
+    ("F64Abs", 6),
+    ("F64Neg", 4),
+    ("F64Ceil", 7),
+    ("F64Floor", 7),
+    ("F64Trunc", 7),
+    ("F64Nearest", 7),
+    ("F64Sqrt", 17),
+    ("F64Add", 7),
+    ("F64Sub", 7),
+    ("F64Mul", 7),
+    ("F64Div", 12),
+    ("F64Min", 52),
+    ("F64Max", 49),
+    ("F64Copysign", 11),
+    ("I32WrapI64", 2),
+    ("I32TruncSF32", 54),
+    ("I32TruncUF32", 54),
+    ("I32TruncSF64", 57),
+    ("I32TruncUF64", 57),
+    ("I64ExtendSI32", 2),
+    ("I64ExtendUI32", 2),
+    ("I64TruncSF32", 73),
+    ("I64TruncUF32", 70),
+    ("I64TruncSF64", 89),
+    ("I64TruncUF64", 70),
+    ("F32ConvertSI32", 12),
+    ("F32ConvertUI32", 6),
+    ("F32ConvertSI64", 6),
+    ("F32ConvertUI64", 39),
+    ("F32DemoteF64", 9),
+    ("F64ConvertSI32", 12),
+    ("F64ConvertUI32", 12),
+    ("F64ConvertSI64", 12),
+    ("F64ConvertUI64", 39),
+    ("F64PromoteF32", 9),
+    ("I32ReinterpretF32", 2),
+    ("I64ReinterpretF64", 2),
+    ("F32ReinterpretI32", 3),
+    ("F64ReinterpretI64", 3),
+    ("I32Extend8S", 1),
+    ("I32Extend16S", 1),
+    ("I64Extend8S", 1),
+    ("I64Extend16S", 1),
+    ("I64Extend32S", 1),
+];
+
+#[allow(missing_docs)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GasCostTableError {
+    #[error("Missing the gas cost of the wasm opcode {0}")]
+    MissingOpcode(String),
+    #[error("Unknown wasm opcode {0}")]
+    UnknownOpcode(String),
+    #[error("The gas cost of the wasm opcode {0} must be non-zero")]
+    ZeroOpcodeCost(String),
+    #[error("The gas cost of a wasm memory page must be non-zero")]
+    ZeroMemoryPageCost,
+    #[error("The gas cost of the host function {0} must be non-zero")]
+    ZeroHostFnCost(&'static str),
+    #[error(
+        "The version of the updated gas cost table {new} must be greater than \
+         the current version {current}"
+    )]
+    StaleVersion { current: u64, new: u64 },
+}
+
+/// The gas costs charged by the host functions exposed to the wasm code
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct HostFnGasCosts {
+    /// The cost of verifying a single signature of a transaction
+    pub verify_tx_sig: u64,
+    /// The cost of recovering the signer of an Ethereum secp256k1 signature
+    pub verify_eth_sig: u64,
+}
+
+impl Default for HostFnGasCosts {
+    fn default() -> Self {
+        Self {
+            verify_tx_sig: VERIFY_TX_SIG_GAS,
+            verify_eth_sig: VERIFY_ETH_SIG_GAS,
+        }
+    }
+}
+
+impl HostFnGasCosts {
+    /// Check that the cost of every host function is non-zero
+    pub fn validate(&self) -> Result<(), GasCostTableError> {
+        let Self {
+            verify_tx_sig,
+            verify_eth_sig,
+        } = self;
+        for (name, cost) in [
+            ("verify_tx_sig", verify_tx_sig),
+            ("verify_eth_sig", verify_eth_sig),
+        ] {
+            if *cost == 0 {
+                return Err(GasCostTableError::ZeroHostFnCost(name));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Versioned gas costs of the wasm opcodes and of the memory growth, as
+/// injected in the wasm code by the gas metering instrumentation, and of the
+/// host functions
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct GasCostTable {
+    /// The version of the table. It must be increased on every update
+    pub version: u64,
+    /// The gas cost of each wasm opcode, by name
+    pub wasm_opcodes: BTreeMap<String, u32>,
+    /// The cost for requesting one more page in wasm (64KiB)
+    pub wasm_memory_page: u32,
+    /// The cost per local variable of a called wasm function
+    pub call_per_local: u32,
+    /// The gas costs of the host functions
+    pub host_fns: HostFnGasCosts,
+}
+
+impl Default for GasCostTable {
+    fn default() -> Self {
+        Self {
+            version: DEFAULT_GAS_COST_TABLE_VERSION,
+            wasm_opcodes: DEFAULT_WASM_OPCODE_COSTS
+                .iter()
+                .map(|(name, cost)| (name.to_string(), *cost))
+                .collect(),
+            wasm_memory_page: WASM_MEMORY_PAGE_GAS,
+            call_per_local: 1,
+            host_fns: HostFnGasCosts::default(),
+        }
+    }
+}
+
+impl GasCostTable {
+    /// Get the gas cost of the wasm opcode with the given name
+    pub fn opcode_cost(&self, name: &str) -> Option<u32> {
+        self.wasm_opcodes.get(name).copied()
+    }
+
+    /// Check that the table contains a non-zero cost for every wasm opcode
+    /// and nothing else, and for every host function
+    pub fn validate(&self) -> Result<(), GasCostTableError> {
+        for (name, _) in DEFAULT_WASM_OPCODE_COSTS.iter() {
+            match self.wasm_opcodes.get(*name) {
+                None => {
+                    return Err(GasCostTableError::MissingOpcode(
+                        name.to_string(),
+                    ));
+                }
+                Some(0) => {
+                    return Err(GasCostTableError::ZeroOpcodeCost(
+                        name.to_string(),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        if let Some(name) = self.wasm_opcodes.keys().find(|name| {
+            !DEFAULT_WASM_OPCODE_COSTS
+                .iter()
+                .any(|(known, _)| known == name)
+        }) {
+            return Err(GasCostTableError::UnknownOpcode(name.clone()));
+        }
+        if self.wasm_memory_page == 0 {
+            return Err(GasCostTableError::ZeroMemoryPageCost);
+        }
+        self.host_fns.validate()
+    }
+
+    /// Check that this table is a valid update of the `current` one
+    pub fn validate_update(
+        &self,
+        current: &Self,
+    ) -> Result<(), GasCostTableError> {
+        if self.version <= current.version {
+            return Err(GasCostTableError::StaleVersion {
+                current: current.version,
+                new: self.version,
+            });
+        }
+        self.validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn test_default_table_is_valid() {
+        let table = GasCostTable::default();
+        assert_eq!(table.wasm_opcodes.len(), DEFAULT_WASM_OPCODE_COSTS.len());
+        table.validate().unwrap();
+    }
+
+    #[test]
+    fn test_table_update() {
+        let current = GasCostTable::default();
+
+        let mut new = current.clone();
+        new.wasm_opcodes.insert("I32Add".to_string(), 2);
+        assert_matches!(
+            new.validate_update(&current),
+            Err(GasCostTableError::StaleVersion { .. })
+        );
+
+        new.version = 2;
+        new.validate_update(&current).unwrap();
+
+        new.wasm_opcodes.insert("I32Add".to_string(), 0);
+        assert_matches!(
+            new.validate_update(&current),
+            Err(GasCostTableError::ZeroOpcodeCost(_))
+        );
+
+        new.wasm_opcodes.remove("I32Add");
+        assert_matches!(
+            new.validate_update(&current),
+            Err(GasCostTableError::MissingOpcode(_))
+        );
+
+        new.wasm_opcodes.insert("I32Add".to_string(), 1);
+        new.wasm_opcodes.insert("I32Foo".to_string(), 1);
+        assert_matches!(
+            new.validate_update(&current),
+            Err(GasCostTableError::UnknownOpcode(_))
+        );
+
+        new.wasm_opcodes.remove("I32Foo");
+        new.host_fns.verify_tx_sig = 0;
+        assert_matches!(
+            new.validate_update(&current),
+            Err(GasCostTableError::ZeroHostFnCost("verify_tx_sig"))
+        );
+    }
+}
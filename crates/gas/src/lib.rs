@@ -15,6 +15,7 @@
     clippy::arithmetic_side_effects
 )]
 
+pub mod cost_table;
 pub mod event;
 pub mod storage;
 
@@ -66,6 +67,9 @@ const PHYSICAL_STORAGE_LATENCY_PER_BYTE: u64 = 1_000_000;
 // This is based on the global average bandwidth
 const NETWORK_TRANSMISSION_GAS_PER_BYTE: u64 = 848;
 
+// TODO: the following costs of the storage accesses and of the native VPs
+// aren't part of the on-chain `cost_table::GasCostTable` yet, so updating
+// them requires a new release of the node
 /// The cost of accessing data from memory (both read and write mode), per byte
 pub const MEMORY_ACCESS_GAS_PER_BYTE: u64 = 104;
 /// The cost of accessing data from storage, per byte
@@ -74,8 +78,12 @@ pub const STORAGE_ACCESS_GAS_PER_BYTE: u64 =
 /// The cost of writing data to storage, per byte
 pub const STORAGE_WRITE_GAS_PER_BYTE: u64 =
     MEMORY_ACCESS_GAS_PER_BYTE + 69_634 + STORAGE_OCCUPATION_GAS_PER_BYTE;
-/// The cost of verifying a single signature of a transaction
+/// The default cost of verifying a single signature of a transaction (see
+/// [`cost_table::HostFnGasCosts`])
 pub const VERIFY_TX_SIG_GAS: u64 = 594_290;
+/// The default cost of recovering the signer of an Ethereum secp256k1
+/// signature (see [`cost_table::HostFnGasCosts`])
+pub const VERIFY_ETH_SIG_GAS: u64 = 652_410;
 /// The cost for requesting one more page in wasm (64KiB)
 #[allow(clippy::cast_possible_truncation)] // const in u32 range
 pub const WASM_MEMORY_PAGE_GAS: u32 =
@@ -138,7 +146,7 @@ impl Gas {
 
     /// Converts the sub gas units to whole ones. If the sub units are not a
     /// multiple of the `SCALE` than ceil the quotient
-    fn get_whole_gas_units(&self) -> u64 {
+    pub fn get_whole_gas_units(&self) -> u64 {
         let quotient = self.sub / SCALE;
         if self.sub % SCALE == 0 {
             quotient
@@ -422,6 +430,12 @@ impl VpGasMeter {
             current_gas: Gas::default(),
         }
     }
+
+    /// Get the gas consumed by the VP alone, excluding the gas consumed by
+    /// the transaction before it
+    pub fn get_vp_consumed_gas(&self) -> Gas {
+        self.current_gas
+    }
 }
 
 impl VpsGas {
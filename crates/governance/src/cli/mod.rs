@@ -1,3 +1,5 @@
+/// CLi governance off chain structures
+pub mod offline;
 /// CLi governance on chain structures
 pub mod onchain;
 /// CLi governance validation
@@ -0,0 +1,346 @@
+use std::collections::BTreeMap;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::account::AccountPublicKeysMap;
+use namada_core::address::Address;
+use namada_core::borsh::BorshSerializeExt;
+use namada_core::hash::Hash;
+use namada_core::key::{common, RefTo, SigScheme};
+use namada_core::storage::Epoch;
+use namada_macros::BorshDeserializer;
+#[cfg(feature = "migrations")]
+use namada_migrations::*;
+use serde::{Deserialize, Serialize};
+
+use super::validation::{
+    is_valid_content, is_valid_signature, is_valid_tally_epoch,
+    ProposalValidation,
+};
+use crate::storage::vote::ProposalVote;
+
+/// The signatures of an off-chain proposal or vote, indexed by the public key
+/// of the signer
+pub type OfflineSignatures = BTreeMap<common::PublicKey, common::Signature>;
+
+#[derive(
+    Debug,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+/// The off-chain proposal structure. Off-chain proposals are never submitted
+/// to the chain and don't lock any funds, they are only used for signaling
+/// votes tallied with the voting power at `tally_epoch`.
+pub struct OfflineProposal {
+    /// The proposal content
+    pub content: BTreeMap<String, String>,
+    /// The proposal author address
+    pub author: Address,
+    /// The epoch at which the voting power of the voters is computed
+    pub tally_epoch: Epoch,
+}
+
+impl OfflineProposal {
+    /// Validate an off-chain proposal
+    pub fn validate(
+        self,
+        current_epoch: Epoch,
+        max_proposal_content_size: u64,
+        force: bool,
+    ) -> Result<Self, ProposalValidation> {
+        if force {
+            return Ok(self);
+        }
+        is_valid_tally_epoch(self.tally_epoch, current_epoch)?;
+        is_valid_content(&self.content, max_proposal_content_size)?;
+
+        Ok(self)
+    }
+
+    /// Hash of the proposal, signed by the author and referenced by the votes
+    pub fn hash(&self) -> Hash {
+        Hash::sha256(self.serialize_to_vec())
+    }
+
+    /// Sign the proposal with the keys of the author
+    pub fn sign(
+        self,
+        signing_keys: &[common::SecretKey],
+    ) -> OfflineSignedProposal {
+        let signatures = sign_hash(&self.hash(), signing_keys);
+
+        OfflineSignedProposal {
+            proposal: self,
+            signatures,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for OfflineProposal {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        serde_json::from_slice(value)
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+/// An off-chain proposal signed by its author
+pub struct OfflineSignedProposal {
+    /// The proposal content
+    pub proposal: OfflineProposal,
+    /// The signatures over the proposal hash
+    pub signatures: OfflineSignatures,
+}
+
+impl OfflineSignedProposal {
+    /// Hash of the signed proposal
+    pub fn hash(&self) -> Hash {
+        self.proposal.hash()
+    }
+
+    /// Check that the proposal was signed by at least `threshold` of the
+    /// author's keys
+    pub fn check_signatures(
+        &self,
+        account_public_keys_map: &AccountPublicKeysMap,
+        threshold: u8,
+    ) -> bool {
+        has_enough_signatures(
+            &self.hash(),
+            &self.signatures,
+            account_public_keys_map,
+            threshold,
+        )
+    }
+
+    /// Validate the signatures and the tally epoch of an off-chain proposal
+    pub fn validate(
+        self,
+        account_public_keys_map: &AccountPublicKeysMap,
+        threshold: u8,
+        current_epoch: Epoch,
+    ) -> Result<Self, ProposalValidation> {
+        is_valid_signature(
+            self.check_signatures(account_public_keys_map, threshold),
+        )?;
+        is_valid_tally_epoch(self.proposal.tally_epoch, current_epoch)?;
+
+        Ok(self)
+    }
+}
+
+impl TryFrom<&[u8]> for OfflineSignedProposal {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        serde_json::from_slice(value)
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+/// A vote on an off-chain proposal
+pub struct OfflineVote {
+    /// The hash of the voted proposal
+    pub proposal_hash: Hash,
+    /// The vote
+    pub vote: ProposalVote,
+    /// The address of the voter
+    pub address: Address,
+    /// The validators the voter is bonded to. A validator voting with its own
+    /// stake must include its own address.
+    pub delegations: Vec<Address>,
+    /// The signatures over the vote hash
+    pub signatures: OfflineSignatures,
+}
+
+impl OfflineVote {
+    /// Create a new, unsigned, vote on the given proposal
+    pub fn new(
+        proposal: &OfflineSignedProposal,
+        vote: ProposalVote,
+        address: Address,
+        delegations: Vec<Address>,
+    ) -> Self {
+        Self {
+            proposal_hash: proposal.hash(),
+            vote,
+            address,
+            delegations,
+            signatures: Default::default(),
+        }
+    }
+
+    /// Hash of the vote data signed by the voter
+    pub fn signing_hash(&self) -> Hash {
+        let vote_data = (
+            &self.proposal_hash,
+            &self.vote,
+            &self.address,
+            &self.delegations,
+        );
+        Hash::sha256(vote_data.serialize_to_vec())
+    }
+
+    /// Sign the vote with the keys of the voter
+    pub fn sign(self, signing_keys: &[common::SecretKey]) -> Self {
+        let signatures = sign_hash(&self.signing_hash(), signing_keys);
+        Self { signatures, ..self }
+    }
+
+    /// Check that the vote was signed by at least `threshold` of the voter's
+    /// keys
+    pub fn check_signatures(
+        &self,
+        account_public_keys_map: &AccountPublicKeysMap,
+        threshold: u8,
+    ) -> bool {
+        has_enough_signatures(
+            &self.signing_hash(),
+            &self.signatures,
+            account_public_keys_map,
+            threshold,
+        )
+    }
+
+    /// Check if the vote is from a validator
+    pub fn is_validator(&self) -> bool {
+        self.delegations.contains(&self.address)
+    }
+}
+
+impl TryFrom<&[u8]> for OfflineVote {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        serde_json::from_slice(value)
+    }
+}
+
+fn sign_hash(
+    hash: &Hash,
+    signing_keys: &[common::SecretKey],
+) -> OfflineSignatures {
+    signing_keys
+        .iter()
+        .map(|key| (key.ref_to(), common::SigScheme::sign(key, hash)))
+        .collect()
+}
+
+fn has_enough_signatures(
+    hash: &Hash,
+    signatures: &OfflineSignatures,
+    account_public_keys_map: &AccountPublicKeysMap,
+    threshold: u8,
+) -> bool {
+    let valid_signatures = signatures
+        .iter()
+        .filter(|(public_key, signature)| {
+            account_public_keys_map.pk_to_idx.contains_key(*public_key)
+                && common::SigScheme::verify_signature(
+                    public_key, hash, signature,
+                )
+                .is_ok()
+        })
+        .count();
+
+    valid_signatures >= usize::from(threshold)
+}
+
+#[cfg(test)]
+mod test {
+    use namada_core::address::testing::established_address_1;
+    use namada_core::key::testing::{keypair_1, keypair_2};
+
+    use super::*;
+
+    fn proposal() -> OfflineProposal {
+        OfflineProposal {
+            content: BTreeMap::from([(
+                "title".to_string(),
+                "Signaling".to_string(),
+            )]),
+            author: established_address_1(),
+            tally_epoch: Epoch(2),
+        }
+    }
+
+    #[test]
+    fn test_offline_proposal_signatures() {
+        let keys_map = AccountPublicKeysMap::from_iter([keypair_1().ref_to()]);
+        let signed = proposal().sign(&[keypair_1()]);
+        assert!(signed.check_signatures(&keys_map, 1));
+        assert!(!signed.check_signatures(&keys_map, 2));
+
+        // Signatures of keys not belonging to the author don't count
+        let signed = proposal().sign(&[keypair_2()]);
+        assert!(!signed.check_signatures(&keys_map, 1));
+
+        // Any change to the proposal invalidates the signatures
+        let mut signed = proposal().sign(&[keypair_1()]);
+        signed.proposal.tally_epoch = Epoch(3);
+        assert!(!signed.check_signatures(&keys_map, 1));
+    }
+
+    #[test]
+    fn test_offline_proposal_validation() {
+        let keys_map = AccountPublicKeysMap::from_iter([keypair_1().ref_to()]);
+        let signed = proposal().sign(&[keypair_1()]);
+        assert!(signed.clone().validate(&keys_map, 1, Epoch(2)).is_ok());
+        assert!(matches!(
+            signed.validate(&keys_map, 1, Epoch(1)),
+            Err(ProposalValidation::InvalidTallyEpoch(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_offline_vote_signatures() {
+        let keys_map = AccountPublicKeysMap::from_iter([keypair_2().ref_to()]);
+        let proposal = proposal().sign(&[keypair_1()]);
+        let voter = established_address_1();
+        let vote = OfflineVote::new(
+            &proposal,
+            ProposalVote::Yay,
+            voter.clone(),
+            vec![voter],
+        )
+        .sign(&[keypair_2()]);
+        assert!(vote.is_validator());
+        assert!(vote.check_signatures(&keys_map, 1));
+
+        // Changing the vote invalidates the signatures
+        let vote = OfflineVote {
+            vote: ProposalVote::Nay,
+            ..vote
+        };
+        assert!(!vote.check_signatures(&keys_map, 1));
+    }
+
+    #[test]
+    fn test_offline_json_roundtrip() {
+        let proposal = proposal().sign(&[keypair_1()]);
+        let json = serde_json::to_vec(&proposal).unwrap();
+        let decoded = OfflineSignedProposal::try_from(json.as_slice()).unwrap();
+        assert_eq!(decoded.hash(), proposal.hash());
+        assert_eq!(decoded.signatures, proposal.signatures);
+    }
+}
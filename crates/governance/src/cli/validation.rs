@@ -7,6 +7,9 @@ use namada_core::token;
 use thiserror::Error;
 
 use super::onchain::{PgfFunding, StewardsUpdate};
+use crate::storage::proposal::{
+    validate_parameter_changes, EthBridgeParamsUpdate, ParameterChange,
+};
 
 /// This enum represents proposal data
 #[derive(Debug, Error)]
@@ -64,6 +67,24 @@ pub enum ProposalValidation {
     /// The pgf funding data is not valid
     #[error("invalid proposal extra data: cannot be empty.")]
     InvalidPgfFundingExtraData,
+    /// The Ethereum bridge parameters data is not valid
+    #[error(
+        "Invalid proposal extra data: at least one Ethereum bridge parameter \
+         must be updated."
+    )]
+    InvalidEthBridgeParamsExtraData,
+    /// The parameter changes data is not valid
+    #[error("Invalid proposal extra data: {0}")]
+    InvalidParameterChangesExtraData(String),
+    /// The off-chain proposal tally epoch is invalid
+    #[error(
+        "Invalid proposal tally epoch: tally epoch ({0}) must be less than or \
+         equal to the current epoch ({1})"
+    )]
+    InvalidTallyEpoch(Epoch, Epoch),
+    /// The off-chain proposal signatures are invalid
+    #[error("The proposal signatures are invalid or below the threshold")]
+    InvalidSignature,
     #[error("Arithmetic {0}.")]
     Arith(#[from] arith::Error),
 }
@@ -239,3 +260,46 @@ pub fn is_valid_pgf_funding_data(
         Err(ProposalValidation::InvalidPgfFundingExtraData)
     }
 }
+
+pub fn is_valid_eth_bridge_params_data(
+    data: &EthBridgeParamsUpdate,
+) -> Result<(), ProposalValidation> {
+    if data.is_empty() {
+        Err(ProposalValidation::InvalidEthBridgeParamsExtraData)
+    } else {
+        Ok(())
+    }
+}
+
+pub fn is_valid_parameter_changes_data(
+    data: &[ParameterChange],
+    native_token: &Address,
+) -> Result<(), ProposalValidation> {
+    validate_parameter_changes(data, native_token).map_err(|err| {
+        ProposalValidation::InvalidParameterChangesExtraData(err.to_string())
+    })
+}
+
+pub fn is_valid_tally_epoch(
+    tally_epoch: Epoch,
+    current_epoch: Epoch,
+) -> Result<(), ProposalValidation> {
+    if tally_epoch <= current_epoch {
+        Ok(())
+    } else {
+        Err(ProposalValidation::InvalidTallyEpoch(
+            tally_epoch,
+            current_epoch,
+        ))
+    }
+}
+
+pub fn is_valid_signature(
+    has_valid_signatures: bool,
+) -> Result<(), ProposalValidation> {
+    if has_valid_signatures {
+        Ok(())
+    } else {
+        Err(ProposalValidation::InvalidSignature)
+    }
+}
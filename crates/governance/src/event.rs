@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use namada_events::extend::{EventAttributeEntry, ExtendAttributesMap};
 use namada_events::{Event, EventLevel, EventToEmit};
 
-use crate::utils::TallyResult as GovTallyResult;
+use crate::utils::{ProposalFundsDestination, TallyResult as GovTallyResult};
 use crate::ProposalType as GovProposalType;
 
 pub mod types {
@@ -274,3 +274,18 @@ impl EventAttributeEntry<'static> for ProposalCodeExitStatus {
         self.0
     }
 }
+
+/// Extend an [`Event`] with the destination of the funds locked by a
+/// proposal.
+pub struct FundsDestination(pub ProposalFundsDestination);
+
+impl EventAttributeEntry<'static> for FundsDestination {
+    type Value = ProposalFundsDestination;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "funds_destination";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
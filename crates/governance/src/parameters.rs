@@ -1,11 +1,15 @@
+use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::dec::Dec;
 use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use namada_storage::{Result, StorageRead, StorageWrite};
+use serde::{Deserialize, Serialize};
 
 use super::storage::keys as goverance_storage;
+use crate::utils::{ProposalFundsDestination, ProposalResult, TallyResult};
 
 #[derive(
     Clone,
@@ -36,6 +40,8 @@ pub struct GovernanceParameters {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epoch
     pub max_proposal_latency: u64,
+    /// Policy deciding where the funds locked by a proposal end up
+    pub proposal_funds_policy: ProposalFundsPolicy,
 }
 
 impl Default for GovernanceParameters {
@@ -48,6 +54,81 @@ impl Default for GovernanceParameters {
             max_proposal_content_size: 10_000,
             min_proposal_grace_epochs: 6,
             max_proposal_latency: 30,
+            proposal_funds_policy: ProposalFundsPolicy::default(),
+        }
+    }
+}
+
+/// Policy deciding what happens to the funds locked by a proposal, once its
+/// votes have been tallied
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub enum ProposalFundsPolicy {
+    /// Refund the author of passed proposals, and burn the funds of
+    /// rejected proposals
+    #[default]
+    BurnOnReject,
+    /// Like [`ProposalFundsPolicy::BurnOnReject`], except that the author of
+    /// a proposal rejected because not enough voting power took part in the
+    /// tally is refunded
+    RefundOnQuorumNotMet,
+    /// Refund the author of any proposal, unless its nay votes reach the
+    /// given fraction of the total voting power, in which case the proposal
+    /// is considered spam and its funds are burned
+    BurnOnSpam {
+        /// Fraction of the total voting power that must vote nay for the
+        /// funds of a proposal to be burned
+        threshold: Dec,
+    },
+}
+
+impl ProposalFundsPolicy {
+    /// Check that the policy is well-formed, i.e. that the spam threshold of
+    /// [`ProposalFundsPolicy::BurnOnSpam`] is a fraction within `[0, 1]`
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::BurnOnReject | Self::RefundOnQuorumNotMet => true,
+            Self::BurnOnSpam { threshold } => {
+                !threshold.is_negative() && *threshold <= Dec::one()
+            }
+        }
+    }
+
+    /// Determine where the funds locked by a proposal authored by `author`
+    /// should be sent to, given the result of its tally
+    pub fn funds_destination(
+        &self,
+        author: Address,
+        proposal_result: &ProposalResult,
+    ) -> ProposalFundsDestination {
+        let refund = match (self, proposal_result.result) {
+            (_, TallyResult::Passed) => true,
+            (Self::BurnOnReject, TallyResult::Rejected) => false,
+            (Self::RefundOnQuorumNotMet, TallyResult::Rejected) => {
+                !proposal_result.quorum_met()
+            }
+            (Self::BurnOnSpam { threshold }, TallyResult::Rejected) => {
+                !proposal_result.nay_over_total_fraction(*threshold)
+            }
+        };
+        if refund {
+            ProposalFundsDestination::Refund(author)
+        } else {
+            ProposalFundsDestination::Burn
         }
     }
 }
@@ -66,6 +147,7 @@ impl GovernanceParameters {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            proposal_funds_policy,
         } = self;
 
         let min_proposal_fund_key =
@@ -101,6 +183,10 @@ impl GovernanceParameters {
             goverance_storage::get_max_proposal_latency_key();
         storage.write(&max_proposal_latency_key, max_proposal_latency)?;
 
+        let proposal_funds_policy_key =
+            goverance_storage::get_proposal_funds_policy_key();
+        storage.write(&proposal_funds_policy_key, proposal_funds_policy)?;
+
         let counter_key = goverance_storage::get_counter_key();
         storage.write(&counter_key, u64::MIN)
     }
@@ -24,6 +24,7 @@ struct Keys {
     max_period: &'static str,
     max_content: &'static str,
     max_latency: &'static str,
+    funds_policy: &'static str,
     min_grace_epochs: &'static str,
     counter: &'static str,
     pending: &'static str,
@@ -271,6 +272,15 @@ pub fn is_min_grace_epochs_key(key: &Key) -> bool {
                     && min_grace_epochs_param == Keys::VALUES.min_grace_epochs)
 }
 
+/// Check if key is a proposal funds policy parameter key
+pub fn is_proposal_funds_policy_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+                    DbKeySeg::AddressSeg(addr),
+                    DbKeySeg::StringSeg(funds_policy_param),
+                ] if addr == &ADDRESS
+                    && funds_policy_param == Keys::VALUES.funds_policy)
+}
+
 /// Check if key is parameter key
 pub fn is_parameter_key(key: &Key) -> bool {
     is_min_proposal_fund_key(key)
@@ -279,6 +289,7 @@ pub fn is_parameter_key(key: &Key) -> bool {
         || is_min_proposal_voting_period_key(key)
         || is_max_proposal_period_key(key)
         || is_min_grace_epochs_key(key)
+        || is_proposal_funds_policy_key(key)
 }
 
 /// Check if key is start epoch or end epoch key
@@ -335,6 +346,13 @@ pub fn get_max_proposal_latency_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get proposal funds policy key
+pub fn get_proposal_funds_policy_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.funds_policy.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get min grace epochs proposal key
 pub fn get_min_proposal_grace_epochs_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -16,13 +16,13 @@ use namada_core::storage::Epoch;
 use namada_storage::{iter_prefix, Error, Result, StorageRead, StorageWrite};
 use namada_trans_token as token;
 
-use crate::parameters::GovernanceParameters;
+use crate::parameters::{GovernanceParameters, ProposalFundsPolicy};
 use crate::storage::keys as governance_keys;
 use crate::storage::proposal::{
     InitProposalData, ProposalType, StorageProposal, VoteProposalData,
 };
 use crate::storage::vote::ProposalVote;
-use crate::utils::{ProposalResult, Vote};
+use crate::utils::{ProposalResult, ProposalSummary, Vote};
 use crate::ADDRESS as governance_address;
 
 /// A proposal creation transaction.
@@ -178,6 +178,35 @@ where
     Ok(proposal)
 }
 
+/// Get the summaries of at most `count` proposals, starting from the proposal
+/// with id `from`, given the current epoch
+pub fn get_proposal_summaries<S>(
+    storage: &S,
+    from: u64,
+    count: u64,
+    current_epoch: Epoch,
+) -> Result<Vec<ProposalSummary>>
+where
+    S: StorageRead,
+{
+    let counter_key = governance_keys::get_counter_key();
+    let next_proposal_id: u64 = storage.read(&counter_key)?.unwrap_or_default();
+    let to = from.saturating_add(count).min(next_proposal_id);
+
+    let mut summaries = Vec::new();
+    for id in from..to {
+        if let Some(proposal) = get_proposal_by_id(storage, id)? {
+            let result = get_proposal_result(storage, id)?;
+            summaries.push(ProposalSummary {
+                status: proposal.get_status(current_epoch),
+                proposal,
+                result,
+            });
+        }
+    }
+    Ok(summaries)
+}
+
 /// Query all the votes for a proposal_id
 pub fn get_proposal_votes<S>(storage: &S, proposal_id: u64) -> Result<Vec<Vote>>
 where
@@ -283,6 +312,8 @@ where
     let max_proposal_latency: u64 =
         storage.read(&key)?.expect("Parameter should be defined.");
 
+    let proposal_funds_policy = get_proposal_funds_policy(storage)?;
+
     Ok(GovernanceParameters {
         min_proposal_fund,
         max_proposal_code_size,
@@ -291,9 +322,21 @@ where
         max_proposal_content_size,
         min_proposal_grace_epochs,
         max_proposal_latency,
+        proposal_funds_policy,
     })
 }
 
+/// Get governance "proposal_funds_policy" parameter. Defaults to
+/// [`ProposalFundsPolicy::BurnOnReject`] if it has not been written to
+/// storage.
+pub fn get_proposal_funds_policy<S>(storage: &S) -> Result<ProposalFundsPolicy>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_proposal_funds_policy_key();
+    Ok(storage.read(&key)?.unwrap_or_default())
+}
+
 /// Get governance "max_proposal_period" parameter
 pub fn get_max_proposal_period<S>(storage: &S) -> Result<u64>
 where
@@ -1,12 +1,17 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
+use std::num::NonZeroU64;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use itertools::Itertools;
 use namada_core::address::Address;
+use namada_core::dec::Dec;
+use namada_core::ethereum_events::EthAddress;
 use namada_core::hash::Hash;
 use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
-use namada_core::storage::Epoch;
+use namada_core::storage::{BlockHeight, Epoch};
+use namada_core::time::DurationSecs;
+use namada_gas::cost_table::GasCostTable;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -16,8 +21,9 @@ use thiserror::Error;
 
 use super::vote::ProposalVote;
 use crate::cli::onchain::{
-    DefaultProposal, PgfAction, PgfContinuous, PgfFundingProposal, PgfRetro,
-    PgfSteward, PgfStewardProposal, StewardsUpdate,
+    DefaultProposal, EthBridgeParamsProposal, ParameterChangesProposal,
+    PgfAction, PgfContinuous, PgfFundingProposal, PgfRetro, PgfSteward,
+    PgfStewardProposal, StewardsUpdate,
 };
 use crate::utils::{ProposalStatus, TallyType};
 
@@ -159,6 +165,38 @@ impl TryFrom<PgfFundingProposal> for InitProposalData {
     }
 }
 
+impl TryFrom<EthBridgeParamsProposal> for InitProposalData {
+    type Error = ProposalError;
+
+    fn try_from(value: EthBridgeParamsProposal) -> Result<Self, Self::Error> {
+        Ok(InitProposalData {
+            content: Hash::default(),
+            author: value.proposal.author,
+            r#type: ProposalType::EthBridgeParams(value.data),
+            voting_start_epoch: value.proposal.voting_start_epoch,
+            voting_end_epoch: value.proposal.voting_end_epoch,
+            activation_epoch: value.proposal.activation_epoch,
+        })
+    }
+}
+
+impl TryFrom<ParameterChangesProposal> for InitProposalData {
+    type Error = ProposalError;
+
+    fn try_from(value: ParameterChangesProposal) -> Result<Self, Self::Error> {
+        Ok(InitProposalData {
+            content: Hash::default(),
+            author: value.proposal.author,
+            r#type: ProposalType::ParameterChanges(
+                value.data.into_iter().collect(),
+            ),
+            voting_start_epoch: value.proposal.voting_start_epoch,
+            voting_end_epoch: value.proposal.voting_end_epoch,
+            activation_epoch: value.proposal.activation_epoch,
+        })
+    }
+}
+
 /// Storage struture for pgf fundings
 #[derive(
     Debug,
@@ -210,6 +248,309 @@ pub enum ProposalType {
     PGFSteward(BTreeSet<AddRemove<Address>>),
     /// PGF funding proposal
     PGFPayment(BTreeSet<PGFAction>),
+    /// Ethereum bridge parameters update proposal
+    EthBridgeParams(EthBridgeParamsUpdate),
+    /// Protocol parameters change proposal
+    ParameterChanges(BTreeSet<ParameterChange>),
+}
+
+/// A change of a protocol parameter to a new value. In proposal data, the
+/// parameter is identified by its key, e.g.
+/// `{"key": "max_block_gas", "value": 20000000}`.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+#[serde(tag = "key", content = "value", rename_all = "snake_case")]
+pub enum ParameterChange {
+    /// The minimum number of blocks in an epoch
+    MinEpochNumBlocks(u64),
+    /// The minimum duration of an epoch
+    MinEpochDuration(DurationSecs),
+    /// The maximum gas of a block
+    MaxBlockGas(u64),
+    /// The minimum gas price of a fee token. A `None` amount removes the
+    /// token from the accepted fee tokens.
+    MinimumGasPrice {
+        /// The fee token
+        token: Address,
+        /// The new minimum gas price
+        amount: Option<Amount>,
+    },
+    /// The maximum PoS inflation rate
+    PosMaxInflationRate(Dec),
+    /// The PoS target ratio of staked tokens
+    PosTargetStakedRatio(Dec),
+    /// The fraction of blocks of the liveness window that a validator must
+    /// sign to not be jailed
+    PosLivenessThreshold(Dec),
+    /// The rate at which the minimum gas price of the native token converts
+    /// to a fee token. A `None` rate removes the token from the tokens
+    /// priced by conversion.
+    FeeConversionRate {
+        /// The fee token
+        token: Address,
+        /// The new amount of the token worth a unit of the native token
+        rate: Option<Dec>,
+    },
+    /// The address allowed to update the fee conversion rates outside of
+    /// governance proposals. A `None` address disables the updates.
+    FeePriceFeed(Option<Address>),
+    /// The gas costs of the wasm opcodes and the host functions. The version
+    /// of the new table must be greater than the one of the table in effect
+    /// when the proposal is executed.
+    GasCostTable(GasCostTable),
+    /// The height at which the chain halts for a coordinated upgrade. It
+    /// must be greater than the height at which the proposal is executed.
+    UpgradeHeight(BlockHeight),
+}
+
+/// Error of an invalid [`ParameterChange`]
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParameterChangeError {
+    #[error("At least one parameter must be changed")]
+    Empty,
+    #[error("The parameter {0} is changed more than once")]
+    Duplicate(String),
+    #[error("Invalid value of the parameter {0}: {1}")]
+    InvalidValue(String, String),
+}
+
+impl ParameterChange {
+    /// The key of the changed parameter. Changes of the minimum gas price
+    /// of different tokens have distinct keys.
+    pub fn key(&self) -> String {
+        match self {
+            Self::MinEpochNumBlocks(_) => "min_epoch_num_blocks".to_string(),
+            Self::MinEpochDuration(_) => "min_epoch_duration".to_string(),
+            Self::MaxBlockGas(_) => "max_block_gas".to_string(),
+            Self::MinimumGasPrice { token, .. } => {
+                format!("minimum_gas_price/{token}")
+            }
+            Self::PosMaxInflationRate(_) => {
+                "pos_max_inflation_rate".to_string()
+            }
+            Self::PosTargetStakedRatio(_) => {
+                "pos_target_staked_ratio".to_string()
+            }
+            Self::PosLivenessThreshold(_) => {
+                "pos_liveness_threshold".to_string()
+            }
+            Self::FeeConversionRate { token, .. } => {
+                format!("fee_conversion_rate/{token}")
+            }
+            Self::FeePriceFeed(_) => "fee_price_feed".to_string(),
+            Self::GasCostTable(_) => "gas_cost_table".to_string(),
+            Self::UpgradeHeight(_) => "upgrade_height".to_string(),
+        }
+    }
+
+    /// Check that the new value of the parameter is valid on its own. The
+    /// native token can't be removed from the accepted fee tokens. The
+    /// checks against the current state, e.g. of the version of a new gas
+    /// cost table, are done when the change is executed.
+    pub fn validate(
+        &self,
+        native_token: &Address,
+    ) -> Result<(), ParameterChangeError> {
+        let invalid = |reason: &str| {
+            Err(ParameterChangeError::InvalidValue(
+                self.key(),
+                reason.to_string(),
+            ))
+        };
+        match self {
+            Self::MinEpochNumBlocks(0) | Self::MaxBlockGas(0) => {
+                invalid("must be greater than zero")
+            }
+            Self::MinEpochDuration(duration) if duration.0 == 0 => {
+                invalid("must be greater than zero")
+            }
+            Self::UpgradeHeight(height) if height.0 == 0 => {
+                invalid("must be greater than zero")
+            }
+            Self::GasCostTable(table) => match table.validate() {
+                Ok(()) => Ok(()),
+                Err(err) => invalid(&err.to_string()),
+            },
+            Self::PosMaxInflationRate(rate)
+            | Self::PosTargetStakedRatio(rate)
+            | Self::PosLivenessThreshold(rate)
+                if rate.is_negative() || *rate > Dec::one() =>
+            {
+                invalid("must be between 0 and 1")
+            }
+            Self::MinimumGasPrice {
+                token,
+                amount: None,
+            } if token == native_token => {
+                invalid("the native token must remain a fee token")
+            }
+            Self::FeeConversionRate {
+                rate: Some(rate), ..
+            } if !namada_parameters::is_valid_fee_conversion_rate(rate) => {
+                invalid(&format!(
+                    "must be greater than zero and at most {}",
+                    namada_parameters::MAX_FEE_CONVERSION_RATE
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Display for ParameterChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MinEpochNumBlocks(value) | Self::MaxBlockGas(value) => {
+                write!(f, "{}: {value}", self.key())
+            }
+            Self::MinEpochDuration(duration) => {
+                write!(f, "{}: {}s", self.key(), duration.0)
+            }
+            Self::MinimumGasPrice {
+                amount: Some(amount),
+                ..
+            } => write!(f, "{}: {}", self.key(), amount.to_string_native()),
+            Self::MinimumGasPrice { amount: None, .. } => {
+                write!(f, "{}: removed", self.key())
+            }
+            Self::PosMaxInflationRate(value)
+            | Self::PosTargetStakedRatio(value)
+            | Self::PosLivenessThreshold(value) => {
+                write!(f, "{}: {value}", self.key())
+            }
+            Self::FeeConversionRate {
+                rate: Some(rate), ..
+            } => write!(f, "{}: {rate}", self.key()),
+            Self::FeePriceFeed(Some(address)) => {
+                write!(f, "{}: {address}", self.key())
+            }
+            Self::FeeConversionRate { rate: None, .. }
+            | Self::FeePriceFeed(None) => {
+                write!(f, "{}: removed", self.key())
+            }
+            Self::GasCostTable(table) => {
+                write!(f, "{}: version {}", self.key(), table.version)
+            }
+            Self::UpgradeHeight(height) => {
+                write!(f, "{}: {height}", self.key())
+            }
+        }
+    }
+}
+
+/// Check that a set of parameter changes is not empty, changes every
+/// parameter at most once and only to valid values.
+pub fn validate_parameter_changes<'a>(
+    changes: impl IntoIterator<Item = &'a ParameterChange>,
+    native_token: &Address,
+) -> Result<(), ParameterChangeError> {
+    let mut keys = BTreeSet::new();
+    for change in changes {
+        change.validate(native_token)?;
+        let key = change.key();
+        if !keys.insert(key.clone()) {
+            return Err(ParameterChangeError::Duplicate(key));
+        }
+    }
+    if keys.is_empty() {
+        return Err(ParameterChangeError::Empty);
+    }
+    Ok(())
+}
+
+/// An update of the Ethereum bridge parameters. The parameters that are
+/// not set are left unchanged.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct EthBridgeParamsUpdate {
+    /// The new Bridge contract
+    #[serde(default)]
+    pub bridge_contract: Option<EthContractUpdate>,
+    /// The new address of the ERC20 contract of the native token
+    #[serde(default)]
+    pub native_erc20: Option<EthAddress>,
+    /// The new minimum number of confirmations needed to trust an Ethereum
+    /// branch
+    #[serde(default)]
+    pub min_confirmations: Option<NonZeroU64>,
+}
+
+impl EthBridgeParamsUpdate {
+    /// Check if the update doesn't change any parameter
+    pub fn is_empty(&self) -> bool {
+        self.bridge_contract.is_none()
+            && self.native_erc20.is_none()
+            && self.min_confirmations.is_none()
+    }
+}
+
+impl Display for EthBridgeParamsUpdate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut params = vec![];
+        if let Some(contract) = &self.bridge_contract {
+            params.push(format!(
+                "Bridge contract: {} (version {})",
+                contract.address, contract.version
+            ));
+        }
+        if let Some(address) = &self.native_erc20 {
+            params.push(format!("Native ERC20 contract: {address}"));
+        }
+        if let Some(confirmations) = &self.min_confirmations {
+            params.push(format!("Minimum confirmations: {confirmations}"));
+        }
+        write!(f, "{}", params.join(", "))
+    }
+}
+
+/// A new version of an upgradeable Ethereum contract
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct EthContractUpdate {
+    /// The Ethereum address of the contract
+    pub address: EthAddress,
+    /// The version of the contract, which must be greater than the
+    /// version of the contract it replaces
+    pub version: NonZeroU64,
 }
 
 /// An add or remove action for PGF
@@ -459,6 +800,16 @@ impl ProposalType {
                     .map(|action| format!("\n  {}", &action))
                     .join("")
             ),
+            ProposalType::EthBridgeParams(update) => {
+                format!("Parameters: {update}")
+            }
+            ProposalType::ParameterChanges(changes) => format!(
+                "Changes:{}",
+                changes
+                    .iter()
+                    .map(|change| format!("\n  {}", &change))
+                    .join("")
+            ),
         }
     }
 }
@@ -470,6 +821,12 @@ impl Display for ProposalType {
             ProposalType::DefaultWithWasm(_) => write!(f, "Default with Wasm"),
             ProposalType::PGFSteward(_) => write!(f, "PGF steward"),
             ProposalType::PGFPayment(_) => write!(f, "PGF funding"),
+            ProposalType::EthBridgeParams(_) => {
+                write!(f, "Ethereum bridge parameters")
+            }
+            ProposalType::ParameterChanges(_) => {
+                write!(f, "Parameter changes")
+            }
         }
     }
 }
@@ -650,6 +1007,8 @@ impl Display for StorageProposal {
 /// Testing helpers and and strategies for governance proposals
 pub mod testing {
     use namada_core::address::testing::arb_non_internal_address;
+    use namada_core::dec::testing::arb_dec;
+    use namada_core::ethereum_events::testing::arb_eth_address;
     use namada_core::hash::testing::arb_hash;
     use namada_core::storage::testing::arb_epoch;
     use namada_core::token::testing::arb_amount;
@@ -735,6 +1094,55 @@ pub mod testing {
         ]
     }
 
+    prop_compose! {
+        /// Generate an arbitrary Ethereum bridge parameters update
+        pub fn arb_eth_bridge_params_update()(
+            bridge_contract in proptest::option::of(
+                (arb_eth_address(), 1..u64::MAX)
+            ),
+            native_erc20 in proptest::option::of(arb_eth_address()),
+            min_confirmations in proptest::option::of(1..u64::MAX),
+        ) -> EthBridgeParamsUpdate {
+            EthBridgeParamsUpdate {
+                bridge_contract: bridge_contract.map(|(address, version)| {
+                    EthContractUpdate {
+                        address,
+                        version: NonZeroU64::new(version).unwrap(),
+                    }
+                }),
+                native_erc20,
+                min_confirmations: min_confirmations.and_then(NonZeroU64::new),
+            }
+        }
+    }
+
+    /// Generate an arbitrary protocol parameter change
+    pub fn arb_parameter_change() -> impl Strategy<Value = ParameterChange> {
+        prop_oneof![
+            any::<u64>().prop_map(ParameterChange::MinEpochNumBlocks),
+            any::<u64>().prop_map(|secs| ParameterChange::MinEpochDuration(
+                DurationSecs(secs)
+            )),
+            any::<u64>().prop_map(ParameterChange::MaxBlockGas),
+            (
+                arb_non_internal_address(),
+                proptest::option::of(arb_amount())
+            )
+                .prop_map(|(token, amount)| {
+                    ParameterChange::MinimumGasPrice { token, amount }
+                }),
+            arb_dec().prop_map(ParameterChange::PosMaxInflationRate),
+            arb_dec().prop_map(ParameterChange::PosTargetStakedRatio),
+            arb_dec().prop_map(ParameterChange::PosLivenessThreshold),
+            (arb_non_internal_address(), proptest::option::of(arb_dec()))
+                .prop_map(|(token, rate)| {
+                    ParameterChange::FeeConversionRate { token, rate }
+                }),
+            proptest::option::of(arb_non_internal_address())
+                .prop_map(ParameterChange::FeePriceFeed),
+        ]
+    }
+
     /// Generate an arbitrary proposal type
     pub fn arb_proposal_type() -> impl Strategy<Value = ProposalType> {
         prop_oneof![
@@ -747,6 +1155,10 @@ pub mod testing {
             .prop_map(ProposalType::PGFSteward),
             collection::btree_set(arb_pgf_action(), 0..10)
                 .prop_map(ProposalType::PGFPayment),
+            arb_eth_bridge_params_update()
+                .prop_map(ProposalType::EthBridgeParams),
+            collection::btree_set(arb_parameter_change(), 0..10)
+                .prop_map(ProposalType::ParameterChanges),
         ]
     }
 
@@ -786,3 +1198,117 @@ pub mod testing {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_parameter_changes() {
+        let native_token = namada_core::address::testing::nam();
+        let token = namada_core::address::testing::btc();
+        let validate =
+            |changes: &[ParameterChange]| -> Result<(), ParameterChangeError> {
+                validate_parameter_changes(changes, &native_token)
+            };
+        let valid = [
+            ParameterChange::MaxBlockGas(20_000_000),
+            ParameterChange::MinimumGasPrice {
+                token: token.clone(),
+                amount: None,
+            },
+            ParameterChange::PosTargetStakedRatio(Dec::one()),
+        ];
+        assert_eq!(validate(&valid), Ok(()));
+
+        assert_eq!(validate(&[]), Err(ParameterChangeError::Empty));
+        assert_eq!(
+            validate(&[
+                ParameterChange::MaxBlockGas(1),
+                ParameterChange::MaxBlockGas(2),
+            ]),
+            Err(ParameterChangeError::Duplicate("max_block_gas".to_string()))
+        );
+        assert_eq!(
+            validate(&[ParameterChange::MinEpochNumBlocks(0)]),
+            Err(ParameterChangeError::InvalidValue(
+                "min_epoch_num_blocks".to_string(),
+                "must be greater than zero".to_string()
+            ))
+        );
+        assert_eq!(
+            validate(&[ParameterChange::MinimumGasPrice {
+                token: native_token.clone(),
+                amount: None,
+            }]),
+            Err(ParameterChangeError::InvalidValue(
+                format!("minimum_gas_price/{native_token}"),
+                "the native token must remain a fee token".to_string()
+            ))
+        );
+        assert_eq!(
+            validate(&[ParameterChange::MinimumGasPrice {
+                token: native_token.clone(),
+                amount: Some(Amount::from_u64(1)),
+            }]),
+            Ok(())
+        );
+        assert!(matches!(
+            validate(&[ParameterChange::PosLivenessThreshold(Dec::two())]),
+            Err(ParameterChangeError::InvalidValue(..))
+        ));
+        assert!(matches!(
+            validate(&[ParameterChange::FeeConversionRate {
+                token: token.clone(),
+                rate: Some(Dec::zero()),
+            }]),
+            Err(ParameterChangeError::InvalidValue(..))
+        ));
+        assert!(matches!(
+            validate(&[ParameterChange::FeeConversionRate {
+                token: token.clone(),
+                rate: Some(
+                    Dec::from(namada_parameters::MAX_FEE_CONVERSION_RATE)
+                        + Dec::one()
+                ),
+            }]),
+            Err(ParameterChangeError::InvalidValue(..))
+        ));
+        let mut gas_cost_table = GasCostTable::default();
+        gas_cost_table.version += 1;
+        assert_eq!(
+            validate(&[
+                ParameterChange::GasCostTable(gas_cost_table.clone()),
+                ParameterChange::UpgradeHeight(BlockHeight(100)),
+            ]),
+            Ok(())
+        );
+        gas_cost_table.host_fns.verify_tx_sig = 0;
+        assert!(matches!(
+            validate(&[ParameterChange::GasCostTable(gas_cost_table)]),
+            Err(ParameterChangeError::InvalidValue(..))
+        ));
+        assert!(matches!(
+            validate(&[ParameterChange::UpgradeHeight(BlockHeight(0))]),
+            Err(ParameterChangeError::InvalidValue(..))
+        ));
+        assert_eq!(
+            validate(&[
+                ParameterChange::FeeConversionRate {
+                    token: token.clone(),
+                    rate: Some(Dec::two()),
+                },
+                ParameterChange::FeePriceFeed(Some(token)),
+            ]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_parameter_change_json() {
+        let change: ParameterChange =
+            serde_json::from_str(r#"{"key": "max_block_gas", "value": 100}"#)
+                .unwrap();
+        assert_eq!(change, ParameterChange::MaxBlockGas(100));
+    }
+}
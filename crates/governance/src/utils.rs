@@ -12,10 +12,20 @@ use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 
-use super::storage::proposal::ProposalType;
+use super::storage::proposal::{ProposalType, StorageProposal};
 use super::storage::vote::ProposalVote;
 
 /// Proposal status
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
 pub enum ProposalStatus {
     /// Pending proposal status
     Pending,
@@ -35,6 +45,19 @@ impl Display for ProposalStatus {
     }
 }
 
+/// Summary of a governance proposal, including its status and the result of
+/// its tally
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer)]
+pub struct ProposalSummary {
+    /// The proposal, as stored on chain
+    pub proposal: StorageProposal,
+    /// The status of the proposal at the epoch it was queried in
+    pub status: ProposalStatus,
+    /// The result of the proposal, available once its votes have been
+    /// tallied
+    pub result: Option<ProposalResult>,
+}
+
 /// Alias to comulate voting power
 pub type VotePower = token::Amount;
 
@@ -92,6 +115,8 @@ impl TallyType {
             (ProposalType::PGFPayment(_), false) => {
                 TallyType::OneHalfOverOneThird
             }
+            (ProposalType::EthBridgeParams(_), _) => TallyType::TwoThirds,
+            (ProposalType::ParameterChanges(_), _) => TallyType::TwoThirds,
         }
     }
 }
@@ -240,6 +265,57 @@ impl ProposalResult {
         })()
         .unwrap_or_default()
     }
+
+    /// Return true if enough voting power took part in the tally for the
+    /// outcome of the proposal to be decided by its votes, i.e. at least 2/3
+    /// of the total voting power for [`TallyType::TwoThirds`] proposals and at
+    /// least 1/3 otherwise. Returns `false` if any arithmetic fails.
+    pub fn quorum_met(&self) -> bool {
+        (|| {
+            let quorum = match self.tally_type {
+                TallyType::TwoThirds => Dec::two_thirds(),
+                TallyType::OneHalfOverOneThird
+                | TallyType::LessOneHalfOverOneThirdNay => Dec::one_third(),
+            };
+            let quorum_power = self.total_voting_power.mul_ceil(quorum)?;
+            let quorum_met = checked!(
+                self.total_yay_power
+                    + self.total_nay_power
+                    + self.total_abstain_power
+                    >= quorum_power
+            )?;
+
+            Ok::<bool, arith::Error>(quorum_met)
+        })()
+        .unwrap_or_default()
+    }
+
+    /// Return true if the nay votes amount to at least the given `fraction`
+    /// of the total voting power. Returns `false` if any arithmetic fails.
+    pub fn nay_over_total_fraction(&self, fraction: Dec) -> bool {
+        self.total_voting_power
+            .mul_ceil(fraction)
+            .map(|threshold| self.total_nay_power >= threshold)
+            .unwrap_or_default()
+    }
+}
+
+/// Destination of the funds locked by a proposal, once it has been tallied
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProposalFundsDestination {
+    /// The funds are refunded to the given address
+    Refund(Address),
+    /// The funds are burned
+    Burn,
+}
+
+impl Display for ProposalFundsDestination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProposalFundsDestination::Refund(address) => write!(f, "{address}"),
+            ProposalFundsDestination::Burn => write!(f, "burn"),
+        }
+    }
 }
 
 impl Display for ProposalResult {
@@ -1418,6 +1494,84 @@ mod test {
         assert!(!proposal_result.two_thirds_nay_over_two_thirds_total())
     }
 
+    #[test]
+    fn test_proposal_funds_destination() {
+        use crate::parameters::ProposalFundsPolicy;
+
+        let author = address::testing::established_address_2();
+        let refund = ProposalFundsDestination::Refund(author.clone());
+        let total_voting_power = token::Amount::from_u64(300);
+
+        let validator_address = address::testing::established_address_1();
+        let mut proposal_votes = ProposalVotes::default();
+        proposal_votes.add_validator(
+            &validator_address,
+            token::Amount::from_u64(50),
+            ProposalVote::Nay,
+        );
+        let rejected = compute_proposal_result(
+            proposal_votes,
+            total_voting_power,
+            TallyType::TwoThirds,
+        )
+        .unwrap();
+        assert!(matches!(rejected.result, TallyResult::Rejected));
+        assert!(!rejected.quorum_met());
+
+        let mut proposal_votes = ProposalVotes::default();
+        proposal_votes.add_validator(
+            &validator_address,
+            token::Amount::from_u64(250),
+            ProposalVote::Yay,
+        );
+        let passed = compute_proposal_result(
+            proposal_votes,
+            total_voting_power,
+            TallyType::TwoThirds,
+        )
+        .unwrap();
+        assert!(matches!(passed.result, TallyResult::Passed));
+
+        let low_spam_threshold = ProposalFundsPolicy::BurnOnSpam {
+            threshold: Dec::from_str("0.1").unwrap(),
+        };
+        let high_spam_threshold = ProposalFundsPolicy::BurnOnSpam {
+            threshold: Dec::from_str("0.5").unwrap(),
+        };
+
+        for policy in [
+            ProposalFundsPolicy::BurnOnReject,
+            ProposalFundsPolicy::RefundOnQuorumNotMet,
+            low_spam_threshold,
+            high_spam_threshold,
+        ] {
+            assert_eq!(
+                policy.funds_destination(author.clone(), &passed),
+                refund,
+                "{policy:?}"
+            );
+        }
+
+        assert_eq!(
+            ProposalFundsPolicy::BurnOnReject
+                .funds_destination(author.clone(), &rejected),
+            ProposalFundsDestination::Burn
+        );
+        assert_eq!(
+            ProposalFundsPolicy::RefundOnQuorumNotMet
+                .funds_destination(author.clone(), &rejected),
+            refund
+        );
+        assert_eq!(
+            low_spam_threshold.funds_destination(author.clone(), &rejected),
+            ProposalFundsDestination::Burn
+        );
+        assert_eq!(
+            high_spam_threshold.funds_destination(author, &rejected),
+            refund
+        );
+    }
+
     #[test]
     fn test_validator_voting_period() {
         assert!(!is_valid_validator_voting_period(
@@ -1,11 +1,10 @@
 use std::str::FromStr;
 
-use namada_sdk::error::{EncodingError, Error, TxSubmitError};
+use namada_sdk::error::{Error, TxSubmitError};
 use namada_sdk::queries::Client;
 use namada_sdk::tx::Tx;
 use tendermint_config::net::Address as TendermintAddress;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
-use tendermint_rpc::error::Error as RpcError;
 use tendermint_rpc::HttpClient;
 
 /// Broadcast a transaction to be included in the blockchain. This
@@ -36,10 +35,9 @@ pub async fn broadcast_tx(
     if response.code == 0.into() {
         Ok(response)
     } else {
-        Err(Error::from(TxSubmitError::TxBroadcast(RpcError::server(
-            serde_json::to_string(&response).map_err(|err| {
-                Error::from(EncodingError::Serde(err.to_string()))
-            })?,
-        ))))
+        Err(Error::from(TxSubmitError::MempoolRejected(
+            response.code.value(),
+            response.log,
+        )))
     }
 }
@@ -135,6 +135,8 @@ pub enum Error {
         "The merklized data did not produce that same hash as the stored root."
     )]
     RootValidationError,
+    #[error("Invalid storage proof: {0}")]
+    InvalidProof(String),
 }
 
 /// Result for functions that may fail
@@ -1007,6 +1009,102 @@ impl From<Proof> for namada_core::tendermint::merkle::proof::ProofOps {
     }
 }
 
+/// Verify a storage proof, as returned by a `storage_value` query, against
+/// the given root of the merkle tree. A `None` value is verified with a
+/// non-existence proof, which only the IBC sub-tree can provide.
+pub fn verify_storage_proof<H: StorageHasher>(
+    key: &Key,
+    value: Option<&[u8]>,
+    proof: &namada_core::tendermint::merkle::proof::ProofOps,
+    root: &MerkleRoot,
+) -> Result<()> {
+    use ics23::HostFunctionsManager;
+    use namada_core::tendermint::merkle::proof::ProofOp;
+    use prost::Message;
+
+    let decode_op = |op: &ProofOp| {
+        CommitmentProof::decode(&op.data[..])
+            .map_err(|err| Error::InvalidProof(err.to_string()))
+    };
+    let existence_root = |ep: &ExistenceProof| {
+        ics23::calculate_existence_root::<HostFunctionsManager>(ep)
+            .map_err(|err| Error::InvalidProof(err.to_string()))
+    };
+
+    let [sub_proof, base_proof] = proof.ops.as_slice() else {
+        return Err(Error::InvalidProof(format!(
+            "Expected a sub-tree and a base tree proof, got {} proof ops",
+            proof.ops.len()
+        )));
+    };
+    let sub_proof = decode_op(sub_proof)?;
+    let base_proof = decode_op(base_proof)?;
+
+    let (store_type, sub_key) = StoreType::sub_key(key)?;
+    let specs = if store_type == StoreType::Ibc {
+        ics23_specs::ibc_proof_specs::<H>()
+    } else {
+        ics23_specs::proof_specs::<H>()
+    };
+    let sub_key = sub_key.to_string();
+
+    // First, the sub proof is verified. Next the base proof is verified
+    // with the sub root
+    let sub_root = match (&sub_proof.proof, value) {
+        (Some(Ics23Proof::Exist(ep)), Some(value)) => {
+            let sub_root = existence_root(ep)?;
+            ics23::verify_membership::<HostFunctionsManager>(
+                &sub_proof,
+                &specs[0],
+                &sub_root,
+                sub_key.as_bytes(),
+                value,
+            )
+            .then_some(sub_root)
+        }
+        (Some(Ics23Proof::Nonexist(nep)), None) => {
+            let ep =
+                nep.left.as_ref().or(nep.right.as_ref()).ok_or_else(|| {
+                    Error::InvalidProof(
+                        "The non-existence proof has no neighbors".to_string(),
+                    )
+                })?;
+            let sub_root = existence_root(ep)?;
+            ics23::verify_non_membership::<HostFunctionsManager>(
+                &sub_proof,
+                &specs[0],
+                &sub_root,
+                sub_key.as_bytes(),
+            )
+            .then_some(sub_root)
+        }
+        _ => None,
+    }
+    .ok_or_else(|| {
+        Error::InvalidProof(format!("The sub-tree proof of {key} is invalid"))
+    })?;
+
+    let Some(Ics23Proof::Exist(base_ep)) = &base_proof.proof else {
+        return Err(Error::InvalidProof(
+            "The base tree proof must be an existence proof".to_string(),
+        ));
+    };
+    let base_root = existence_root(base_ep)?;
+    let verified = ics23::verify_membership::<HostFunctionsManager>(
+        &base_proof,
+        &specs[1],
+        &base_root,
+        store_type.to_string().as_bytes(),
+        &sub_root,
+    );
+    if !verified || base_root != root.0 {
+        return Err(Error::InvalidProof(format!(
+            "The proof of {key} does not match the merkle root"
+        )));
+    }
+    Ok(())
+}
+
 impl<'a, H: StorageHasher + Default> SubTreeRead for &'a Smt<H> {
     fn root(&self) -> MerkleRoot {
         Smt::<H>::root(self).into()
@@ -1260,6 +1358,7 @@ mod test {
     use assert_matches::assert_matches;
     use ics23::HostFunctionsManager;
     use namada_core::hash::Sha256Hasher;
+    use namada_core::tendermint::merkle::proof::ProofOps;
 
     use super::*;
     use crate::ics23_specs::{ibc_proof_specs, proof_specs};
@@ -1611,4 +1710,88 @@ mod test {
             );
         assert!(basetree_verification_res);
     }
+
+    #[test]
+    fn test_verify_storage_proof() {
+        let mut tree = MerkleTree::<Sha256Hasher>::default();
+
+        let key_prefix: Key =
+            Address::Internal(InternalAddress::Ibc).to_db_key().into();
+        let ibc_key = key_prefix.push(&"test".to_string()).unwrap();
+        let ibc_non_key = key_prefix.push(&"test2".to_string()).unwrap();
+        let key_prefix: Key =
+            Address::Internal(InternalAddress::PoS).to_db_key().into();
+        let pos_key = key_prefix.push(&"test".to_string()).unwrap();
+
+        let ibc_val = [1u8; 8].to_vec();
+        tree.update(&ibc_key, ibc_val.clone()).unwrap();
+        let pos_val = [2u8; 8].to_vec();
+        tree.update(&pos_key, pos_val.clone()).unwrap();
+        let root = tree.root();
+
+        let existence_proof = |key: &Key, value: &[u8]| {
+            let proof = match tree
+                .get_sub_tree_existence_proof(
+                    std::array::from_ref(key),
+                    vec![value],
+                )
+                .unwrap()
+            {
+                MembershipProof::ICS23(proof) => proof,
+                _ => panic!("Test failed"),
+            };
+            ProofOps::from(tree.get_sub_tree_proof(key, proof).unwrap())
+        };
+
+        // existence proofs of both kinds of sub-trees are verified
+        for (key, value) in [(&ibc_key, &ibc_val), (&pos_key, &pos_val)] {
+            let proof = existence_proof(key, value);
+            verify_storage_proof::<Sha256Hasher>(
+                key,
+                Some(value),
+                &proof,
+                &root,
+            )
+            .unwrap();
+            // a different value or an absent one is rejected
+            assert_matches!(
+                verify_storage_proof::<Sha256Hasher>(
+                    key,
+                    Some(&[3u8; 8]),
+                    &proof,
+                    &root,
+                ),
+                Err(Error::InvalidProof(_))
+            );
+            assert_matches!(
+                verify_storage_proof::<Sha256Hasher>(key, None, &proof, &root),
+                Err(Error::InvalidProof(_))
+            );
+            // so is a different root
+            assert_matches!(
+                verify_storage_proof::<Sha256Hasher>(
+                    key,
+                    Some(value),
+                    &proof,
+                    &MerkleRoot([0u8; 32]),
+                ),
+                Err(Error::InvalidProof(_))
+            );
+        }
+
+        // non-existence proofs of IBC keys are verified
+        let proof: ProofOps =
+            tree.get_non_existence_proof(&ibc_non_key).unwrap().into();
+        verify_storage_proof::<Sha256Hasher>(&ibc_non_key, None, &proof, &root)
+            .unwrap();
+        assert_matches!(
+            verify_storage_proof::<Sha256Hasher>(
+                &ibc_non_key,
+                Some(&ibc_val),
+                &proof,
+                &root,
+            ),
+            Err(Error::InvalidProof(_))
+        );
+    }
 }
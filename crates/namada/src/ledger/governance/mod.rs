@@ -3,12 +3,15 @@
 pub mod utils;
 
 use std::collections::BTreeSet;
+use std::num::NonZeroU64;
 
 use borsh::BorshDeserialize;
 use namada_core::arith::{self, checked};
 use namada_core::booleans::{BoolResultUnitExt, ResultBoolExt};
+use namada_governance::parameters::ProposalFundsPolicy;
 use namada_governance::storage::proposal::{
-    AddRemove, PGFAction, ProposalType,
+    validate_parameter_changes, AddRemove, PGFAction, ParameterChange,
+    ProposalType,
 };
 use namada_governance::storage::{is_proposal_accepted, keys as gov_storage};
 use namada_governance::utils::is_valid_validator_voting_period;
@@ -22,6 +25,8 @@ use thiserror::Error;
 
 use self::utils::ReadType;
 use crate::address::{Address, InternalAddress};
+use crate::eth_bridge::storage::bridge_contract_key;
+use crate::eth_bridge::storage::parameters::UpgradeableContract;
 use crate::ledger::native_vp::{Ctx, NativeVp};
 use crate::ledger::{native_vp, pos};
 use crate::storage::{Epoch, Key};
@@ -179,7 +184,9 @@ where
                 (KeyType::PROPOSAL_COMMIT, _) => {
                     self.is_valid_proposal_commit()
                 }
-                (KeyType::PARAMETER, _) => self.is_valid_parameter(tx_data),
+                (KeyType::PARAMETER, _) => {
+                    self.is_valid_parameter(tx_data, key)
+                }
                 (KeyType::BALANCE, _) => self.is_valid_balance(&native_token),
                 (KeyType::UNKNOWN_GOVERNANCE, _) => {
                     Err(native_vp::Error::new_alloc(format!(
@@ -559,6 +566,63 @@ where
                     .into()
                 })
             }
+            ProposalType::EthBridgeParams(update) => {
+                if update.is_empty() {
+                    return Err(native_vp::Error::new_const(
+                        "Ethereum bridge parameters proposals must update at \
+                         least one parameter",
+                    )
+                    .into());
+                }
+                let Some(contract) = update.bridge_contract else {
+                    return Ok(());
+                };
+                // the Bridge contract may only be replaced by a newer
+                // version of it
+                let current_contract: Option<UpgradeableContract> =
+                    self.ctx.pre().read(&bridge_contract_key())?;
+                match current_contract {
+                    Some(current)
+                        if contract.version
+                            <= NonZeroU64::from(current.version) =>
+                    {
+                        Err(native_vp::Error::new_alloc(format!(
+                            "The version of the new Bridge contract ({}) must \
+                             be greater than the current one ({})",
+                            contract.version,
+                            NonZeroU64::from(current.version)
+                        ))
+                        .into())
+                    }
+                    _ => Ok(()),
+                }
+            }
+            ProposalType::ParameterChanges(changes) => {
+                let native_token = self.ctx.pre().get_native_token()?;
+                validate_parameter_changes(&changes, &native_token).map_err(
+                    |err| {
+                        native_vp::Error::new_alloc(format!(
+                            "Invalid parameter changes proposal: {err}"
+                        ))
+                    },
+                )?;
+                // A new gas cost table must already have a greater version
+                // than the current one. It's checked again on execution.
+                let current_gas_costs =
+                    namada_parameters::read_vm_gas_cost_table(&self.ctx.pre())?;
+                changes.iter().try_for_each(|change| match change {
+                    ParameterChange::GasCostTable(table) => table
+                        .validate_update(&current_gas_costs)
+                        .map_err(|err| {
+                            native_vp::Error::new_alloc(format!(
+                                "Invalid gas cost table in a parameter \
+                                 changes proposal: {err}"
+                            ))
+                            .into()
+                        }),
+                    _ => Ok(()),
+                })
+            }
             // Default proposal condition are checked already for all other
             // proposals.
             // default_with_wasm proposal needs to check only for valid code
@@ -1066,6 +1130,7 @@ where
     pub fn is_valid_parameter(
         &self,
         batched_tx: &BatchedTxRef<'_>,
+        key: &Key,
     ) -> Result<()> {
         let BatchedTxRef { tx, cmt } = batched_tx;
         tx.data(cmt).map_or_else(
@@ -1088,7 +1153,29 @@ where
                         .into()
                     })
             },
-        )
+        )?;
+        if gov_storage::is_proposal_funds_policy_key(key) {
+            self.is_valid_proposal_funds_policy()?;
+        }
+        Ok(())
+    }
+
+    /// Validate the new proposal funds policy parameter
+    pub fn is_valid_proposal_funds_policy(&self) -> Result<()> {
+        let policy: Option<ProposalFundsPolicy> = self
+            .ctx
+            .post()
+            .read(&gov_storage::get_proposal_funds_policy_key())?;
+        match policy {
+            Some(policy) if !policy.is_valid() => {
+                Err(native_vp::Error::new_alloc(format!(
+                    "Invalid proposal funds policy {policy:?}: the spam \
+                     threshold must be within [0, 1]"
+                ))
+                .into())
+            }
+            _ => Ok(()),
+        }
     }
 
     /// Check if a vote is from a validator
@@ -1245,15 +1332,22 @@ impl KeyType {
 mod test {
     use std::cell::RefCell;
     use std::collections::BTreeSet;
+    use std::num::NonZeroU64;
+    use std::str::FromStr;
 
     use borsh_ext::BorshSerializeExt;
     use namada_gas::{TxGasMeter, VpGasMeter};
+    use namada_governance::parameters::ProposalFundsPolicy;
     use namada_governance::storage::keys::{
         get_activation_epoch_key, get_author_key, get_committing_proposals_key,
-        get_content_key, get_counter_key, get_funds_key, get_proposal_type_key,
-        get_vote_proposal_key, get_voting_end_epoch_key,
+        get_content_key, get_counter_key, get_funds_key,
+        get_proposal_execution_key, get_proposal_funds_policy_key,
+        get_proposal_type_key, get_vote_proposal_key, get_voting_end_epoch_key,
         get_voting_start_epoch_key,
     };
+    use namada_governance::storage::proposal::{
+        EthBridgeParamsUpdate, EthContractUpdate,
+    };
     use namada_governance::{ProposalType, ProposalVote, ADDRESS};
     use namada_proof_of_stake::bond_tokens;
     use namada_sdk::address::testing::{
@@ -1275,6 +1369,10 @@ mod test {
     use namada_tx::{Authorization, Code, Data, Section, Tx};
 
     use crate::core::address::Address;
+    use crate::core::dec::Dec;
+    use crate::core::ethereum_events::EthAddress;
+    use crate::eth_bridge::storage::bridge_contract_key;
+    use crate::eth_bridge::storage::parameters::UpgradeableContract;
     use crate::ledger::governance::GovernanceVp;
     use crate::ledger::native_vp::ibc::{
         get_dummy_genesis_validator, get_dummy_header,
@@ -3073,4 +3171,143 @@ mod test {
             Err(_)
         );
     }
+
+    #[test]
+    fn test_governance_eth_bridge_contract_version() {
+        let mut state = init_storage();
+
+        let proposal_id = 0;
+        let keys_changed = BTreeSet::from([get_proposal_type_key(proposal_id)]);
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::cache();
+        let tx_index = TxIndex::default();
+        let verifiers = BTreeSet::new();
+
+        let current = UpgradeableContract {
+            address: EthAddress([1; 20]),
+            version: NonZeroU64::new(2).unwrap().into(),
+        };
+        state
+            .write_log_mut()
+            .write(&bridge_contract_key(), current.serialize_to_vec())
+            .unwrap();
+        state.write_log_mut().commit_tx();
+        state.commit_block().unwrap();
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        let batched_tx = tx.batch_ref_first_tx();
+
+        for (version, is_valid) in [(1, false), (2, false), (3, true)] {
+            let proposal_type =
+                ProposalType::EthBridgeParams(EthBridgeParamsUpdate {
+                    bridge_contract: Some(EthContractUpdate {
+                        address: EthAddress([2; 20]),
+                        version: NonZeroU64::new(version).unwrap(),
+                    }),
+                    native_erc20: None,
+                    min_confirmations: None,
+                });
+            state
+                .write_log_mut()
+                .write(
+                    &get_proposal_type_key(proposal_id),
+                    proposal_type.serialize_to_vec(),
+                )
+                .unwrap();
+
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache.clone(),
+            );
+            let governance_vp = GovernanceVp { ctx };
+
+            assert_eq!(
+                governance_vp.is_valid_proposal_type(proposal_id).is_ok(),
+                is_valid,
+                "Unexpected validation result for contract version {version}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_governance_proposal_funds_policy_threshold() {
+        let mut state = init_storage();
+
+        let proposal_id = 0_u64;
+        let policy_key = get_proposal_funds_policy_key();
+        let keys_changed = BTreeSet::from([policy_key.clone()]);
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::cache();
+        let tx_index = TxIndex::default();
+        let verifiers = BTreeSet::new();
+
+        // The parameter change is applied by an accepted proposal
+        state
+            .write_log_mut()
+            .write(&get_proposal_execution_key(proposal_id), vec![])
+            .unwrap();
+        state.write_log_mut().commit_tx();
+        state.commit_block().unwrap();
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(proposal_id.serialize_to_vec()));
+        let batched_tx = tx.batch_ref_first_tx();
+
+        for (threshold, is_valid) in [
+            ("0", true),
+            ("0.5", true),
+            ("1", true),
+            ("-0.1", false),
+            ("1.1", false),
+        ] {
+            let policy = ProposalFundsPolicy::BurnOnSpam {
+                threshold: Dec::from_str(threshold).unwrap(),
+            };
+            state
+                .write_log_mut()
+                .write(&policy_key, policy.serialize_to_vec())
+                .unwrap();
+
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache.clone(),
+            );
+            let governance_vp = GovernanceVp { ctx };
+
+            assert_eq!(
+                governance_vp
+                    .is_valid_parameter(&batched_tx, &policy_key)
+                    .is_ok(),
+                is_valid,
+                "Unexpected validation result for threshold {threshold}"
+            );
+        }
+    }
 }
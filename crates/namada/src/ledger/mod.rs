@@ -279,6 +279,12 @@ mod test {
         let current_epoch = client.state.in_mem().last_epoch;
         assert_eq!(current_epoch, read_epoch);
 
+        // Request the protocol parameters
+        namada_parameters::init_test_storage(&mut client.state).unwrap();
+        let read_params = RPC.shell().parameters(&client).await.unwrap();
+        let params = namada_parameters::read(&client.state).unwrap();
+        assert_eq!(params, read_params);
+
         // Request dry run tx
         let mut outer_tx = Tx::from_type(TxType::Raw);
         outer_tx.header.chain_id = client.state.in_mem().chain_id.clone();
@@ -366,6 +372,26 @@ mod test {
             .unwrap();
         assert!(has_balance_key);
 
+        // Request the values of multiple keys at once
+        let other_balance_key = token::storage_key::balance_key(
+            &token_addr,
+            &address::testing::established_address_3(),
+        );
+        let read_values = RPC
+            .shell()
+            .storage_values(
+                &client,
+                Some(vec![&balance_key, &other_balance_key].serialize_to_vec()),
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            read_values.data,
+            vec![Some(balance.serialize_to_vec()), None]
+        );
+
         Ok(())
     }
 }
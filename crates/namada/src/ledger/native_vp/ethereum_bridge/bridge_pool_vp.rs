@@ -10,6 +10,17 @@
 //! correctly. This means that the appropriate data is
 //! added to the pool and gas fees are submitted appropriately
 //! and that tokens to be transferred are escrowed.
+//!
+//! Transfers may also be cancelled by their sender, once they
+//! have sat in the pool for a configurable number of blocks, and
+//! as long as they are not part of a batch relayed to Ethereum
+//! that is still being confirmed. In this case, the VP checks that
+//! the escrowed gas fees and tokens are refunded.
+//!
+//! The gas fees of a pending transfer may be topped up by their
+//! payer, to make the transfer more attractive to relayers. The
+//! topped up fees are escrowed separately from the transfer, so
+//! that its hash remains unchanged.
 
 use std::borrow::Cow;
 use std::collections::BTreeSet;
@@ -22,20 +33,29 @@ use namada_core::booleans::BoolResultUnitExt;
 use namada_core::eth_bridge_pool::erc20_token_address;
 use namada_core::hints;
 use namada_ethereum_bridge::storage::bridge_pool::{
+    get_epoch_utilization_key, get_fee_top_up_key, get_key_from_hash,
     get_pending_key, is_bridge_pool_key, BRIDGE_POOL_ADDRESS,
 };
 use namada_ethereum_bridge::storage::eth_bridge_queries::is_bridge_active_at;
-use namada_ethereum_bridge::storage::parameters::read_native_erc20_address;
+use namada_ethereum_bridge::storage::parameters::{
+    read_bridge_pool_cancel_delay, read_native_erc20_address,
+};
+use namada_ethereum_bridge::storage::vote_tallies::{eth_msgs_prefix, Keys};
 use namada_ethereum_bridge::storage::whitelist;
 use namada_ethereum_bridge::ADDRESS as BRIDGE_ADDRESS;
 use namada_state::{ResultExt, StateRead};
+use namada_tx::action::{Action, EthBridgeAction, Read};
 use namada_tx::BatchedTxRef;
 
 use crate::address::{Address, InternalAddress};
-use crate::eth_bridge_pool::{PendingTransfer, TransferToEthereumKind};
-use crate::ethereum_events::EthAddress;
-use crate::ledger::native_vp::{self, Ctx, NativeVp, StorageReader};
-use crate::storage::Key;
+use crate::eth_bridge_pool::{
+    EpochUtilization, PendingTransfer, TransferFeeTopUp, TransferToEthereumKind,
+};
+use crate::ethereum_events::{
+    EthAddress, EthereumEvent, TransferToEthereum as TransferToEthereumEvent,
+};
+use crate::ledger::native_vp::{self, Ctx, NativeVp, StorageReader, VpEnv};
+use crate::storage::{BlockHeight, DbKeySeg, Key};
 use crate::token::storage_key::balance_key;
 use crate::token::Amount;
 use crate::uint::I320;
@@ -221,6 +241,29 @@ where
         Ok(true)
     }
 
+    /// Check that the NAM escrowed under the Ethereum bridge address
+    /// was left untouched. This escrow may only change with wrapped
+    /// NAM transfers, whose amounts are checked separately.
+    fn check_nam_escrow_unchanged(&self) -> Result<(), Error> {
+        let escrow_key =
+            balance_key(&self.ctx.state.in_mem().native_token, &BRIDGE_ADDRESS);
+        let escrow_pre: Amount =
+            (&self.ctx).read_pre_value(&escrow_key)?.unwrap_or_default();
+        let escrow_post: Amount = (&self.ctx)
+            .read_post_value(&escrow_key)?
+            .unwrap_or_default();
+        if escrow_pre != escrow_post {
+            let error = native_vp::Error::new_const(
+                "The NAM escrowed under the Ethereum bridge address may only \
+                 change with wrapped NAM transfers",
+            )
+            .into();
+            tracing::debug!("{error}");
+            return Err(error);
+        }
+        Ok(())
+    }
+
     /// Validate a wrapped NAM transfer to Ethereum.
     fn check_wnam_escrow(
         &self,
@@ -296,6 +339,371 @@ where
         Ok(true)
     }
 
+    /// Validate that the amount of an ERC20 asset queued in the
+    /// Bridge pool during the current epoch was updated, and that
+    /// it does not exceed the epoch cap of the asset.
+    fn check_epoch_utilization(
+        &self,
+        transfer: &PendingTransfer,
+        keys_changed: &BTreeSet<Key>,
+    ) -> Result<(), Error> {
+        let asset = &transfer.transfer.asset;
+        let utilization_key = get_epoch_utilization_key(asset);
+        if !keys_changed.contains(&utilization_key) {
+            let error = native_vp::Error::new_alloc(format!(
+                "The Bridge pool utilization of {asset} was not updated",
+            ))
+            .into();
+            tracing::debug!(?transfer, "{error}");
+            return Err(error);
+        }
+        let epoch = self.ctx.get_block_epoch()?;
+        let pre: EpochUtilization = (&self.ctx)
+            .read_pre_value(&utilization_key)?
+            .unwrap_or_default();
+        let post: Option<EpochUtilization> =
+            (&self.ctx).read_post_value(&utilization_key)?;
+        let expected =
+            pre.queue(epoch, transfer.transfer.amount).ok_or_else(|| {
+                Error(native_vp::Error::SimpleMessage(
+                    "Overflow while updating the Bridge pool utilization",
+                ))
+            })?;
+        if post.as_ref() != Some(&expected) {
+            let error = native_vp::Error::new_alloc(format!(
+                "Invalid Bridge pool utilization of {asset}: {post:?}.\n \
+                 Expected: {expected:?}",
+            ))
+            .into();
+            tracing::debug!("{error}");
+            return Err(error);
+        }
+        let epoch_cap_key: Key = whitelist::Key {
+            asset: *asset,
+            suffix: whitelist::KeyType::EpochCap,
+        }
+        .into();
+        if let Some(cap) =
+            (&self.ctx).read_pre_value::<Amount>(&epoch_cap_key)?
+        {
+            if expected.amount > cap {
+                let error = native_vp::Error::new_alloc(format!(
+                    "Queueing {} of {asset} would exceed its epoch cap of {} \
+                     in epoch {epoch}",
+                    transfer.transfer.amount.to_string_native(),
+                    cap.to_string_native(),
+                ))
+                .into();
+                tracing::debug!(?transfer, "{error}");
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if the given transfer is part of a batch relayed to Ethereum,
+    /// whose [`EthereumEvent::TransfersToEthereum`] has been voted on by
+    /// Namada validators, but not confirmed yet.
+    fn is_transfer_in_flight(
+        &self,
+        transfer: &PendingTransfer,
+    ) -> Result<bool, Error> {
+        let relayed = TransferToEthereumEvent::from(transfer);
+        let body_segment = Keys::segments().body;
+        let pre = self.ctx.pre();
+        let events = namada_state::iter_prefix_with_filter::<EthereumEvent, _>(
+            &pre,
+            &eth_msgs_prefix(),
+            |key| {
+                matches!(
+                    key.last(),
+                    Some(DbKeySeg::StringSeg(segment))
+                        if segment == body_segment
+                )
+            },
+        )?;
+        for event in events {
+            let (_, event) = event?;
+            let EthereumEvent::TransfersToEthereum { transfers, .. } = &event
+            else {
+                continue;
+            };
+            if !transfers.contains(&relayed) {
+                continue;
+            }
+            let seen: bool = (&self.ctx)
+                .read_pre_value(&Keys::from(&event).seen())?
+                .unwrap_or_default();
+            if !seen {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Validate the cancellation of a transfer in the Bridge pool.
+    fn check_cancelled_transfer(
+        &self,
+        wnam_address: &EthAddress,
+        transfer: &PendingTransfer,
+        keys_changed: &BTreeSet<Key>,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<(), Error> {
+        let pending_key = get_pending_key(transfer);
+        if (&self.ctx)
+            .read_post_value::<PendingTransfer>(&pending_key)?
+            .is_some()
+        {
+            let error = native_vp::Error::new_const(
+                "Rejecting transaction as the transfer is already in the \
+                 Ethereum bridge pool.",
+            )
+            .into();
+            tracing::debug!("{error}");
+            return Err(error);
+        }
+
+        // only the sender of a transfer may cancel it
+        let sender = &transfer.transfer.sender;
+        let authorized = verifiers.contains(sender)
+            && self.ctx.read_actions()?.into_iter().any(|action| {
+                matches!(
+                    action,
+                    Action::EthBridge(EthBridgeAction::CancelPoolTransfer(
+                        ref source
+                    )) if source == sender
+                )
+            });
+        if !authorized {
+            let error = native_vp::Error::new_alloc(format!(
+                "The cancellation of the transfer was not authorized by its \
+                 sender {sender}",
+            ))
+            .into();
+            tracing::debug!(?transfer, "{error}");
+            return Err(error);
+        }
+
+        // the transfer must have been in the pool for long enough
+        let inserted_height = self
+            .ctx
+            .state
+            .in_mem()
+            .block
+            .tree
+            .get(&pending_key)
+            .map_err(|e| {
+                native_vp::Error::new_alloc(format!(
+                    "Could not read the insertion height of the transfer: {e}"
+                ))
+            })
+            .and_then(|bytes| {
+                BlockHeight::try_from_slice(&bytes).into_storage_result()
+            })?;
+        let cancel_delay: u64 =
+            read_bridge_pool_cancel_delay(&self.ctx.pre())?.into();
+        let cancellable_height =
+            inserted_height.checked_add(cancel_delay).ok_or_else(|| {
+                native_vp::Error::new_const(
+                    "Overflow while computing the cancellation height of the \
+                     transfer",
+                )
+            })?;
+        let current_height = self.ctx.get_block_height()?;
+        if current_height < cancellable_height {
+            let error = native_vp::Error::new_alloc(format!(
+                "The transfer can only be cancelled from height \
+                 {cancellable_height} onwards, but the current height is \
+                 {current_height}",
+            ))
+            .into();
+            tracing::debug!(?transfer, "{error}");
+            return Err(error);
+        }
+
+        // a transfer that was relayed to Ethereum in a batch that has not
+        // been acknowledged by Namada yet will be removed from the pool
+        // as soon as the batch is confirmed, so it cannot be cancelled
+        if self.is_transfer_in_flight(transfer)? {
+            let error = native_vp::Error::new_const(
+                "The transfer was relayed to Ethereum in a batch that is \
+                 still being confirmed",
+            )
+            .into();
+            tracing::debug!(?transfer, "{error}");
+            return Err(error);
+        }
+
+        // the topped up gas fees of the transfer must be removed
+        let fee_top_up_key = get_fee_top_up_key(&transfer.keccak256());
+        if (&self.ctx)
+            .read_post_value::<Amount>(&fee_top_up_key)?
+            .is_some()
+        {
+            return Err(native_vp::Error::new_const(
+                "The topped up gas fees of the cancelled transfer were not \
+                 removed",
+            )
+            .into());
+        }
+        let fee_top_up: Amount = (&self.ctx)
+            .read_pre_value(&fee_top_up_key)?
+            .unwrap_or_default();
+        let mut refunded = transfer.clone();
+        refunded.gas_fee.amount =
+            checked!(refunded.gas_fee.amount + fee_top_up)
+                .map_err(|e| Error(e.into()))?;
+
+        // the escrowed gas fees and tokens must be refunded
+        let refund_checks = self
+            .determine_escrow_checks(wnam_address, &refunded)?
+            .reversed();
+        if !refund_checks.validate(keys_changed) {
+            let error = native_vp::Error::new_const(
+                "Invalid storage modifications in the Bridge pool",
+            )
+            .into();
+            tracing::debug!("{error}");
+            return Err(error);
+        }
+        if !self.check_escrowed_toks(refund_checks.gas_check)? {
+            return Err(native_vp::Error::new_const(
+                "The gas fees of the cancelled transfer were not refunded",
+            )
+            .into());
+        }
+        if !self.check_escrowed_toks(refund_checks.token_check)? {
+            return Err(native_vp::Error::new_const(
+                "The tokens of the cancelled transfer were not refunded",
+            )
+            .into());
+        }
+        // only the refund of a wrapped NAM transfer may release
+        // escrowed NAM, which was checked above
+        if &transfer.transfer.asset != wnam_address {
+            self.check_nam_escrow_unchanged()?;
+        }
+        tracing::info!(
+            "The Ethereum bridge pool VP accepted the cancellation of the \
+             transfer {:?}.",
+            transfer
+        );
+        Ok(())
+    }
+
+    /// Validate topping up the gas fees of a transfer in the Bridge pool.
+    fn check_fee_top_up(
+        &self,
+        wnam_address: &EthAddress,
+        top_up: &TransferFeeTopUp,
+        keys_changed: &BTreeSet<Key>,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<(), Error> {
+        if top_up.amount.is_zero() {
+            let error = native_vp::Error::new_const(
+                "Rejecting transaction as the topped up gas fees are nil",
+            )
+            .into();
+            tracing::debug!("{error}");
+            return Err(error);
+        }
+        let fee_top_up_key = get_fee_top_up_key(&top_up.transfer);
+        for key in keys_changed.iter().filter(|k| is_bridge_pool_key(k)) {
+            if *key != fee_top_up_key {
+                let error = native_vp::Error::new_alloc(format!(
+                    "Rejecting transaction as it is attempting to change an \
+                     incorrect key in the Ethereum bridge pool: {key}.\n \
+                     Expected key: {fee_top_up_key}",
+                ))
+                .into();
+                tracing::debug!("{error}");
+                return Err(error);
+            }
+        }
+        let transfer: PendingTransfer = (&self.ctx)
+            .read_pre_value(&get_key_from_hash(&top_up.transfer))?
+            .ok_or_else(|| {
+                Error(native_vp::Error::SimpleMessage(
+                    "Rejecting transaction as the transfer is not in the \
+                     Ethereum bridge pool",
+                ))
+            })?;
+
+        // only the payer of the gas fees of a transfer may top them up
+        let payer = &transfer.gas_fee.payer;
+        let authorized = verifiers.contains(payer)
+            && self.ctx.read_actions()?.into_iter().any(|action| {
+                matches!(
+                    action,
+                    Action::EthBridge(EthBridgeAction::TopUpPoolTransfer(
+                        ref source
+                    )) if source == payer
+                )
+            });
+        if !authorized {
+            let error = native_vp::Error::new_alloc(format!(
+                "Topping up the gas fees of the transfer was not authorized \
+                 by their payer {payer}",
+            ))
+            .into();
+            tracing::debug!(?transfer, "{error}");
+            return Err(error);
+        }
+
+        // the topped up gas fees must be recorded
+        let pre_top_up: Amount = (&self.ctx)
+            .read_pre_value(&fee_top_up_key)?
+            .unwrap_or_default();
+        let post_top_up: Amount = (&self.ctx)
+            .read_post_value(&fee_top_up_key)?
+            .unwrap_or_default();
+        if Some(post_top_up) != pre_top_up.checked_add(top_up.amount) {
+            let error = native_vp::Error::new_alloc(format!(
+                "The topped up gas fees of the transfer were not updated \
+                 correctly: expected {} more than {pre_top_up}, got \
+                 {post_top_up}",
+                top_up.amount,
+            ))
+            .into();
+            tracing::debug!(?transfer, "{error}");
+            return Err(error);
+        }
+
+        // the topped up gas fees must be escrowed
+        let gas_check = EscrowDelta {
+            token: Cow::Borrowed(&transfer.gas_fee.token),
+            payer_account: payer,
+            escrow_account: &BRIDGE_POOL_ADDRESS,
+            expected_debit: top_up.amount,
+            expected_credit: top_up.amount,
+            transferred_amount: &top_up.amount,
+            _kind: PhantomData,
+        };
+        if !gas_check.validate(keys_changed) {
+            let error = native_vp::Error::new_const(
+                "Invalid storage modifications in the Bridge pool",
+            )
+            .into();
+            tracing::debug!("{error}");
+            return Err(error);
+        }
+        if !self.check_gas_escrow(wnam_address, &transfer, gas_check)? {
+            return Err(native_vp::Error::new_const(
+                "The topped up gas fees were not correctly escrowed into the \
+                 Bridge pool storage",
+            )
+            .into());
+        }
+        self.check_nam_escrow_unchanged()?;
+        tracing::info!(
+            "The Ethereum bridge pool VP accepted topping up the gas fees of \
+             the transfer {:?} by {}.",
+            transfer,
+            top_up.amount,
+        );
+        Ok(())
+    }
+
     /// Determine the debit and credit amounts that should be checked.
     fn determine_escrow_checks<'trans, 'this: 'trans>(
         &'this self,
@@ -401,6 +809,18 @@ struct EscrowDelta<'a, KIND> {
 }
 
 impl<KIND> EscrowDelta<'_, KIND> {
+    /// Swap the debited and credited accounts of an [`EscrowDelta`],
+    /// such that it checks the refund of the escrowed amount.
+    fn reversed(self) -> Self {
+        Self {
+            payer_account: self.escrow_account,
+            escrow_account: self.payer_account,
+            expected_debit: self.expected_credit,
+            expected_credit: self.expected_debit,
+            ..self
+        }
+    }
+
     /// Validate an [`EscrowDelta`].
     ///
     /// # Conditions for validation
@@ -474,6 +894,14 @@ struct EscrowCheck<'a> {
 }
 
 impl EscrowCheck<'_> {
+    /// Check refunds of escrowed amounts, rather than escrows.
+    fn reversed(self) -> Self {
+        Self {
+            gas_check: self.gas_check.reversed(),
+            token_check: self.token_check.reversed(),
+        }
+    }
+
     #[inline]
     fn validate(&self, changed_keys: &BTreeSet<Key>) -> bool {
         self.gas_check.validate(changed_keys)
@@ -514,11 +942,11 @@ where
         &self,
         batched_tx: &BatchedTxRef<'_>,
         keys_changed: &BTreeSet<Key>,
-        _verifiers: &BTreeSet<Address>,
+        verifiers: &BTreeSet<Address>,
     ) -> Result<(), Error> {
         tracing::debug!(
             keys_changed_len = keys_changed.len(),
-            verifiers_len = _verifiers.len(),
+            verifiers_len = verifiers.len(),
             "Ethereum Bridge Pool VP triggered",
         );
         if !is_bridge_active_at(
@@ -541,30 +969,53 @@ where
             )
             .into());
         };
+        // The deltas in the escrowed amounts we must check.
+        let wnam_address =
+            read_native_erc20_address(&self.ctx.pre()).map_err(Error)?;
+        // txs topping up the gas fees of a pending transfer carry
+        // different data than the other Bridge pool txs
+        let is_fee_top_up =
+            self.ctx.read_actions()?.into_iter().any(|action| {
+                matches!(
+                    action,
+                    Action::EthBridge(EthBridgeAction::TopUpPoolTransfer(_))
+                )
+            });
+        if is_fee_top_up {
+            let top_up: TransferFeeTopUp =
+                BorshDeserialize::try_from_slice(&tx_data[..])
+                    .into_storage_result()
+                    .map_err(Error)?;
+            return self.check_fee_top_up(
+                &wnam_address,
+                &top_up,
+                keys_changed,
+                verifiers,
+            );
+        }
         let transfer: PendingTransfer =
             BorshDeserialize::try_from_slice(&tx_data[..])
                 .into_storage_result()
                 .map_err(Error)?;
 
         let pending_key = get_pending_key(&transfer);
-        // check that transfer is not already in the pool
-        match (&self.ctx).read_pre_value::<PendingTransfer>(&pending_key) {
-            Ok(Some(_)) => {
-                let error = native_vp::Error::new_const(
-                    "Rejecting transaction as the transfer is already in the \
-                     Ethereum bridge pool.",
-                )
-                .into();
-                tracing::debug!("{error}");
-                return Err(error);
-            }
-            // NOTE: make sure we don't erase storage errors returned by the
-            // ctx, as these may contain gas errors!
-            Err(e) => return Err(e.into()),
-            _ => {}
-        }
+        // check if the transfer is already in the pool, in which
+        // case this tx can only be cancelling it
+        let is_pending = (&self.ctx)
+            .read_pre_value::<PendingTransfer>(&pending_key)?
+            .is_some();
+        let utilization_key =
+            matches!(transfer.transfer.kind, TransferToEthereumKind::Erc20)
+                .then(|| get_epoch_utilization_key(&transfer.transfer.asset));
+        // the topped up gas fees of a transfer are removed when
+        // it is cancelled
+        let fee_top_up_key =
+            is_pending.then(|| get_fee_top_up_key(&transfer.keccak256()));
         for key in keys_changed.iter().filter(|k| is_bridge_pool_key(k)) {
-            if *key != pending_key {
+            if *key != pending_key
+                && Some(key) != utilization_key.as_ref()
+                && Some(key) != fee_top_up_key.as_ref()
+            {
                 let error = native_vp::Error::new_alloc(format!(
                     "Rejecting transaction as it is attempting to change an \
                      incorrect key in the Ethereum bridge pool: {key}.\n \
@@ -575,6 +1026,14 @@ where
                 return Err(error);
             }
         }
+        if is_pending {
+            return self.check_cancelled_transfer(
+                &wnam_address,
+                &transfer,
+                keys_changed,
+                verifiers,
+            );
+        }
         let pending: PendingTransfer =
             (&self.ctx).read_post_value(&pending_key)?.ok_or_else(|| {
                 Error(native_vp::Error::SimpleMessage(
@@ -591,9 +1050,12 @@ where
             tracing::debug!("{error}");
             return Err(error);
         }
-        // The deltas in the escrowed amounts we must check.
-        let wnam_address =
-            read_native_erc20_address(&self.ctx.pre()).map_err(Error)?;
+        match &transfer.transfer.kind {
+            TransferToEthereumKind::Erc20 => {
+                self.check_epoch_utilization(&transfer, keys_changed)?
+            }
+            TransferToEthereumKind::Nut => {}
+        }
         let escrow_checks =
             self.determine_escrow_checks(&wnam_address, &transfer)?;
         if !escrow_checks.validate(keys_changed) {
@@ -631,6 +1093,7 @@ where
                 .into()
             })
         } else {
+            self.check_nam_escrow_unchanged()?;
             self.check_escrowed_toks(escrow_checks.token_check)?
                 .ok_or_else(|| {
                     native_vp::Error::new_alloc(format!(
@@ -663,20 +1126,27 @@ mod test_bridge_pool_vp {
     use std::env::temp_dir;
 
     use namada_core::borsh::BorshSerializeExt;
-    use namada_ethereum_bridge::storage::bridge_pool::get_signed_root_key;
+    use namada_ethereum_bridge::storage::bridge_pool::{
+        get_nonce_key, get_signed_root_key,
+    };
     use namada_ethereum_bridge::storage::parameters::{
-        Contracts, EthereumBridgeParams, UpgradeableContract,
+        BridgePoolCancelDelay, Contracts, EthereumBridgeParams,
+        UpgradeableContract,
     };
+    use namada_ethereum_bridge::storage::proof::BridgePoolRootProof;
     use namada_ethereum_bridge::storage::wrapped_erc20s;
     use namada_gas::TxGasMeter;
     use namada_state::testing::TestState;
-    use namada_state::StorageWrite;
+    use namada_state::{StorageRead, StorageWrite};
+    use namada_tx::action::Write;
     use namada_tx::data::TxType;
 
     use super::*;
     use crate::address::testing::{nam, wnam};
     use crate::eth_bridge_pool::{GasFee, TransferToEthereum};
+    use crate::ethereum_events::Uint;
     use crate::hash::Hash;
+    use crate::keccak::KeccakHash;
     use crate::ledger::gas::VpGasMeter;
     use crate::state::write_log::WriteLog;
     use crate::storage::TxIndex;
@@ -908,6 +1378,30 @@ mod test_bridge_pool_vp {
         }
     }
 
+    /// Account for the amount of ERC20 assets queued by `transfer`
+    /// in the Bridge pool, during the current epoch.
+    fn queue_utilization(
+        transfer: &PendingTransfer,
+        state: &mut TestState,
+    ) -> BTreeSet<Key> {
+        if !matches!(transfer.transfer.kind, TransferToEthereumKind::Erc20) {
+            return BTreeSet::new();
+        }
+        let key = get_epoch_utilization_key(&transfer.transfer.asset);
+        let epoch = state.in_mem().get_current_epoch().0;
+        let utilization = state
+            .read::<EpochUtilization>(&key)
+            .expect("Test failed")
+            .unwrap_or_default()
+            .queue(epoch, transfer.transfer.amount)
+            .expect("Test failed");
+        state
+            .write_log_mut()
+            .write(&key, utilization.serialize_to_vec())
+            .expect("Test failed");
+        BTreeSet::from([key])
+    }
+
     /// Initialize some dummy storage for testing
     fn setup_storage() -> TestState {
         // a dummy config for testing
@@ -915,6 +1409,8 @@ mod test_bridge_pool_vp {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: Default::default(),
+            event_confirmations: Default::default(),
+            bridge_pool_cancel_delay: Default::default(),
             contracts: Contracts {
                 native_erc20: wnam(),
                 bridge: UpgradeableContract {
@@ -967,9 +1463,44 @@ mod test_bridge_pool_vp {
         expect: Expect,
     ) where
         F: FnOnce(&mut PendingTransfer, &mut WriteLog) -> BTreeSet<Key>,
+    {
+        assert_bridge_pool_with_epoch_cap(
+            None,
+            payer_gas_delta,
+            gas_escrow_delta,
+            payer_delta,
+            escrow_delta,
+            insert_transfer,
+            expect,
+        )
+    }
+
+    /// Like [`assert_bridge_pool`], but caps the amount of [`ASSET`]
+    /// that can be queued in the Bridge pool per epoch.
+    fn assert_bridge_pool_with_epoch_cap<F>(
+        epoch_cap: Option<u64>,
+        payer_gas_delta: I320,
+        gas_escrow_delta: I320,
+        payer_delta: I320,
+        escrow_delta: I320,
+        insert_transfer: F,
+        expect: Expect,
+    ) where
+        F: FnOnce(&mut PendingTransfer, &mut WriteLog) -> BTreeSet<Key>,
     {
         // setup
         let mut state = setup_storage();
+        if let Some(cap) = epoch_cap {
+            let cap_key = whitelist::Key {
+                asset: ASSET,
+                suffix: whitelist::KeyType::EpochCap,
+            }
+            .into();
+            state
+                .write(&cap_key, Amount::from(cap))
+                .expect("Test failed");
+            state.write_log_mut().commit_tx();
+        }
         let tx = Tx::from_type(TxType::Raw);
 
         // the transfer to be added to the pool
@@ -990,6 +1521,7 @@ mod test_bridge_pool_vp {
         // add transfer to pool
         let mut keys_changed =
             insert_transfer(&mut transfer, state.write_log_mut());
+        keys_changed.append(&mut queue_utilization(&transfer, &mut state));
 
         // change Bertha's balances
         let mut new_keys_changed = update_balances(
@@ -1065,6 +1597,66 @@ mod test_bridge_pool_vp {
         );
     }
 
+    /// Test that a transfer of an ERC20 asset cannot be used to
+    /// drain the NAM escrowed under the Ethereum bridge address.
+    #[test]
+    fn test_drain_nam_escrow_rejected() {
+        assert_bridge_pool(
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            -I320::from(TOKENS),
+            I320::from(TOKENS),
+            |transfer, log| {
+                log.write(
+                    &get_pending_key(transfer),
+                    transfer.serialize_to_vec(),
+                )
+                .unwrap();
+                let escrow_key = balance_key(&nam(), &BRIDGE_ADDRESS);
+                log.write(&escrow_key, Amount::zero().serialize_to_vec())
+                    .unwrap();
+                let thief_key = balance_key(&nam(), &established_address_1());
+                log.write(
+                    &thief_key,
+                    Amount::from(ESCROWED_AMOUNT).serialize_to_vec(),
+                )
+                .unwrap();
+                BTreeSet::from([
+                    get_pending_key(transfer),
+                    escrow_key,
+                    thief_key,
+                ])
+            },
+            Expect::Rejected,
+        );
+    }
+
+    /// Test that transfers of ERC20 assets within their epoch
+    /// cap are accepted, and those in excess of it are rejected.
+    #[test]
+    fn test_bridge_pool_epoch_cap() {
+        for (cap, expect) in
+            [(TOKENS, Expect::Accepted), (TOKENS - 1, Expect::Rejected)]
+        {
+            assert_bridge_pool_with_epoch_cap(
+                Some(cap),
+                -I320::from(GAS_FEE),
+                I320::from(GAS_FEE),
+                -I320::from(TOKENS),
+                I320::from(TOKENS),
+                |transfer, log| {
+                    log.write(
+                        &get_pending_key(transfer),
+                        transfer.serialize_to_vec(),
+                    )
+                    .unwrap();
+                    BTreeSet::from([get_pending_key(transfer)])
+                },
+                expect,
+            );
+        }
+    }
+
     /// Test that if the balance for the gas payer
     /// was not correctly adjusted, reject
     #[test]
@@ -1381,6 +1973,305 @@ mod test_bridge_pool_vp {
         assert!(res.is_err());
     }
 
+    /// Helper function that tests topping up the gas fees of the
+    /// transfer in the initial pool.
+    fn assert_fee_top_up(
+        authorized: bool,
+        payer_gas_delta: I320,
+        gas_escrow_delta: I320,
+        recorded_top_up: u64,
+        expect: Expect,
+    ) {
+        // setup
+        let mut state = setup_storage();
+        let tx = Tx::from_type(TxType::Raw);
+        let transfer = initial_pool();
+        let top_up = TransferFeeTopUp {
+            transfer: transfer.keccak256(),
+            amount: GAS_FEE.into(),
+        };
+
+        // record the topped up gas fees
+        let fee_top_up_key = get_fee_top_up_key(&top_up.transfer);
+        state
+            .write_log_mut()
+            .write(
+                &fee_top_up_key,
+                Amount::from(recorded_top_up).serialize_to_vec(),
+            )
+            .expect("Test failed");
+        let mut keys_changed = BTreeSet::from([fee_top_up_key]);
+
+        // update Bertha's balances
+        let mut new_keys_changed = update_balances(
+            state.write_log_mut(),
+            Balance {
+                asset: ASSET,
+                kind: TransferToEthereumKind::Erc20,
+                owner: bertha_address(),
+                gas: BERTHA_WEALTH.into(),
+                token: BERTHA_TOKENS.into(),
+            },
+            payer_gas_delta,
+            I320::from(0),
+        );
+        keys_changed.append(&mut new_keys_changed);
+
+        // update the bridge pool balances
+        let mut new_keys_changed = update_balances(
+            state.write_log_mut(),
+            Balance {
+                asset: ASSET,
+                kind: TransferToEthereumKind::Erc20,
+                owner: BRIDGE_POOL_ADDRESS,
+                gas: ESCROWED_AMOUNT.into(),
+                token: ESCROWED_TOKENS.into(),
+            },
+            gas_escrow_delta,
+            I320::from(0),
+        );
+        keys_changed.append(&mut new_keys_changed);
+
+        // only the payer of the gas fees may authorize the top up
+        let payer = transfer.gas_fee.payer.clone();
+        let verifiers = if authorized {
+            BTreeSet::from([payer.clone()])
+        } else {
+            BTreeSet::default()
+        };
+        state
+            .push_action(Action::EthBridge(EthBridgeAction::TopUpPoolTransfer(
+                payer,
+            )))
+            .expect("Test failed");
+
+        // create the data to be given to the vp
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        ));
+        let vp = BridgePoolVp {
+            ctx: setup_ctx(&tx, &state, &gas_meter, &keys_changed, &verifiers),
+        };
+
+        let mut tx = Tx::new(state.in_mem().chain_id.clone(), None);
+        tx.add_data(top_up);
+
+        let res = vp.validate_tx(&tx, &keys_changed, &verifiers);
+        match (expect, res) {
+            (Expect::Accepted, Ok(())) => (),
+            (Expect::Accepted, Err(err)) => {
+                panic!("Expected VP success, but got: {err}")
+            }
+            (Expect::Rejected, Err(_)) => (),
+            (Expect::Rejected, Ok(())) => {
+                panic!("Expected VP failure, but the tx was accepted")
+            }
+        }
+    }
+
+    /// Test topping up the gas fees of a pending transfer.
+    #[test]
+    fn test_fee_top_up_happy_flow() {
+        assert_fee_top_up(
+            true,
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            GAS_FEE,
+            Expect::Accepted,
+        );
+    }
+
+    /// Test that topping up gas fees must be authorized by their payer.
+    #[test]
+    fn test_fee_top_up_not_authorized() {
+        assert_fee_top_up(
+            false,
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            GAS_FEE,
+            Expect::Rejected,
+        );
+    }
+
+    /// Test that the topped up gas fees must be escrowed.
+    #[test]
+    fn test_fee_top_up_not_escrowed() {
+        assert_fee_top_up(
+            true,
+            -I320::from(GAS_FEE),
+            I320::from(0),
+            GAS_FEE,
+            Expect::Rejected,
+        );
+    }
+
+    /// Test that the topped up gas fees must be recorded.
+    #[test]
+    fn test_fee_top_up_not_recorded() {
+        assert_fee_top_up(
+            true,
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            2 * GAS_FEE,
+            Expect::Rejected,
+        );
+    }
+
+    /// Helper function that tests cancelling a transfer inserted in
+    /// the pool at height 10, once its cancellation delay has passed.
+    /// A Bridge pool root covering the transfer is signed at every
+    /// block. If `relayed_batch` is `Some(seen)`, a batch relaying the
+    /// transfer to Ethereum was voted on, and confirmed if `seen`.
+    fn assert_cancel_transfer(relayed_batch: Option<bool>, expect: Expect) {
+        // setup
+        let mut state = setup_storage();
+        let tx = Tx::from_type(TxType::Raw);
+        let transfer = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                asset: ASSET,
+                sender: bertha_address(),
+                recipient: EthAddress([1; 20]),
+                amount: TOKENS.into(),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: GAS_FEE.into(),
+                payer: bertha_address(),
+            },
+        };
+
+        // add the transfer to the pool at height 10
+        let inserted_height = BlockHeight(10);
+        state.in_mem_mut().block.height = inserted_height;
+        let pending_key = get_pending_key(&transfer);
+        state
+            .write_log_mut()
+            .write(&pending_key, transfer.serialize_to_vec())
+            .expect("Test failed");
+        state.write_log_mut().commit_tx();
+        state.commit_block().expect("Test failed");
+
+        // cancel the transfer once its cancellation delay has passed
+        let cancel_delay: u64 = BridgePoolCancelDelay::default().into();
+        let current_height = inserted_height
+            .checked_add(cancel_delay)
+            .expect("Test failed");
+        state.in_mem_mut().block.height = current_height;
+
+        // the Bridge pool root of the previous block covers the transfer
+        let root =
+            BridgePoolRootProof::new((KeccakHash([0; 32]), Uint::from(0)));
+        let signed_height = current_height.prev_height().expect("Test failed");
+        state
+            .write(&get_signed_root_key(), (root, signed_height))
+            .expect("Test failed");
+        state
+            .write(&get_nonce_key(), Uint::from(0))
+            .expect("Test failed");
+        if let Some(seen) = relayed_batch {
+            let event = EthereumEvent::TransfersToEthereum {
+                nonce: Uint::from(0),
+                transfers: vec![TransferToEthereumEvent::from(&transfer)],
+                relayer: bertha_address(),
+            };
+            let keys = Keys::from(&event);
+            state.write(&keys.body(), &event).expect("Test failed");
+            state.write(&keys.seen(), seen).expect("Test failed");
+        }
+        state.write_log_mut().commit_tx();
+
+        state
+            .write_log_mut()
+            .delete(&pending_key)
+            .expect("Test failed");
+        let mut keys_changed = BTreeSet::from([pending_key]);
+
+        // refund Bertha
+        let mut new_keys_changed = update_balances(
+            state.write_log_mut(),
+            Balance {
+                asset: ASSET,
+                kind: TransferToEthereumKind::Erc20,
+                owner: bertha_address(),
+                gas: BERTHA_WEALTH.into(),
+                token: BERTHA_TOKENS.into(),
+            },
+            I320::from(GAS_FEE),
+            I320::from(TOKENS),
+        );
+        keys_changed.append(&mut new_keys_changed);
+
+        // release the escrowed gas fees and tokens
+        let mut new_keys_changed = update_balances(
+            state.write_log_mut(),
+            Balance {
+                asset: ASSET,
+                kind: TransferToEthereumKind::Erc20,
+                owner: BRIDGE_POOL_ADDRESS,
+                gas: ESCROWED_AMOUNT.into(),
+                token: ESCROWED_TOKENS.into(),
+            },
+            -I320::from(GAS_FEE),
+            -I320::from(TOKENS),
+        );
+        keys_changed.append(&mut new_keys_changed);
+
+        // the sender authorizes the cancellation
+        let sender = transfer.transfer.sender.clone();
+        let verifiers = BTreeSet::from([sender.clone()]);
+        state
+            .push_action(Action::EthBridge(EthBridgeAction::CancelPoolTransfer(
+                sender,
+            )))
+            .expect("Test failed");
+
+        // create the data to be given to the vp
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        ));
+        let vp = BridgePoolVp {
+            ctx: setup_ctx(&tx, &state, &gas_meter, &keys_changed, &verifiers),
+        };
+
+        let mut tx = Tx::new(state.in_mem().chain_id.clone(), None);
+        tx.add_data(transfer);
+
+        let res = vp.validate_tx(&tx, &keys_changed, &verifiers);
+        match (expect, res) {
+            (Expect::Accepted, Ok(())) => (),
+            (Expect::Accepted, Err(err)) => {
+                panic!("Expected VP success, but got: {err}")
+            }
+            (Expect::Rejected, Err(_)) => (),
+            (Expect::Rejected, Ok(())) => {
+                panic!("Expected VP failure, but the tx was accepted")
+            }
+        }
+    }
+
+    /// Test cancelling a transfer once its cancellation delay has passed,
+    /// even though the Bridge pool root signed at every block covers it.
+    #[test]
+    fn test_cancel_transfer_happy_flow() {
+        assert_cancel_transfer(None, Expect::Accepted);
+    }
+
+    /// Test that a transfer in a batch relayed to Ethereum, which has not
+    /// been confirmed by Namada validators yet, cannot be cancelled.
+    #[test]
+    fn test_cancel_transfer_in_flight() {
+        assert_cancel_transfer(Some(false), Expect::Rejected);
+    }
+
+    /// Test that a transfer can be cancelled if the batch relaying an
+    /// identical transfer was already confirmed, i.e. the transfer was
+    /// added to the pool again.
+    #[test]
+    fn test_cancel_transfer_relayed_before() {
+        assert_cancel_transfer(Some(true), Expect::Accepted);
+    }
+
     /// Test that a transfer added to the pool with zero gas fees
     /// is rejected.
     #[test]
@@ -1473,6 +2364,7 @@ mod test_bridge_pool_vp {
                 .unwrap();
             BTreeSet::from([get_pending_key(&transfer)])
         };
+        keys_changed.append(&mut queue_utilization(&transfer, &mut state));
         // We escrow 100 Nam into the bridge pool VP
         // and 100 Nam in the Eth bridge VP
         let account_key = balance_key(&nam(), &bertha_address());
@@ -1713,6 +2605,7 @@ mod test_bridge_pool_vp {
                 .unwrap();
             BTreeSet::from([get_pending_key(&transfer)])
         };
+        keys_changed.append(&mut queue_utilization(&transfer, &mut state));
 
         // update Daewon's balances
         let mut new_keys_changed = update_balances(
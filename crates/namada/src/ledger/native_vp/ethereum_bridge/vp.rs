@@ -8,9 +8,10 @@ use namada_core::collections::HashSet;
 use namada_core::storage::Key;
 use namada_ethereum_bridge::storage;
 use namada_ethereum_bridge::storage::escrow_key;
+use namada_tx::action::{Action, EthBridgeAction, Read};
 use namada_tx::BatchedTxRef;
 
-use crate::ledger::native_vp::{self, Ctx, NativeVp, StorageReader};
+use crate::ledger::native_vp::{self, Ctx, NativeVp, StorageReader, VpEnv};
 use crate::state::StateRead;
 use crate::token::storage_key::{balance_key, is_balance_key};
 use crate::token::Amount;
@@ -37,8 +38,11 @@ where
     CA: 'static + WasmCacheAccess,
 {
     /// If the Ethereum bridge's escrow key was written to, we check
-    /// that the NAM balance increased and that the Bridge pool VP has
-    /// been triggered.
+    /// that the NAM balance changed and that the Bridge pool VP has
+    /// been triggered. The balance may only decrease when a transfer
+    /// is cancelled from the Bridge pool, in which case the Bridge
+    /// pool VP checks that exactly the NAM escrowed by the cancelled
+    /// wrapped NAM transfer is refunded.
     fn check_escrow(&self, verifiers: &BTreeSet<Address>) -> Result<(), Error> {
         let escrow_key = balance_key(
             &self.ctx.state.in_mem().native_token,
@@ -50,13 +54,14 @@ where
         let escrow_post: Amount =
             (&self.ctx).must_read_post_value(&escrow_key)?;
 
-        // The amount escrowed should increase.
-        if escrow_pre < escrow_post {
-            // NB: normally, we only escrow NAM under the Ethereum bridge
-            // address in the context of a Bridge pool transfer
-            let bridge_pool_is_verifier =
-                verifiers.contains(&storage::bridge_pool::BRIDGE_POOL_ADDRESS);
+        // NB: normally, we only escrow NAM under the Ethereum bridge
+        // address in the context of a Bridge pool transfer
+        let bridge_pool_is_verifier =
+            verifiers.contains(&storage::bridge_pool::BRIDGE_POOL_ADDRESS);
 
+        // The amount escrowed should increase, unless a Bridge pool
+        // transfer is being cancelled.
+        if escrow_pre < escrow_post {
             bridge_pool_is_verifier.ok_or_else(|| {
                 native_vp::Error::new_const(
                     "Bridge pool VP was not marked as a verifier of the \
@@ -64,6 +69,16 @@ where
                 )
                 .into()
             })
+        } else if escrow_pre > escrow_post
+            && bridge_pool_is_verifier
+            && self.ctx.read_actions()?.into_iter().any(|action| {
+                matches!(
+                    action,
+                    Action::EthBridge(EthBridgeAction::CancelPoolTransfer(_))
+                )
+            })
+        {
+            Ok(())
         } else {
             Err(native_vp::Error::new_const(
                 "User tx attempted to decrease the amount of native tokens \
@@ -178,6 +193,7 @@ mod tests {
     use namada_gas::TxGasMeter;
     use namada_state::testing::TestState;
     use namada_state::StorageWrite;
+    use namada_tx::action::Write;
     use namada_tx::data::TxType;
     use namada_tx::{Tx, TxCommitments};
     use rand::Rng;
@@ -233,6 +249,8 @@ mod tests {
             erc20_whitelist: vec![],
             eth_start_height: Default::default(),
             min_confirmations: Default::default(),
+            event_confirmations: Default::default(),
+            bridge_pool_cancel_delay: Default::default(),
             contracts: Contracts {
                 native_erc20: wnam(),
                 bridge: UpgradeableContract {
@@ -463,6 +481,102 @@ mod tests {
         assert!(res.is_err());
     }
 
+    /// Test that escrowed Nam may be refunded, if a transfer
+    /// is cancelled from the Bridge pool.
+    #[test]
+    fn test_refund_nam_accepted() {
+        let mut state = setup_storage();
+        let owner =
+            Address::decode(ARBITRARY_OWNER_A_ADDRESS).expect("Test failed");
+        // escrow some NAM under the Ethereum bridge
+        let escrow_key = balance_key(&nam(), &crate::ethereum_bridge::ADDRESS);
+        state
+            .write(&escrow_key, Amount::from(ESCROW_AMOUNT))
+            .expect("Test failed");
+        state.commit_block().expect("Test failed");
+
+        // credit the user's balance
+        let account_key = balance_key(
+            &nam(),
+            &Address::decode(ARBITRARY_OWNER_A_ADDRESS).expect("Test failed"),
+        );
+        state
+            .write_log_mut()
+            .write(
+                &account_key,
+                Amount::from(ARBITRARY_OWNER_A_INITIAL_BALANCE + ESCROW_AMOUNT)
+                    .serialize_to_vec(),
+            )
+            .expect("Test failed");
+
+        // debit the balance from the escrow
+        state
+            .write_log_mut()
+            .write(&escrow_key, Amount::zero().serialize_to_vec())
+            .expect("Test failed");
+
+        let keys_changed = BTreeSet::from([account_key, escrow_key]);
+
+        // set up the VP
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.push_default_inner_tx();
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        ));
+        let batched_tx = tx.batch_ref_first_tx();
+
+        // without a cancellation, the refund is rejected even if
+        // the Bridge pool VP is triggered
+        let verifiers = BTreeSet::from([BRIDGE_POOL_ADDRESS]);
+        let vp = EthBridge {
+            ctx: setup_ctx(
+                batched_tx.tx,
+                batched_tx.cmt,
+                &state,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+            ),
+        };
+        let res = vp.validate_tx(&batched_tx, &keys_changed, &verifiers);
+        assert!(res.is_err());
+
+        // the refund is accepted if a transfer is cancelled and
+        // the Bridge pool VP is triggered
+        state
+            .push_action(Action::EthBridge(
+                EthBridgeAction::CancelPoolTransfer(owner),
+            ))
+            .expect("Test failed");
+        let vp = EthBridge {
+            ctx: setup_ctx(
+                batched_tx.tx,
+                batched_tx.cmt,
+                &state,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+            ),
+        };
+        let res = vp.validate_tx(&batched_tx, &keys_changed, &verifiers);
+        assert!(res.is_ok());
+
+        // otherwise, it is rejected
+        let verifiers = BTreeSet::new();
+        let vp = EthBridge {
+            ctx: setup_ctx(
+                batched_tx.tx,
+                batched_tx.cmt,
+                &state,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+            ),
+        };
+        let res = vp.validate_tx(&batched_tx, &keys_changed, &verifiers);
+        assert!(res.is_err());
+    }
+
     /// Test that the VP checks that the bridge pool vp will
     /// be triggered if escrowing occurs.
     #[test]
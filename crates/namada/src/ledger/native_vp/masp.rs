@@ -473,6 +473,7 @@ where
                     crate::parameters::max_signatures_per_transaction(
                         &self.ctx.pre(),
                     )?;
+                let verify_sig_gas = self.ctx.host_fn_gas_costs().verify_tx_sig;
                 let mut gas_meter = self.ctx.gas_meter.borrow_mut();
                 tx_data
                     .tx
@@ -482,7 +483,7 @@ where
                         &Some(signer.clone()),
                         threshold,
                         max_signatures_per_transaction,
-                        || gas_meter.consume(crate::gas::VERIFY_TX_SIG_GAS),
+                        || gas_meter.consume(verify_sig_gas),
                     )
                     .map_err(native_vp::Error::new)?;
             } else {
@@ -16,6 +16,7 @@ use borsh::BorshDeserialize;
 use namada_core::storage;
 use namada_core::storage::Epochs;
 use namada_events::{Event, EventType};
+use namada_gas::cost_table::HostFnGasCosts;
 use namada_gas::GasMetering;
 use namada_tx::{BatchedTxRef, Tx, TxCommitments};
 pub use namada_vp_env::VpEnv;
@@ -147,6 +148,19 @@ where
         }
     }
 
+    /// Get the gas costs of the host functions, which also apply to the
+    /// same operations performed by native VPs
+    pub fn host_fn_gas_costs(&self) -> HostFnGasCosts {
+        #[cfg(feature = "wasm-runtime")]
+        {
+            self.vp_wasm_cache.host_fn_gas_costs()
+        }
+        #[cfg(not(feature = "wasm-runtime"))]
+        {
+            HostFnGasCosts::default()
+        }
+    }
+
     /// Read access to the prior storage (state before tx execution)
     /// via [`trait@StorageRead`].
     pub fn pre<'view>(&'view self) -> CtxPreStorageRead<'view, 'a, S, CA> {
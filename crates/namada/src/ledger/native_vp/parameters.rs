@@ -1,11 +1,13 @@
 //! Native VP for protocol parameters
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use namada_core::address::Address;
 use namada_core::booleans::BoolResultUnitExt;
+use namada_core::dec::Dec;
 use namada_core::storage::Key;
-use namada_state::StateRead;
+use namada_gas::cost_table::GasCostTable;
+use namada_state::{StateRead, StorageRead};
 use namada_tx::BatchedTxRef;
 use thiserror::Error;
 
@@ -43,7 +45,7 @@ where
         &self,
         batched_tx: &BatchedTxRef<'_>,
         keys_changed: &BTreeSet<Key>,
-        _verifiers: &BTreeSet<Address>,
+        verifiers: &BTreeSet<Address>,
     ) -> Result<()> {
         keys_changed.iter().try_for_each(|key| {
             let key_type: KeyType = key.into();
@@ -56,6 +58,11 @@ where
                 .into());
             };
             match key_type {
+                KeyType::PARAMETER
+                    if self.is_price_feed_update(key, verifiers)? =>
+                {
+                    self.validate_price_feed_update(key)
+                }
                 KeyType::PARAMETER | KeyType::UNKNOWN_PARAMETER => {
                     namada_governance::storage::is_proposal_accepted(
                         &self.ctx.pre(),
@@ -68,8 +75,12 @@ where
                              outside of a governance proposal, or from a \
                              non-accepted governance proposal: {key}",
                         ))
-                        .into()
-                    })
+                    })?;
+                    if namada_parameters::storage::is_vm_gas_cost_table_key(key)
+                    {
+                        self.validate_vm_gas_cost_table_update(key)?;
+                    }
+                    Ok(())
                 }
                 KeyType::UNKNOWN => Ok(()),
             }
@@ -77,6 +88,74 @@ where
     }
 }
 
+impl<'a, S, CA> ParametersVp<'a, S, CA>
+where
+    S: StateRead,
+    CA: 'static + WasmCacheAccess,
+{
+    /// Check if the key is the one of the fee conversion rates and the fee
+    /// price feed, if any, is a verifier of the tx, in which case its VP
+    /// authorizes the update of the rates
+    fn is_price_feed_update(
+        &self,
+        key: &Key,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<bool> {
+        if !namada_parameters::storage::is_fee_conversion_rates_key(key) {
+            return Ok(false);
+        }
+        Ok(
+            namada_parameters::storage::get_fee_price_feed(&self.ctx.pre())?
+                .is_some_and(|price_feed| verifiers.contains(&price_feed)),
+        )
+    }
+
+    /// Check that an update of the fee conversion rates by the fee price feed
+    /// only changes the rates of the tokens that governance has already
+    /// priced by conversion, to positive values
+    fn validate_price_feed_update(&self, key: &Key) -> Result<()> {
+        let pre =
+            namada_parameters::read_fee_conversion_rates(&self.ctx.pre())?;
+        let post: BTreeMap<Address, Dec> =
+            self.ctx.post().read(key)?.unwrap_or_default();
+        if !pre.keys().eq(post.keys()) {
+            return Err(native_vp::Error::new_const(
+                "The fee price feed cannot add or remove fee tokens",
+            )
+            .into());
+        }
+        post.values()
+            .all(namada_parameters::is_valid_fee_conversion_rate)
+            .ok_or_else(|| {
+                native_vp::Error::new_const(
+                    "The fee conversion rates must be greater than zero and \
+                     at most the maximal fee conversion rate",
+                )
+                .into()
+            })
+    }
+
+    /// Check that an update of the gas costs of the wasm opcodes bumps the
+    /// version of the table, such that the wasm caches are invalidated
+    fn validate_vm_gas_cost_table_update(&self, key: &Key) -> Result<()> {
+        let current =
+            namada_parameters::read_vm_gas_cost_table(&self.ctx.pre())?;
+        let new: GasCostTable =
+            self.ctx.post().read(key)?.ok_or_else(|| {
+                native_vp::Error::new_const(
+                    "The gas cost table of the wasm opcodes cannot be deleted",
+                )
+            })?;
+        new.validate_update(&current).map_err(|err| {
+            native_vp::Error::new_alloc(format!(
+                "Invalid update of the gas cost table of the wasm opcodes: \
+                 {err}"
+            ))
+            .into()
+        })
+    }
+}
+
 #[allow(clippy::upper_case_acronyms)]
 enum KeyType {
     #[allow(clippy::upper_case_acronyms)]
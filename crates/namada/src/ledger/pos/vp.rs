@@ -8,9 +8,9 @@ pub use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::storage::read_pos_params;
 use namada_proof_of_stake::storage_key::is_params_key;
 pub use namada_proof_of_stake::types;
-use namada_proof_of_stake::types::BondId;
-use namada_proof_of_stake::{storage_key, token};
-use namada_state::StateRead;
+use namada_proof_of_stake::types::{validate_metadata_field, BondId};
+use namada_proof_of_stake::{storage_key, token, MetadataError};
+use namada_state::{StateRead, StorageRead};
 use namada_tx::action::{
     Action, Bond, ClaimRewards, PosAction, Read, Redelegation, Unbond, Withdraw,
 };
@@ -308,6 +308,15 @@ where
                      governance proposal that has been accepted",
                 )));
             }
+            if let Some(validator) = storage_key::is_validator_metadata_key(key)
+            {
+                self.is_valid_metadata_change(
+                    key,
+                    validator,
+                    &became_validator,
+                    &changed_metadata,
+                )?;
+            }
             // TODO: validate changes keys against the accumulated changes
         }
         Ok(())
@@ -324,6 +333,41 @@ where
         Self { ctx }
     }
 
+    /// Return `Ok` if the validator metadata change under the given key was
+    /// announced by the tx and the new value is well-formed
+    fn is_valid_metadata_change(
+        &self,
+        key: &Key,
+        validator: &Address,
+        became_validator: &BTreeSet<Address>,
+        changed_metadata: &BTreeSet<Address>,
+    ) -> Result<()> {
+        if !became_validator.contains(validator)
+            && !changed_metadata.contains(validator)
+        {
+            return Err(Error::NativeVpError(native_vp::Error::new_alloc(
+                format!(
+                    "Metadata of validator {validator} was modified without \
+                     a corresponding PoS action"
+                ),
+            )));
+        }
+        let value: Option<String> =
+            self.ctx.post().read(key).map_err(Error::NativeVpError)?;
+        match value {
+            Some(value) => validate_metadata_field(&value),
+            None if *key == storage_key::validator_email_key(validator) => {
+                Err(MetadataError::CannotRemoveEmail)
+            }
+            None => Ok(()),
+        }
+        .map_err(|err| {
+            Error::NativeVpError(native_vp::Error::new_alloc(format!(
+                "Invalid metadata of validator {validator}: {err}"
+            )))
+        })
+    }
+
     /// Return `Ok` if the changed parameters are valid
     fn is_valid_parameter_change(&self) -> Result<()> {
         let validation_errors = read_pos_params(&self.ctx.post())
@@ -340,3 +384,165 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use borsh_ext::BorshSerializeExt;
+    use namada_gas::{TxGasMeter, VpGasMeter};
+    use namada_state::testing::TestState;
+    use namada_tx::action::Write;
+    use namada_tx::data::TxType;
+    use namada_tx::{BatchedTx, Code, Data, Tx};
+
+    use super::*;
+    use crate::core::address::testing::established_address_1;
+    use crate::storage::TxIndex;
+    use crate::vm::wasm::compilation_cache::common::testing::cache as wasm_cache;
+
+    /// Run the PoS VP on the given changed keys of the write log, with the
+    /// validator as a verifier
+    fn validate_metadata_change(
+        state: &TestState,
+        validator: &Address,
+        keys_changed: BTreeSet<Key>,
+    ) -> Result<()> {
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        let BatchedTx { tx, cmt } = tx.batch_first_tx();
+        let tx_index = TxIndex::default();
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) = wasm_cache();
+        let verifiers = BTreeSet::from([validator.clone()]);
+        let ctx = Ctx::new(
+            &namada_proof_of_stake::ADDRESS,
+            state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+        PosVP::new(ctx).validate_tx(
+            &tx.batch_ref_tx(&cmt),
+            &keys_changed,
+            &verifiers,
+        )
+    }
+
+    /// Write the given metadata value of the validator in the write log
+    fn write_metadata(state: &mut TestState, key: &Key, value: Option<&str>) {
+        match value {
+            Some(value) => state
+                .write_log_mut()
+                .write(key, value.to_string().serialize_to_vec())
+                .unwrap(),
+            None => state.write_log_mut().delete(key).unwrap(),
+        };
+    }
+
+    /// Test that a well-formed metadata change announced by the tx is
+    /// accepted
+    #[test]
+    fn test_valid_metadata_change() {
+        let mut state = TestState::default();
+        let validator = established_address_1();
+        let key = storage_key::validator_description_key(&validator);
+        state
+            .push_action(Action::Pos(PosAction::MetadataChange(
+                validator.clone(),
+            )))
+            .unwrap();
+        write_metadata(&mut state, &key, Some("A validator"));
+
+        let result =
+            validate_metadata_change(&state, &validator, BTreeSet::from([key]));
+        assert!(result.is_ok());
+    }
+
+    /// Test that a metadata write without a corresponding PoS action is
+    /// rejected
+    #[test]
+    fn test_metadata_change_without_action() {
+        let mut state = TestState::default();
+        let validator = established_address_1();
+        let key = storage_key::validator_description_key(&validator);
+        write_metadata(&mut state, &key, Some("A validator"));
+
+        let result =
+            validate_metadata_change(&state, &validator, BTreeSet::from([key]));
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("without a corresponding PoS action")
+        );
+    }
+
+    /// Test that the removal of a validator's email is rejected, while the
+    /// other metadata can be removed
+    #[test]
+    fn test_metadata_email_removal() {
+        let mut state = TestState::default();
+        let validator = established_address_1();
+        state
+            .push_action(Action::Pos(PosAction::MetadataChange(
+                validator.clone(),
+            )))
+            .unwrap();
+        let description_key =
+            storage_key::validator_description_key(&validator);
+        write_metadata(&mut state, &description_key, None);
+        let result = validate_metadata_change(
+            &state,
+            &validator,
+            BTreeSet::from([description_key.clone()]),
+        );
+        assert!(result.is_ok());
+
+        let email_key = storage_key::validator_email_key(&validator);
+        write_metadata(&mut state, &email_key, None);
+        let result = validate_metadata_change(
+            &state,
+            &validator,
+            BTreeSet::from([description_key, email_key]),
+        );
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains(&MetadataError::CannotRemoveEmail.to_string())
+        );
+    }
+
+    /// Test that a metadata value containing a control character is
+    /// rejected
+    #[test]
+    fn test_metadata_with_control_character() {
+        let mut state = TestState::default();
+        let validator = established_address_1();
+        let key = storage_key::validator_description_key(&validator);
+        state
+            .push_action(Action::Pos(PosAction::MetadataChange(
+                validator.clone(),
+            )))
+            .unwrap();
+        write_metadata(&mut state, &key, Some("A\u{7}validator"));
+
+        let result =
+            validate_metadata_change(&state, &validator, BTreeSet::from([key]));
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains(&MetadataError::InvalidCharacters.to_string())
+        );
+    }
+}
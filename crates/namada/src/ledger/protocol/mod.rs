@@ -1051,6 +1051,9 @@ where
             // all the other errors we keep evaluating the vps. This
             // allows to display a consistent VpsResult across all
             // nodes and find any invalid signatures
+            result
+                .gas_per_vp
+                .insert(addr.clone(), gas_meter.borrow().get_vp_consumed_gas());
             result
                 .gas_used
                 .set(gas_meter.into_inner())
@@ -1078,6 +1081,8 @@ fn merge_vp_results(
     let mut errors = a.errors;
     errors.append(&mut b.errors);
     let status_flags = a.status_flags | b.status_flags;
+    let mut gas_per_vp = a.gas_per_vp;
+    gas_per_vp.append(&mut b.gas_per_vp);
     let mut gas_used = a.gas_used;
 
     gas_used
@@ -1090,6 +1095,7 @@ fn merge_vp_results(
         gas_used,
         errors,
         status_flags,
+        gas_per_vp,
     })
 }
 
@@ -1325,4 +1331,65 @@ mod tests {
         );
         assert!(matches!(result.unwrap_err(), Error::GasError(_)));
     }
+
+    /// Test that the verdict and the gas used by each VP are reported
+    #[test]
+    fn test_vps_result_gas_per_vp() {
+        use namada_token::storage_key::balance_key;
+
+        let (mut state, _validators) = test_utils::setup_default_storage();
+
+        let token_address = Address::Established([0xff; 20].into());
+        let src_address = Address::Established([0xab; 20].into());
+        let dst_address = Address::Established([0xba; 20].into());
+        namada_token::credit_tokens(
+            &mut state,
+            &token_address,
+            &src_address,
+            1000.into(),
+        )
+        .unwrap();
+        state.commit_tx();
+        state.commit_block().unwrap();
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.set_code(namada_tx::Code::new(vec![], None));
+        tx.set_data(namada_tx::Data::new(vec![]));
+        namada_token::transfer(
+            &mut state,
+            &token_address,
+            &src_address,
+            &dst_address,
+            500.into(),
+        )
+        .unwrap();
+        let changed_keys = BTreeSet::from([
+            balance_key(&token_address, &src_address),
+            balance_key(&token_address, &dst_address),
+        ]);
+        let multitoken = Address::Internal(InternalAddress::Multitoken);
+        let verifiers = BTreeSet::from([multitoken.clone()]);
+
+        let (mut vp_cache, _) =
+            wasm::compilation_cache::common::testing::cache();
+        let gas_meter = TxGasMeter::new(u64::MAX);
+
+        let batched_tx = tx.batch_ref_first_tx();
+        let result = execute_vps(
+            verifiers,
+            changed_keys,
+            &batched_tx,
+            &TxIndex::default(),
+            &state,
+            &gas_meter,
+            &mut vp_cache,
+        )
+        .unwrap();
+        assert!(
+            result.accepted_vps.contains(&multitoken)
+                || result.rejected_vps.contains(&multitoken)
+        );
+        assert_eq!(result.gas_per_vp.len(), 1);
+        assert!(result.gas_per_vp[&multitoken] > namada_gas::Gas::default());
+    }
 }
@@ -10,9 +10,12 @@ use borsh_ext::BorshSerializeExt;
 use masp_primitives::transaction::Transaction;
 use namada_core::address::ESTABLISHED_ADDRESS_BYTES_LEN;
 use namada_core::arith::{self, checked};
+use namada_core::ethereum_events::EthAddress;
 use namada_core::internal::KeyVal;
+use namada_core::key::secp256k1;
 use namada_core::storage::TX_INDEX_LENGTH;
 use namada_events::{Event, EventTypeBuilder};
+use namada_gas::cost_table::HostFnGasCosts;
 use namada_gas::{
     self as gas, GasMetering, TxGasMeter, VpGasMeter,
     MEMORY_ACCESS_GAS_PER_BYTE,
@@ -271,6 +274,19 @@ where
         let sentinel = unsafe { self.sentinel.get() };
         (gas_meter, sentinel)
     }
+
+    /// Get the gas costs of the host functions
+    pub fn host_fn_gas_costs(&self) -> HostFnGasCosts {
+        #[cfg(feature = "wasm-runtime")]
+        {
+            let vp_wasm_cache = unsafe { self.vp_wasm_cache.get() };
+            vp_wasm_cache.host_fn_gas_costs()
+        }
+        #[cfg(not(feature = "wasm-runtime"))]
+        {
+            HostFnGasCosts::default()
+        }
+    }
 }
 
 impl<D, H, CA> Clone for TxCtx<D, H, CA>
@@ -555,6 +571,19 @@ where
         let gas_meter = unsafe { self.gas_meter.get() };
         gas_meter
     }
+
+    /// Get the gas costs of the host functions
+    pub fn host_fn_gas_costs(&self) -> HostFnGasCosts {
+        #[cfg(feature = "wasm-runtime")]
+        {
+            let vp_wasm_cache = unsafe { self.vp_wasm_cache.get() };
+            vp_wasm_cache.host_fn_gas_costs()
+        }
+        #[cfg(not(feature = "wasm-runtime"))]
+        {
+            HostFnGasCosts::default()
+        }
+    }
 }
 
 impl<D, H, EVAL, CA> Clone for VpCtx<D, H, EVAL, CA>
@@ -2015,13 +2044,14 @@ where
 
     let tx = unsafe { env.ctx.tx.get() };
 
+    let verify_sig_gas = env.ctx.host_fn_gas_costs().verify_tx_sig;
     match tx.verify_signatures(
         &hashes,
         public_keys_map,
         &Some(signer),
         threshold,
         max_signatures,
-        || gas_meter.borrow_mut().consume(gas::VERIFY_TX_SIG_GAS),
+        || gas_meter.borrow_mut().consume(verify_sig_gas),
     ) {
         Ok(_) => Ok(()),
         Err(err) => match err {
@@ -2036,6 +2066,53 @@ where
     }
 }
 
+/// Verify that an Ethereum `personal_sign` signature over the given message
+/// was produced by the key of the given Ethereum address
+pub fn vp_verify_eth_signature<MEM, D, H, EVAL, CA>(
+    env: &VpVmEnv<MEM, D, H, EVAL, CA>,
+    message_ptr: u64,
+    message_len: u64,
+    signature_ptr: u64,
+    signature_len: u64,
+    eth_address_ptr: u64,
+    eth_address_len: u64,
+) -> vp_host_fns::EnvResult<i64>
+where
+    MEM: VmMemory,
+    D: 'static + DB + for<'iter> DBIter<'iter>,
+    H: 'static + StorageHasher,
+    EVAL: VpEvaluator,
+    CA: WasmCacheAccess,
+{
+    let gas_meter = env.ctx.gas_meter();
+    let (message, gas) = env
+        .memory
+        .read_bytes(message_ptr, message_len.try_into()?)
+        .map_err(|e| vp_host_fns::RuntimeError::MemoryError(Box::new(e)))?;
+    vp_host_fns::add_gas(gas_meter, gas)?;
+
+    let (signature, gas) = env
+        .memory
+        .read_bytes(signature_ptr, signature_len.try_into()?)
+        .map_err(|e| vp_host_fns::RuntimeError::MemoryError(Box::new(e)))?;
+    vp_host_fns::add_gas(gas_meter, gas)?;
+
+    let (eth_address, gas) = env
+        .memory
+        .read_bytes(eth_address_ptr, eth_address_len.try_into()?)
+        .map_err(|e| vp_host_fns::RuntimeError::MemoryError(Box::new(e)))?;
+    vp_host_fns::add_gas(gas_meter, gas)?;
+    let eth_address = EthAddress::try_from_slice(&eth_address)
+        .map_err(vp_host_fns::RuntimeError::EncodingError)?;
+
+    vp_host_fns::add_gas(
+        gas_meter,
+        env.ctx.host_fn_gas_costs().verify_eth_sig,
+    )?;
+    let valid = verify_eth_signature(&message, &signature, &eth_address);
+    Ok(HostEnvResult::from(valid).to_i64())
+}
+
 /// Log a string from exposed to the wasm VM Tx environment. The message will be
 /// printed at the [`tracing::Level::INFO`]. This function is for development
 /// only.
@@ -2180,13 +2257,14 @@ where
     let tx = unsafe { env.ctx.tx.get() };
 
     let (gas_meter, sentinel) = env.ctx.gas_meter_and_sentinel();
+    let verify_sig_gas = env.ctx.host_fn_gas_costs().verify_tx_sig;
     match tx.verify_signatures(
         &hashes,
         public_keys_map,
         &None,
         threshold,
         max_signatures,
-        || gas_meter.borrow_mut().consume(gas::VERIFY_TX_SIG_GAS),
+        || gas_meter.borrow_mut().consume(verify_sig_gas),
     ) {
         Ok(_) => Ok(HostEnvResult::Success.to_i64()),
         Err(err) => match err {
@@ -2202,6 +2280,66 @@ where
     }
 }
 
+/// Verify that an Ethereum `personal_sign` signature over the given message
+/// was produced by the key of the given Ethereum address
+pub fn tx_verify_eth_signature<MEM, D, H, CA>(
+    env: &TxVmEnv<MEM, D, H, CA>,
+    message_ptr: u64,
+    message_len: u64,
+    signature_ptr: u64,
+    signature_len: u64,
+    eth_address_ptr: u64,
+    eth_address_len: u64,
+) -> TxResult<i64>
+where
+    MEM: VmMemory,
+    D: 'static + DB + for<'iter> DBIter<'iter>,
+    H: 'static + StorageHasher,
+    CA: WasmCacheAccess,
+{
+    let (message, gas) = env
+        .memory
+        .read_bytes(message_ptr, message_len.try_into()?)
+        .map_err(|e| TxRuntimeError::MemoryError(Box::new(e)))?;
+    tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+
+    let (signature, gas) = env
+        .memory
+        .read_bytes(signature_ptr, signature_len.try_into()?)
+        .map_err(|e| TxRuntimeError::MemoryError(Box::new(e)))?;
+    tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+
+    let (eth_address, gas) = env
+        .memory
+        .read_bytes(eth_address_ptr, eth_address_len.try_into()?)
+        .map_err(|e| TxRuntimeError::MemoryError(Box::new(e)))?;
+    tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+    let eth_address = EthAddress::try_from_slice(&eth_address)
+        .map_err(TxRuntimeError::EncodingError)?;
+
+    tx_charge_gas::<MEM, D, H, CA>(
+        env,
+        env.ctx.host_fn_gas_costs().verify_eth_sig,
+    )?;
+    let valid = verify_eth_signature(&message, &signature, &eth_address);
+    Ok(HostEnvResult::from(valid).to_i64())
+}
+
+/// Check that the given `r || s || v` encoded signature over the message
+/// recovers to the expected Ethereum address. Malformed signatures are
+/// simply invalid.
+fn verify_eth_signature(
+    message: &[u8],
+    signature: &[u8],
+    eth_address: &EthAddress,
+) -> bool {
+    <&[u8; secp256k1::SIGNATURE_SIZE]>::try_from(signature)
+        .ok()
+        .and_then(|sig| secp256k1::Signature::from_eth_rsv_bytes(sig).ok())
+        .and_then(|sig| sig.recover_eth_signer(message).ok())
+        .is_some_and(|signer| &signer == eth_address)
+}
+
 /// Appends the new note commitments to the tree in storage
 pub fn tx_update_masp_note_commitment_tree<MEM, D, H, CA>(
     env: &TxVmEnv<MEM, D, H, CA>,
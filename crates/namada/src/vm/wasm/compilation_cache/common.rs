@@ -15,6 +15,9 @@ use std::time::Duration;
 
 use clru::{CLruCache, CLruCacheConfig, WeightScale};
 use namada_core::collections::HashMap;
+use namada_gas::cost_table::{
+    GasCostTable, HostFnGasCosts, DEFAULT_GAS_COST_TABLE_VERSION,
+};
 use wasmer::{Module, Store};
 use wasmer_cache::{FileSystemCache, Hash as CacheHash};
 
@@ -33,6 +36,8 @@ pub struct Cache<N: CacheName, A: WasmCacheAccess> {
     progress: Arc<RwLock<HashMap<Hash, Compilation>>>,
     /// In-memory LRU cache of compiled modules
     in_memory: Arc<RwLock<MemoryCache>>,
+    /// The gas costs injected in the compiled modules
+    gas_costs: Arc<RwLock<GasCostTable>>,
     /// The cache's name
     name: PhantomData<N>,
     /// Cache access level
@@ -99,11 +104,32 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
             dir,
             progress: Default::default(),
             in_memory,
+            gas_costs: Default::default(),
             name: Default::default(),
             access: Default::default(),
         }
     }
 
+    /// Update the gas costs injected in the modules compiled from now on.
+    /// The modules compiled with a different version of the costs are not
+    /// fetched anymore.
+    pub fn update_gas_costs(&self, gas_costs: GasCostTable) {
+        if self.gas_costs.read().unwrap().version != gas_costs.version {
+            tracing::info!(
+                "{} switching to version {} of the gas costs.",
+                N::name(),
+                gas_costs.version
+            );
+            *self.gas_costs.write().unwrap() = gas_costs;
+        }
+    }
+
+    /// Get the gas costs of the host functions, from the same version of the
+    /// gas costs as the modules compiled from now on
+    pub fn host_fn_gas_costs(&self) -> HostFnGasCosts {
+        self.gas_costs.read().unwrap().host_fns
+    }
+
     /// Get a WASM module from LRU cache, from a file or compile it and cache
     /// it. If the cache access is set to [`crate::vm::WasmCacheRwAccess`], it
     /// updates the position in the LRU cache. Otherwise, the compiled
@@ -112,10 +138,11 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
         &mut self,
         code_hash: &Hash,
     ) -> Result<Option<(Module, Store)>, wasm::run::Error> {
+        let key = module_key(code_hash, self.gas_costs.read().unwrap().version);
         if A::is_read_write() {
-            self.get(code_hash)
+            self.get(&key)
         } else {
-            self.peek(code_hash)
+            self.peek(&key)
         }
     }
 
@@ -327,7 +354,8 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
         &mut self,
         code: impl AsRef<[u8]>,
     ) -> Result<Option<(Module, Store)>, wasm::run::Error> {
-        let hash = hash_of_code(&code);
+        let gas_costs = self.gas_costs.read().unwrap().clone();
+        let hash = module_key(&hash_of_code(&code), gas_costs.version);
 
         if !A::is_read_write() {
             // It doesn't update the cache and files
@@ -335,7 +363,7 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
             match progress.get(&hash) {
                 Some(_) => return self.peek(&hash),
                 None => {
-                    let code = wasm::run::prepare_wasm_code(code)?;
+                    let code = wasm::run::prepare_wasm_code(code, &gas_costs)?;
                     return Ok(Some(compile(code)?));
                 }
             }
@@ -344,14 +372,14 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
         let mut progress = self.progress.write().unwrap();
         if progress.get(&hash).is_some() {
             drop(progress);
-            return self.fetch(&hash);
+            return self.get(&hash);
         }
         progress.insert(hash, Compilation::Compiling);
         drop(progress);
 
         tracing::info!("Compiling {} {}.", N::name(), hash.to_string());
 
-        match wasm::run::prepare_wasm_code(code) {
+        match wasm::run::prepare_wasm_code(code, &gas_costs) {
             Ok(code) => match compile(code) {
                 Ok((module, store)) => {
                     // Write the file
@@ -395,7 +423,8 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
     /// thread and the function returns immediately.
     pub fn pre_compile(&mut self, code: impl AsRef<[u8]>) {
         if A::is_read_write() {
-            let hash = hash_of_code(&code);
+            let gas_costs = self.gas_costs.read().unwrap().clone();
+            let hash = module_key(&hash_of_code(&code), gas_costs.version);
             let mut progress = self.progress.write().unwrap();
             match progress.get(&hash) {
                 Some(_) => {
@@ -415,7 +444,8 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
                         tracing::info!("Compiling WASM {}.", hash.to_string());
 
                         let (_module, _store) =
-                            match wasm::run::prepare_wasm_code(code) {
+                            match wasm::run::prepare_wasm_code(code, &gas_costs)
+                            {
                                 Ok(code) => match compile(code) {
                                     Ok((module, store)) => {
                                         // Write the file
@@ -483,6 +513,7 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
             dir: self.dir.clone(),
             progress: self.progress.clone(),
             in_memory: self.in_memory.clone(),
+            gas_costs: self.gas_costs.clone(),
             name: Default::default(),
             access: Default::default(),
         }
@@ -493,6 +524,20 @@ fn hash_of_code(code: impl AsRef<[u8]>) -> Hash {
     Hash::sha256(code.as_ref())
 }
 
+/// Get the key under which the module compiled from the code with the given
+/// hash is cached. The modules are instrumented with the gas costs of the wasm
+/// opcodes, so the key depends on the version of the costs. For the default
+/// version, the key is the code hash itself.
+fn module_key(code_hash: &Hash, gas_costs_version: u64) -> Hash {
+    if gas_costs_version == DEFAULT_GAS_COST_TABLE_VERSION {
+        *code_hash
+    } else {
+        let mut bytes = code_hash.0.to_vec();
+        bytes.extend_from_slice(&gas_costs_version.to_le_bytes());
+        Hash::sha256(bytes)
+    }
+}
+
 fn compile(
     code: impl AsRef<[u8]>,
 ) -> Result<(Module, Store), wasm::run::Error> {
@@ -1061,6 +1106,51 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_fetch_or_compile_with_updated_gas_costs() {
+        let tx_no_op = load_wasm(TestWasms::TxNoOp.path());
+        let (mut cache, _tmp_dir) = cache(10);
+
+        let fetched = cache.compile_or_fetch(&tx_no_op.code).unwrap();
+        assert_matches!(fetched, Some(_), "The code should be compiled");
+        let fetched = cache.fetch(&tx_no_op.hash).unwrap();
+        assert_matches!(fetched, Some(_), "The module should be in cache");
+
+        // Updating the table without changing its version has no effect
+        let mut gas_costs = GasCostTable::default();
+        gas_costs.wasm_opcodes.insert("Nop".to_string(), 2);
+        gas_costs.host_fns.verify_tx_sig = 1;
+        cache.update_gas_costs(gas_costs.clone());
+        assert_eq!(cache.host_fn_gas_costs(), HostFnGasCosts::default());
+        let fetched = cache.fetch(&tx_no_op.hash).unwrap();
+        assert_matches!(fetched, Some(_), "The module should be in cache");
+
+        // The module compiled with the previous costs must not be fetched
+        gas_costs.version = DEFAULT_GAS_COST_TABLE_VERSION + 1;
+        gas_costs.host_fns.verify_tx_sig = 1;
+        cache.update_gas_costs(gas_costs.clone());
+        assert_eq!(cache.host_fn_gas_costs(), gas_costs.host_fns);
+        let fetched = cache.fetch(&tx_no_op.hash).unwrap();
+        assert_matches!(
+            fetched,
+            None,
+            "The module compiled with outdated costs must not be fetched"
+        );
+
+        let fetched = cache.compile_or_fetch(&tx_no_op.code).unwrap();
+        assert_matches!(fetched, Some(_), "The code should be recompiled");
+        let key = module_key(&tx_no_op.hash, gas_costs.version);
+        assert_ne!(key, tx_no_op.hash);
+        assert!(
+            module_file_exists(&cache.dir, &key),
+            "The file must be written"
+        );
+        assert!(
+            module_file_exists(&cache.dir, &tx_no_op.hash),
+            "The file compiled with the previous costs must be kept"
+        );
+    }
+
     /// Get the WASM code bytes, its hash and find the compiled module's size
     fn load_wasm(file: impl AsRef<Path>) -> WasmWithMeta {
         // When `WeightScale` calls `loupe::size_of_val` in the cache, for some
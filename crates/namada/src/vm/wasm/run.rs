@@ -9,7 +9,8 @@ use std::num::NonZeroU32;
 
 use borsh::BorshDeserialize;
 use namada_core::validity_predicate::VpError;
-use namada_gas::{GasMetering, TxGasMeter, WASM_MEMORY_PAGE_GAS};
+use namada_gas::cost_table::GasCostTable;
+use namada_gas::{GasMetering, TxGasMeter};
 use namada_state::{DBIter, State, StateRead, StorageHasher, StorageRead, DB};
 use namada_tx::data::{TxSentinel, TxType};
 use namada_tx::{BatchedTxRef, Commitment, Section, Tx, TxCommitments};
@@ -557,8 +558,12 @@ pub fn untrusted_wasm_store(limit: Limit<BaseTunables>) -> wasmer::Store {
     )
 }
 
-/// Inject gas counter and stack-height limiter into the given wasm code
-pub fn prepare_wasm_code<T: AsRef<[u8]>>(code: T) -> Result<Vec<u8>> {
+/// Inject gas counter and stack-height limiter into the given wasm code,
+/// charging the gas costs of the given table
+pub fn prepare_wasm_code<T: AsRef<[u8]>>(
+    code: T,
+    gas_costs: &GasCostTable,
+) -> Result<Vec<u8>> {
     let module: elements::Module = elements::deserialize_buffer(code.as_ref())
         .map_err(Error::DeserializationError)?;
     let module = wasm_instrument::gas_metering::inject(
@@ -566,7 +571,7 @@ pub fn prepare_wasm_code<T: AsRef<[u8]>>(code: T) -> Result<Vec<u8>> {
         wasm_instrument::gas_metering::host_function::Injector::new(
             "env", "gas",
         ),
-        &GasRules,
+        &GasRules(gas_costs),
     )
     .map_err(|_original_module| Error::GasMeterInjection)?;
     let module =
@@ -662,216 +667,221 @@ where
     }
 }
 
-struct GasRules;
+/// Gas metering rules injected in the wasm code, with the costs of the given
+/// table
+struct GasRules<'a>(&'a GasCostTable);
 
-impl wasm_instrument::gas_metering::Rules for GasRules {
+impl<'a> wasm_instrument::gas_metering::Rules for GasRules<'a> {
     fn instruction_cost(
         &self,
         instruction: &wasm_instrument::parity_wasm::elements::Instruction,
     ) -> Option<u32> {
         // NOTE: costs set to 0 don't actually trigger the injection of a call
         // to the gas host function (no useless instructions are
-        // injected)
-        // NOTE: these costs are taken from the benchmarks crate. None of them
-        // should be zero
-        let gas = match instruction {
-            Unreachable => 129_358,
-            // Just a flag, aribitrary cost of 1
-            End => 1,
-            // Just a flag, aribitrary cost of 1
-            Else => 1,
-            Nop => 1,
-            Block(_) => 1,
-            Loop(_) => 1,
-            If(_) => 4,
-            Br(_) => 27,
-            BrIf(_) => 36,
-            BrTable(_) => 70,
-            Return => 7,
-            Call(_) => 43,
-            CallIndirect(_, _) => 140,
-            Drop => 1,
-            Select => 37,
-            GetLocal(_) => 2,
-            SetLocal(_) => 2,
-            TeeLocal(_) => 2,
-            GetGlobal(_) => 3,
-            SetGlobal(_) => 4,
-            I32Load(_, _) => 5,
-            I64Load(_, _) => 5,
-            F32Load(_, _) => 6,
-            F64Load(_, _) => 6,
-            I32Load8S(_, _) => 5,
-            I32Load8U(_, _) => 5,
-            I32Load16S(_, _) => 5,
-            I32Load16U(_, _) => 5,
-            I64Load8S(_, _) => 5,
-            I64Load8U(_, _) => 5,
-            I64Load16S(_, _) => 5,
-            I64Load16U(_, _) => 5,
-            I64Load32S(_, _) => 5,
-            I64Load32U(_, _) => 5,
-            I32Store(_, _) => 5,
-            I64Store(_, _) => 7,
-            F32Store(_, _) => 5,
-            F64Store(_, _) => 6,
-            I32Store8(_, _) => 5,
-            I32Store16(_, _) => 15,
-            I64Store8(_, _) => 5,
-            I64Store16(_, _) => 15,
-            I64Store32(_, _) => 6,
-            CurrentMemory(_) => 108,
-            GrowMemory(_) => 394,
-            I32Const(_) => 1,
-            I64Const(_) => 1,
-            F32Const(_) => 1,
-            F64Const(_) => 1,
-            I32Eqz => 6,
-            I32Eq => 6,
-            I32Ne => 6,
-            I32LtS => 6,
-            I32LtU => 6,
-            I32GtS => 6,
-            I32GtU => 6,
-            I32LeS => 6,
-            I32LeU => 6,
-            I32GeS => 6,
-            I32GeU => 6,
-            I64Eqz => 7,
-            I64Eq => 7,
-            I64Ne => 7,
-            I64LtS => 7,
-            I64LtU => 7,
-            I64GtS => 7,
-            I64GtU => 7,
-            I64LeS => 7,
-            I64LeU => 7,
-            I64GeS => 7,
-            I64GeU => 7,
-            F32Eq => 8,
-            F32Ne => 8,
-            F32Lt => 8,
-            F32Gt => 8,
-            F32Le => 8,
-            F32Ge => 8,
-            F64Eq => 10,
-            F64Ne => 10,
-            F64Lt => 9,
-            F64Gt => 9,
-            F64Le => 9,
-            F64Ge => 9,
-            I32Clz => 35,
-            I32Ctz => 34,
-            I32Popcnt => 3,
-            I32Add => 3,
-            I32Sub => 3,
-            I32Mul => 5,
-            I32DivS => 17,
-            I32DivU => 17,
-            I32RemS => 41,
-            I32RemU => 17,
-            I32And => 3,
-            I32Or => 3,
-            I32Xor => 3,
-            I32Shl => 3,
-            I32ShrS => 3,
-            I32ShrU => 3,
-            I32Rotl => 3,
-            I32Rotr => 3,
-            I64Clz => 35,
-            I64Ctz => 34,
-            I64Popcnt => 3,
-            I64Add => 5,
-            I64Sub => 5,
-            I64Mul => 6,
-            I64DivS => 28,
-            I64DivU => 28,
-            I64RemS => 46,
-            I64RemU => 28,
-            I64And => 5,
-            I64Or => 5,
-            I64Xor => 5,
-            I64Shl => 4,
-            I64ShrS => 4,
-            I64ShrU => 4,
-            I64Rotl => 4,
-            I64Rotr => 4,
-            F32Abs => 4,
-            F32Neg => 3,
-            F32Ceil => 6,
-            F32Floor => 6,
-            F32Trunc => 6,
-            F32Nearest => 6,
-            F32Sqrt => 9,
-            F32Add => 6,
-            F32Sub => 6,
-            F32Mul => 6,
-            F32Div => 9,
-            F32Min => 50,
-            F32Max => 47,
-            F32Copysign => 6,
-            F64Abs => 6,
-            F64Neg => 4,
-            F64Ceil => 7,
-            F64Floor => 7,
-            F64Trunc => 7,
-            F64Nearest => 7,
-            F64Sqrt => 17,
-            F64Add => 7,
-            F64Sub => 7,
-            F64Mul => 7,
-            F64Div => 12,
-            F64Min => 52,
-            F64Max => 49,
-            F64Copysign => 11,
-            I32WrapI64 => 2,
-            I32TruncSF32 => 54,
-            I32TruncUF32 => 54,
-            I32TruncSF64 => 57,
-            I32TruncUF64 => 57,
-            I64ExtendSI32 => 2,
-            I64ExtendUI32 => 2,
-            I64TruncSF32 => 73,
-            I64TruncUF32 => 70,
-            I64TruncSF64 => 89,
-            I64TruncUF64 => 70,
-            F32ConvertSI32 => 12,
-            F32ConvertUI32 => 6,
-            F32ConvertSI64 => 6,
-            F32ConvertUI64 => 39,
-            F32DemoteF64 => 9,
-            F64ConvertSI32 => 12,
-            F64ConvertUI32 => 12,
-            F64ConvertSI64 => 12,
-            F64ConvertUI64 => 39,
-            F64PromoteF32 => 9,
-            I32ReinterpretF32 => 2,
-            I64ReinterpretF64 => 2,
-            F32ReinterpretI32 => 3,
-            F64ReinterpretI64 => 3,
-            SignExt(SignExtInstruction::I32Extend8S) => 1,
-            SignExt(SignExtInstruction::I32Extend16S) => 1,
-            SignExt(SignExtInstruction::I64Extend8S) => 1,
-            SignExt(SignExtInstruction::I64Extend16S) => 1,
-            SignExt(SignExtInstruction::I64Extend32S) => 1,
-        };
-
-        // We always return a cost, forbidden instructions should be rejected at
-        // validation time not here
-        Some(gas)
+        // injected). The table is validated before being written to storage,
+        // so every opcode has a non-zero cost. We always return a cost,
+        // forbidden instructions should be rejected at validation time not
+        // here
+        self.0.opcode_cost(opcode_name(instruction))
     }
 
     fn memory_grow_cost(
         &self,
     ) -> wasm_instrument::gas_metering::MemoryGrowCost {
         wasm_instrument::gas_metering::MemoryGrowCost::Linear(
-            NonZeroU32::new(WASM_MEMORY_PAGE_GAS)
+            NonZeroU32::new(self.0.wasm_memory_page)
                 .expect("Memory grow gas cost should be non-zero"),
         )
     }
 
     fn call_per_local_cost(&self) -> u32 {
-        1
+        self.0.call_per_local
+    }
+}
+
+/// Get the name of a wasm opcode, as used in the keys of the
+/// [`GasCostTable`]
+fn opcode_name(
+    instruction: &wasm_instrument::parity_wasm::elements::Instruction,
+) -> &'static str {
+    match instruction {
+        Unreachable => "Unreachable",
+        End => "End",
+        Else => "Else",
+        Nop => "Nop",
+        Block(_) => "Block",
+        Loop(_) => "Loop",
+        If(_) => "If",
+        Br(_) => "Br",
+        BrIf(_) => "BrIf",
+        BrTable(_) => "BrTable",
+        Return => "Return",
+        Call(_) => "Call",
+        CallIndirect(_, _) => "CallIndirect",
+        Drop => "Drop",
+        Select => "Select",
+        GetLocal(_) => "GetLocal",
+        SetLocal(_) => "SetLocal",
+        TeeLocal(_) => "TeeLocal",
+        GetGlobal(_) => "GetGlobal",
+        SetGlobal(_) => "SetGlobal",
+        I32Load(_, _) => "I32Load",
+        I64Load(_, _) => "I64Load",
+        F32Load(_, _) => "F32Load",
+        F64Load(_, _) => "F64Load",
+        I32Load8S(_, _) => "I32Load8S",
+        I32Load8U(_, _) => "I32Load8U",
+        I32Load16S(_, _) => "I32Load16S",
+        I32Load16U(_, _) => "I32Load16U",
+        I64Load8S(_, _) => "I64Load8S",
+        I64Load8U(_, _) => "I64Load8U",
+        I64Load16S(_, _) => "I64Load16S",
+        I64Load16U(_, _) => "I64Load16U",
+        I64Load32S(_, _) => "I64Load32S",
+        I64Load32U(_, _) => "I64Load32U",
+        I32Store(_, _) => "I32Store",
+        I64Store(_, _) => "I64Store",
+        F32Store(_, _) => "F32Store",
+        F64Store(_, _) => "F64Store",
+        I32Store8(_, _) => "I32Store8",
+        I32Store16(_, _) => "I32Store16",
+        I64Store8(_, _) => "I64Store8",
+        I64Store16(_, _) => "I64Store16",
+        I64Store32(_, _) => "I64Store32",
+        CurrentMemory(_) => "CurrentMemory",
+        GrowMemory(_) => "GrowMemory",
+        I32Const(_) => "I32Const",
+        I64Const(_) => "I64Const",
+        F32Const(_) => "F32Const",
+        F64Const(_) => "F64Const",
+        I32Eqz => "I32Eqz",
+        I32Eq => "I32Eq",
+        I32Ne => "I32Ne",
+        I32LtS => "I32LtS",
+        I32LtU => "I32LtU",
+        I32GtS => "I32GtS",
+        I32GtU => "I32GtU",
+        I32LeS => "I32LeS",
+        I32LeU => "I32LeU",
+        I32GeS => "I32GeS",
+        I32GeU => "I32GeU",
+        I64Eqz => "I64Eqz",
+        I64Eq => "I64Eq",
+        I64Ne => "I64Ne",
+        I64LtS => "I64LtS",
+        I64LtU => "I64LtU",
+        I64GtS => "I64GtS",
+        I64GtU => "I64GtU",
+        I64LeS => "I64LeS",
+        I64LeU => "I64LeU",
+        I64GeS => "I64GeS",
+        I64GeU => "I64GeU",
+        F32Eq => "F32Eq",
+        F32Ne => "F32Ne",
+        F32Lt => "F32Lt",
+        F32Gt => "F32Gt",
+        F32Le => "F32Le",
+        F32Ge => "F32Ge",
+        F64Eq => "F64Eq",
+        F64Ne => "F64Ne",
+        F64Lt => "F64Lt",
+        F64Gt => "F64Gt",
+        F64Le => "F64Le",
+        F64Ge => "F64Ge",
+        I32Clz => "I32Clz",
+        I32Ctz => "I32Ctz",
+        I32Popcnt => "I32Popcnt",
+        I32Add => "I32Add",
+        I32Sub => "I32Sub",
+        I32Mul => "I32Mul",
+        I32DivS => "I32DivS",
+        I32DivU => "I32DivU",
+        I32RemS => "I32RemS",
+        I32RemU => "I32RemU",
+        I32And => "I32And",
+        I32Or => "I32Or",
+        I32Xor => "I32Xor",
+        I32Shl => "I32Shl",
+        I32ShrS => "I32ShrS",
+        I32ShrU => "I32ShrU",
+        I32Rotl => "I32Rotl",
+        I32Rotr => "I32Rotr",
+        I64Clz => "I64Clz",
+        I64Ctz => "I64Ctz",
+        I64Popcnt => "I64Popcnt",
+        I64Add => "I64Add",
+        I64Sub => "I64Sub",
+        I64Mul => "I64Mul",
+        I64DivS => "I64DivS",
+        I64DivU => "I64DivU",
+        I64RemS => "I64RemS",
+        I64RemU => "I64RemU",
+        I64And => "I64And",
+        I64Or => "I64Or",
+        I64Xor => "I64Xor",
+        I64Shl => "I64Shl",
+        I64ShrS => "I64ShrS",
+        I64ShrU => "I64ShrU",
+        I64Rotl => "I64Rotl",
+        I64Rotr => "I64Rotr",
+        F32Abs => "F32Abs",
+        F32Neg => "F32Neg",
+        F32Ceil => "F32Ceil",
+        F32Floor => "F32Floor",
+        F32Trunc => "F32Trunc",
+        F32Nearest => "F32Nearest",
+        F32Sqrt => "F32Sqrt",
+        F32Add => "F32Add",
+        F32Sub => "F32Sub",
+        F32Mul => "F32Mul",
+        F32Div => "F32Div",
+        F32Min => "F32Min",
+        F32Max => "F32Max",
+        F32Copysign => "F32Copysign",
+        F64Abs => "F64Abs",
+        F64Neg => "F64Neg",
+        F64Ceil => "F64Ceil",
+        F64Floor => "F64Floor",
+        F64Trunc => "F64Trunc",
+        F64Nearest => "F64Nearest",
+        F64Sqrt => "F64Sqrt",
+        F64Add => "F64Add",
+        F64Sub => "F64Sub",
+        F64Mul => "F64Mul",
+        F64Div => "F64Div",
+        F64Min => "F64Min",
+        F64Max => "F64Max",
+        F64Copysign => "F64Copysign",
+        I32WrapI64 => "I32WrapI64",
+        I32TruncSF32 => "I32TruncSF32",
+        I32TruncUF32 => "I32TruncUF32",
+        I32TruncSF64 => "I32TruncSF64",
+        I32TruncUF64 => "I32TruncUF64",
+        I64ExtendSI32 => "I64ExtendSI32",
+        I64ExtendUI32 => "I64ExtendUI32",
+        I64TruncSF32 => "I64TruncSF32",
+        I64TruncUF32 => "I64TruncUF32",
+        I64TruncSF64 => "I64TruncSF64",
+        I64TruncUF64 => "I64TruncUF64",
+        F32ConvertSI32 => "F32ConvertSI32",
+        F32ConvertUI32 => "F32ConvertUI32",
+        F32ConvertSI64 => "F32ConvertSI64",
+        F32ConvertUI64 => "F32ConvertUI64",
+        F32DemoteF64 => "F32DemoteF64",
+        F64ConvertSI32 => "F64ConvertSI32",
+        F64ConvertUI32 => "F64ConvertUI32",
+        F64ConvertSI64 => "F64ConvertSI64",
+        F64ConvertUI64 => "F64ConvertUI64",
+        F64PromoteF32 => "F64PromoteF32",
+        I32ReinterpretF32 => "I32ReinterpretF32",
+        I64ReinterpretF64 => "I64ReinterpretF64",
+        F32ReinterpretI32 => "F32ReinterpretI32",
+        F64ReinterpretI64 => "F64ReinterpretI64",
+        SignExt(SignExtInstruction::I32Extend8S) => "I32Extend8S",
+        SignExt(SignExtInstruction::I32Extend16S) => "I32Extend16S",
+        SignExt(SignExtInstruction::I64Extend8S) => "I64Extend8S",
+        SignExt(SignExtInstruction::I64Extend16S) => "I64Extend16S",
+        SignExt(SignExtInstruction::I64Extend32S) => "I64Extend32S",
     }
 }
 
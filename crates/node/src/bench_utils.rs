@@ -14,17 +14,20 @@ use std::sync::Once;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
+use data_encoding::HEXUPPER;
 use masp_primitives::transaction::Transaction;
 use masp_primitives::zip32::ExtendedFullViewingKey;
 use masp_proofs::prover::LocalTxProver;
 use namada::address::MASP;
 use namada::core::address::{self, Address, InternalAddress};
 use namada::core::chain::ChainId;
+use namada::core::hash::Hash;
 use namada::core::key::common::SecretKey;
+use namada::core::key::tm_consensus_key_raw_hash;
 use namada::core::masp::{
     ExtendedViewingKey, PaymentAddress, TransferSource, TransferTarget,
 };
-use namada::core::storage::{BlockHeight, Epoch, Key, KeySeg, TxIndex};
+use namada::core::storage::{BlockHeight, Epoch, Header, Key, KeySeg, TxIndex};
 use namada::core::time::DateTimeUtc;
 use namada::core::token::{Amount, DenominatedAmount, Transfer};
 use namada::events::extend::{ComposeEvent, MaspTxBatchRefs, MaspTxBlockIndex};
@@ -74,10 +77,15 @@ use namada::ledger::native_vp::ibc::get_dummy_header;
 use namada::ledger::queries::{
     Client, EncodedResponseQuery, RequestCtx, RequestQuery, Router, RPC,
 };
+use namada::proof_of_stake::storage::{
+    read_consensus_validator_set_addresses_with_stake,
+    validator_consensus_key_handle,
+};
 use namada::state::StorageRead;
+use namada::tendermint::abci::types::VoteInfo;
 use namada::tx::data::pos::Bond;
 use namada::tx::data::{
-    BatchResults, BatchedTxResult, Fee, TxResult, VpsResult,
+    BatchResults, BatchedTxResult, Fee, TxResult, VpsResult, WrapperTx,
 };
 use namada::tx::event::{new_tx_event, Batch};
 use namada::tx::{
@@ -111,14 +119,22 @@ use namada_test_utils::tx_data::TxWriteData;
 use rand_core::OsRng;
 use sha2::{Digest, Sha256};
 use tempfile::TempDir;
+use tokio::sync::mpsc::UnboundedReceiver;
 
 use crate::config;
 use crate::config::global::GlobalConfig;
 use crate::config::TendermintMode;
 use crate::facade::tendermint::v0_37::abci::request::InitChain;
 use crate::facade::tendermint_proto::google::protobuf::Timestamp;
+use crate::facade::tendermint_proto::v0_37::abci::{
+    RequestPrepareProposal, RequestProcessProposal,
+};
 use crate::facade::tendermint_rpc;
 use crate::shell::Shell;
+use crate::shims::abcipp_shim_types::shim::request::{
+    FinalizeBlock, ProcessedTx,
+};
+use crate::shims::abcipp_shim_types::shim::response::ProcessProposal;
 
 pub const WASM_DIR: &str = "../../wasm";
 
@@ -127,6 +143,9 @@ pub const ALBERT_SPENDING_KEY: &str = "albert_spending";
 pub const BERTHA_PAYMENT_ADDRESS: &str = "bertha_payment";
 const BERTHA_SPENDING_KEY: &str = "bertha_spending";
 
+/// The gas limit of the wrapper txs generated for the benchmarks
+const WRAPPER_GAS_LIMIT: u64 = 1_000_000;
+
 const FILE_NAME: &str = "shielded.dat";
 const TMP_FILE_NAME: &str = "shielded.tmp";
 const SPECULATIVE_FILE_NAME: &str = "speculative_shielded.dat";
@@ -141,6 +160,8 @@ pub struct BenchShell {
     // Cache of the masp transactions and their changed keys in the last block
     // committed, the tx index coincides with the index in this collection
     pub last_block_masp_txs: Vec<(Tx, BTreeSet<Key>)>,
+    // Keep the broadcaster channel open for the protocol txs of a validator
+    _broadcast_receiver: UnboundedReceiver<Vec<u8>>,
     // NOTE: Temporary directory should be dropped last since Shell need to
     // flush data on drop
     tempdir: TempDir,
@@ -162,6 +183,14 @@ impl DerefMut for BenchShell {
 
 impl Default for BenchShell {
     fn default() -> Self {
+        Self::new(TendermintMode::Full)
+    }
+}
+
+impl BenchShell {
+    /// Initialize a bench shell in the given mode. In validator mode, the
+    /// shell runs as the genesis validator and can propose blocks.
+    pub fn new(mode: TendermintMode) -> Self {
         SHELL_INIT.call_once(|| {
             tracing_subscriber::fmt()
                 .with_env_filter(
@@ -170,12 +199,13 @@ impl Default for BenchShell {
                 .init();
         });
 
-        let (sender, _) = tokio::sync::mpsc::unbounded_channel();
+        let (sender, broadcast_receiver) =
+            tokio::sync::mpsc::unbounded_channel();
         let tempdir = tempfile::tempdir().unwrap();
         let path = tempdir.path().canonicalize().unwrap();
 
         let shell = Shell::new(
-            config::Ledger::new(path, Default::default(), TendermintMode::Full),
+            config::Ledger::new(path, Default::default(), mode),
             WASM_DIR.into(),
             sender,
             None,
@@ -186,6 +216,7 @@ impl Default for BenchShell {
         let mut bench_shell = BenchShell {
             inner: shell,
             last_block_masp_txs: vec![],
+            _broadcast_receiver: broadcast_receiver,
             tempdir,
         };
 
@@ -289,9 +320,7 @@ impl Default for BenchShell {
 
         bench_shell
     }
-}
 
-impl BenchShell {
     pub fn generate_tx(
         &self,
         wasm_code_path: &str,
@@ -618,6 +647,158 @@ impl BenchShell {
             .push((masp_tx, self.state.write_log().get_keys()));
         self.state.commit_tx();
     }
+
+    /// Wrap the given tx in a wrapper signed by `signer`, who also pays the
+    /// fees, and return the encoded tx as it would be found in the mempool
+    pub fn wrap_tx(
+        &self,
+        mut batched_tx: BatchedTx,
+        signer: &SecretKey,
+    ) -> Vec<u8> {
+        use namada::core::key::RefTo;
+        batched_tx
+            .tx
+            .update_header(namada::tx::data::TxType::Wrapper(Box::new(
+                WrapperTx::new(
+                    Fee {
+                        token: self.state.in_mem().native_token.clone(),
+                        amount_per_gas_unit: DenominatedAmount::native(
+                            1.into(),
+                        ),
+                    },
+                    signer.ref_to(),
+                    WRAPPER_GAS_LIMIT.into(),
+                ),
+            )));
+        batched_tx
+            .tx
+            .add_section(Section::Authorization(Authorization::new(
+                batched_tx.tx.sechashes(),
+                [(0, signer.clone())].into_iter().collect(),
+                None,
+            )));
+        batched_tx.tx.to_bytes()
+    }
+
+    /// Generate a mempool of `n` wrapped transparent transfers from Albert to
+    /// Bertha. The transferred amounts depend on the block height, so that
+    /// the txs of different blocks never hit the replay protection.
+    pub fn generate_transfer_batch(&self, n: u64) -> Vec<Vec<u8>> {
+        let height = self.state.in_mem().get_last_block_height();
+        (0..n)
+            .map(|i| {
+                let transfer = self.generate_tx(
+                    TX_TRANSFER_WASM,
+                    Transfer {
+                        source: defaults::albert_address(),
+                        target: defaults::bertha_address(),
+                        token: self.state.in_mem().native_token.clone(),
+                        amount: Amount::from_u64(height.0 * n + i + 1)
+                            .native_denominated(),
+                        shielded: None,
+                    },
+                    None,
+                    None,
+                    vec![&defaults::albert_keypair()],
+                );
+                self.wrap_tx(transfer, &defaults::albert_keypair())
+            })
+            .collect()
+    }
+
+    /// Produce a block out of the given mempool, running `PrepareProposal`,
+    /// `ProcessProposal`, `FinalizeBlock` and `Commit` with this shell as the
+    /// block proposer. Returns the number of txs included in the block.
+    ///
+    /// The shell must be in validator mode to propose blocks.
+    pub fn produce_block(&mut self, mempool: Vec<Vec<u8>>) -> usize {
+        let (proposer_address, votes) = self.proposer_and_votes();
+        let height = self.state.in_mem().get_last_block_height().next_height();
+        #[allow(clippy::disallowed_methods)]
+        let time = DateTimeUtc::now();
+        let timestamp = Timestamp {
+            seconds: time.0.timestamp(),
+            nanos: time.0.timestamp_subsec_nanos() as i32,
+        };
+
+        let proposal = self
+            .prepare_proposal(RequestPrepareProposal {
+                txs: mempool.into_iter().map(Into::into).collect(),
+                height: height.0 as i64,
+                time: Some(timestamp.clone()),
+                proposer_address: proposer_address.clone().into(),
+                ..Default::default()
+            })
+            .txs;
+
+        let (response, tx_results) =
+            self.process_proposal(RequestProcessProposal {
+                txs: proposal.clone(),
+                height: height.0 as i64,
+                time: Some(timestamp),
+                proposer_address: proposer_address.clone().into(),
+                ..Default::default()
+            });
+        assert_eq!(response, ProcessProposal::Accept);
+
+        let num_txs = proposal.len();
+        self.finalize_block(FinalizeBlock {
+            header: Header {
+                hash: Hash([0; 32]),
+                time,
+                next_validators_hash: Hash([0; 32]),
+            },
+            byzantine_validators: vec![],
+            txs: proposal
+                .into_iter()
+                .zip(tx_results)
+                .map(|(tx, result)| ProcessedTx { tx, result })
+                .collect(),
+            proposer_address,
+            votes,
+        })
+        .unwrap();
+        self.inner.commit();
+
+        num_txs
+    }
+
+    // Get the raw hash of the consensus key of the block proposer and the
+    // votes of the last block
+    fn proposer_and_votes(&self) -> (Vec<u8>, Vec<VoteInfo>) {
+        let params =
+            proof_of_stake::storage::read_pos_params(&self.state).unwrap();
+        let current_epoch = self.state.in_mem().get_current_epoch().0;
+        let proposer = read_consensus_validator_set_addresses_with_stake(
+            &self.state,
+            current_epoch,
+        )
+        .unwrap()
+        .into_iter()
+        .next()
+        .expect("There must be a consensus validator");
+        let consensus_key = validator_consensus_key_handle(&proposer.address)
+            .get(&self.state, current_epoch, &params)
+            .unwrap()
+            .unwrap();
+        let proposer_address = HEXUPPER
+            .decode(tm_consensus_key_raw_hash(&consensus_key).as_bytes())
+            .unwrap();
+        let votes = vec![VoteInfo {
+            validator: tendermint::abci::types::Validator {
+                address: proposer_address.clone().try_into().unwrap(),
+                power: u64::try_from(
+                    u128::try_from(proposer.bonded_stake).unwrap(),
+                )
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            },
+            sig_info: tendermint::abci::types::BlockSignatureInfo::LegacySigned,
+        }];
+
+        (proposer_address, votes)
+    }
 }
 
 pub fn generate_foreign_key_tx(signer: &SecretKey) -> BatchedTx {
@@ -952,7 +1133,14 @@ impl Client for BenchShell {
 
 impl Default for BenchShieldedCtx {
     fn default() -> Self {
-        let shell = BenchShell::default();
+        Self::new(TendermintMode::Full)
+    }
+}
+
+impl BenchShieldedCtx {
+    /// Initialize a shielded context over a bench shell in the given mode
+    pub fn new(mode: TendermintMode) -> Self {
+        let shell = BenchShell::new(mode);
         let base_dir = shell.tempdir.as_ref().canonicalize().unwrap();
 
         // Create a global config and an empty wallet in the chain dir - this is
@@ -1029,9 +1217,7 @@ impl Default for BenchShieldedCtx {
             wallet: chain_ctx.wallet,
         }
     }
-}
 
-impl BenchShieldedCtx {
     pub fn generate_masp_tx(
         mut self,
         amount: Amount,
@@ -1054,6 +1240,7 @@ impl BenchShieldedCtx {
                 None,
                 &[spending_key.into()],
                 &[],
+                &Default::default(),
             ))
             .unwrap();
         let native_token = self.shell.state.in_mem().native_token.clone();
@@ -1128,6 +1315,27 @@ impl BenchShieldedCtx {
         (ctx, tx)
     }
 
+    /// Generate a mempool of `n` wrapped shielding transfers from Albert's
+    /// transparent address to Albert's payment address
+    pub fn generate_shielding_batch(mut self, n: u64) -> (Self, Vec<Vec<u8>>) {
+        let payment_address = self
+            .wallet
+            .find_payment_addr(ALBERT_PAYMENT_ADDRESS)
+            .unwrap()
+            .to_owned();
+        let mut mempool = vec![];
+        for i in 0..n {
+            let (ctx, tx) = self.generate_masp_tx(
+                Amount::native_whole(i + 1),
+                TransferSource::Address(defaults::albert_address()),
+                TransferTarget::PaymentAddress(payment_address),
+            );
+            mempool.push(ctx.shell.wrap_tx(tx, &defaults::albert_keypair()));
+            self = ctx;
+        }
+        (self, mempool)
+    }
+
     pub fn generate_shielded_action(
         self,
         amount: Amount,
@@ -113,6 +113,12 @@ pub mod eth_events {
             })
         }
 
+        /// Raise the number of confirmations needed to consider this
+        /// event finalized to at least `confirmations`.
+        pub fn require_confirmations(&mut self, confirmations: Uint256) {
+            self.confirmations = self.confirmations.max(confirmations);
+        }
+
         /// Check if the minimum number of confirmations has been
         /// reached at the input block height.
         pub fn is_confirmed(&self, height: &Uint256) -> bool {
@@ -2,32 +2,39 @@ pub mod control;
 pub mod events;
 pub mod test_tools;
 
-use std::ops::ControlFlow;
-
 use async_trait::async_trait;
 use ethabi::Address;
-use ethbridge_events::{event_codecs, EventKind};
+use ethbridge_events::{event_codecs, DynEventCodec, EventKind};
 use itertools::Either;
-use namada::control_flow::time::{Constant, Duration, Instant, Sleep};
+use namada::control_flow::time::{
+    Duration, ExponentialBackoff, Instant, SleepStrategy,
+};
 use namada::core::ethereum_events::EthereumEvent;
-use namada::core::{ethereum_structs, hints};
+use namada::core::ethereum_structs;
+use namada::core::keccak::keccak_hash;
 use namada::eth_bridge::ethers;
 use namada::eth_bridge::ethers::providers::{Http, Middleware, Provider};
 use namada::eth_bridge::oracle::config::Config;
 use namada_sdk::eth_bridge::{eth_syncing_status_timeout, SyncStatus};
 use num256::Uint256;
+use num_traits::{CheckedSub, ToPrimitive};
 use thiserror::Error;
 use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::mpsc::Sender as BoundedSender;
 use tokio::task::LocalSet;
 
 use self::events::PendingEvent;
+use self::status::Status;
 use super::abortable::AbortableSpawner;
+use crate::metrics::METRICS;
 use crate::oracle::control::Command;
 
 /// The default amount of time the oracle will wait between processing blocks
 const DEFAULT_BACKOFF: Duration = Duration::from_millis(500);
 const DEFAULT_CEILING: Duration = Duration::from_secs(30);
+/// The base of the exponential backoff between the retries of a block, after
+/// failing to query the Ethereum RPC endpoint
+const FAILURE_BACKOFF_BASE: u64 = 2;
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -81,15 +88,42 @@ pub trait RpcClient {
     where
         Self: Sized;
 
-    /// Query a block for Ethereum events from a given ABI type
-    /// and contract address.
+    /// Query a block for Ethereum events matching any of the given ABI
+    /// types, emitted by some contract address. The logs are returned
+    /// in the order they were emitted, i.e. sorted by (block height,
+    /// log index).
     async fn check_events_in_block(
         &self,
         block: ethereum_structs::BlockHeight,
         address: Address,
-        abi_signature: &str,
+        abi_signatures: &[&str],
     ) -> Result<Vec<Self::Log>, Error>;
 
+    /// Query a range of blocks, both ends included, for Ethereum events
+    /// matching any of the given ABI types, emitted by some contract
+    /// address. The logs are returned along with the height of the block
+    /// that emitted them, in the order they were emitted.
+    ///
+    /// By default, the blocks of the range are queried one by one.
+    async fn check_events_in_range(
+        &self,
+        from: ethereum_structs::BlockHeight,
+        to: ethereum_structs::BlockHeight,
+        address: Address,
+        abi_signatures: &[&str],
+    ) -> Result<Vec<(ethereum_structs::BlockHeight, Self::Log)>, Error> {
+        let mut logs = vec![];
+        let mut block = from;
+        while block <= to {
+            let block_logs = self
+                .check_events_in_block(block.clone(), address, abi_signatures)
+                .await?;
+            logs.extend(block_logs.into_iter().map(|log| (block.clone(), log)));
+            block = block.next();
+        }
+        Ok(logs)
+    }
+
     /// Check if the fullnode we are connected to is syncing or is up
     /// to date with the Ethereum (an return the block height).
     ///
@@ -123,7 +157,7 @@ impl RpcClient for Provider<Http> {
         &self,
         block: ethereum_structs::BlockHeight,
         contract_address: Address,
-        abi_signature: &str,
+        abi_signatures: &[&str],
     ) -> Result<Vec<Self::Log>, Error> {
         let height = {
             let n: Uint256 = block.into();
@@ -131,21 +165,69 @@ impl RpcClient for Provider<Http> {
                 n.0.try_into().expect("Ethereum block number overflow");
             n
         };
-        self.get_logs(
-            &ethers::types::Filter::new()
-                .from_block(height)
-                .to_block(height)
-                .event(abi_signature)
-                .address(contract_address),
-        )
-        .await
-        .map_err(|error| {
+        let mut logs = self
+            .get_logs(
+                &ethers::types::Filter::new()
+                    .from_block(height)
+                    .to_block(height)
+                    .events(abi_signatures)
+                    .address(contract_address),
+            )
+            .await
+            .map_err(|error| {
+                Error::CheckEvents(
+                    abi_signatures.join(", "),
+                    contract_address,
+                    error.to_string(),
+                )
+            })?;
+        // Do not rely on the RPC endpoint to order the logs of
+        // different event types
+        logs.sort_by_key(|log| (log.block_number, log.log_index));
+        Ok(logs)
+    }
+
+    async fn check_events_in_range(
+        &self,
+        from: ethereum_structs::BlockHeight,
+        to: ethereum_structs::BlockHeight,
+        contract_address: Address,
+        abi_signatures: &[&str],
+    ) -> Result<Vec<(ethereum_structs::BlockHeight, Self::Log)>, Error> {
+        let to_u64 = |block: ethereum_structs::BlockHeight| -> u64 {
+            let n: Uint256 = block.into();
+            n.0.try_into().expect("Ethereum block number overflow")
+        };
+        let check_events_error = |reason: String| {
             Error::CheckEvents(
-                abi_signature.into(),
+                abi_signatures.join(", "),
                 contract_address,
-                error.to_string(),
+                reason,
             )
-        })
+        };
+        let mut logs = self
+            .get_logs(
+                &ethers::types::Filter::new()
+                    .from_block(to_u64(from))
+                    .to_block(to_u64(to))
+                    .events(abi_signatures)
+                    .address(contract_address),
+            )
+            .await
+            .map_err(|error| check_events_error(error.to_string()))?;
+        // Do not rely on the RPC endpoint to order the logs of
+        // different event types
+        logs.sort_by_key(|log| (log.block_number, log.log_index));
+        logs.into_iter()
+            .map(|log| {
+                let block = log.block_number.ok_or_else(|| {
+                    check_events_error(
+                        "Got an event log without a block number".into(),
+                    )
+                })?;
+                Ok((block.as_u64().into(), log))
+            })
+            .collect()
     }
 
     async fn syncing(
@@ -171,10 +253,9 @@ impl RpcClient for Provider<Http> {
 
     #[inline(always)]
     fn may_recover(&self, error: &Error) -> bool {
-        !matches!(
-            error,
-            Error::Timeout | Error::Channel(_, _) | Error::CheckEvents(_, _, _)
-        )
+        // An unresponsive endpoint may come back, in which case the oracle
+        // resumes from the block it was processing
+        !matches!(error, Error::Channel(_, _))
     }
 }
 
@@ -191,8 +272,16 @@ pub struct Oracle<C = Provider<Http>> {
     last_processed_block: last_processed_block::Sender,
     /// How long the oracle should wait between checking blocks
     backoff: Duration,
-    /// How long the oracle should allow the fullnode to be unresponsive
+    /// How long the oracle should allow the fullnode to be unresponsive.
+    /// This is also the longest wait between the retries of a block.
     ceiling: Duration,
+    /// How long the oracle may fail to query the fullnode before it
+    /// reports itself as degraded
+    stall_threshold: Duration,
+    /// The maximum number of blocks whose events are queried at once
+    max_block_range: u64,
+    /// The health of the oracle is published here.
+    status: status::Sender,
     /// A channel for controlling and configuring the oracle.
     control: control::Receiver,
 }
@@ -200,12 +289,16 @@ pub struct Oracle<C = Provider<Http>> {
 impl<C: RpcClient> Oracle<C> {
     /// Construct a new [`Oracle`]. Note that it can not do anything until it
     /// has been sent a configuration via the passed in `control` channel.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         client_or_url: Either<C, &str>,
         sender: BoundedSender<EthereumEvent>,
         last_processed_block: last_processed_block::Sender,
+        status: status::Sender,
         backoff: Duration,
         ceiling: Duration,
+        stall_threshold: Duration,
+        max_block_range: u64,
         control: control::Receiver,
     ) -> Self {
         Self {
@@ -216,7 +309,10 @@ impl<C: RpcClient> Oracle<C> {
             sender,
             backoff,
             ceiling,
+            stall_threshold,
+            max_block_range: max_block_range.max(1),
             last_processed_block,
+            status,
             control,
         }
     }
@@ -240,14 +336,44 @@ impl<C: RpcClient> Oracle<C> {
         true
     }
 
-    /// Check if a new config has been sent from the Shell.
+    /// Check if a new config has been sent from the Shell. If several
+    /// configs are pending, only the most recent one is returned.
     fn update_config(&mut self) -> Option<Config> {
-        match self.control.try_recv() {
-            Ok(Command::UpdateConfig(config)) => Some(config),
-            Err(TryRecvError::Disconnected) => panic!(
-                "The Ethereum oracle command channel has unexpectedly hung up."
+        let mut latest_config = None;
+        loop {
+            match self.control.try_recv() {
+                Ok(Command::UpdateConfig(config)) => {
+                    latest_config = Some(config);
+                }
+                Err(TryRecvError::Disconnected) => panic!(
+                    "The Ethereum oracle command channel has unexpectedly \
+                     hung up."
+                ),
+                Err(TryRecvError::Empty) => return latest_config,
+            }
+        }
+    }
+
+    /// Publish the health of the oracle, logging any change to it
+    fn update_status(&self, new_status: Status) {
+        let changed = self.status.send_if_modified(|status| {
+            let changed = *status != new_status;
+            *status = new_status;
+            changed
+        });
+        if !changed {
+            return;
+        }
+        match new_status {
+            Status::Healthy => tracing::info!(
+                "The Ethereum oracle has recovered and is querying its RPC \
+                 endpoint again"
+            ),
+            Status::Degraded => tracing::warn!(
+                stall_threshold = ?self.stall_threshold,
+                "The Ethereum oracle is degraded, as it has failed to query \
+                 its RPC endpoint for longer than the stall threshold"
             ),
-            _ => None,
         }
     }
 
@@ -283,6 +409,9 @@ pub fn run_oracle<C: RpcClient>(
     sender: BoundedSender<EthereumEvent>,
     control: control::Receiver,
     last_processed_block: last_processed_block::Sender,
+    status: status::Sender,
+    stall_threshold: Duration,
+    max_block_range: u64,
     spawner: &mut AbortableSpawner,
 ) -> tokio::task::JoinHandle<()> {
     let url = url.as_ref().to_owned();
@@ -297,8 +426,11 @@ pub fn run_oracle<C: RpcClient>(
                         Either::Right(&url),
                         sender,
                         last_processed_block,
+                        status,
                         DEFAULT_BACKOFF,
                         DEFAULT_CEILING,
+                        stall_threshold,
+                        max_block_range,
                         control,
                     );
                     run_oracle_aux(oracle).await;
@@ -320,18 +452,21 @@ pub fn run_oracle<C: RpcClient>(
 }
 
 /// Determine what action to take after attempting to
-/// process events contained in an Ethereum block.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// process events contained in a range of Ethereum blocks.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum ProcessEventAction {
     /// No events could be processed at this time, so we must keep
     /// polling for new events.
     ContinuePollingEvents,
+    /// The Ethereum RPC endpoint could not be queried, so we must
+    /// retry the current height after backing off.
+    RetryAfterFailure,
     /// Some error occurred while processing Ethereum events in
     /// the current height. We must halt the oracle.
     HaltOracle,
-    /// The current Ethereum block height has been processed.
-    /// We must advance to the next Ethereum height.
-    ProceedToNextBlock,
+    /// The Ethereum blocks up to and including the given height have
+    /// been processed. We must advance to the next Ethereum height.
+    ProceedToNextBlock(ethereum_structs::BlockHeight),
 }
 
 impl ProcessEventAction {
@@ -339,43 +474,31 @@ impl ProcessEventAction {
     #[inline]
     #[allow(dead_code)]
     pub fn process_new_block(&self) -> bool {
-        matches!(self, Self::ProceedToNextBlock)
-    }
-}
-
-impl ProcessEventAction {
-    /// Handles the requested oracle action, translating it to a format
-    /// understood by the set of [`Sleep`] abstractions.
-    fn handle(self) -> ControlFlow<Result<(), ()>, ()> {
-        match self {
-            ProcessEventAction::ContinuePollingEvents => {
-                ControlFlow::Continue(())
-            }
-            ProcessEventAction::HaltOracle => ControlFlow::Break(Err(())),
-            ProcessEventAction::ProceedToNextBlock => {
-                ControlFlow::Break(Ok(()))
-            }
-        }
+        matches!(self, Self::ProceedToNextBlock(_))
     }
 }
 
-/// Tentatively process a batch of Ethereum events.
+/// Tentatively process a batch of Ethereum events, from the blocks of at
+/// most `block_range` heights starting at `next_block_to_process`.
 pub(crate) async fn try_process_eth_events<C: RpcClient>(
     oracle: &Oracle<C>,
     config: &Config,
     next_block_to_process: &ethereum_structs::BlockHeight,
+    block_range: u64,
 ) -> ProcessEventAction {
-    process_events_in_block(next_block_to_process, oracle, config)
+    process_events_in_range(next_block_to_process, block_range, oracle, config)
         .await
         .map_or_else(
             |error| {
-                if oracle.client.may_recover(&error) {
+                if let Error::MoreConfirmations = error {
+                    ProcessEventAction::ContinuePollingEvents
+                } else if oracle.client.may_recover(&error) {
                     tracing::debug!(
                         %error,
                         block = ?next_block_to_process,
                         "Error while trying to process Ethereum block"
                     );
-                    ProcessEventAction::ContinuePollingEvents
+                    ProcessEventAction::RetryAfterFailure
                 } else {
                     tracing::error!(
                         reason = %error,
@@ -385,7 +508,7 @@ pub(crate) async fn try_process_eth_events<C: RpcClient>(
                     ProcessEventAction::HaltOracle
                 }
             },
-            |()| ProcessEventAction::ProceedToNextBlock,
+            ProcessEventAction::ProceedToNextBlock,
         )
 }
 
@@ -416,54 +539,108 @@ async fn run_oracle_aux<C: RpcClient>(mut oracle: Oracle<C>) {
 
     let mut next_block_to_process = config.start_block.clone();
 
+    // The wait between the retries of a block grows exponentially while
+    // the Ethereum RPC endpoint can't be queried, up to the ceiling
+    let (backoff, ceiling) = (oracle.backoff, oracle.ceiling);
+    let failure_backoff = ExponentialBackoff {
+        base: FAILURE_BACKOFF_BASE,
+        as_duration: move |factor: u64| {
+            backoff.saturating_mul(u32::try_from(factor).unwrap_or(u32::MAX))
+        },
+    }
+    .map(move |backoff| std::cmp::min(backoff, ceiling));
+    let mut failed_retries = 0;
+    // When the oracle started failing to query the endpoint
+    let mut failing_since: Option<Instant> = None;
+    // The number of blocks queried at once. It is halved whenever the
+    // endpoint fails to serve a range, and doubled again up to the
+    // configured maximum whenever a range is served.
+    let mut block_range = oracle.max_block_range;
+
     loop {
         tracing::info!(
             ?next_block_to_process,
-            "Checking Ethereum block for bridge events"
+            block_range,
+            "Checking Ethereum blocks for bridge events"
         );
-        let res = Sleep { strategy: Constant(oracle.backoff) }.run(|| async {
-            tokio::select! {
-                action = try_process_eth_events(&oracle, &config, &next_block_to_process) => {
-                    action.handle()
-                },
-                _ = oracle.sender.closed() => {
-                    tracing::info!(
-                        "Ethereum oracle can not send events to the ledger; the \
-                        receiver has hung up. Shutting down"
-                    );
-                    ControlFlow::Break(Err(()))
+        let action = tokio::select! {
+            action = try_process_eth_events(&oracle, &config, &next_block_to_process, block_range) => {
+                action
+            },
+            _ = oracle.sender.closed() => {
+                tracing::info!(
+                    "Ethereum oracle can not send events to the ledger; the \
+                    receiver has hung up. Shutting down"
+                );
+                ProcessEventAction::HaltOracle
+            }
+        };
+        match action {
+            ProcessEventAction::HaltOracle => break,
+            ProcessEventAction::RetryAfterFailure if block_range > 1 => {
+                // The endpoint may be unable to serve the range, e.g. if it
+                // holds too many logs, so retry right away with a smaller
+                // one
+                block_range /= 2;
+                tracing::debug!(
+                    ?next_block_to_process,
+                    block_range,
+                    "Retrying with a smaller range of Ethereum blocks"
+                );
+            }
+            ProcessEventAction::RetryAfterFailure => {
+                let since = *failing_since.get_or_insert_with(Instant::now);
+                if since.elapsed() >= oracle.stall_threshold {
+                    oracle.update_status(Status::Degraded);
                 }
+                // Back off before retrying the same block
+                tokio::time::sleep(failure_backoff.backoff(&failed_retries))
+                    .await;
+                failure_backoff.next_state(&mut failed_retries);
+            }
+            ProcessEventAction::ContinuePollingEvents => {
+                // The endpoint is reachable, but the block is not confirmed
+                // yet. Wait before retrying the same block.
+                failing_since = None;
+                failed_retries = 0;
+                oracle.update_status(Status::Healthy);
+                tokio::time::sleep(oracle.backoff).await;
+            }
+            ProcessEventAction::ProceedToNextBlock(last_processed_block) => {
+                failing_since = None;
+                failed_retries = 0;
+                block_range =
+                    block_range.saturating_mul(2).min(oracle.max_block_range);
+                oracle.update_status(Status::Healthy);
+                next_block_to_process = last_processed_block.next();
+                oracle
+                    .last_processed_block
+                    .send_replace(Some(last_processed_block));
             }
-        })
-        .await;
-
-        if hints::unlikely(res.is_err()) {
-            break;
         }
-
-        oracle
-            .last_processed_block
-            .send_replace(Some(next_block_to_process.clone()));
-        // check if a new config has been sent.
+        // Check if a new config has been sent. This is also done between
+        // the retries of a block, so that e.g. a new bridge contract or a
+        // lower number of confirmations takes effect right away.
         if let Some(new_config) = oracle.update_config() {
+            tracing::info!(?new_config, "Oracle received a new configuration");
             config = new_config;
         }
         if !config.active {
             config = oracle.wait_on_reactivation().await;
         }
-        next_block_to_process = next_block_to_process.next();
     }
 }
 
-/// Checks if the given block has any events relating to the bridge, and if so,
-/// sends them to the oracle's `sender` channel
-async fn process_events_in_block<C: RpcClient>(
-    block_to_process: &ethereum_structs::BlockHeight,
+/// Checks if the blocks of a range of at most `block_range` heights, starting
+/// at the given block, have any events relating to the bridge, and if so,
+/// sends them to the oracle's `sender` channel. Returns the last block of the
+/// range that was processed.
+async fn process_events_in_range<C: RpcClient>(
+    first_block: &ethereum_structs::BlockHeight,
+    block_range: u64,
     oracle: &Oracle<C>,
     config: &Config,
-) -> Result<(), Error> {
-    let mut queue: Vec<PendingEvent> = vec![];
-    let pending = &mut queue;
+) -> Result<ethereum_structs::BlockHeight, Error> {
     // update the latest block height
 
     let last_processed_block_ref = oracle.last_processed_block.borrow();
@@ -480,12 +657,15 @@ async fn process_events_in_block<C: RpcClient>(
         SyncStatus::Syncing => return Err(Error::FallenBehind),
     }
     .into();
-    let minimum_latest_block = block_to_process.clone().unchecked_add(
-        ethereum_structs::BlockHeight::from(config.min_confirmations),
-    );
+    // no event may be acted on before the lowest number of confirmations
+    // configured for any of the event types has been reached
+    let lowest_min_confirmations =
+        ethereum_structs::BlockHeight::from(config.lowest_min_confirmations());
+    let minimum_latest_block =
+        first_block.unchecked_add(lowest_min_confirmations.clone());
     if minimum_latest_block > latest_block {
         tracing::debug!(
-            ?block_to_process,
+            ?first_block,
             ?latest_block,
             ?minimum_latest_block,
             "Waiting for enough Ethereum blocks to be synced"
@@ -493,52 +673,102 @@ async fn process_events_in_block<C: RpcClient>(
         return Err(Error::MoreConfirmations);
     }
     tracing::debug!(
-        ?block_to_process,
+        ?first_block,
         ?latest_block,
         "Got latest Ethereum block height"
     );
-    // check for events in Ethereum blocks that have reached the minimum number
-    // of confirmations
-    for codec in event_codecs() {
-        let sig = codec.event_signature();
-        let addr: Address = match codec.kind() {
-            EventKind::Bridge => config.bridge_contract.into(),
-        };
+    if let Some(lag) = latest_block.checked_sub(first_block) {
+        METRICS.eth_oracle_lag.set(lag.to_i64().unwrap_or(i64::MAX));
+    }
+    // the range ends at the last block with the lowest number of
+    // confirmations, if it is reached before the maximum range
+    let last_confirmed_block: ethereum_structs::BlockHeight = latest_block
+        .checked_sub(<&Uint256>::from(&lowest_min_confirmations))
+        .expect("The first block of the range is confirmed")
+        .into();
+    let last_block = std::cmp::min(
+        first_block.unchecked_add(block_range.saturating_sub(1)),
+        last_confirmed_block,
+    );
+    // check for events in the Ethereum blocks of the range, fetching the
+    // events of all the types emitted by a contract at once
+    let mut events_by_contract = vec![];
+    for (addr, codecs) in event_codecs_by_contract(config) {
+        let sigs: Vec<_> =
+            codecs.iter().map(|codec| codec.event_signature()).collect();
+        let sig_refs: Vec<&str> = sigs.iter().map(AsRef::as_ref).collect();
+        let sig = sig_refs.join(", ");
+        // the keccak hash of an event signature is the first topic of
+        // its logs
+        let sig_hashes: Vec<_> =
+            sig_refs.iter().map(|sig| keccak_hash(sig).0).collect();
         tracing::debug!(
-            ?block_to_process,
+            ?first_block,
+            ?last_block,
             ?addr,
             ?sig,
             "Checking for bridge events"
         );
-        // fetch the events for matching the given signature
-        let mut events = {
+        // fetch the events matching any of the given signatures
+        let events = {
             let logs = oracle
                 .client
-                .check_events_in_block(block_to_process.clone(), addr, &sig)
+                .check_events_in_range(
+                    first_block.clone(),
+                    last_block.clone(),
+                    addr,
+                    &sig_refs,
+                )
                 .await?;
             if !logs.is_empty() {
                 tracing::info!(
-                    ?block_to_process,
+                    ?first_block,
+                    ?last_block,
                     ?addr,
                     ?sig,
                     n_events = logs.len(),
-                    "Found bridge events in Ethereum block"
+                    "Found bridge events in Ethereum blocks"
                 )
             }
+            // the logs are sorted by (block height, log index), so the
+            // events are queued in the order they were emitted
             logs.into_iter()
-                .map(IntoEthAbiLog::into_ethabi_log)
-                .filter_map(|log| {
+                .filter_map(|(block, log)| {
+                    let log = log.into_ethabi_log();
+                    let Some(codec) = log.topics.first().and_then(|topic| {
+                        sig_hashes
+                            .iter()
+                            .position(|hash| *hash == topic.0)
+                            .map(|index| codecs[index])
+                    }) else {
+                        tracing::error!(
+                            ?block,
+                            ?addr,
+                            ?sig,
+                            "Got an event log of an unexpected type: {:#?}",
+                            log
+                        );
+                        return None;
+                    };
                     match PendingEvent::decode(
                         codec,
-                        block_to_process.clone().into(),
+                        block.clone().into(),
                         &log,
-                        u64::from(config.min_confirmations).into(),
+                        u64::from(config.lowest_min_confirmations()).into(),
                     ) {
-                        Ok(event) => Some(event),
+                        Ok(mut event) => {
+                            event.require_confirmations(
+                                u64::from(
+                                    config.min_confirmations_for(&event.event),
+                                )
+                                .into(),
+                            );
+                            Some((block, event))
+                        }
                         Err(error) => {
                             tracing::error!(
                                 ?error,
-                                ?block_to_process,
+                                ?block,
                                 ?addr,
                                 ?sig,
                                 "Couldn't decode event: {:#?}",
@@ -548,36 +778,95 @@ async fn process_events_in_block<C: RpcClient>(
                         }
                     }
                 })
-                .collect()
+                .collect::<Vec<_>>()
         };
-        pending.append(&mut events);
+        events_by_contract.push((addr, sig, events));
+    }
+    // the blocks from the first one holding events with a higher confirmation
+    // depth than the lowest one which has not been reached must be retried
+    // later on, such that no events are sent out of order
+    let first_unconfirmed_block = events_by_contract
+        .iter()
+        .flat_map(|(_, _, events)| events)
+        .filter(|(block, pending)| {
+            let required_latest_block = block
+                .unchecked_add(config.min_confirmations_for(&pending.event));
+            required_latest_block > latest_block
+        })
+        .map(|(block, _)| block.clone())
+        .min();
+    let last_block = match first_unconfirmed_block {
+        Some(block) if block == *first_block => {
+            tracing::debug!(
+                ?first_block,
+                ?latest_block,
+                "Waiting for enough Ethereum blocks to be synced to confirm \
+                 the events in the block"
+            );
+            return Err(Error::MoreConfirmations);
+        }
+        Some(block) => Uint256::from(block)
+            .checked_sub(&Uint256::from(1u64))
+            .expect("The first unconfirmed block is after the first block")
+            .into(),
+        None => last_block,
+    };
+    for (addr, sig, events) in events_by_contract {
+        let mut pending: Vec<_> = events
+            .into_iter()
+            .filter(|(block, _)| *block <= last_block)
+            .map(|(_, event)| event)
+            .collect();
         if !pending.is_empty() {
             tracing::info!(
-                ?block_to_process,
+                ?first_block,
+                ?last_block,
                 ?addr,
                 ?sig,
                 pending = pending.len(),
                 "There are Ethereum events pending"
             );
         }
-        let confirmed = process_queue(&latest_block, pending);
+        let confirmed = process_queue(&latest_block, &mut pending);
         if !confirmed.is_empty() {
             tracing::info!(
-                ?block_to_process,
+                ?first_block,
+                ?last_block,
                 ?addr,
                 ?sig,
                 pending = pending.len(),
                 confirmed = confirmed.len(),
-                min_confirmations = ?config.min_confirmations,
                 "Some events that have reached the minimum number of \
                  confirmations and will be sent onwards"
             );
         }
         if !oracle.send(confirmed).await {
-            return Err(Error::Channel(sig.into(), addr));
+            return Err(Error::Channel(sig, addr));
+        }
+    }
+    Ok(last_block)
+}
+
+/// Group the codecs of the Ethereum events relevant to the bridge by the
+/// address of the contract that emits them, preserving the order of the
+/// codecs
+fn event_codecs_by_contract(
+    config: &Config,
+) -> Vec<(Address, Vec<DynEventCodec>)> {
+    let mut codecs_by_contract: Vec<(Address, Vec<DynEventCodec>)> = vec![];
+    for codec in event_codecs() {
+        let addr: Address = match codec.kind() {
+            EventKind::Bridge => config.bridge_contract.into(),
+        };
+        match codecs_by_contract
+            .iter_mut()
+            .find(|(contract, _)| *contract == addr)
+        {
+            Some((_, codecs)) => codecs.push(codec),
+            None => codecs_by_contract.push((addr, vec![codec])),
         }
     }
-    Ok(())
+    codecs_by_contract
 }
 
 /// Check which events in the queue have reached their
@@ -600,6 +889,31 @@ fn process_queue(
     confirmed
 }
 
+pub mod status {
+    //! Functionality to do with publishing the health of the oracle.
+    use tokio::sync::watch;
+
+    /// The health of the oracle.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum Status {
+        /// The oracle is able to query its Ethereum RPC endpoint.
+        Healthy,
+        /// The oracle has failed to query its Ethereum RPC endpoint for
+        /// longer than the configured stall threshold.
+        Degraded,
+    }
+
+    pub type Sender = watch::Sender<Status>;
+    pub type Receiver = watch::Receiver<Status>;
+
+    /// Construct a [`tokio::sync::watch`] channel to publish the health of
+    /// the oracle. The oracle is considered healthy until it fails to query
+    /// its RPC endpoint for long enough.
+    pub fn channel() -> (Sender, Receiver) {
+        watch::channel(Status::Healthy)
+    }
+}
+
 pub mod last_processed_block {
     //! Functionality to do with publishing which blocks we have processed.
     use namada::core::ethereum_structs;
@@ -620,11 +934,13 @@ pub mod last_processed_block {
 mod test_oracle {
     use std::num::NonZeroU64;
 
+    use assert_matches::assert_matches;
     use ethbridge_bridge_events::{TransferToChainFilter, TransferToErcFilter};
     use namada::core::address::testing::gen_established_address;
     use namada::core::ethereum_events::{EthAddress, TransferToEthereum};
     use namada::core::hash::Hash;
     use namada::eth_bridge::ethers::types::H160;
+    use namada::eth_bridge::storage::parameters::EventConfirmations;
     use namada::eth_bridge::structs::Erc20Transfer;
     use tokio::sync::oneshot::channel;
     use tokio::time::timeout;
@@ -632,7 +948,8 @@ mod test_oracle {
     use super::*;
     use crate::ethereum_oracle::test_tools::event_log::GetLog;
     use crate::ethereum_oracle::test_tools::mock_web3_client::{
-        event_signature, TestCmd, TestOracle, Web3Client, Web3Controller,
+        event_signature, MockEvent, TestCmd, TestOracle, Web3Client,
+        Web3Controller,
     };
 
     /// The data returned from setting up a test
@@ -683,6 +1000,9 @@ mod test_oracle {
                 // backoff should be short for tests so that they run faster
                 backoff: Duration::from_millis(5),
                 ceiling: DEFAULT_CEILING,
+                stall_threshold: DEFAULT_CEILING,
+                max_block_range: 1,
+                status: status::channel().0,
                 control: control_receiver,
             },
             controller,
@@ -954,6 +1274,258 @@ mod test_oracle {
         oracle.await.expect("Test failed");
     }
 
+    /// Test that events of different types logged in the same block are
+    /// sent in the order they were emitted, rather than grouped by type
+    #[tokio::test]
+    async fn test_events_in_emission_order() {
+        let TestPackage {
+            oracle,
+            mut eth_recv,
+            controller,
+            blocks_processed_recv: _processed,
+            mut control_sender,
+        } = setup();
+        let min_confirmations = 10;
+        let config = Config {
+            min_confirmations: NonZeroU64::try_from(min_confirmations)
+                .expect("Test wasn't set up correctly"),
+            ..Config::default()
+        };
+        let oracle =
+            start_with_default_config(oracle, &mut control_sender, config)
+                .await;
+
+        let transfer_to_chain = |nonce: u64| {
+            TransferToChainFilter {
+                nonce: nonce.into(),
+                transfers: vec![],
+                confirmations: 10.into(),
+            }
+            .get_log()
+        };
+        let transfer_to_erc = TransferToErcFilter {
+            transfers: vec![],
+            relayer_address: gen_established_address().to_string(),
+            nonce: 0.into(),
+        }
+        .get_log();
+
+        // interleave the types of the events logged in the same block
+        let logs = [
+            (
+                event_signature::<TransferToChainFilter>(),
+                transfer_to_chain(0),
+            ),
+            (event_signature::<TransferToErcFilter>(), transfer_to_erc),
+            (
+                event_signature::<TransferToChainFilter>(),
+                transfer_to_chain(1),
+            ),
+        ];
+        for (event_type, log) in logs {
+            let (sender, _) = channel();
+            controller.apply_cmd(TestCmd::NewEvent {
+                event_type,
+                log,
+                height: 5,
+                seen: sender,
+            });
+        }
+        controller.apply_cmd(TestCmd::NewHeight(Uint256::from(50u32)));
+
+        let mut received = vec![];
+        for _ in 0..3 {
+            let event =
+                timeout(std::time::Duration::from_secs(3), eth_recv.recv())
+                    .await
+                    .expect("Timed out waiting for an event")
+                    .expect("Test failed");
+            received.push(match event {
+                EthereumEvent::TransfersToNamada { nonce, .. } => (true, nonce),
+                EthereumEvent::TransfersToEthereum { nonce, .. } => {
+                    (false, nonce)
+                }
+                event => panic!("Test failed, {:?}", event),
+            });
+        }
+        assert_eq!(
+            received,
+            vec![(true, 0.into()), (false, 0.into()), (true, 1.into())]
+        );
+
+        drop(eth_recv);
+        oracle.await.expect("Test failed");
+    }
+
+    /// Test that events whose type requires a higher number of confirmations
+    /// than the global minimum are only sent once they have reached it
+    #[tokio::test]
+    async fn test_event_type_confirmations() {
+        let TestPackage {
+            oracle,
+            mut eth_recv,
+            controller,
+            blocks_processed_recv: _processed,
+            mut control_sender,
+        } = setup();
+        let min_confirmations = 10;
+        let transfers_to_namada_confirmations = 20;
+        let config = Config {
+            min_confirmations: NonZeroU64::try_from(min_confirmations)
+                .expect("Test wasn't set up correctly"),
+            event_confirmations: EventConfirmations {
+                transfers_to_namada: Some(
+                    NonZeroU64::try_from(transfers_to_namada_confirmations)
+                        .expect("Test wasn't set up correctly")
+                        .into(),
+                ),
+                ..Default::default()
+            },
+            ..Config::default()
+        };
+        let oracle =
+            start_with_default_config(oracle, &mut control_sender, config)
+                .await;
+
+        let new_event = TransferToChainFilter {
+            nonce: 0.into(),
+            transfers: vec![],
+            confirmations: min_confirmations.into(),
+        }
+        .get_log();
+        let (sender, _) = channel();
+        controller.apply_cmd(TestCmd::NewEvent {
+            event_type: event_signature::<TransferToChainFilter>(),
+            log: new_event,
+            height: 5,
+            seen: sender,
+        });
+
+        // the global minimum has been reached, but not the one of
+        // transfers to Namada
+        controller.apply_cmd(TestCmd::NewHeight(Uint256::from(
+            5 + transfers_to_namada_confirmations - 1,
+        )));
+        assert!(
+            timeout(std::time::Duration::from_secs(1), eth_recv.recv())
+                .await
+                .is_err()
+        );
+
+        controller.apply_cmd(TestCmd::NewHeight(Uint256::from(
+            5 + transfers_to_namada_confirmations,
+        )));
+        let event = timeout(std::time::Duration::from_secs(3), eth_recv.recv())
+            .await
+            .expect("Timed out waiting for the event")
+            .expect("Test failed");
+        assert_matches!(
+            event,
+            EthereumEvent::TransfersToNamada { nonce, .. }
+                if nonce == 0.into()
+        );
+
+        drop(eth_recv);
+        oracle.await.expect("Test failed");
+    }
+
+    /// Test that events from blocks orphaned by a reorg shallower than the
+    /// minimum number of confirmations are dropped, while events included
+    /// in the new fork are emitted
+    #[tokio::test]
+    async fn test_reorg_below_confirmation_depth() {
+        let TestPackage {
+            oracle,
+            mut eth_recv,
+            controller,
+            blocks_processed_recv: _processed,
+            mut control_sender,
+        } = setup();
+        let min_confirmations = 10;
+        let config = Config {
+            min_confirmations: NonZeroU64::try_from(min_confirmations)
+                .expect("Test wasn't set up correctly"),
+            ..Config::default()
+        };
+        let oracle =
+            start_with_default_config(oracle, &mut control_sender, config)
+                .await;
+
+        let first_event = TransferToChainFilter {
+            nonce: 0.into(),
+            transfers: vec![],
+            confirmations: 10.into(),
+        }
+        .get_log();
+        let second_event = TransferToChainFilter {
+            nonce: 1.into(),
+            transfers: vec![],
+            confirmations: 10.into(),
+        }
+        .get_log();
+
+        // the first event is logged in a block which has not been confirmed
+        let (sender, orphaned_seen) = channel();
+        controller.apply_cmd(TestCmd::NewEvent {
+            event_type: event_signature::<TransferToChainFilter>(),
+            log: first_event.clone(),
+            height: 5,
+            seen: sender,
+        });
+        // the block is orphaned, and the first event is included again
+        // in a later block of the new fork
+        let (first_sender, first_seen) = channel();
+        let (second_sender, second_seen) = channel();
+        controller.apply_cmd(TestCmd::Reorg {
+            from_height: 3,
+            new_events: vec![
+                MockEvent {
+                    event_type: event_signature::<TransferToChainFilter>(),
+                    log: second_event,
+                    height: 6,
+                    seen: second_sender,
+                },
+                MockEvent {
+                    event_type: event_signature::<TransferToChainFilter>(),
+                    log: first_event,
+                    height: 8,
+                    seen: first_sender,
+                },
+            ],
+        });
+        // the orphaned event was never fetched by the oracle
+        assert!(orphaned_seen.await.is_err());
+
+        // increase block height so both events of the new fork are confirmed
+        controller.apply_cmd(TestCmd::NewHeight(Uint256::from(30u32)));
+        second_seen.await.expect("Test failed");
+        first_seen.await.expect("Test failed");
+
+        // check the events are received in the order of the new fork
+        for expected_nonce in [1u64, 0] {
+            let event = eth_recv.recv().await.expect("Test failed");
+            if let EthereumEvent::TransfersToNamada { nonce, transfers } = event
+            {
+                assert_eq!(nonce, expected_nonce.into());
+                assert!(transfers.is_empty());
+            } else {
+                panic!("Test failed, {:?}", event);
+            }
+        }
+
+        // check no other events are received
+        let mut time = std::time::Duration::from_secs(1);
+        while time > std::time::Duration::from_millis(10) {
+            assert!(eth_recv.try_recv().is_err());
+            time -= std::time::Duration::from_millis(10);
+        }
+        // the oracle never processed a block that was later orphaned
+        assert!(controller.orphaned_blocks_processed().is_empty());
+
+        drop(eth_recv);
+        oracle.await.expect("Test failed");
+    }
+
     /// Test that Ethereum blocks are processed in sequence up to the latest
     /// block that has reached the minimum number of confirmations
     #[tokio::test]
@@ -1064,11 +1636,9 @@ mod test_oracle {
             assert_eq!(block_processed, Uint256::from(height));
         }
 
-        // Deactivate the bridge before all confirmed events are confirmed and
-        // processed There is a very fine needle to thread here. A block
-        // must be processed **after** this config is sent in order for
-        // the updated config to be received. However, this test can
-        // flake due to channel race conditions.
+        // Deactivate the bridge while the oracle is waiting for the next
+        // block to be confirmed. The updated config is received between
+        // the retries of the block.
         control_sender
             .try_send(Command::UpdateConfig(Config {
                 active: false,
@@ -1080,16 +1650,8 @@ mod test_oracle {
             min_confirmations + confirmed_block_height - 4,
         )));
 
-        let block_processed = timeout(
-            std::time::Duration::from_secs(3),
-            blocks_processed_recv.recv(),
-        )
-        .await
-        .expect("Timed out waiting for block to be checked")
-        .unwrap();
-        assert_eq!(block_processed, Uint256::from(confirmed_block_height - 4));
-
-        // check that the oracle hasn't yet checked any further blocks
+        // check that the oracle hasn't checked any further blocks, even
+        // though the next one is now confirmed
         let res = timeout(
             std::time::Duration::from_secs(3),
             blocks_processed_recv.recv(),
@@ -1106,7 +1668,56 @@ mod test_oracle {
         controller.apply_cmd(TestCmd::NewHeight(Uint256::from(
             min_confirmations + confirmed_block_height,
         )));
-        for height in (confirmed_block_height - 3)..=confirmed_block_height {
+        for height in (confirmed_block_height - 4)..=confirmed_block_height {
+            let block_processed = timeout(
+                std::time::Duration::from_secs(3),
+                blocks_processed_recv.recv(),
+            )
+            .await
+            .expect("Timed out waiting for block to be checked")
+            .unwrap();
+            assert_eq!(block_processed, Uint256::from(height));
+        }
+        drop(eth_recv);
+        oracle.await.expect("Test failed");
+    }
+
+    /// Test that a new config sent while the oracle is waiting for a block
+    /// to be confirmed is used right away
+    #[tokio::test]
+    async fn test_update_config_while_waiting() {
+        let TestPackage {
+            oracle,
+            eth_recv,
+            controller,
+            mut blocks_processed_recv,
+            mut control_sender,
+        } = setup();
+        let config = Config::default();
+        let oracle = start_with_default_config(
+            oracle,
+            &mut control_sender,
+            config.clone(),
+        )
+        .await;
+
+        // with the initial config, no block has enough confirmations
+        controller.apply_cmd(TestCmd::NewHeight(Uint256::from(10u32)));
+        let res = timeout(
+            std::time::Duration::from_secs(1),
+            blocks_processed_recv.recv(),
+        )
+        .await;
+        assert!(res.is_err());
+
+        // lower the number of confirmations, without any new Ethereum block
+        control_sender
+            .try_send(Command::UpdateConfig(Config {
+                min_confirmations: NonZeroU64::new(5).unwrap(),
+                ..config
+            }))
+            .expect("Test failed");
+        for height in 0u64..=5 {
             let block_processed = timeout(
                 std::time::Duration::from_secs(3),
                 blocks_processed_recv.recv(),
@@ -1120,6 +1731,56 @@ mod test_oracle {
         oracle.await.expect("Test failed");
     }
 
+    /// Test that the oracle reports itself as degraded once its RPC endpoint
+    /// has been unresponsive for longer than the stall threshold, and that it
+    /// recovers once the endpoint comes back
+    #[tokio::test]
+    async fn test_stall_detection() {
+        let TestPackage {
+            mut oracle,
+            eth_recv,
+            controller,
+            mut blocks_processed_recv,
+            mut control_sender,
+        } = setup();
+        let (status_sender, mut status_recv) = status::channel();
+        oracle.status = status_sender;
+        oracle.stall_threshold = Duration::from_millis(50);
+        controller.apply_cmd(TestCmd::Unresponsive);
+        let config = Config::default();
+        controller.apply_cmd(TestCmd::NewHeight(Uint256::from(u64::from(
+            config.min_confirmations,
+        ))));
+        let oracle =
+            start_with_default_config(oracle, &mut control_sender, config)
+                .await;
+
+        timeout(std::time::Duration::from_secs(3), status_recv.changed())
+            .await
+            .expect("Timed out waiting for the oracle to be degraded")
+            .unwrap();
+        assert_eq!(*status_recv.borrow_and_update(), Status::Degraded);
+
+        // the oracle resumes from the block it failed to process
+        controller.apply_cmd(TestCmd::Normal);
+        let block_processed = timeout(
+            std::time::Duration::from_secs(3),
+            blocks_processed_recv.recv(),
+        )
+        .await
+        .expect("Timed out waiting for block to be checked")
+        .unwrap();
+        assert_eq!(block_processed, Uint256::from(0u64));
+        timeout(std::time::Duration::from_secs(3), status_recv.changed())
+            .await
+            .expect("Timed out waiting for the oracle to recover")
+            .unwrap();
+        assert_eq!(*status_recv.borrow(), Status::Healthy);
+
+        drop(eth_recv);
+        oracle.await.expect("Test failed");
+    }
+
     /// Test that if the Ethereum RPC endpoint returns a latest block that is
     /// more than one block later than the previous latest block we received, we
     /// still check all the blocks in between
@@ -1183,4 +1844,70 @@ mod test_oracle {
         drop(eth_recv);
         oracle.await.expect("Test failed");
     }
+
+    /// Test that the oracle queries ranges of blocks at once, falling back
+    /// to smaller ranges when the endpoint fails to serve them, without
+    /// skipping any block or event
+    #[tokio::test]
+    async fn test_block_range_fallback() {
+        let TestPackage {
+            mut oracle,
+            mut eth_recv,
+            controller,
+            mut blocks_processed_recv,
+            mut control_sender,
+        } = setup();
+        oracle.max_block_range = 50;
+        let config = Config::default();
+        let oracle = start_with_default_config(
+            oracle,
+            &mut control_sender,
+            config.clone(),
+        )
+        .await;
+        controller.apply_cmd(TestCmd::BlockRangeLimit(10));
+
+        let new_event = TransferToChainFilter {
+            nonce: 0.into(),
+            transfers: vec![],
+            confirmations: 100.into(),
+        }
+        .get_log();
+        let (sender, _) = channel();
+        controller.apply_cmd(TestCmd::NewEvent {
+            event_type: event_signature::<TransferToChainFilter>(),
+            log: new_event,
+            height: 25,
+            seen: sender,
+        });
+
+        let confirmed_block_height = 40;
+        let synced_block_height =
+            u64::from(config.min_confirmations) + confirmed_block_height;
+        controller
+            .apply_cmd(TestCmd::NewHeight(Uint256::from(synced_block_height)));
+
+        // check that all the confirmed blocks are processed in order
+        for height in 0u64..confirmed_block_height + 1 {
+            let block_processed = timeout(
+                std::time::Duration::from_secs(3),
+                blocks_processed_recv.recv(),
+            )
+            .await
+            .expect("Timed out waiting for block to be checked")
+            .unwrap();
+            assert_eq!(block_processed, Uint256::from(height));
+        }
+        let event = timeout(std::time::Duration::from_secs(3), eth_recv.recv())
+            .await
+            .expect("Timed out waiting for an event")
+            .expect("Test failed");
+        assert_matches!(
+            event,
+            EthereumEvent::TransfersToNamada { nonce, .. } if nonce == 0.into()
+        );
+
+        drop(eth_recv);
+        oracle.await.expect("Test failed");
+    }
 }
@@ -1,38 +1,314 @@
 use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use borsh::BorshDeserialize;
+use borsh_ext::BorshSerializeExt;
+use data_encoding::{HEXLOWER, HEXLOWER_PERMISSIVE};
+use hmac::{Hmac, Mac, NewMac};
 use namada::core::ethereum_events::EthereumEvent;
+use namada::core::ethereum_structs;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
 use tokio::sync::mpsc::Sender as BoundedSender;
 use tokio::sync::oneshot::{Receiver, Sender};
 use warp::reply::WithStatus;
 use warp::Filter;
 
 use crate::ethereum_oracle as oracle;
+use crate::ethereum_oracle::last_processed_block;
 
 /// The endpoint to which Borsh-serialized Ethereum events should be sent to,
 /// via an HTTP POST request.
 const EVENTS_POST_ENDPOINT: &str = "eth_events";
 
+/// The endpoint to which a JSON array of [`BatchedEvent`]s should be sent to,
+/// via an HTTP POST request.
+const EVENTS_BATCH_POST_ENDPOINT: &str = "eth_events_batch";
+
+/// The maximum delay of an event submitted as part of a batch.
+pub const MAX_BATCH_DELAY: Duration = Duration::from_secs(60 * 60);
+
+/// The header holding the nonce of an authenticated request. Nonces are UNIX
+/// timestamps in nanoseconds, and must be strictly increasing across all the
+/// requests made to the endpoint since it was started.
+pub const NONCE_HEADER: &str = "x-namada-nonce";
+
+/// How far ahead of the clock of the node the nonce of a request may be.
+const MAX_NONCE_CLOCK_DRIFT: Duration = Duration::from_secs(60);
+
+/// The header holding the hex-encoded HMAC-SHA256 of an authenticated
+/// request, as computed by [`sign_request`].
+pub const SIGNATURE_HEADER: &str = "x-namada-signature";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Compute the hex-encoded HMAC-SHA256 authenticating a request made to the
+/// endpoint at `path` with the given nonce and body, keyed with the secret
+/// shared with the ledger.
+pub fn sign_request(
+    secret: &[u8],
+    nonce: u64,
+    path: &str,
+    body: &[u8],
+) -> String {
+    HEXLOWER.encode(
+        &request_mac(secret, nonce, path, body)
+            .finalize()
+            .into_bytes(),
+    )
+}
+
+fn request_mac(
+    secret: &[u8],
+    nonce: u64,
+    path: &str,
+    body: &[u8],
+) -> HmacSha256 {
+    let mut mac = HmacSha256::new_varkey(secret)
+        .expect("HMAC should accept keys of any length");
+    mac.update(&nonce.to_be_bytes());
+    // NB: the variable length fields are prefixed with their length, so that
+    // bytes can't be moved from one field to the next
+    for field in [path.as_bytes(), body] {
+        let len = u64::try_from(field.len()).expect("Length should fit in u64");
+        mac.update(&len.to_be_bytes());
+        mac.update(field);
+    }
+    mac
+}
+
+/// The current UNIX timestamp in nanoseconds.
+fn unix_timestamp_nanos() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .and_then(|elapsed| u64::try_from(elapsed.as_nanos()).ok())
+        .unwrap_or_default()
+}
+
+/// Authenticates the requests made to the events endpoint with a shared
+/// secret, and rejects replayed requests.
+#[derive(Clone)]
+struct Authenticator {
+    secret: Arc<Vec<u8>>,
+    /// The nonce of the last authenticated request, starting from the time
+    /// the endpoint was started. Requests signed before the node restarted
+    /// thus can't be replayed, without having to persist the nonce.
+    last_nonce: Arc<Mutex<u64>>,
+}
+
+impl Authenticator {
+    fn new(secret: Vec<u8>) -> Self {
+        Self {
+            secret: Arc::new(secret),
+            last_nonce: Arc::new(Mutex::new(unix_timestamp_nanos())),
+        }
+    }
+
+    /// Check that a request made to the endpoint at `path` was signed with
+    /// the shared secret and that its nonce was never used before.
+    fn authenticate(
+        &self,
+        path: &str,
+        nonce: Option<u64>,
+        signature: Option<&str>,
+        body: &[u8],
+    ) -> Result<(), &'static str> {
+        let (Some(nonce), Some(signature)) = (nonce, signature) else {
+            return Err("missing nonce or signature");
+        };
+        let signature = HEXLOWER_PERMISSIVE
+            .decode(signature.as_bytes())
+            .map_err(|_| "malformed signature")?;
+        request_mac(&self.secret, nonce, path, body)
+            .verify(&signature)
+            .map_err(|_| "invalid signature")?;
+        // NB: the nonce is only consumed once the request was authenticated,
+        // so that forged requests can't be used to lock out the clients
+        let mut last_nonce = self.last_nonce.lock().unwrap();
+        if nonce <= *last_nonce {
+            return Err("replayed nonce");
+        }
+        let max_drift = u64::try_from(MAX_NONCE_CLOCK_DRIFT.as_nanos())
+            .expect("The max clock drift should fit in u64");
+        if nonce > unix_timestamp_nanos().saturating_add(max_drift) {
+            return Err("nonce too far in the future");
+        }
+        *last_nonce = nonce;
+        Ok(())
+    }
+}
+
+/// Authenticate a request if the endpoint requires it, returning the reply
+/// to send back if the request is rejected.
+fn authorize(
+    auth: Option<&Authenticator>,
+    path: &str,
+    nonce: Option<u64>,
+    signature: Option<&str>,
+    body: &[u8],
+) -> Result<(), WithStatus<&'static str>> {
+    let Some(auth) = auth else {
+        return Ok(());
+    };
+    auth.authenticate(path, nonce, signature, body)
+        .map_err(|error| {
+            tracing::warn!(error, path, "Rejected unauthenticated request");
+            warp::reply::with_status(
+                "Unauthorized",
+                warp::http::StatusCode::UNAUTHORIZED,
+            )
+        })
+}
+
+/// An Ethereum event submitted as part of a batch to the events endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchedEvent {
+    /// The hex-encoded Borsh serialization of the Ethereum event
+    pub event: String,
+    /// The delay after the reception of the batch, in milliseconds, before
+    /// the event is forwarded to the ledger. Batches with delays longer than
+    /// [`MAX_BATCH_DELAY`] are rejected.
+    #[serde(default)]
+    pub delay_ms: u64,
+    /// The Ethereum block height to report as the last processed block once
+    /// the event has been forwarded to the ledger
+    #[serde(default)]
+    pub target_height: Option<u64>,
+}
+
+impl BatchedEvent {
+    /// Create a new batched event, to be forwarded without delay.
+    pub fn new(event: &EthereumEvent) -> Self {
+        Self {
+            event: HEXLOWER.encode(&event.serialize_to_vec()),
+            delay_ms: 0,
+            target_height: None,
+        }
+    }
+
+    /// Forward the event after the given delay.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        self
+    }
+
+    /// Report the given Ethereum block height as processed after forwarding
+    /// the event.
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.target_height = Some(height);
+        self
+    }
+
+    /// The delay before the event is forwarded to the ledger, unless it
+    /// exceeds [`MAX_BATCH_DELAY`].
+    fn delay(&self) -> Option<Duration> {
+        Some(Duration::from_millis(self.delay_ms))
+            .filter(|delay| *delay <= MAX_BATCH_DELAY)
+    }
+
+    /// Decode the Ethereum event in this batch entry.
+    fn decode(&self) -> Option<EthereumEvent> {
+        let bytes = HEXLOWER.decode(self.event.as_bytes()).ok()?;
+        EthereumEvent::try_from_slice(&bytes).ok()
+    }
+}
+
 /// Starts a [`warp::Server`] that listens for Borsh-serialized Ethereum events
 /// and then forwards them to `sender`. It shuts down if a signal is sent on the
 /// `abort_recv` channel. Accepts the receive-half of an oracle control channel
 /// (`control_recv`) that will be kept alive until shutdown.
+///
+/// Batches of events can also be submitted as a JSON array of
+/// [`BatchedEvent`]s, in which case the target heights of the events are
+/// published to `last_processed_block`.
+///
+/// If a hex-encoded `secret` is given, requests are only accepted if they
+/// carry a fresh nonce and a valid signature (see [`sign_request`]).
 pub async fn serve(
     listen_addr: String,
+    secret: Option<String>,
     sender: BoundedSender<EthereumEvent>,
+    last_processed_block: last_processed_block::Sender,
     mut control_recv: oracle::control::Receiver,
     abort_recv: Receiver<Sender<()>>,
 ) {
     let listen_addr: SocketAddr = listen_addr
         .parse()
         .expect("Failed to parse the events endpoint listen address");
+    let auth = secret.map(|secret| {
+        let secret = HEXLOWER_PERMISSIVE
+            .decode(secret.as_bytes())
+            .expect("Failed to decode the events endpoint secret");
+        Authenticator::new(secret)
+    });
+    if auth.is_none() {
+        tracing::warn!(
+            ?listen_addr,
+            "No secret was configured for the Ethereum event endpoint, \
+             requests will not be authenticated"
+        );
+    }
     tracing::info!(?listen_addr, "Ethereum event endpoint is starting");
+    let batch_sender = sender.clone();
+    let batch_auth = auth.clone();
     let eth_events = warp::post()
         .and(warp::path(EVENTS_POST_ENDPOINT))
+        .and(warp::path::end())
+        .and(warp::header::optional::<u64>(NONCE_HEADER))
+        .and(warp::header::optional::<String>(SIGNATURE_HEADER))
         .and(warp::body::bytes())
-        .then(move |bytes: bytes::Bytes| send(bytes, sender.clone()));
+        .then(
+            move |nonce: Option<u64>,
+                  signature: Option<String>,
+                  bytes: bytes::Bytes| {
+                let auth = auth.clone();
+                let sender = sender.clone();
+                async move {
+                    if let Err(reply) = authorize(
+                        auth.as_ref(),
+                        EVENTS_POST_ENDPOINT,
+                        nonce,
+                        signature.as_deref(),
+                        &bytes,
+                    ) {
+                        return reply;
+                    }
+                    send(bytes, sender).await
+                }
+            },
+        );
+    let eth_events_batch = warp::post()
+        .and(warp::path(EVENTS_BATCH_POST_ENDPOINT))
+        .and(warp::path::end())
+        .and(warp::header::optional::<u64>(NONCE_HEADER))
+        .and(warp::header::optional::<String>(SIGNATURE_HEADER))
+        .and(warp::body::bytes())
+        .then(
+            move |nonce: Option<u64>,
+                  signature: Option<String>,
+                  bytes: bytes::Bytes| {
+                let auth = batch_auth.clone();
+                let sender = batch_sender.clone();
+                let last_processed_block = last_processed_block.clone();
+                async move {
+                    if let Err(reply) = authorize(
+                        auth.as_ref(),
+                        EVENTS_BATCH_POST_ENDPOINT,
+                        nonce,
+                        signature.as_deref(),
+                        &bytes,
+                    ) {
+                        return reply;
+                    }
+                    send_batch(bytes, sender, last_processed_block).await
+                }
+            },
+        );
+    let routes = eth_events.or(eth_events_batch);
 
-    let (_, future) = warp::serve(eth_events).bind_with_graceful_shutdown(
+    let (_, future) = warp::serve(routes).bind_with_graceful_shutdown(
         listen_addr,
         async move {
             tracing::info!(
@@ -101,3 +377,200 @@ async fn send(
         }
     }
 }
+
+/// Callback to send out a batch of events from the oracle. The events are
+/// validated upfront, and then forwarded in the background in the order of
+/// their delays, such that a batch can be used to reorder events.
+async fn send_batch(
+    bytes: bytes::Bytes,
+    sender: BoundedSender<EthereumEvent>,
+    last_processed_block: last_processed_block::Sender,
+) -> WithStatus<&'static str> {
+    let batch: Vec<BatchedEvent> = match serde_json::from_slice(&bytes) {
+        Ok(batch) => batch,
+        Err(error) => {
+            tracing::warn!(?error, "Couldn't parse batch request");
+            return warp::reply::with_status(
+                "Bad request",
+                warp::http::StatusCode::BAD_REQUEST,
+            );
+        }
+    };
+    tracing::info!(len = batch.len(), "Received batch request");
+    let mut events = Vec::with_capacity(batch.len());
+    for entry in batch {
+        let Some(event) = entry.decode() else {
+            tracing::warn!(?entry, "Couldn't decode batched event");
+            return warp::reply::with_status(
+                "Bad request",
+                warp::http::StatusCode::BAD_REQUEST,
+            );
+        };
+        let Some(delay) = entry.delay() else {
+            tracing::warn!(
+                ?entry,
+                max_delay = ?MAX_BATCH_DELAY,
+                "The delay of the batched event is too long"
+            );
+            return warp::reply::with_status(
+                "Bad request",
+                warp::http::StatusCode::BAD_REQUEST,
+            );
+        };
+        events.push((delay, entry.target_height, event));
+    }
+    // NB: the sort is stable, so events with the same delay are forwarded
+    // in the order they were submitted
+    events.sort_by_key(|(delay, _, _)| *delay);
+    tokio::spawn(async move {
+        let start = tokio::time::Instant::now();
+        for (delay, target_height, event) in events {
+            tokio::time::sleep_until(start + delay).await;
+            tracing::debug!("Serialized event - {:#?}", event);
+            if let Err(error) = sender.send(event).await {
+                tracing::warn!(?error, "Couldn't send batched event");
+                return;
+            }
+            if let Some(height) = target_height {
+                last_processed_block.send_replace(Some(
+                    ethereum_structs::BlockHeight::from(height),
+                ));
+            }
+        }
+    });
+    warp::reply::with_status("OK", warp::http::StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use namada::core::ethereum_events::Uint;
+
+    use super::*;
+
+    /// Test that batched events can be decoded back from their JSON
+    /// representation.
+    #[test]
+    fn test_batched_event_roundtrip() {
+        let event = EthereumEvent::TransfersToNamada {
+            nonce: Uint::from(1u64),
+            transfers: vec![],
+        };
+        let batch = vec![
+            BatchedEvent::new(&event)
+                .with_delay(Duration::from_millis(250))
+                .at_height(42),
+            BatchedEvent::new(&event),
+        ];
+        let json = serde_json::to_string(&batch).expect("Test failed");
+        let decoded: Vec<BatchedEvent> =
+            serde_json::from_str(&json).expect("Test failed");
+        assert_eq!(decoded, batch);
+        assert_eq!(decoded[0].delay_ms, 250);
+        assert_eq!(decoded[0].target_height, Some(42));
+        assert_eq!(decoded[1].decode(), Some(event));
+
+        // the delay and target height can be omitted
+        let json = format!(r#"[{{"event": "{}"}}]"#, batch[1].event);
+        let decoded: Vec<BatchedEvent> =
+            serde_json::from_str(&json).expect("Test failed");
+        assert_eq!(decoded[0], batch[1]);
+    }
+
+    /// Test that batched events can't be delayed for longer than
+    /// [`MAX_BATCH_DELAY`].
+    #[test]
+    fn test_batched_event_max_delay() {
+        let event = BatchedEvent::new(&EthereumEvent::TransfersToNamada {
+            nonce: Uint::from(1u64),
+            transfers: vec![],
+        });
+        assert_eq!(event.delay(), Some(Duration::ZERO));
+        let event = event.with_delay(MAX_BATCH_DELAY);
+        assert_eq!(event.delay(), Some(MAX_BATCH_DELAY));
+        let event = event.with_delay(Duration::MAX);
+        assert_eq!(event.delay_ms, u64::MAX);
+        assert_eq!(event.delay(), None);
+    }
+
+    /// Test that only requests signed with the shared secret and carrying a
+    /// fresh nonce are authenticated.
+    #[test]
+    fn test_events_endpoint_authentication() {
+        let secret = b"shared secret".to_vec();
+        let auth = Authenticator::new(secret.clone());
+        let body = b"event";
+        let path = EVENTS_POST_ENDPOINT;
+        let start = *auth.last_nonce.lock().unwrap();
+
+        let nonce = start + 1;
+        let signature = sign_request(&secret, nonce, path, body);
+        assert!(auth
+            .authenticate(path, Some(nonce), Some(&signature), body)
+            .is_ok());
+        // the same request can't be replayed
+        assert_eq!(
+            auth.authenticate(path, Some(nonce), Some(&signature), body),
+            Err("replayed nonce")
+        );
+        // nor can older nonces be used, including the ones signed before the
+        // endpoint was started
+        for nonce in [start, start - 1] {
+            let signature = sign_request(&secret, nonce, path, body);
+            assert_eq!(
+                auth.authenticate(path, Some(nonce), Some(&signature), body),
+                Err("replayed nonce")
+            );
+        }
+        // nor nonces too far in the future
+        let drift = u64::try_from(MAX_NONCE_CLOCK_DRIFT.as_nanos()).unwrap();
+        let nonce = unix_timestamp_nanos() + 2 * drift;
+        let signature = sign_request(&secret, nonce, path, body);
+        assert_eq!(
+            auth.authenticate(path, Some(nonce), Some(&signature), body),
+            Err("nonce too far in the future")
+        );
+
+        // the signature must cover the nonce, path and body of the request
+        let nonce = start + 2;
+        let signature = sign_request(&secret, nonce, path, body);
+        for (path, nonce, body) in [
+            (EVENTS_BATCH_POST_ENDPOINT, nonce, &body[..]),
+            (path, nonce + 1, &body[..]),
+            (path, nonce, &b"other event"[..]),
+        ] {
+            assert_eq!(
+                auth.authenticate(path, Some(nonce), Some(&signature), body),
+                Err("invalid signature")
+            );
+        }
+        // and bytes can't be moved from the path to the body
+        let shifted_body = [&b"_batch"[..], &body[..]].concat();
+        let shifted_signature =
+            sign_request(&secret, nonce, path, &shifted_body);
+        assert_eq!(
+            auth.authenticate(
+                EVENTS_BATCH_POST_ENDPOINT,
+                Some(nonce),
+                Some(&shifted_signature),
+                body
+            ),
+            Err("invalid signature")
+        );
+        // rejected requests don't consume their nonce
+        assert!(auth
+            .authenticate(path, Some(nonce), Some(&signature), body)
+            .is_ok());
+
+        // requests signed with another secret are rejected
+        let nonce = start + 3;
+        let signature = sign_request(b"other secret", nonce, path, body);
+        assert_eq!(
+            auth.authenticate(path, Some(nonce), Some(&signature), body),
+            Err("invalid signature")
+        );
+        assert_eq!(
+            auth.authenticate(path, Some(nonce), None, body),
+            Err("missing nonce or signature")
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! A fake Ethereum node, serving the subset of the Ethereum JSON-RPC API
+//! queried by the oracle (`eth_blockNumber`, `eth_syncing` and
+//! `eth_getLogs`). It can be embedded in tests to run the real oracle
+//! without an external Ethereum node.
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use namada::core::ethereum_events::EthAddress;
+use namada::eth_bridge::ethers::types::{Bytes, Log, H160, H256, U256, U64};
+use serde_json::{json, Value};
+use tokio::sync::oneshot;
+use warp::Filter;
+
+/// The state of the fake Ethereum chain.
+#[derive(Debug, Default)]
+struct Chain {
+    /// The height of the latest block
+    block_number: u64,
+    /// Whether the node reports that it is still syncing
+    syncing: bool,
+    /// The logs emitted in the blocks of the chain
+    logs: Vec<Log>,
+    /// The number of `eth_getLogs` queries served so far
+    get_logs_calls: u64,
+}
+
+/// A fake Ethereum node, whose chain can be controlled by the test that
+/// embeds it. Clones of a [`FakeEthNode`] share the same chain.
+#[derive(Debug, Clone, Default)]
+pub struct FakeEthNode {
+    chain: Arc<Mutex<Chain>>,
+}
+
+impl FakeEthNode {
+    /// Create a fake Ethereum node at the given block height.
+    pub fn new(block_number: u64) -> Self {
+        let node = Self::default();
+        node.set_block_number(block_number);
+        node
+    }
+
+    /// Set the height of the latest block of the chain.
+    pub fn set_block_number(&self, block_number: u64) {
+        self.chain.lock().unwrap().block_number = block_number;
+    }
+
+    /// Mine `blocks` new empty blocks, returning the new block height.
+    pub fn advance(&self, blocks: u64) -> u64 {
+        let mut chain = self.chain.lock().unwrap();
+        chain.block_number += blocks;
+        chain.block_number
+    }
+
+    /// Set whether the node reports that it is still syncing.
+    pub fn set_syncing(&self, syncing: bool) {
+        self.chain.lock().unwrap().syncing = syncing;
+    }
+
+    /// Return the number of `eth_getLogs` queries served so far.
+    pub fn get_logs_calls(&self) -> u64 {
+        self.chain.lock().unwrap().get_logs_calls
+    }
+
+    /// Emit a log from `contract` in the block at the given height. The
+    /// first topic must be the signature of the event.
+    pub fn push_log(
+        &self,
+        block_number: u64,
+        contract: EthAddress,
+        topics: Vec<[u8; 32]>,
+        data: Vec<u8>,
+    ) {
+        let mut chain = self.chain.lock().unwrap();
+        let log_index = chain
+            .logs
+            .iter()
+            .filter(|log| log.block_number == Some(block_number.into()))
+            .count();
+        chain.logs.push(Log {
+            address: H160(contract.0),
+            topics: topics.into_iter().map(H256).collect(),
+            data: Bytes::from(data),
+            block_number: Some(block_number.into()),
+            log_index: Some(U256::from(log_index)),
+            removed: Some(false),
+            ..Default::default()
+        });
+    }
+
+    /// Start serving the JSON-RPC API at `listen_addr` in the background,
+    /// returning the address the server is bound to. The server shuts down
+    /// once a signal is sent on (or the sender of) `abort_recv` is dropped.
+    pub fn spawn(
+        &self,
+        listen_addr: SocketAddr,
+        abort_recv: oneshot::Receiver<()>,
+    ) -> SocketAddr {
+        let node = self.clone();
+        let rpc = warp::post()
+            .and(warp::path::end())
+            .and(warp::body::json())
+            .map(move |request: Value| {
+                warp::reply::json(&node.handle(request))
+            });
+        let (addr, future) =
+            warp::serve(rpc).bind_with_graceful_shutdown(listen_addr, async {
+                let _ = abort_recv.await;
+            });
+        tracing::info!(?addr, "Fake Ethereum node is listening");
+        tokio::spawn(future);
+        addr
+    }
+
+    /// Reply to a JSON-RPC request, or to a batch of them.
+    fn handle(&self, request: Value) -> Value {
+        match request {
+            Value::Array(batch) => Value::Array(
+                batch.into_iter().map(|req| self.handle(req)).collect(),
+            ),
+            request => {
+                let id = request.get("id").cloned().unwrap_or(Value::Null);
+                let method = request
+                    .get("method")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                let params = request.get("params").unwrap_or(&Value::Null);
+                match self.call(method, params) {
+                    Ok(result) => json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "result": result,
+                    }),
+                    Err(message) => json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": {"code": -32601, "message": message},
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Execute a JSON-RPC method against the fake chain.
+    fn call(&self, method: &str, params: &Value) -> Result<Value, String> {
+        let mut chain = self.chain.lock().unwrap();
+        match method {
+            "eth_blockNumber" => Ok(json!(U64::from(chain.block_number))),
+            "eth_syncing" => Ok(if chain.syncing {
+                json!({
+                    "startingBlock": U64::zero(),
+                    "currentBlock": U64::from(chain.block_number),
+                    "highestBlock": U64::from(chain.block_number + 1),
+                })
+            } else {
+                Value::Bool(false)
+            }),
+            "eth_getLogs" => {
+                chain.get_logs_calls += 1;
+                let filter = params.get(0).unwrap_or(&Value::Null);
+                let logs: Vec<&Log> = chain
+                    .logs
+                    .iter()
+                    .filter(|log| log_matches(log, filter, chain.block_number))
+                    .collect();
+                Ok(json!(logs))
+            }
+            method => Err(format!("the method {method} does not exist")),
+        }
+    }
+}
+
+/// Check whether `log` is selected by an `eth_getLogs` filter.
+fn log_matches(log: &Log, filter: &Value, latest: u64) -> bool {
+    let block_number = log.block_number.map_or(0, |n| n.as_u64());
+    let from = parse_block(filter.get("fromBlock"), latest).unwrap_or(latest);
+    let to = parse_block(filter.get("toBlock"), latest).unwrap_or(latest);
+    if block_number < from || block_number > to {
+        return false;
+    }
+    if !matches_any(filter.get("address"), |addr| {
+        addr.parse::<H160>().ok() == Some(log.address)
+    }) {
+        return false;
+    }
+    let topics = filter
+        .get("topics")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    topics.iter().enumerate().all(|(i, topic)| {
+        matches_any(Some(topic), |topic| {
+            topic.parse::<H256>().ok().as_ref() == log.topics.get(i)
+        })
+    })
+}
+
+/// Check whether a filter entry, which may be null, a single value or an
+/// array of alternatives, is satisfied by `pred`.
+fn matches_any(entry: Option<&Value>, pred: impl Fn(&str) -> bool) -> bool {
+    match entry {
+        None | Some(Value::Null) => true,
+        Some(Value::String(value)) => pred(value),
+        Some(Value::Array(values)) => {
+            values.iter().filter_map(Value::as_str).any(pred)
+        }
+        Some(_) => false,
+    }
+}
+
+/// Parse a block number, or a block tag, of an `eth_getLogs` filter.
+fn parse_block(block: Option<&Value>, latest: u64) -> Option<u64> {
+    match block?.as_str()? {
+        "latest" | "pending" | "safe" | "finalized" => Some(latest),
+        "earliest" => Some(0),
+        number => u64::from_str_radix(number.trim_start_matches("0x"), 16).ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada::eth_bridge::ethers::providers::{Http, Middleware, Provider};
+    use namada::eth_bridge::ethers::types::{Filter, SyncingStatus};
+
+    use super::*;
+
+    /// Test that the oracle's Ethereum client can query the fake node.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_fake_eth_node_rpc() {
+        let node = FakeEthNode::new(10);
+        let contract = EthAddress([1; 20]);
+        let other_contract = EthAddress([2; 20]);
+        let signature = [3; 32];
+        node.push_log(5, contract, vec![signature], vec![0xab]);
+        node.push_log(5, other_contract, vec![signature], vec![]);
+        node.push_log(7, contract, vec![[4; 32]], vec![]);
+
+        let (abort_send, abort_recv) = oneshot::channel();
+        let addr = node.spawn(([127, 0, 0, 1], 0).into(), abort_recv);
+        let client =
+            Provider::<Http>::try_from(format!("http://{addr}")).unwrap();
+
+        assert_eq!(client.get_block_number().await.unwrap(), 10.into());
+        assert_eq!(node.advance(2), 12);
+        assert_eq!(client.get_block_number().await.unwrap(), 12.into());
+        assert!(matches!(
+            client.syncing().await.unwrap(),
+            SyncingStatus::IsFalse
+        ));
+        node.set_syncing(true);
+        assert!(matches!(
+            client.syncing().await.unwrap(),
+            SyncingStatus::IsSyncing(_)
+        ));
+
+        let logs = client
+            .get_logs(
+                &Filter::new()
+                    .from_block(5)
+                    .to_block(5)
+                    .address(H160(contract.0))
+                    .topic0(H256(signature)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].data, Bytes::from(vec![0xab]));
+
+        let logs = client
+            .get_logs(&Filter::new().from_block(6).to_block(7))
+            .await
+            .unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].topics, vec![H256([4; 32])]);
+        assert_eq!(node.get_logs_calls(), 2);
+
+        abort_send.send(()).unwrap();
+    }
+}
@@ -1,4 +1,5 @@
 pub mod events_endpoint;
+pub mod fake_eth_node;
 
 #[cfg(test)]
 pub mod event_log {
@@ -93,6 +94,26 @@ pub mod mock_web3_client {
             height: u32,
             seen: Sender<()>,
         },
+        /// Replace the blocks from `from_height` onwards with a fork
+        /// containing `new_events`, keeping the latest block height. The
+        /// events of the orphaned blocks are dropped, along with their
+        /// `seen` senders.
+        Reorg {
+            from_height: u32,
+            new_events: Vec<MockEvent>,
+        },
+        /// Fail the queries of ranges of more blocks than the given limit
+        BlockRangeLimit(u64),
+    }
+
+    /// An event logged by the mock client at some Ethereum block height
+    #[derive(Debug)]
+    pub struct MockEvent {
+        pub event_type: MockEventType,
+        pub log: ethabi::RawLog,
+        pub height: u32,
+        /// Notified once the oracle has fetched the event
+        pub seen: Sender<()>,
     }
 
     /// The type of events supported
@@ -116,13 +137,54 @@ pub mod mock_web3_client {
                     oracle.latest_block_height = height
                 }
                 TestCmd::NewEvent {
-                    event_type: ty,
+                    event_type,
                     log,
                     height,
                     seen,
-                } => oracle.events.push((ty, log, height, seen)),
+                } => oracle.events.push(MockEvent {
+                    event_type,
+                    log,
+                    height,
+                    seen,
+                }),
+                TestCmd::Reorg {
+                    from_height,
+                    new_events,
+                } => {
+                    let from_height = Uint256::from(from_height);
+                    if let Some(last) = oracle.last_block_processed.as_ref() {
+                        if *last >= from_height {
+                            // the oracle has already processed some of the
+                            // orphaned blocks
+                            let mut height = from_height;
+                            while height <= *last {
+                                oracle
+                                    .orphaned_blocks_processed
+                                    .push(height.clone());
+                                height = height + Uint256::from(1u64);
+                            }
+                        }
+                    }
+                    oracle.events.retain(|event| {
+                        Uint256::from(event.height) < from_height
+                    });
+                    oracle
+                        .fetched_logs
+                        .retain(|(block, _, _)| *block < from_height);
+                    oracle.events.extend(new_events);
+                }
+                TestCmd::BlockRangeLimit(limit) => {
+                    oracle.block_range_limit = Some(limit)
+                }
             }
         }
+
+        /// Get the heights of the blocks processed by the oracle which were
+        /// later orphaned by a reorg. This must be empty if the reorgs are
+        /// shallower than the confirmation depth of the oracle.
+        pub fn orphaned_blocks_processed(&self) -> Vec<Uint256> {
+            self.0.lock().unwrap().orphaned_blocks_processed.clone()
+        }
     }
 
     impl Clone for Web3Controller {
@@ -139,9 +201,14 @@ pub mod mock_web3_client {
     pub struct Web3ClientInner {
         active: bool,
         latest_block_height: Uint256,
-        events: Vec<(MockEventType, ethabi::RawLog, u32, Sender<()>)>,
+        events: Vec<MockEvent>,
+        /// The logs already returned for each queried block, such that
+        /// querying a block again returns the same logs
+        fetched_logs: Vec<(Uint256, MockEventType, ethabi::RawLog)>,
         blocks_processed: UnboundedSender<Uint256>,
         last_block_processed: Option<Uint256>,
+        orphaned_blocks_processed: Vec<Uint256>,
+        block_range_limit: Option<u64>,
     }
 
     #[async_trait(?Send)]
@@ -163,23 +230,48 @@ pub mod mock_web3_client {
             &self,
             block: BlockHeight,
             addr: Address,
-            ty: &str,
+            tys: &[&str],
         ) -> Result<Vec<Self::Log>, Error> {
             let block_to_check: Uint256 = block.into();
             let mut client = self.0.lock().unwrap();
             if client.active {
-                let mut logs = vec![];
+                let mut logs: Vec<_> = client
+                    .fetched_logs
+                    .iter()
+                    .filter(|(block, event_type, _)| {
+                        *block == block_to_check
+                            && tys.contains(&event_type.as_ref())
+                    })
+                    .map(|(_, _, log)| log.clone())
+                    .collect();
+                let mut new_logs = vec![];
                 let mut events = vec![];
                 std::mem::swap(&mut client.events, &mut events);
-                for (event_ty, log, height, seen) in events.into_iter() {
-                    if event_ty == ty && block_to_check >= Uint256::from(height)
+                for event in events.into_iter() {
+                    if tys.contains(&event.event_type.as_ref())
+                        && block_to_check >= Uint256::from(event.height)
                     {
-                        seen.send(()).unwrap();
-                        logs.push(log);
+                        event.seen.send(()).unwrap();
+                        new_logs.push((
+                            event.height,
+                            event.event_type,
+                            event.log,
+                        ));
                     } else {
-                        client.events.push((event_ty, log, height, seen));
+                        client.events.push(event);
                     }
                 }
+                // events of the same block are logged in the order they
+                // were sent to the mock client
+                new_logs.sort_by_key(|(height, _, _)| *height);
+                for (_, event_type, log) in new_logs {
+                    client.fetched_logs.push((
+                        block_to_check.clone(),
+                        event_type,
+                        log.clone(),
+                    ));
+                    logs.push(log);
+                }
                 if client.last_block_processed.as_ref() < Some(&block_to_check)
                 {
                     _ = client.blocks_processed.send(block_to_check.clone());
@@ -192,13 +284,44 @@ pub mod mock_web3_client {
                      as it has been artificially set as unresponsive"
                 );
                 Err(Error::CheckEvents(
-                    ty.into(),
+                    tys.join(", "),
                     addr,
                     "Test oracle is not responding".into(),
                 ))
             }
         }
 
+        async fn check_events_in_range(
+            &self,
+            from: BlockHeight,
+            to: BlockHeight,
+            addr: Address,
+            tys: &[&str],
+        ) -> Result<Vec<(BlockHeight, Self::Log)>, Error> {
+            let block_range_limit = self.0.lock().unwrap().block_range_limit;
+            if let Some(limit) = block_range_limit {
+                if to >= from.unchecked_add(limit) {
+                    return Err(Error::CheckEvents(
+                        tys.join(", "),
+                        addr,
+                        "Test oracle block range limit exceeded".into(),
+                    ));
+                }
+            }
+            let mut logs = vec![];
+            let mut block = from;
+            while block <= to {
+                let block_logs = self
+                    .check_events_in_block(block.clone(), addr, tys)
+                    .await?;
+                logs.extend(
+                    block_logs.into_iter().map(|log| (block.clone(), log)),
+                );
+                block = block.next();
+            }
+            Ok(logs)
+        }
+
         async fn syncing(
             &self,
             _: Option<&BlockHeight>,
@@ -227,8 +350,11 @@ pub mod mock_web3_client {
                     active: true,
                     latest_block_height: Default::default(),
                     events: vec![],
+                    fetched_logs: vec![],
                     blocks_processed: block_processed_send,
                     last_block_processed: None,
+                    orphaned_blocks_processed: vec![],
+                    block_range_limit: None,
                 }))),
             )
         }
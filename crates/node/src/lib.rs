@@ -18,6 +18,7 @@ mod abortable;
 pub mod bench_utils;
 mod broadcaster;
 pub mod ethereum_oracle;
+mod metrics;
 pub mod shell;
 pub mod shims;
 pub mod storage;
@@ -133,11 +134,16 @@ impl Shell {
             }
             Request::FinalizeBlock(finalize) => {
                 tracing::debug!("Request FinalizeBlock");
+                let _timer =
+                    metrics::METRICS.block_processing_time.start_timer();
                 self.finalize_block(finalize).map(Response::FinalizeBlock)
             }
             Request::Commit => {
                 tracing::debug!("Request Commit");
-                Ok(Response::Commit(self.commit()))
+                let response = self.commit();
+                self.take_snapshot_if_due();
+                self.prune_old_blocks();
+                Ok(Response::Commit(response))
             }
             Request::Flush => Ok(Response::Flush),
             Request::Echo(msg) => Ok(Response::Echo(response::Echo {
@@ -152,16 +158,16 @@ impl Shell {
                 Ok(Response::CheckTx(self.mempool_validate(&tx.tx, r#type)))
             }
             Request::ListSnapshots => {
-                Ok(Response::ListSnapshots(Default::default()))
+                Ok(Response::ListSnapshots(self.list_snapshots()))
             }
-            Request::OfferSnapshot(_) => {
-                Ok(Response::OfferSnapshot(Default::default()))
+            Request::OfferSnapshot(req) => {
+                Ok(Response::OfferSnapshot(self.offer_snapshot(req)))
             }
-            Request::LoadSnapshotChunk(_) => {
-                Ok(Response::LoadSnapshotChunk(Default::default()))
+            Request::LoadSnapshotChunk(req) => {
+                Ok(Response::LoadSnapshotChunk(self.load_snapshot_chunk(req)))
             }
-            Request::ApplySnapshotChunk(_) => {
-                Ok(Response::ApplySnapshotChunk(Default::default()))
+            Request::ApplySnapshotChunk(req) => {
+                Ok(Response::ApplySnapshotChunk(self.apply_snapshot_chunk(req)))
             }
         }
     }
@@ -355,6 +361,7 @@ pub fn rollback(config: config::Ledger) -> Result<(), shell::Error> {
 ///   - An Ethereum full node.
 ///   - An oracle, to receive events from the Ethereum full node, and forward
 ///     them to the ledger.
+///   - Optionally, a server exporting the metrics of the node.
 ///
 /// All must be alive for correct functioning.
 async fn run_aux(config: config::Ledger, wasm_dir: PathBuf) {
@@ -376,6 +383,9 @@ async fn run_aux(config: config::Ledger, wasm_dir: PathBuf) {
             }
         };
 
+    // Start the metrics server if necessary
+    let metrics = start_metrics_server(&mut spawner, &config);
+
     tracing::info!("Loading MASP verifying keys.");
     let _ = namada_sdk::masp::preload_verifying_keys();
     tracing::info!("Done loading MASP verifying keys.");
@@ -394,10 +404,16 @@ async fn run_aux(config: config::Ledger, wasm_dir: PathBuf) {
     let aborted = spawner.wait_for_abort().await.child_terminated();
 
     // Wait for all managed tasks to finish.
-    let res = tokio::try_join!(tendermint_node, abci, eth_oracle, broadcaster);
+    let res = tokio::try_join!(
+        tendermint_node,
+        abci,
+        eth_oracle,
+        broadcaster,
+        metrics
+    );
 
     match res {
-        Ok((tendermint_res, abci_res, _, _)) => {
+        Ok((tendermint_res, abci_res, _, _, _)) => {
             // we ignore errors on user-initiated shutdown
             if aborted {
                 if let Err(err) = tendermint_res {
@@ -526,6 +542,29 @@ async fn run_aux_setup(
     }
 }
 
+/// Spawns a server exporting the metrics of the node, if a metrics address
+/// is configured.
+fn start_metrics_server(
+    spawner: &mut AbortableSpawner,
+    config: &config::Ledger,
+) -> task::JoinHandle<()> {
+    let Some(metrics_address) = config.shell.metrics_address else {
+        return spawn_dummy_task(());
+    };
+    let (abort_send, abort_recv) = tokio::sync::oneshot::channel::<()>();
+
+    spawner
+        .spawn_abortable("Metrics", move |aborter| async move {
+            metrics::serve(metrics_address, abort_recv).await;
+            tracing::info!("Metrics server is no longer running.");
+
+            drop(aborter);
+        })
+        .with_cleanup(async move {
+            let _ = abort_send.send(());
+        })
+}
+
 /// This function spawns an ABCI server and a [`Broadcaster`] into the
 /// asynchronous runtime. Additionally, it executes a shell in
 /// a new OS thread, to drive the ABCI server.
@@ -585,6 +624,10 @@ fn start_abci_broadcaster_shell(
             .expect("`db_block_cache_size_bytes` must not exceed `usize::MAX`"),
     );
 
+    // Discard the state of a snapshot restore interrupted by a stop of the
+    // node, before the DB is opened by the shell
+    shell::discard_interrupted_snapshot_restore(&config);
+
     // Construct our ABCI application.
     let tendermint_mode = config.shell.tendermint_mode.clone();
     let proxy_app_address =
@@ -786,6 +829,7 @@ async fn maybe_start_ethereum_oracle(
         mpsc::channel(config.ethereum_bridge.channel_buffer_size);
     let (last_processed_block_sender, last_processed_block_receiver) =
         last_processed_block::channel();
+    let (status_sender, status_receiver) = oracle::status::channel();
     let (control_sender, control_receiver) = oracle::control::channel();
 
     match config.ethereum_bridge.mode {
@@ -795,6 +839,11 @@ async fn maybe_start_ethereum_oracle(
                 eth_sender,
                 control_receiver,
                 last_processed_block_sender,
+                status_sender,
+                std::time::Duration::from_secs(
+                    config.ethereum_bridge.oracle_stall_threshold,
+                ),
+                config.ethereum_bridge.oracle_max_block_range,
                 spawner,
             );
 
@@ -804,10 +853,12 @@ async fn maybe_start_ethereum_oracle(
                     eth_receiver,
                     control_sender,
                     last_processed_block_receiver,
+                    status_receiver,
                 ),
             }
         }
         ethereum_bridge::ledger::Mode::SelfHostedEndpoint => {
+            let secret = config.ethereum_bridge.events_endpoint_secret.clone();
             let (oracle_abort_send, oracle_abort_recv) =
                 tokio::sync::oneshot::channel::<tokio::sync::oneshot::Sender<()>>(
                 );
@@ -817,7 +868,9 @@ async fn maybe_start_ethereum_oracle(
                     move |aborter| async move {
                         oracle::test_tools::events_endpoint::serve(
                             ethereum_url,
+                            secret,
                             eth_sender,
+                            last_processed_block_sender,
                             control_receiver,
                             oracle_abort_recv,
                         )
@@ -854,6 +907,7 @@ async fn maybe_start_ethereum_oracle(
                     eth_receiver,
                     control_sender,
                     last_processed_block_receiver,
+                    status_receiver,
                 ),
             }
         }
@@ -0,0 +1,179 @@
+//! Prometheus metrics of the node internals.
+//!
+//! The metrics are always recorded, but they are only exported when a
+//! `metrics_address` is set in the shell config, in which case [`serve`] is
+//! spawned to expose them over HTTP at the [`METRICS_ENDPOINT`].
+
+use std::net::SocketAddr;
+
+use once_cell::sync::Lazy;
+use prometheus::core::Collector;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use tokio::sync::oneshot::Receiver;
+use warp::Filter;
+
+/// The path at which the metrics are served
+pub const METRICS_ENDPOINT: &str = "metrics";
+
+/// The namespace of all the metrics of the node
+const NAMESPACE: &str = "namada";
+
+/// The metrics of this node
+pub static METRICS: Lazy<Metrics> = Lazy::new(Metrics::new);
+
+/// The metrics collected by the node
+#[derive(Debug)]
+pub struct Metrics {
+    registry: Registry,
+    /// Time taken to finalize a block, in seconds
+    pub block_processing_time: Histogram,
+    /// Number of txs applied in finalized blocks, labelled by their
+    /// result code
+    pub txs: IntCounterVec,
+    /// Gas used by the txs of the last finalized block
+    pub block_gas_used: IntGauge,
+    /// Number of wrapper txs accepted into the mempool since the last
+    /// committed block
+    pub mempool_txs: IntGauge,
+    /// Number of Ethereum blocks between the latest block and the block
+    /// being processed by the oracle
+    pub eth_oracle_lag: IntGauge,
+    /// Number of transfers pending in the Ethereum bridge pool
+    pub bridge_pool_transfers: IntGauge,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new_custom(Some(NAMESPACE.to_owned()), None)
+            .expect("The metrics namespace must be valid");
+        let block_processing_time = Histogram::with_opts(HistogramOpts::new(
+            "block_processing_seconds",
+            "Time taken to finalize a block, in seconds",
+        ))
+        .expect("Failed to create a metric");
+        let txs = IntCounterVec::new(
+            Opts::new(
+                "txs_total",
+                "Number of txs applied in finalized blocks, by result code",
+            ),
+            &["code"],
+        )
+        .expect("Failed to create a metric");
+        let block_gas_used = IntGauge::new(
+            "block_gas_used",
+            "Gas used by the txs of the last finalized block",
+        )
+        .expect("Failed to create a metric");
+        let mempool_txs = IntGauge::new(
+            "mempool_txs",
+            "Number of wrapper txs accepted into the mempool since the last \
+             committed block",
+        )
+        .expect("Failed to create a metric");
+        let eth_oracle_lag = IntGauge::new(
+            "eth_oracle_lag_blocks",
+            "Number of Ethereum blocks the oracle is behind the latest block",
+        )
+        .expect("Failed to create a metric");
+        let bridge_pool_transfers = IntGauge::new(
+            "bridge_pool_transfers",
+            "Number of transfers pending in the Ethereum bridge pool",
+        )
+        .expect("Failed to create a metric");
+
+        let collectors: [Box<dyn Collector>; 6] = [
+            Box::new(block_processing_time.clone()),
+            Box::new(txs.clone()),
+            Box::new(block_gas_used.clone()),
+            Box::new(mempool_txs.clone()),
+            Box::new(eth_oracle_lag.clone()),
+            Box::new(bridge_pool_transfers.clone()),
+        ];
+        for collector in collectors {
+            registry
+                .register(collector)
+                .expect("Metrics must only be registered once");
+        }
+
+        Self {
+            registry,
+            block_processing_time,
+            txs,
+            block_gas_used,
+            mempool_txs,
+            eth_oracle_lag,
+            bridge_pool_transfers,
+        }
+    }
+
+    /// Encode the current values of the metrics in the Prometheus text format
+    pub fn encode(&self) -> String {
+        let mut buffer = vec![];
+        if let Err(err) =
+            TextEncoder::new().encode(&self.registry.gather(), &mut buffer)
+        {
+            tracing::error!("Failed to encode the metrics: {err}");
+        }
+        String::from_utf8(buffer)
+            .expect("The Prometheus text format must be valid UTF-8")
+    }
+}
+
+/// Convert a count to the value of a gauge, saturating at its maximum value
+pub fn to_gauge_value<T: TryInto<i64>>(value: T) -> i64 {
+    value.try_into().unwrap_or(i64::MAX)
+}
+
+/// Starts a [`warp::Server`] that serves the metrics of the node at the
+/// [`METRICS_ENDPOINT`] of the given address. It shuts down if a signal is
+/// sent on the `abort_recv` channel.
+pub async fn serve(listen_addr: SocketAddr, abort_recv: Receiver<()>) {
+    let metrics = warp::get()
+        .and(warp::path(METRICS_ENDPOINT))
+        .and(warp::path::end())
+        .map(|| {
+            warp::reply::with_header(
+                METRICS.encode(),
+                "content-type",
+                prometheus::TEXT_FORMAT,
+            )
+        });
+
+    let server = warp::serve(metrics).try_bind_with_graceful_shutdown(
+        listen_addr,
+        async move {
+            let _ = abort_recv.await;
+        },
+    );
+    match server {
+        Ok((addr, future)) => {
+            tracing::info!(?addr, "Serving the node metrics");
+            future.await
+        }
+        Err(err) => {
+            tracing::error!(
+                ?listen_addr,
+                "Failed to start the metrics server: {err}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that the recorded metrics are encoded with the node's namespace
+    #[test]
+    fn test_encode_metrics() {
+        METRICS.block_gas_used.set(42);
+        METRICS.txs.with_label_values(&["Ok"]).inc();
+
+        let encoded = METRICS.encode();
+        assert!(encoded.contains("namada_block_gas_used 42"));
+        assert!(encoded.contains("namada_txs_total{code=\"Ok\"}"));
+    }
+}
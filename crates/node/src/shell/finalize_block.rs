@@ -6,6 +6,7 @@ use masp_primitives::sapling::Node;
 use namada::core::storage::{BlockResults, Epoch, Header};
 use namada::events::Event;
 use namada::gas::event::GasUsed;
+use namada::gas::Gas;
 use namada::governance::pgf::inflation as pgf_inflation;
 use namada::hash::Hash;
 use namada::ledger::events::extend::{
@@ -21,7 +22,9 @@ use namada::proof_of_stake::storage::{
     find_validator_by_raw_hash, write_last_block_proposer_address,
 };
 use namada::state::write_log::StorageModification;
-use namada::state::{ResultExt, StorageWrite, EPOCH_SWITCH_BLOCKS_DELAY};
+use namada::state::{
+    ResultExt, StorageWrite, StoreRef, StoreType, EPOCH_SWITCH_BLOCKS_DELAY,
+};
 use namada::token::utils::is_masp_tx;
 use namada::tx::data::protocol::ProtocolTxType;
 use namada::tx::data::VpStatusFlags;
@@ -32,6 +35,7 @@ use namada::vote_ext::ethereum_tx_data_variants;
 
 use super::*;
 use crate::facade::tendermint::abci::types::VoteInfo;
+use crate::metrics::{to_gauge_value, METRICS};
 use crate::shell::stats::InternalStats;
 
 impl<D, H> Shell<D, H>
@@ -63,6 +67,12 @@ where
         &mut self,
         req: shim::request::FinalizeBlock,
     ) -> Result<shim::response::FinalizeBlock> {
+        // Refuse to process blocks past the upgrade height scheduled by
+        // governance, once it has been reached
+        if let Some(upgrade_height) = self.upgrade_halt_height() {
+            return Err(Error::HaltedForUpgrade(upgrade_height));
+        }
+
         let mut response = shim::response::FinalizeBlock::default();
 
         // Begin the new block and check if a new epoch has begun
@@ -104,9 +114,13 @@ where
         // Sub-system updates:
         // - Governance - applied first in case a proposal changes any of the
         //   other syb-systems
+        //    - Tracks the keys it changes, in case a proposal updates the
+        //      Ethereum oracle config
+        let mut changed_keys = BTreeSet::new();
         governance::finalize_block(
             self,
             emit_events,
+            &mut changed_keys,
             current_epoch,
             new_epoch,
         )?;
@@ -124,6 +138,8 @@ where
         )?;
         // - IBC
         ibc::finalize_block(&mut self.state, emit_events, new_epoch)?;
+        // - Upgrade height, cleared once reached
+        self.clear_reached_upgrade_height(height)?;
 
         if new_epoch {
             // Apply PoS and PGF inflation
@@ -145,7 +161,6 @@ where
 
         // Tracks the accepted transactions
         self.state.in_mem_mut().block.results = BlockResults::default();
-        let mut changed_keys = BTreeSet::new();
         for (tx_index, processed_tx) in req.txs.iter().enumerate() {
             let tx = if let Ok(tx) = Tx::try_from(processed_tx.tx.as_ref()) {
                 tx
@@ -312,6 +327,12 @@ where
                 } else {
                     None
                 };
+            // The fee payer is always touched by a wrapper tx that reached
+            // this point, regardless of the result of the inner txs
+            let mut indexed_addresses = BTreeSet::new();
+            if let TxType::Wrapper(wrapper) = &tx_header.tx_type {
+                indexed_addresses.insert(wrapper.fee_payer());
+            }
             let tx_gas_meter = RefCell::new(tx_gas_meter);
             let mut tx_event = new_tx_event(&tx, height.0);
             let is_atomic_batch = tx.header.atomic;
@@ -350,9 +371,18 @@ where
                     tx_event: &mut tx_event,
                     stats: &mut stats,
                     changed_keys: &mut changed_keys,
+                    indexed_addresses: &mut indexed_addresses,
                 },
             );
             response.events.emit(tx_event);
+            if let Some(entries) = self.tx_index_entries.as_mut() {
+                let tx_index = TxIndex::must_from_usize(tx_index);
+                entries.extend(
+                    indexed_addresses
+                        .into_iter()
+                        .map(|address| (address, tx_index)),
+                );
+            }
         }
 
         stats.set_tx_cache_size(
@@ -386,8 +416,15 @@ where
 
         if update_for_tendermint {
             self.update_epoch(&mut response);
-            // send the latest oracle configs. These may have changed due to
-            // governance.
+        }
+        // send the latest oracle configs at every new epoch, or as soon as
+        // they are changed in storage. These may have changed due to
+        // governance.
+        if update_for_tendermint
+            || changed_keys
+                .iter()
+                .any(namada::eth_bridge::storage::is_oracle_config_key)
+        {
             self.update_eth_oracle(&changed_keys);
         }
 
@@ -396,12 +433,48 @@ where
             native_block_proposer_address,
         )?;
 
+        self.update_block_metrics(&response.events);
         self.event_log_mut().emit_many(response.events.clone());
         tracing::debug!("End finalize_block {height} of epoch {current_epoch}");
 
         Ok(response)
     }
 
+    /// Record the metrics of the finalized block from the events of its txs
+    /// and the state of the bridge pool
+    fn update_block_metrics(&self, events: &[Event]) {
+        let mut block_gas_used = Gas::default();
+        for event in events
+            .iter()
+            .filter(|event| *event.kind() == namada::tx::event::types::APPLIED)
+        {
+            if let Ok(code) = event.read_attribute::<Code>() {
+                METRICS.txs.with_label_values(&[&format!("{code:?}")]).inc();
+            }
+            if let Ok(gas) = event.read_attribute::<GasUsed>() {
+                block_gas_used = block_gas_used
+                    .checked_add(gas)
+                    .unwrap_or_else(|| Gas::from(u64::MAX));
+            }
+        }
+        METRICS
+            .block_gas_used
+            .set(to_gauge_value(block_gas_used.get_whole_gas_units()));
+
+        if let StoreRef::BridgePool(store) = self
+            .state
+            .in_mem()
+            .block
+            .tree
+            .stores()
+            .store(&StoreType::BridgePool)
+        {
+            METRICS
+                .bridge_pool_transfers
+                .set(to_gauge_value(store.len()));
+        }
+    }
+
     /// Sets the metadata necessary for a new block, including the height,
     /// validator changes, and evidence of byzantine behavior. Applies slashes
     /// if necessary. Returns a bool indicating if a new epoch began and the
@@ -761,6 +834,8 @@ struct TxLogs<'finalize> {
     tx_event: &'finalize mut Event,
     stats: &'finalize mut InternalStats,
     changed_keys: &'finalize mut BTreeSet<Key>,
+    // Addresses touched by the tx, for the optional tx index
+    indexed_addresses: &'finalize mut BTreeSet<Address>,
 }
 
 #[derive(Default)]
@@ -778,6 +853,7 @@ struct TempTxLogs {
     tx_event: Event,
     stats: InternalStats,
     changed_keys: BTreeSet<Key>,
+    indexed_addresses: BTreeSet<Address>,
     response_events: Vec<Event>,
 }
 
@@ -790,6 +866,7 @@ impl TempTxLogs {
             ),
             stats: Default::default(),
             changed_keys: Default::default(),
+            indexed_addresses: Default::default(),
             response_events: Default::default(),
         }
     }
@@ -806,6 +883,7 @@ impl<'finalize> TempTxLogs {
         logs.tx_event.merge(self.tx_event);
         logs.stats.merge(self.stats);
         logs.changed_keys.extend(self.changed_keys);
+        logs.indexed_addresses.extend(self.indexed_addresses);
         response.events.extend(self.response_events);
     }
 
@@ -841,6 +919,18 @@ impl<'finalize> TempTxLogs {
 
                         self.changed_keys
                             .extend(result.changed_keys.iter().cloned());
+                        self.indexed_addresses.extend(
+                            result.vps_result.accepted_vps.iter().cloned(),
+                        );
+                        // Index the tokens whose balances were modified too
+                        self.indexed_addresses.extend(
+                            result.changed_keys.iter().filter_map(|key| {
+                                token::storage_key::is_any_token_balance_key(
+                                    key,
+                                )
+                                .map(|[token, _owner]| token.clone())
+                            }),
+                        );
                         self.stats.increment_successful_txs();
                         flags.commit_batch_hash = true;
 
@@ -5363,6 +5453,405 @@ mod test_finalize_block {
         assert_eq!(u64::from(cmd.min_confirmations), 42);
     }
 
+    /// Test that passed Ethereum bridge parameters proposals are executed
+    /// and that the new config is sent to the Ethereum oracle, unless they
+    /// change the wrapped NAM contract under pending wrapped NAM transfers.
+    #[test]
+    fn test_eth_bridge_params_proposal_execution() {
+        use namada::core::eth_bridge_pool::{
+            GasFee, PendingTransfer, TransferToEthereum, TransferToEthereumKind,
+        };
+        use namada::eth_bridge::storage::parameters::UpgradeableContract;
+        use namada::eth_bridge::storage::{
+            bridge_contract_key, native_erc20_key,
+        };
+        use namada::governance::storage::proposal::{
+            EthBridgeParamsUpdate, EthContractUpdate,
+        };
+
+        if !is_bridge_comptime_enabled() {
+            // NOTE: this test doesn't work if the ethereum bridge
+            // is disabled at compile time.
+            return;
+        }
+        let (mut shell, _broadcaster, _, mut control_receiver) =
+            setup_at_height(3u64);
+
+        let current_contract: UpgradeableContract = shell
+            .state
+            .read(&bridge_contract_key())
+            .expect("Test failed")
+            .expect("Test failed");
+        let wnam: EthAddress = shell
+            .state
+            .read(&native_erc20_key())
+            .expect("Test failed")
+            .expect("Test failed");
+        let new_contract = EthContractUpdate {
+            address: EthAddress([0xbb; 20]),
+            version: NonZeroU64::from(current_contract.version)
+                .checked_add(1)
+                .expect("Test failed"),
+        };
+        let new_wnam = EthAddress([0xcc; 20]);
+
+        let mut add_proposal = |update, activation_epoch: Epoch| {
+            let validator = shell.mode.get_validator_address().unwrap().clone();
+            let proposal = InitProposalData {
+                content: Hash::default(),
+                author: validator.clone(),
+                voting_start_epoch: Epoch::default(),
+                voting_end_epoch: Epoch::default().next(),
+                activation_epoch,
+                r#type: ProposalType::EthBridgeParams(update),
+            };
+            let id = namada::governance::init_proposal(
+                &mut shell.state,
+                &proposal,
+                vec![],
+                None,
+            )
+            .expect("Test failed");
+            let vote = VoteProposalData {
+                id,
+                vote: ProposalVote::Yay,
+                voter: validator,
+            };
+            namada::governance::vote_proposal(
+                &mut shell.state,
+                vote,
+                HashSet::new(),
+            )
+            .expect("Test failed");
+        };
+        let update = EthBridgeParamsUpdate {
+            bridge_contract: Some(new_contract),
+            native_erc20: Some(new_wnam),
+            min_confirmations: NonZeroU64::new(42),
+        };
+        add_proposal(update.clone(), Epoch(1));
+        add_proposal(update, Epoch(2));
+
+        // a pending wrapped NAM transfer prevents the first proposal from
+        // being executed
+        let bertha = address::testing::established_address_2();
+        let pending = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                amount: 10u64.into(),
+                asset: wnam,
+                recipient: EthAddress([0xaa; 20]),
+                sender: bertha.clone(),
+            },
+            gas_fee: GasFee {
+                token: shell.state.in_mem().native_token.clone(),
+                amount: 10u64.into(),
+                payer: bertha,
+            },
+        };
+        let pending_key = bridge_pool::get_pending_key(&pending);
+        shell
+            .state
+            .write(&pending_key, pending)
+            .expect("Test failed");
+
+        let mut changed_keys = BTreeSet::new();
+        governance::load_and_execute_governance_proposals(
+            &mut shell,
+            &mut Vec::<Event>::new(),
+            &mut changed_keys,
+            Epoch(1),
+        )
+        .expect("Test failed");
+        assert!(changed_keys.is_empty());
+        let contract: UpgradeableContract = shell
+            .state
+            .read(&bridge_contract_key())
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(contract, current_contract);
+        assert_eq!(
+            shell.state.read(&native_erc20_key()).expect("Test failed"),
+            Some(wnam)
+        );
+
+        // once the transfer has been relayed, the second proposal is
+        // executed and the oracle receives the new config
+        shell.state.delete(&pending_key).expect("Test failed");
+        governance::load_and_execute_governance_proposals(
+            &mut shell,
+            &mut Vec::<Event>::new(),
+            &mut changed_keys,
+            Epoch(2),
+        )
+        .expect("Test failed");
+        assert_eq!(
+            changed_keys,
+            BTreeSet::from([
+                bridge_contract_key(),
+                native_erc20_key(),
+                min_confirmations_key(),
+            ])
+        );
+        let contract: UpgradeableContract = shell
+            .state
+            .read(&bridge_contract_key())
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(contract.address, new_contract.address);
+        assert_eq!(NonZeroU64::from(contract.version), new_contract.version);
+        assert_eq!(
+            shell.state.read(&native_erc20_key()).expect("Test failed"),
+            Some(new_wnam)
+        );
+
+        while control_receiver.try_recv().is_ok() {}
+        shell.update_eth_oracle(&changed_keys);
+        let Command::UpdateConfig(cmd) =
+            control_receiver.try_recv().expect("Test failed");
+        assert_eq!(cmd.bridge_contract, new_contract.address);
+        assert_eq!(u64::from(cmd.min_confirmations), 42);
+    }
+
+    /// Test that passed parameter changes proposals are executed, unless
+    /// the changes are invalid, in which case the parameters are left
+    /// untouched.
+    #[test]
+    fn test_parameter_changes_proposal_execution() {
+        use namada::governance::storage::proposal::ParameterChange;
+        use namada::ledger::parameters::storage::{
+            get_gas_cost_key, get_max_block_gas_key,
+        };
+
+        let (mut shell, _broadcaster, _, _) = setup_at_height(3u64);
+        let native_token = shell.state.in_mem().native_token.clone();
+        let btc = address::testing::btc();
+
+        let mut add_proposal = |changes, activation_epoch: Epoch| {
+            let validator = shell.mode.get_validator_address().unwrap().clone();
+            let proposal = InitProposalData {
+                content: Hash::default(),
+                author: validator.clone(),
+                voting_start_epoch: Epoch::default(),
+                voting_end_epoch: Epoch::default().next(),
+                activation_epoch,
+                r#type: ProposalType::ParameterChanges(changes),
+            };
+            let id = namada::governance::init_proposal(
+                &mut shell.state,
+                &proposal,
+                vec![],
+                None,
+            )
+            .expect("Test failed");
+            let vote = VoteProposalData {
+                id,
+                vote: ProposalVote::Yay,
+                voter: validator,
+            };
+            namada::governance::vote_proposal(
+                &mut shell.state,
+                vote,
+                HashSet::new(),
+            )
+            .expect("Test failed");
+        };
+        let max_block_gas: u64 = shell
+            .state
+            .read(&get_max_block_gas_key())
+            .expect("Test failed")
+            .expect("Test failed");
+        let new_max_block_gas = max_block_gas + 1;
+        // an invalid PoS parameter
+        add_proposal(
+            BTreeSet::from([
+                ParameterChange::MaxBlockGas(new_max_block_gas),
+                ParameterChange::PosLivenessThreshold(Dec::two()),
+            ]),
+            Epoch(1),
+        );
+        // the removal of the native token from the fee tokens
+        add_proposal(
+            BTreeSet::from([
+                ParameterChange::MaxBlockGas(new_max_block_gas),
+                ParameterChange::MinimumGasPrice {
+                    token: native_token.clone(),
+                    amount: None,
+                },
+            ]),
+            Epoch(2),
+        );
+        let liveness_threshold = Dec::new(5, 1).expect("Test failed");
+        add_proposal(
+            BTreeSet::from([
+                ParameterChange::MaxBlockGas(new_max_block_gas),
+                ParameterChange::MinimumGasPrice {
+                    token: btc.clone(),
+                    amount: Some(Amount::from_u64(2)),
+                },
+                ParameterChange::PosLivenessThreshold(liveness_threshold),
+            ]),
+            Epoch(3),
+        );
+
+        let gas_cost: BTreeMap<Address, Amount> = shell
+            .state
+            .read(&get_gas_cost_key())
+            .expect("Test failed")
+            .expect("Test failed");
+        let pos_params = read_pos_params(&shell.state).unwrap();
+        for epoch in [Epoch(1), Epoch(2)] {
+            governance::load_and_execute_governance_proposals(
+                &mut shell,
+                &mut Vec::<Event>::new(),
+                &mut BTreeSet::new(),
+                epoch,
+            )
+            .expect("Test failed");
+            assert_eq!(
+                shell
+                    .state
+                    .read::<u64>(&get_max_block_gas_key())
+                    .expect("Test failed"),
+                Some(max_block_gas)
+            );
+            assert_eq!(
+                shell.state.read(&get_gas_cost_key()).expect("Test failed"),
+                Some(gas_cost.clone())
+            );
+            assert_eq!(
+                read_pos_params(&shell.state).unwrap().liveness_threshold,
+                pos_params.liveness_threshold
+            );
+        }
+
+        governance::load_and_execute_governance_proposals(
+            &mut shell,
+            &mut Vec::<Event>::new(),
+            &mut BTreeSet::new(),
+            Epoch(3),
+        )
+        .expect("Test failed");
+        assert_eq!(
+            shell
+                .state
+                .read::<u64>(&get_max_block_gas_key())
+                .expect("Test failed"),
+            Some(new_max_block_gas)
+        );
+        let mut expected_gas_cost = gas_cost;
+        expected_gas_cost.insert(btc, Amount::from_u64(2));
+        assert_eq!(
+            shell.state.read(&get_gas_cost_key()).expect("Test failed"),
+            Some(expected_gas_cost)
+        );
+        assert_eq!(
+            read_pos_params(&shell.state).unwrap().liveness_threshold,
+            liveness_threshold
+        );
+    }
+
+    /// Test that a parameter changes proposal updates the gas cost table,
+    /// which is loaded into the wasm caches on commit, and schedules an
+    /// upgrade height, unless the new table has a stale version.
+    #[test]
+    fn test_gas_cost_table_proposal_execution() {
+        use namada::gas::cost_table::GasCostTable;
+        use namada::governance::storage::proposal::ParameterChange;
+        use namada::ledger::parameters::storage::get_upgrade_height;
+
+        let (mut shell, _broadcaster, _, _) = setup_at_height(3u64);
+        let mut add_proposal = |changes, activation_epoch: Epoch| {
+            let validator = shell.mode.get_validator_address().unwrap().clone();
+            let proposal = InitProposalData {
+                content: Hash::default(),
+                author: validator.clone(),
+                voting_start_epoch: Epoch::default(),
+                voting_end_epoch: Epoch::default().next(),
+                activation_epoch,
+                r#type: ProposalType::ParameterChanges(changes),
+            };
+            let id = namada::governance::init_proposal(
+                &mut shell.state,
+                &proposal,
+                vec![],
+                None,
+            )
+            .expect("Test failed");
+            let vote = VoteProposalData {
+                id,
+                vote: ProposalVote::Yay,
+                voter: validator,
+            };
+            namada::governance::vote_proposal(
+                &mut shell.state,
+                vote,
+                HashSet::new(),
+            )
+            .expect("Test failed");
+        };
+        let current = parameters::read_vm_gas_cost_table(&shell.state)
+            .expect("Test failed");
+        let mut new_table = GasCostTable {
+            version: current.version + 1,
+            ..current.clone()
+        };
+        new_table.host_fns.verify_tx_sig *= 2;
+        let upgrade_height = BlockHeight(1_000);
+        // a table with a stale version
+        add_proposal(
+            BTreeSet::from([
+                ParameterChange::GasCostTable(current.clone()),
+                ParameterChange::UpgradeHeight(upgrade_height),
+            ]),
+            Epoch(1),
+        );
+        add_proposal(
+            BTreeSet::from([
+                ParameterChange::GasCostTable(new_table.clone()),
+                ParameterChange::UpgradeHeight(upgrade_height),
+            ]),
+            Epoch(2),
+        );
+
+        governance::load_and_execute_governance_proposals(
+            &mut shell,
+            &mut Vec::<Event>::new(),
+            &mut BTreeSet::new(),
+            Epoch(1),
+        )
+        .expect("Test failed");
+        assert_eq!(
+            parameters::read_vm_gas_cost_table(&shell.state)
+                .expect("Test failed"),
+            current
+        );
+        assert_eq!(get_upgrade_height(&shell.state).unwrap(), None);
+
+        governance::load_and_execute_governance_proposals(
+            &mut shell,
+            &mut Vec::<Event>::new(),
+            &mut BTreeSet::new(),
+            Epoch(2),
+        )
+        .expect("Test failed");
+        assert_eq!(
+            parameters::read_vm_gas_cost_table(&shell.state)
+                .expect("Test failed"),
+            new_table
+        );
+        assert_eq!(
+            get_upgrade_height(&shell.state).unwrap(),
+            Some(upgrade_height)
+        );
+
+        // the new costs are used once the block is committed
+        assert_eq!(shell.vp_wasm_cache.host_fn_gas_costs(), current.host_fns);
+        shell.commit();
+        assert_eq!(shell.vp_wasm_cache.host_fn_gas_costs(), new_table.host_fns);
+        assert_eq!(shell.tx_wasm_cache.host_fn_gas_costs(), new_table.host_fns);
+    }
+
     // Test a successful tx batch containing three valid transactions
     #[test]
     fn test_successful_batch() {
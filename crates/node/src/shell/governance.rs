@@ -1,16 +1,30 @@
+use std::collections::BTreeMap;
+use std::num::NonZeroU64;
+
 use namada::core::collections::HashMap;
 use namada::core::encode;
-use namada::core::storage::Epoch;
-use namada::governance::event::GovernanceEvent;
+use namada::core::eth_bridge_pool::PendingTransfer;
+use namada::core::ethereum_events::EthAddress;
+use namada::core::storage::{Epoch, KeySeg};
+use namada::eth_bridge::storage as bridge_storage;
+use namada::eth_bridge::storage::bridge_pool::{
+    is_pending_transfer_key, BRIDGE_POOL_ADDRESS,
+};
+use namada::eth_bridge::storage::parameters::{
+    MinimumConfirmations, UpgradeableContract,
+};
+use namada::governance::event::{FundsDestination, GovernanceEvent};
 use namada::governance::pgf::storage::keys as pgf_storage;
 use namada::governance::pgf::storage::steward::StewardDetail;
 use namada::governance::pgf::{storage as pgf, ADDRESS};
 use namada::governance::storage::proposal::{
-    AddRemove, PGFAction, PGFTarget, ProposalType, StoragePgfFunding,
+    validate_parameter_changes, AddRemove, EthBridgeParamsUpdate, PGFAction,
+    PGFTarget, ParameterChange, ProposalType, StoragePgfFunding,
 };
 use namada::governance::storage::{keys as gov_storage, load_proposals};
 use namada::governance::utils::{
-    compute_proposal_result, ProposalVotes, TallyResult, TallyType, VotePower,
+    compute_proposal_result, ProposalFundsDestination, ProposalVotes,
+    TallyResult, TallyType, VotePower,
 };
 use namada::governance::{
     storage as gov_api, ProposalVote, ADDRESS as gov_address,
@@ -24,7 +38,7 @@ use namada::proof_of_stake::storage::{
 };
 use namada::proof_of_stake::types::{BondId, ValidatorState};
 use namada::sdk::events::{EmitEvents, EventLevel};
-use namada::state::StorageWrite;
+use namada::state::{iter_prefix_with_filter, StorageWrite};
 use namada::token::event::{TokenEvent, TokenOperation, UserAccount};
 use namada::token::read_balance;
 use namada::tx::{Code, Data};
@@ -36,6 +50,7 @@ use super::*;
 pub fn finalize_block<D, H>(
     shell: &mut Shell<D, H>,
     events: &mut impl EmitEvents,
+    changed_keys: &mut BTreeSet<Key>,
     current_epoch: Epoch,
     is_new_epoch: bool,
 ) -> Result<()>
@@ -44,7 +59,12 @@ where
     H: 'static + StorageHasher + Sync,
 {
     if is_new_epoch {
-        load_and_execute_governance_proposals(shell, events, current_epoch)?;
+        load_and_execute_governance_proposals(
+            shell,
+            events,
+            changed_keys,
+            current_epoch,
+        )?;
     }
     Ok(())
 }
@@ -58,6 +78,7 @@ pub struct ProposalsResult {
 pub fn load_and_execute_governance_proposals<D, H>(
     shell: &mut Shell<D, H>,
     events: &mut impl EmitEvents,
+    changed_keys: &mut BTreeSet<Key>,
     current_epoch: Epoch,
 ) -> Result<ProposalsResult>
 where
@@ -66,8 +87,12 @@ where
 {
     let proposal_ids = load_proposals(&shell.state, current_epoch)?;
 
-    let proposals_result =
-        execute_governance_proposals(shell, events, proposal_ids)?;
+    let proposals_result = execute_governance_proposals(
+        shell,
+        events,
+        changed_keys,
+        proposal_ids,
+    )?;
 
     Ok(proposals_result)
 }
@@ -75,6 +100,7 @@ where
 fn execute_governance_proposals<D, H>(
     shell: &mut Shell<D, H>,
     events: &mut impl EmitEvents,
+    changed_keys: &mut BTreeSet<Key>,
     proposal_ids: BTreeSet<u64>,
 ) -> Result<ProposalsResult>
 where
@@ -119,7 +145,11 @@ where
         .expect("Proposal result calculation must not over/underflow");
         gov_api::write_proposal_result(&mut shell.state, id, proposal_result)?;
 
-        let transfer_address = match proposal_result.result {
+        let funds_destination =
+            gov_api::get_proposal_funds_policy(&shell.state)?
+                .funds_destination(proposal_author.clone(), &proposal_result);
+
+        match proposal_result.result {
             TallyResult::Passed => {
                 let proposal_event = match proposal_type {
                     ProposalType::Default => {
@@ -185,10 +215,43 @@ where
                             id
                         );
 
+                        GovernanceEvent::passed_proposal(id, false, false)
+                    }
+                    ProposalType::EthBridgeParams(update) => {
+                        let result = execute_eth_bridge_params_proposal(
+                            &mut shell.state,
+                            changed_keys,
+                            update,
+                        )?;
+                        tracing::info!(
+                            "Governance proposal (ethereum bridge parameters) \
+                             {} has been executed and passed, the parameters \
+                             were {}.",
+                            id,
+                            if result { "updated" } else { "not updated" }
+                        );
+
+                        GovernanceEvent::passed_proposal(id, false, false)
+                    }
+                    ProposalType::ParameterChanges(changes) => {
+                        let result = execute_parameter_changes_proposal(
+                            &mut shell.state,
+                            changes,
+                        )?;
+                        tracing::info!(
+                            "Governance proposal (parameter changes) {} has \
+                             been executed and passed, the parameters were {}.",
+                            id,
+                            if result { "changed" } else { "not changed" }
+                        );
+
                         GovernanceEvent::passed_proposal(id, false, false)
                     }
                 };
-                events.emit(proposal_event);
+                events.emit(
+                    proposal_event
+                        .with(FundsDestination(funds_destination.clone())),
+                );
                 proposals_result.passed.push(id);
 
                 // Take events that could have been emitted by PGF
@@ -204,8 +267,6 @@ where
                         .into_iter()
                         .map(|event| event.with(Height(current_height))),
                 );
-
-                gov_api::get_proposal_author(&shell.state, id)?
             }
             TallyResult::Rejected => {
                 if let ProposalType::PGFPayment(_) = proposal_type {
@@ -229,20 +290,21 @@ where
                     id,
                     matches!(proposal_type, ProposalType::DefaultWithWasm(_)),
                 );
-                events.emit(proposal_event);
+                events.emit(
+                    proposal_event
+                        .with(FundsDestination(funds_destination.clone())),
+                );
                 proposals_result.rejected.push(id);
 
                 tracing::info!(
                     "Governance proposal {} has been executed and rejected.",
                     id
                 );
-
-                None
             }
-        };
+        }
 
         let native_token = shell.state.get_native_token()?;
-        if let Some(address) = transfer_address {
+        if let ProposalFundsDestination::Refund(address) = funds_destination {
             token::transfer(
                 &mut shell.state,
                 &native_token,
@@ -461,6 +523,230 @@ where
     Ok(true)
 }
 
+/// Write the updated Ethereum bridge parameters to storage, recording the
+/// changed keys so that the new config is pushed to the Ethereum oracle.
+/// Returns `false` without updating anything if the new Bridge contract is
+/// not newer than the current one, or if the wrapped NAM ERC20 address is
+/// changed while wrapped NAM transfers are still pending in the Bridge pool.
+fn execute_eth_bridge_params_proposal<S>(
+    storage: &mut S,
+    changed_keys: &mut BTreeSet<Key>,
+    update: EthBridgeParamsUpdate,
+) -> Result<bool>
+where
+    S: StorageRead + StorageWrite,
+{
+    let mut updates: Vec<(Key, Vec<u8>)> = vec![];
+
+    if let Some(contract) = update.bridge_contract {
+        let key = bridge_storage::bridge_contract_key();
+        let current: Option<UpgradeableContract> = storage.read(&key)?;
+        if let Some(current) = current {
+            let current_version = NonZeroU64::from(current.version);
+            if contract.version <= current_version {
+                tracing::warn!(
+                    "Not updating the Ethereum bridge parameters, as the new \
+                     Bridge contract version {} is not greater than the \
+                     current version {current_version}",
+                    contract.version,
+                );
+                return Ok(false);
+            }
+        }
+        let contract = UpgradeableContract {
+            address: contract.address,
+            version: contract.version.into(),
+        };
+        updates.push((key, encode(&contract)));
+    }
+    if let Some(address) = update.native_erc20 {
+        let key = bridge_storage::native_erc20_key();
+        let current: Option<EthAddress> = storage.read(&key)?;
+        if let Some(current) = current.filter(|current| *current != address) {
+            // pending wrapped NAM transfers are relayed and released from
+            // escrow based on the current native ERC20 address
+            let prefix = BRIDGE_POOL_ADDRESS.to_db_key().into();
+            let pending = iter_prefix_with_filter::<PendingTransfer, _>(
+                storage,
+                &prefix,
+                is_pending_transfer_key,
+            )?;
+            for transfer in pending {
+                let (_, transfer) = transfer?;
+                if transfer.transfer.asset == current {
+                    tracing::warn!(
+                        "Not updating the Ethereum bridge parameters, as \
+                         there are pending wrapped NAM transfers in the \
+                         Bridge pool"
+                    );
+                    return Ok(false);
+                }
+            }
+        }
+        updates.push((bridge_storage::native_erc20_key(), encode(&address)));
+    }
+    if let Some(confirmations) = update.min_confirmations {
+        let confirmations = MinimumConfirmations::from(confirmations);
+        updates.push((
+            bridge_storage::min_confirmations_key(),
+            encode(&confirmations),
+        ));
+    }
+
+    for (key, value) in updates {
+        storage.write_bytes(&key, value)?;
+        changed_keys.insert(key);
+    }
+
+    Ok(true)
+}
+
+/// Write the changed protocol parameters to storage. Returns `false`
+/// without changing anything if the changes or the resulting PoS parameters
+/// are not valid. A new gas cost table is loaded into the wasm caches once
+/// the block is committed.
+fn execute_parameter_changes_proposal<S>(
+    storage: &mut S,
+    changes: BTreeSet<ParameterChange>,
+) -> Result<bool>
+where
+    S: StorageRead + StorageWrite,
+{
+    let native_token = storage.get_native_token()?;
+    if let Err(err) = validate_parameter_changes(&changes, &native_token) {
+        tracing::warn!(
+            "Not changing the protocol parameters, as the changes are \
+             invalid: {err}"
+        );
+        return Ok(false);
+    }
+
+    let mut epoch_duration =
+        parameters::read_epoch_duration_parameter(storage)?;
+    let mut max_block_gas = None;
+    let gas_cost_key = parameters::storage::get_gas_cost_key();
+    let mut gas_cost: BTreeMap<Address, token::Amount> =
+        storage.read(&gas_cost_key)?.unwrap_or_default();
+    let mut fee_conversion_rates =
+        parameters::read_fee_conversion_rates(storage)?;
+    let mut fee_price_feed = None;
+    let mut gas_cost_table = None;
+    let mut upgrade_height = None;
+    let mut pos_params = read_pos_params(storage)?.owned;
+
+    for change in changes {
+        match change {
+            ParameterChange::MinEpochNumBlocks(blocks) => {
+                epoch_duration.min_num_of_blocks = blocks;
+            }
+            ParameterChange::MinEpochDuration(duration) => {
+                epoch_duration.min_duration = duration;
+            }
+            ParameterChange::MaxBlockGas(gas) => {
+                max_block_gas = Some(gas);
+            }
+            ParameterChange::MinimumGasPrice {
+                token,
+                amount: Some(amount),
+            } => {
+                gas_cost.insert(token, amount);
+            }
+            ParameterChange::MinimumGasPrice {
+                token,
+                amount: None,
+            } => {
+                gas_cost.remove(&token);
+            }
+            ParameterChange::PosMaxInflationRate(rate) => {
+                pos_params.max_inflation_rate = rate;
+            }
+            ParameterChange::PosTargetStakedRatio(ratio) => {
+                pos_params.target_staked_ratio = ratio;
+            }
+            ParameterChange::PosLivenessThreshold(threshold) => {
+                pos_params.liveness_threshold = threshold;
+            }
+            ParameterChange::FeeConversionRate {
+                token,
+                rate: Some(rate),
+            } => {
+                fee_conversion_rates.insert(token, rate);
+            }
+            ParameterChange::FeeConversionRate { token, rate: None } => {
+                fee_conversion_rates.remove(&token);
+            }
+            ParameterChange::FeePriceFeed(address) => {
+                fee_price_feed = Some(address);
+            }
+            ParameterChange::GasCostTable(table) => {
+                gas_cost_table = Some(table);
+            }
+            ParameterChange::UpgradeHeight(height) => {
+                upgrade_height = Some(height);
+            }
+        }
+    }
+
+    if let Some(table) = &gas_cost_table {
+        let current = parameters::read_vm_gas_cost_table(storage)?;
+        if let Err(err) = table.validate_update(&current) {
+            tracing::warn!(
+                "Not changing the protocol parameters, as the new gas cost \
+                 table is invalid: {err}"
+            );
+            return Ok(false);
+        }
+    }
+    if let Some(height) = upgrade_height {
+        let current_height = storage.get_block_height()?;
+        if height <= current_height {
+            tracing::warn!(
+                "Not changing the protocol parameters, as the upgrade height \
+                 {height} is not after the current height {current_height}"
+            );
+            return Ok(false);
+        }
+    }
+
+    let errors = pos_params.validate();
+    if !errors.is_empty() {
+        tracing::warn!(
+            "Not changing the protocol parameters, as the new PoS parameters \
+             are invalid: {errors:?}"
+        );
+        return Ok(false);
+    }
+
+    parameters::update_epoch_parameter(storage, &epoch_duration)?;
+    if let Some(max_block_gas) = max_block_gas {
+        storage.write(
+            &parameters::storage::get_max_block_gas_key(),
+            max_block_gas,
+        )?;
+    }
+    storage.write(&gas_cost_key, gas_cost)?;
+    storage.write(
+        &parameters::storage::get_fee_conversion_rates_key(),
+        fee_conversion_rates,
+    )?;
+    let fee_price_feed_key = parameters::storage::get_fee_price_feed_key();
+    match fee_price_feed {
+        Some(Some(address)) => storage.write(&fee_price_feed_key, address)?,
+        Some(None) => storage.delete(&fee_price_feed_key)?,
+        None => {}
+    }
+    if let Some(table) = gas_cost_table {
+        parameters::update_vm_gas_cost_table(storage, &table)?;
+    }
+    if let Some(height) = upgrade_height {
+        storage
+            .write(&parameters::storage::get_upgrade_height_key(), height)?;
+    }
+    namada::proof_of_stake::storage::write_pos_params(storage, &pos_params)?;
+
+    Ok(true)
+}
+
 fn execute_pgf_funding_proposal<D, H>(
     state: &mut WlState<D, H>,
     events: &mut impl EmitEvents,
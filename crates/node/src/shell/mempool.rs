@@ -0,0 +1,107 @@
+//! Tracking of the wrapper txs accepted into the mempool of this node.
+
+use std::collections::BTreeMap;
+
+use namada::core::address::Address;
+use namada::core::hash::Hash;
+use namada::core::token::DenominatedAmount;
+use namada::tx::data::WrapperTx;
+use namada::tx::Tx;
+
+use crate::metrics::{to_gauge_value, METRICS};
+
+/// The wrapper txs accepted into the mempool since the last committed block,
+/// used to reject duplicate submissions of the same txs.
+///
+/// Two wrappers are considered duplicates if they carry the same raw header,
+/// i.e. the same batch of inner txs, which replay protection would only let
+/// through once. Inner txs built separately get distinct raw headers, since
+/// their sections are salted with the time they were built at, so
+/// repeating a transfer is not affected.
+///
+/// A duplicate paying a higher fee per gas unit, in the same token, replaces
+/// the tracked wrapper, so that a stuck tx can be resubmitted with a bumped
+/// fee. The replaced wrapper is then evicted when it is rechecked.
+///
+/// CometBFT removes the txs included in a block from its mempool, and
+/// rechecks the remaining ones after each commit. Thus, the tracked txs are
+/// cleared on commit and re-added as they get rechecked. The txs evicted by
+/// a failed recheck are dropped right away.
+#[derive(Debug, Default)]
+pub struct MempoolTxs {
+    /// The tracked wrapper txs, keyed by their raw header hash
+    wrappers: BTreeMap<Hash, MempoolEntry>,
+}
+
+/// The hashes and the fee of a wrapper tx
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MempoolEntry {
+    raw_header_hash: Hash,
+    wrapper_hash: Hash,
+    fee_token: Address,
+    amount_per_gas_unit: DenominatedAmount,
+}
+
+impl MempoolEntry {
+    /// Compute the mempool entry of the given wrapper tx
+    pub fn new(tx: &Tx, wrapper: &WrapperTx) -> Self {
+        Self {
+            raw_header_hash: tx.raw_header_hash(),
+            wrapper_hash: tx.header_hash(),
+            fee_token: wrapper.fee.token.clone(),
+            amount_per_gas_unit: wrapper.fee.amount_per_gas_unit,
+        }
+    }
+
+    /// Check if this entry pays a higher fee than the given one
+    fn outbids(&self, entry: &MempoolEntry) -> bool {
+        self.fee_token == entry.fee_token
+            && self.amount_per_gas_unit > entry.amount_per_gas_unit
+    }
+}
+
+impl MempoolTxs {
+    /// Get the hash of the tracked wrapper tx with the same raw header as
+    /// the given one, if it is not the given tx itself and the given tx
+    /// doesn't pay a higher fee
+    pub fn duplicate_of(&self, entry: &MempoolEntry) -> Option<Hash> {
+        self.wrappers
+            .get(&entry.raw_header_hash)
+            .filter(|tracked| {
+                tracked.wrapper_hash != entry.wrapper_hash
+                    && !entry.outbids(tracked)
+            })
+            .map(|tracked| tracked.wrapper_hash)
+    }
+
+    /// Track a wrapper tx accepted into the mempool, replacing the tracked
+    /// wrapper with the same raw header, if any
+    pub fn insert(&mut self, entry: MempoolEntry) {
+        self.wrappers.insert(entry.raw_header_hash, entry);
+        METRICS.mempool_txs.set(to_gauge_value(self.len()));
+    }
+
+    /// Stop tracking the wrapper tx with the given hash, e.g. once it was
+    /// evicted from the mempool
+    pub fn remove(&mut self, wrapper_hash: &Hash) {
+        self.wrappers
+            .retain(|_, tracked| tracked.wrapper_hash != *wrapper_hash);
+        METRICS.mempool_txs.set(to_gauge_value(self.len()));
+    }
+
+    /// Stop tracking all the wrapper txs
+    pub fn clear(&mut self) {
+        self.wrappers.clear();
+        METRICS.mempool_txs.set(0);
+    }
+
+    /// The number of tracked wrapper txs
+    pub fn len(&self) -> usize {
+        self.wrappers.len()
+    }
+
+    /// Check if no wrapper txs are tracked
+    pub fn is_empty(&self) -> bool {
+        self.wrappers.is_empty()
+    }
+}
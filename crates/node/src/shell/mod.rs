@@ -9,13 +9,17 @@ pub mod block_alloc;
 mod finalize_block;
 mod governance;
 mod init_chain;
+mod mempool;
 pub use init_chain::InitChainValidation;
 use namada::vm::wasm::run::check_tx_allowed;
 use namada_sdk::state::StateRead;
 pub mod prepare_proposal;
 use namada::state::State;
 pub mod process_proposal;
+mod pruning;
 pub(super) mod queries;
+mod snapshots;
+pub use snapshots::discard_interrupted_snapshot_restore;
 mod stats;
 #[cfg(any(test, feature = "testing"))]
 #[allow(dead_code)]
@@ -48,12 +52,12 @@ use namada::ledger::pos::namada_proof_of_stake::types::{
 };
 use namada::ledger::protocol::ShellParams;
 use namada::ledger::{parameters, protocol};
-use namada::parameters::validate_tx_bytes;
+use namada::parameters::{validate_code_bytes, validate_tx_bytes};
 use namada::proof_of_stake::storage::read_pos_params;
 use namada::state::tx_queue::ExpiredTx;
 use namada::state::{
     DBIter, FullAccessState, Sha256Hasher, StorageHasher, StorageRead,
-    TempWlState, WlState, DB, EPOCH_SWITCH_BLOCKS_DELAY,
+    StorageWrite, TempWlState, WlState, DB, EPOCH_SWITCH_BLOCKS_DELAY,
 };
 use namada::token;
 pub use namada::tx::data::ResultCode;
@@ -118,8 +122,14 @@ pub enum Error {
     Storage(#[from] namada::state::StorageError),
     #[error("Transaction replay attempt: {0}")]
     ReplayAttempt(String),
+    #[error("Halted at block height {0} for a scheduled chain upgrade")]
+    HaltedForUpgrade(BlockHeight),
 }
 
+/// Name of the file written to the chain directory once the upgrade height
+/// scheduled by governance has been committed.
+pub const UPGRADE_MARKER_FILE: &str = "upgrade-marker";
+
 impl From<Error> for TxResult {
     fn from(err: Error) -> Self {
         TxResult {
@@ -351,6 +361,46 @@ where
     storage_read_past_height_limit: Option<u64>,
     /// Log of events emitted by `FinalizeBlock` ABCI calls.
     event_log: EventLog,
+    /// State sync snapshots taken by this node and the snapshot being
+    /// restored, if any.
+    snapshots: snapshots::Snapshots,
+    /// Taken from config `pruning`. Pruning of the data of old blocks.
+    pruning: config::PruningMode,
+    /// The background pruning job started after the last commit, if any.
+    pruning_job: Option<std::thread::JoinHandle<()>>,
+    /// Taken from config `tx_index`. When enabled, holds the addresses
+    /// touched by the txs of the block being finalized, to be written to the
+    /// tx index on commit.
+    tx_index_entries: Option<Vec<(Address, TxIndex)>>,
+    /// Results of the validation of the block proposals received on top of
+    /// the last committed block.
+    proposal_cache: RefCell<process_proposal::ProposalCache>,
+    /// The wrapper txs accepted into the mempool since the last committed
+    /// block.
+    mempool_txs: RefCell<mempool::MempoolTxs>,
+    /// The upgrade height scheduled by governance, once the block at that
+    /// height has been finalized, or the height recorded in the upgrade
+    /// marker left by a previous run of the node.
+    upgrade_height_reached: Option<BlockHeight>,
+    /// Taken from config `halt_at_height`. The height after which the node
+    /// stops processing blocks.
+    halt_at_height: Option<BlockHeight>,
+}
+
+impl<D, H> Drop for Shell<D, H>
+where
+    D: DB + for<'iter> DBIter<'iter> + Sync + 'static,
+    H: StorageHasher + Sync + 'static,
+{
+    fn drop(&mut self) {
+        // NB: the DB only flushes itself once its last handle is dropped,
+        // which may be held by a background job, so the shell flushes it
+        // on shutdown regardless
+        self.join_pruning_job();
+        if let Err(err) = self.state.db().flush(true) {
+            tracing::error!("Failed to flush the DB on shutdown: {err}");
+        }
+    }
 }
 
 /// Storage key filter to store the diffs into the storage. Return `false` for
@@ -371,6 +421,7 @@ pub struct EthereumOracleChannels {
     ethereum_receiver: EthereumReceiver,
     control_sender: oracle::control::Sender,
     last_processed_block_receiver: last_processed_block::Receiver,
+    status_receiver: oracle::status::Receiver,
 }
 
 impl EthereumOracleChannels {
@@ -378,11 +429,13 @@ impl EthereumOracleChannels {
         events_receiver: Receiver<EthereumEvent>,
         control_sender: oracle::control::Sender,
         last_processed_block_receiver: last_processed_block::Receiver,
+        status_receiver: oracle::status::Receiver,
     ) -> Self {
         Self {
             ethereum_receiver: EthereumReceiver::new(events_receiver),
             control_sender,
             last_processed_block_receiver,
+            status_receiver,
         }
     }
 }
@@ -406,10 +459,27 @@ where
     ) -> Self {
         let chain_id = config.chain_id;
         let db_path = config.shell.db_dir(&chain_id);
+        let snapshots = snapshots::Snapshots::new(
+            config.shell.snapshots_dir(&chain_id),
+            config.shell.snapshot_interval,
+            config.shell.snapshots_to_keep,
+        );
         let base_dir = config.shell.base_dir;
         let mode = config.shell.tendermint_mode;
         let storage_read_past_height_limit =
             config.shell.storage_read_past_height_limit;
+        let pruning = config.shell.pruning;
+        let tx_index = config.shell.tx_index;
+        let halt_at_height = config.shell.halt_at_height;
+        if pruning != config::PruningMode::Archive
+            && storage_read_past_height_limit.is_none()
+        {
+            tracing::warn!(
+                "Pruning is disabled, because the state can be read at any \
+                 past height. Set `shell.storage_read_past_height_limit` to \
+                 enable it."
+            );
+        }
         if !Path::new(&base_dir).is_dir() {
             std::fs::create_dir(&base_dir)
                 .expect("Creating directory for Namada should not fail");
@@ -435,7 +505,7 @@ where
         };
 
         // load last state from storage
-        let state = FullAccessState::open(
+        let mut state = FullAccessState::open(
             db_path,
             db_cache,
             chain_id.clone(),
@@ -443,6 +513,20 @@ where
             config.shell.storage_read_past_height_limit,
             is_key_diff_storable,
         );
+        if let Some(cache_size) = config.shell.merkle_tree_cache_size {
+            state
+                .in_mem_mut()
+                .merkle_tree_cache
+                .set_capacity(cache_size);
+        }
+        if let Some(height) = config.ethereum_bridge.oracle_rescan_from {
+            tracing::warn!(
+                height,
+                "Overriding the last processed Ethereum block, the Ethereum \
+                 oracle will rescan events from the configured height"
+            );
+            state.in_mem_mut().ethereum_height = Some(height.into());
+        }
         let vp_wasm_cache_dir =
             base_dir.join(chain_id.as_str()).join("vp_wasm_cache");
         let tx_wasm_cache_dir =
@@ -450,7 +534,7 @@ where
         // load in keys and address from wallet if mode is set to `Validator`
         let mode = match mode {
             TendermintMode::Validator => {
-                #[cfg(not(test))]
+                #[cfg(not(any(test, feature = "benches")))]
                 {
                     let wallet_path = &base_dir.join(chain_id.as_str());
                     tracing::debug!(
@@ -488,7 +572,7 @@ where
                              wallet",
                         )
                 }
-                #[cfg(test)]
+                #[cfg(any(test, feature = "benches"))]
                 {
                     let (protocol_keypair, eth_bridge_keypair) =
                         wallet::defaults::validator_keys();
@@ -531,8 +615,18 @@ where
             storage_read_past_height_limit,
             // TODO(namada#3237): config event log params
             event_log: EventLog::default(),
+            snapshots,
+            pruning,
+            pruning_job: None,
+            tx_index_entries: tx_index.then(Vec::new),
+            proposal_cache: Default::default(),
+            mempool_txs: Default::default(),
+            upgrade_height_reached: None,
+            halt_at_height,
         };
+        shell.upgrade_height_reached = shell.read_upgrade_marker();
         shell.update_eth_oracle(&Default::default());
+        shell.update_wasm_gas_costs();
         shell
     }
 
@@ -585,6 +679,10 @@ where
                 );
             }
         };
+        if self.is_eth_oracle_degraded() {
+            // Surfaced in the `abci_info` query of CometBFT
+            response.data = "Ethereum bridge oracle degraded".to_string();
+        }
 
         response
     }
@@ -643,11 +741,21 @@ where
     /// Commit a block. Persist the application state and return the Merkle root
     /// hash.
     pub fn commit(&mut self) -> response::Commit {
+        self.join_pruning_job();
         self.bump_last_processed_eth_block();
 
+        if let Some(entries) = self.tx_index_entries.as_mut() {
+            self.state
+                .write_tx_index_entries(std::mem::take(entries))
+                .expect("Encountered a storage error while indexing txs");
+        }
+
         self.state
             .commit_block()
             .expect("Encountered a storage error while committing a block");
+        self.proposal_cache.get_mut().clear();
+        self.mempool_txs.get_mut().clear();
+        self.update_wasm_gas_costs();
 
         let merkle_root = self.state.in_mem().merkle_root();
         let committed_height = self.state.in_mem().get_last_block_height();
@@ -655,6 +763,10 @@ where
             "Committed block hash: {merkle_root}, height: {committed_height}",
         );
 
+        if let Some(upgrade_height) = self.upgrade_halt_height() {
+            self.write_upgrade_marker(upgrade_height);
+        }
+
         self.broadcast_queued_txs();
 
         response::Commit {
@@ -666,6 +778,92 @@ where
         }
     }
 
+    /// Load the gas costs of the wasm opcodes from the committed state into
+    /// the wasm compilation caches. The costs can only change in a committed
+    /// block, so they don't need to be read on every wasm execution.
+    fn update_wasm_gas_costs(&self) {
+        let gas_costs = namada::parameters::read_vm_gas_cost_table(&self.state)
+            .expect("Must be able to read the gas costs of the wasm opcodes");
+        self.vp_wasm_cache.update_gas_costs(gas_costs.clone());
+        self.tx_wasm_cache.update_gas_costs(gas_costs);
+    }
+
+    /// Returns the upgrade height scheduled by governance or the
+    /// `halt_at_height` of the node config, if the block at that height has
+    /// been committed. Once that is the case, the shell must not process any
+    /// further blocks.
+    pub fn upgrade_halt_height(&self) -> Option<BlockHeight> {
+        let last_height = self.state.in_mem().get_last_block_height();
+        [self.upgrade_height_reached, self.halt_at_height]
+            .into_iter()
+            .flatten()
+            .filter(|height| *height <= last_height)
+            .min()
+    }
+
+    /// If the block being finalized is at the upgrade height scheduled by
+    /// governance, remove the upgrade height from storage and remember to
+    /// halt once the block is committed. The halt is persisted by the
+    /// upgrade marker written on commit, until the operator removes it.
+    fn clear_reached_upgrade_height(
+        &mut self,
+        height: BlockHeight,
+    ) -> Result<()> {
+        let upgrade_height =
+            namada::parameters::storage::get_upgrade_height(&self.state)?;
+        if upgrade_height == Some(height) {
+            self.state.delete(
+                &namada::parameters::storage::get_upgrade_height_key(),
+            )?;
+            self.upgrade_height_reached = Some(height);
+        }
+        Ok(())
+    }
+
+    /// Write the upgrade marker file to the chain directory, signaling to
+    /// operators and tooling that the node halted for a chain upgrade.
+    fn write_upgrade_marker(&self, upgrade_height: BlockHeight) {
+        let marker_path = self.upgrade_marker_path();
+        let merkle_root = self.state.in_mem().merkle_root();
+        let contents = format!(
+            "height = {upgrade_height}\napp_hash = \"{merkle_root}\"\n"
+        );
+        match std::fs::write(&marker_path, contents) {
+            Ok(()) => tracing::info!(
+                "Reached the upgrade height {upgrade_height}, halting the \
+                 chain. Wrote the upgrade marker to {}.",
+                marker_path.to_string_lossy()
+            ),
+            Err(err) => tracing::error!(
+                "Reached the upgrade height {upgrade_height}, but failed to \
+                 write the upgrade marker to {}: {err}",
+                marker_path.to_string_lossy()
+            ),
+        }
+    }
+
+    /// Read the upgrade height from the upgrade marker file. A node
+    /// restarted after halting for an upgrade keeps halting until the
+    /// operator acknowledges the upgrade by removing the marker, which
+    /// doesn't depend on the version of the upgraded binary.
+    fn read_upgrade_marker(&self) -> Option<BlockHeight> {
+        let contents =
+            std::fs::read_to_string(self.upgrade_marker_path()).ok()?;
+        contents
+            .lines()
+            .find_map(|line| match line.split_once(" = ") {
+                Some(("height", value)) => value.parse().ok().map(BlockHeight),
+                _ => None,
+            })
+    }
+
+    /// The path of the upgrade marker file in the chain directory
+    fn upgrade_marker_path(&self) -> PathBuf {
+        self.base_dir
+            .join(self.chain_id.as_str())
+            .join(UPGRADE_MARKER_FILE)
+    }
+
     /// Updates the Ethereum oracle's last processed block.
     #[inline]
     fn bump_last_processed_eth_block(&mut self) {
@@ -691,6 +889,19 @@ where
         }
     }
 
+    /// Check if the Ethereum oracle of this node failed to query its RPC
+    /// endpoint for longer than its stall threshold.
+    pub fn is_eth_oracle_degraded(&self) -> bool {
+        matches!(
+            &self.mode,
+            ShellMode::Validator {
+                eth_oracle: Some(eth_oracle),
+                ..
+            } if *eth_oracle.status_receiver.borrow()
+                == oracle::status::Status::Degraded
+        )
+    }
+
     /// Empties all the ledger's queues of transactions to be broadcasted
     /// via CometBFT's P2P network.
     #[inline]
@@ -836,6 +1047,7 @@ where
             );
             let config = namada::eth_bridge::oracle::config::Config {
                 min_confirmations: config.min_confirmations.into(),
+                event_confirmations: config.event_confirmations,
                 bridge_contract: config.contracts.bridge.address,
                 start_block,
                 active,
@@ -871,8 +1083,24 @@ where
     pub fn mempool_validate(
         &self,
         tx_bytes: &[u8],
-        r#_type: MempoolTxType,
+        r#type: MempoolTxType,
     ) -> response::CheckTx {
+        let response = self.check_mempool_tx(tx_bytes);
+
+        // CometBFT evicts the txs that fail a recheck from its mempool, so
+        // they must not be reported as duplicates anymore
+        if matches!(r#type, MempoolTxType::RecheckTransaction)
+            && response.code != ResultCode::Ok.into()
+        {
+            if let Ok(tx) = Tx::try_from(tx_bytes) {
+                self.mempool_txs.borrow_mut().remove(&tx.header_hash());
+            }
+        }
+        response
+    }
+
+    /// Run the mempool validation checks of the given tx
+    fn check_mempool_tx(&self, tx_bytes: &[u8]) -> response::CheckTx {
         use namada::tx::data::protocol::ProtocolTxType;
         use namada::vote_ext::ethereum_tx_data_variants;
 
@@ -903,6 +1131,15 @@ where
             }
         };
 
+        // Embedded wasm code size
+        if !validate_code_bytes(&self.state, tx.max_embedded_code_len())
+            .expect("Failed to get max code bytes param from storage")
+        {
+            response.code = ResultCode::CodeTooLarge.into();
+            response.log = format!("{INVALID_MSG}: Tx code too large");
+            return response;
+        }
+
         // Tx chain id
         if tx.header.chain_id != self.chain_id {
             response.code = ResultCode::InvalidChainId.into();
@@ -1140,6 +1377,21 @@ where
                         return response;
                     }
                 }
+
+                // Reject duplicates of the txs already in the mempool
+                let entry = mempool::MempoolEntry::new(&tx, &wrapper);
+                let mut mempool_txs = self.mempool_txs.borrow_mut();
+                if let Some(duplicate) = mempool_txs.duplicate_of(&entry) {
+                    response.code = ResultCode::DuplicateTx.into();
+                    response.log = format!(
+                        "{INVALID_MSG}: Wrapper transaction {} has the same \
+                         inner transactions as {duplicate}, which is already \
+                         in the mempool with a fee at least as high",
+                        tx.header_hash()
+                    );
+                    return response;
+                }
+                mempool_txs.insert(entry);
             }
             TxType::Raw => {
                 response.code = ResultCode::InvalidTx.into();
@@ -1494,11 +1746,13 @@ mod test_utils {
                 tokio::sync::mpsc::channel(ORACLE_CHANNEL_BUFFER_SIZE);
             let (_, last_processed_block_receiver) =
                 last_processed_block::channel();
+            let (_, status_receiver) = oracle::status::channel();
             let (control_sender, control_receiver) = oracle::control::channel();
             let eth_oracle = EthereumOracleChannels::new(
                 eth_receiver,
                 control_sender,
                 last_processed_block_receiver,
+                status_receiver,
             );
             let base_dir = tempdir().unwrap().as_ref().canonicalize().unwrap();
             let vp_wasm_compilation_cache = 50 * 1024 * 1024; // 50 kiB
@@ -1686,30 +1940,9 @@ mod test_utils {
         }
     }
 
-    /// Start a new test shell and initialize it. Returns the shell paired with
-    /// a broadcast receiver, which will receives any protocol txs sent by the
-    /// shell.
-    pub(super) fn setup_with_cfg<H: Into<BlockHeight>>(
-        SetupCfg {
-            last_height,
-            num_validators,
-            enable_ethereum_oracle,
-        }: SetupCfg<H>,
-    ) -> (
-        TestShell,
-        UnboundedReceiver<Vec<u8>>,
-        Sender<EthereumEvent>,
-        Receiver<oracle::control::Command>,
-    ) {
-        let (mut test, receiver, eth_sender, control_receiver) =
-            TestShell::new_at_height(last_height);
-        if !enable_ethereum_oracle {
-            if let ShellMode::Validator { eth_oracle, .. } = &mut test.mode {
-                // drop the eth oracle event receiver
-                _ = eth_oracle.take();
-            }
-        }
-        let req = request::InitChain {
+    /// The `InitChain` request used to initialize the test shells
+    pub(super) fn init_chain_request() -> request::InitChain {
+        request::InitChain {
             time: Timestamp {
                 seconds: 0,
                 nanos: 0,
@@ -1741,8 +1974,33 @@ mod test_utils {
             validators: vec![],
             app_state_bytes: vec![].into(),
             initial_height: 1_u32.into(),
-        };
-        test.init_chain(req, num_validators);
+        }
+    }
+
+    /// Start a new test shell and initialize it. Returns the shell paired with
+    /// a broadcast receiver, which will receives any protocol txs sent by the
+    /// shell.
+    pub(super) fn setup_with_cfg<H: Into<BlockHeight>>(
+        SetupCfg {
+            last_height,
+            num_validators,
+            enable_ethereum_oracle,
+        }: SetupCfg<H>,
+    ) -> (
+        TestShell,
+        UnboundedReceiver<Vec<u8>>,
+        Sender<EthereumEvent>,
+        Receiver<oracle::control::Command>,
+    ) {
+        let (mut test, receiver, eth_sender, control_receiver) =
+            TestShell::new_at_height(last_height);
+        if !enable_ethereum_oracle {
+            if let ShellMode::Validator { eth_oracle, .. } = &mut test.mode {
+                // drop the eth oracle event receiver
+                _ = eth_oracle.take();
+            }
+        }
+        test.init_chain(init_chain_request(), num_validators);
         test.state.commit_block().expect("Test failed");
         (test, receiver, eth_sender, control_receiver)
     }
@@ -1858,6 +2116,9 @@ mod test_utils {
 
 #[cfg(test)]
 mod shell_tests {
+    use std::collections::BTreeMap;
+
+    use namada::core::dec::Dec;
     use namada::core::storage::Epoch;
     use namada::eth_bridge::storage::eth_bridge_queries::is_bridge_comptime_enabled;
     use namada::token::read_denom;
@@ -2421,6 +2682,136 @@ mod shell_tests {
         )
     }
 
+    /// Mempool validation must reject wrappers carrying the same inner txs
+    /// as a wrapper already in the mempool, unless they pay a higher fee,
+    /// until the next block is committed
+    #[test]
+    fn test_duplicate_in_mempool() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+
+        let timestamp = Tx::from_type(TxType::Raw).header.timestamp;
+        let new_wrapper = |amount_per_gas_unit: u64, salt: u8| {
+            let mut wrapper =
+                Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit: DenominatedAmount::native(
+                            token::Amount::from_uint(amount_per_gas_unit, 0)
+                                .expect("This can't fail"),
+                        ),
+                        token: shell.state.in_mem().native_token.clone(),
+                    },
+                    wallet::defaults::albert_keypair().ref_to(),
+                    GAS_LIMIT_MULTIPLIER.into(),
+                ))));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper.header.timestamp = timestamp;
+            // the sections are salted with a fixed value, rather than the
+            // current time
+            let mut code = Code::new("wasm_code".as_bytes().to_owned(), None);
+            code.salt = [salt; 8];
+            wrapper.set_code(code);
+            let mut data = Data::new("transaction data".as_bytes().to_owned());
+            data.salt = [salt; 8];
+            wrapper.set_data(data);
+            wrapper.add_section(Section::Authorization(Authorization::new(
+                wrapper.sechashes(),
+                [(0, wallet::defaults::albert_keypair())]
+                    .into_iter()
+                    .collect(),
+                None,
+            )));
+            wrapper
+        };
+        let wrapper = new_wrapper(100, 0);
+        let result = shell.mempool_validate(
+            wrapper.to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert_eq!(result.code, ResultCode::Ok.into());
+
+        // the same inner txs can't be resubmitted without bumping their fee
+        for amount_per_gas_unit in [50, 100] {
+            let duplicate = new_wrapper(amount_per_gas_unit, 0);
+            assert_ne!(wrapper.header_hash(), duplicate.header_hash());
+            assert_eq!(wrapper.raw_header_hash(), duplicate.raw_header_hash());
+            let result = shell.mempool_validate(
+                duplicate.to_bytes().as_ref(),
+                MempoolTxType::NewTransaction,
+            );
+            assert_eq!(result.code, ResultCode::DuplicateTx.into());
+            assert_eq!(
+                result.log,
+                format!(
+                    "Mempool validation failed: Wrapper transaction {} has \
+                     the same inner transactions as {}, which is already in \
+                     the mempool with a fee at least as high",
+                    duplicate.header_hash(),
+                    wrapper.header_hash()
+                )
+            );
+        }
+
+        // the same transfer built again is not a duplicate
+        let repeated = new_wrapper(100, 1);
+        assert_ne!(wrapper.raw_header_hash(), repeated.raw_header_hash());
+        let result = shell.mempool_validate(
+            repeated.to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert_eq!(result.code, ResultCode::Ok.into());
+
+        // a duplicate with a higher fee replaces the tracked tx, which is
+        // evicted once rechecked
+        let bumped = new_wrapper(200, 0);
+        let result = shell.mempool_validate(
+            bumped.to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert_eq!(result.code, ResultCode::Ok.into());
+        let result = shell.mempool_validate(
+            wrapper.to_bytes().as_ref(),
+            MempoolTxType::RecheckTransaction,
+        );
+        assert_eq!(result.code, ResultCode::DuplicateTx.into());
+        assert_eq!(shell.mempool_txs.borrow().len(), 2);
+
+        // rechecking the tracked tx itself must succeed
+        let result = shell.mempool_validate(
+            bumped.to_bytes().as_ref(),
+            MempoolTxType::RecheckTransaction,
+        );
+        assert_eq!(result.code, ResultCode::Ok.into());
+
+        // the tracked txs are cleared once a block is committed
+        shell.finalize_and_commit(None);
+        let result = shell.mempool_validate(
+            wrapper.to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert_eq!(result.code, ResultCode::Ok.into());
+
+        // a tracked tx evicted by a failed recheck is not tracked anymore
+        let mut batch = namada::state::testing::TestState::batch();
+        shell
+            .state
+            .write_replay_protection_entry(
+                &mut batch,
+                &replay_protection::current_key(&wrapper.header_hash()),
+            )
+            .expect("Test failed");
+        let result = shell.mempool_validate(
+            wrapper.to_bytes().as_ref(),
+            MempoolTxType::RecheckTransaction,
+        );
+        assert_eq!(result.code, ResultCode::ReplayTx.into());
+        assert!(shell.mempool_txs.borrow().is_empty());
+        let result = shell.mempool_validate(
+            bumped.to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert_eq!(result.code, ResultCode::Ok.into());
+    }
+
     /// Check that a transaction with a wrong chain id gets discarded
     #[test]
     fn test_wrong_chain_id() {
@@ -2606,6 +2997,122 @@ mod shell_tests {
         assert_eq!(result.code, ResultCode::FeeError.into());
     }
 
+    // Check that a wrapper paying fees in a token priced by conversion from the
+    // native token is accepted, as long as it covers the converted minimum gas
+    // price
+    #[test]
+    fn test_fee_converted_token() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        let apfel = address::testing::apfel();
+        let apfel_denom = read_denom(&shell.state, &apfel)
+            .expect("unable to read denomination from storage")
+            .expect("unable to find denomination of apfels");
+        // The minimum gas price of the native token is a single unit of its
+        // smallest denomination, converting to 2 units of apfels
+        parameters::update_fee_conversion_rates(
+            &mut shell.state,
+            BTreeMap::from([(apfel.clone(), Dec::from(2))]),
+        )
+        .expect("Test failed");
+
+        let new_wrapper = |amount_per_gas_unit: u64| {
+            let mut wrapper =
+                Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit: DenominatedAmount::new(
+                            amount_per_gas_unit.into(),
+                            apfel_denom,
+                        ),
+                        token: apfel.clone(),
+                    },
+                    wallet::defaults::albert_keypair().ref_to(),
+                    GAS_LIMIT_MULTIPLIER.into(),
+                ))));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper
+                .set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
+            wrapper.set_data(Data::new(
+                "transaction data".as_bytes().to_owned(),
+            ));
+            wrapper.add_section(Section::Authorization(Authorization::new(
+                wrapper.sechashes(),
+                [(0, wallet::defaults::albert_keypair())]
+                    .into_iter()
+                    .collect(),
+                None,
+            )));
+            wrapper
+        };
+
+        let result = shell.mempool_validate(
+            new_wrapper(1).to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert_eq!(result.code, ResultCode::FeeError.into());
+
+        let result = shell.mempool_validate(
+            new_wrapper(100).to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert_eq!(result.code, ResultCode::Ok.into());
+    }
+
+    /// Check that a fee token whose converted gas cost overflows is treated
+    /// as if it could not pay for fees, instead of failing to read it
+    #[test]
+    fn test_fee_conversion_overflow() {
+        use namada::core::uint::I256;
+        use namada::state::StorageWrite;
+
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        let apfel = address::testing::apfel();
+        let native_token = shell.state.in_mem().native_token.clone();
+        shell
+            .state
+            .write(
+                &parameters::storage::get_gas_cost_key(),
+                BTreeMap::from([(native_token, token::Amount::from(3))]),
+            )
+            .expect("Test failed");
+        parameters::update_fee_conversion_rates(
+            &mut shell.state,
+            BTreeMap::from([(apfel.clone(), Dec(I256::maximum()))]),
+        )
+        .expect("Test failed");
+
+        assert_eq!(
+            parameters::read_gas_cost(&shell.state, &apfel)
+                .expect("Test failed"),
+            None
+        );
+
+        let mut wrapper =
+            Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                Fee {
+                    amount_per_gas_unit: DenominatedAmount::native(1.into()),
+                    token: apfel,
+                },
+                wallet::defaults::albert_keypair().ref_to(),
+                GAS_LIMIT_MULTIPLIER.into(),
+            ))));
+        wrapper.header.chain_id = shell.chain_id.clone();
+        wrapper.set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
+        wrapper.set_data(Data::new("transaction data".as_bytes().to_owned()));
+        wrapper.add_section(Section::Authorization(Authorization::new(
+            wrapper.sechashes(),
+            [(0, wallet::defaults::albert_keypair())]
+                .into_iter()
+                .collect(),
+            None,
+        )));
+
+        let result = shell.mempool_validate(
+            wrapper.to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert_eq!(result.code, ResultCode::FeeError.into());
+    }
+
     // Check that a wrapper transactions whose fees cannot be paid is rejected
     #[test]
     fn test_insufficient_balance_for_fee() {
@@ -2727,4 +3234,222 @@ mod shell_tests {
         );
         assert_eq!(result.code, ResultCode::TooLarge.into());
     }
+
+    /// Test max code bytes parameter in CheckTx
+    #[test]
+    fn test_max_code_bytes_check_tx() {
+        use namada::state::StorageWrite;
+
+        const MAX_CODE_BYTES: u32 = 100;
+
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        shell
+            .state
+            .write(
+                &parameters::storage::get_max_code_bytes_key(),
+                MAX_CODE_BYTES,
+            )
+            .unwrap();
+
+        let new_tx = |size: u32| {
+            let keypair = super::test_utils::gen_keypair();
+            let mut wrapper =
+                Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit: DenominatedAmount::native(
+                            100.into(),
+                        ),
+                        token: shell.state.in_mem().native_token.clone(),
+                    },
+                    keypair.ref_to(),
+                    GAS_LIMIT_MULTIPLIER.into(),
+                ))));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper.set_code(Code::new(vec![0; size as usize], None));
+            wrapper
+                .set_data(Data::new("transaction data".as_bytes().to_owned()));
+            wrapper.add_section(Section::Authorization(Authorization::new(
+                wrapper.sechashes(),
+                [(0, keypair)].into_iter().collect(),
+                None,
+            )));
+            wrapper
+        };
+
+        // code of the max size is accepted
+        let result = shell.mempool_validate(
+            new_tx(MAX_CODE_BYTES).to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert!(result.code != ResultCode::CodeTooLarge.into());
+
+        // max code bytes + 1, on the other hand, is not
+        let result = shell.mempool_validate(
+            new_tx(MAX_CODE_BYTES + 1).to_bytes().as_ref(),
+            MempoolTxType::NewTransaction,
+        );
+        assert_eq!(result.code, ResultCode::CodeTooLarge.into());
+    }
+
+    /// Test that the shell halts once the upgrade height scheduled by
+    /// governance has been committed, and that it writes the upgrade
+    /// marker file to the chain directory.
+    #[test]
+    fn test_upgrade_halt_height() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        assert_eq!(shell.upgrade_halt_height(), None);
+
+        let chain_dir = shell.base_dir.join(shell.chain_id.as_str());
+        std::fs::create_dir_all(&chain_dir).unwrap();
+        let marker_path = chain_dir.join(UPGRADE_MARKER_FILE);
+
+        let upgrade_height = shell.state.in_mem().get_last_block_height() + 2;
+        shell
+            .state
+            .write(
+                &parameters::storage::get_upgrade_height_key(),
+                upgrade_height,
+            )
+            .unwrap();
+
+        shell.finalize_and_commit(None);
+        assert_eq!(shell.upgrade_halt_height(), None);
+        assert!(!marker_path.exists());
+
+        shell.finalize_and_commit(None);
+        assert_eq!(shell.upgrade_halt_height(), Some(upgrade_height));
+        let marker = std::fs::read_to_string(&marker_path).unwrap();
+        assert!(marker.starts_with(&format!("height = {upgrade_height}\n")));
+        // the upgrade height was removed from storage with the halted block
+        assert_eq!(
+            parameters::storage::get_upgrade_height(&shell.state).unwrap(),
+            None
+        );
+    }
+
+    /// Test that a node restarted from the same DB after halting at the
+    /// upgrade height scheduled by governance keeps halting, unless it was
+    /// upgraded to another version.
+    #[test]
+    fn test_upgrade_halt_persisted_on_restart() {
+        use crate::shims::abcipp_shim_types::shim::request::FinalizeBlock;
+
+        let base_dir = tempfile::tempdir().unwrap();
+        let new_shell = || {
+            let (sender, _receiver) = tokio::sync::mpsc::unbounded_channel();
+            Shell::<storage::PersistentDB, Sha256Hasher>::new(
+                config::Ledger::new(
+                    base_dir.path(),
+                    Default::default(),
+                    TendermintMode::Validator,
+                ),
+                test_utils::top_level_directory().join("wasm"),
+                sender,
+                None,
+                None,
+                50 * 1024 * 1024,
+                50 * 1024 * 1024,
+            )
+        };
+        let mut shell = new_shell();
+        shell.state.in_mem_mut().block.height = BlockHeight(1);
+        shell
+            .init_chain(test_utils::init_chain_request(), 1)
+            .expect("Test failed");
+        shell.state.commit_block().expect("Test failed");
+        std::fs::create_dir_all(base_dir.path().join(shell.chain_id.as_str()))
+            .unwrap();
+
+        let upgrade_height = shell.state.in_mem().get_last_block_height() + 1;
+        shell
+            .state
+            .write(
+                &parameters::storage::get_upgrade_height_key(),
+                upgrade_height,
+            )
+            .unwrap();
+        let mut req = FinalizeBlock::default();
+        req.header.time = {
+            #[allow(clippy::disallowed_methods)]
+            DateTimeUtc::now()
+        };
+        shell.finalize_block(req.clone()).expect("Test failed");
+        shell.commit();
+        assert_eq!(shell.upgrade_halt_height(), Some(upgrade_height));
+        let marker_path = shell.upgrade_marker_path();
+        drop(shell);
+
+        // the upgrade height was cleared from storage, but the restarted
+        // node still halts and refuses to finalize blocks
+        let mut shell = new_shell();
+        assert_eq!(
+            shell.state.in_mem().get_last_block_height(),
+            upgrade_height
+        );
+        assert_eq!(
+            parameters::storage::get_upgrade_height(&shell.state).unwrap(),
+            None
+        );
+        assert_eq!(shell.upgrade_halt_height(), Some(upgrade_height));
+        assert!(matches!(
+            shell.finalize_block(req),
+            Err(Error::HaltedForUpgrade(height)) if height == upgrade_height
+        ));
+        drop(shell);
+
+        // the node resumes once the operator removed the marker, even if
+        // the upgraded binary has the same version
+        std::fs::remove_file(&marker_path).unwrap();
+        let shell = new_shell();
+        assert_eq!(shell.upgrade_halt_height(), None);
+    }
+
+    /// Test that the node halts after committing the `halt_at_height` of its
+    /// config.
+    #[test]
+    fn test_halt_at_height() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        std::fs::create_dir_all(shell.base_dir.join(shell.chain_id.as_str()))
+            .unwrap();
+
+        let halt_height = shell.state.in_mem().get_last_block_height() + 1;
+        shell.halt_at_height = Some(halt_height);
+        assert_eq!(shell.upgrade_halt_height(), None);
+        shell.finalize_and_commit(None);
+        assert_eq!(shell.upgrade_halt_height(), Some(halt_height));
+        assert!(shell.upgrade_marker_path().exists());
+        assert!(matches!(
+            shell.finalize_block(Default::default()),
+            Err(Error::HaltedForUpgrade(height)) if height == halt_height
+        ));
+    }
+
+    /// Test that the upgraded node processes blocks again after the
+    /// upgrade height scheduled by governance.
+    #[test]
+    fn test_blocks_processed_after_upgrade_height() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+
+        let upgrade_height = shell.state.in_mem().get_last_block_height() + 1;
+        shell
+            .state
+            .write(
+                &parameters::storage::get_upgrade_height_key(),
+                upgrade_height,
+            )
+            .unwrap();
+        shell.finalize_and_commit(None);
+        assert_eq!(shell.upgrade_halt_height(), Some(upgrade_height));
+
+        // an upgraded node does not halt at the upgrade height anymore
+        shell.upgrade_height_reached = None;
+        assert_eq!(shell.upgrade_halt_height(), None);
+
+        shell.finalize_and_commit(None);
+        assert_eq!(
+            shell.state.in_mem().get_last_block_height(),
+            upgrade_height + 1
+        );
+        assert_eq!(shell.upgrade_halt_height(), None);
+    }
 }
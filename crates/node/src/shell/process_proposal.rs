@@ -2,6 +2,7 @@
 //! and [`RevertProposal`] ABCI++ methods for the Shell
 
 use data_encoding::HEXUPPER;
+use namada::core::collections::HashMap;
 use namada::hash::Hash;
 use namada::ledger::pos::PosQueries;
 use namada::proof_of_stake::storage::find_validator_by_raw_hash;
@@ -25,6 +26,60 @@ pub struct ValidationMeta {
     pub txs_bin: TxBin<BlockSpace>,
 }
 
+/// In-memory cache of the results of the validation of the block proposals
+/// received on top of the last committed block, keyed by the hash of the
+/// proposed block. This avoids validating the txs of a proposal again when
+/// CometBFT re-sends it in a later round of the same height.
+///
+/// The round is not part of the key, because the result only depends on the
+/// last committed state and on the contents of the block, i.e. its txs,
+/// height, time and proposer. The block hash commits to all of these, so two
+/// proposals with the same hash have the same validation result, whichever
+/// round they were proposed in.
+#[derive(Debug, Default)]
+pub struct ProposalCache {
+    /// The height of the last committed block at which the results were
+    /// obtained
+    height: BlockHeight,
+    /// The validation results of the proposals, keyed by block hash
+    results: HashMap<Vec<u8>, (ProcessProposal, Vec<TxResult>)>,
+}
+
+impl ProposalCache {
+    /// Get the cached validation result of the proposal with the given hash,
+    /// if it was processed on top of the last committed block at `height`
+    fn get(
+        &self,
+        height: BlockHeight,
+        hash: &[u8],
+    ) -> Option<(ProcessProposal, Vec<TxResult>)> {
+        if self.height != height {
+            return None;
+        }
+        self.results.get(hash).cloned()
+    }
+
+    /// Cache the validation result of the proposal with the given hash,
+    /// processed on top of the last committed block at `height`
+    fn insert(
+        &mut self,
+        height: BlockHeight,
+        hash: Vec<u8>,
+        result: (ProcessProposal, Vec<TxResult>),
+    ) {
+        if self.height != height {
+            self.clear();
+            self.height = height;
+        }
+        self.results.insert(hash, result);
+    }
+
+    /// Invalidate all the cached results
+    pub fn clear(&mut self) {
+        self.results.clear();
+    }
+}
+
 impl<D, H> From<&WlState<D, H>> for ValidationMeta
 where
     D: 'static + DB + for<'iter> DBIter<'iter>,
@@ -70,6 +125,17 @@ where
             n_txs = req.txs.len(),
             "Received block proposal",
         );
+        let last_height = self.state.in_mem().get_last_block_height();
+        if let Some(result) =
+            self.proposal_cache.borrow().get(last_height, &req.hash)
+        {
+            tracing::info!(
+                height = req.height,
+                hash = ?HEXUPPER.encode(&req.hash),
+                "Block proposal was already processed, reusing its result",
+            );
+            return result;
+        }
         let native_block_proposer_address = {
             let tm_raw_hash_string =
                 tm_raw_hash_to_string(&req.proposer_address);
@@ -107,14 +173,24 @@ where
                 "Found invalid transactions, proposed block will be rejected"
             );
         }
-        (
+        let result = (
             if invalid_txs {
                 ProcessProposal::Reject
             } else {
                 ProcessProposal::Accept
             },
             tx_results,
-        )
+        );
+        // NB: proposals without a hash can't be told apart, so their results
+        // are not cached
+        if !req.hash.is_empty() {
+            self.proposal_cache.borrow_mut().insert(
+                last_height,
+                req.hash.to_vec(),
+                result.clone(),
+            );
+        }
+        result
     }
 
     /// Evaluates the corresponding [`TxResult`] for each tx in the
@@ -263,6 +339,15 @@ where
             Err(tx_result) => return tx_result,
         };
 
+        if !validate_code_bytes(&self.state, tx.max_embedded_code_len())
+            .expect("Failed to get max code bytes param from storage")
+        {
+            return TxResult {
+                code: ResultCode::CodeTooLarge.into(),
+                info: "Tx code too large".into(),
+            };
+        }
+
         if let Err(err) = tx.validate_tx() {
             return TxResult {
                 code: ResultCode::InvalidSig.into(),
@@ -1871,4 +1956,58 @@ mod test_process_proposal {
             assert!(rsp.is_ok());
         }
     }
+
+    /// Test that the results of the validation of a block proposal are cached
+    /// by block hash until the next block is committed
+    #[test]
+    fn test_process_proposal_cache() {
+        use crate::facade::tendermint_proto::google::protobuf::Timestamp;
+        use crate::shims::abcipp_shim_types::shim::response::ProcessProposal as ProcessProposalResponse;
+
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        let proposer_address = HEXUPPER
+            .decode(
+                wallet::defaults::validator_keypair()
+                    .to_public()
+                    .tm_raw_hash()
+                    .as_bytes(),
+            )
+            .unwrap();
+        #[allow(clippy::disallowed_methods)]
+        let time = DateTimeUtc::now();
+        let request =
+            |txs: Vec<Vec<u8>>, hash: Vec<u8>| RequestProcessProposal {
+                txs: txs.into_iter().map(prost::bytes::Bytes::from).collect(),
+                proposer_address: proposer_address.clone().into(),
+                time: Some(Timestamp {
+                    seconds: time.0.timestamp(),
+                    nanos: time.0.timestamp_subsec_nanos() as i32,
+                }),
+                hash: hash.into(),
+                ..Default::default()
+            };
+
+        // A proposal with an undecodable tx is rejected
+        let (response, tx_results) =
+            shell.process_proposal(request(vec![vec![0xff; 8]], vec![1; 32]));
+        assert_eq!(response, ProcessProposalResponse::Reject);
+        assert_eq!(tx_results.len(), 1);
+
+        // The same proposal isn't validated again, so its result is returned
+        // even if its txs don't match anymore
+        let cached = shell.process_proposal(request(vec![], vec![1; 32]));
+        assert_eq!(cached, (response, tx_results));
+
+        // Proposals with another hash are validated
+        let (response, tx_results) =
+            shell.process_proposal(request(vec![], vec![2; 32]));
+        assert_eq!(response, ProcessProposalResponse::Accept);
+        assert!(tx_results.is_empty());
+
+        // The cache is invalidated once a block is committed
+        shell.finalize_and_commit(None);
+        let (response, _) =
+            shell.process_proposal(request(vec![], vec![1; 32]));
+        assert_eq!(response, ProcessProposalResponse::Accept);
+    }
 }
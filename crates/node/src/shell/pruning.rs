@@ -0,0 +1,242 @@
+//! Pruning of the data of old blocks.
+//!
+//! After every commit, the data of the blocks that fell out of the configured
+//! [`PruningMode`] retention is deleted from the DB. The data needed to read
+//! the state and to build Merkle proofs (e.g. for IBC and light clients) at
+//! any height within `storage_read_past_height_limit` is always kept. Once
+//! all the heights of an epoch have been pruned, the Merkle tree stores
+//! written at its start are pruned too.
+//!
+//! The pruning runs in a background thread, off the commit path, on a clone
+//! of the DB handle. The job started after a commit runs while the next block
+//! is processed, and is joined before the next commit, a snapshot restore and
+//! the shutdown of the shell, so that it never writes to the DB concurrently
+//! with them. Each job prunes at most [`MAX_PRUNED_HEIGHTS_PER_COMMIT`] heights
+//! with a single batch of deletions, without reading the pruned data, so a node
+//! that has kept the data of all the blocks so far catches up over many
+//! commits. The disk space is reclaimed by the background compactions of
+//! RocksDB.
+
+use namada::core::storage::{BlockHeight, Epoch, Epochs};
+use namada::state::{DBIter, DbResult, StorageHasher, DB};
+
+use super::Shell;
+use crate::config::PruningMode;
+use crate::storage::PersistentDB;
+
+/// The maximum number of block heights pruned after a single commit. This
+/// spreads the work over many blocks when pruning is enabled on a node that
+/// has kept the data of all the blocks so far.
+const MAX_PRUNED_HEIGHTS_PER_COMMIT: u64 = 100;
+
+impl<D, H> Shell<D, H>
+where
+    D: DB + for<'iter> DBIter<'iter> + Sync + 'static,
+    H: StorageHasher + Sync + 'static,
+{
+    /// Wait for the background pruning job to finish, if any
+    pub(super) fn join_pruning_job(&mut self) {
+        if let Some(job) = self.pruning_job.take() {
+            if job.join().is_err() {
+                tracing::error!(
+                    "The pruning of the data of old blocks panicked"
+                );
+            }
+        }
+    }
+}
+
+impl Shell {
+    /// Start pruning the data of the blocks that are no longer retained in a
+    /// background thread
+    pub fn prune_old_blocks(&mut self) {
+        self.join_pruning_job();
+        let Some(retained_height) = self.pruning_retained_height() else {
+            return;
+        };
+        let db = self.state.db().clone();
+        let pred_epochs = self.state.in_mem().block.pred_epochs.clone();
+        self.pruning_job = Some(std::thread::spawn(move || {
+            match prune_next_blocks(&db, &pred_epochs, retained_height) {
+                Ok(Some((from, to))) => tracing::debug!(
+                    "Pruned the data of the blocks from height {from} to {to}"
+                ),
+                Ok(None) => {}
+                Err(err) => tracing::error!(
+                    "Failed to prune the data of old blocks: {err}"
+                ),
+            }
+        }));
+    }
+
+    /// The oldest block height whose data must be retained, if pruning is
+    /// enabled
+    fn pruning_retained_height(&self) -> Option<BlockHeight> {
+        let in_mem = self.state.in_mem();
+        let last_height = in_mem.get_last_block_height();
+        let pred_epochs = &in_mem.block.pred_epochs;
+        let oldest_retained = match self.pruning {
+            PruningMode::Archive => return None,
+            PruningMode::KeepBlocks(blocks) => {
+                BlockHeight(last_height.0.checked_sub(blocks)?)
+            }
+            PruningMode::KeepEpochs(epochs) => pred_epochs
+                .get_start_height_of_epoch(Epoch(
+                    in_mem.last_epoch.0.checked_sub(epochs)?,
+                ))?,
+        };
+        // Never prune the heights at which the state can still be read
+        let oldest_readable = BlockHeight(
+            last_height
+                .0
+                .checked_sub(self.storage_read_past_height_limit?)?,
+        );
+        let oldest_retained = std::cmp::min(oldest_retained, oldest_readable);
+        // The Merkle tree at a retained height is rebuilt from the stores
+        // written at the start of its epoch and the diffs since then
+        let epoch = pred_epochs.get_epoch(oldest_retained)?;
+        pred_epochs.get_start_height_of_epoch(epoch)
+    }
+}
+
+/// Prune the data of the next at most [`MAX_PRUNED_HEIGHTS_PER_COMMIT`]
+/// heights below `retained_height` that haven't been pruned yet. Returns the
+/// range of the pruned heights, if any.
+fn prune_next_blocks(
+    db: &PersistentDB,
+    pred_epochs: &Epochs,
+    retained_height: BlockHeight,
+) -> DbResult<Option<(BlockHeight, BlockHeight)>> {
+    let Some(last_prunable) = retained_height.prev_height() else {
+        return Ok(None);
+    };
+    let from = db
+        .read_pruned_height()?
+        .map(|height| height.next_height())
+        .unwrap_or_else(BlockHeight::first);
+    if from > last_prunable {
+        return Ok(None);
+    }
+    let to = std::cmp::min(
+        last_prunable,
+        BlockHeight(from.0.saturating_add(MAX_PRUNED_HEIGHTS_PER_COMMIT - 1)),
+    );
+    db.prune_blocks(from, to, pruned_epochs(pred_epochs, from, to))?;
+    Ok(Some((from, to)))
+}
+
+/// The epochs whose heights are all pruned once the heights from `from` to
+/// `to` are, but weren't before, i.e. the epochs starting from the one of
+/// `from`, up to the one before the epoch of the first retained height.
+fn pruned_epochs(
+    pred_epochs: &Epochs,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> Vec<Epoch> {
+    match (
+        pred_epochs.get_epoch(from),
+        pred_epochs.get_epoch(to.next_height()),
+    ) {
+        (Some(first), Some(retained)) => {
+            (first.0..retained.0).map(Epoch).collect()
+        }
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod test_pruning {
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::storage::open;
+
+    /// Test that the pruning catches up over several commits when more than
+    /// [`MAX_PRUNED_HEIGHTS_PER_COMMIT`] heights are pending
+    #[test]
+    fn test_pruning_catches_up() {
+        let dir = tempdir().unwrap();
+        let db = open(dir.path(), None).unwrap();
+        let mut pred_epochs = Epochs::default();
+        for height in [1_u64, 101, 201] {
+            pred_epochs.new_epoch(BlockHeight(height));
+        }
+        let retained_height = BlockHeight(251);
+
+        let mut pruned = vec![];
+        while let Some(range) =
+            prune_next_blocks(&db, &pred_epochs, retained_height).unwrap()
+        {
+            pruned.push(range);
+        }
+        assert_eq!(
+            pruned,
+            vec![
+                (BlockHeight(1), BlockHeight(100)),
+                (BlockHeight(101), BlockHeight(200)),
+                (BlockHeight(201), BlockHeight(250)),
+            ]
+        );
+        assert_eq!(db.read_pruned_height().unwrap(), Some(BlockHeight(250)));
+
+        // Only the newly unretained heights are pruned after the next commit
+        assert_eq!(
+            prune_next_blocks(&db, &pred_epochs, BlockHeight(253)).unwrap(),
+            Some((BlockHeight(251), BlockHeight(252)))
+        );
+    }
+
+    /// Test that the background pruning job is joined before a commit and
+    /// when the shell is dropped
+    #[test]
+    fn test_pruning_job_joined() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let (mut shell, _recv, _, _) = crate::shell::test_utils::setup();
+        let spawn_job = || {
+            let done = Arc::new(AtomicBool::new(false));
+            let job = {
+                let done = done.clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                    done.store(true, Ordering::SeqCst);
+                })
+            };
+            (done, job)
+        };
+
+        let (done, job) = spawn_job();
+        shell.pruning_job = Some(job);
+        shell.commit();
+        assert!(done.load(Ordering::SeqCst));
+        assert!(shell.pruning_job.is_none());
+
+        let (done, job) = spawn_job();
+        shell.pruning_job = Some(job);
+        drop(shell);
+        assert!(done.load(Ordering::SeqCst));
+    }
+
+    /// Test that the Merkle tree stores of an epoch are only pruned once all
+    /// of its heights are
+    #[test]
+    fn test_pruned_epochs() {
+        let mut pred_epochs = Epochs::default();
+        for height in [1_u64, 11, 21, 31] {
+            pred_epochs.new_epoch(BlockHeight(height));
+        }
+        let pruned = |from: u64, to: u64| {
+            pruned_epochs(&pred_epochs, BlockHeight(from), BlockHeight(to))
+        };
+        assert_eq!(pruned(1, 5), vec![]);
+        assert_eq!(pruned(6, 10), vec![Epoch(0)]);
+        assert_eq!(pruned(11, 25), vec![Epoch(1)]);
+        assert_eq!(pruned(26, 30), vec![Epoch(2)]);
+        assert_eq!(pruned(1, 30), vec![Epoch(0), Epoch(1), Epoch(2)]);
+        assert_eq!(
+            pruned_epochs(&Epochs::default(), BlockHeight(1), BlockHeight(5)),
+            vec![]
+        );
+    }
+}
@@ -1,7 +1,10 @@
 //! Shell methods for querying state
 
+use namada::core::hash::Hash;
 use namada::ledger::dry_run_tx;
-use namada::ledger::queries::{RequestCtx, ResponseQuery, RPC};
+use namada::ledger::queries::{
+    EthOracleStatus, NodeMode, NodeStatus, RequestCtx, ResponseQuery, RPC,
+};
 
 use super::*;
 
@@ -26,6 +29,12 @@ where
         // Invoke the root RPC handler - returns borsh-encoded data on success
         let result = if query.path == RPC.shell().dry_run_tx_path() {
             dry_run_tx(ctx, &query)
+        } else if query.path == RPC.shell().node_status_path() {
+            Ok(ResponseQuery {
+                data: self.node_status().serialize_to_vec(),
+                height: self.state.in_mem().get_last_block_height(),
+                ..Default::default()
+            })
         } else {
             namada::ledger::queries::handle_path(ctx, &query)
         };
@@ -62,6 +71,40 @@ where
         token::read_balance(&self.state, token, owner)
             .expect("Token balance read in the protocol must not fail")
     }
+
+    /// Collect the status of this node. Unlike other queries, this
+    /// includes node-local data which is not part of the chain's state.
+    pub fn node_status(&self) -> NodeStatus {
+        let in_mem = self.state.in_mem();
+        let (mode, eth_oracle) = match &self.mode {
+            ShellMode::Validator {
+                data, eth_oracle, ..
+            } => (
+                NodeMode::Validator(data.address.clone()),
+                eth_oracle.as_ref().map(|channels| EthOracleStatus {
+                    degraded: *channels.status_receiver.borrow()
+                        == oracle::status::Status::Degraded,
+                    last_processed_block: channels
+                        .last_processed_block_receiver
+                        .borrow()
+                        .clone(),
+                    queued_events: channels.ethereum_receiver.queue.len()
+                        as u64,
+                }),
+            ),
+            ShellMode::Full => (NodeMode::Full, None),
+            ShellMode::Seed => (NodeMode::Seed, None),
+        };
+        NodeStatus {
+            chain_id: in_mem.chain_id.clone(),
+            last_block: in_mem.last_block.clone(),
+            app_hash: in_mem.get_state().map(|(root, _)| Hash(root.0)),
+            epoch: in_mem.last_epoch,
+            mode,
+            eth_oracle,
+            mempool_txs: self.mempool_txs.borrow().len() as u64,
+        }
+    }
 }
 
 // NOTE: we are testing `namada::ledger::queries_ext`,
@@ -172,6 +215,33 @@ mod test_queries {
         };
     }
 
+    /// Test that the status of a validator node reports its address
+    /// and the status of its Ethereum oracle.
+    #[test]
+    fn test_validator_node_status() {
+        let (shell, _recv, _, _oracle_control_recv) =
+            test_utils::setup_at_height(3u64);
+
+        let status = shell.node_status();
+        assert_eq!(status.chain_id, shell.state.in_mem().chain_id);
+        assert_eq!(status.epoch, shell.state.in_mem().last_epoch);
+        assert_eq!(
+            status.mode,
+            NodeMode::Validator(
+                shell.mode.get_validator_address().unwrap().clone()
+            )
+        );
+        assert_eq!(
+            status.eth_oracle,
+            Some(EthOracleStatus {
+                degraded: false,
+                last_processed_block: None,
+                queued_events: 0,
+            })
+        );
+        assert_eq!(status.mempool_txs, 0);
+    }
+
     const fn send_valset(value: bool) -> bool {
         if !is_bridge_comptime_enabled() {
             false
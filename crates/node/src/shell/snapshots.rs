@@ -0,0 +1,737 @@
+//! ABCI state sync snapshots, backed by RocksDB checkpoints.
+//!
+//! Every `snapshot_interval` blocks, a checkpoint of the DB is taken right
+//! after the block is committed. The checkpoint is then dumped in a
+//! background thread into chunks of borsh-encoded [`SnapshotEntry`]s, stored
+//! in `{snapshots_dir}/{height}/chunk-{index}`, together with the
+//! [`SnapshotMetadata`] holding the hashes of all the chunks.
+//!
+//! A new node restores a snapshot by writing the entries of every chunk into
+//! its empty DB and finally loading the state of the last committed block.
+//! A marker file is kept in the snapshots directory while a restore is in
+//! progress. If the restored state doesn't match the snapshot, its entries
+//! are removed from the DB, and if the node stops before the restore is
+//! complete, they are removed on its next start.
+
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
+use namada::core::hash::Hash;
+use namada::core::storage::BlockHeight;
+use namada::state::{DbError, InMemory};
+use namada_sdk::tendermint::AppHash;
+
+use super::Shell;
+use crate::config;
+use crate::facade::tendermint::abci::response::ApplySnapshotChunkResult;
+use crate::facade::tendermint::abci::types::Snapshot;
+use crate::facade::tendermint::block;
+use crate::facade::tendermint::v0_37::abci::{request, response};
+use crate::storage::{self, SnapshotEntry};
+
+/// The format of the snapshots produced by this module. It must be bumped
+/// whenever the chunks change, e.g. when a column family is added to
+/// [`storage::SNAPSHOT_CFS`], as the snapshots of another format can't be
+/// restored. Format 2 added the Merkle tree column family.
+pub const SNAPSHOT_FORMAT: u32 = 2;
+/// The number of snapshots to keep, if not configured
+pub const DEFAULT_SNAPSHOTS_TO_KEEP: u64 = 2;
+/// The approximate size of a snapshot chunk in bytes
+const CHUNK_SIZE: usize = 10 * 1024 * 1024;
+/// The name of the file with the metadata of a snapshot
+const METADATA_FILE: &str = "metadata";
+/// The name of the file marking a snapshot restore in progress
+const RESTORE_MARKER_FILE: &str = "restoring";
+
+/// The metadata of a snapshot, sent to peers in [`Snapshot::metadata`]
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SnapshotMetadata {
+    /// The hashes of the snapshot's chunks, in order
+    pub chunk_hashes: Vec<Hash>,
+    /// The format of the snapshot's chunks
+    pub format: u32,
+}
+
+impl SnapshotMetadata {
+    /// The hash of the whole snapshot, committing to all of its chunks
+    pub fn snapshot_hash(&self) -> Hash {
+        Hash::sha256(self.chunk_hashes.serialize_to_vec())
+    }
+}
+
+/// The snapshots of this node and the state of an ongoing restore
+#[derive(Debug)]
+pub struct Snapshots {
+    /// The directory where the snapshots are stored
+    dir: PathBuf,
+    /// Take a snapshot every given number of blocks, if set
+    interval: Option<u64>,
+    /// The number of most recent snapshots to keep
+    to_keep: u64,
+    /// The snapshot that is being restored, if any
+    restoring: Option<RestoringSnapshot>,
+}
+
+/// A snapshot accepted from a peer that is being restored
+#[derive(Debug)]
+struct RestoringSnapshot {
+    /// The height of the snapshot
+    height: BlockHeight,
+    /// The expected app hash once the snapshot is restored
+    app_hash: AppHash,
+    /// The hashes of the snapshot's chunks
+    chunk_hashes: Vec<Hash>,
+    /// The index of the next chunk to apply
+    next_chunk: u32,
+}
+
+impl Snapshots {
+    /// Initialize the snapshots from the node's configuration
+    pub fn new(
+        dir: PathBuf,
+        interval: Option<u64>,
+        to_keep: Option<u64>,
+    ) -> Self {
+        Self {
+            dir,
+            interval: interval.filter(|interval| *interval > 0),
+            to_keep: to_keep.unwrap_or(DEFAULT_SNAPSHOTS_TO_KEEP),
+            restoring: None,
+        }
+    }
+
+    /// Check if a snapshot should be taken at the given height
+    fn is_due(&self, height: BlockHeight) -> bool {
+        self.interval
+            .map(|interval| height.0 % interval == 0)
+            .unwrap_or_default()
+    }
+
+    /// The directory of the snapshot at the given height
+    fn snapshot_dir(&self, height: BlockHeight) -> PathBuf {
+        self.dir.join(height.to_string())
+    }
+
+    /// The path of the file marking a snapshot restore in progress
+    fn restore_marker(&self) -> PathBuf {
+        self.dir.join(RESTORE_MARKER_FILE)
+    }
+
+    /// Read the metadata of all the complete snapshots, sorted by height.
+    /// The snapshots of format 1 had no format in their metadata, which
+    /// therefore doesn't decode.
+    fn read_all(&self) -> Vec<(BlockHeight, SnapshotMetadata)> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return vec![];
+        };
+        let mut snapshots: Vec<_> = entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let height = entry.file_name().to_str()?.parse().ok()?;
+                let metadata =
+                    fs::read(entry.path().join(METADATA_FILE)).ok()?;
+                let metadata =
+                    SnapshotMetadata::try_from_slice(&metadata).ok()?;
+                Some((BlockHeight(height), metadata))
+            })
+            .collect();
+        snapshots.sort_by_key(|(height, _)| *height);
+        snapshots
+    }
+}
+
+impl Shell {
+    /// Take a snapshot of the DB if one is due at the last committed height.
+    /// The checkpoint is created synchronously, while its chunks are written
+    /// in a background thread.
+    pub fn take_snapshot_if_due(&mut self) {
+        let height = self.state.in_mem().get_last_block_height();
+        if !self.snapshots.is_due(height) {
+            return;
+        }
+        let snapshots_dir = self.snapshots.dir.clone();
+        let checkpoint_dir = snapshots_dir.join(format!("{height}.checkpoint"));
+        if let Err(err) = fs::create_dir_all(&snapshots_dir) {
+            tracing::error!(
+                "Failed to create the snapshots directory {}: {err}",
+                snapshots_dir.to_string_lossy()
+            );
+            return;
+        }
+        if let Err(err) = self.state.db().checkpoint(&checkpoint_dir) {
+            tracing::error!(
+                "Failed to create a DB checkpoint at height {height}: {err}"
+            );
+            return;
+        }
+        let to_keep = self.snapshots.to_keep;
+        std::thread::spawn(move || {
+            match write_snapshot(&snapshots_dir, &checkpoint_dir, height) {
+                Ok(chunks) => {
+                    tracing::info!(
+                        "Created a state sync snapshot at height {height} \
+                         with {chunks} chunks"
+                    );
+                    prune_snapshots(&snapshots_dir, to_keep);
+                }
+                Err(err) => tracing::error!(
+                    "Failed to create a state sync snapshot at height \
+                     {height}: {err}"
+                ),
+            }
+            if let Err(err) = fs::remove_dir_all(&checkpoint_dir) {
+                tracing::warn!(
+                    "Failed to remove the DB checkpoint {}: {err}",
+                    checkpoint_dir.to_string_lossy()
+                );
+            }
+        });
+    }
+
+    /// List the snapshots available for peers to sync from
+    pub fn list_snapshots(&self) -> response::ListSnapshots {
+        let snapshots = self
+            .snapshots
+            .read_all()
+            .into_iter()
+            .filter(|(_, metadata)| metadata.format == SNAPSHOT_FORMAT)
+            .filter_map(|(height, metadata)| {
+                Some(Snapshot {
+                    height: block::Height::try_from(height.0).ok()?,
+                    format: metadata.format,
+                    chunks: u32::try_from(metadata.chunk_hashes.len()).ok()?,
+                    hash: metadata.snapshot_hash().0.to_vec().into(),
+                    metadata: metadata.serialize_to_vec().into(),
+                })
+            })
+            .collect();
+        response::ListSnapshots { snapshots }
+    }
+
+    /// Decide whether to restore the state from a snapshot offered by a peer
+    pub fn offer_snapshot(
+        &mut self,
+        req: request::OfferSnapshot,
+    ) -> response::OfferSnapshot {
+        let request::OfferSnapshot { snapshot, app_hash } = req;
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return response::OfferSnapshot::RejectFormat;
+        }
+        if self.state.in_mem().last_block.is_some() {
+            tracing::warn!(
+                "Rejecting a state sync snapshot, as the node already has \
+                 some state"
+            );
+            return response::OfferSnapshot::Reject;
+        }
+        let Ok(metadata) = SnapshotMetadata::try_from_slice(&snapshot.metadata)
+        else {
+            return response::OfferSnapshot::Reject;
+        };
+        if metadata.format != snapshot.format {
+            return response::OfferSnapshot::RejectFormat;
+        }
+        if u32::try_from(metadata.chunk_hashes.len()) != Ok(snapshot.chunks)
+            || metadata.snapshot_hash().0.as_slice() != snapshot.hash.as_ref()
+        {
+            return response::OfferSnapshot::Reject;
+        }
+        if let Err(err) = self.start_restore() {
+            tracing::error!(
+                "Failed to prepare the DB to restore a state sync snapshot: \
+                 {err}"
+            );
+            return response::OfferSnapshot::Reject;
+        }
+        let height = BlockHeight(snapshot.height.value());
+        tracing::info!(
+            "Accepted a state sync snapshot at height {height} with {} chunks",
+            snapshot.chunks
+        );
+        self.snapshots.restoring = Some(RestoringSnapshot {
+            height,
+            app_hash,
+            chunk_hashes: metadata.chunk_hashes,
+            next_chunk: 0,
+        });
+        response::OfferSnapshot::Accept
+    }
+
+    /// Load a chunk of one of our snapshots requested by a peer
+    pub fn load_snapshot_chunk(
+        &self,
+        req: request::LoadSnapshotChunk,
+    ) -> response::LoadSnapshotChunk {
+        let chunk = if req.format == SNAPSHOT_FORMAT {
+            let height = BlockHeight(req.height.value());
+            let path = self
+                .snapshots
+                .snapshot_dir(height)
+                .join(chunk_file_name(req.chunk));
+            fs::read(path).unwrap_or_else(|err| {
+                tracing::warn!(
+                    "Failed to load chunk {} of the snapshot at height \
+                     {height}: {err}",
+                    req.chunk
+                );
+                vec![]
+            })
+        } else {
+            vec![]
+        };
+        response::LoadSnapshotChunk {
+            chunk: chunk.into(),
+        }
+    }
+
+    /// Apply a chunk of the snapshot that is being restored
+    pub fn apply_snapshot_chunk(
+        &mut self,
+        req: request::ApplySnapshotChunk,
+    ) -> response::ApplySnapshotChunk {
+        let request::ApplySnapshotChunk {
+            index,
+            chunk,
+            sender,
+        } = req;
+        let result = |result| response::ApplySnapshotChunk {
+            result,
+            refetch_chunks: vec![],
+            reject_senders: vec![],
+        };
+        self.join_pruning_job();
+        let Some(restoring) = self.snapshots.restoring.as_mut() else {
+            return result(ApplySnapshotChunkResult::Abort);
+        };
+        if index != restoring.next_chunk {
+            return response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![restoring.next_chunk],
+                reject_senders: vec![],
+            };
+        }
+        let entries = usize::try_from(index)
+            .ok()
+            .and_then(|index| restoring.chunk_hashes.get(index))
+            .filter(|hash| **hash == Hash::sha256(&chunk))
+            .and_then(|_| Vec::<SnapshotEntry>::try_from_slice(&chunk).ok());
+        let Some(entries) = entries else {
+            tracing::warn!(
+                "Received an invalid snapshot chunk {index} from {sender}"
+            );
+            return response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![index],
+                reject_senders: vec![sender],
+            };
+        };
+        if let Err(err) = self.state.db().restore_snapshot_chunk(entries) {
+            tracing::error!("Failed to apply snapshot chunk {index}: {err}");
+            self.abort_restore();
+            return result(ApplySnapshotChunkResult::Abort);
+        }
+        restoring.next_chunk = index.saturating_add(1);
+        if usize::try_from(restoring.next_chunk).ok()
+            != Some(restoring.chunk_hashes.len())
+        {
+            return result(ApplySnapshotChunkResult::Accept);
+        }
+
+        // All the chunks have been applied, load the restored state
+        let RestoringSnapshot {
+            height, app_hash, ..
+        } = self
+            .snapshots
+            .restoring
+            .take()
+            .expect("The restored snapshot must be set");
+        self.state.load_last_state();
+        let last_height = self.state.in_mem().get_last_block_height();
+        let merkle_root = self.state.in_mem().merkle_root();
+        if last_height != height
+            || merkle_root.0.as_slice() != app_hash.as_bytes()
+        {
+            tracing::error!(
+                "The state restored from the snapshot at height {height} \
+                 doesn't match, got height {last_height} and app hash \
+                 {merkle_root}"
+            );
+            self.abort_restore();
+            return result(ApplySnapshotChunkResult::Abort);
+        }
+        remove_restore_marker(&self.snapshots.restore_marker());
+        self.update_wasm_gas_costs();
+        tracing::info!(
+            "Restored the state from the snapshot at height {height}"
+        );
+        result(ApplySnapshotChunkResult::Accept)
+    }
+
+    /// Mark a snapshot restore as in progress and remove the entries left
+    /// in the DB by a previous restore, if any.
+    fn start_restore(&mut self) -> io::Result<()> {
+        self.join_pruning_job();
+        fs::create_dir_all(&self.snapshots.dir)?;
+        fs::write(self.snapshots.restore_marker(), [])?;
+        self.state.db().clear_snapshot_cfs().map_err(|err| {
+            io::Error::new(io::ErrorKind::Other, err.to_string())
+        })
+    }
+
+    /// Discard a partially restored snapshot, leaving the node without any
+    /// state, as before the snapshot was offered.
+    fn abort_restore(&mut self) {
+        self.join_pruning_job();
+        self.snapshots.restoring = None;
+        let in_mem = self.state.in_mem_mut();
+        let mut merkle_tree_cache =
+            std::mem::take(&mut in_mem.merkle_tree_cache);
+        merkle_tree_cache.clear();
+        *in_mem = InMemory {
+            merkle_tree_cache,
+            ..InMemory::new(
+                in_mem.chain_id.clone(),
+                in_mem.native_token.clone(),
+                in_mem.storage_read_past_height_limit,
+            )
+        };
+        match self.state.db().clear_snapshot_cfs() {
+            Ok(()) => remove_restore_marker(&self.snapshots.restore_marker()),
+            // The marker is kept to retry on the next start of the node
+            Err(err) => tracing::error!(
+                "Failed to discard a partially restored snapshot: {err}"
+            ),
+        }
+    }
+}
+
+/// Remove the entries of a snapshot restore that was interrupted by a stop
+/// of the node from its DB. Must be called before the DB is opened by the
+/// shell.
+pub fn discard_interrupted_snapshot_restore(config: &config::Ledger) {
+    let marker = config
+        .shell
+        .snapshots_dir(&config.chain_id)
+        .join(RESTORE_MARKER_FILE);
+    if !marker.exists() {
+        return;
+    }
+    tracing::warn!("Discarding a partially restored state sync snapshot");
+    storage::open(config.shell.db_dir(&config.chain_id), None)
+        .and_then(|db| db.clear_snapshot_cfs())
+        .expect("Must be able to discard a partially restored snapshot");
+    remove_restore_marker(&marker);
+}
+
+/// Remove the file marking a snapshot restore in progress
+fn remove_restore_marker(marker: &Path) {
+    if let Err(err) = fs::remove_file(marker) {
+        tracing::warn!(
+            "Failed to remove the snapshot restore marker {}: {err}",
+            marker.to_string_lossy()
+        );
+    }
+}
+
+/// The name of the file with the chunk at the given index
+fn chunk_file_name(index: u32) -> String {
+    format!("chunk-{index}")
+}
+
+/// Write the chunks and metadata of a snapshot of the given DB checkpoint.
+/// The snapshot is written into a temporary directory that is renamed once
+/// complete, so that partial snapshots are never offered to peers. Returns
+/// the number of chunks.
+fn write_snapshot(
+    snapshots_dir: &Path,
+    checkpoint_dir: &Path,
+    height: BlockHeight,
+) -> io::Result<usize> {
+    let to_io_err = |err| io::Error::new(io::ErrorKind::Other, err);
+    let tmp_dir = snapshots_dir.join(format!("{height}.tmp"));
+    fs::create_dir_all(&tmp_dir)?;
+
+    let checkpoint = storage::open(checkpoint_dir, None)
+        .map_err(|err| to_io_err(err.to_string()))?;
+    let mut chunk_hashes = vec![];
+    checkpoint
+        .for_each_snapshot_chunk(CHUNK_SIZE, |entries| {
+            let index = u32::try_from(chunk_hashes.len())
+                .map_err(DbError::NumConversionError)?;
+            let bytes = entries.serialize_to_vec();
+            fs::write(tmp_dir.join(chunk_file_name(index)), &bytes)
+                .map_err(|err| DbError::DBError(err.to_string()))?;
+            chunk_hashes.push(Hash::sha256(&bytes));
+            Ok(())
+        })
+        .map_err(|err| to_io_err(err.to_string()))?;
+    drop(checkpoint);
+
+    let chunks = chunk_hashes.len();
+    let metadata = SnapshotMetadata {
+        chunk_hashes,
+        format: SNAPSHOT_FORMAT,
+    };
+    fs::write(tmp_dir.join(METADATA_FILE), metadata.serialize_to_vec())?;
+    fs::rename(tmp_dir, snapshots_dir.join(height.to_string()))?;
+    Ok(chunks)
+}
+
+/// Remove all but the `to_keep` most recent snapshots
+fn prune_snapshots(snapshots_dir: &Path, to_keep: u64) {
+    let Ok(entries) = fs::read_dir(snapshots_dir) else {
+        return;
+    };
+    let mut heights: Vec<u64> = entries
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect();
+    heights.sort_unstable();
+    let to_remove = heights
+        .len()
+        .saturating_sub(usize::try_from(to_keep).unwrap_or(usize::MAX));
+    for height in heights.into_iter().take(to_remove) {
+        let dir = snapshots_dir.join(height.to_string());
+        if let Err(err) = fs::remove_dir_all(&dir) {
+            tracing::warn!(
+                "Failed to remove the snapshot {}: {err}",
+                dir.to_string_lossy()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_snapshots {
+    use namada::state::DB;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::config::TendermintMode;
+    use crate::shell::test_utils::{init_chain_request, top_level_directory};
+
+    /// Create a shell backed by RocksDB in the given directory
+    fn new_shell(base_dir: &Path) -> Shell {
+        let (sender, _receiver) = tokio::sync::mpsc::unbounded_channel();
+        Shell::new(
+            config::Ledger::new(
+                base_dir,
+                Default::default(),
+                TendermintMode::Validator,
+            ),
+            top_level_directory().join("wasm"),
+            sender,
+            None,
+            None,
+            50 * 1024 * 1024,
+            50 * 1024 * 1024,
+        )
+    }
+
+    /// Test that a snapshot offered by a peer is restored if the restored
+    /// state matches the app hash, and that the restored entries are
+    /// discarded otherwise.
+    #[test]
+    fn test_offer_and_apply_snapshot() {
+        let source_dir = tempdir().unwrap();
+        let mut source = new_shell(source_dir.path());
+        source.state.in_mem_mut().block.height = BlockHeight(1);
+        source
+            .init_chain(init_chain_request(), 1)
+            .expect("Test failed");
+        source.state.commit_block().expect("Test failed");
+        let height = source.state.in_mem().get_last_block_height();
+        let app_hash =
+            AppHash::try_from(source.state.in_mem().merkle_root().0.to_vec())
+                .unwrap();
+
+        let checkpoint_dir = source_dir.path().join("checkpoint");
+        fs::create_dir_all(&source.snapshots.dir).unwrap();
+        source.state.db().checkpoint(&checkpoint_dir).unwrap();
+        write_snapshot(&source.snapshots.dir, &checkpoint_dir, height).unwrap();
+        let snapshot = source.list_snapshots().snapshots.pop().unwrap();
+        assert_eq!(snapshot.height.value(), height.0);
+
+        let target_dir = tempdir().unwrap();
+        let mut target = new_shell(target_dir.path());
+        let restore = |target: &mut Shell, app_hash: AppHash| {
+            let offered = target.offer_snapshot(request::OfferSnapshot {
+                snapshot: snapshot.clone(),
+                app_hash,
+            });
+            assert_eq!(offered, response::OfferSnapshot::Accept);
+            assert!(target.snapshots.restore_marker().exists());
+            let mut result = ApplySnapshotChunkResult::Unknown;
+            for index in 0..snapshot.chunks {
+                let chunk = source
+                    .load_snapshot_chunk(request::LoadSnapshotChunk {
+                        height: snapshot.height,
+                        format: SNAPSHOT_FORMAT,
+                        chunk: index,
+                    })
+                    .chunk;
+                result = target
+                    .apply_snapshot_chunk(request::ApplySnapshotChunk {
+                        index,
+                        chunk,
+                        sender: "peer".to_string(),
+                    })
+                    .result;
+            }
+            result
+        };
+
+        // A snapshot that doesn't match the app hash is discarded
+        let wrong_app_hash = AppHash::try_from(vec![0_u8; 32]).unwrap();
+        assert_eq!(
+            restore(&mut target, wrong_app_hash),
+            ApplySnapshotChunkResult::Abort
+        );
+        assert!(target.state.in_mem().last_block.is_none());
+        assert!(target.state.db().read_last_block().unwrap().is_none());
+        assert!(!target.snapshots.restore_marker().exists());
+
+        // The node can then restore a matching snapshot
+        assert_eq!(
+            restore(&mut target, app_hash),
+            ApplySnapshotChunkResult::Accept
+        );
+        assert_eq!(target.state.in_mem().get_last_block_height(), height);
+        assert_eq!(
+            target.state.in_mem().merkle_root(),
+            source.state.in_mem().merkle_root()
+        );
+        assert!(!target.snapshots.restore_marker().exists());
+    }
+
+    /// Test that the snapshots of another format are neither offered to
+    /// peers nor restored
+    #[test]
+    fn test_snapshot_format() {
+        let dir = tempdir().unwrap();
+        let mut shell = new_shell(dir.path());
+        let chunk_hashes = vec![Hash::sha256(b"chunk")];
+
+        // The snapshots of format 1 had no format in their metadata
+        for (height, metadata) in [
+            (1_u64, chunk_hashes.serialize_to_vec()),
+            (
+                2,
+                SnapshotMetadata {
+                    chunk_hashes: chunk_hashes.clone(),
+                    format: SNAPSHOT_FORMAT,
+                }
+                .serialize_to_vec(),
+            ),
+        ] {
+            let snapshot_dir =
+                shell.snapshots.snapshot_dir(BlockHeight(height));
+            fs::create_dir_all(&snapshot_dir).unwrap();
+            fs::write(snapshot_dir.join(METADATA_FILE), metadata).unwrap();
+        }
+        let snapshots = shell.list_snapshots().snapshots;
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].height.value(), 2);
+        assert_eq!(snapshots[0].format, SNAPSHOT_FORMAT);
+
+        let app_hash = AppHash::try_from(vec![0_u8; 32]).unwrap();
+        let mut offer = |format: u32, metadata: &SnapshotMetadata| {
+            let mut snapshot = snapshots[0].clone();
+            snapshot.format = format;
+            snapshot.metadata = metadata.serialize_to_vec().into();
+            shell.offer_snapshot(request::OfferSnapshot {
+                snapshot,
+                app_hash: app_hash.clone(),
+            })
+        };
+        let old = SnapshotMetadata {
+            chunk_hashes: chunk_hashes.clone(),
+            format: 1,
+        };
+        assert_eq!(offer(1, &old), response::OfferSnapshot::RejectFormat);
+        // The format of the metadata must match the offered one
+        assert_eq!(
+            offer(SNAPSHOT_FORMAT, &old),
+            response::OfferSnapshot::RejectFormat
+        );
+        assert!(shell.snapshots.restoring.is_none());
+    }
+
+    /// Test that the entries of an interrupted restore are discarded on the
+    /// next start of the node
+    #[test]
+    fn test_discard_interrupted_snapshot_restore() {
+        let base_dir = tempdir().unwrap();
+        let config = config::Ledger::new(
+            base_dir.path(),
+            Default::default(),
+            TendermintMode::Full,
+        );
+        let db_dir = config.shell.db_dir(&config.chain_id);
+        let snapshots_dir = config.shell.snapshots_dir(&config.chain_id);
+        let count_entries = || {
+            let db = storage::open(&db_dir, None).unwrap();
+            let mut entries = 0;
+            db.for_each_snapshot_chunk(1, |chunk| {
+                entries += chunk.len();
+                Ok(())
+            })
+            .unwrap();
+            entries
+        };
+        storage::open(&db_dir, None)
+            .unwrap()
+            .restore_snapshot_chunk(vec![SnapshotEntry {
+                cf: "subspace".to_string(),
+                key: b"key".to_vec(),
+                value: b"value".to_vec(),
+            }])
+            .unwrap();
+
+        // Without the marker, the DB is left alone
+        discard_interrupted_snapshot_restore(&config);
+        assert_eq!(count_entries(), 1);
+
+        fs::create_dir_all(&snapshots_dir).unwrap();
+        fs::write(snapshots_dir.join(RESTORE_MARKER_FILE), []).unwrap();
+        discard_interrupted_snapshot_restore(&config);
+        assert!(!snapshots_dir.join(RESTORE_MARKER_FILE).exists());
+        assert_eq!(count_entries(), 0);
+    }
+
+    /// Test that only the most recent snapshots are kept
+    #[test]
+    fn test_prune_snapshots() {
+        let dir = tempdir().unwrap();
+        for height in [1_u64, 10, 20, 30] {
+            fs::create_dir(dir.path().join(height.to_string())).unwrap();
+        }
+        // Temporary directories of snapshots in progress are left alone
+        fs::create_dir(dir.path().join("40.tmp")).unwrap();
+
+        prune_snapshots(dir.path(), 2);
+
+        let mut left: Vec<String> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        left.sort();
+        assert_eq!(left, vec!["20", "30", "40.tmp"]);
+    }
+
+    /// Test when snapshots are due
+    #[test]
+    fn test_snapshot_is_due() {
+        let snapshots = Snapshots::new(PathBuf::new(), Some(100), None);
+        assert!(!snapshots.is_due(BlockHeight(1)));
+        assert!(snapshots.is_due(BlockHeight(100)));
+        assert!(snapshots.is_due(BlockHeight(200)));
+        assert_eq!(snapshots.to_keep, DEFAULT_SNAPSHOTS_TO_KEEP);
+
+        let disabled = Snapshots::new(PathBuf::new(), Some(0), None);
+        assert!(!disabled.is_due(BlockHeight(100)));
+        let disabled = Snapshots::new(PathBuf::new(), None, None);
+        assert!(!disabled.is_due(BlockHeight(100)));
+    }
+}
@@ -5,10 +5,12 @@ use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::task::Poll;
 
+use borsh::BorshDeserialize;
 use color_eyre::eyre::{Report, Result};
 use data_encoding::HEXUPPER;
 use itertools::Either;
 use lazy_static::lazy_static;
+use masp_primitives::sapling::ViewingKey;
 use namada::address::Address;
 use namada::control_flow::time::Duration;
 use namada::core::collections::HashMap;
@@ -16,8 +18,8 @@ use namada::core::ethereum_events::EthereumEvent;
 use namada::core::ethereum_structs;
 use namada::core::hash::Hash;
 use namada::core::key::tm_consensus_key_raw_hash;
-use namada::core::storage::{BlockHeight, Epoch, Header};
-use namada::core::time::DateTimeUtc;
+use namada::core::storage::{BlockHeight, Epoch, Header, Key};
+use namada::core::time::{DateTimeUtc, DurationSecs};
 use namada::eth_bridge::oracle::config::Config as OracleConfig;
 use namada::ledger::dry_run_tx;
 use namada::ledger::events::log::dumb_queries;
@@ -37,7 +39,10 @@ use namada::state::{
 };
 use namada::tendermint::abci::response::Info;
 use namada::tendermint::abci::types::VoteInfo;
+use namada::token;
 use namada::tx::event::Code as CodeAttr;
+use namada_apps_lib::cli::args::Global;
+use namada_apps_lib::cli::Context;
 use namada_sdk::queries::Client;
 use namada_sdk::tendermint_proto::google::protobuf::Timestamp;
 use namada_sdk::tx::data::ResultCode;
@@ -48,7 +53,7 @@ use crate::ethereum_oracle::test_tools::mock_web3_client::{
     TestOracle, Web3Client, Web3Controller,
 };
 use crate::ethereum_oracle::{
-    control, last_processed_block, try_process_eth_events,
+    control, last_processed_block, status, try_process_eth_events,
 };
 use crate::facade::tendermint_proto::v0_37::abci::{
     RequestPrepareProposal, RequestProcessProposal,
@@ -57,7 +62,7 @@ use crate::facade::tendermint_rpc::endpoint::block;
 use crate::facade::tendermint_rpc::error::Error as RpcError;
 use crate::facade::tendermint_rpc::SimpleRequest;
 use crate::facade::{tendermint, tendermint_rpc};
-use crate::shell::testing::utils::TestDir;
+use crate::shell::testing::utils::{TestDir, TestingIo};
 use crate::shell::{EthereumOracleChannels, Shell};
 use crate::shims::abcipp_shim_types::shim::request::{
     FinalizeBlock, ProcessedTx,
@@ -87,6 +92,9 @@ impl MockEthOracle {
             &self.oracle,
             &self.config,
             &*self.next_block_to_process.read().await,
+            // process a single block at a time, to advance the
+            // Ethereum height one block per drive
+            1,
         )
         .await
         .process_new_block()
@@ -197,20 +205,25 @@ pub fn mock_services(cfg: MockServicesCfg) -> MockServicesPackage {
     let (eth_sender, eth_receiver) = mpsc::channel(1000);
     let (last_processed_block_sender, last_processed_block_receiver) =
         last_processed_block::channel();
+    let (status_sender, status_receiver) = status::channel();
     let (control_sender, control_receiver) = control::channel();
     let eth_oracle_controller = eth_client.controller();
     let oracle = TestOracle::new(
         Either::Left(eth_client),
         eth_sender.clone(),
         last_processed_block_sender,
+        status_sender,
         Duration::from_millis(5),
         Duration::from_secs(30),
+        Duration::from_secs(120),
+        1,
         control_receiver,
     );
     let eth_oracle_channels = EthereumOracleChannels::new(
         eth_receiver,
         control_sender,
         last_processed_block_receiver,
+        status_receiver,
     );
     let (tx_broadcaster, tx_receiver) = mpsc::unbounded_channel();
     let ethereum_oracle = MockEthOracle {
@@ -257,6 +270,10 @@ pub struct MockNode {
     pub blocks: Arc<Mutex<HashMap<BlockHeight, block::Response>>>,
     pub services: Arc<MockServices>,
     pub auto_drive_services: bool,
+    /// The clock providing the time of the blocks produced by the node. It
+    /// only moves when explicitly advanced, such that epoch changes and tx
+    /// expiration do not depend on wall-clock time.
+    pub clock: Arc<Mutex<DateTimeUtc>>,
 }
 
 impl Debug for MockNode {
@@ -337,6 +354,22 @@ impl MockNode {
         self.shell.lock().unwrap().state.in_mem().last_epoch
     }
 
+    /// The time of the next blocks produced by the node.
+    pub fn block_time(&self) -> DateTimeUtc {
+        *self.clock.lock().unwrap()
+    }
+
+    /// Set the time of the next blocks produced by the node.
+    pub fn set_time(&self, time: DateTimeUtc) {
+        *self.clock.lock().unwrap() = time;
+    }
+
+    /// Move the node's clock forward by the given duration.
+    pub fn advance_time(&self, duration: DurationSecs) {
+        let mut clock = self.clock.lock().unwrap();
+        *clock = *clock + duration;
+    }
+
     pub fn next_epoch(&mut self) -> Epoch {
         {
             let mut locked = self.shell.lock().unwrap();
@@ -345,10 +378,8 @@ impl MockNode {
                 locked.state.in_mem().get_last_block_height() + 1;
             locked.state.in_mem_mut().next_epoch_min_start_height =
                 next_epoch_height;
-            locked.state.in_mem_mut().next_epoch_min_start_time = {
-                #[allow(clippy::disallowed_methods)]
-                DateTimeUtc::now()
-            };
+            locked.state.in_mem_mut().next_epoch_min_start_time =
+                self.block_time();
             let next_epoch_min_start_height =
                 locked.state.in_mem().next_epoch_min_start_height;
             if let Some(LastBlock { height, .. }) =
@@ -376,6 +407,73 @@ impl MockNode {
         locked.state.get_native_token().unwrap()
     }
 
+    /// Read and decode the value stored under `key` in the last committed
+    /// state, if any.
+    pub fn storage_value<T: BorshDeserialize>(&self, key: &Key) -> Option<T> {
+        let locked = self.shell.lock().unwrap();
+        locked.state.read(key).unwrap()
+    }
+
+    /// Read the transparent balance of `owner` in the given `token`.
+    pub fn balance(&self, token: &Address, owner: &Address) -> token::Amount {
+        let locked = self.shell.lock().unwrap();
+        token::read_balance(&locked.state, token, owner).unwrap()
+    }
+
+    /// Compute the shielded balance in the given `token` of the viewing key
+    /// stored under `vk_alias` in the node's wallet. The shielded context
+    /// must have been synced beforehand (e.g. with `shielded-sync`).
+    pub fn shielded_balance(
+        &self,
+        vk_alias: &str,
+        token: &Address,
+    ) -> token::Amount {
+        let global = {
+            let locked = self.shell.lock().unwrap();
+            Global {
+                is_pre_genesis: false,
+                chain_id: Some(locked.chain_id.clone()),
+                base_dir: locked.base_dir.clone(),
+                wasm_dir: Some(locked.wasm_dir.clone()),
+            }
+        };
+        let mut chain_ctx = Context::new::<TestingIo>(global)
+            .expect("Failed to load the client context")
+            .take_chain_or_exit();
+        let vk: ViewingKey = (*chain_ctx
+            .wallet
+            .find_viewing_key(vk_alias)
+            .expect("Viewing key not found in the wallet"))
+        .into();
+        let epoch = self.current_epoch();
+        let shielded = &mut chain_ctx.shielded;
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            shielded
+                .load()
+                .await
+                .expect("Failed to load shielded context");
+            shielded
+                .precompute_asset_types(&self, vec![token])
+                .await
+                .unwrap();
+            let Some(balance) = shielded
+                .compute_exchanged_balance(&self, &TestingIo, &vk, epoch)
+                .await
+                .unwrap()
+            else {
+                return token::Amount::zero();
+            };
+            shielded
+                .decode_combine_sum_to_epoch(&self, balance, epoch)
+                .await
+                .0
+                .get(token)
+                .into()
+        })
+    }
+
     /// Get the address of the block proposer and the votes for the block
     fn prepare_request(&self) -> (Vec<u8>, Vec<VoteInfo>) {
         let (val1, ck) = {
@@ -420,6 +518,7 @@ impl MockNode {
     pub fn finalize_and_commit(&self) {
         let (proposer_address, votes) = self.prepare_request();
 
+        let time = self.block_time();
         let mut locked = self.shell.lock().unwrap();
         let height =
             locked.state.in_mem().get_last_block_height().next_height();
@@ -447,8 +546,7 @@ impl MockNode {
         let req = FinalizeBlock {
             header: Header {
                 hash: Hash([0; 32]),
-                #[allow(clippy::disallowed_methods)]
-                time: DateTimeUtc::now(),
+                time,
                 next_validators_hash: Hash([0; 32]),
             },
             byzantine_validators: vec![],
@@ -497,7 +595,7 @@ impl MockNode {
                             .try_into()
                             .unwrap(),
                         height: 1u32.into(),
-                        time: tendermint::Time::now(),
+                        time: time.try_into().unwrap(),
                         last_block_id: None,
                         last_commit_hash: None,
                         data_hash: None,
@@ -526,8 +624,7 @@ impl MockNode {
         self.finalize_and_commit();
         let (proposer_address, votes) = self.prepare_request();
 
-        #[allow(clippy::disallowed_methods)]
-        let time = DateTimeUtc::now();
+        let time = self.block_time();
         let req = RequestProcessProposal {
             txs: txs.clone().into_iter().map(|tx| tx.into()).collect(),
             proposer_address: proposer_address.clone().into(),
@@ -561,8 +658,7 @@ impl MockNode {
         let req = FinalizeBlock {
             header: Header {
                 hash: Hash([0; 32]),
-                #[allow(clippy::disallowed_methods)]
-                time: DateTimeUtc::now(),
+                time,
                 next_validators_hash: Hash([0; 32]),
             },
             byzantine_validators: vec![],
@@ -617,7 +713,7 @@ impl MockNode {
                             .try_into()
                             .unwrap(),
                         height: 1u32.into(),
-                        time: tendermint::Time::now(),
+                        time: time.try_into().unwrap(),
                         last_block_id: None,
                         last_commit_hash: None,
                         data_hash: None,
@@ -93,6 +93,23 @@ impl AbcippShim {
     /// [`AbciService`].
     pub fn run(mut self) {
         while let Ok((req, resp_sender)) = self.shell_recv.recv() {
+            // stop processing blocks once the upgrade height scheduled
+            // by governance has been committed
+            if matches!(
+                req,
+                Req::PrepareProposal(_)
+                    | Req::ProcessProposal(_)
+                    | Req::BeginBlock(_)
+            ) {
+                if let Some(height) = self.service.upgrade_halt_height() {
+                    let err = Error::Shell(
+                        crate::shell::Error::HaltedForUpgrade(height),
+                    );
+                    tracing::info!("{err}");
+                    let _ = resp_sender.send(Err(err.into()));
+                    break;
+                }
+            }
             let resp = match req {
                 Req::ProcessProposal(proposal) => self
                     .service
@@ -11,7 +11,7 @@ use arse_merkle_tree::H256;
 use blake2b_rs::{Blake2b, Blake2bBuilder};
 use namada::state::StorageHasher;
 use namada_sdk::state::FullAccessState;
-pub use rocksdb::RocksDBUpdateVisitor;
+pub use rocksdb::{open, RocksDBUpdateVisitor, SnapshotEntry};
 
 #[derive(Default)]
 pub struct PersistentStorageHasher(Blake2bHasher);
@@ -177,6 +177,8 @@ mod tests {
             fee_unshielding_gas_limit: 0,
             minimum_gas_price: Default::default(),
             is_native_token_transferable: true,
+            epoch_switch_policy: Default::default(),
+            max_code_bytes: 1024 * 1024,
         };
         parameters::init_storage(&params, &mut state).expect("Test failed");
         // insert and commit
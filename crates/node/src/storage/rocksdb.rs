@@ -19,6 +19,9 @@
 //!     - `commit_only_data_commitment`
 //!     - `update_epoch_blocks_delay`
 //!   - `conversion_state`: MASP conversion state
+//!   - `pruned_height`: the last block height whose data has been pruned
+//!   - `db_layout_version`: the version of this layout, used to migrate the
+//!     DB on startup
 //! - `subspace`: accounts sub-spaces
 //!   - `{address}/{dyn}`: any byte data associated with accounts
 //! - `diffs`: diffs in account subspaces' key-vals modified with `persist_diff
@@ -33,28 +36,34 @@
 //! - `block`: block state
 //!   - `results/{h}`: block results at height `h`
 //!   - `h`: for each block at height `h`:
-//!     - `tree`: merkle tree
-//!       - `root`: root hash
-//!       - `store`: the tree's store
 //!     - `time`: block time
 //!     - `epoch`: block epoch
 //!     - `address_gen`: established address generator
 //!     - `header`: block's header
+//! - `merkle_tree`: merkle tree stores
+//!   - `{h}/tree/{st}`: the store `st` written at every block, at height `h`
+//!   - `{e}/tree/{st}`: the store `st` written at the start of epoch `e`
+//!     - `root`: root hash
+//!     - `store`: the tree's store
 //! - `replay_protection`: hashes of processed tx for replay protection purposes
 //!     - `current/{hash}`: a hash included in the current block
 //!     - `{hash}`: a hash included in previous blocks
+//! - `tx_index`: optional node-local index of the txs touching an address
+//!     - `{address}/{height}/{index}`: the tx at index `index` of block
+//!       `height` touched `address`
 
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 use std::str::FromStr;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use data_encoding::HEXLOWER;
 use itertools::Either;
+use namada::core::address::Address;
 use namada::core::collections::HashSet;
-use namada::core::storage::{BlockHeight, Epoch, Header, Key, KeySeg};
+use namada::core::storage::{BlockHeight, Epoch, Header, Key, KeySeg, TxIndex};
 use namada::core::{decode, encode, ethereum_events};
 use namada::eth_bridge::storage::proof::BridgePoolRootProof;
 use namada::ledger::eth_bridge::storage::bridge_pool;
@@ -62,14 +71,15 @@ use namada::replay_protection;
 use namada::state::merkle_tree::{
     tree_key_prefix_with_epoch, tree_key_prefix_with_height,
 };
+use namada::state::storage::tx_index;
 use namada::state::{
     BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch, DbError as Error,
     DbResult as Result, MerkleTreeStoresRead, PatternIterator, PrefixIterator,
     StoreType, DB,
 };
 use namada::storage::{
-    DbColFam, BLOCK_CF, DIFFS_CF, REPLAY_PROTECTION_CF, ROLLBACK_CF, STATE_CF,
-    SUBSPACE_CF,
+    DbColFam, BLOCK_CF, DIFFS_CF, MERKLE_TREE_CF, REPLAY_PROTECTION_CF,
+    ROLLBACK_CF, STATE_CF, SUBSPACE_CF, TX_INDEX_CF,
 };
 use namada_sdk::arith::checked;
 use namada_sdk::migrations::DBUpdateVisitor;
@@ -83,8 +93,6 @@ use rocksdb::{
 
 use crate::config::utils::num_of_threads;
 
-// TODO the DB schema will probably need some kind of versioning
-
 /// Env. var to set a number of Rayon global worker threads
 const ENV_VAR_ROCKSDB_COMPACTION_THREADS: &str =
     "NAMADA_ROCKSDB_COMPACTION_THREADS";
@@ -97,6 +105,8 @@ const COMMIT_ONLY_DATA_KEY: &str = "commit_only_data_commitment";
 const CONVERSION_STATE_KEY: &str = "conversion_state";
 const ETHEREUM_HEIGHT_KEY: &str = "ethereum_height";
 const ETH_EVENTS_QUEUE_KEY: &str = "eth_events_queue";
+const PRUNED_HEIGHT_KEY: &str = "pruned_height";
+const DB_LAYOUT_VERSION_KEY: &str = "db_layout_version";
 const RESULTS_KEY_PREFIX: &str = "results";
 const PRED_KEY_PREFIX: &str = "pred";
 
@@ -111,9 +121,38 @@ const ADDRESS_GEN_KEY_SEGMENT: &str = "address_gen";
 const OLD_DIFF_PREFIX: &str = "old";
 const NEW_DIFF_PREFIX: &str = "new";
 
-/// RocksDB handle
-#[derive(Debug)]
-pub struct RocksDB(rocksdb::DB);
+/// The current version of the DB layout. Version 1 moved the Merkle tree
+/// stores from the `block` column family into their own.
+const DB_LAYOUT_VERSION: u64 = 1;
+/// Max number of entries written in a single batch by the layout migration
+const MIGRATION_BATCH_SIZE: usize = 1024;
+
+/// Column families whose entries are included in state sync snapshots
+pub const SNAPSHOT_CFS: [&str; 7] = [
+    SUBSPACE_CF,
+    DIFFS_CF,
+    ROLLBACK_CF,
+    STATE_CF,
+    BLOCK_CF,
+    MERKLE_TREE_CF,
+    REPLAY_PROTECTION_CF,
+];
+
+/// A single DB entry of a state sync snapshot chunk
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SnapshotEntry {
+    /// Name of the column family
+    pub cf: String,
+    /// Raw key
+    pub key: Vec<u8>,
+    /// Raw value
+    pub value: Vec<u8>,
+}
+
+/// RocksDB handle. The handle can be cloned to access the same DB from
+/// another thread.
+#[derive(Clone, Debug)]
+pub struct RocksDB(Arc<rocksdb::DB>);
 
 /// DB Handle for batch writes.
 #[derive(Default)]
@@ -205,6 +244,23 @@ pub fn open(
     block_cf_opts.set_block_based_table_factory(&table_opts);
     cfs.push(ColumnFamilyDescriptor::new(BLOCK_CF, block_cf_opts));
 
+    // for the Merkle tree stores (insert-intensive, with large values)
+    let mut merkle_tree_cf_opts = Options::default();
+    merkle_tree_cf_opts.set_compression_type(DBCompressionType::Zstd);
+    merkle_tree_cf_opts.set_compression_options(0, 0, 0, 1024 * 1024);
+    merkle_tree_cf_opts.set_compaction_style(DBCompactionStyle::Universal);
+    // Keep the large stores out of the LSM tree, so that compactions don't
+    // rewrite them
+    merkle_tree_cf_opts.set_enable_blob_files(true);
+    merkle_tree_cf_opts.set_min_blob_size(4 * 1024);
+    merkle_tree_cf_opts.set_blob_compression_type(DBCompressionType::Zstd);
+    merkle_tree_cf_opts.set_enable_blob_gc(true);
+    merkle_tree_cf_opts.set_block_based_table_factory(&table_opts);
+    cfs.push(ColumnFamilyDescriptor::new(
+        MERKLE_TREE_CF,
+        merkle_tree_cf_opts,
+    ));
+
     // for replay protection (read/insert-intensive)
     let mut replay_protection_cf_opts = Options::default();
     replay_protection_cf_opts.set_compression_type(DBCompressionType::Zstd);
@@ -218,14 +274,27 @@ pub fn open(
         replay_protection_cf_opts,
     ));
 
-    rocksdb::DB::open_cf_descriptors(&db_opts, path, cfs)
-        .map(RocksDB)
-        .map_err(|e| Error::DBError(e.into_string()))
+    // for the optional tx index (insert-intensive)
+    let mut tx_index_cf_opts = Options::default();
+    tx_index_cf_opts.set_compression_type(DBCompressionType::Zstd);
+    tx_index_cf_opts.set_compression_options(0, 0, 0, 1024 * 1024);
+    tx_index_cf_opts.set_compaction_style(DBCompactionStyle::Universal);
+    tx_index_cf_opts.set_block_based_table_factory(&table_opts);
+    cfs.push(ColumnFamilyDescriptor::new(TX_INDEX_CF, tx_index_cf_opts));
+
+    let db = rocksdb::DB::open_cf_descriptors(&db_opts, path, cfs)
+        .map(|db| RocksDB(Arc::new(db)))
+        .map_err(|e| Error::DBError(e.into_string()))?;
+    db.migrate_layout()?;
+    Ok(db)
 }
 
 impl Drop for RocksDB {
     fn drop(&mut self) {
-        self.flush(true).expect("flush failed");
+        // Only flush when the last handle is dropped
+        if Arc::strong_count(&self.0) == 1 {
+            self.flush(true).expect("flush failed");
+        }
     }
 }
 
@@ -386,6 +455,12 @@ impl RocksDB {
             let cf = self
                 .get_column_family(BLOCK_CF)
                 .expect("Block column family should exist");
+            self.dump_it(cf, Some(prefix.clone()), &mut file);
+
+            // Merkle tree
+            let cf = self
+                .get_column_family(MERKLE_TREE_CF)
+                .expect("Merkle tree column family should exist");
             self.dump_it(cf, Some(prefix), &mut file);
         }
 
@@ -691,6 +766,223 @@ impl RocksDB {
             .get_cf(rollback_cf, key)
             .map_err(|e| Error::DBError(e.into_string()))
     }
+
+    /// Migrate a DB written with an older layout to the current one. This
+    /// is a no-op for a DB that is up to date.
+    fn migrate_layout(&self) -> Result<()> {
+        let state_cf = self.get_column_family(STATE_CF)?;
+        let version: u64 = self
+            .read_value(state_cf, DB_LAYOUT_VERSION_KEY)?
+            .unwrap_or_default();
+        if version >= DB_LAYOUT_VERSION {
+            return Ok(());
+        }
+
+        // Move the Merkle tree stores, i.e. the keys whose second segment is
+        // `tree`, out of the block column family
+        let block_cf = self.get_column_family(BLOCK_CF)?;
+        let merkle_tree_cf = self.get_column_family(MERKLE_TREE_CF)?;
+        let mut batch = RocksDB::batch();
+        let mut batch_len = 0_usize;
+        let mut moved = 0_usize;
+        for entry in self.0.iterator_cf(block_cf, IteratorMode::Start) {
+            let (key, value) =
+                entry.map_err(|e| Error::DBError(e.into_string()))?;
+            let is_tree_key = std::str::from_utf8(&key)
+                .is_ok_and(|key| key.split('/').nth(1) == Some("tree"));
+            if !is_tree_key {
+                continue;
+            }
+            batch.0.put_cf(merkle_tree_cf, &key, value);
+            batch.0.delete_cf(block_cf, &key);
+            batch_len = batch_len.saturating_add(1);
+            if batch_len >= MIGRATION_BATCH_SIZE {
+                self.exec_batch(std::mem::take(&mut batch))?;
+                moved = moved.saturating_add(batch_len);
+                batch_len = 0;
+            }
+        }
+        moved = moved.saturating_add(batch_len);
+        self.add_value_to_batch(
+            state_cf,
+            DB_LAYOUT_VERSION_KEY,
+            &DB_LAYOUT_VERSION,
+            &mut batch,
+        );
+        self.exec_batch(batch)?;
+        if moved > 0 {
+            tracing::info!(
+                "Migrated the DB to layout version {DB_LAYOUT_VERSION}, \
+                 moving {moved} Merkle tree entries to their column family."
+            );
+        }
+        Ok(())
+    }
+
+    /// Read the last block height whose data has been pruned, if any.
+    pub fn read_pruned_height(&self) -> Result<Option<BlockHeight>> {
+        let state_cf = self.get_column_family(STATE_CF)?;
+        self.read_value(state_cf, PRUNED_HEIGHT_KEY)
+    }
+
+    /// Prune the data of the blocks at the heights from `from` to `to`
+    /// (inclusive) that is only needed to read the state or to build proofs
+    /// at these heights: the Merkle tree stores written at every block, the
+    /// block header and metadata, the block results and the subspace diffs.
+    /// The Merkle tree stores written at the start of the given `epochs` are
+    /// pruned too, so none of their heights may be retained. The deletions
+    /// are written as tombstones and the disk space is reclaimed by the
+    /// background compactions.
+    pub fn prune_blocks(
+        &self,
+        from: BlockHeight,
+        to: BlockHeight,
+        epochs: impl IntoIterator<Item = Epoch>,
+    ) -> Result<()> {
+        let state_cf = self.get_column_family(STATE_CF)?;
+        let block_cf = self.get_column_family(BLOCK_CF)?;
+        let merkle_tree_cf = self.get_column_family(MERKLE_TREE_CF)?;
+        let diffs_cf = self.get_column_family(DIFFS_CF)?;
+        let mut batch = RocksDB::batch();
+        // Note that the stores written at the start of an epoch share the key
+        // space with the ones written at every block, so they cannot be
+        // deleted by prefix.
+        let delete_tree_stores =
+            |batch: &mut RocksDBWriteBatch, key_prefix: Key| {
+                batch.0.delete_cf(
+                    merkle_tree_cf,
+                    format!("{key_prefix}/{MERKLE_TREE_ROOT_KEY_SEGMENT}"),
+                );
+                batch.0.delete_cf(
+                    merkle_tree_cf,
+                    format!("{key_prefix}/{MERKLE_TREE_STORE_KEY_SEGMENT}"),
+                );
+            };
+        for epoch in epochs {
+            for st in StoreType::iter().filter(|st| !st.is_stored_every_block())
+            {
+                delete_tree_stores(
+                    &mut batch,
+                    tree_key_prefix_with_epoch(st, epoch),
+                );
+            }
+        }
+        let mut height = from;
+        while height <= to {
+            let prefix = height.raw();
+            // The Merkle tree stores written at every block
+            for st in StoreType::iter().filter(|st| st.is_stored_every_block())
+            {
+                delete_tree_stores(
+                    &mut batch,
+                    tree_key_prefix_with_height(st, height),
+                );
+            }
+            for segment in [
+                BLOCK_HEADER_KEY_SEGMENT,
+                BLOCK_TIME_KEY_SEGMENT,
+                EPOCH_KEY_SEGMENT,
+                PRED_EPOCHS_KEY_SEGMENT,
+                ADDRESS_GEN_KEY_SEGMENT,
+            ] {
+                batch.0.delete_cf(block_cf, format!("{prefix}/{segment}"));
+            }
+            batch
+                .0
+                .delete_cf(block_cf, format!("{RESULTS_KEY_PREFIX}/{prefix}"));
+            // All the diffs at this height start with "{height}/" and `0` is
+            // the character following `/`
+            batch.0.delete_range_cf(
+                diffs_cf,
+                format!("{prefix}/"),
+                format!("{prefix}0"),
+            );
+            height = height.next_height();
+        }
+        self.add_value_to_batch(state_cf, PRUNED_HEIGHT_KEY, &to, &mut batch);
+        self.exec_batch(batch)
+    }
+
+    /// Create a checkpoint of the DB in the given directory. The checkpoint
+    /// hard-links the live SST files, so it is cheap to take and is a
+    /// consistent view of the DB at the time of the call. The directory must
+    /// not exist yet.
+    pub fn checkpoint(&self, dir: impl AsRef<Path>) -> Result<()> {
+        rocksdb::checkpoint::Checkpoint::new(&self.0)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(dir))
+            .map_err(|e| Error::DBError(e.into_string()))
+    }
+
+    /// Iterate over all the entries of the DB in the order of
+    /// [`SNAPSHOT_CFS`] and pass them to `write_chunk` in chunks of
+    /// roughly `chunk_size` bytes.
+    pub fn for_each_snapshot_chunk<F>(
+        &self,
+        chunk_size: usize,
+        mut write_chunk: F,
+    ) -> Result<()>
+    where
+        F: FnMut(Vec<SnapshotEntry>) -> Result<()>,
+    {
+        let mut chunk = vec![];
+        let mut chunk_bytes = 0_usize;
+        for cf_name in SNAPSHOT_CFS {
+            let cf = self.get_column_family(cf_name)?;
+            for entry in self.0.iterator_cf(cf, IteratorMode::Start) {
+                let (key, value) =
+                    entry.map_err(|e| Error::DBError(e.into_string()))?;
+                chunk_bytes = chunk_bytes
+                    .saturating_add(key.len())
+                    .saturating_add(value.len());
+                chunk.push(SnapshotEntry {
+                    cf: cf_name.to_string(),
+                    key: key.into_vec(),
+                    value: value.into_vec(),
+                });
+                if chunk_bytes >= chunk_size {
+                    write_chunk(std::mem::take(&mut chunk))?;
+                    chunk_bytes = 0;
+                }
+            }
+        }
+        if !chunk.is_empty() {
+            write_chunk(chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Write the entries of a state sync snapshot chunk to the DB.
+    pub fn restore_snapshot_chunk(
+        &self,
+        entries: Vec<SnapshotEntry>,
+    ) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        for SnapshotEntry { cf, key, value } in entries {
+            let cf = self.get_column_family(&cf)?;
+            batch.put_cf(cf, key, value);
+        }
+        self.0
+            .write(batch)
+            .map_err(|e| Error::DBError(e.into_string()))
+    }
+
+    /// Delete all the entries of the column families in [`SNAPSHOT_CFS`],
+    /// e.g. to discard a partially restored snapshot.
+    pub fn clear_snapshot_cfs(&self) -> Result<()> {
+        for cf_name in SNAPSHOT_CFS {
+            let cf = self.get_column_family(cf_name)?;
+            let mut batch = WriteBatch::default();
+            for entry in self.0.iterator_cf(cf, IteratorMode::Start) {
+                let (key, _) =
+                    entry.map_err(|e| Error::DBError(e.into_string()))?;
+                batch.delete_cf(cf, key);
+            }
+            self.0
+                .write(batch)
+                .map_err(|e| Error::DBError(e.into_string()))?;
+        }
+        Ok(())
+    }
 }
 
 impl DB for RocksDB {
@@ -899,6 +1191,7 @@ impl DB for RocksDB {
         );
 
         let block_cf = self.get_column_family(BLOCK_CF)?;
+        let merkle_tree_cf = self.get_column_family(MERKLE_TREE_CF)?;
         let prefix = height.raw();
 
         // Merkle tree
@@ -912,7 +1205,7 @@ impl DB for RocksDB {
                 let root_key =
                     format!("{key_prefix}/{MERKLE_TREE_ROOT_KEY_SEGMENT}");
                 self.add_value_to_batch(
-                    block_cf,
+                    merkle_tree_cf,
                     root_key,
                     merkle_tree_stores.root(st),
                     batch,
@@ -920,7 +1213,7 @@ impl DB for RocksDB {
                 let store_key =
                     format!("{key_prefix}/{MERKLE_TREE_STORE_KEY_SEGMENT}");
                 self.add_value_bytes_to_batch(
-                    block_cf,
+                    merkle_tree_cf,
                     store_key,
                     merkle_tree_stores.store(st).encode(),
                     batch,
@@ -968,7 +1261,7 @@ impl DB for RocksDB {
         store_type: Option<StoreType>,
     ) -> Result<Option<MerkleTreeStoresRead>> {
         // Get the latest height at which the tree stores were written
-        let block_cf = self.get_column_family(BLOCK_CF)?;
+        let merkle_tree_cf = self.get_column_family(MERKLE_TREE_CF)?;
         let mut merkle_tree_stores = MerkleTreeStoresRead::default();
         let store_types = store_type
             .as_ref()
@@ -982,14 +1275,14 @@ impl DB for RocksDB {
             };
             let root_key =
                 format!("{key_prefix}/{MERKLE_TREE_ROOT_KEY_SEGMENT}");
-            match self.read_value(block_cf, root_key)? {
+            match self.read_value(merkle_tree_cf, root_key)? {
                 Some(root) => merkle_tree_stores.set_root(st, root),
                 None => return Ok(None),
             }
 
             let store_key =
                 format!("{key_prefix}/{MERKLE_TREE_STORE_KEY_SEGMENT}");
-            match self.read_value_bytes(block_cf, store_key)? {
+            match self.read_value_bytes(merkle_tree_cf, store_key)? {
                 Some(bytes) => {
                     merkle_tree_stores.set_store(st.decode_store(bytes)?)
                 }
@@ -1235,12 +1528,12 @@ impl DB for RocksDB {
         store_type: &StoreType,
         epoch: Epoch,
     ) -> Result<()> {
-        let block_cf = self.get_column_family(BLOCK_CF)?;
+        let merkle_tree_cf = self.get_column_family(MERKLE_TREE_CF)?;
         let key_prefix = tree_key_prefix_with_epoch(store_type, epoch);
         let root_key = format!("{key_prefix}/{MERKLE_TREE_ROOT_KEY_SEGMENT}");
-        batch.0.delete_cf(block_cf, root_key);
+        batch.0.delete_cf(merkle_tree_cf, root_key);
         let store_key = format!("{key_prefix}/{MERKLE_TREE_STORE_KEY_SEGMENT}");
-        batch.0.delete_cf(block_cf, store_key);
+        batch.0.delete_cf(merkle_tree_cf, store_key);
         Ok(())
     }
 
@@ -1283,6 +1576,25 @@ impl DB for RocksDB {
         Ok(())
     }
 
+    fn write_tx_index_entry(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        address: &Address,
+        height: BlockHeight,
+        index: TxIndex,
+    ) -> Result<()> {
+        let tx_index_cf = self.get_column_family(TX_INDEX_CF)?;
+
+        self.add_value_bytes_to_batch(
+            tx_index_cf,
+            tx_index::key(address, height, index).to_string(),
+            vec![],
+            batch,
+        );
+
+        Ok(())
+    }
+
     fn move_current_replay_protection_entries(
         &mut self,
         batch: &mut Self::WriteBatch,
@@ -1552,6 +1864,15 @@ impl<'iter> DBIter<'iter> for RocksDB {
         let prefix = Some(replay_protection::current_prefix());
         iter_prefix(self, replay_protection_cf, None, prefix.as_ref())
     }
+
+    fn iter_tx_index(&'iter self, address: &Address) -> Self::PrefixIter {
+        let tx_index_cf = self
+            .get_column_family(TX_INDEX_CF)
+            .expect("{TX_INDEX_CF} column family should exist");
+
+        let stripped_prefix = Some(tx_index::prefix(address));
+        iter_prefix(self, tx_index_cf, stripped_prefix.as_ref(), None)
+    }
 }
 
 fn iter_subspace_prefix<'iter>(
@@ -1839,6 +2160,222 @@ mod test {
             .expect("Block should have been written");
     }
 
+    /// Test that a DB restored from the snapshot chunks of a checkpoint has
+    /// the same content as the original DB.
+    #[test]
+    fn test_snapshot_restore() {
+        let dir = tempdir().unwrap();
+        let db = open(dir.path().join("db"), None).unwrap();
+
+        let mut batch = RocksDB::batch();
+        let last_height = BlockHeight::default();
+        for i in 0..10_u8 {
+            db.batch_write_subspace_val(
+                &mut batch,
+                last_height,
+                &Key::parse(format!("test{i}")).unwrap(),
+                vec![i; 16],
+                true,
+            )
+            .unwrap();
+        }
+        add_block_to_batch(
+            &db,
+            &mut batch,
+            BlockHeight::default(),
+            Epoch::default(),
+            Epochs::default(),
+            &ConversionState::default(),
+        )
+        .unwrap();
+        db.exec_batch(batch).unwrap();
+
+        let checkpoint_dir = dir.path().join("checkpoint");
+        db.checkpoint(&checkpoint_dir).unwrap();
+        let checkpoint = open(&checkpoint_dir, None).unwrap();
+
+        let mut chunks = vec![];
+        checkpoint
+            .for_each_snapshot_chunk(64, |chunk| {
+                chunks.push(chunk);
+                Ok(())
+            })
+            .unwrap();
+        assert!(chunks.len() > 1);
+
+        let restored = open(dir.path().join("restored"), None).unwrap();
+        for chunk in chunks {
+            restored.restore_snapshot_chunk(chunk).unwrap();
+        }
+
+        for i in 0..10_u8 {
+            let key = Key::parse(format!("test{i}")).unwrap();
+            assert_eq!(
+                restored.read_subspace_val(&key).unwrap(),
+                Some(vec![i; 16])
+            );
+        }
+        let original = db.read_last_block().unwrap().unwrap();
+        let restored_block = restored.read_last_block().unwrap().unwrap();
+        assert_eq!(original.height, restored_block.height);
+        assert_eq!(original.epoch, restored_block.epoch);
+
+        // Clearing the restored entries leaves an empty DB
+        restored.clear_snapshot_cfs().unwrap();
+        assert!(restored.read_last_block().unwrap().is_none());
+        for cf_name in SNAPSHOT_CFS {
+            let cf = restored.get_column_family(cf_name).unwrap();
+            assert!(
+                restored
+                    .0
+                    .iterator_cf(cf, IteratorMode::Start)
+                    .next()
+                    .is_none()
+            );
+        }
+    }
+
+    /// Test that pruning removes the data of the pruned blocks only.
+    #[test]
+    fn test_prune_blocks() {
+        let dir = tempdir().unwrap();
+        let db = open(dir.path(), None).unwrap();
+        let key = Key::parse("test").unwrap();
+
+        // Write the blocks at heights 1 to 10, so that the keys of the block
+        // at height 1 are a prefix of the keys of the block at height 10
+        let mut pred_epochs = Epochs::default();
+        for height in 1..=10_u64 {
+            let mut batch = RocksDB::batch();
+            let height = BlockHeight(height);
+            pred_epochs.new_epoch(height);
+            db.batch_write_subspace_val(
+                &mut batch,
+                height,
+                &key,
+                encode(&height),
+                true,
+            )
+            .unwrap();
+            add_block_to_batch(
+                &db,
+                &mut batch,
+                height,
+                Epoch(height.0),
+                pred_epochs.clone(),
+                &ConversionState::default(),
+            )
+            .unwrap();
+            db.exec_batch(batch).unwrap();
+        }
+        assert_eq!(db.read_pruned_height().unwrap(), None);
+
+        db.prune_blocks(BlockHeight(1), BlockHeight(2), [Epoch(1), Epoch(2)])
+            .unwrap();
+        assert_eq!(db.read_pruned_height().unwrap(), Some(BlockHeight(2)));
+
+        let block_cf = db.get_column_family(BLOCK_CF).unwrap();
+        let diffs_cf = db.get_column_family(DIFFS_CF).unwrap();
+        let has_block_data = |height: BlockHeight| {
+            let time_key = format!("{}/{BLOCK_TIME_KEY_SEGMENT}", height.raw());
+            db.read_value_bytes(block_cf, time_key).unwrap().is_some()
+        };
+        let has_diff = |height: BlockHeight| {
+            let (_, new_key) = old_and_new_diff_key(&key, height).unwrap();
+            db.read_value_bytes(diffs_cf, new_key).unwrap().is_some()
+        };
+        for height in 1..=10_u64 {
+            let height = BlockHeight(height);
+            let pruned = height.0 <= 2;
+            assert_eq!(has_block_data(height), !pruned);
+            assert_eq!(has_diff(height), !pruned);
+        }
+        // The Merkle tree stores written at every block and at the start of
+        // the pruned epochs are removed
+        let has_stores = |epoch: Epoch, height: BlockHeight, st: StoreType| {
+            db.read_merkle_tree_stores(epoch, height, Some(st))
+                .unwrap()
+                .is_some()
+        };
+        for height in 1..=10_u64 {
+            let pruned = height <= 2;
+            let height = BlockHeight(height);
+            let epoch = Epoch(height.0);
+            assert_eq!(has_stores(epoch, height, StoreType::Base), !pruned);
+            assert_eq!(has_stores(epoch, height, StoreType::Account), !pruned);
+            assert_eq!(has_stores(epoch, height, StoreType::Ibc), !pruned);
+        }
+        assert!(
+            db.read_merkle_tree_stores(Epoch(3), BlockHeight(3), None)
+                .unwrap()
+                .is_some()
+        );
+        // The last block can still be read
+        let last_block = db.read_last_block().unwrap().unwrap();
+        assert_eq!(last_block.height, BlockHeight(10));
+    }
+
+    /// Test that the Merkle tree stores of a DB written with the layout
+    /// version 0 are moved to their own column family on startup.
+    #[test]
+    fn test_migrate_layout() {
+        let dir = tempdir().unwrap();
+        {
+            let db = open(dir.path(), None).unwrap();
+            let mut batch = RocksDB::batch();
+            add_block_to_batch(
+                &db,
+                &mut batch,
+                BlockHeight::default(),
+                Epoch::default(),
+                Epochs::default(),
+                &ConversionState::default(),
+            )
+            .unwrap();
+            db.exec_batch(batch).unwrap();
+
+            // Move the Merkle tree stores back to the block column family
+            // and remove the layout version, as written by older versions
+            let state_cf = db.get_column_family(STATE_CF).unwrap();
+            let block_cf = db.get_column_family(BLOCK_CF).unwrap();
+            let merkle_tree_cf = db.get_column_family(MERKLE_TREE_CF).unwrap();
+            let mut batch = RocksDB::batch();
+            for entry in db.0.iterator_cf(merkle_tree_cf, IteratorMode::Start) {
+                let (key, value) = entry.unwrap();
+                batch.0.put_cf(block_cf, &key, value);
+                batch.0.delete_cf(merkle_tree_cf, &key);
+            }
+            batch.0.delete_cf(state_cf, DB_LAYOUT_VERSION_KEY);
+            db.exec_batch(batch).unwrap();
+            assert!(db
+                .read_merkle_tree_stores(Epoch::default(), BlockHeight(0), None)
+                .unwrap()
+                .is_none());
+        }
+
+        let db = open(dir.path(), None).unwrap();
+        let state_cf = db.get_column_family(STATE_CF).unwrap();
+        let block_cf = db.get_column_family(BLOCK_CF).unwrap();
+        assert_eq!(
+            db.read_value::<u64>(state_cf, DB_LAYOUT_VERSION_KEY)
+                .unwrap(),
+            Some(DB_LAYOUT_VERSION)
+        );
+        assert!(db
+            .read_merkle_tree_stores(Epoch::default(), BlockHeight(0), None)
+            .unwrap()
+            .is_some());
+        // The block data is left in place
+        let time_key =
+            format!("{}/{BLOCK_TIME_KEY_SEGMENT}", BlockHeight(0).raw());
+        assert!(db.read_value_bytes(block_cf, time_key).unwrap().is_some());
+        assert!(db
+            .0
+            .iterator_cf(block_cf, IteratorMode::Start)
+            .map(|entry| entry.unwrap().0)
+            .all(|key| !key.windows(6).any(|w| w == b"/tree/")));
+    }
+
     #[test]
     fn test_read() {
         let dir = tempdir().unwrap();
@@ -23,10 +23,12 @@ use std::collections::BTreeMap;
 
 use namada_core::address::{Address, InternalAddress};
 use namada_core::chain::ProposalBytes;
+use namada_core::dec::Dec;
 pub use namada_core::parameters::*;
 use namada_core::storage::Key;
 use namada_core::time::DurationSecs;
 use namada_core::token;
+use namada_gas::cost_table::GasCostTable;
 use namada_storage::{ResultExt, StorageRead, StorageWrite};
 pub use storage::get_max_block_gas;
 use thiserror::Error;
@@ -78,6 +80,8 @@ where
         minimum_gas_price,
         fee_unshielding_gas_limit,
         is_native_token_transferable,
+        epoch_switch_policy,
+        max_code_bytes,
     } = parameters;
 
     // write max tx bytes parameter
@@ -150,6 +154,15 @@ where
     storage
         .write(&native_token_transferable_key, is_native_token_transferable)?;
 
+    let epoch_switch_policy_key = storage::get_epoch_switch_policy_key();
+    storage.write(&epoch_switch_policy_key, epoch_switch_policy)?;
+
+    let max_code_bytes_key = storage::get_max_code_bytes_key();
+    storage.write(&max_code_bytes_key, max_code_bytes)?;
+
+    let vm_gas_cost_table_key = storage::get_vm_gas_cost_table_key();
+    storage.write(&vm_gas_cost_table_key, GasCostTable::default())?;
+
     Ok(())
 }
 
@@ -228,6 +241,20 @@ where
     storage.write(&key, value)
 }
 
+/// Update the gas costs of the wasm opcodes in storage. Meant to be called
+/// from the code of a governance proposal. The version of the new table must
+/// be greater than the current one, or the parameters VP rejects the update.
+pub fn update_vm_gas_cost_table<S>(
+    storage: &mut S,
+    value: &GasCostTable,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage::get_vm_gas_cost_table_key();
+    storage.write(&key, value)
+}
+
 /// Update the epochs_per_year parameter in storage. Returns the parameters and
 /// gas cost.
 pub fn update_epochs_per_year_parameter<S>(
@@ -282,7 +309,10 @@ where
         .into_storage_result()
 }
 
-/// Read the cost per unit of gas for the provided token
+/// Read the cost per unit of gas for the provided token. A token without a
+/// minimum gas price of its own, but with a fee conversion rate, costs the
+/// minimum gas price of the native token converted at that rate. A converted
+/// cost that overflows is treated as if the token could not pay for fees.
 pub fn read_gas_cost<S>(
     storage: &S,
     token: &Address,
@@ -294,7 +324,71 @@ where
         .read(&storage::get_gas_cost_key())?
         .ok_or(ReadError::ParametersMissing)
         .into_storage_result()?;
-    Ok(gas_cost_table.get(token).map(|amount| amount.to_owned()))
+    if let Some(amount) = gas_cost_table.get(token) {
+        return Ok(Some(amount.to_owned()));
+    }
+    let Some(rate) = read_fee_conversion_rates(storage)?.remove(token) else {
+        return Ok(None);
+    };
+    let native_token = storage.get_native_token()?;
+    let Some(native_gas_cost) = gas_cost_table.get(&native_token) else {
+        return Ok(None);
+    };
+    Ok(native_gas_cost.mul_ceil(rate).ok())
+}
+
+/// The largest fee conversion rate that may be set for a token
+pub const MAX_FEE_CONVERSION_RATE: u64 = 1_000_000_000_000_000_000;
+
+/// Check that a fee conversion rate is greater than zero and at most
+/// [`MAX_FEE_CONVERSION_RATE`]
+pub fn is_valid_fee_conversion_rate(rate: &Dec) -> bool {
+    !rate.is_negative()
+        && !rate.is_zero()
+        && *rate <= Dec::from(MAX_FEE_CONVERSION_RATE)
+}
+
+/// Read the fee conversion rates, i.e. the amount of each token, in its
+/// smallest denomination, that is worth a unit of the smallest denomination
+/// of the native token. Chains initialized before the introduction of this
+/// parameter have no conversion rates.
+pub fn read_fee_conversion_rates<S>(
+    storage: &S,
+) -> namada_storage::Result<BTreeMap<Address, Dec>>
+where
+    S: StorageRead,
+{
+    Ok(storage
+        .read(&storage::get_fee_conversion_rates_key())?
+        .unwrap_or_default())
+}
+
+/// Update the conversion rates of the given fee tokens, leaving the rates of
+/// the other tokens untouched
+pub fn update_fee_conversion_rates<S>(
+    storage: &mut S,
+    rates: BTreeMap<Address, Dec>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let mut current = read_fee_conversion_rates(storage)?;
+    current.extend(rates);
+    storage.write(&storage::get_fee_conversion_rates_key(), current)
+}
+
+/// Read the versioned gas costs of the wasm opcodes. Chains initialized
+/// before the introduction of this parameter use the default table until a
+/// governance proposal writes a new one.
+pub fn read_vm_gas_cost_table<S>(
+    storage: &S,
+) -> namada_storage::Result<GasCostTable>
+where
+    S: StorageRead,
+{
+    Ok(storage
+        .read(&storage::get_vm_gas_cost_table_key())?
+        .unwrap_or_default())
 }
 
 /// Read all the parameters from storage. Returns the parameters and gas
@@ -397,6 +491,9 @@ where
         .ok_or(ReadError::ParametersMissing)
         .into_storage_result()?;
 
+    let epoch_switch_policy = storage::get_epoch_switch_policy(storage)?;
+    let max_code_bytes = storage::get_max_code_bytes(storage)?;
+
     Ok(Parameters {
         max_tx_bytes,
         epoch_duration,
@@ -411,6 +508,8 @@ where
         minimum_gas_price,
         fee_unshielding_gas_limit,
         is_native_token_transferable,
+        epoch_switch_policy,
+        max_code_bytes,
     })
 }
 
@@ -428,6 +527,18 @@ where
     Ok(tx_size <= max_tx_bytes as usize)
 }
 
+/// Validate the size of the wasm code embedded in a tx.
+pub fn validate_code_bytes<S>(
+    storage: &S,
+    code_size: usize,
+) -> namada_storage::Result<bool>
+where
+    S: StorageRead,
+{
+    let max_code_bytes = storage::get_max_code_bytes(storage)?;
+    Ok(code_size <= max_code_bytes as usize)
+}
+
 /// Storage key for the Ethereum address of wNam.
 pub fn native_erc20_key() -> Key {
     storage::get_native_erc20_key_at_addr(ADDRESS)
@@ -456,6 +567,8 @@ where
         fee_unshielding_gas_limit: 0,
         minimum_gas_price: Default::default(),
         is_native_token_transferable: true,
+        epoch_switch_policy: Default::default(),
+        max_code_bytes: DEFAULT_MAX_CODE_BYTES,
     };
     init_storage(&params, storage)
 }
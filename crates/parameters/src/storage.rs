@@ -1,7 +1,8 @@
 //! Parameters storage
 
 use namada_core::address::Address;
-use namada_core::storage::{DbKeySeg, Key};
+use namada_core::parameters::{EpochSwitchPolicy, DEFAULT_MAX_CODE_BYTES};
+use namada_core::storage::{BlockHeight, DbKeySeg, Key};
 use namada_macros::StorageKeys;
 use namada_storage::StorageRead;
 
@@ -20,10 +21,16 @@ struct Keys {
     active_status: &'static str,
     /// Sub-key for storing the minimum confirmations parameter
     min_confirmations: &'static str,
+    /// Sub-key for storing the minimum confirmations of specific types of
+    /// Ethereum events
+    event_confirmations: &'static str,
     /// Sub-key for storing the Ethereum address for wNam.
     native_erc20: &'static str,
     /// Sub-lkey for storing the Ethereum address of the bridge contract.
     bridge_contract_address: &'static str,
+    /// Sub-key for storing the number of blocks after which the sender
+    /// of a Bridge pool transfer may cancel it.
+    bridge_pool_cancel_delay: &'static str,
     // ========================================
     // Core parameters
     // ========================================
@@ -37,9 +44,22 @@ struct Keys {
     max_tx_bytes: &'static str,
     max_block_gas: &'static str,
     minimum_gas_price: &'static str,
+    /// Sub-key for storing the rates at which the minimum gas price of the
+    /// native token converts to the fee tokens without a price of their own
+    fee_conversion_rates: &'static str,
+    /// Sub-key for storing the address allowed to update the fee conversion
+    /// rates outside of governance proposals
+    fee_price_feed: &'static str,
     fee_unshielding_gas_limit: &'static str,
     max_signatures_per_transaction: &'static str,
     native_token_transferable: &'static str,
+    epoch_switch_policy: &'static str,
+    max_code_bytes: &'static str,
+    /// Sub-key for storing the block height at which the chain halts for a
+    /// coordinated upgrade. Only ever written by governance.
+    upgrade_height: &'static str,
+    /// Sub-key for storing the versioned gas costs of the wasm opcodes
+    vm_gas_cost_table: &'static str,
 }
 
 /// Returns if the key is a parameter key.
@@ -100,6 +120,11 @@ pub fn is_max_tx_bytes_key(key: &Key) -> bool {
     is_max_tx_bytes_key_at_addr(key, &ADDRESS)
 }
 
+/// Returns if the key is the vm gas cost table key.
+pub fn is_vm_gas_cost_table_key(key: &Key) -> bool {
+    is_vm_gas_cost_table_key_at_addr(key, &ADDRESS)
+}
+
 /// Storage key used for epoch parameter.
 pub fn get_epoch_duration_storage_key() -> Key {
     get_epoch_duration_key_at_addr(ADDRESS)
@@ -155,6 +180,34 @@ pub fn get_gas_cost_key() -> Key {
     get_minimum_gas_price_key_at_addr(ADDRESS)
 }
 
+/// Returns if the key is the fee conversion rates key.
+pub fn is_fee_conversion_rates_key(key: &Key) -> bool {
+    is_fee_conversion_rates_key_at_addr(key, &ADDRESS)
+}
+
+/// Storage key used for the fee conversion rates
+pub fn get_fee_conversion_rates_key() -> Key {
+    get_fee_conversion_rates_key_at_addr(ADDRESS)
+}
+
+/// Storage key used for the address of the fee price feed
+pub fn get_fee_price_feed_key() -> Key {
+    get_fee_price_feed_key_at_addr(ADDRESS)
+}
+
+/// Helper function to retrieve the address allowed to update the fee
+/// conversion rates outside of governance proposals, if any
+pub fn get_fee_price_feed(
+    storage: &impl StorageRead,
+) -> std::result::Result<Option<Address>, namada_storage::Error> {
+    storage.read(&get_fee_price_feed_key())
+}
+
+/// Storage key used for the versioned gas costs of the wasm opcodes
+pub fn get_vm_gas_cost_table_key() -> Key {
+    get_vm_gas_cost_table_key_at_addr(ADDRESS)
+}
+
 /// Storage key used for the max signatures per transaction key
 pub fn get_max_signatures_per_transaction_key() -> Key {
     get_max_signatures_per_transaction_key_at_addr(ADDRESS)
@@ -188,3 +241,48 @@ pub fn is_native_token_transferable(
         ),
     )
 }
+
+/// Storage key used for the epoch switch policy
+pub fn get_epoch_switch_policy_key() -> Key {
+    get_epoch_switch_policy_key_at_addr(ADDRESS)
+}
+
+/// Helper function to retrieve the `epoch_switch_policy` protocol parameter
+/// from storage. Chains initialized before the introduction of this parameter
+/// use the default policy.
+pub fn get_epoch_switch_policy(
+    storage: &impl StorageRead,
+) -> std::result::Result<EpochSwitchPolicy, namada_storage::Error> {
+    Ok(storage
+        .read(&get_epoch_switch_policy_key())?
+        .unwrap_or_default())
+}
+
+/// Storage key used for the max size of the wasm code embedded in a tx
+pub fn get_max_code_bytes_key() -> Key {
+    get_max_code_bytes_key_at_addr(ADDRESS)
+}
+
+/// Helper function to retrieve the `max_code_bytes` protocol parameter from
+/// storage. Chains initialized before the introduction of this parameter use
+/// the default limit.
+pub fn get_max_code_bytes(
+    storage: &impl StorageRead,
+) -> std::result::Result<u32, namada_storage::Error> {
+    Ok(storage
+        .read(&get_max_code_bytes_key())?
+        .unwrap_or(DEFAULT_MAX_CODE_BYTES))
+}
+
+/// Storage key used for the scheduled chain upgrade height
+pub fn get_upgrade_height_key() -> Key {
+    get_upgrade_height_key_at_addr(ADDRESS)
+}
+
+/// Helper function to retrieve the block height at which the chain halts for
+/// a coordinated upgrade, if one has been scheduled by governance.
+pub fn get_upgrade_height(
+    storage: &impl StorageRead,
+) -> std::result::Result<Option<BlockHeight>, namada_storage::Error> {
+    storage.read(&get_upgrade_height_key())
+}
@@ -167,6 +167,10 @@ pub enum ReactivationError {
 pub enum MetadataError {
     #[error("The validator email cannot be removed")]
     CannotRemoveEmail,
+    #[error("The validator metadata must be within {0} characters")]
+    TooLong(u64),
+    #[error("The validator metadata cannot contain control characters")]
+    InvalidCharacters,
 }
 
 #[allow(missing_docs)]
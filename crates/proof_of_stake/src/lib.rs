@@ -2728,6 +2728,8 @@ pub mod test_utils {
             fee_unshielding_gas_limit: 10000,
             minimum_gas_price: BTreeMap::new(),
             is_native_token_transferable: true,
+            epoch_switch_policy: Default::default(),
+            max_code_bytes: 1024 * 1024,
         };
         init_storage(&chain_parameters, storage).unwrap();
         init_genesis_helper(storage, &params, validators, current_epoch)?;
@@ -23,7 +23,8 @@ use namada_storage::collections::{LazyMap, LazySet, LazyVec};
 pub use rev_order::ReverseOrdTokenAmount;
 use serde::{Deserialize, Serialize};
 
-use crate::parameters::PosParams;
+use crate::error::MetadataError;
+use crate::parameters::{PosParams, MAX_VALIDATOR_METADATA_LEN};
 
 /// Stored positions of validators in validator sets
 pub type ValidatorSetPositions = crate::epoched::NestedEpoched<
@@ -408,6 +409,18 @@ impl Default for ValidatorMetaData {
     }
 }
 
+/// Check that a single validator metadata field is not longer than
+/// [`MAX_VALIDATOR_METADATA_LEN`] and contains no control characters.
+pub fn validate_metadata_field(value: &str) -> Result<(), MetadataError> {
+    if value.len() as u64 > MAX_VALIDATOR_METADATA_LEN {
+        return Err(MetadataError::TooLong(MAX_VALIDATOR_METADATA_LEN));
+    }
+    if value.chars().any(char::is_control) {
+        return Err(MetadataError::InvalidCharacters);
+    }
+    Ok(())
+}
+
 /// An update of the consensus and below-capacity validator set.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ValidatorSetUpdate {
@@ -759,4 +772,21 @@ pub mod tests {
     pub fn arb_epoch(range: Range<u64>) -> impl Strategy<Value = Epoch> {
         range.prop_map(Epoch)
     }
+
+    #[test]
+    fn test_validate_metadata_field() {
+        assert!(validate_metadata_field("validator@example.com").is_ok());
+        assert!(validate_metadata_field("").is_ok());
+
+        let max_len = usize::try_from(MAX_VALIDATOR_METADATA_LEN).unwrap();
+        assert!(validate_metadata_field(&"a".repeat(max_len)).is_ok());
+        assert!(matches!(
+            validate_metadata_field(&"a".repeat(max_len + 1)),
+            Err(MetadataError::TooLong(_))
+        ));
+        assert!(matches!(
+            validate_metadata_field("name\nwith newline"),
+            Err(MetadataError::InvalidCharacters)
+        ));
+    }
 }
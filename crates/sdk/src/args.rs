@@ -15,7 +15,8 @@ use namada_core::storage::{BlockHeight, Epoch};
 use namada_core::time::DateTimeUtc;
 use namada_core::{storage, token};
 use namada_governance::cli::onchain::{
-    DefaultProposal, PgfFundingProposal, PgfStewardProposal,
+    DefaultProposal, EthBridgeParamsProposal, ParameterChangesProposal,
+    PgfFundingProposal, PgfStewardProposal,
 };
 use namada_tx::data::GasLimit;
 use namada_tx::Memo;
@@ -239,6 +240,9 @@ pub struct TxTransfer<C: NamadaTypes = SdkTypes> {
     pub token: C::Address,
     /// Transferred token amount
     pub amount: InputAmount,
+    /// Number of times a shielded transfer is rebuilt against the new
+    /// conversions if the epoch changes before it is applied
+    pub masp_rebuild_retries: u8,
     /// Path to the TX WASM code file
     pub tx_code_path: PathBuf,
 }
@@ -276,6 +280,15 @@ impl<C: NamadaTypes> TxTransfer<C> {
         Self { amount, ..self }
     }
 
+    /// Number of times a shielded transfer is rebuilt against the new
+    /// conversions if the epoch changes before it is applied
+    pub fn masp_rebuild_retries(self, masp_rebuild_retries: u8) -> Self {
+        Self {
+            masp_rebuild_retries,
+            ..self
+        }
+    }
+
     /// Path to the TX WASM code file
     pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
         Self {
@@ -296,6 +309,61 @@ impl TxTransfer {
     }
 }
 
+/// Arguments to shield all the transparent balances of an address
+#[derive(Clone, Debug)]
+pub struct TxShieldAll<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Address whose transparent balances are shielded
+    pub source: C::Address,
+    /// Payment address receiving the shielded balances
+    pub target: PaymentAddress,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxShieldAll<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxShieldAll {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxShieldAll<C> {
+    /// Address whose transparent balances are shielded
+    pub fn source(self, source: C::Address) -> Self {
+        Self { source, ..self }
+    }
+
+    /// Payment address receiving the shielded balances
+    pub fn target(self, target: PaymentAddress) -> Self {
+        Self { target, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxShieldAll {
+    /// Make the transfers shielding all the transparent balances of the source
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<Vec<TxTransfer>> {
+        tx::build_shield_all(context, self).await
+    }
+}
+
 /// IBC transfer transaction arguments
 #[derive(Clone, Debug)]
 pub struct TxIbcTransfer<C: NamadaTypes = SdkTypes> {
@@ -431,6 +499,10 @@ pub struct InitProposal<C: NamadaTypes = SdkTypes> {
     pub is_pgf_stewards: bool,
     /// Flag if proposal is of type Pgf funding
     pub is_pgf_funding: bool,
+    /// Flag if proposal is of type Ethereum bridge parameters
+    pub is_eth_bridge_params: bool,
+    /// Flag if proposal is of type protocol parameter changes
+    pub is_parameter_changes: bool,
     /// Path to the tx WASM file
     pub tx_code_path: PathBuf,
 }
@@ -472,6 +544,22 @@ impl<C: NamadaTypes> InitProposal<C> {
         }
     }
 
+    /// Flag if proposal is of type Ethereum bridge parameters
+    pub fn is_eth_bridge_params(self, is_eth_bridge_params: bool) -> Self {
+        Self {
+            is_eth_bridge_params,
+            ..self
+        }
+    }
+
+    /// Flag if proposal is of type protocol parameter changes
+    pub fn is_parameter_changes(self, is_parameter_changes: bool) -> Self {
+        Self {
+            is_parameter_changes,
+            ..self
+        }
+    }
+
     /// Path to the tx WASM file
     pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
         Self {
@@ -534,6 +622,63 @@ impl InitProposal {
                 })?;
 
             tx::build_pgf_stewards_proposal(context, self, proposal).await
+        } else if self.is_eth_bridge_params {
+            let proposal = EthBridgeParamsProposal::try_from(
+                self.proposal_data.as_ref(),
+            )
+            .map_err(|e| {
+                crate::error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                    e.to_string(),
+                )
+            })?;
+            let nam_address = context.native_token();
+            let author_balance = rpc::get_token_balance(
+                context.client(),
+                &nam_address,
+                &proposal.proposal.author,
+            )
+            .await?;
+            let proposal = proposal
+                .validate(
+                    &governance_parameters,
+                    current_epoch,
+                    author_balance,
+                    self.tx.force,
+                )
+                .map_err(|e| {
+                    crate::error::TxSubmitError::InvalidProposal(e.to_string())
+                })?;
+
+            tx::build_eth_bridge_params_proposal(context, self, proposal).await
+        } else if self.is_parameter_changes {
+            let proposal = ParameterChangesProposal::try_from(
+                self.proposal_data.as_ref(),
+            )
+            .map_err(|e| {
+                crate::error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                    e.to_string(),
+                )
+            })?;
+            let nam_address = context.native_token();
+            let author_balance = rpc::get_token_balance(
+                context.client(),
+                &nam_address,
+                &proposal.proposal.author,
+            )
+            .await?;
+            let proposal = proposal
+                .validate(
+                    &governance_parameters,
+                    current_epoch,
+                    author_balance,
+                    &nam_address,
+                    self.tx.force,
+                )
+                .map_err(|e| {
+                    crate::error::TxSubmitError::InvalidProposal(e.to_string())
+                })?;
+
+            tx::build_parameter_changes_proposal(context, self, proposal).await
         } else {
             let proposal = DefaultProposal::try_from(
                 self.proposal_data.as_ref(),
@@ -928,6 +1073,219 @@ impl TxUpdateAccount {
     }
 }
 
+/// Transaction to set or remove a spender's allowance on an account arguments
+#[derive(Clone, Debug)]
+pub struct TxUpdateAllowance<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Address of the account granting the allowance
+    pub owner: C::Address,
+    /// Address of the spender
+    pub spender: C::Address,
+    /// The token the allowance applies to
+    pub token: C::Address,
+    /// The maximum amount the spender may spend per day. `None` removes the
+    /// allowance.
+    pub daily_cap: Option<token::Amount>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxUpdateAllowance<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxUpdateAllowance {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxUpdateAllowance<C> {
+    /// Address of the account granting the allowance
+    pub fn owner(self, owner: C::Address) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// Address of the spender
+    pub fn spender(self, spender: C::Address) -> Self {
+        Self { spender, ..self }
+    }
+
+    /// The token the allowance applies to
+    pub fn token(self, token: C::Address) -> Self {
+        Self { token, ..self }
+    }
+
+    /// The maximum amount the spender may spend per day
+    pub fn daily_cap(self, daily_cap: token::Amount) -> Self {
+        Self {
+            daily_cap: Some(daily_cap),
+            ..self
+        }
+    }
+
+    /// Remove the allowance
+    pub fn remove(self) -> Self {
+        Self {
+            daily_cap: None,
+            ..self
+        }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxUpdateAllowance {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_update_allowance(context, self).await
+    }
+}
+
+/// Transaction to spend tokens from an account within an allowance arguments
+#[derive(Clone, Debug)]
+pub struct TxSpendAllowance<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Address of the account granting the allowance
+    pub owner: C::Address,
+    /// Address of the spender
+    pub spender: C::Address,
+    /// Address of the recipient
+    pub target: C::Address,
+    /// The token to transfer
+    pub token: C::Address,
+    /// The amount to transfer
+    pub amount: token::Amount,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxSpendAllowance<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxSpendAllowance {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxSpendAllowance<C> {
+    /// Address of the account granting the allowance
+    pub fn owner(self, owner: C::Address) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// Address of the spender
+    pub fn spender(self, spender: C::Address) -> Self {
+        Self { spender, ..self }
+    }
+
+    /// Address of the recipient
+    pub fn target(self, target: C::Address) -> Self {
+        Self { target, ..self }
+    }
+
+    /// The token to transfer
+    pub fn token(self, token: C::Address) -> Self {
+        Self { token, ..self }
+    }
+
+    /// The amount to transfer
+    pub fn amount(self, amount: token::Amount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxSpendAllowance {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_spend_allowance(context, self).await
+    }
+}
+
+/// Transaction for the fee price feed to update the fee conversion rates
+/// arguments
+#[derive(Clone, Debug)]
+pub struct TxUpdateFeeConversionRates<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Address of the fee price feed
+    pub price_feed: C::Address,
+    /// The new amounts of the fee tokens worth a unit of the native token
+    pub rates: Vec<(C::Address, Dec)>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxUpdateFeeConversionRates<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxUpdateFeeConversionRates {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxUpdateFeeConversionRates<C> {
+    /// Address of the fee price feed
+    pub fn price_feed(self, price_feed: C::Address) -> Self {
+        Self { price_feed, ..self }
+    }
+
+    /// The new amounts of the fee tokens worth a unit of the native token
+    pub fn rates(self, rates: Vec<(C::Address, Dec)>) -> Self {
+        Self { rates, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxUpdateFeeConversionRates {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_update_fee_conversion_rates(context, self).await
+    }
+}
+
 /// Bond arguments
 #[derive(Clone, Debug)]
 pub struct Bond<C: NamadaTypes = SdkTypes> {
@@ -940,6 +1298,10 @@ pub struct Bond<C: NamadaTypes = SdkTypes> {
     /// Source address for delegations. For self-bonds, the validator is
     /// also the source.
     pub source: Option<C::Address>,
+    /// Additional validator addresses and amounts to bond in the same
+    /// transaction. The gas limit of the transaction must cover all the
+    /// bonds.
+    pub extra_bonds: Vec<(C::Address, token::Amount)>,
     /// Path to the TX WASM code file
     pub tx_code_path: PathBuf,
 }
@@ -976,6 +1338,17 @@ impl<C: NamadaTypes> Bond<C> {
         }
     }
 
+    /// Additional validator address and amount to bond in the same
+    /// transaction
+    pub fn extra_bond(
+        mut self,
+        validator: C::Address,
+        amount: token::Amount,
+    ) -> Self {
+        self.extra_bonds.push((validator, amount));
+        self
+    }
+
     /// Path to the TX WASM code file
     pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
         Self {
@@ -1335,6 +1708,9 @@ pub struct QueryBalance<C: NamadaTypes = SdkTypes> {
     pub token: C::Address,
     /// Whether not to convert balances
     pub no_conversions: bool,
+    /// The block height at which to query the balance. Only supported for
+    /// transparent balances.
+    pub height: Option<BlockHeight>,
 }
 
 /// Query historical transfer(s)
@@ -1956,6 +2332,8 @@ pub struct QueryFindValidator<C: NamadaTypes = SdkTypes> {
 pub struct QueryRawBytes<C: NamadaTypes = SdkTypes> {
     /// The storage key to query
     pub storage_key: storage::Key,
+    /// The block height at which to query the key
+    pub height: Option<BlockHeight>,
     /// Common query args
     pub query: Query<C>,
 }
@@ -2003,6 +2381,16 @@ pub struct Tx<C: NamadaTypes = SdkTypes> {
     pub force: bool,
     /// Do not wait for the transaction to be added to the blockchain
     pub broadcast_only: bool,
+    /// Check whether the transaction was already applied on chain before
+    /// submitting it, and skip the submission if so
+    pub skip_if_applied: bool,
+    /// Display a summary of the transaction and ask for confirmation before
+    /// submitting it
+    pub confirm: bool,
+    /// Wait for the transaction to be applied over the websocket event
+    /// subscription of the ledger node, rather than polling it. Requires
+    /// the `websocket-client` feature, without which the node is polled.
+    pub subscribe_events: bool,
     /// The address of the ledger node as host:port
     pub ledger_address: C::ConfigRpcTendermintAddress,
     /// If any new account is initialized by the tx, use the given alias to
@@ -2079,6 +2467,27 @@ pub trait TxBuilder<C: NamadaTypes>: Sized {
             ..x
         })
     }
+    /// Check whether the transaction was already applied on chain before
+    /// submitting it, and skip the submission if so
+    fn skip_if_applied(self, skip_if_applied: bool) -> Self {
+        self.tx(|x| Tx {
+            skip_if_applied,
+            ..x
+        })
+    }
+    /// Wait for the transaction to be applied over the websocket event
+    /// subscription of the ledger node, rather than polling it
+    fn subscribe_events(self, subscribe_events: bool) -> Self {
+        self.tx(|x| Tx {
+            subscribe_events,
+            ..x
+        })
+    }
+    /// Display a summary of the transaction and ask for confirmation before
+    /// submitting it
+    fn confirm(self, confirm: bool) -> Self {
+        self.tx(|x| Tx { confirm, ..x })
+    }
     /// The address of the ledger node as host:port
     fn ledger_address(self, ledger_address: C::TendermintAddress) -> Self {
         self.tx(|x| Tx {
@@ -2203,6 +2612,9 @@ pub struct KeyGen {
     pub prompt_bip39_passphrase: bool,
     /// Allow non-compliant derivation path
     pub allow_non_compliant: bool,
+    /// The block height before which a generated spending key cannot have
+    /// received any notes
+    pub birthday: Option<BlockHeight>,
 }
 
 /// Wallet restore key and implicit address arguments
@@ -2305,6 +2717,9 @@ pub struct KeyAddressAdd {
     pub value: String,
     /// Don't encrypt the key
     pub unsafe_dont_encrypt: bool,
+    /// The block height before which an added spending or viewing key
+    /// cannot have received any notes
+    pub birthday: Option<BlockHeight>,
 }
 
 /// Wallet key / address remove arguments
@@ -2451,6 +2866,104 @@ impl EthereumBridgePool {
     }
 }
 
+/// Cancel a pending transfer in the Ethereum bridge pool.
+#[derive(Clone, Debug)]
+pub struct CancelEthereumTransfer<C: NamadaTypes = SdkTypes> {
+    /// The args for building a tx to the bridge pool
+    pub tx: Tx<C>,
+    /// The keccak hash of the pending transfer to cancel
+    pub transfer: KeccakHash,
+    /// Path to the tx WASM code file
+    pub code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for CancelEthereumTransfer<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        CancelEthereumTransfer {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> CancelEthereumTransfer<C> {
+    /// The keccak hash of the pending transfer to cancel
+    pub fn transfer(self, transfer: KeccakHash) -> Self {
+        Self { transfer, ..self }
+    }
+
+    /// Path to the tx WASM code file
+    pub fn code_path(self, code_path: PathBuf) -> Self {
+        Self { code_path, ..self }
+    }
+}
+
+impl CancelEthereumTransfer {
+    /// Build a transaction from this builder
+    pub async fn build(
+        self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        bridge_pool::cancel_eth_transfer(context, self).await
+    }
+}
+
+/// Top up the gas fees of a pending transfer in the Ethereum bridge pool.
+#[derive(Clone, Debug)]
+pub struct TopUpEthereumTransfer<C: NamadaTypes = SdkTypes> {
+    /// The args for building a tx to the bridge pool
+    pub tx: Tx<C>,
+    /// The keccak hash of the pending transfer to top up
+    pub transfer: KeccakHash,
+    /// The amount of gas fees to add, in the token the gas
+    /// fees of the transfer are paid in
+    pub amount: InputAmount,
+    /// Path to the tx WASM code file
+    pub code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TopUpEthereumTransfer<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TopUpEthereumTransfer {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TopUpEthereumTransfer<C> {
+    /// The keccak hash of the pending transfer to top up
+    pub fn transfer(self, transfer: KeccakHash) -> Self {
+        Self { transfer, ..self }
+    }
+
+    /// The amount of gas fees to add
+    pub fn amount(self, amount: InputAmount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Path to the tx WASM code file
+    pub fn code_path(self, code_path: PathBuf) -> Self {
+        Self { code_path, ..self }
+    }
+}
+
+impl TopUpEthereumTransfer {
+    /// Build a transaction from this builder
+    pub async fn build(
+        self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        bridge_pool::top_up_eth_transfer(context, self).await
+    }
+}
+
 /// Bridge pool proof arguments.
 #[derive(Debug, Clone)]
 pub struct BridgePoolProof<C: NamadaTypes = SdkTypes> {
@@ -2466,6 +2979,9 @@ pub struct BridgePoolProof<C: NamadaTypes = SdkTypes> {
     /// the Bridge pool, to compensate the Ethereum relay
     /// procedure.
     pub relayer: Address,
+    /// Don't prompt for confirmation if some of the transfers have likely
+    /// been relayed already, and report them in the response instead.
+    pub auto_confirm: bool,
 }
 
 /// Arguments to an Ethereum Bridge pool relay operation.
@@ -2496,6 +3012,18 @@ pub struct RelayBridgePoolProof<C: NamadaTypes = SdkTypes> {
     /// Safe mode overrides keyboard interrupt signals, to ensure
     /// Ethereum transfers aren't canceled midway through.
     pub safe_mode: bool,
+    /// Don't prompt for confirmation if some of the transfers have likely
+    /// been relayed already, and relay them anyway.
+    pub auto_confirm: bool,
+}
+
+/// Arguments to a relayer health check.
+#[derive(Debug, Clone)]
+pub struct BridgePoolHealthCheck<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// The Ethereum RPC endpoint.
+    pub eth_rpc_endpoint: C::EthereumAddress,
 }
 
 /// Bridge validator set arguments.
@@ -322,6 +322,18 @@ impl<S: SleepStrategy> Sleep<S> {
     }
 }
 
+/// Run a future until the given deadline, returning [`Error::Elapsed`]
+/// if it does not complete in time.
+#[inline]
+pub async fn timeout_at<F: Future>(
+    deadline: Instant,
+    future: F,
+) -> Result<F::Output, Error> {
+    internal_timeout_at(deadline, future)
+        .await
+        .map_err(|_| Error::Elapsed)
+}
+
 /// Pause the active task for the given duration.
 #[inline]
 pub async fn sleep(dur: Duration) {
@@ -3,6 +3,7 @@
 use namada_core::address::Address;
 use namada_core::dec::Dec;
 use namada_core::ethereum_events::EthAddress;
+use namada_core::keccak::KeccakHash;
 use namada_core::storage::Epoch;
 use namada_core::{arith, storage};
 use namada_events::EventError;
@@ -65,6 +66,16 @@ pub enum QueryError {
     /// synchronizing with the network.
     #[error("Node is still catching up with the network")]
     CatchingUp,
+    /// The queried node does not serve the trusted chain.
+    #[error("The queried node does not serve the trusted chain: {0}")]
+    UntrustedChain(String),
+    /// The deadline was exceeded while waiting for the node to reach
+    /// some state.
+    #[error("Timed out waiting for {0}")]
+    Timeout(String),
+    /// A storage proof could not be verified.
+    #[error("Invalid storage proof: {0}")]
+    InvalidProof(String),
 }
 
 /// Errors that deal with Decoding, Encoding, or Conversions
@@ -108,6 +119,21 @@ pub enum TxSubmitError {
     /// Error during broadcasting a transaction
     #[error("Encountered error while broadcasting transaction: {0}")]
     TxBroadcast(RpcError),
+    /// The transaction was rejected by the mempool of the node
+    #[error("The transaction was rejected from the mempool (code {0}): {1}")]
+    MempoolRejected(u32, String),
+    /// An invalid number of endpoints was requested to accept a transaction
+    #[error(
+        "Invalid broadcast quorum {0}, expected a value between 1 and the \
+         number of endpoints ({1})"
+    )]
+    InvalidBroadcastQuorum(usize, usize),
+    /// Not enough endpoints accepted the transaction into their mempool
+    #[error(
+        "The transaction was accepted by {0} endpoint(s), but {1} were \
+         required. Errors: {2}"
+    )]
+    BroadcastQuorumNotReached(usize, usize, String),
     /// Invalid commission rate set
     #[error("Invalid new commission rate, received {0}")]
     InvalidCommissionRate(Dec),
@@ -156,6 +182,15 @@ pub enum TxSubmitError {
     /// Bond amount is zero
     #[error("The requested bond amount is 0.")]
     BondIsZero,
+    /// Bonding to multiple validators in one tx requires a source
+    #[error(
+        "A source address must be given to also bond to validator {0} in the \
+         same transaction."
+    )]
+    MultipleBondsWithoutSource(Address),
+    /// The same validator was given more than once in a bond
+    #[error("Validator {0} was given more than once in the bond.")]
+    DuplicateBondValidator(Address),
     /// Unbond amount is zero
     #[error("The requested unbond amount is 0.")]
     UnbondIsZero,
@@ -219,6 +254,18 @@ pub enum TxSubmitError {
          transferred. Amount to transfer is {1} {2}"
     )]
     NegativeBalanceAfterTransfer(Box<Address>, String, Box<Address>),
+    /// Insufficient shielded balance
+    #[error(
+        "The shielded balance of the source spending key is lower than the \
+         amount to be transferred. Amount to transfer is {0} {1}"
+    )]
+    InsufficientShieldedFunds(String, Box<Address>),
+    /// Invalid MASP conversion
+    #[error(
+        "The conversion queried for the MASP asset type {0} is invalid, as it \
+         requires a zero amount of the asset"
+    )]
+    InvalidMaspConversion(String),
     /// No Balance found for token
     #[error("{0}")]
     MaspError(String),
@@ -278,6 +325,26 @@ pub enum TxSubmitError {
     /// Invalid owner account
     #[error("The source account {0} is not valid or doesn't exist.")]
     InvalidAccount(String),
+    /// No allowance of the token was granted to the spender
+    #[error("The account {0} has not granted {1} an allowance of token {2}.")]
+    AllowanceNotFound(Address, Address, Address),
+    /// The amount to spend is larger than the allowance's daily cap
+    #[error("The amount to spend {0} exceeds the allowance's daily cap {1}.")]
+    AllowanceExceeded(String, String),
+    /// The address is not the fee price feed
+    #[error("The address {0} is not the fee price feed.")]
+    NotFeePriceFeed(Address),
+    /// The token is not priced by conversion for fee payment
+    #[error(
+        "The token {0} is not priced by conversion from the native token for \
+         fee payment."
+    )]
+    FeeTokenNotConverted(Address),
+    /// The fee conversion rate is not positive
+    #[error(
+        "The fee conversion rate {1} of token {0} must be greater than zero."
+    )]
+    InvalidFeeConversionRate(Address, Dec),
     /// The redelegation amount is larger than the remaining bond amount
     #[error(
         "The redelegation amount is larger than the remaining bond amount. \
@@ -364,7 +431,13 @@ pub enum EthereumBridgeError {
     /// Exceeded token caps error.
     #[error("ERC20 token caps exceeded: {0}")]
     Erc20TokenCapsExceeded(EthAddress),
+    /// Exceeded epoch caps error.
+    #[error("ERC20 epoch cap exceeded: {0}")]
+    Erc20EpochCapExceeded(EthAddress),
     /// Transfer already in pool error.
     #[error("An identical transfer is already present in the Bridge pool")]
     TransferAlreadyInPool,
+    /// Transfer not in pool error.
+    #[error("The transfer {0} is not in the Bridge pool")]
+    TransferNotInPool(KeccakHash),
 }
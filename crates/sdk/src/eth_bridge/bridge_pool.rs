@@ -13,20 +13,25 @@ use namada_core::arith::checked;
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::eth_abi::Encode;
 use namada_core::eth_bridge_pool::{
-    erc20_token_address, GasFee, PendingTransfer, TransferToEthereum,
-    TransferToEthereumKind,
+    erc20_token_address, GasFee, PendingTransfer, TransferFeeTopUp,
+    TransferToEthereum, TransferToEthereumKind,
 };
 use namada_core::ethereum_events::EthAddress;
 use namada_core::keccak::KeccakHash;
 use namada_core::voting_power::FractionalVotingPower;
-use namada_ethereum_bridge::storage::bridge_pool::get_pending_key;
+use namada_ethereum_bridge::storage::bridge_pool::{
+    get_nonce_key, get_pending_key,
+};
 use namada_token::storage_key::balance_key;
 use namada_token::Amount;
 use namada_tx::Tx;
 use owo_colors::OwoColorize;
 use serde::Serialize;
 
-use super::{block_on_eth_sync, eth_sync_or_exit, BlockOnEthSync};
+use super::{
+    block_on_eth_sync, eth_sync_or_exit, eth_syncing_status, BlockOnEthSync,
+    SyncStatus,
+};
 use crate::control_flow::install_shutdown_signal;
 use crate::control_flow::time::{Duration, Instant};
 use crate::error::{
@@ -117,6 +122,153 @@ pub async fn build_bridge_pool_tx(
     Ok((tx, signing_data))
 }
 
+/// Craft a transaction that cancels a pending transfer in the Ethereum
+/// bridge pool, refunding its escrowed gas fees and tokens.
+pub async fn cancel_eth_transfer(
+    context: &impl Namada,
+    args::CancelEthereumTransfer {
+        tx: tx_args,
+        transfer: transfer_hash,
+        code_path,
+    }: args::CancelEthereumTransfer,
+) -> Result<(Tx, SigningTxData), Error> {
+    let transfer = query_pending_transfer(context, transfer_hash).await?;
+    let sender = transfer.transfer.sender.clone();
+    let (tx_code_hash, signing_data) = futures::try_join!(
+        query_wasm_code_hash(context, code_path.to_string_lossy()),
+        aux_signing_data(
+            context,
+            &tx_args,
+            // token owner
+            Some(sender.clone()),
+            // tx signer
+            Some(sender),
+        ),
+    )?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, &tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let chain_id = tx_args
+        .chain_id
+        .clone()
+        .ok_or_else(|| Error::Other("No chain id available".into()))?;
+
+    let mut tx = Tx::new(chain_id, tx_args.expiration.to_datetime());
+    if let Some(memo) = &tx_args.memo {
+        tx.add_memo(memo);
+    }
+    tx.add_code_from_hash(
+        tx_code_hash,
+        Some(code_path.to_string_lossy().into_owned()),
+    )
+    .add_data(transfer);
+
+    prepare_tx(
+        &tx_args,
+        &mut tx,
+        fee_amount,
+        signing_data.fee_payer.clone(),
+    )
+    .await?;
+
+    Ok((tx, signing_data))
+}
+
+/// Craft a transaction that tops up the gas fees of a pending transfer
+/// in the Ethereum bridge pool.
+pub async fn top_up_eth_transfer(
+    context: &impl Namada,
+    args::TopUpEthereumTransfer {
+        tx: tx_args,
+        transfer: transfer_hash,
+        amount,
+        code_path,
+    }: args::TopUpEthereumTransfer,
+) -> Result<(Tx, SigningTxData), Error> {
+    let transfer = query_pending_transfer(context, transfer_hash).await?;
+    let GasFee {
+        token: fee_token,
+        payer,
+        ..
+    } = transfer.gas_fee;
+    let validate_fee_amount =
+        validate_amount(context, amount, &fee_token, tx_args.force).map(
+            |result| {
+                result.map_err(|e| {
+                    Error::Other(format!(
+                        "Failed to validate Bridge pool fee amount: {e}",
+                    ))
+                })
+            },
+        );
+    let (amount, tx_code_hash, signing_data) = futures::try_join!(
+        validate_fee_amount,
+        query_wasm_code_hash(context, code_path.to_string_lossy()),
+        aux_signing_data(
+            context,
+            &tx_args,
+            // gas fee payer
+            Some(payer.clone()),
+            // tx signer
+            Some(payer),
+        ),
+    )?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, &tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let chain_id = tx_args
+        .chain_id
+        .clone()
+        .ok_or_else(|| Error::Other("No chain id available".into()))?;
+
+    let mut tx = Tx::new(chain_id, tx_args.expiration.to_datetime());
+    if let Some(memo) = &tx_args.memo {
+        tx.add_memo(memo);
+    }
+    tx.add_code_from_hash(
+        tx_code_hash,
+        Some(code_path.to_string_lossy().into_owned()),
+    )
+    .add_data(TransferFeeTopUp {
+        transfer: transfer_hash,
+        amount: amount.amount(),
+    });
+
+    prepare_tx(
+        &tx_args,
+        &mut tx,
+        fee_amount,
+        signing_data.fee_payer.clone(),
+    )
+    .await?;
+
+    Ok((tx, signing_data))
+}
+
+/// Look up the pending transfer with the given hash in the Ethereum
+/// bridge pool.
+async fn query_pending_transfer(
+    context: &impl Namada,
+    transfer_hash: KeccakHash,
+) -> Result<PendingTransfer, Error> {
+    RPC.shell()
+        .eth_bridge()
+        .read_ethereum_bridge_pool(context.client())
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                e.to_string(),
+            ))
+        })?
+        .into_iter()
+        .find(|transfer| transfer.keccak256() == transfer_hash)
+        .ok_or(Error::EthereumBridge(
+            EthereumBridgeError::TransferNotInPool(transfer_hash),
+        ))
+}
+
 /// Perform client validation checks on a Bridge pool transfer.
 #[allow(clippy::too_many_arguments)]
 async fn validate_bridge_pool_tx(
@@ -248,6 +400,32 @@ async fn validate_bridge_pool_tx(
         }
     }
 
+    // validate the epoch caps of ERC20 assets
+    if let TransferToEthereumKind::Erc20 = &transfer.transfer.kind {
+        let utilization = RPC
+            .shell()
+            .eth_bridge()
+            .get_erc20_epoch_utilization(
+                context.client(),
+                &transfer.transfer.asset,
+            )
+            .await
+            .map_err(|e| {
+                Error::Query(QueryError::General(format!(
+                    "Failed to read the Bridge pool utilization of {}: {e}",
+                    transfer.transfer.asset
+                )))
+            })?;
+
+        if utilization.exceeds_epoch_cap(transfer.transfer.amount)? {
+            return Err(Error::EthereumBridge(
+                EthereumBridgeError::Erc20EpochCapExceeded(
+                    transfer.transfer.asset,
+                ),
+            ));
+        }
+    }
+
     // validate balances
     let maybe_balance_error = if token_addr == transfer.gas_fee.token {
         let expected_debit =
@@ -420,12 +598,15 @@ pub async fn query_relay_progress(
 }
 
 /// Internal method to construct a proof that a set of transfers are in the
-/// bridge pool.
+/// bridge pool. Unless `auto_confirm` is set, the user is asked whether to
+/// proceed if some of the transfers have likely been relayed already. The
+/// hashes of these transfers are returned along with the proof.
 async fn construct_bridge_pool_proof(
     client: &(impl Client + Sync),
     io: &(impl Io + MaybeSync),
     args: GenBridgePoolProofReq<'_, '_>,
-) -> Result<GenBridgePoolProofRsp, Error> {
+    auto_confirm: bool,
+) -> Result<(GenBridgePoolProofRsp, Vec<KeccakHash>), Error> {
     let in_progress = RPC
         .shell()
         .eth_bridge()
@@ -461,6 +642,8 @@ async fn construct_bridge_pool_proof(
              validator signatures behind them in Namada; thus they are still \
              in the Bridge pool:\n{warnings:?}",
         );
+    }
+    if !warnings.is_empty() && !auto_confirm {
         display!(io, "\nDo you wish to proceed? (y/n): ");
         io.flush();
         loop {
@@ -502,7 +685,7 @@ async fn construct_bridge_pool_proof(
             ))
         })?;
 
-    Ok(response.data)
+    Ok((response.data, warnings))
 }
 
 /// A response from construction a bridge pool proof.
@@ -512,6 +695,8 @@ struct BridgePoolProofResponse {
     relayer_address: Address,
     total_fees: HashMap<Address, Amount>,
     abi_encoded_args: Vec<u8>,
+    /// Hashes of the transfers that have likely been relayed already
+    warnings: Vec<KeccakHash>,
 }
 
 /// Construct a merkle proof of a batch of transfers in
@@ -522,10 +707,13 @@ pub async fn construct_proof(
     io: &(impl Io + MaybeSync),
     args: args::BridgePoolProof,
 ) -> Result<(), Error> {
-    let GenBridgePoolProofRsp {
-        abi_encoded_args,
-        appendices,
-    } = construct_bridge_pool_proof(
+    let (
+        GenBridgePoolProofRsp {
+            abi_encoded_args,
+            appendices,
+        },
+        warnings,
+    ) = construct_bridge_pool_proof(
         client,
         io,
         GenBridgePoolProofReq {
@@ -533,6 +721,7 @@ pub async fn construct_proof(
             relayer: Cow::Borrowed(&args.relayer),
             with_appendix: true,
         },
+        args.auto_confirm,
     )
     .await?;
     let resp = BridgePoolProofResponse {
@@ -557,6 +746,7 @@ pub async fn construct_proof(
             .transpose()?
             .unwrap_or_default(),
         abi_encoded_args,
+        warnings,
     };
     display_line!(
         io,
@@ -597,9 +787,12 @@ where
         eth_sync_or_exit(&*eth_client, io).await?;
     }
 
-    let GenBridgePoolProofRsp {
-        abi_encoded_args, ..
-    } = construct_bridge_pool_proof(
+    let (
+        GenBridgePoolProofRsp {
+            abi_encoded_args, ..
+        },
+        _warnings,
+    ) = construct_bridge_pool_proof(
         client,
         io,
         GenBridgePoolProofReq {
@@ -607,6 +800,7 @@ where
             relayer: Cow::Owned(args.relayer),
             with_appendix: false,
         },
+        args.auto_confirm,
     )
     .await?;
     let bridge =
@@ -647,39 +841,14 @@ where
             ))
         })?;
 
-    match bp_proof.batch_nonce.cmp(&contract_nonce) {
-        Ordering::Equal => {}
-        Ordering::Less => {
-            let error = "Error".on_red();
-            let error = error.bold();
-            let error = error.blink();
-            display_line!(
-                io,
-                "{error}: The Bridge pool nonce in the smart contract is \
-                 {contract_nonce}, while the nonce in Namada is still {}. A \
-                 relay of the former one has already happened, but a proof \
-                 has yet to be crafted in Namada.",
-                bp_proof.batch_nonce
-            );
-            return Err(Error::EthereumBridge(
-                EthereumBridgeError::InvalidBpNonce,
-            ));
-        }
-        Ordering::Greater => {
-            let error = "Error".on_red();
-            let error = error.bold();
-            let error = error.blink();
-            display_line!(
-                io,
-                "{error}: The Bridge pool nonce in the smart contract is \
-                 {contract_nonce}, while the nonce in Namada is still {}. \
-                 Somehow, Namada's nonce is ahead of the contract's nonce!",
-                bp_proof.batch_nonce
-            );
-            return Err(Error::EthereumBridge(
-                EthereumBridgeError::InvalidBpNonce,
-            ));
-        }
+    if let Some(mismatch) =
+        bp_nonce_mismatch(bp_proof.batch_nonce, contract_nonce)
+    {
+        let error = "Error".on_red();
+        let error = error.bold();
+        let error = error.blink();
+        display_line!(io, "{error}: {mismatch}");
+        return Err(Error::EthereumBridge(EthereumBridgeError::InvalidBpNonce));
     }
 
     let mut relay_op =
@@ -708,6 +877,205 @@ where
     Ok(())
 }
 
+/// Describe the mismatch between the Bridge pool nonce in Namada and
+/// the one in the Bridge smart contract, if there is any.
+fn bp_nonce_mismatch(
+    namada_nonce: ethers::types::U256,
+    contract_nonce: ethers::types::U256,
+) -> Option<String> {
+    match namada_nonce.cmp(&contract_nonce) {
+        Ordering::Equal => None,
+        Ordering::Less => Some(format!(
+            "The Bridge pool nonce in the smart contract is \
+             {contract_nonce}, while the nonce in Namada is still \
+             {namada_nonce}. A relay of the former one has already happened, \
+             but a proof has yet to be crafted in Namada."
+        )),
+        Ordering::Greater => Some(format!(
+            "The Bridge pool nonce in the smart contract is \
+             {contract_nonce}, while the nonce in Namada is still \
+             {namada_nonce}. Somehow, Namada's nonce is ahead of the \
+             contract's nonce!"
+        )),
+    }
+}
+
+/// The outcome of one of the checks of [`check_relayer_health`].
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthCheck {
+    /// The name of the check.
+    pub check: &'static str,
+    /// Whether the check succeeded.
+    pub healthy: bool,
+    /// A description of the outcome of the check.
+    pub details: String,
+}
+
+impl HealthCheck {
+    fn pass(check: &'static str, details: String) -> Self {
+        Self {
+            check,
+            healthy: true,
+            details,
+        }
+    }
+
+    fn fail(check: &'static str, details: String) -> Self {
+        Self {
+            check,
+            healthy: false,
+            details,
+        }
+    }
+}
+
+/// A json serializable report on the ability of a relayer to relay
+/// Bridge pool proofs to Ethereum.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    /// Whether all the checks succeeded.
+    pub healthy: bool,
+    /// The outcome of each check.
+    pub checks: Vec<HealthCheck>,
+}
+
+/// Check that both the Namada and the Ethereum nodes are in a state
+/// where Bridge pool proofs can be relayed, running the same checks as
+/// [`relay_bridge_pool_proof`]:
+///
+/// - the Namada node is reachable and has caught up with the chain,
+/// - the Ethereum node has finished synchronizing,
+/// - the address of the Bridge contract can be read from Namada,
+/// - the Bridge pool nonce in Namada matches the contract's nonce.
+///
+/// A failed check does not prevent the remaining ones from running.
+/// Prints out a json payload.
+pub async fn check_relayer_health<E>(
+    eth_client: Arc<E>,
+    client: &(impl Client + Sync),
+    io: &impl Io,
+) -> Result<HealthReport, Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Debug + std::fmt::Display,
+{
+    let mut checks = vec![];
+
+    checks.push(match client.status().await {
+        Ok(status) if status.sync_info.catching_up => HealthCheck::fail(
+            "namada_rpc",
+            format!(
+                "The Namada node is still catching up, at block height {}",
+                status.sync_info.latest_block_height
+            ),
+        ),
+        Ok(status) => HealthCheck::pass(
+            "namada_rpc",
+            format!(
+                "The Namada node is at block height {}",
+                status.sync_info.latest_block_height
+            ),
+        ),
+        Err(err) => HealthCheck::fail(
+            "namada_rpc",
+            format!("Unable to reach the Namada node: {err}"),
+        ),
+    });
+
+    checks.push(match eth_syncing_status(&*eth_client).await {
+        Ok(SyncStatus::AtHeight(height)) => HealthCheck::pass(
+            "ethereum_sync",
+            format!("The Ethereum node is synchronized at block {height}"),
+        ),
+        Ok(SyncStatus::Syncing) => HealthCheck::fail(
+            "ethereum_sync",
+            "The Ethereum node has not finished synchronizing".to_string(),
+        ),
+        Err(err) => HealthCheck::fail(
+            "ethereum_sync",
+            format!("Unable to query the Ethereum node: {err}"),
+        ),
+    });
+
+    let bridge =
+        match RPC.shell().eth_bridge().read_bridge_contract(client).await {
+            Ok(contract) => {
+                checks.push(HealthCheck::pass(
+                    "bridge_contract",
+                    format!(
+                        "The Bridge contract is deployed at {}",
+                        contract.address
+                    ),
+                ));
+                Some(Bridge::new(contract.address, eth_client))
+            }
+            Err(err) => {
+                checks.push(HealthCheck::fail(
+                    "bridge_contract",
+                    format!(
+                        "Unable to read the Bridge contract address: {err}"
+                    ),
+                ));
+                None
+            }
+        };
+
+    checks.push(match bridge {
+        Some(bridge) => {
+            let namada_nonce =
+                query_storage_value::<_, Uint>(client, &get_nonce_key()).await;
+            // NOTE: this operation costs no gas on Ethereum
+            let contract_nonce = bridge.transfer_to_erc_20_nonce().call().await;
+            match (namada_nonce, contract_nonce) {
+                (Ok(namada_nonce), Ok(contract_nonce)) => {
+                    match bp_nonce_mismatch(namada_nonce.into(), contract_nonce)
+                    {
+                        Some(mismatch) => {
+                            HealthCheck::fail("bridge_pool_nonce", mismatch)
+                        }
+                        None => HealthCheck::pass(
+                            "bridge_pool_nonce",
+                            format!(
+                                "The Bridge pool nonce is {contract_nonce} \
+                                 in both Namada and the smart contract"
+                            ),
+                        ),
+                    }
+                }
+                (Err(err), _) => HealthCheck::fail(
+                    "bridge_pool_nonce",
+                    format!(
+                        "Unable to read the Bridge pool nonce in Namada: {err}"
+                    ),
+                ),
+                (_, Err(err)) => HealthCheck::fail(
+                    "bridge_pool_nonce",
+                    format!(
+                        "Unable to read the Bridge pool nonce in the smart \
+                         contract: {err}"
+                    ),
+                ),
+            }
+        }
+        None => HealthCheck::fail(
+            "bridge_pool_nonce",
+            "Skipped, since the Bridge contract address is unknown".to_string(),
+        ),
+    });
+
+    let report = HealthReport {
+        healthy: checks.iter().all(|check| check.healthy),
+        checks,
+    };
+    display_line!(
+        io,
+        "{}",
+        serde_json::to_string_pretty(&report)
+            .map_err(|e| EncodingError::Serde(e.to_string()))?
+    );
+    Ok(report)
+}
+
 /// Query the status of a set of transfers to Ethreum, indexed
 /// by their keccak hash.
 ///
@@ -938,12 +1306,33 @@ mod recommendations {
             * signature_checks(voting_powers, &bp_root.signatures)?
             + valset_fee() * valset_size;
 
+        // relayers are paid the fee top-ups of a transfer along
+        // with its original gas fees
+        let mut signed_pool =
+            query_signed_bridge_pool(context.client(), context.io()).await?;
+        let fee_top_ups = RPC
+            .shell()
+            .eth_bridge()
+            .read_fee_top_ups(context.client())
+            .await
+            .map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                    e.to_string(),
+                ))
+            })?;
+        for pending in signed_pool.values_mut() {
+            if let Some(fee_top_up) = fee_top_ups.get(&pending.keccak256()) {
+                pending.gas_fee.amount =
+                    checked!(pending.gas_fee.amount + *fee_top_up)?;
+            }
+        }
+
         // we don't recommend transfers that have already been relayed
         let eligible = generate_eligible(
             context.io(),
             &args.conversion_table,
             &in_progress,
-            query_signed_bridge_pool(context.client(), context.io()).await?,
+            signed_pool,
         )?;
 
         let max_gas =
@@ -56,8 +56,9 @@ use namada_core::collections::HashSet;
 use namada_core::dec::Dec;
 use namada_core::ethereum_events::EthAddress;
 use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
+use namada_core::keccak::KeccakHash;
 use namada_core::key::*;
-use namada_core::masp::{TransferSource, TransferTarget};
+use namada_core::masp::{PaymentAddress, TransferSource, TransferTarget};
 use namada_tx::data::wrapper::GasLimit;
 use namada_tx::Tx;
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
@@ -65,18 +66,21 @@ use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use crate::io::Io;
 use crate::masp::{ShieldedContext, ShieldedUtils};
 use crate::rpc::{
-    denominate_amount, format_denominated_amount, query_native_token,
+    denominate_amount, fetch_chain_info, format_denominated_amount,
+    query_native_token, ChainInfo, TrustedBlock,
 };
 use crate::signing::SigningTxData;
 use crate::token::{DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
 use crate::tx::{
     ProcessTxResponse, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
-    TX_BRIDGE_POOL_WASM, TX_CHANGE_COMMISSION_WASM,
+    TX_BRIDGE_POOL_WASM, TX_CANCEL_BRIDGE_POOL_WASM, TX_CHANGE_COMMISSION_WASM,
     TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
     TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM,
     TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM,
-    TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM,
+    TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK,
+    TX_SPEND_ALLOWANCE_WASM, TX_TOP_UP_BRIDGE_POOL_WASM, TX_TRANSFER_WASM,
     TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
+    TX_UPDATE_ALLOWANCE_WASM, TX_UPDATE_FEE_CONVERSION_RATES_WASM,
     TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
     VP_USER_WASM,
 };
@@ -150,6 +154,9 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             output_folder: None,
             force: false,
             broadcast_only: false,
+            skip_if_applied: false,
+            confirm: false,
+            subscribe_events: false,
             ledger_address: tendermint_rpc::Url::from_str(
                 "http://127.0.0.1:26657",
             )
@@ -185,6 +192,21 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             target,
             token,
             amount,
+            masp_rebuild_retries: 1,
+            tx_code_path: PathBuf::from(TX_TRANSFER_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TxShieldAll builder from the given minimum set of arguments
+    fn new_shield_all(
+        &self,
+        source: Address,
+        target: PaymentAddress,
+    ) -> args::TxShieldAll {
+        args::TxShieldAll {
+            source,
+            target,
             tx_code_path: PathBuf::from(TX_TRANSFER_WASM),
             tx: self.tx_builder(),
         }
@@ -223,6 +245,7 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             validator,
             amount,
             source: None,
+            extra_bonds: vec![],
             tx: self.tx_builder(),
             tx_code_path: PathBuf::from(TX_BOND_WASM),
         }
@@ -297,6 +320,8 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             proposal_data,
             is_pgf_stewards: false,
             is_pgf_funding: false,
+            is_eth_bridge_params: false,
+            is_parameter_changes: false,
             tx_code_path: PathBuf::from(TX_INIT_PROPOSAL),
             tx: self.tx_builder(),
         }
@@ -319,6 +344,61 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a TxUpdateAllowance builder that grants the spender the given
+    /// daily cap of the token
+    fn new_update_allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+        token: Address,
+        daily_cap: token::Amount,
+    ) -> args::TxUpdateAllowance {
+        args::TxUpdateAllowance {
+            owner,
+            spender,
+            token,
+            daily_cap: Some(daily_cap),
+            tx_code_path: PathBuf::from(TX_UPDATE_ALLOWANCE_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TxSpendAllowance builder from the given minimum set of
+    /// arguments
+    fn new_spend_allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+        target: Address,
+        token: Address,
+        amount: token::Amount,
+    ) -> args::TxSpendAllowance {
+        args::TxSpendAllowance {
+            owner,
+            spender,
+            target,
+            token,
+            amount,
+            tx_code_path: PathBuf::from(TX_SPEND_ALLOWANCE_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TxUpdateFeeConversionRates builder for the fee price feed to
+    /// update the given conversion rates
+    fn new_update_fee_conversion_rates(
+        &self,
+        price_feed: Address,
+        rates: Vec<(Address, Dec)>,
+    ) -> args::TxUpdateFeeConversionRates {
+        args::TxUpdateFeeConversionRates {
+            price_feed,
+            rates,
+            tx_code_path: PathBuf::from(TX_UPDATE_FEE_CONVERSION_RATES_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a VoteProposal builder from the given minimum set of arguments
     fn new_proposal_vote(
         &self,
@@ -534,6 +614,34 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a CancelEthereumTransfer builder from the given minimum set of
+    /// arguments
+    fn new_cancel_eth_transfer(
+        &self,
+        transfer: KeccakHash,
+    ) -> args::CancelEthereumTransfer {
+        args::CancelEthereumTransfer {
+            transfer,
+            code_path: PathBuf::from(TX_CANCEL_BRIDGE_POOL_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TopUpEthereumTransfer builder from the given minimum set of
+    /// arguments
+    fn new_top_up_eth_transfer(
+        &self,
+        transfer: KeccakHash,
+        amount: InputAmount,
+    ) -> args::TopUpEthereumTransfer {
+        args::TopUpEthereumTransfer {
+            transfer,
+            amount,
+            code_path: PathBuf::from(TX_TOP_UP_BRIDGE_POOL_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a ResignSteward builder from the given minimum set of arguments
     fn new_resign_steward(&self, steward: Address) -> args::ResignSteward {
         args::ResignSteward {
@@ -569,7 +677,8 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
-    /// Sign the given transaction using the given signing data
+    /// Sign the given transaction using the given signing data. Returns the
+    /// hashes of the signed wrapper and inner transactions.
     async fn sign<D, F>(
         &self,
         tx: &mut Tx,
@@ -579,7 +688,7 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         + MaybeSend
         + MaybeSync,
         user_data: D,
-    ) -> crate::error::Result<()>
+    ) -> crate::error::Result<signing::TxHashes>
     where
         D: Clone + MaybeSend + MaybeSync,
         F: MaybeSend
@@ -677,6 +786,9 @@ where
                 output_folder: None,
                 force: false,
                 broadcast_only: false,
+                skip_if_applied: false,
+                confirm: false,
+                subscribe_events: false,
                 ledger_address: tendermint_rpc::Url::from_str(
                     "http://127.0.0.1:26657",
                 )
@@ -716,6 +828,29 @@ where
             native_token,
         ))
     }
+
+    /// Construct a new Namada context for the chain served by the given
+    /// client, fetching the chain ID and native token address from it. If
+    /// `trusted` is given, the chain is first checked to contain the pinned
+    /// block. The transaction builder is set up to target the fetched chain.
+    pub async fn bootstrap(
+        client: C,
+        wallet: Wallet<U>,
+        shielded: ShieldedContext<V>,
+        io: I,
+        trusted: Option<&TrustedBlock>,
+    ) -> crate::error::Result<(NamadaImpl<C, U, V, I>, ChainInfo)> {
+        let chain_info = fetch_chain_info(&client, trusted).await?;
+        let mut namada = NamadaImpl::native_new(
+            client,
+            wallet,
+            shielded,
+            io,
+            chain_info.native_token.clone(),
+        );
+        namada.prototype.chain_id = Some(chain_info.chain_id.clone());
+        Ok((namada, chain_info))
+    }
 }
 
 #[cfg_attr(feature = "async-send", async_trait::async_trait)]
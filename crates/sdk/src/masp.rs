@@ -51,6 +51,7 @@ use masp_proofs::prover::LocalTxProver;
 #[cfg(not(feature = "testing"))]
 use masp_proofs::sapling::SaplingVerificationContext;
 use namada_core::address::Address;
+use namada_core::arith::checked;
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::dec::Dec;
 pub use namada_core::masp::{
@@ -75,7 +76,7 @@ use ripemd::Digest as RipemdDigest;
 use sha2::Digest;
 use thiserror::Error;
 
-use crate::error::{Error, QueryError};
+use crate::error::{Error, QueryError, TxSubmitError};
 use crate::io::Io;
 use crate::queries::Client;
 use crate::rpc::{query_block, query_conversion, query_denom};
@@ -142,6 +143,32 @@ pub enum TransferErr {
     General(#[from] Error),
 }
 
+/// A pre-flight estimate of the shielded inputs required by a transfer,
+/// computed without building any proofs
+#[derive(Debug, Clone)]
+pub struct ShieldedTransferEstimate {
+    /// Epoch whose asset types the amounts are expressed in
+    pub epoch: Epoch,
+    /// The amount required to fund the transfer
+    pub required: I128Sum,
+    /// The value of the selected notes, after applying the conversions
+    pub available: I128Sum,
+    /// The amount by which the selected notes fall short of the required
+    /// amount. Zero if the transfer can be funded.
+    pub shortfall: I128Sum,
+    /// The number of notes that would be spent
+    pub notes: usize,
+    /// The number of conversions that would be applied to the notes
+    pub conversions: usize,
+}
+
+impl ShieldedTransferEstimate {
+    /// Check if the selected notes can fund the transfer
+    pub fn is_sufficient(&self) -> bool {
+        self.shortfall.is_zero()
+    }
+}
+
 #[derive(Debug, Clone)]
 struct ExtractedMaspTxs(Vec<(TxCommitments, Transaction)>);
 
@@ -492,6 +519,37 @@ pub fn find_valid_diversifier<R: RngCore + CryptoRng>(
     (diversifier, g_d)
 }
 
+/// A note decrypted from a shielded output: the index of the output in its
+/// transaction, the note itself, the diversifier of its payment address and
+/// its memo.
+pub type DecryptedNote = (usize, Note, Diversifier, MemoBytes);
+
+/// Try to decrypt all the outputs of the given transaction with the given
+/// viewing key, returning the notes that could be decrypted.
+pub fn trial_decrypt(
+    shielded: &Transaction,
+    vk: &ViewingKey,
+) -> Vec<DecryptedNote> {
+    let ivk = PreparedIncomingViewingKey::new(&vk.ivk());
+    shielded
+        .sapling_bundle()
+        .map_or(&vec![], |x| &x.shielded_outputs)
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, so)| {
+            // Let's try to see if this viewing key can decrypt latest
+            // note
+            try_sapling_note_decryption::<_, OutputDescription<<<Authorized as Authorization>::SaplingAuth as masp_primitives::transaction::components::sapling::Authorization>::Proof>>(
+                &NETWORK,
+                1.into(),
+                &ivk,
+                so,
+            )
+            .map(|(note, pa, memo)| (idx, note, *pa.diversifier(), memo))
+        })
+        .collect()
+}
+
 /// Determine if using the current note would actually bring us closer to our
 /// target
 pub fn is_amount_required(src: I128Sum, dest: I128Sum, delta: I128Sum) -> bool {
@@ -553,6 +611,17 @@ impl Unscanned {
         self.txs.keys().any(|k| k.height.0 == height)
     }
 
+    /// The number of fetched transactions that are still waiting to be
+    /// scanned.
+    pub fn len(&self) -> usize {
+        self.txs.len()
+    }
+
+    /// Check if there are no transactions waiting to be scanned.
+    pub fn is_empty(&self) -> bool {
+        self.txs.is_empty()
+    }
+
     /// We remove all indices from blocks that have been entirely scanned.
     /// If a block is only partially scanned, we leave all the events in the
     /// cache.
@@ -570,6 +639,43 @@ impl IntoIterator for Unscanned {
     }
 }
 
+/// The number of block heights fetched between two consecutive checkpoints of
+/// the shielded context.
+pub const FETCH_CHECKPOINT_INTERVAL: u64 = 100;
+
+/// Records a range of block heights whose shielded transactions have already
+/// been fetched into the [`Unscanned`] cache, so that an interrupted sync can
+/// resume without fetching them again.
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
+pub struct FetchCheckpoint {
+    /// The first block height of the fetched range
+    pub from: BlockHeight,
+    /// The last block height of the fetched range (inclusive)
+    pub to: BlockHeight,
+}
+
+/// A snapshot of the progress of a shielded sync, which wallets can use to
+/// display a progress indicator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncProgress {
+    /// The last block height that was fetched, if a fetch is underway
+    pub last_fetched: Option<BlockHeight>,
+    /// The last transaction that was scanned by all the viewing keys of the
+    /// context
+    pub last_scanned: Option<IndexedTx>,
+    /// The number of fetched transactions that have yet to be scanned
+    pub pending_txs: usize,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 /// The possible sync states of the shielded context
 pub enum ContextSyncStatus {
@@ -580,6 +686,35 @@ pub enum ContextSyncStatus {
     Speculative,
 }
 
+/// Prefix of the versioned encoding of a [`ShieldedContext`]. The unversioned
+/// encoding of earlier releases starts with the (optional) left node of the
+/// commitment tree, i.e. with a `0` or `1` byte, so the two cannot be
+/// mistaken for one another.
+const SHIELDED_CONTEXT_MAGIC: &[u8; 4] = b"NMSC";
+
+/// The current version of the encoding of a [`ShieldedContext`]
+pub const SHIELDED_CONTEXT_VERSION: u8 = 1;
+
+/// The unversioned layout of a [`ShieldedContext`] saved by earlier releases,
+/// from before viewing key birthdays and fetch checkpoints
+#[derive(BorshSerialize, BorshDeserialize)]
+struct LegacyShieldedContext {
+    tree: CommitmentTree<Node>,
+    vk_heights: BTreeMap<ViewingKey, Option<IndexedTx>>,
+    pos_map: HashMap<ViewingKey, BTreeSet<usize>>,
+    nf_map: HashMap<Nullifier, usize>,
+    note_map: HashMap<usize, Note>,
+    memo_map: HashMap<usize, MemoBytes>,
+    div_map: HashMap<usize, Diversifier>,
+    witness_map: HashMap<usize, IncrementalWitness<Node>>,
+    spents: HashSet<usize>,
+    asset_types: HashMap<AssetType, AssetData>,
+    vk_map: HashMap<usize, ViewingKey>,
+    tx_note_map: BTreeMap<IndexedTx, usize>,
+    unscanned: Unscanned,
+    sync_status: ContextSyncStatus,
+}
+
 /// Represents the current state of the shielded pool from the perspective of
 /// the chosen viewing keys.
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
@@ -592,6 +727,10 @@ pub struct ShieldedContext<U: ShieldedUtils> {
     /// Maps viewing keys to the block height to which they are synced.
     /// In particular, the height given by the value *has been scanned*.
     pub vk_heights: BTreeMap<ViewingKey, Option<IndexedTx>>,
+    /// Maps viewing keys to the block height before which they cannot have
+    /// received any notes, such that the preceding blocks need not be
+    /// scanned
+    pub vk_birthdays: BTreeMap<ViewingKey, BlockHeight>,
     /// Maps viewing keys to applicable note positions
     pub pos_map: HashMap<ViewingKey, BTreeSet<usize>>,
     /// Maps a nullifier to the note position to which it applies
@@ -614,6 +753,9 @@ pub struct ShieldedContext<U: ShieldedUtils> {
     pub tx_note_map: BTreeMap<IndexedTx, usize>,
     /// A cache of fetched indexed txs.
     pub unscanned: Unscanned,
+    /// The range of block heights already fetched into the cache by an
+    /// unfinished sync
+    pub fetch_checkpoint: Option<FetchCheckpoint>,
     /// The sync state of the context
     pub sync_status: ContextSyncStatus,
 }
@@ -625,6 +767,7 @@ impl<U: ShieldedUtils + Default> Default for ShieldedContext<U> {
         ShieldedContext::<U> {
             utils: U::default(),
             vk_heights: BTreeMap::new(),
+            vk_birthdays: BTreeMap::new(),
             tx_note_map: BTreeMap::default(),
             tree: CommitmentTree::empty(),
             pos_map: HashMap::default(),
@@ -637,6 +780,7 @@ impl<U: ShieldedUtils + Default> Default for ShieldedContext<U> {
             asset_types: HashMap::default(),
             vk_map: HashMap::default(),
             unscanned: Default::default(),
+            fetch_checkpoint: None,
             sync_status: ContextSyncStatus::Confirmed,
         }
     }
@@ -665,6 +809,55 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         self.utils.save(self).await
     }
 
+    /// Encode this context for [`ShieldedUtils`] to persist, prefixed with
+    /// the version of its format.
+    pub fn encode_versioned(&self) -> Vec<u8> {
+        let mut bytes = SHIELDED_CONTEXT_MAGIC.to_vec();
+        bytes.push(SHIELDED_CONTEXT_VERSION);
+        self.serialize(&mut bytes)
+            .expect("cannot serialize shielded context");
+        bytes
+    }
+
+    /// Decode a context persisted by [`ShieldedUtils`], also accepting the
+    /// unversioned format of the contexts saved by earlier releases. The
+    /// `utils` of the decoded context are left to their default value.
+    pub fn decode_versioned(bytes: &[u8]) -> std::io::Result<Self> {
+        let Some(versioned) = bytes.strip_prefix(SHIELDED_CONTEXT_MAGIC) else {
+            let legacy = LegacyShieldedContext::try_from_slice(bytes)?;
+            return Ok(Self {
+                tree: legacy.tree,
+                vk_heights: legacy.vk_heights,
+                pos_map: legacy.pos_map,
+                nf_map: legacy.nf_map,
+                note_map: legacy.note_map,
+                memo_map: legacy.memo_map,
+                div_map: legacy.div_map,
+                witness_map: legacy.witness_map,
+                spents: legacy.spents,
+                asset_types: legacy.asset_types,
+                vk_map: legacy.vk_map,
+                tx_note_map: legacy.tx_note_map,
+                unscanned: legacy.unscanned,
+                sync_status: legacy.sync_status,
+                ..Default::default()
+            });
+        };
+        match versioned.split_first() {
+            Some((&SHIELDED_CONTEXT_VERSION, encoded)) => {
+                Self::try_from_slice(encoded)
+            }
+            Some((version, _)) => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("unsupported shielded context version {version}"),
+            )),
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "missing shielded context version",
+            )),
+        }
+    }
+
     /// Update the merkle tree of witnesses the first time we
     /// scan a new MASP transaction.
     fn update_witness_map(
@@ -713,6 +906,7 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         _batch_size: u64,
         sks: &[ExtendedSpendingKey],
         fvks: &[ViewingKey],
+        birthdays: &BTreeMap<ViewingKey, BlockHeight>,
     ) -> Result<(), Error> {
         // add new viewing keys
         // Reload the state from file to get the last confirmed state and
@@ -736,44 +930,87 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         for vk in fvks {
             self.vk_heights.entry(*vk).or_default();
         }
+        self.vk_birthdays.extend(birthdays);
         let _ = self.save().await;
-        // the latest block height which has been added to the witness Merkle
-        // tree
-        let Some(least_idx) = self.vk_heights.values().min().cloned() else {
+        // the block height from which each viewing key has to be scanned,
+        // which is either that of the last tx it scanned or its birthday
+        let Some(least_height) = self
+            .vk_heights
+            .iter()
+            .map(|(vk, ix)| match ix {
+                Some(ix) => Some(ix.height),
+                None => self.vk_birthdays.get(vk).copied(),
+            })
+            .min()
+        else {
             return Ok(());
         };
+        // the latest tx which has been added to the witness Merkle tree
         let last_witnessed_tx = self.tx_note_map.keys().max().cloned();
         // get the bounds on the block heights to fetch
-        let start_idx =
-            std::cmp::min(last_witnessed_tx.as_ref(), least_idx.as_ref())
-                .map(|ix| ix.height);
+        let start_idx = match last_witnessed_tx.as_ref() {
+            Some(ix) => std::cmp::min(Some(ix.height), least_height),
+            None => least_height,
+        };
         let start_idx = start_query_height.or(start_idx);
-        // Load all transactions accepted until this point
-        // N.B. the cache is a hash map
-        self.unscanned.extend(
-            self.fetch_shielded_transfers(
-                client,
-                logger,
-                start_idx,
-                last_query_height,
-            )
-            .await?,
-        );
+        // Query for the last produced block height
+        let last_block_height = query_block(client)
+            .await?
+            .map_or_else(BlockHeight::first, |block| block.height);
+        let last_query_height = last_query_height.unwrap_or(last_block_height);
+        let first_height_to_query =
+            start_idx.map_or_else(BlockHeight::first, |height| height);
+        // If no tx has been witnessed yet, the blocks preceding the first
+        // height to query are skipped, so we start from the commitment tree
+        // the protocol had at that point
+        if last_witnessed_tx.is_none() {
+            if let Some(height) = first_height_to_query
+                .prev_height()
+                .filter(|height| *height >= BlockHeight::first())
+            {
+                self.tree = Self::fetch_commitment_tree(client, height).await?;
+            }
+        }
+        // Resume from the last checkpoint if a previous sync was interrupted
+        // after having already fetched part of the requested range
+        let (checkpoint_from, first_unfetched_height) =
+            self.resume_fetch_from(first_height_to_query);
+        // Load all transactions accepted until this point, persisting the
+        // cache at regular intervals in case of interruptions
+        self.fetch_checkpointed(
+            logger.fetch(first_unfetched_height.0..=last_query_height.0),
+            checkpoint_from,
+            |height| Self::fetch_shielded_transfers_at_height(client, height),
+        )
+        .await?;
         // persist the cache in case of interruptions.
         let _ = self.save().await;
 
+        // Trial-decrypt the fetched notes across all the available cores. The
+        // witness map and the rest of the context are still updated
+        // sequentially below, in the order of the transactions, so that the
+        // result does not depend on the scheduling of the worker threads
+        #[cfg(feature = "multicore")]
+        let mut decrypted = self.par_trial_decrypt();
         let txs = logger.scan(self.unscanned.clone());
+        let vk_birthdays = self.vk_birthdays.clone();
         for (ref indexed_tx, ref stx) in txs {
             if Some(indexed_tx) > last_witnessed_tx.as_ref() {
                 self.update_witness_map(indexed_tx.to_owned(), stx)?;
             }
             let mut vk_heights = BTreeMap::new();
             std::mem::swap(&mut vk_heights, &mut self.vk_heights);
-            for (vk, h) in vk_heights
-                .iter_mut()
-                .filter(|(_vk, h)| h.as_ref() < Some(indexed_tx))
-            {
-                self.scan_tx(indexed_tx.to_owned(), stx, vk)?;
+            for (vk, h) in vk_heights.iter_mut().filter(|(vk, h)| {
+                h.as_ref() < Some(indexed_tx)
+                    && vk_birthdays
+                        .get(vk)
+                        .map_or(true, |birthday| *birthday <= indexed_tx.height)
+            }) {
+                #[cfg(feature = "multicore")]
+                let notes = decrypted.remove(&(indexed_tx.to_owned(), *vk));
+                #[cfg(not(feature = "multicore"))]
+                let notes = None;
+                self.scan_tx_with_notes(indexed_tx.to_owned(), stx, vk, notes)?;
                 *h = Some(indexed_tx.to_owned());
             }
             // possibly remove unneeded elements from the cache.
@@ -781,10 +1018,95 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
             std::mem::swap(&mut vk_heights, &mut self.vk_heights);
             let _ = self.save().await;
         }
+        // Every fetched transaction has been scanned, the next sync will have
+        // to compute its range from the viewing key heights again
+        self.fetch_checkpoint = None;
+        let _ = self.save().await;
 
         Ok(())
     }
 
+    /// Get the first height of the fetch checkpoint to extend and the first
+    /// height that is left to fetch, for a fetch that has to start at the
+    /// given height. The fetch checkpoint is only resumed if it covers the
+    /// start of the fetch.
+    fn resume_fetch_from(
+        &self,
+        first_height_to_query: BlockHeight,
+    ) -> (BlockHeight, BlockHeight) {
+        match self.fetch_checkpoint {
+            Some(FetchCheckpoint { from, to })
+                if from <= first_height_to_query =>
+            {
+                (from, std::cmp::max(first_height_to_query, to.next_height()))
+            }
+            _ => (first_height_to_query, first_height_to_query),
+        }
+    }
+
+    /// Fetch the shielded transactions of the given block heights into the
+    /// cache of unscanned transactions, extending the fetch checkpoint that
+    /// starts at `checkpoint_from` as we go. The context, and with it the
+    /// checkpoint, is saved every [`FETCH_CHECKPOINT_INTERVAL`] heights.
+    async fn fetch_checkpointed<F, Fut>(
+        &mut self,
+        heights: impl IntoIterator<Item = u64>,
+        checkpoint_from: BlockHeight,
+        mut fetch_at_height: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(BlockHeight) -> Fut,
+        Fut: std::future::Future<Output = Result<IndexedNoteData, Error>>,
+    {
+        for height in heights {
+            if !self.unscanned.contains_height(height) {
+                let txs = fetch_at_height(height.into()).await?;
+                self.unscanned.extend(txs);
+            }
+            self.fetch_checkpoint = Some(FetchCheckpoint {
+                from: checkpoint_from,
+                to: height.into(),
+            });
+            if height % FETCH_CHECKPOINT_INTERVAL == 0 {
+                let _ = self.save().await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Query the note commitment tree of the shielded pool, as it was at
+    /// the end of the block with the given height.
+    async fn fetch_commitment_tree<C: Client + Sync>(
+        client: &C,
+        height: BlockHeight,
+    ) -> Result<CommitmentTree<Node>, Error> {
+        let (tree, _) = rpc::query_storage_value_bytes(
+            client,
+            &token::storage_key::masp_commitment_tree_key(),
+            Some(height),
+            false,
+        )
+        .await?;
+        let Some(tree) = tree else {
+            return Ok(CommitmentTree::empty());
+        };
+        CommitmentTree::<Node>::try_from_slice(&tree).map_err(|e| {
+            Error::Other(format!(
+                "Failed to decode the note commitment tree at height \
+                 {height}: {e}"
+            ))
+        })
+    }
+
+    /// Get the progress of the current (or last interrupted) shielded sync.
+    pub fn sync_progress(&self) -> SyncProgress {
+        SyncProgress {
+            last_fetched: self.fetch_checkpoint.map(|cp| cp.to),
+            last_scanned: self.vk_heights.values().min().cloned().flatten(),
+            pending_txs: self.unscanned.len(),
+        }
+    }
+
     /// Obtain a chronologically-ordered list of all accepted shielded
     /// transactions from a node.
     pub async fn fetch_shielded_transfers<C: Client + Sync, IO: Io>(
@@ -809,45 +1131,56 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
             if self.unscanned.contains_height(height) {
                 continue;
             }
+            shielded_txs.extend(
+                Self::fetch_shielded_transfers_at_height(client, height.into())
+                    .await?,
+            );
+        }
 
-            let txs_results = match get_indexed_masp_events_at_height(
-                client,
-                height.into(),
-                None,
-            )
-            .await?
+        Ok(shielded_txs)
+    }
+
+    /// Obtain all the accepted shielded transactions of the block at the
+    /// given height.
+    async fn fetch_shielded_transfers_at_height<C: Client + Sync>(
+        client: &C,
+        height: BlockHeight,
+    ) -> Result<IndexedNoteData, Error> {
+        let mut shielded_txs = BTreeMap::new();
+        let txs_results =
+            match get_indexed_masp_events_at_height(client, height, None)
+                .await?
             {
                 Some(events) => events,
-                None => continue,
+                None => return Ok(shielded_txs),
             };
 
-            // Query the actual block to get the txs bytes. If we only need one
-            // tx it might be slightly better to query the /tx endpoint to
-            // reduce the amount of data sent over the network, but this is a
-            // minimal improvement and it's even hard to tell how many times
-            // we'd need a single masp tx to make this worth it
-            let block = client
-                .block(height as u32)
-                .await
-                .map_err(|e| Error::from(QueryError::General(e.to_string())))?
-                .block
-                .data;
+        // Query the actual block to get the txs bytes. If we only need one
+        // tx it might be slightly better to query the /tx endpoint to
+        // reduce the amount of data sent over the network, but this is a
+        // minimal improvement and it's even hard to tell how many times
+        // we'd need a single masp tx to make this worth it
+        let block = client
+            .block(height.0 as u32)
+            .await
+            .map_err(|e| Error::from(QueryError::General(e.to_string())))?
+            .block
+            .data;
 
-            for idx in txs_results {
-                let tx = Tx::try_from(block[idx.0 as usize].as_ref())
-                    .map_err(|e| Error::Other(e.to_string()))?;
-                let extracted_masp_txs = Self::extract_masp_tx(&tx).await?;
-                // Collect the current transactions
-                for (inner_tx, transaction) in extracted_masp_txs.0 {
-                    shielded_txs.insert(
-                        IndexedTx {
-                            height: height.into(),
-                            index: idx,
-                            inner_tx,
-                        },
-                        transaction,
-                    );
-                }
+        for idx in txs_results {
+            let tx = Tx::try_from(block[idx.0 as usize].as_ref())
+                .map_err(|e| Error::Other(e.to_string()))?;
+            let extracted_masp_txs = Self::extract_masp_tx(&tx).await?;
+            // Collect the current transactions
+            for (inner_tx, transaction) in extracted_masp_txs.0 {
+                shielded_txs.insert(
+                    IndexedTx {
+                        height,
+                        index: idx,
+                        inner_tx,
+                    },
+                    transaction,
+                );
             }
         }
 
@@ -922,61 +1255,97 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         indexed_tx: IndexedTx,
         shielded: &Transaction,
         vk: &ViewingKey,
+    ) -> Result<(), Error> {
+        self.scan_tx_with_notes(indexed_tx, shielded, vk, None)
+    }
+
+    /// Trial-decrypt the outputs of all the cached transactions with the
+    /// viewing keys that still have to scan them, splitting the work across
+    /// multiple threads.
+    #[cfg(feature = "multicore")]
+    fn par_trial_decrypt(
+        &self,
+    ) -> BTreeMap<(IndexedTx, ViewingKey), Vec<DecryptedNote>> {
+        use rayon::prelude::*;
+
+        if !matches!(self.sync_status, ContextSyncStatus::Confirmed) {
+            return BTreeMap::new();
+        }
+        let jobs: Vec<_> = self
+            .unscanned
+            .txs
+            .iter()
+            .flat_map(|(indexed_tx, stx)| {
+                self.vk_heights
+                    .iter()
+                    .filter(move |(_vk, h)| h.as_ref() < Some(indexed_tx))
+                    .map(move |(vk, _h)| (indexed_tx, stx, vk))
+            })
+            .collect();
+        jobs.into_par_iter()
+            .map(|(indexed_tx, stx, vk)| {
+                ((indexed_tx.clone(), *vk), trial_decrypt(stx, vk))
+            })
+            .collect()
+    }
+
+    /// Same as [`Self::scan_tx`], but takes the notes of the transaction that
+    /// the viewing key can decrypt if they have already been computed.
+    fn scan_tx_with_notes(
+        &mut self,
+        indexed_tx: IndexedTx,
+        shielded: &Transaction,
+        vk: &ViewingKey,
+        decrypted: Option<Vec<DecryptedNote>>,
     ) -> Result<(), Error> {
         // For tracking the account changes caused by this Transaction
         let mut transaction_delta = TransactionDelta::new();
         if let ContextSyncStatus::Confirmed = self.sync_status {
-            let mut note_pos = self.tx_note_map[&indexed_tx];
+            let first_note_pos = self.tx_note_map[&indexed_tx];
             // Listen for notes sent to our viewing keys, only if we are syncing
             // (i.e. in a confirmed status)
-            for so in shielded
+            if shielded
                 .sapling_bundle()
-                .map_or(&vec![], |x| &x.shielded_outputs)
+                .map_or(false, |x| !x.shielded_outputs.is_empty())
             {
-                // Let's try to see if this viewing key can decrypt latest
+                self.pos_map.entry(*vk).or_default();
+            }
+            let decrypted =
+                decrypted.unwrap_or_else(|| trial_decrypt(shielded, vk));
+            // So this current viewing key does decrypt these notes...
+            for (output_idx, note, diversifier, memo) in decrypted {
+                let note_pos = checked!(first_note_pos + output_idx)
+                    .map_err(Error::from)?;
+                // Add this note to list of notes decrypted by this viewing
+                // key
+                self.pos_map.entry(*vk).or_default().insert(note_pos);
+                // Compute the nullifier now to quickly recognize when spent
+                let nf = note.nf(
+                    &vk.nk,
+                    note_pos.try_into().map_err(|_| {
+                        Error::Other("Can not get nullifier".to_string())
+                    })?,
+                );
+                self.note_map.insert(note_pos, note);
+                self.memo_map.insert(note_pos, memo);
+                // The payment address' diversifier is required to spend
                 // note
-                let notes = self.pos_map.entry(*vk).or_default();
-                let decres = try_sapling_note_decryption::<_, OutputDescription<<<Authorized as Authorization>::SaplingAuth as masp_primitives::transaction::components::sapling::Authorization>::Proof>>(
-                &NETWORK,
-                1.into(),
-                &PreparedIncomingViewingKey::new(&vk.ivk()),
-                so,
-            );
-                // So this current viewing key does decrypt this current note...
-                if let Some((note, pa, memo)) = decres {
-                    // Add this note to list of notes decrypted by this viewing
-                    // key
-                    notes.insert(note_pos);
-                    // Compute the nullifier now to quickly recognize when spent
-                    let nf = note.nf(
-                        &vk.nk,
-                        note_pos.try_into().map_err(|_| {
-                            Error::Other("Can not get nullifier".to_string())
-                        })?,
-                    );
-                    self.note_map.insert(note_pos, note);
-                    self.memo_map.insert(note_pos, memo);
-                    // The payment address' diversifier is required to spend
-                    // note
-                    self.div_map.insert(note_pos, *pa.diversifier());
-                    self.nf_map.insert(nf, note_pos);
-                    // Note the account changes
-                    let balance = transaction_delta
-                        .entry(*vk)
-                        .or_insert_with(I128Sum::zero);
-                    *balance += I128Sum::from_nonnegative(
-                        note.asset_type,
-                        note.value as i128,
+                self.div_map.insert(note_pos, diversifier);
+                self.nf_map.insert(nf, note_pos);
+                // Note the account changes
+                let balance =
+                    transaction_delta.entry(*vk).or_insert_with(I128Sum::zero);
+                *balance += I128Sum::from_nonnegative(
+                    note.asset_type,
+                    note.value as i128,
+                )
+                .map_err(|()| {
+                    Error::Other(
+                        "found note with invalid value or asset type"
+                            .to_string(),
                     )
-                    .map_err(|()| {
-                        Error::Other(
-                            "found note with invalid value or asset type"
-                                .to_string(),
-                        )
-                    })?;
-                    self.vk_map.insert(note_pos, *vk);
-                }
-                note_pos += 1;
+                })?;
+                self.vk_map.insert(note_pos, *vk);
             }
         }
 
@@ -1212,6 +1581,10 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
                  0, this is a bug, please report it.",
                 asset_type
             );
+            return Err(TxSubmitError::InvalidMaspConversion(
+                asset_type.to_string(),
+            )
+            .into());
         }
         // We should use an amount of the AllowedConversion that almost
         // cancels the original amount
@@ -1541,6 +1914,78 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         res
     }
 
+    /// Estimate the inputs required to transfer `amount` of `token` out of the
+    /// notes of the given spending key, without building any proofs. The
+    /// notes and conversions are selected exactly as in
+    /// [`Self::gen_shielded_transfer`], so that a transfer which cannot be
+    /// funded is reported before the expensive proof generation.
+    ///
+    /// The MASP fee rule of the builder is zero, as tx fees are paid by the
+    /// transparent fee payer, hence the notes only need to cover the
+    /// transferred amount.
+    pub async fn estimate_shielded_transfer(
+        context: &impl Namada,
+        spending_key: &ExtendedSpendingKey,
+        token: &Address,
+        amount: token::DenominatedAmount,
+    ) -> Result<ShieldedTransferEstimate, Error> {
+        {
+            // Load the current shielded context given the spending key we
+            // possess
+            let mut shielded = context.shielded_mut().await;
+            let _ = shielded.load().await;
+        }
+        // Determine epoch in which to submit potential shielded transaction
+        let epoch = rpc::query_epoch(context.client()).await?;
+        let Some(denom) = query_denom(context.client(), token).await else {
+            return Err(Error::from(QueryError::General(format!(
+                "denomination for token {token}"
+            ))));
+        };
+
+        let mut shielded = context.shielded_mut().await;
+        let (_, masp_amount) = shielded
+            .convert_amount(
+                context.client(),
+                epoch,
+                token,
+                denom,
+                amount.amount(),
+            )
+            .await?;
+        let required = I128Sum::from_sum(masp_amount);
+        let (available, notes, conversions) = shielded
+            .collect_unspent_notes(
+                context,
+                &to_viewing_key(spending_key).vk,
+                required.clone(),
+                epoch,
+            )
+            .await?;
+
+        // Only the asset types of the transfer that are not fully covered
+        // contribute to the shortfall
+        let mut shortfall = I128Sum::zero();
+        for (asset_type, value) in required.components() {
+            let missing = value.saturating_sub(available[asset_type]);
+            if missing > 0 {
+                shortfall += I128Sum::from_pair(*asset_type, missing);
+            }
+        }
+
+        Ok(ShieldedTransferEstimate {
+            epoch,
+            required,
+            available,
+            shortfall,
+            notes: notes.len(),
+            conversions: conversions
+                .values()
+                .filter(|(_, _, value)| value.is_positive())
+                .count(),
+        })
+    }
+
     /// Make shielded components to embed within a Transfer object. If no
     /// shielded payment address nor spending key is specified, then no
     /// shielded components are produced. Otherwise a transaction containing
@@ -1585,7 +2030,8 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
 
         // Try to get a seed from env var, if any.
         #[allow(unused_mut)]
-        let mut rng = StdRng::from_rng(OsRng).unwrap();
+        let mut rng = StdRng::from_rng(OsRng)
+            .map_err(|e| Error::Other(format!("Failed to seed an RNG: {e}")))?;
         #[cfg(feature = "testing")]
         let mut rng = if let Ok(seed) = env::var(ENV_VAR_MASP_TEST_SEED)
             .map_err(|e| Error::Other(e.to_string()))
@@ -1641,9 +2087,10 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
                     delta_time.num_seconds() / max_block_time.0 as i64,
                 )
                 .map_err(|e| Error::Other(e.to_string()))?;
-                u32::try_from(last_block_height)
-                    .map_err(|e| Error::Other(e.to_string()))?
-                    + delta_blocks
+                let last_block_height = u32::try_from(last_block_height)
+                    .map_err(|e| Error::Other(e.to_string()))?;
+                checked!(last_block_height + delta_blocks)
+                    .map_err(Error::from)?
             }
             None => {
                 // NOTE: The masp library doesn't support optional expiration so
@@ -1795,10 +2242,13 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
                 && decoded.epoch.map_or(true, |vbal_epoch| vbal_epoch <= epoch)
                 && *rem_amount > 0
             {
-                let val = u128::try_from(*val).expect(
-                    "value balance in absence of output descriptors should be \
-                     non-negative",
-                );
+                let val = u128::try_from(*val).map_err(|_| {
+                    Error::Other(
+                        "value balance in absence of output descriptors \
+                         should be non-negative"
+                            .to_string(),
+                    )
+                })?;
                 // We want to take at most the remaining quota for the
                 // current denomination to the receiver
                 let contr = std::cmp::min(*rem_amount as u128, val) as u64;
@@ -1818,10 +2268,13 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
                 } else {
                     // If there is a transparent output
                     let hash = transparent_target_hash
-                        .expect(
-                            "transparent target hash should have been \
-                             computed already",
-                        )
+                        .ok_or_else(|| {
+                            Error::Other(
+                                "transparent target hash should have been \
+                                 computed already"
+                                    .to_string(),
+                            )
+                        })?
                         .into();
                     builder
                         .add_transparent_output(
@@ -2207,6 +2660,275 @@ mod tests {
     }
 }
 
+#[cfg(test)]
+mod context_tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// A [`ShieldedUtils`] that keeps the saved shielded context in memory
+    #[derive(Default, Clone, BorshSerialize, BorshDeserialize)]
+    struct MemShieldedUtils {
+        #[borsh(skip)]
+        saved: Arc<Mutex<Option<Vec<u8>>>>,
+    }
+
+    #[cfg_attr(feature = "async-send", async_trait::async_trait)]
+    #[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
+    impl ShieldedUtils for MemShieldedUtils {
+        fn local_tx_prover(&self) -> LocalTxProver {
+            unimplemented!("no proofs are needed to test the shielded sync")
+        }
+
+        async fn load<U: ShieldedUtils + MaybeSend>(
+            &self,
+            ctx: &mut ShieldedContext<U>,
+            _force_confirmed: bool,
+        ) -> std::io::Result<()> {
+            let bytes =
+                self.saved.lock().unwrap().clone().ok_or_else(|| {
+                    std::io::Error::from(std::io::ErrorKind::NotFound)
+                })?;
+            *ctx = ShieldedContext {
+                utils: ctx.utils.clone(),
+                ..ShieldedContext::<U>::decode_versioned(&bytes)?
+            };
+            Ok(())
+        }
+
+        async fn save<U: ShieldedUtils + MaybeSync>(
+            &self,
+            ctx: &ShieldedContext<U>,
+        ) -> std::io::Result<()> {
+            *self.saved.lock().unwrap() = Some(ctx.encode_versioned());
+            Ok(())
+        }
+    }
+
+    /// Test that a fetch that gets interrupted resumes from the last saved
+    /// checkpoint rather than from the start of the fetched range.
+    #[tokio::test]
+    async fn test_interrupted_fetch_resumes_from_checkpoint() {
+        let utils = MemShieldedUtils::default();
+        let mut ctx = ShieldedContext {
+            utils: utils.clone(),
+            ..Default::default()
+        };
+        let first_height = BlockHeight::first();
+        let (from, first_unfetched) = ctx.resume_fetch_from(first_height);
+        assert_eq!(first_unfetched, first_height);
+
+        // the connection to the node is lost while fetching height 250
+        let result = ctx
+            .fetch_checkpointed(
+                first_unfetched.0..=300,
+                from,
+                |height| async move {
+                    if height == BlockHeight(250) {
+                        Err(Error::Other("connection lost".to_string()))
+                    } else {
+                        Ok(IndexedNoteData::new())
+                    }
+                },
+            )
+            .await;
+        assert!(result.is_err());
+
+        // the next sync only fetches the heights after the last checkpoint
+        let mut ctx = ShieldedContext {
+            utils,
+            ..Default::default()
+        };
+        ctx.load_confirmed().await.unwrap();
+        assert_eq!(
+            ctx.fetch_checkpoint,
+            Some(FetchCheckpoint {
+                from: first_height,
+                to: BlockHeight(200),
+            })
+        );
+        let (from, first_unfetched) = ctx.resume_fetch_from(first_height);
+        assert_eq!((from, first_unfetched), (first_height, BlockHeight(201)));
+        let mut fetched = vec![];
+        ctx.fetch_checkpointed(first_unfetched.0..=300, from, |height| {
+            fetched.push(height.0);
+            async { Ok(IndexedNoteData::new()) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(fetched, (201..=300).collect::<Vec<_>>());
+        assert_eq!(
+            ctx.fetch_checkpoint,
+            Some(FetchCheckpoint {
+                from: first_height,
+                to: BlockHeight(300),
+            })
+        );
+    }
+
+    /// Test that contexts saved in the unversioned format of earlier releases
+    /// can still be loaded, and that unknown versions are rejected.
+    #[test]
+    fn test_decode_legacy_shielded_context() {
+        let vk = to_viewing_key(&ExtendedSpendingKey::master(b"legacy")).vk;
+        let legacy = LegacyShieldedContext {
+            tree: CommitmentTree::empty(),
+            vk_heights: BTreeMap::from([(vk, None)]),
+            pos_map: HashMap::default(),
+            nf_map: HashMap::default(),
+            note_map: HashMap::default(),
+            memo_map: HashMap::default(),
+            div_map: HashMap::default(),
+            witness_map: HashMap::default(),
+            spents: HashSet::default(),
+            asset_types: HashMap::default(),
+            vk_map: HashMap::default(),
+            tx_note_map: BTreeMap::default(),
+            unscanned: Unscanned::default(),
+            sync_status: ContextSyncStatus::Confirmed,
+        };
+        let ctx = ShieldedContext::<MemShieldedUtils>::decode_versioned(
+            &legacy.serialize_to_vec(),
+        )
+        .unwrap();
+        assert_eq!(ctx.vk_heights, legacy.vk_heights);
+        assert!(ctx.vk_birthdays.is_empty());
+        assert_eq!(ctx.fetch_checkpoint, None);
+
+        let mut bytes = ctx.encode_versioned();
+        let decoded =
+            ShieldedContext::<MemShieldedUtils>::decode_versioned(&bytes)
+                .unwrap();
+        assert_eq!(decoded.vk_heights, ctx.vk_heights);
+
+        bytes[SHIELDED_CONTEXT_MAGIC.len()] = SHIELDED_CONTEXT_VERSION + 1;
+        assert!(
+            ShieldedContext::<MemShieldedUtils>::decode_versioned(&bytes)
+                .is_err()
+        );
+    }
+
+    /// Build a transaction shielding the given values to the given payment
+    /// addresses
+    #[cfg(feature = "multicore")]
+    fn shielding_tx(
+        outputs: &[(masp_primitives::sapling::PaymentAddress, u64)],
+    ) -> Transaction {
+        let asset_type = encode_asset_type(
+            namada_core::address::testing::nam(),
+            Denomination(6),
+            MaspDigitPos::Zero,
+            None,
+        )
+        .unwrap();
+        let mut builder =
+            Builder::<Network, _>::new(NETWORK, (u32::MAX - 20).into());
+        builder
+            .add_transparent_input(TxOut {
+                asset_type,
+                value: outputs.iter().map(|(_, value)| value).sum(),
+                address: TransparentAddress([0; 20]),
+            })
+            .unwrap();
+        for (payment_addr, value) in outputs {
+            builder
+                .add_sapling_output(
+                    None,
+                    *payment_addr,
+                    asset_type,
+                    *value,
+                    MemoBytes::empty(),
+                )
+                .unwrap();
+        }
+        let (transaction, _metadata) = builder
+            .build(
+                &testing::MockTxProver(Mutex::new(OsRng)),
+                &FeeRule::non_standard(U64Sum::zero()),
+                &mut OsRng,
+                &mut RngBuildParams::new(OsRng),
+            )
+            .unwrap();
+        transaction
+    }
+
+    /// Test that scanning the transactions with the notes trial-decrypted in
+    /// parallel gives the same notes and positions as the sequential scan.
+    #[cfg(feature = "multicore")]
+    #[test]
+    fn test_par_trial_decrypt_matches_sequential_scan() {
+        let vks: Vec<ViewingKey> = [[1; 32], [2; 32]]
+            .iter()
+            .map(|seed| {
+                let sk = ExtendedSpendingKey::master(seed);
+                ExtendedFullViewingKey::from(&sk).fvk.vk
+            })
+            .collect();
+        let pas: Vec<_> = vks
+            .iter()
+            .map(|vk| {
+                vk.to_payment_address(find_valid_diversifier(&mut OsRng).0)
+                    .unwrap()
+            })
+            .collect();
+        let txs: Vec<_> = [
+            shielding_tx(&[(pas[0], 10), (pas[1], 20)]),
+            shielding_tx(&[(pas[1], 30)]),
+            shielding_tx(&[(pas[0], 40), (pas[0], 50), (pas[1], 60)]),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(idx, tx)| {
+            let indexed_tx = IndexedTx {
+                height: BlockHeight(1),
+                index: TxIndex(idx as u32),
+                inner_tx: TxCommitments::default(),
+            };
+            (indexed_tx, tx)
+        })
+        .collect();
+
+        let new_ctx = || {
+            let mut ctx = ShieldedContext::<MemShieldedUtils>::default();
+            for vk in &vks {
+                ctx.vk_heights.insert(*vk, None);
+            }
+            for (indexed_tx, tx) in &txs {
+                ctx.update_witness_map(indexed_tx.clone(), tx).unwrap();
+                ctx.unscanned.txs.insert(indexed_tx.clone(), tx.clone());
+            }
+            ctx
+        };
+
+        let mut sequential = new_ctx();
+        for (indexed_tx, tx) in &txs {
+            for vk in &vks {
+                sequential.scan_tx(indexed_tx.clone(), tx, vk).unwrap();
+            }
+        }
+
+        let mut parallel = new_ctx();
+        let mut decrypted = parallel.par_trial_decrypt();
+        assert_eq!(decrypted.len(), txs.len() * vks.len());
+        for (indexed_tx, tx) in &txs {
+            for vk in &vks {
+                let notes = decrypted.remove(&(indexed_tx.clone(), *vk));
+                assert!(notes.is_some());
+                parallel
+                    .scan_tx_with_notes(indexed_tx.clone(), tx, vk, notes)
+                    .unwrap();
+            }
+        }
+
+        assert_eq!(sequential.note_map.len(), 6);
+        assert_eq!(sequential.pos_map, parallel.pos_map);
+        assert_eq!(sequential.note_map, parallel.note_map);
+        assert_eq!(sequential.nf_map, parallel.nf_map);
+        assert_eq!(sequential.div_map, parallel.div_map);
+        assert_eq!(sequential.vk_map, parallel.vk_map);
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
 /// Tests and strategies for transactions
 pub mod testing {
@@ -3094,28 +3816,7 @@ pub mod fs {
         pub fn new(context_dir: PathBuf) -> ShieldedContext<Self> {
             // Make sure that MASP parameters are downloaded to enable MASP
             // transaction building and verification later on
-            let params_dir = get_params_dir();
-            let spend_path = params_dir.join(SPEND_NAME);
-            let convert_path = params_dir.join(CONVERT_NAME);
-            let output_path = params_dir.join(OUTPUT_NAME);
-            if !(spend_path.exists()
-                && convert_path.exists()
-                && output_path.exists())
-            {
-                #[allow(clippy::print_stdout)]
-                {
-                    println!("MASP parameters not present, downloading...");
-                }
-                masp_proofs::download_masp_parameters(None)
-                    .expect("MASP parameters not present or downloadable");
-                #[allow(clippy::print_stdout)]
-                {
-                    println!(
-                        "MASP parameter download complete, resuming \
-                         execution..."
-                    );
-                }
-            }
+            ensure_masp_params();
             // Finally initialize a shielded context with the supplied directory
 
             let sync_status =
@@ -3137,6 +3838,46 @@ pub mod fs {
         }
     }
 
+    /// Download the MASP parameters if they are not present in the params
+    /// directory
+    pub(super) fn ensure_masp_params() {
+        let params_dir = get_params_dir();
+        let spend_path = params_dir.join(SPEND_NAME);
+        let convert_path = params_dir.join(CONVERT_NAME);
+        let output_path = params_dir.join(OUTPUT_NAME);
+        if !(spend_path.exists()
+            && convert_path.exists()
+            && output_path.exists())
+        {
+            #[allow(clippy::print_stdout)]
+            {
+                println!("MASP parameters not present, downloading...");
+            }
+            masp_proofs::download_masp_parameters(None)
+                .expect("MASP parameters not present or downloadable");
+            #[allow(clippy::print_stdout)]
+            {
+                println!(
+                    "MASP parameter download complete, resuming execution..."
+                );
+            }
+        }
+    }
+
+    /// Load a MASP transaction prover from the params directory
+    pub(super) fn load_local_tx_prover() -> LocalTxProver {
+        if let Ok(params_dir) = env::var(ENV_VAR_MASP_PARAMS_DIR) {
+            let params_dir = PathBuf::from(params_dir);
+            let spend_path = params_dir.join(SPEND_NAME);
+            let convert_path = params_dir.join(CONVERT_NAME);
+            let output_path = params_dir.join(OUTPUT_NAME);
+            LocalTxProver::new(&spend_path, &output_path, &convert_path)
+        } else {
+            LocalTxProver::with_default_location()
+                .expect("unable to load MASP Parameters")
+        }
+    }
+
     impl Default for FsShieldedUtils {
         fn default() -> Self {
             Self {
@@ -3149,16 +3890,7 @@ pub mod fs {
     #[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
     impl ShieldedUtils for FsShieldedUtils {
         fn local_tx_prover(&self) -> LocalTxProver {
-            if let Ok(params_dir) = env::var(ENV_VAR_MASP_PARAMS_DIR) {
-                let params_dir = PathBuf::from(params_dir);
-                let spend_path = params_dir.join(SPEND_NAME);
-                let convert_path = params_dir.join(CONVERT_NAME);
-                let output_path = params_dir.join(OUTPUT_NAME);
-                LocalTxProver::new(&spend_path, &output_path, &convert_path)
-            } else {
-                LocalTxProver::with_default_location()
-                    .expect("unable to load MASP Parameters")
-            }
+            load_local_tx_prover()
         }
 
         /// Try to load the last saved shielded context from the given context
@@ -3183,7 +3915,7 @@ pub mod fs {
             // Fill the supplied context with the deserialized object
             *ctx = ShieldedContext {
                 utils: ctx.utils.clone(),
-                ..ShieldedContext::<U>::deserialize(&mut &bytes[..])?
+                ..ShieldedContext::<U>::decode_versioned(&bytes)?
             };
             Ok(())
         }
@@ -3213,10 +3945,7 @@ pub mod fs {
                     .write(true)
                     .create_new(true)
                     .open(tmp_path.clone())?;
-                let mut bytes = Vec::new();
-                ctx.serialize(&mut bytes)
-                    .expect("cannot serialize shielded context");
-                ctx_file.write_all(&bytes[..])?;
+                ctx_file.write_all(&ctx.encode_versioned())?;
             }
             // Atomically update the old shielded context file with new data.
             // Atomicity is required to prevent other client instances from
@@ -3236,6 +3965,176 @@ pub mod fs {
     }
 }
 
+#[cfg(all(feature = "std", feature = "sled"))]
+/// Implementation of MASP functionality backed by an embedded key-value
+/// database, for clients that cannot rely on plain files (e.g. wallets
+/// sharing the shielded context between concurrent readers)
+pub mod db {
+    use std::io::{Error as IoError, ErrorKind};
+    use std::path::Path;
+
+    use super::*;
+
+    /// Database tree holding the shielded contexts
+    const TREE_NAME: &str = "shielded";
+    /// Key of the confirmed shielded context
+    const CONFIRMED_KEY: &str = "confirmed";
+    /// Key of the speculative shielded context
+    const SPECULATIVE_KEY: &str = "speculative";
+
+    fn db_error(err: sled::Error) -> IoError {
+        IoError::new(ErrorKind::Other, err)
+    }
+
+    #[derive(Debug, Default, BorshSerialize, BorshDeserialize, Clone)]
+    /// An implementation of ShieldedUtils that stores the shielded context
+    /// in a sled database. Writes are atomic and the handle can be cloned
+    /// to share the database between concurrent readers.
+    pub struct DbShieldedUtils {
+        #[borsh(skip)]
+        db: Option<sled::Db>,
+    }
+
+    impl DbShieldedUtils {
+        /// Initialize a shielded transaction context backed by the database
+        /// at the given path, creating it if it does not exist yet
+        pub fn new(
+            db_path: impl AsRef<Path>,
+        ) -> std::io::Result<ShieldedContext<Self>> {
+            let db = sled::open(db_path).map_err(db_error)?;
+            Ok(Self::from_db(db))
+        }
+
+        /// Initialize a shielded transaction context backed by an already
+        /// opened database
+        pub fn from_db(db: sled::Db) -> ShieldedContext<Self> {
+            // Make sure that MASP parameters are downloaded to enable MASP
+            // transaction building and verification later on
+            super::fs::ensure_masp_params();
+
+            let sync_status = match db
+                .open_tree(TREE_NAME)
+                .and_then(|tree| tree.contains_key(SPECULATIVE_KEY))
+            {
+                // Load speculative state
+                Ok(true) => ContextSyncStatus::Speculative,
+                _ => ContextSyncStatus::Confirmed,
+            };
+
+            let utils = Self { db: Some(db) };
+            ShieldedContext {
+                utils,
+                sync_status,
+                ..Default::default()
+            }
+        }
+
+        fn tree(&self) -> std::io::Result<sled::Tree> {
+            self.db
+                .as_ref()
+                .ok_or_else(|| {
+                    IoError::new(
+                        ErrorKind::NotFound,
+                        "the shielded context database is not open",
+                    )
+                })?
+                .open_tree(TREE_NAME)
+                .map_err(db_error)
+        }
+    }
+
+    #[cfg_attr(feature = "async-send", async_trait::async_trait)]
+    #[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
+    impl ShieldedUtils for DbShieldedUtils {
+        fn local_tx_prover(&self) -> LocalTxProver {
+            super::fs::load_local_tx_prover()
+        }
+
+        /// Try to load the last saved shielded context from the database. If
+        /// this fails, then leave the current context unchanged.
+        async fn load<U: ShieldedUtils + MaybeSend>(
+            &self,
+            ctx: &mut ShieldedContext<U>,
+            force_confirmed: bool,
+        ) -> std::io::Result<()> {
+            let key = if force_confirmed {
+                CONFIRMED_KEY
+            } else {
+                match ctx.sync_status {
+                    ContextSyncStatus::Confirmed => CONFIRMED_KEY,
+                    ContextSyncStatus::Speculative => SPECULATIVE_KEY,
+                }
+            };
+            let bytes =
+                self.tree()?.get(key).map_err(db_error)?.ok_or_else(|| {
+                    IoError::new(
+                        ErrorKind::NotFound,
+                        format!("no {key} shielded context in the database"),
+                    )
+                })?;
+            // Fill the supplied context with the deserialized object
+            *ctx = ShieldedContext {
+                utils: ctx.utils.clone(),
+                ..ShieldedContext::<U>::decode_versioned(&bytes)?
+            };
+            Ok(())
+        }
+
+        /// Save the given shielded context into the database. Saving a
+        /// confirmed context atomically drops the speculative one, if any.
+        async fn save<U: ShieldedUtils + MaybeSync>(
+            &self,
+            ctx: &ShieldedContext<U>,
+        ) -> std::io::Result<()> {
+            let tree = self.tree()?;
+            let bytes = ctx.encode_versioned();
+            let mut batch = sled::Batch::default();
+            match ctx.sync_status {
+                ContextSyncStatus::Confirmed => {
+                    batch.insert(CONFIRMED_KEY, bytes);
+                    // The speculative state is overruled by the confirmed one
+                    batch.remove(SPECULATIVE_KEY);
+                }
+                ContextSyncStatus::Speculative => {
+                    batch.insert(SPECULATIVE_KEY, bytes);
+                }
+            }
+            // Readers observe either the old or the new context, never a
+            // partially written one
+            tree.apply_batch(batch).map_err(db_error)?;
+            tree.flush_async().await.map_err(db_error)?;
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_confirmed_save_drops_speculative_context() {
+            let db = sled::Config::new().temporary(true).open().unwrap();
+            let utils = DbShieldedUtils { db: Some(db) };
+            let mut ctx = ShieldedContext {
+                utils: utils.clone(),
+                sync_status: ContextSyncStatus::Speculative,
+                ..Default::default()
+            };
+            utils.save(&ctx).await.unwrap();
+            let tree = utils.tree().unwrap();
+            assert!(tree.contains_key(SPECULATIVE_KEY).unwrap());
+            // A confirmed context cannot be loaded before it's been saved
+            assert!(utils.load(&mut ctx, true).await.is_err());
+
+            ctx.sync_status = ContextSyncStatus::Confirmed;
+            utils.save(&ctx).await.unwrap();
+            assert!(tree.contains_key(CONFIRMED_KEY).unwrap());
+            assert!(!tree.contains_key(SPECULATIVE_KEY).unwrap());
+            utils.load(&mut ctx, false).await.unwrap();
+        }
+    }
+}
+
 /// A enum to indicate how to log sync progress depending on
 /// whether sync is currently fetch or scanning blocks.
 #[derive(Debug, Copy, Clone)]
@@ -4,12 +4,17 @@
 // Re-export to show in rustdoc!
 use namada_core::storage::BlockHeight;
 use namada_state::{DBIter, StorageHasher, DB};
-pub use shell::Shell;
 use shell::SHELL;
+pub use shell::{
+    EthOracleStatus, NodeMode, NodeStatus, PrefixIterPage, PrefixIterRequest,
+    Shell, MAX_PREFIX_ITER_RESULTS,
+};
 pub use types::{
     EncodedResponseQuery, Error, RequestCtx, RequestQuery, ResponseQuery,
     Router,
 };
+#[cfg(any(test, feature = "async-client"))]
+pub use verifying::{QueryVerifier, VerifyingClient};
 use vp::{Vp, VP};
 
 pub use self::shell::eth_bridge::{
@@ -22,6 +27,8 @@ use crate::MaybeSend;
 mod router;
 mod shell;
 mod types;
+#[cfg(any(test, feature = "async-client"))]
+mod verifying;
 pub mod vp;
 
 // Most commonly expected patterns should be declared first
@@ -209,6 +216,13 @@ use crate::tendermint::block::Height;
 /// A client with async request dispatcher method, which can be used to invoke
 /// type-safe methods from a root [`Router`], generated
 /// via `router!` macro.
+///
+/// Only [`Client::request`] and [`Client::perform`] have to be implemented,
+/// which allows backends other than Tendermint's HTTP RPC to be plugged in.
+/// A gRPC backend may additionally override [`Client::broadcast_tx_sync`]
+/// to submit txs through its own endpoint. A client that does not trust the
+/// node it is connected to can be wrapped in a [`VerifyingClient`] to check
+/// the responses with a [`QueryVerifier`], e.g. backed by a light client.
 #[cfg(any(test, feature = "async-client"))]
 #[cfg_attr(feature = "async-send", async_trait::async_trait)]
 #[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
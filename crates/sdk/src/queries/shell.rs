@@ -2,26 +2,31 @@ use std::collections::BTreeMap;
 
 pub(super) mod eth_bridge;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
-use namada_account::{Account, AccountPublicKeysMap};
+use namada_account::{Account, AccountPublicKeysMap, AllowanceSpending};
 use namada_core::address::Address;
 use namada_core::arith::checked;
+use namada_core::chain::ChainId;
 use namada_core::dec::Dec;
+use namada_core::ethereum_structs;
 use namada_core::hash::Hash;
 use namada_core::hints;
+use namada_core::ibc::IbcTokenHash;
 use namada_core::masp::TokenMap;
+use namada_core::parameters::Parameters;
 use namada_core::storage::{
-    self, BlockHeight, BlockResults, Epoch, KeySeg, PrefixValue,
+    self, BlockHeight, BlockResults, Epoch, KeySeg, PrefixValue, TxIndex,
 };
-use namada_core::token::{Denomination, MaspDigitPos};
+use namada_core::token::{self, Denomination, MaspDigitPos};
 use namada_core::uint::Uint;
 use namada_ibc::event::IbcEventType;
+use namada_ibc::storage::{ibc_trace_key_prefix, is_ibc_trace_key};
 use namada_state::{DBIter, LastBlock, StateRead, StorageHasher, DB};
-use namada_storage::{ResultExt, StorageRead};
+use namada_storage::{tx_index, ResultExt, StorageRead};
 use namada_token::storage_key::masp_token_map_key;
 #[cfg(any(test, feature = "async-client"))]
 use namada_tx::data::TxResult;
@@ -66,12 +71,22 @@ router! {SHELL,
     // The address of the native token
     ( "native_token" ) -> Address = native_token,
 
+    // The protocol parameters
+    ( "parameters" ) -> Parameters = parameters,
+
+    // The minimum gas price of a fee token, if it is accepted for fee
+    // payment, either directly or by conversion from the native token
+    ( "gas_cost" / [token: Address] ) -> Option<token::Amount> = gas_cost,
+
     // Epoch of the input block height
     ( "epoch_at_height" / [height: BlockHeight]) -> Option<Epoch> = epoch_at_height,
 
     // Query the last committed block
     ( "last_block" ) -> Option<LastBlock> = last_block,
 
+    // The status of the queried node, which is only served by the shell
+    ( "status" ) -> NodeStatus = (with_options node_status),
+
     // First block height of the current epoch
     ( "first_block_height_of_current_epoch" ) -> BlockHeight = first_block_height_of_current_epoch,
 
@@ -79,6 +94,10 @@ router! {SHELL,
     ( "value" / [storage_key: storage::Key] )
         -> Vec<u8> = (with_options storage_value),
 
+    // Raw storage access - read the values of the Borsh-encoded list of keys
+    // given in the request data
+    ( "values" ) -> Vec<Option<Vec<u8>>> = (with_options storage_values),
+
     // Dry run a transaction
     ( "dry_run_tx" ) -> TxResult<String> = (with_options dry_run_tx),
 
@@ -86,6 +105,11 @@ router! {SHELL,
     ( "prefix" / [storage_key: storage::Key] )
         -> Vec<PrefixValue> = (with_options storage_prefix),
 
+    // Raw storage access - paginated prefix iterator, configured by the
+    // Borsh-encoded `PrefixIterRequest` given in the request data
+    ( "prefix_iter" / [storage_key: storage::Key] )
+        -> PrefixIterPage = (with_options storage_prefix_iter),
+
     // Raw storage access - is given storage key present?
     ( "has_key" / [storage_key: storage::Key] )
         -> bool = storage_has_key,
@@ -111,11 +135,97 @@ router! {SHELL,
     // Query public key revealad
     ( "revealed" / [owner: Address] ) -> bool = revealed,
 
+    // Query the allowances granted by an account, as triples of spender,
+    // token and daily cap
+    ( "allowances" / [owner: Address] ) -> Vec<(Address, Address, token::Amount)> = allowances,
+
+    // Query the spending record of an allowance
+    ( "allowance_spending" / [owner: Address] / [spender: Address] / [token: Address] ) -> AllowanceSpending = allowance_spending,
+
     // IBC UpdateClient event
     ( "ibc_client_update" / [client_id: ClientId] / [consensus_height: BlockHeight] ) -> Option<Event> = ibc_client_update,
 
     // IBC packet event
     ( "ibc_packet" / [event_type: IbcEventType] / [source_port: PortId] / [source_channel: ChannelId] / [destination_port: PortId] / [destination_channel: ChannelId] / [sequence: Sequence]) -> Option<Event> = ibc_packet,
+
+    // IBC denomination trace of an IBC token hash
+    ( "ibc_denom_trace" / [hash: IbcTokenHash] ) -> Option<String> = ibc_denom_trace,
+
+    // A page of the txs that touched the given address, if the node keeps a
+    // tx index
+    ( "tx_index" / "by_address" / [address: Address] / [page: u64] )
+        -> Vec<(BlockHeight, TxIndex)> = tx_index_by_address,
+}
+
+/// Max number of entries in a page of the paginated prefix iterator
+pub const MAX_PREFIX_ITER_RESULTS: u64 = 1000;
+
+/// A request for a page of the storage values with a matching prefix
+#[derive(Debug, Clone, Default, BorshSerialize, BorshDeserialize)]
+pub struct PrefixIterRequest {
+    /// Only return the entries after this key, which is the continuation
+    /// token of the previous page
+    pub start_after: Option<storage::Key>,
+    /// Max number of entries in the page. If zero or greater than
+    /// [`MAX_PREFIX_ITER_RESULTS`], the latter is used.
+    pub max_results: u64,
+    /// Only return the values that can be decoded as the type with this
+    /// hash, see `namada_migrations::TypeHash`. The node must have the
+    /// "migrations" feature enabled to apply this filter.
+    pub value_type: Option<[u8; 32]>,
+}
+
+/// A page of the storage values with a matching prefix
+#[derive(Debug, Clone, Default, BorshSerialize, BorshDeserialize)]
+pub struct PrefixIterPage {
+    /// The entries of the page
+    pub values: Vec<PrefixValue>,
+    /// The continuation token to request the next page with, or `None` if
+    /// this is the last page
+    pub next: Option<storage::Key>,
+}
+
+/// The status of a node
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct NodeStatus {
+    /// The chain the node runs
+    pub chain_id: ChainId,
+    /// The last committed block, if any
+    pub last_block: Option<LastBlock>,
+    /// The app hash of the last committed block, if any
+    pub app_hash: Option<Hash>,
+    /// The epoch of the last committed block
+    pub epoch: Epoch,
+    /// The mode the node runs in
+    pub mode: NodeMode,
+    /// The status of the Ethereum oracle, if the node runs one
+    pub eth_oracle: Option<EthOracleStatus>,
+    /// The number of wrapper txs the node accepted into its mempool since
+    /// the last committed block
+    pub mempool_txs: u64,
+}
+
+/// The mode a node runs in
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum NodeMode {
+    /// A validator node, with the address of the validator
+    Validator(Address),
+    /// A full node
+    Full,
+    /// A seed node
+    Seed,
+}
+
+/// The status of the Ethereum oracle of a validator node
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct EthOracleStatus {
+    /// Whether the oracle failed to query its Ethereum RPC endpoint for
+    /// longer than its stall threshold
+    pub degraded: bool,
+    /// The most recent Ethereum block processed by the oracle
+    pub last_processed_block: Option<ethereum_structs::BlockHeight>,
+    /// The number of Ethereum events waiting to be voted on
+    pub queued_events: u64,
 }
 
 // Handlers:
@@ -131,6 +241,21 @@ where
     unimplemented!("Dry running tx requires \"wasm-runtime\" feature.")
 }
 
+/// The node status includes node-local data, thus it is served by the shell
+/// itself. Other query handlers report an error instead.
+fn node_status<D, H, V, T>(
+    _ctx: RequestCtx<'_, D, H, V, T>,
+    _request: &RequestQuery,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    Err(namada_storage::Error::new_const(
+        "The node status can only be served by the shell of a node",
+    ))
+}
+
 /// Query to read block results from storage
 pub fn read_results<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -310,6 +435,37 @@ where
     Ok(data)
 }
 
+/// Query a page of the txs that touched the given address, sorted by block
+/// height and tx index. Returns no entries when the node doesn't keep a tx
+/// index.
+fn tx_index_by_address<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    address: Address,
+    page: u64,
+) -> namada_storage::Result<Vec<(BlockHeight, TxIndex)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let skip = usize::try_from(page)
+        .ok()
+        .and_then(|page| page.checked_mul(tx_index::PAGE_SIZE))
+        .ok_or_else(|| {
+            namada_storage::Error::new_const("Page number out of bounds")
+        })?;
+    let (iter, _gas) = ctx.state.db_iter_tx_index(&address);
+    iter.skip(skip)
+        .take(tx_index::PAGE_SIZE)
+        .map(|(key, _value, _gas)| {
+            tx_index::parse_stripped_key(&key).ok_or_else(|| {
+                namada_storage::Error::new_alloc(format!(
+                    "Invalid tx index key {key}"
+                ))
+            })
+        })
+        .collect()
+}
+
 fn epoch<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
 ) -> namada_storage::Result<Epoch>
@@ -332,6 +488,27 @@ where
     Ok(data)
 }
 
+fn parameters<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<Parameters>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_parameters::read(ctx.state)
+}
+
+fn gas_cost<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    token: Address,
+) -> namada_storage::Result<Option<token::Amount>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_parameters::read_gas_cost(ctx.state, &token)
+}
+
 fn epoch_at_height<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     height: BlockHeight,
@@ -386,31 +563,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let last_committed_height = ctx.state.in_mem().get_last_block_height();
-    let queried_height = {
-        let height: BlockHeight = request.height.into();
-        let is_last_height_query = height.0 == 0;
-
-        if hints::likely(is_last_height_query) {
-            last_committed_height
-        } else {
-            height
-        }
-    };
-
-    if let Some(past_height_limit) = ctx.storage_read_past_height_limit {
-        if checked!(queried_height + past_height_limit)? < last_committed_height
-        {
-            return Err(namada_storage::Error::new(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                format!(
-                    "Cannot query more than {past_height_limit} blocks in the \
-                     past (configured via \
-                     `shell.storage_read_past_height_limit`)."
-                ),
-            )));
-        }
-    }
+    let queried_height = storage_read_height(&ctx, request)?;
 
     match ctx
         .state
@@ -454,6 +607,90 @@ where
     }
 }
 
+fn storage_values<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let storage_keys: Vec<storage::Key> =
+        BorshDeserialize::try_from_slice(&request.data)
+            .into_storage_result()?;
+    let queried_height = storage_read_height(&ctx, request)?;
+
+    let mut values = Vec::with_capacity(storage_keys.len());
+    let mut ops = vec![];
+    for storage_key in &storage_keys {
+        let (value, _gas) = ctx
+            .state
+            .db_read_with_height(storage_key, queried_height)
+            .into_storage_result()?;
+        if request.prove {
+            let mut proof = match &value {
+                Some(value) => ctx.state.get_existence_proof(
+                    storage_key,
+                    value,
+                    queried_height,
+                ),
+                None => ctx
+                    .state
+                    .get_non_existence_proof(storage_key, queried_height),
+            }
+            .into_storage_result()?;
+            ops.append(&mut proof.ops);
+        }
+        values.push(value);
+    }
+    // The proofs of the keys are concatenated in the order of the keys
+    let proof = request.prove.then_some(ProofOps { ops });
+    Ok(EncodedResponseQuery {
+        data: values.serialize_to_vec(),
+        proof,
+        height: queried_height,
+        ..Default::default()
+    })
+}
+
+/// Get the height at which to read the storage for the given request, checking
+/// that it's within the configured limit of past heights that can be queried
+fn storage_read_height<D, H, V, T>(
+    ctx: &RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+) -> namada_storage::Result<BlockHeight>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let last_committed_height = ctx.state.in_mem().get_last_block_height();
+    let queried_height = {
+        let height: BlockHeight = request.height.into();
+        let is_last_height_query = height.0 == 0;
+
+        if hints::likely(is_last_height_query) {
+            last_committed_height
+        } else {
+            height
+        }
+    };
+
+    if let Some(past_height_limit) = ctx.storage_read_past_height_limit {
+        if checked!(queried_height + past_height_limit)? < last_committed_height
+        {
+            return Err(namada_storage::Error::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Cannot query more than {past_height_limit} blocks in the \
+                     past (configured via \
+                     `shell.storage_read_past_height_limit`)."
+                ),
+            )));
+        }
+    }
+    Ok(queried_height)
+}
+
 fn storage_prefix<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     request: &RequestQuery,
@@ -507,6 +744,106 @@ where
     })
 }
 
+fn storage_prefix_iter<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+    storage_key: storage::Key,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    require_latest_height(&ctx, request)?;
+
+    let PrefixIterRequest {
+        start_after,
+        max_results,
+        value_type,
+    } = if request.data.is_empty() {
+        PrefixIterRequest::default()
+    } else {
+        BorshDeserialize::try_from_slice(&request.data).into_storage_result()?
+    };
+    let max_results = if max_results == 0 {
+        MAX_PREFIX_ITER_RESULTS
+    } else {
+        max_results.min(MAX_PREFIX_ITER_RESULTS)
+    };
+    let max_results = usize::try_from(max_results).into_storage_result()?;
+    // The DB is ordered by the string representation of the keys
+    let start_after = start_after.map(|key| key.to_string());
+
+    let mut page = PrefixIterPage::default();
+    let iter = namada_storage::iter_prefix_bytes(ctx.state, &storage_key)?;
+    for iter_result in iter {
+        let (key, value) = iter_result?;
+        if let Some(start_after) = &start_after {
+            if key.to_string() <= *start_after {
+                continue;
+            }
+        }
+        if page.values.len() == max_results {
+            // There are more entries, continue after the last one returned
+            page.next = page.values.last().map(|entry| entry.key.clone());
+            break;
+        }
+        if let Some(type_hash) = &value_type {
+            if !value_has_type(&value, type_hash)? {
+                continue;
+            }
+        }
+        page.values.push(PrefixValue { key, value });
+    }
+
+    let queried_height = ctx.state.in_mem().get_last_block_height();
+    let proof = if request.prove {
+        let mut ops = vec![];
+        for PrefixValue { key, value } in &page.values {
+            let mut proof = ctx
+                .state
+                .get_existence_proof(key, value, queried_height)
+                .into_storage_result()?;
+            ops.append(&mut proof.ops);
+        }
+        Some(ProofOps { ops })
+    } else {
+        None
+    };
+    Ok(EncodedResponseQuery {
+        data: page.serialize_to_vec(),
+        proof,
+        height: queried_height,
+        ..Default::default()
+    })
+}
+
+/// Check if the given value can be decoded as the type with the given hash.
+#[cfg(feature = "migrations")]
+fn value_has_type(
+    value: &[u8],
+    type_hash: &[u8; 32],
+) -> namada_storage::Result<bool> {
+    let deserializer = namada_migrations::get_deserializer(type_hash)
+        .ok_or_else(|| {
+            namada_storage::Error::new_alloc(format!(
+                "Unknown value type hash {}",
+                data_encoding::HEXUPPER.encode(type_hash)
+            ))
+        })?;
+    Ok(deserializer(value.to_vec()).is_some())
+}
+
+/// Check if the given value can be decoded as the type with the given hash.
+#[cfg(not(feature = "migrations"))]
+fn value_has_type(
+    _value: &[u8],
+    _type_hash: &[u8; 32],
+) -> namada_storage::Result<bool> {
+    Err(namada_storage::Error::new_const(
+        "Filtering values by type requires the \"migrations\" feature",
+    ))
+}
+
 fn storage_has_key<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     storage_key: storage::Key,
@@ -571,6 +908,29 @@ where
     Ok(ctx.event_log.with_matcher(matcher).iter().next().cloned())
 }
 
+fn ibc_denom_trace<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    hash: IbcTokenHash,
+) -> namada_storage::Result<Option<String>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    // The same trace is stored for every owner and base token that received
+    // the IBC token, so the first match is returned
+    let hash = hash.to_string();
+    let prefix = ibc_trace_key_prefix(None);
+    for result in namada_storage::iter_prefix::<String>(ctx.state, &prefix)? {
+        let (key, trace) = result?;
+        if let Some((_, token_hash)) = is_ibc_trace_key(&key) {
+            if token_hash == hash {
+                return Ok(Some(trace));
+            }
+        }
+    }
+    Ok(None)
+}
+
 fn account<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     owner: Address,
@@ -608,11 +968,46 @@ where
     Ok(!public_keys.is_empty())
 }
 
+fn allowances<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+) -> namada_storage::Result<Vec<(Address, Address, token::Amount)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_account::read_allowances(ctx.state, &owner)
+}
+
+fn allowance_spending<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+    spender: Address,
+    token: Address,
+) -> namada_storage::Result<AllowanceSpending>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_account::read_allowance_spending(ctx.state, &owner, &spender, &token)
+}
+
 #[cfg(test)]
 mod test {
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    use borsh::BorshDeserialize;
+    use borsh_ext::BorshSerializeExt;
     use namada_core::address;
+    use namada_core::dec::Dec;
+    use namada_core::storage::{self, PrefixValue};
+    use namada_core::token;
+    use namada_storage::StorageWrite;
     use namada_token::storage_key::balance_key;
 
+    use super::PrefixIterRequest;
+    use crate::queries::testing::TestClient;
     use crate::queries::RPC;
 
     #[test]
@@ -623,9 +1018,15 @@ mod test {
         let token_addr = address::testing::established_address_1();
         let owner = address::testing::established_address_2();
         let key = balance_key(&token_addr, &owner);
+        let path = RPC.shell().parameters_path();
+        assert_eq!("/shell/parameters", path);
+
         let path = RPC.shell().storage_value_path(&key);
         assert_eq!(format!("/shell/value/{}", key), path);
 
+        let path = RPC.shell().storage_values_path();
+        assert_eq!("/shell/values", path);
+
         let path = RPC.shell().dry_run_tx_path();
         assert_eq!("/shell/dry_run_tx", path);
 
@@ -634,5 +1035,155 @@ mod test {
 
         let path = RPC.shell().storage_has_key_path(&key);
         assert_eq!(format!("/shell/has_key/{}", key), path);
+
+        let hash = namada_ibc::storage::calc_ibc_token_hash(
+            "transfer/channel-0/uatom",
+        );
+        let path = RPC.shell().ibc_denom_trace_path(&hash);
+        assert_eq!(format!("/shell/ibc_denom_trace/{}", hash), path);
+
+        let path = RPC.shell().tx_index_by_address_path(&owner, &2);
+        assert_eq!(format!("/shell/tx_index/by_address/{}/2", owner), path);
+
+        let path = RPC.shell().storage_prefix_iter_path(&key);
+        assert_eq!(format!("/shell/prefix_iter/{}", key), path);
+
+        let path = RPC.shell().allowances_path(&owner);
+        assert_eq!(format!("/shell/allowances/{}", owner), path);
+    }
+
+    /// Test that the allowances granted by an account are returned.
+    #[tokio::test]
+    async fn test_allowances() {
+        let mut client = TestClient::new(RPC);
+        let owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+        let other_owner = address::testing::established_address_3();
+        let nam = address::testing::nam();
+        let btc = address::testing::btc();
+        let cap = token::Amount::from_uint(100, 0).unwrap();
+        for (owner, token) in
+            [(&owner, &nam), (&owner, &btc), (&other_owner, &nam)]
+        {
+            namada_account::write_allowance(
+                &mut client.state,
+                owner,
+                &spender,
+                token,
+                Some(cap),
+            )
+            .expect("Test failed");
+        }
+        client.state.commit_block().expect("Test failed");
+
+        let allowances = RPC.shell().allowances(&client, &owner).await.unwrap();
+        assert_eq!(allowances.len(), 2);
+        assert!(allowances.contains(&(spender.clone(), nam.clone(), cap)));
+        assert!(allowances.contains(&(spender.clone(), btc, cap)));
+
+        namada_account::write_allowance(
+            &mut client.state,
+            &owner,
+            &spender,
+            &nam,
+            None,
+        )
+        .expect("Test failed");
+        client.state.commit_block().expect("Test failed");
+        let allowances = RPC.shell().allowances(&client, &owner).await.unwrap();
+        assert_eq!(allowances.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_gas_cost() {
+        let mut client = TestClient::new(RPC);
+        let nam = address::testing::nam();
+        let btc = address::testing::btc();
+        let eth = address::testing::eth();
+        let gas_cost_table = BTreeMap::from([(
+            nam.clone(),
+            token::Amount::from_uint(10, 0).unwrap(),
+        )]);
+        client
+            .state
+            .write(
+                &namada_parameters::storage::get_gas_cost_key(),
+                gas_cost_table,
+            )
+            .expect("Test failed");
+        namada_parameters::update_fee_conversion_rates(
+            &mut client.state,
+            BTreeMap::from([(btc.clone(), Dec::from_str("2.5").unwrap())]),
+        )
+        .expect("Test failed");
+        client.state.commit_block().expect("Test failed");
+
+        let gas_cost = RPC.shell().gas_cost(&client, &nam).await.unwrap();
+        assert_eq!(gas_cost, Some(token::Amount::from_uint(10, 0).unwrap()));
+        // the gas cost of a token priced by conversion is rounded up
+        let gas_cost = RPC.shell().gas_cost(&client, &btc).await.unwrap();
+        assert_eq!(gas_cost, Some(token::Amount::from_uint(25, 0).unwrap()));
+        let gas_cost = RPC.shell().gas_cost(&client, &eth).await.unwrap();
+        assert_eq!(gas_cost, None);
+    }
+
+    /// Test that the node status is not served outside of the shell of a
+    /// node, instead of panicking.
+    #[tokio::test]
+    async fn test_node_status_not_served() {
+        let client = TestClient::new(RPC);
+        let result = RPC.shell().node_status(&client, None, None, false).await;
+        assert!(result.is_err());
+    }
+
+    /// Test that the values with a matching prefix are returned page by
+    /// page.
+    #[tokio::test]
+    async fn test_storage_prefix_iter() {
+        let mut client = TestClient::new(RPC);
+        let prefix = storage::Key::parse("test").unwrap();
+        for i in 0..5_u64 {
+            let key = prefix.push(&format!("key{i}")).unwrap();
+            client.state.write(&key, i).expect("Test failed");
+        }
+        let other_key = storage::Key::parse("other").unwrap();
+        client.state.write(&other_key, 5_u64).expect("Test failed");
+        client.state.commit_block().expect("Test failed");
+
+        let mut request = PrefixIterRequest {
+            max_results: 2,
+            ..Default::default()
+        };
+        let mut values = vec![];
+        let mut num_pages = 0;
+        loop {
+            let page = RPC
+                .shell()
+                .storage_prefix_iter(
+                    &client,
+                    Some(request.serialize_to_vec()),
+                    None,
+                    false,
+                    &prefix,
+                )
+                .await
+                .unwrap()
+                .data;
+            assert!(page.values.len() <= 2);
+            num_pages += 1;
+            values.extend(page.values);
+            match page.next {
+                Some(next) => request.start_after = Some(next),
+                None => break,
+            }
+        }
+        assert_eq!(num_pages, 3);
+        let values: Vec<u64> = values
+            .into_iter()
+            .map(|PrefixValue { value, .. }| {
+                u64::try_from_slice(&value).unwrap()
+            })
+            .collect();
+        assert_eq!(values, vec![0, 1, 2, 3, 4]);
     }
 }
@@ -9,13 +9,17 @@ use namada_core::address::Address;
 use namada_core::arith::checked;
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::eth_abi::{Encode, EncodeCell};
-use namada_core::eth_bridge_pool::{PendingTransfer, PendingTransferAppendix};
+use namada_core::eth_bridge_pool::{
+    erc20_token_address, get_epoch_utilization_key, EpochUtilization,
+    PendingTransfer, PendingTransferAppendix, TransferToEthereumKind,
+    BRIDGE_POOL_ADDRESS,
+};
 use namada_core::ethereum_events::{
-    EthAddress, EthereumEvent, TransferToEthereum,
+    EthAddress, EthereumEvent, TransferToEthereum, Uint,
 };
 use namada_core::keccak::KeccakHash;
 use namada_core::storage::{BlockHeight, DbKeySeg, Epoch, Key};
-use namada_core::token::Amount;
+use namada_core::token::{Amount, Denomination};
 use namada_core::voting_power::FractionalVotingPower;
 use namada_core::{ethereum_structs, hints};
 use namada_ethereum_bridge::event::{BpTransferStatus, BridgePoolTxHash};
@@ -24,12 +28,15 @@ use namada_ethereum_bridge::protocol::transactions::votes::{
 };
 use namada_ethereum_bridge::storage::bridge_pool::get_key_from_hash;
 use namada_ethereum_bridge::storage::eth_bridge_queries::EthBridgeQueries;
-use namada_ethereum_bridge::storage::parameters::UpgradeableContract;
+use namada_ethereum_bridge::storage::parameters::{
+    read_native_erc20_address, UpgradeableContract,
+};
 use namada_ethereum_bridge::storage::proof::{sort_sigs, EthereumProof};
 use namada_ethereum_bridge::storage::vote_tallies::{eth_msgs_prefix, Keys};
 use namada_ethereum_bridge::storage::{
     bridge_contract_key, native_erc20_key, vote_tallies,
 };
+use namada_ethereum_bridge::ADDRESS as ETH_BRIDGE_ADDRESS;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -37,6 +44,7 @@ use namada_proof_of_stake::pos_queries::PosQueries;
 use namada_state::MembershipProof::BridgePool;
 use namada_state::{DBIter, StorageHasher, StoreRef, StoreType, DB};
 use namada_storage::{CustomError, ResultExt, StorageRead};
+use namada_token::read_balance;
 use namada_vote_ext::validator_set_update::{
     ValidatorSetArgs, VotingPowersMap,
 };
@@ -113,6 +121,158 @@ impl Erc20FlowControl {
     }
 }
 
+/// Contains the metadata of some ERC20 asset bridged to Namada.
+#[derive(
+    Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct Erc20Metadata {
+    /// The address of the ERC20 asset on Ethereum.
+    pub eth_address: EthAddress,
+    /// The address of the wrapped asset in Namada.
+    pub token: Address,
+    /// The ticker symbol of the asset, if one has been registered.
+    pub symbol: Option<String>,
+    /// The denomination of the asset, if it has been whitelisted.
+    pub denom: Option<Denomination>,
+}
+
+/// Contains the amount of some ERC20 asset queued in the Bridge
+/// pool during the current epoch, and its respective epoch cap.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub struct Erc20EpochUtilization {
+    /// The epoch at which the query was performed.
+    pub epoch: Epoch,
+    /// The amount of the asset queued in the Bridge pool during `epoch`.
+    pub queued: Amount,
+    /// The maximum amount of the asset that can be queued in the
+    /// Bridge pool per epoch, if any.
+    pub epoch_cap: Option<Amount>,
+}
+
+impl Erc20EpochUtilization {
+    /// Check if queueing `transferred_amount` exceeds the epoch cap of
+    /// some ERC20 asset.
+    #[inline]
+    pub fn exceeds_epoch_cap(
+        &self,
+        transferred_amount: Amount,
+    ) -> crate::error::Result<bool> {
+        let Some(epoch_cap) = self.epoch_cap else {
+            return Ok(false);
+        };
+        Ok(checked!(self.queued + transferred_amount)? > epoch_cap)
+    }
+}
+
+/// Accounting of the NAM escrowed by the Ethereum bridge,
+/// reconciled against the contents of the Bridge pool.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub struct NativeEscrow {
+    /// NAM escrowed under the Ethereum bridge account, backing
+    /// the wNAM minted on Ethereum or pending to be minted.
+    pub escrowed: Amount,
+    /// Amount of wNAM in pending transfers to Ethereum.
+    pub pending_transfers: Amount,
+    /// NAM held by the Bridge pool account.
+    pub pool_balance: Amount,
+    /// NAM paid as gas fees of pending transfers to Ethereum.
+    pub pending_gas_fees: Amount,
+}
+
+impl NativeEscrow {
+    /// Check if the escrowed NAM covers the wNAM pending to be
+    /// minted, and the NAM gas fees of pending transfers.
+    #[inline]
+    pub fn is_solvent(&self) -> bool {
+        self.escrowed >= self.pending_transfers
+            && self.pool_balance >= self.pending_gas_fees
+    }
+}
+
+/// Accounting of some wrapped ERC20 asset escrowed in the
+/// Bridge pool, reconciled against its pending transfers.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub struct Erc20Escrow {
+    /// Total minted supply of the wrapped asset in Namada.
+    pub supply: Amount,
+    /// Wrapped asset held by the Bridge pool account.
+    pub pool_balance: Amount,
+    /// Amount of the asset in pending transfers to Ethereum.
+    pub pending_transfers: Amount,
+    /// Wrapped asset paid as gas fees of pending transfers to Ethereum.
+    pub pending_gas_fees: Amount,
+}
+
+impl Erc20Escrow {
+    /// Check if the wrapped asset held by the Bridge pool covers its
+    /// pending transfers and gas fees, and does not exceed its supply.
+    #[inline]
+    pub fn is_solvent(&self) -> crate::error::Result<bool> {
+        let pending = checked!(self.pending_transfers + self.pending_gas_fees)?;
+        Ok(self.pool_balance >= pending && self.pool_balance <= self.supply)
+    }
+}
+
+/// The status of a batch of transfers to Namada, identified by the
+/// nonce of its `TransfersToNamada` Ethereum event.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub enum TransferToNamadaStatus {
+    /// No validator has voted on an event with the queried nonce yet.
+    Unseen,
+    /// Validators are voting on the event, and the given fraction
+    /// of the voting power is backing it.
+    Voting(FractionalVotingPower),
+    /// The event has been seen by a quorum of validators, but it is
+    /// queued until the events with lower nonces are applied.
+    Queued,
+    /// The transfers in the event have been applied to Namada.
+    Applied,
+}
+
 /// Request data to pass to `generate_bridge_pool_proof`.
 #[derive(Debug, Clone, Eq, PartialEq, BorshSerialize, BorshDeserialize)]
 pub struct GenBridgePoolProofReq<'transfers, 'relayer> {
@@ -188,6 +348,11 @@ router! {ETH_BRIDGE,
     ( "pool" / "transfer_status" )
         -> TransferToEthereumStatus = (with_options pending_eth_transfer_status),
 
+    // Read the gas fees topped up on the transfers in the Ethereum
+    // bridge pool, indexed by the hashes of the transfers.
+    ( "pool" / "fee_top_ups" )
+        -> HashMap<KeccakHash, Amount> = read_fee_top_ups,
+
     // Request a proof of a validator set signed off for
     // the given epoch.
     //
@@ -232,6 +397,31 @@ router! {ETH_BRIDGE,
     // ERC20 token in Namada.
     ( "erc20" / "flow_control" / [asset: EthAddress] )
         -> Erc20FlowControl = get_erc20_flow_control,
+
+    // Read the amount of some wrapped ERC20 token queued in the
+    // Bridge pool during the current epoch, and its epoch cap.
+    ( "erc20" / "epoch_utilization" / [asset: EthAddress] )
+        -> Erc20EpochUtilization = get_erc20_epoch_utilization,
+
+    // Read the metadata registered for some wrapped ERC20 token
+    // in Namada.
+    ( "erc20" / "metadata" / [asset: EthAddress] )
+        -> Erc20Metadata = get_erc20_metadata,
+
+    // Check whether the `TransfersToNamada` Ethereum event with the
+    // given nonce is unseen, being voted on, queued or applied.
+    ( "transfers_to_namada" / "status" / [nonce: Uint] )
+        -> TransferToNamadaStatus = transfer_to_namada_status,
+
+    // Read the NAM escrowed by the Ethereum bridge, reconciled
+    // against the contents of the Bridge pool.
+    ( "escrow" / "native" )
+        -> NativeEscrow = read_native_escrow,
+
+    // Read the amount of some wrapped ERC20 token escrowed in the
+    // Bridge pool, reconciled against its pending transfers.
+    ( "escrow" / "erc20" / [asset: EthAddress] )
+        -> Erc20Escrow = read_erc20_escrow,
 }
 
 /// Given a list of keccak hashes, check whether they have been
@@ -363,6 +553,190 @@ where
     })
 }
 
+/// Read the amount of some wrapped ERC20 token queued in the
+/// Bridge pool during the current epoch, and its epoch cap.
+fn get_erc20_epoch_utilization<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    asset: EthAddress,
+) -> namada_storage::Result<Erc20EpochUtilization>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch = ctx.state.in_mem().get_current_epoch().0;
+    let queued = ctx
+        .state
+        .read::<EpochUtilization>(&get_epoch_utilization_key(&asset))?
+        .unwrap_or_default()
+        .queued_at(epoch);
+    let epoch_cap = ctx.state.ethbridge_queries().get_token_epoch_cap(&asset);
+
+    Ok(Erc20EpochUtilization {
+        epoch,
+        queued,
+        epoch_cap,
+    })
+}
+
+/// Read the metadata registered for some wrapped ERC20 token.
+fn get_erc20_metadata<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    asset: EthAddress,
+) -> namada_storage::Result<Erc20Metadata>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let ethbridge_queries = ctx.state.ethbridge_queries();
+
+    Ok(Erc20Metadata {
+        eth_address: asset,
+        token: erc20_token_address(&asset),
+        symbol: ethbridge_queries.get_token_symbol(&asset),
+        denom: ethbridge_queries.get_token_denom(&asset),
+    })
+}
+
+/// Check the status of the `TransfersToNamada` Ethereum event
+/// with the given nonce.
+fn transfer_to_namada_status<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    nonce: Uint,
+) -> namada_storage::Result<TransferToNamadaStatus>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    // events are applied in nonce order, so any event with a
+    // lower nonce than the next expected one has been applied
+    if nonce < ctx.state.ethbridge_queries().get_next_nam_transfers_nonce() {
+        return Ok(TransferToNamadaStatus::Applied);
+    }
+    for (k, v, _) in ctx.state.iter_prefix(&eth_msgs_prefix())? {
+        let mut key = Key::from_str(&k).expect(
+            "Iterating over keys from storage shouldn't not yield \
+             un-parsable keys.",
+        );
+        match key.segments.last() {
+            Some(DbKeySeg::StringSeg(ref seg))
+                if seg == Keys::segments().body => {}
+            _ => continue,
+        }
+        match EthereumEvent::try_from_slice(&v) {
+            Ok(EthereumEvent::TransfersToNamada { nonce: n, .. })
+                if n == nonce => {}
+            _ => continue,
+        }
+
+        // we checked above that key is not empty, so this write is fine
+        *key.segments.last_mut().unwrap() =
+            DbKeySeg::StringSeg(Keys::segments().seen.into());
+        let is_seen =
+            ctx.state.read::<bool>(&key).into_storage_result()?.expect(
+                "Iterating over storage should not yield keys without values.",
+            );
+        if is_seen {
+            return Ok(TransferToNamadaStatus::Queued);
+        }
+
+        *key.segments.last_mut().unwrap() =
+            DbKeySeg::StringSeg(Keys::segments().voting_power.into());
+        let voting_power = ctx
+            .state
+            .read::<EpochedVotingPower>(&key)
+            .into_storage_result()?
+            .expect(
+                "Iterating over storage should not yield keys without values.",
+            )
+            .fractional_stake(ctx.state);
+        return Ok(TransferToNamadaStatus::Voting(voting_power));
+    }
+    Ok(TransferToNamadaStatus::Unseen)
+}
+
+/// Read the NAM escrowed by the Ethereum bridge, reconciled
+/// against the contents of the Bridge pool.
+fn read_native_escrow<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<NativeEscrow>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let native_token = ctx.state.in_mem().native_token.clone();
+    let wnam_address = read_native_erc20_address(ctx.state)?;
+    let escrowed = read_balance(ctx.state, &native_token, &ETH_BRIDGE_ADDRESS)?;
+    let pool_balance =
+        read_balance(ctx.state, &native_token, &BRIDGE_POOL_ADDRESS)?;
+    let ethbridge_queries = ctx.state.ethbridge_queries();
+
+    let mut pending_transfers = Amount::zero();
+    let mut pending_gas_fees = Amount::zero();
+    for transfer in read_ethereum_bridge_pool(ctx)? {
+        if transfer.transfer.asset == wnam_address {
+            pending_transfers =
+                checked!(pending_transfers + transfer.transfer.amount)?;
+        }
+        if transfer.gas_fee.token == native_token {
+            let fee_top_up = ethbridge_queries
+                .get_transfer_fee_top_up(&transfer.keccak256());
+            pending_gas_fees = checked!(
+                pending_gas_fees + transfer.gas_fee.amount + fee_top_up
+            )?;
+        }
+    }
+
+    Ok(NativeEscrow {
+        escrowed,
+        pending_transfers,
+        pool_balance,
+        pending_gas_fees,
+    })
+}
+
+/// Read the amount of some wrapped ERC20 token escrowed in the
+/// Bridge pool, reconciled against its pending transfers.
+fn read_erc20_escrow<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    asset: EthAddress,
+) -> namada_storage::Result<Erc20Escrow>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let token = erc20_token_address(&asset);
+    let ethbridge_queries = ctx.state.ethbridge_queries();
+    let supply = ethbridge_queries
+        .get_token_supply(&asset)
+        .unwrap_or_default();
+    let pool_balance = read_balance(ctx.state, &token, &BRIDGE_POOL_ADDRESS)?;
+
+    let mut pending_transfers = Amount::zero();
+    let mut pending_gas_fees = Amount::zero();
+    for transfer in read_ethereum_bridge_pool(ctx)? {
+        if transfer.transfer.asset == asset
+            && transfer.transfer.kind == TransferToEthereumKind::Erc20
+        {
+            pending_transfers =
+                checked!(pending_transfers + transfer.transfer.amount)?;
+        }
+        if transfer.gas_fee.token == token {
+            let fee_top_up = ethbridge_queries
+                .get_transfer_fee_top_up(&transfer.keccak256());
+            pending_gas_fees = checked!(
+                pending_gas_fees + transfer.gas_fee.amount + fee_top_up
+            )?;
+        }
+    }
+
+    Ok(Erc20Escrow {
+        supply,
+        pool_balance,
+        pending_transfers,
+        pending_gas_fees,
+    })
+}
+
 /// Helper function to read a smart contract from storage.
 fn read_contract<T, D, H, V, U>(
     key: &Key,
@@ -423,6 +797,26 @@ where
     ))
 }
 
+/// Read the gas fees topped up on the transfers in the
+/// Ethereum bridge pool.
+fn read_fee_top_ups<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<HashMap<KeccakHash, Amount>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let ethbridge_queries = ctx.state.ethbridge_queries();
+    Ok(read_ethereum_bridge_pool(ctx)?
+        .into_iter()
+        .filter_map(|transfer| {
+            let hash = transfer.keccak256();
+            let fee_top_up = ethbridge_queries.get_transfer_fee_top_up(&hash);
+            (!fee_top_up.is_zero()).then_some((hash, fee_top_up))
+        })
+        .collect())
+}
+
 /// Read the contents of the Ethereum bridge
 /// pool covered by the latest signed root.
 fn read_signed_ethereum_bridge_pool<D, H, V, T>(
@@ -843,6 +1237,7 @@ mod test_ethbridge_router {
     use namada_core::eth_bridge_pool::{
         GasFee, TransferToEthereum, TransferToEthereumKind,
     };
+    use namada_core::storage::InnerEthEventsQueue;
     use namada_core::voting_power::EthBridgeVotingPower;
     use namada_ethereum_bridge::protocol::transactions::validator_set_update::aggregate_votes;
     use namada_ethereum_bridge::storage::bridge_pool::{
@@ -852,6 +1247,7 @@ mod test_ethbridge_router {
     use namada_ethereum_bridge::storage::whitelist;
     use namada_storage::mockdb::MockDBWriteBatch;
     use namada_storage::StorageWrite;
+    use namada_token::storage_key::balance_key;
     use namada_vote_ext::validator_set_update;
     use namada_vote_ext::validator_set_update::{
         EthAddrBook, VotingPowersMapExt,
@@ -1652,6 +2048,307 @@ mod test_ethbridge_router {
         );
     }
 
+    /// Test reading the amount of an ERC20 token queued in the
+    /// Bridge pool during the current epoch, and its epoch cap.
+    #[tokio::test]
+    async fn test_get_erc20_epoch_utilization() {
+        const ERC20_TOKEN: EthAddress = EthAddress([0; 20]);
+
+        let mut client = TestClient::new(RPC);
+
+        // initialize storage
+        test_utils::init_default_storage(&mut client.state);
+
+        // nothing has been queued, and there is no cap
+        let result = RPC
+            .shell()
+            .eth_bridge()
+            .get_erc20_epoch_utilization(&client, &ERC20_TOKEN)
+            .await
+            .expect("Test failed");
+        assert!(result.queued.is_zero());
+        assert_eq!(result.epoch_cap, None);
+        assert!(!result
+            .exceeds_epoch_cap(Amount::max())
+            .expect("Test failed"));
+
+        // write the utilization and the epoch cap to storage
+        let queued_amount = Amount::native_whole(123);
+        let cap_amount = Amount::native_whole(200);
+        let utilization = EpochUtilization::default()
+            .queue(result.epoch, queued_amount)
+            .expect("Test failed");
+        client
+            .state
+            .write(&get_epoch_utilization_key(&ERC20_TOKEN), utilization)
+            .expect("Test failed");
+        let key = whitelist::Key {
+            asset: ERC20_TOKEN,
+            suffix: whitelist::KeyType::EpochCap,
+        }
+        .into();
+        client.state.write(&key, cap_amount).expect("Test failed");
+
+        let result = RPC
+            .shell()
+            .eth_bridge()
+            .get_erc20_epoch_utilization(&client, &ERC20_TOKEN)
+            .await
+            .expect("Test failed");
+        assert_eq!(result.queued, queued_amount);
+        assert_eq!(result.epoch_cap, Some(cap_amount));
+        assert!(!result
+            .exceeds_epoch_cap(Amount::native_whole(77))
+            .expect("Test failed"));
+        assert!(result
+            .exceeds_epoch_cap(Amount::native_whole(78))
+            .expect("Test failed"));
+    }
+
+    /// Test reading the metadata registered for an ERC20 token.
+    #[tokio::test]
+    async fn test_get_erc20_metadata() {
+        const ERC20_TOKEN: EthAddress = EthAddress([0; 20]);
+
+        let mut client = TestClient::new(RPC);
+
+        // initialize storage
+        test_utils::init_default_storage(&mut client.state);
+
+        // nothing has been registered for the token
+        let result = RPC
+            .shell()
+            .eth_bridge()
+            .get_erc20_metadata(&client, &ERC20_TOKEN)
+            .await
+            .expect("Test failed");
+        assert_eq!(result.eth_address, ERC20_TOKEN);
+        assert_eq!(result.token, erc20_token_address(&ERC20_TOKEN));
+        assert_eq!(result.symbol, None);
+        assert_eq!(result.denom, None);
+
+        // register the symbol and denomination of the token
+        let key = whitelist::Key {
+            asset: ERC20_TOKEN,
+            suffix: whitelist::KeyType::Symbol,
+        }
+        .into();
+        client
+            .state
+            .write(&key, "USDC".to_string())
+            .expect("Test failed");
+        let key = whitelist::Key {
+            asset: ERC20_TOKEN,
+            suffix: whitelist::KeyType::Denomination,
+        }
+        .into();
+        client
+            .state
+            .write(&key, Denomination(6))
+            .expect("Test failed");
+
+        let result = RPC
+            .shell()
+            .eth_bridge()
+            .get_erc20_metadata(&client, &ERC20_TOKEN)
+            .await
+            .expect("Test failed");
+        assert_eq!(result.symbol.as_deref(), Some("USDC"));
+        assert_eq!(result.denom, Some(Denomination(6)));
+    }
+
+    /// Test querying the status of transfers to Namada by the
+    /// nonce of their Ethereum event.
+    #[tokio::test]
+    async fn test_transfer_to_namada_status() {
+        let mut client = TestClient::new(RPC);
+        let (_, dummy_validator_stake) = test_utils::default_validator();
+        test_utils::init_default_storage(&mut client.state);
+        client
+            .state
+            .in_mem_mut()
+            .eth_events_queue
+            .transfers_to_namada = InnerEthEventsQueue::new_at(1.into());
+
+        // write a partially voted on event with nonce 1
+        let eth_event = EthereumEvent::TransfersToNamada {
+            nonce: 1.into(),
+            transfers: vec![],
+        };
+        let eth_msg_key = vote_tallies::Keys::from(&eth_event);
+        let voting_power = FractionalVotingPower::HALF;
+        client
+            .state
+            .write(&eth_msg_key.body(), eth_event)
+            .expect("Test failed");
+        client
+            .state
+            .write(
+                &eth_msg_key.voting_power(),
+                EpochedVotingPower::from([(
+                    0.into(),
+                    voting_power * dummy_validator_stake,
+                )]),
+            )
+            .expect("Test failed");
+        client
+            .state
+            .write(&eth_msg_key.seen(), false)
+            .expect("Test failed");
+        client
+            .state
+            .commit_block_from_batch(MockDBWriteBatch)
+            .expect("Test failed");
+        client.state.in_mem_mut().block.height += 1;
+
+        let client_ref = &client;
+        let status = move |nonce: u64| async move {
+            RPC.shell()
+                .eth_bridge()
+                .transfer_to_namada_status(client_ref, &nonce.into())
+                .await
+                .expect("Test failed")
+        };
+        assert_eq!(status(0).await, TransferToNamadaStatus::Applied);
+        assert_eq!(
+            status(1).await,
+            TransferToNamadaStatus::Voting(voting_power)
+        );
+        assert_eq!(status(2).await, TransferToNamadaStatus::Unseen);
+
+        // the event is seen by a quorum of validators
+        client
+            .state
+            .write(&eth_msg_key.seen(), true)
+            .expect("Test failed");
+        client
+            .state
+            .commit_block_from_batch(MockDBWriteBatch)
+            .expect("Test failed");
+        client.state.in_mem_mut().block.height += 1;
+        let status = RPC
+            .shell()
+            .eth_bridge()
+            .transfer_to_namada_status(&client, &1.into())
+            .await
+            .expect("Test failed");
+        assert_eq!(status, TransferToNamadaStatus::Queued);
+    }
+
+    /// Test reconciling the escrowed NAM and wrapped ERC20 assets
+    /// against the contents of the Bridge pool.
+    #[tokio::test]
+    async fn test_read_bridge_escrows() {
+        const ERC20_TOKEN: EthAddress = EthAddress([0; 20]);
+
+        let mut client = TestClient::new(RPC);
+        test_utils::init_default_storage(&mut client.state);
+        let wnam = read_native_erc20_address(&client.state).unwrap();
+        let erc20 = erc20_token_address(&ERC20_TOKEN);
+
+        // queue a transfer of wNAM, paying gas fees in NAM, and
+        // a transfer of some ERC20, paying gas fees in that same ERC20
+        let wnam_transfer = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                asset: wnam,
+                recipient: EthAddress([0; 20]),
+                sender: bertha_address(),
+                amount: 10.into(),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: 1.into(),
+                payer: bertha_address(),
+            },
+        };
+        let erc20_transfer = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                asset: ERC20_TOKEN,
+                recipient: EthAddress([0; 20]),
+                sender: bertha_address(),
+                amount: 20.into(),
+            },
+            gas_fee: GasFee {
+                token: erc20.clone(),
+                amount: 2.into(),
+                payer: bertha_address(),
+            },
+        };
+        client.state.in_mem_mut().block.height = 1.into();
+        for transfer in [&wnam_transfer, &erc20_transfer] {
+            client
+                .state
+                .write(&get_pending_key(transfer), transfer)
+                .expect("Test failed");
+        }
+
+        // write the escrowed balances
+        let writes = [
+            (balance_key(&nam(), &ETH_BRIDGE_ADDRESS), Amount::from(100)),
+            (balance_key(&nam(), &BRIDGE_POOL_ADDRESS), Amount::from(1)),
+            (balance_key(&erc20, &BRIDGE_POOL_ADDRESS), Amount::from(22)),
+            (
+                whitelist::Key {
+                    asset: ERC20_TOKEN,
+                    suffix: whitelist::KeyType::WrappedSupply,
+                }
+                .into(),
+                Amount::from(50),
+            ),
+        ];
+        for (key, amount) in writes {
+            client.state.write(&key, amount).expect("Test failed");
+        }
+
+        // commit the changes and increase block height
+        client.state.commit_block().expect("Test failed");
+        client.state.in_mem_mut().block.height += 1;
+
+        let native = RPC
+            .shell()
+            .eth_bridge()
+            .read_native_escrow(&client)
+            .await
+            .unwrap();
+        assert_eq!(
+            native,
+            NativeEscrow {
+                escrowed: 100.into(),
+                pending_transfers: 10.into(),
+                pool_balance: 1.into(),
+                pending_gas_fees: 1.into(),
+            }
+        );
+        assert!(native.is_solvent());
+
+        let escrow = RPC
+            .shell()
+            .eth_bridge()
+            .read_erc20_escrow(&client, &ERC20_TOKEN)
+            .await
+            .unwrap();
+        assert_eq!(
+            escrow,
+            Erc20Escrow {
+                supply: 50.into(),
+                pool_balance: 22.into(),
+                pending_transfers: 20.into(),
+                pending_gas_fees: 2.into(),
+            }
+        );
+        assert!(escrow.is_solvent().unwrap());
+
+        // an escrow that does not cover the pending
+        // transfers is not solvent
+        let escrow = Erc20Escrow {
+            pool_balance: 21.into(),
+            ..escrow
+        };
+        assert!(!escrow.is_solvent().unwrap());
+    }
+
     /// Test that querying the status of the Bridge pool
     /// returns the expected keccak hashes.
     #[tokio::test]
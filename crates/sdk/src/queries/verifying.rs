@@ -0,0 +1,203 @@
+//! A [`Client`] that checks the responses of the queries it forwards to
+//! another client, e.g. against the app hashes tracked by a light client, so
+//! that the node serving the queries doesn't have to be trusted.
+
+use namada_core::storage::BlockHeight;
+use tendermint_rpc::Error as RpcError;
+
+use super::{Client, EncodedResponseQuery};
+use crate::{MaybeSend, MaybeSync};
+
+/// Capability to verify the responses of queries. A verifier decides which
+/// queries need a proof and checks the response against it, e.g. by checking
+/// the proof of a storage value against the app hash of a header verified by
+/// a light client.
+pub trait QueryVerifier {
+    /// Whether a proof must be requested for the query at the given path
+    fn requires_proof(&self, path: &str) -> bool;
+
+    /// Check the response to the query at the given path. Returns a
+    /// description of the failure if the response can't be trusted.
+    fn verify(
+        &self,
+        path: &str,
+        data: Option<&[u8]>,
+        response: &EncodedResponseQuery,
+    ) -> Result<(), String>;
+}
+
+/// A client that forwards the queries to the wrapped client and checks their
+/// responses with a [`QueryVerifier`]. Txs are broadcast with the wrapped
+/// client, so a client with its own broadcasting endpoint (e.g. gRPC) keeps
+/// using it.
+#[derive(Debug, Clone)]
+pub struct VerifyingClient<C, V> {
+    /// The client the queries are forwarded to
+    pub client: C,
+    /// The verifier of the responses
+    pub verifier: V,
+}
+
+impl<C, V> VerifyingClient<C, V> {
+    /// Wrap the given client to verify its responses with `verifier`
+    pub fn new(client: C, verifier: V) -> Self {
+        Self { client, verifier }
+    }
+}
+
+#[cfg_attr(feature = "async-send", async_trait::async_trait)]
+#[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
+impl<C, V> Client for VerifyingClient<C, V>
+where
+    C: Client + MaybeSync,
+    V: QueryVerifier + MaybeSync,
+{
+    type Error = C::Error;
+
+    async fn request(
+        &self,
+        path: String,
+        data: Option<Vec<u8>>,
+        height: Option<BlockHeight>,
+        prove: bool,
+    ) -> Result<EncodedResponseQuery, Self::Error> {
+        let prove = prove || self.verifier.requires_proof(&path);
+        let response = self
+            .client
+            .request(path.clone(), data.clone(), height, prove)
+            .await?;
+        self.verifier
+            .verify(&path, data.as_deref(), &response)
+            .map_err(|err| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "Failed to verify the response of query {path}: {err}"
+                    ),
+                )
+            })?;
+        Ok(response)
+    }
+
+    async fn broadcast_tx_sync(
+        &self,
+        tx: impl Into<Vec<u8>> + MaybeSend,
+    ) -> Result<tendermint_rpc::endpoint::broadcast::tx_sync::Response, RpcError>
+    {
+        self.client.broadcast_tx_sync(tx).await
+    }
+
+    async fn perform<R>(&self, request: R) -> Result<R::Output, RpcError>
+    where
+        R: tendermint_rpc::SimpleRequest,
+    {
+        self.client.perform(request).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// A client that records the queries it receives and answers them with
+    /// an empty response
+    #[derive(Default)]
+    struct RecordingClient {
+        requests: Mutex<Vec<(String, bool)>>,
+    }
+
+    #[cfg_attr(feature = "async-send", async_trait::async_trait)]
+    #[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
+    impl Client for RecordingClient {
+        type Error = std::io::Error;
+
+        async fn request(
+            &self,
+            path: String,
+            _data: Option<Vec<u8>>,
+            _height: Option<BlockHeight>,
+            prove: bool,
+        ) -> Result<EncodedResponseQuery, Self::Error> {
+            self.requests.lock().unwrap().push((path, prove));
+            Ok(EncodedResponseQuery {
+                data: vec![],
+                info: Default::default(),
+                proof: None,
+                height: BlockHeight::default(),
+            })
+        }
+
+        async fn perform<R>(&self, _request: R) -> Result<R::Output, RpcError>
+        where
+            R: tendermint_rpc::SimpleRequest,
+        {
+            unimplemented!()
+        }
+    }
+
+    /// A verifier that requires proofs for storage values and rejects the
+    /// responses to the given path
+    struct TestVerifier {
+        rejected_path: &'static str,
+    }
+
+    impl QueryVerifier for TestVerifier {
+        fn requires_proof(&self, path: &str) -> bool {
+            path.starts_with("/shell/value/")
+        }
+
+        fn verify(
+            &self,
+            path: &str,
+            _data: Option<&[u8]>,
+            _response: &EncodedResponseQuery,
+        ) -> Result<(), String> {
+            if path == self.rejected_path {
+                Err("rejected".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Test that a proof is requested for the queries that require one and
+    /// that the responses failing verification are rejected
+    #[tokio::test]
+    async fn test_verifying_client() {
+        let client = VerifyingClient::new(
+            RecordingClient::default(),
+            TestVerifier {
+                rejected_path: "/shell/epoch",
+            },
+        );
+        let value_path = "/shell/value/#tnam1".to_string();
+        assert!(
+            client
+                .request(value_path.clone(), None, None, false)
+                .await
+                .is_ok()
+        );
+        assert!(
+            client
+                .request("/shell/parameters".to_string(), None, None, false)
+                .await
+                .is_ok()
+        );
+        assert!(
+            client
+                .request("/shell/epoch".to_string(), None, None, false)
+                .await
+                .is_err()
+        );
+        assert_eq!(
+            *client.client.requests.lock().unwrap(),
+            vec![
+                (value_path, true),
+                ("/shell/parameters".to_string(), false),
+                ("/shell/epoch".to_string(), false),
+            ]
+        );
+    }
+}
@@ -2,7 +2,7 @@
 
 use namada_governance::parameters::GovernanceParameters;
 use namada_governance::storage::proposal::StorageProposal;
-use namada_governance::utils::{ProposalResult, Vote};
+use namada_governance::utils::{ProposalResult, ProposalSummary, Vote};
 use namada_state::{DBIter, StorageHasher, DB};
 
 use crate::queries::types::RequestCtx;
@@ -11,6 +11,7 @@ use crate::queries::types::RequestCtx;
 router! {GOV,
     ( "proposal" / [id: u64 ] ) -> Option<StorageProposal> = proposal_id,
     ( "proposal" / [id: u64 ] / "votes" ) -> Vec<Vote> = proposal_id_votes,
+    ( "proposals" / [from: u64] / [count: u64] ) -> Vec<ProposalSummary> = proposals,
     ( "parameters" ) -> GovernanceParameters = parameters,
     ( "stored_proposal_result" / [id: u64] ) -> Option<ProposalResult> = proposal_result,
 }
@@ -27,6 +28,29 @@ where
     namada_governance::storage::get_proposal_by_id(ctx.state, id)
 }
 
+/// Maximum number of proposals returned by a single paginated proposals query
+pub const MAX_PROPOSALS_PAGE_SIZE: u64 = 100;
+
+/// Query the summaries of at most `count` proposals (capped at
+/// [`MAX_PROPOSALS_PAGE_SIZE`]), starting from the proposal id `from`
+fn proposals<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    from: u64,
+    count: u64,
+) -> namada_storage::Result<Vec<ProposalSummary>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let current_epoch = ctx.state.in_mem().last_epoch;
+    namada_governance::storage::get_proposal_summaries(
+        ctx.state,
+        from,
+        count.min(MAX_PROPOSALS_PAGE_SIZE),
+        current_epoch,
+    )
+}
+
 /// Query all the votes for the given proposal id
 fn proposal_id_votes<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
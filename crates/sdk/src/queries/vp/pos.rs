@@ -1,11 +1,13 @@
 //! Queries router and handlers for PoS validity predicate
 
+use std::cmp;
 use std::collections::{BTreeMap, BTreeSet};
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::address::Address;
 use namada_core::arith::{self, checked};
 use namada_core::collections::{HashMap, HashSet};
+use namada_core::dec::Dec;
 use namada_core::key::common;
 use namada_core::storage::Epoch;
 use namada_core::token;
@@ -17,7 +19,8 @@ use namada_proof_of_stake::slashing::{
     find_all_enqueued_slashes, find_all_slashes,
 };
 use namada_proof_of_stake::storage::{
-    bond_handle, read_all_validator_addresses,
+    bond_handle, liveness_sum_missed_votes_handle,
+    read_all_validator_addresses,
     read_below_capacity_validator_set_addresses_with_stake,
     read_consensus_validator_set_addresses_with_stake, read_pos_params,
     read_total_stake, read_validator_avatar, read_validator_description,
@@ -69,6 +72,9 @@ router! {POS,
 
         ( "last_infraction_epoch" / [validator: Address] )
             -> Option<Epoch> = validator_last_infraction_epoch,
+
+        ( "liveness" / [validator: Address] )
+            -> Option<ValidatorLiveness> = validator_liveness,
     },
 
     ( "validator_set" ) = {
@@ -153,6 +159,22 @@ pub struct Enriched<T> {
     pub total_withdrawable: token::Amount,
 }
 
+/// Liveness statistics of a consensus validator over the sliding window of
+/// blocks used to decide whether it should be jailed for missing votes.
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct ValidatorLiveness {
+    /// Number of the most recent blocks the statistics refer to. This is the
+    /// `liveness_window_check` parameter, unless the chain is younger.
+    pub window: u64,
+    /// Number of blocks in the window the validator didn't sign
+    pub missed_votes: u64,
+    /// Number of blocks in the window the validator signed
+    pub signed_votes: u64,
+    /// Minimum fraction of signed blocks in the window, below which the
+    /// validator gets jailed
+    pub threshold: Dec,
+}
+
 /// Bonds and unbonds with all details (slashes and rewards, if any) grouped by
 /// their bond IDs enriched with extra information calculated from the data
 /// queried from the node.
@@ -327,6 +349,34 @@ where
     read_validator_last_slash_epoch(ctx.state, &validator)
 }
 
+/// Get the liveness statistics of a validator. Returns `None` when there's no
+/// liveness data for the given address, i.e. when it's not a consensus
+/// validator.
+fn validator_liveness<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+) -> namada_storage::Result<Option<ValidatorLiveness>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let Some(missed_votes) =
+        liveness_sum_missed_votes_handle().get(ctx.state, &validator)?
+    else {
+        return Ok(None);
+    };
+    let params = read_pos_params(ctx.state)?;
+    let last_height = ctx.state.in_mem().get_last_block_height();
+    let window = cmp::min(params.liveness_window_check, last_height.0);
+
+    Ok(Some(ValidatorLiveness {
+        window,
+        missed_votes,
+        signed_votes: window.checked_sub(missed_votes).unwrap_or_default(),
+        threshold: params.liveness_threshold,
+    }))
+}
+
 /// Get the total stake of a validator at the given epoch or current when
 /// `None`. The total stake is a sum of validator's self-bonds and delegations
 /// to their address.
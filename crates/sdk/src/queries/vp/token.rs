@@ -1,8 +1,12 @@
 //! Token validity predicate queries
 
-use namada_core::address::Address;
+use borsh::BorshDeserialize;
+use namada_core::address::{Address, InternalAddress};
+use namada_core::storage::{self, KeySeg};
 use namada_core::token;
 use namada_state::{DBIter, StorageHasher, DB};
+use namada_storage::ResultExt;
+use namada_token::storage_key::is_any_token_balance_key;
 use namada_token::{
     get_effective_total_native_supply, read_denom, read_total_supply,
 };
@@ -12,6 +16,7 @@ use crate::queries::RequestCtx;
 router! {TOKEN,
     ( "denomination" / [addr: Address] ) -> Option<token::Denomination> = denomination,
     ( "total_supply" / [addr: Address] ) -> token::Amount = total_supply,
+    ( "balances" / [owner: Address] ) -> Vec<(Address, token::Amount)> = balances,
 }
 
 /// Get the number of decimal places (in base 10) for a
@@ -44,6 +49,38 @@ where
     }
 }
 
+/// Get all the non-zero balances of the given `owner`, paired with the
+/// addresses of their tokens.
+fn balances<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+) -> namada_storage::Result<Vec<(Address, token::Amount)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let multitoken_prefix = storage::Key::from(
+        Address::Internal(InternalAddress::Multitoken).to_db_key(),
+    );
+    let mut balances = vec![];
+    for result in
+        namada_storage::iter_prefix_bytes(ctx.state, &multitoken_prefix)?
+    {
+        let (key, value) = result?;
+        match is_any_token_balance_key(&key) {
+            Some([token, balance_owner]) if *balance_owner == owner => {
+                let amount = token::Amount::try_from_slice(&value[..])
+                    .into_storage_result()?;
+                if !amount.is_zero() {
+                    balances.push((token.clone(), amount));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(balances)
+}
+
 #[cfg(any(test, feature = "async-client"))]
 pub mod client_only_methods {
     use borsh::BorshDeserialize;
@@ -81,3 +118,51 @@ pub mod client_only_methods {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use namada_core::address::testing::{
+        established_address_1, established_address_2, established_address_3,
+        nam,
+    };
+    use namada_storage::StorageWrite;
+    use namada_token::storage_key::balance_key;
+
+    use super::*;
+    use crate::queries::testing::TestClient;
+    use crate::queries::RPC;
+
+    /// Test that all the non-zero balances of an owner are returned, and
+    /// only those.
+    #[tokio::test]
+    async fn test_query_balances() {
+        let mut client = TestClient::new(RPC);
+        let owner = established_address_1();
+        let other = established_address_2();
+        let token = established_address_3();
+
+        let writes = [
+            (balance_key(&nam(), &owner), token::Amount::from(100)),
+            (balance_key(&token, &owner), token::Amount::from(5)),
+            (balance_key(&token, &other), token::Amount::from(7)),
+            (balance_key(&other, &owner), token::Amount::zero()),
+        ];
+        for (key, amount) in writes {
+            client.state.write(&key, amount).expect("Test failed");
+        }
+
+        // commit the changes and increase block height
+        client.state.commit_block().expect("Test failed");
+        client.state.in_mem_mut().block.height += 1;
+
+        let mut balances =
+            RPC.vp().token().balances(&client, &owner).await.unwrap();
+        balances.sort();
+        let mut expected = vec![
+            (nam(), token::Amount::from(100)),
+            (token, token::Amount::from(5)),
+        ];
+        expected.sort();
+        assert_eq!(balances, expected);
+    }
+}
@@ -2,21 +2,26 @@
 
 use std::cell::Cell;
 use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
 use std::ops::ControlFlow;
 
 use borsh::BorshDeserialize;
+use borsh_ext::BorshSerializeExt;
+use futures::StreamExt;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
-use namada_account::Account;
+use namada_account::{Account, AllowanceSpending};
 use namada_core::address::{Address, InternalAddress};
 use namada_core::arith::checked;
+use namada_core::chain::ChainId;
 use namada_core::collections::{HashMap, HashSet};
-use namada_core::hash::Hash;
+use namada_core::hash::{Hash, Sha256Hasher};
 use namada_core::ibc::IbcTokenHash;
 use namada_core::key::common;
+use namada_core::parameters::Parameters;
 use namada_core::storage::{
-    BlockHeight, BlockResults, Epoch, Key, PrefixValue,
+    BlockHeight, BlockResults, Epoch, Key, PrefixValue, TxIndex,
 };
 use namada_core::token::{
     Amount, DenominatedAmount, Denomination, MaspDigitPos,
@@ -24,12 +29,14 @@ use namada_core::token::{
 use namada_core::{storage, token};
 use namada_gas::event::GasUsed as GasUsedAttr;
 use namada_gas::Gas;
+use namada_governance::cli::offline::{OfflineSignedProposal, OfflineVote};
 use namada_governance::parameters::GovernanceParameters;
 use namada_governance::pgf::parameters::PgfParameters;
 use namada_governance::pgf::storage::steward::StewardDetail;
 use namada_governance::storage::proposal::StorageProposal;
 use namada_governance::utils::{
-    compute_proposal_result, ProposalResult, ProposalVotes, Vote,
+    compute_proposal_result, ProposalResult, ProposalSummary, ProposalVotes,
+    TallyType, Vote,
 };
 use namada_ibc::storage::{
     ibc_trace_key, ibc_trace_key_prefix, is_ibc_trace_key,
@@ -37,11 +44,14 @@ use namada_ibc::storage::{
 use namada_parameters::{storage as params_storage, EpochDuration};
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::types::{
-    BondsAndUnbondsDetails, CommissionPair, ValidatorMetaData,
+    BondsAndUnbondsDetails, CommissionPair, Slash, ValidatorMetaData,
+};
+use namada_state::{merkle_tree, LastBlock};
+use namada_token::storage_key::balance_key;
+use namada_tx::data::{BatchedTxResult, ResultCode, TxResult, VpsResult};
+use namada_tx::event::{
+    Batch as BatchAttr, BatchMemos, Code as CodeAttr, Memos as MemosAttr,
 };
-use namada_state::LastBlock;
-use namada_tx::data::{BatchedTxResult, ResultCode, TxResult};
-use namada_tx::event::{Batch as BatchAttr, Code as CodeAttr};
 use serde::Serialize;
 
 use crate::args::InputAmount;
@@ -52,12 +62,14 @@ use crate::internal_macros::echo_error;
 use crate::io::Io;
 use crate::masp::MaspTokenRewardData;
 use crate::queries::vp::pos::{
-    EnrichedBondsAndUnbondsDetails, ValidatorStateInfo,
+    EnrichedBondsAndUnbondsDetails, ValidatorLiveness, ValidatorStateInfo,
+};
+use crate::queries::{
+    Client, NodeStatus, PrefixIterPage, PrefixIterRequest, RPC,
 };
-use crate::queries::{Client, RPC};
-use crate::tendermint::block::Height;
+use crate::tendermint::block::{Header, Height};
 use crate::tendermint::merkle::proof::ProofOps;
-use crate::tendermint_rpc::query::Query;
+use crate::tendermint_rpc::query::{EventType, Query};
 use crate::{display_line, edisplay_line, error, Namada, Tx};
 
 /// Identical to [`query_tx_status`], but does not need a [`Namada`]
@@ -117,6 +129,128 @@ where
     })
 }
 
+/// Identical to [`query_tx_status2`], but rather than polling the ABCI event
+/// endpoints, waits for the node to push the tx event over its event
+/// subscription stream (e.g. the Tendermint websocket endpoint).
+///
+/// Falls back to polling if the subscription cannot be established, or if
+/// the stream ends before the tx event is received.
+#[cfg(feature = "websocket-client")]
+pub async fn query_tx_status_subscribed<C, IO>(
+    client: &C,
+    io: &IO,
+    status: TxEventQuery<'_>,
+    deadline: time::Instant,
+) -> Result<Event, Error>
+where
+    C: crate::queries::Client
+        + crate::tendermint_rpc::SubscriptionClient
+        + Sync,
+    IO: crate::io::Io + crate::MaybeSend + crate::MaybeSync,
+{
+    let mut subscription = match client.subscribe(Query::from(status)).await {
+        Ok(subscription) => subscription,
+        Err(err) => {
+            tracing::debug!(
+                query = ?status,
+                %err,
+                "Event subscription failed, falling back to polling the tx \
+                 status",
+            );
+            return query_tx_status2(client, io, status, deadline).await;
+        }
+    };
+
+    let maybe_event = async {
+        // The tx event may have been emitted before we subscribed to it
+        if let Ok(Some(event)) = query_tx_events(client, status).await {
+            return Some(event);
+        }
+        tracing::debug!(query = ?status, "Waiting for tx event notification");
+        match time::timeout_at(deadline, subscription.next()).await {
+            Ok(Some(Ok(_))) => {
+                query_tx_events(client, status).await.ok().flatten()
+            }
+            Ok(Some(Err(err))) => {
+                tracing::debug!(
+                    query = ?status,
+                    %err,
+                    "Event subscription stream failed",
+                );
+                None
+            }
+            Ok(None) | Err(_) => None,
+        }
+    }
+    .await;
+
+    if let Err(err) = client.unsubscribe(Query::from(status)).await {
+        tracing::debug!(query = ?status, %err, "Failed to unsubscribe");
+    }
+
+    match maybe_event {
+        Some(event) => {
+            tracing::debug!(event = ?event, "Found tx event");
+            Ok(event)
+        }
+        // Poll the tx status until the deadline, which also takes care of
+        // reporting a deadline that was exceeded
+        None => query_tx_status2(client, io, status, deadline).await,
+    }
+}
+
+/// Same as [`query_tx_status_subscribed`], but connects to the websocket
+/// endpoint of the ledger node at the given address. Falls back to polling
+/// the node with the client of the given context if the connection fails.
+#[cfg(all(feature = "websocket-client", not(target_family = "wasm")))]
+pub async fn query_tx_status_over_websocket(
+    context: &impl Namada,
+    ledger_address: &crate::tendermint_rpc::Url,
+    status: TxEventQuery<'_>,
+    deadline: time::Instant,
+) -> Result<Event, Error> {
+    use crate::tendermint_rpc::{
+        SubscriptionClient, WebSocketClient, WebSocketClientUrl,
+    };
+
+    let connect = async {
+        let url = format!(
+            "{}/websocket",
+            ledger_address
+                .to_string()
+                .replacen("http", "ws", 1)
+                .trim_end_matches('/')
+        )
+        .parse::<WebSocketClientUrl>()?;
+        WebSocketClient::new(url).await
+    };
+    match connect.await {
+        Ok((client, driver)) => {
+            tokio::spawn(driver.run());
+            let result = query_tx_status_subscribed(
+                &client,
+                context.io(),
+                status,
+                deadline,
+            )
+            .await;
+            if let Err(err) = client.close() {
+                tracing::debug!(%err, "Failed to close the websocket client");
+            }
+            result
+        }
+        Err(err) => {
+            tracing::debug!(
+                %ledger_address,
+                %err,
+                "Websocket connection failed, falling back to polling the tx \
+                 status",
+            );
+            query_tx_status(context, status, deadline).await
+        }
+    }
+}
+
 /// Query the status of a given transaction.
 ///
 /// If a response is not delivered until `deadline`, we exit the cli with an
@@ -129,6 +263,122 @@ pub async fn query_tx_status(
     query_tx_status2(context.client(), context.io(), status, deadline).await
 }
 
+/// Wait until a block at the given `height` (or above) has been committed,
+/// returning the height of the last committed block.
+///
+/// Rather than busy-polling the node, new blocks are awaited over its event
+/// subscription stream (e.g. the Tendermint websocket endpoint). Falls back
+/// to polling if the subscription cannot be established.
+pub async fn wait_for_height<C>(
+    client: &C,
+    height: BlockHeight,
+    deadline: time::Instant,
+) -> Result<BlockHeight, Error>
+where
+    C: crate::queries::Client
+        + crate::tendermint_rpc::SubscriptionClient
+        + Sync,
+{
+    wait_for_new_blocks(client, deadline, || async {
+        let last_height = query_block(client).await?.map(|block| block.height);
+        Ok(last_height.filter(|last_height| *last_height >= height))
+    })
+    .await?
+    .ok_or_else(|| {
+        Error::from(QueryError::Timeout(format!("block height {height}")))
+    })
+}
+
+/// Wait until the epoch following the current one has started, returning
+/// the new epoch.
+///
+/// Like [`wait_for_height`], this awaits new blocks over the node's event
+/// subscription stream, falling back to polling if needed.
+pub async fn wait_for_next_epoch<C>(
+    client: &C,
+    deadline: time::Instant,
+) -> Result<Epoch, Error>
+where
+    C: crate::queries::Client
+        + crate::tendermint_rpc::SubscriptionClient
+        + Sync,
+{
+    let start_epoch = query_epoch(client).await?;
+    wait_for_new_blocks(client, deadline, || async {
+        let epoch = query_epoch(client).await?;
+        Ok((epoch > start_epoch).then_some(epoch))
+    })
+    .await?
+    .ok_or_else(|| {
+        Error::from(QueryError::Timeout(format!(
+            "the epoch following {start_epoch}"
+        )))
+    })
+}
+
+/// Run `check` every time a new block is committed, until it yields a value
+/// or the `deadline` is exceeded, in which case `None` is returned.
+async fn wait_for_new_blocks<C, T, F, G>(
+    client: &C,
+    deadline: time::Instant,
+    mut check: G,
+) -> Result<Option<T>, Error>
+where
+    C: crate::queries::Client
+        + crate::tendermint_rpc::SubscriptionClient
+        + Sync,
+    G: FnMut() -> F,
+    F: Future<Output = Result<Option<T>, Error>>,
+{
+    /// How long to sleep between polls, if we cannot subscribe to new blocks
+    const POLL_INTERVAL: time::Duration = time::Duration::from_secs(1);
+
+    let query = Query::from(EventType::NewBlock);
+    let mut subscription = match client.subscribe(query.clone()).await {
+        Ok(subscription) => Some(subscription),
+        Err(err) => {
+            tracing::debug!(
+                %err,
+                "New block subscription failed, falling back to polling",
+            );
+            None
+        }
+    };
+    let subscribed = subscription.is_some();
+
+    let result = loop {
+        match check().await {
+            Ok(None) => {}
+            result => break result,
+        }
+        let next_block = match subscription.as_mut() {
+            Some(stream) => time::timeout_at(deadline, stream.next()).await,
+            None => time::timeout_at(deadline, time::sleep(POLL_INTERVAL))
+                .await
+                .map(|()| None),
+        };
+        match next_block {
+            Ok(Some(Ok(_)) | None) => {}
+            Ok(Some(Err(err))) => {
+                tracing::debug!(
+                    %err,
+                    "New block subscription stream failed, falling back to \
+                     polling",
+                );
+                subscription = None;
+            }
+            Err(_) => break Ok(None),
+        }
+    };
+
+    if subscribed {
+        if let Err(err) = client.unsubscribe(query).await {
+            tracing::debug!(%err, "Failed to unsubscribe from new blocks");
+        }
+    }
+    result
+}
+
 /// Query the epoch of the last committed block
 pub async fn query_epoch<C: crate::queries::Client + Sync>(
     client: &C,
@@ -143,6 +393,77 @@ pub async fn query_native_token<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().native_token(client).await)
 }
 
+/// Query the protocol parameters
+pub async fn query_protocol_parameters<C: crate::queries::Client + Sync>(
+    client: &C,
+) -> Result<Parameters, error::Error> {
+    convert_response::<C, _>(RPC.shell().parameters(client).await)
+}
+
+/// A block hash pinned at some height. Used to check that a node serves the
+/// chain we expect before trusting any of its responses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedBlock {
+    /// The height of the pinned block.
+    pub height: BlockHeight,
+    /// The expected hash of the block header at `height`.
+    pub hash: Hash,
+}
+
+/// The information needed to start interacting with a chain.
+#[derive(Debug, Clone)]
+pub struct ChainInfo {
+    /// The chain ID.
+    pub chain_id: ChainId,
+    /// The address of the native token.
+    pub native_token: Address,
+    /// The protocol parameters.
+    pub parameters: Parameters,
+}
+
+/// Fetch the chain ID, native token and protocol parameters of
+/// the chain served by the given node. If `trusted` is given, the node must
+/// serve a block with the pinned hash at the pinned height. Otherwise, the
+/// node is trusted blindly.
+pub async fn fetch_chain_info<C: crate::queries::Client + Sync>(
+    client: &C,
+    trusted: Option<&TrustedBlock>,
+) -> Result<ChainInfo, error::Error> {
+    if let Some(TrustedBlock { height, hash }) = trusted {
+        let height = Height::try_from(height.0)
+            .map_err(|err| Error::from(QueryError::General(err.to_string())))?;
+        let block_hash = client
+            .block(height)
+            .await
+            .map_err(|err| Error::from(QueryError::General(err.to_string())))?
+            .block_id
+            .hash;
+        let expected_hash = crate::tendermint::Hash::from(*hash);
+        if block_hash != expected_hash {
+            return Err(Error::from(QueryError::UntrustedChain(format!(
+                "expected the block at height {height} to have hash \
+                 {expected_hash}, but found {block_hash}"
+            ))));
+        }
+    }
+
+    let chain_id = {
+        let status = client
+            .status()
+            .await
+            .map_err(|err| Error::from(QueryError::General(err.to_string())))?;
+        ChainId(status.node_info.network.to_string())
+    };
+    let native_token = query_native_token(client).await?;
+    let parameters = query_protocol_parameters(client).await?;
+
+    Ok(ChainInfo {
+        chain_id,
+        native_token,
+        parameters,
+    })
+}
+
 /// Query the epoch of the given block height, if it exists.
 /// Will return none if the input block height is greater than
 /// the latest committed block height.
@@ -162,6 +483,17 @@ pub async fn query_block<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().last_block(client).await)
 }
 
+/// Query the status of the node, i.e. its chain, last committed block, epoch,
+/// mode and Ethereum oracle health
+pub async fn query_node_status<C: crate::queries::Client + Sync>(
+    client: &C,
+) -> Result<NodeStatus, error::Error> {
+    convert_response::<C, _>(
+        RPC.shell().node_status(client, None, None, false).await,
+    )
+    .map(|response| response.data)
+}
+
 /// A helper to unwrap client's response. Will shut down process on error.
 fn unwrap_client_response<C: crate::queries::Client, T>(
     response: Result<T, C::Error>,
@@ -187,6 +519,20 @@ pub async fn query_results<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().read_results(client).await)
 }
 
+/// Query a page of the txs that touched the given address. The node must have
+/// the tx index enabled, otherwise no entries are returned.
+pub async fn query_txs_by_address<C: crate::queries::Client + Sync>(
+    client: &C,
+    address: &Address,
+    page: u64,
+) -> Result<Vec<(BlockHeight, TxIndex)>, Error> {
+    convert_response::<C, _>(
+        RPC.shell()
+            .tx_index_by_address(client, address, &page)
+            .await,
+    )
+}
+
 /// Query token amount of owner.
 pub async fn get_token_balance<C: crate::queries::Client + Sync>(
     client: &C,
@@ -198,6 +544,32 @@ pub async fn get_token_balance<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query token amount of owner at the given block height.
+pub async fn get_token_balance_at_height<C: crate::queries::Client + Sync>(
+    client: &C,
+    token: &Address,
+    owner: &Address,
+    height: BlockHeight,
+) -> Result<token::Amount, error::Error> {
+    let key = balance_key(token, owner);
+    match query_storage_value_at_height(client, &key, Some(height)).await {
+        Ok(balance) => Ok(balance),
+        Err(Error::Query(QueryError::NoSuchKey(_))) => {
+            Ok(token::Amount::zero())
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Query all the non-zero token amounts of owner, paired with the addresses of
+/// their tokens.
+pub async fn get_token_balances<C: crate::queries::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<Vec<(Address, token::Amount)>, error::Error> {
+    convert_response::<C, _>(RPC.vp().token().balances(client, owner).await)
+}
+
 /// Query token total supply.
 pub async fn get_token_total_supply<C: crate::queries::Client + Sync>(
     client: &C,
@@ -376,6 +748,20 @@ pub async fn query_storage_value<C, T>(
     client: &C,
     key: &storage::Key,
 ) -> Result<T, Error>
+where
+    T: BorshDeserialize,
+    C: crate::queries::Client + Sync,
+{
+    query_storage_value_at_height(client, key, None).await
+}
+
+/// Query a storage value at the given block height, or at the last committed
+/// block if no height is given, and decode it with [`BorshDeserialize`].
+pub async fn query_storage_value_at_height<C, T>(
+    client: &C,
+    key: &storage::Key,
+    height: Option<BlockHeight>,
+) -> Result<T, Error>
 where
     T: BorshDeserialize,
     C: crate::queries::Client + Sync,
@@ -385,6 +771,13 @@ where
     // returns 0 bytes when the key is not found.
     let maybe_unit = T::try_from_slice(&[]);
     if let Ok(unit) = maybe_unit {
+        // `storage_has_key` can only be queried at the last committed block
+        if height.is_some() {
+            return Err(Error::Other(format!(
+                "Cannot query the presence of the key {key} at a past block \
+                 height"
+            )));
+        }
         return if convert_response::<C, _>(
             RPC.shell().storage_has_key(client, key).await,
         )? {
@@ -396,7 +789,7 @@ where
 
     let response = convert_response::<C, _>(
         RPC.shell()
-            .storage_value(client, None, None, false, key)
+            .storage_value(client, None, height, false, key)
             .await,
     )?;
     if response.data.is_empty() {
@@ -426,9 +819,139 @@ pub async fn query_storage_value_bytes<C: crate::queries::Client + Sync>(
     })
 }
 
+/// Query a storage value at the state committed to by the app hash of a
+/// trusted block header, verify its proof and decode it with
+/// [`BorshDeserialize`]. This doesn't require trusting the queried node.
+pub async fn query_verified_storage_value<C, T>(
+    client: &C,
+    key: &storage::Key,
+    trusted_header: &Header,
+) -> Result<T, Error>
+where
+    T: BorshDeserialize,
+    C: crate::queries::Client + Sync,
+{
+    // The app hash of a block commits to the state after the previous block
+    let height = trusted_header
+        .height
+        .value()
+        .checked_sub(1)
+        .filter(|height| *height > 0)
+        .map(BlockHeight)
+        .ok_or_else(|| {
+            Error::from(QueryError::InvalidProof(
+                "The app hash of the first block does not commit to any \
+                 state"
+                    .to_string(),
+            ))
+        })?;
+    let (value, proof) =
+        query_storage_value_bytes(client, key, Some(height), true).await?;
+    let proof = proof.ok_or_else(|| {
+        Error::from(QueryError::NoResponse(format!(
+            "The proof of the storage key {key}"
+        )))
+    })?;
+    verify_storage_value_proof(key, value.as_deref(), &proof, trusted_header)?;
+    let value = value.ok_or_else(|| QueryError::NoSuchKey(key.to_string()))?;
+    T::try_from_slice(&value[..])
+        .map_err(|err| Error::from(EncodingError::Decoding(err.to_string())))
+}
+
+/// Verify the proof of a storage value, or of its absence if `value` is
+/// `None`, against the app hash of a trusted block header. The proof must
+/// have been queried at the height below that of the header.
+pub fn verify_storage_value_proof(
+    key: &storage::Key,
+    value: Option<&[u8]>,
+    proof: &ProofOps,
+    trusted_header: &Header,
+) -> Result<(), Error> {
+    let app_hash: [u8; 32] =
+        trusted_header.app_hash.as_bytes().try_into().map_err(|_| {
+            QueryError::InvalidProof(format!(
+                "Unexpected app hash {} in the trusted header",
+                trusted_header.app_hash
+            ))
+        })?;
+    merkle_tree::verify_storage_proof::<Sha256Hasher>(
+        key,
+        value,
+        proof,
+        &merkle_tree::MerkleRoot(app_hash),
+    )
+    .map_err(|err| Error::from(QueryError::InvalidProof(err.to_string())))
+}
+
+/// Query the values of multiple storage keys in a single request and decode
+/// them with [`BorshDeserialize`]. Returns the values in the order of the keys,
+/// with `None` for the keys that have no value.
+pub async fn query_storage_values<C, T>(
+    client: &C,
+    keys: &[storage::Key],
+) -> Result<Vec<Option<T>>, Error>
+where
+    T: BorshDeserialize,
+    C: crate::queries::Client + Sync,
+{
+    let (values, _proof) =
+        query_storage_values_bytes(client, keys, None, false).await?;
+    values
+        .into_iter()
+        .map(|value| {
+            value
+                .map(|value| T::try_from_slice(&value[..]))
+                .transpose()
+                .map_err(|err| {
+                    Error::from(EncodingError::Decoding(err.to_string()))
+                })
+        })
+        .collect()
+}
+
+/// Query the values of multiple storage keys in a single request without
+/// decoding them. If requested, the returned proof holds the concatenated
+/// proofs of the keys, in the order of the keys.
+pub async fn query_storage_values_bytes<C: crate::queries::Client + Sync>(
+    client: &C,
+    keys: &[storage::Key],
+    height: Option<BlockHeight>,
+    prove: bool,
+) -> Result<(Vec<Option<Vec<u8>>>, Option<ProofOps>), error::Error> {
+    let data = Some(keys.serialize_to_vec());
+    let response = convert_response::<C, _>(
+        RPC.shell()
+            .storage_values(client, data, height, prove)
+            .await,
+    )?;
+    Ok((response.data, response.proof))
+}
+
+/// Query a page of the storage values with a matching prefix. The next page
+/// can be requested with the continuation token of the returned page.
+pub async fn query_storage_prefix_page<C: crate::queries::Client + Sync>(
+    client: &C,
+    key: &storage::Key,
+    request: &PrefixIterRequest,
+) -> Result<PrefixIterPage, error::Error> {
+    convert_response::<C, _>(
+        RPC.shell()
+            .storage_prefix_iter(
+                client,
+                Some(request.serialize_to_vec()),
+                None,
+                false,
+                key,
+            )
+            .await,
+    )
+    .map(|response| response.data)
+}
+
 /// Query a range of storage values with a matching prefix and decode them with
 /// [`BorshDeserialize`]. Returns an iterator of the storage keys paired with
-/// their associated values.
+/// their associated values. The values are fetched page by page, so that
+/// large prefixes don't have to fit in a single response.
 pub async fn query_storage_prefix<'a, 'b, N: Namada, T>(
     context: &'b N,
     key: &storage::Key,
@@ -436,11 +959,19 @@ pub async fn query_storage_prefix<'a, 'b, N: Namada, T>(
 where
     T: BorshDeserialize,
 {
-    let values = convert_response::<N::Client, _>(
-        RPC.shell()
-            .storage_prefix(context.client(), None, None, false, key)
-            .await,
-    )?;
+    let mut values = vec![];
+    let mut request = PrefixIterRequest::default();
+    loop {
+        let PrefixIterPage {
+            values: mut page,
+            next,
+        } = query_storage_prefix_page(context.client(), key, &request).await?;
+        values.append(&mut page);
+        match next {
+            Some(next) => request.start_after = Some(next),
+            None => break,
+        }
+    }
     let decode =
         |PrefixValue { key, value }: PrefixValue| match T::try_from_slice(
             &value[..],
@@ -456,10 +987,10 @@ where
             }
             Ok(value) => Some((key, value)),
         };
-    Ok(if values.data.is_empty() {
+    Ok(if values.is_empty() {
         None
     } else {
-        Some(values.data.into_iter().filter_map(decode))
+        Some(values.into_iter().filter_map(decode))
     })
 }
 
@@ -519,6 +1050,26 @@ pub async fn query_tx_events<C: crate::queries::Client + Sync>(
     }
 }
 
+/// Query the events emitted by the ledger when finalizing the block at the
+/// given height, decoded from their ABCI encoding.
+pub async fn query_block_events<C: crate::queries::Client + Sync>(
+    client: &C,
+    height: BlockHeight,
+) -> Result<Vec<Event>, Error> {
+    let height = Height::try_from(height.0)
+        .map_err(|err| Error::from(QueryError::General(err.to_string())))?;
+    let events = client
+        .block_results(height)
+        .await
+        .map_err(|err| Error::from(QueryError::General(err.to_string())))?
+        .end_block_events
+        .unwrap_or_default();
+    events
+        .into_iter()
+        .map(|event| Event::try_from(event).map_err(Error::from))
+        .collect()
+}
+
 /// Dry run a transaction
 pub async fn dry_run_tx<N: Namada>(
     context: &N,
@@ -540,6 +1091,8 @@ pub async fn dry_run_tx<N: Namada>(
                     cmt_result_str.push_str(&format!(
                         "Inner transaction {cmt_hash} was successfully applied",
                     ));
+                    cmt_result_str
+                        .push_str(&format_vp_verdicts(&result.vps_result));
                 } else {
                     cmt_result_str.push_str(&format!(
                         "Inner transaction {} was rejected by VPs: \
@@ -553,7 +1106,9 @@ pub async fn dry_run_tx<N: Namada>(
                             .unwrap(),
                         serde_json::to_string_pretty(&result.changed_keys)
                             .unwrap(),
-                    ))
+                    ));
+                    cmt_result_str
+                        .push_str(&format_vp_verdicts(&result.vps_result));
                 }
             }
             Err(msg) => cmt_result_str.push_str(&format!(
@@ -568,6 +1123,21 @@ pub async fn dry_run_tx<N: Namada>(
     Ok(result)
 }
 
+/// Format the verdict of each VP triggered by an inner tx, along with the gas
+/// it used
+fn format_vp_verdicts(vps_result: &VpsResult) -> String {
+    let mut verdicts = String::new();
+    for (addr, gas) in &vps_result.gas_per_vp {
+        let verdict = if vps_result.rejected_vps.contains(addr) {
+            "rejected"
+        } else {
+            "accepted"
+        };
+        verdicts.push_str(&format!("\n  VP of {addr} {verdict} (gas {gas})"));
+    }
+    verdicts
+}
+
 /// Data needed for broadcasting a tx and
 /// monitoring its progress on chain
 ///
@@ -604,6 +1174,8 @@ pub struct TxResponse {
     pub code: ResultCode,
     /// Gas used.
     pub gas_used: Gas,
+    /// Memos of the inner txs, indexed by the hash of their commitments
+    pub memos: BatchMemos,
 }
 
 /// Determines a result of an inner tx from
@@ -613,8 +1185,8 @@ pub enum InnerTxResult<'a> {
     Success(&'a BatchedTxResult),
     /// Some VPs rejected the tx
     VpsRejected(&'a BatchedTxResult),
-    /// Transaction failed in some other way
-    OtherFailure,
+    /// Transaction failed in some other way, with the given error
+    OtherFailure(&'a str),
 }
 
 impl TryFrom<Event> for TxResponse {
@@ -640,6 +1212,10 @@ impl TryFrom<Event> for TxResponse {
         let gas_used = event
             .read_attribute::<GasUsedAttr>()
             .map_err(|err| err.to_string())?;
+        let memos = event
+            .read_attribute_opt::<MemosAttr>()
+            .map_err(|err| err.to_string())?
+            .unwrap_or_default();
 
         Ok(TxResponse {
             batch,
@@ -649,6 +1225,7 @@ impl TryFrom<Event> for TxResponse {
             height,
             code,
             gas_used,
+            memos,
         })
     }
 }
@@ -675,7 +1252,7 @@ impl TxResponse {
                             InnerTxResult::VpsRejected(res)
                         }
                     }
-                    Err(_) => InnerTxResult::OtherFailure,
+                    Err(err) => InnerTxResult::OtherFailure(err),
                 };
                 result.insert(cmt_hash.to_owned(), value);
             }
@@ -776,6 +1353,36 @@ pub async fn get_delegations_of_delegator_at<
     )
 }
 
+/// Query the staking rewards available for claim from the bond of `source`
+/// to `validator`, or from the validator's self-bond if no `source` is given
+pub async fn query_rewards<C: crate::queries::Client + Sync>(
+    client: &C,
+    source: &Option<Address>,
+    validator: &Address,
+) -> Result<token::Amount, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().pos().rewards(client, validator, source).await,
+    )
+}
+
+/// Query the staking rewards available for claim from each of the validators
+/// the given delegator has bonds with in the current epoch
+pub async fn query_rewards_of_delegator<C: crate::queries::Client + Sync>(
+    client: &C,
+    delegator: &Address,
+) -> Result<HashMap<Address, token::Amount>, error::Error> {
+    let epoch = query_epoch(client).await?;
+    let validators =
+        get_delegation_validators(client, delegator, epoch).await?;
+    let source = Some(delegator.clone());
+    let mut rewards = HashMap::new();
+    for validator in validators {
+        let amount = query_rewards(client, &source, &validator).await?;
+        rewards.insert(validator, amount);
+    }
+    Ok(rewards)
+}
+
 /// Query proposal by Id
 pub async fn query_proposal_by_id<C: crate::queries::Client + Sync>(
     client: &C,
@@ -786,6 +1393,19 @@ pub async fn query_proposal_by_id<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query the summaries of at most `count` proposals, starting from the
+/// proposal with id `from`. The number of returned proposals is capped by the
+/// node.
+pub async fn query_proposals<C: crate::queries::Client + Sync>(
+    client: &C,
+    from: u64,
+    count: u64,
+) -> Result<Vec<ProposalSummary>, Error> {
+    convert_response::<C, _>(
+        RPC.vp().gov().proposals(client, &from, &count).await,
+    )
+}
+
 /// Query and return validator's commission rate and max commission rate change
 /// per epoch
 pub async fn query_commission_rate<C: crate::queries::Client + Sync>(
@@ -847,6 +1467,15 @@ pub async fn query_bond<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query the slashes recorded from evidence of misbehavior that are yet to be
+/// processed, grouped by validator and by the epoch in which they will be
+/// applied
+pub async fn query_enqueued_slashes<C: crate::queries::Client + Sync>(
+    client: &C,
+) -> Result<HashMap<Address, BTreeMap<Epoch, Vec<Slash>>>, error::Error> {
+    convert_response::<C, _>(RPC.vp().pos().enqueued_slashes(client).await)
+}
+
 /// Query a validator's bonds for a given epoch
 pub async fn query_last_infraction_epoch<C: crate::queries::Client + Sync>(
     client: &C,
@@ -860,6 +1489,16 @@ pub async fn query_last_infraction_epoch<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query the liveness statistics of a consensus validator
+pub async fn query_validator_liveness<C: crate::queries::Client + Sync>(
+    client: &C,
+    validator: &Address,
+) -> Result<Option<ValidatorLiveness>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().pos().validator_liveness(client, validator).await,
+    )
+}
+
 /// Query the accunt substorage space of an address
 pub async fn get_account_info<C: crate::queries::Client + Sync>(
     client: &C,
@@ -878,6 +1517,39 @@ pub async fn is_public_key_revealed<C: crate::queries::Client + Sync>(
     convert_response::<C, bool>(RPC.shell().revealed(client, owner).await)
 }
 
+/// Query the minimum gas price of a fee token, if the token is accepted for
+/// fee payment
+pub async fn get_gas_cost<C: crate::queries::Client + Sync>(
+    client: &C,
+    token: &Address,
+) -> Result<Option<token::Amount>, error::Error> {
+    convert_response::<C, _>(RPC.shell().gas_cost(client, token).await)
+}
+
+/// Query the allowances granted by an account, as triples of spender, token
+/// and daily cap
+pub async fn get_allowances<C: crate::queries::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<Vec<(Address, Address, token::Amount)>, error::Error> {
+    convert_response::<C, _>(RPC.shell().allowances(client, owner).await)
+}
+
+/// Query the spending record of a spender's allowance of a token on an
+/// account
+pub async fn get_allowance_spending<C: crate::queries::Client + Sync>(
+    client: &C,
+    owner: &Address,
+    spender: &Address,
+    token: &Address,
+) -> Result<AllowanceSpending, error::Error> {
+    convert_response::<C, _>(
+        RPC.shell()
+            .allowance_spending(client, owner, spender, token)
+            .await,
+    )
+}
+
 /// Query an account substorage at a specific index
 pub async fn get_public_key_at<C: crate::queries::Client + Sync>(
     client: &C,
@@ -972,6 +1644,106 @@ pub async fn query_proposal_result<C: crate::queries::Client + Sync>(
     Ok(Some(proposal_result))
 }
 
+/// Validate an off-chain proposal, checking its signatures against the
+/// on-chain account of its author
+pub async fn verify_offline_proposal<C: crate::queries::Client + Sync>(
+    client: &C,
+    proposal: OfflineSignedProposal,
+) -> Result<OfflineSignedProposal, Error> {
+    let author = get_account_info(client, &proposal.proposal.author)
+        .await?
+        .ok_or_else(|| {
+            Error::Tx(TxSubmitError::InvalidProposal(format!(
+                "The author {} of the proposal doesn't exist",
+                proposal.proposal.author
+            )))
+        })?;
+    let current_epoch = query_epoch(client).await?;
+
+    proposal
+        .validate(&author.public_keys_map, author.threshold, current_epoch)
+        .map_err(|e| Error::Tx(TxSubmitError::InvalidProposal(e.to_string())))
+}
+
+/// Tally the votes of an off-chain proposal with the voting power at its
+/// tally epoch, in the same way as on-chain default proposals. Votes that
+/// refer to a different proposal or that are not signed by the voter's
+/// on-chain keys are ignored.
+pub async fn compute_offline_proposal_result<
+    C: crate::queries::Client + Sync,
+>(
+    client: &C,
+    proposal: &OfflineSignedProposal,
+    votes: Vec<OfflineVote>,
+) -> Result<ProposalResult, Error> {
+    let tally_epoch = proposal.proposal.tally_epoch;
+    let proposal_hash = proposal.hash();
+    let mut proposal_votes = ProposalVotes::default();
+
+    for vote in votes {
+        if vote.proposal_hash != proposal_hash {
+            tracing::debug!(
+                "Skipping vote of {} for a different proposal",
+                vote.address
+            );
+            continue;
+        }
+        let is_signed = get_account_info(client, &vote.address)
+            .await?
+            .map(|account| {
+                vote.check_signatures(
+                    &account.public_keys_map,
+                    account.threshold,
+                )
+            })
+            .unwrap_or_default();
+        if !is_signed {
+            tracing::debug!(
+                "Skipping vote of {} with invalid signatures",
+                vote.address
+            );
+            continue;
+        }
+
+        if vote.is_validator() {
+            let voting_power =
+                get_validator_stake(client, tally_epoch, &vote.address).await?;
+            proposal_votes.add_validator(
+                &vote.address,
+                voting_power,
+                vote.vote.clone(),
+            );
+        }
+        for validator in &vote.delegations {
+            if validator == &vote.address {
+                continue;
+            }
+            let voting_power = get_bond_amount_at(
+                client,
+                &vote.address,
+                validator,
+                tally_epoch,
+            )
+            .await?;
+            proposal_votes.add_delegator(
+                &vote.address,
+                validator,
+                voting_power,
+                vote.vote.clone(),
+            );
+        }
+    }
+
+    let total_staked_tokens =
+        get_total_staked_tokens(client, tally_epoch).await?;
+
+    Ok(compute_proposal_result(
+        proposal_votes,
+        total_staked_tokens,
+        TallyType::TwoThirds,
+    )?)
+}
+
 /// Query a validator's unbonds for a given epoch
 pub async fn query_and_print_unbonds(
     context: &impl Namada,
@@ -1353,14 +2125,12 @@ pub async fn query_ibc_denom<N: Namada>(
     owner: Option<&Address>,
 ) -> String {
     let hash = match Address::decode(token.as_ref()) {
-        Ok(Address::Internal(InternalAddress::IbcToken(hash))) => {
-            hash.to_string()
-        }
+        Ok(Address::Internal(InternalAddress::IbcToken(hash))) => hash,
         _ => return token.as_ref().to_string(),
     };
 
     if let Some(owner) = owner {
-        let ibc_trace_key = ibc_trace_key(owner.to_string(), &hash);
+        let ibc_trace_key = ibc_trace_key(owner.to_string(), hash.to_string());
         if let Ok(ibc_denom) =
             query_storage_value::<_, String>(context.client(), &ibc_trace_key)
                 .await
@@ -1370,18 +2140,110 @@ pub async fn query_ibc_denom<N: Namada>(
     }
 
     // No owner is specified or the owner doesn't have the token
-    let ibc_denom_prefix = ibc_trace_key_prefix(None);
-    if let Ok(Some(ibc_denoms)) =
-        query_storage_prefix::<_, String>(context, &ibc_denom_prefix).await
+    if let Ok(Some(ibc_denom)) =
+        query_ibc_denom_trace(context.client(), &hash).await
     {
-        for (key, ibc_denom) in ibc_denoms {
-            if let Some((_, token_hash)) = is_ibc_trace_key(&key) {
-                if token_hash == hash {
-                    return ibc_denom;
-                }
-            }
-        }
+        return ibc_denom;
     }
 
     token.as_ref().to_string()
 }
+
+/// Query the IBC denomination trace (e.g. `transfer/channel-0/uatom`) of the
+/// given IBC token hash, if any.
+pub async fn query_ibc_denom_trace<C: crate::queries::Client + Sync>(
+    client: &C,
+    hash: &IbcTokenHash,
+) -> Result<Option<String>, error::Error> {
+    convert_response::<C, _>(RPC.shell().ibc_denom_trace(client, hash).await)
+}
+
+#[cfg(all(test, feature = "websocket-client"))]
+mod test_tx_status {
+    use super::*;
+    use crate::events::extend::{ComposeEvent, TxHash};
+    use crate::events::EventLevel;
+    use crate::io::NullIo;
+    use crate::queries::testing::TestClient;
+    use crate::queries::EncodedResponseQuery;
+    use crate::tendermint_rpc::error::Error as RpcError;
+    use crate::tendermint_rpc::{
+        SimpleRequest, Subscription, SubscriptionClient,
+    };
+    use crate::tx::event::types::APPLIED;
+
+    /// A client whose event subscriptions always fail
+    struct NoSubscriptionClient<C>(C);
+
+    #[cfg_attr(feature = "async-send", async_trait::async_trait)]
+    #[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
+    impl<C> crate::queries::Client for NoSubscriptionClient<C>
+    where
+        C: crate::queries::Client + Sync,
+    {
+        type Error = C::Error;
+
+        async fn request(
+            &self,
+            path: String,
+            data: Option<Vec<u8>>,
+            height: Option<BlockHeight>,
+            prove: bool,
+        ) -> Result<EncodedResponseQuery, Self::Error> {
+            self.0.request(path, data, height, prove).await
+        }
+
+        async fn perform<R>(&self, request: R) -> Result<R::Output, RpcError>
+        where
+            R: SimpleRequest,
+        {
+            self.0.perform(request).await
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<C: Send + Sync> SubscriptionClient for NoSubscriptionClient<C> {
+        async fn subscribe(
+            &self,
+            _query: Query,
+        ) -> Result<Subscription, RpcError> {
+            Err(RpcError::client_internal(
+                "Event subscriptions are not supported".to_string(),
+            ))
+        }
+
+        async fn unsubscribe(&self, _query: Query) -> Result<(), RpcError> {
+            Ok(())
+        }
+
+        fn close(self) -> Result<(), RpcError> {
+            Ok(())
+        }
+    }
+
+    /// Test that the tx status is polled when the event subscription can't
+    /// be established.
+    #[tokio::test]
+    async fn test_query_tx_status_subscribed_fallback() {
+        let mut client = TestClient::new(RPC);
+        let tx_hash = Hash::sha256(b"tx");
+        let event: Event = Event::new(APPLIED, EventLevel::Tx)
+            .with(TxHash(tx_hash))
+            .into();
+        client.event_log.log_events(vec![event.clone()]);
+        let client = NoSubscriptionClient(client);
+
+        #[allow(clippy::disallowed_methods)]
+        let deadline = time::Instant::now() + time::Duration::from_secs(5);
+        let tx_hash = tx_hash.to_string();
+        let found = query_tx_status_subscribed(
+            &client,
+            &NullIo,
+            TxEventQuery::Applied(&tx_hash),
+            deadline,
+        )
+        .await
+        .unwrap();
+        assert_eq!(found, event);
+    }
+}
@@ -1,5 +1,4 @@
 //! Functions to sign transactions
-use std::collections::BTreeMap;
 use std::fmt::Display;
 
 use borsh::BorshDeserialize;
@@ -14,6 +13,7 @@ use namada_account::{AccountPublicKeysMap, InitAccount, UpdateAccount};
 use namada_core::address::{Address, ImplicitAddress, InternalAddress, MASP};
 use namada_core::arith::checked;
 use namada_core::collections::{HashMap, HashSet};
+use namada_core::hash::Hash;
 use namada_core::key::*;
 use namada_core::masp::{AssetData, ExtendedViewingKey, PaymentAddress};
 use namada_core::sign::SignatureIndex;
@@ -25,7 +25,6 @@ use namada_governance::storage::proposal::{
     InitProposalData, ProposalType, VoteProposalData,
 };
 use namada_governance::storage::vote::ProposalVote;
-use namada_parameters::storage as parameter_storage;
 use namada_token::storage_key::balance_key;
 use namada_tx::data::pgf::UpdateStewardCommission;
 use namada_tx::data::pos::BecomeValidator;
@@ -185,6 +184,27 @@ pub async fn default_sign(
     )))
 }
 
+/// The hashes identifying a signed transaction on chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxHashes {
+    /// The hash of the wrapper transaction
+    pub wrapper: Hash,
+    /// The hashes of the inner transactions of the batch
+    pub inner: Vec<Hash>,
+}
+
+impl TxHashes {
+    /// Collect the wrapper and inner transaction hashes of the given tx. These
+    /// can be used to track the tx on chain, or to check whether it was
+    /// already applied before submitting it again.
+    pub fn new(tx: &Tx) -> Self {
+        Self {
+            wrapper: tx.header_hash(),
+            inner: tx.commitments().iter().map(|cmt| cmt.get_hash()).collect(),
+        }
+    }
+}
+
 /// Sign a transaction with a given signing key or public key of a given signer.
 /// If no explicit signer given, use the `default`. If no `default` is given,
 /// Error.
@@ -196,6 +216,9 @@ pub async fn default_sign(
 /// hashes needed for monitoring the tx on chain.
 ///
 /// If it is a dry run, it is not put in a wrapper, but returned as is.
+///
+/// On success, the hashes of the signed wrapper and inner transactions are
+/// returned.
 pub async fn sign_tx<'a, D, F, U>(
     wallet: &RwLock<Wallet<U>>,
     args: &args::Tx,
@@ -203,7 +226,7 @@ pub async fn sign_tx<'a, D, F, U>(
     signing_data: SigningTxData,
     sign: impl Fn(Tx, common::PublicKey, HashSet<Signable>, D) -> F,
     user_data: D,
-) -> Result<(), Error>
+) -> Result<TxHashes, Error>
 where
     D: Clone + MaybeSend,
     U: WalletIo,
@@ -294,7 +317,7 @@ where
             .await?;
         }
     }
-    Ok(())
+    Ok(TxHashes::new(tx))
 }
 
 /// Return the necessary data regarding an account to be able to generate a
@@ -305,7 +328,15 @@ pub async fn aux_signing_data(
     owner: Option<Address>,
     default_signer: Option<Address>,
 ) -> Result<SigningTxData, Error> {
-    let public_keys = if owner.is_some() || args.wrapper_fee_payer.is_none() {
+    // A separate gas payer only covers the fees of the tx, so the inner tx
+    // keeps being signed by its own signers. The signers can only be left
+    // out if there are none to be found, in which case the gas payer signs
+    // the whole tx.
+    let public_keys = if owner.is_some()
+        || default_signer.is_some()
+        || !args.signing_keys.is_empty()
+        || args.wrapper_fee_payer.is_none()
+    {
         tx_signers(context, args, default_signer.clone()).await?
     } else {
         vec![]
@@ -369,11 +400,12 @@ pub async fn init_validator_signing_data(
     args: &args::Tx<SdkTypes>,
     validator_keys: Vec<common::PublicKey>,
 ) -> Result<SigningTxData, Error> {
-    let mut public_keys = if args.wrapper_fee_payer.is_none() {
-        tx_signers(context, args, None).await?
-    } else {
-        vec![]
-    };
+    let mut public_keys =
+        if !args.signing_keys.is_empty() || args.wrapper_fee_payer.is_none() {
+            tx_signers(context, args, None).await?
+        } else {
+            vec![]
+        };
     public_keys.extend(validator_keys.clone());
 
     let account_public_keys_map =
@@ -420,22 +452,16 @@ pub async fn validate_fee<N: Namada>(
     context: &N,
     args: &args::Tx<SdkTypes>,
 ) -> Result<DenominatedAmount, Error> {
-    let gas_cost_key = parameter_storage::get_gas_cost_key();
-    let minimum_fee = match rpc::query_storage_value::<
-        _,
-        BTreeMap<Address, Amount>,
-    >(context.client(), &gas_cost_key)
-    .await
-    .and_then(|map| {
-        map.get(&args.fee_token)
-            .map(ToOwned::to_owned)
-            .ok_or_else(|| {
+    let minimum_fee = match rpc::get_gas_cost(context.client(), &args.fee_token)
+        .await
+        .and_then(|gas_cost| {
+            gas_cost.ok_or_else(|| {
                 Error::Other(format!(
                     "Could not retrieve from storage the gas cost for token {}",
                     args.fee_token
                 ))
             })
-    }) {
+        }) {
         Ok(amount) => amount,
         Err(e) => {
             if !args.force {
@@ -933,6 +959,29 @@ fn proposal_type_to_ledger_vector(
                 }
             }
         }
+        ProposalType::EthBridgeParams(update) => {
+            output
+                .push("Proposal type : Ethereum Bridge Parameters".to_string());
+            if let Some(contract) = &update.bridge_contract {
+                output.push(format!("Bridge contract : {}", contract.address));
+                output.push(format!(
+                    "Bridge contract version : {}",
+                    contract.version
+                ));
+            }
+            if let Some(address) = &update.native_erc20 {
+                output.push(format!("Native ERC20 : {}", address));
+            }
+            if let Some(confirmations) = &update.min_confirmations {
+                output.push(format!("Min confirmations : {}", confirmations));
+            }
+        }
+        ProposalType::ParameterChanges(changes) => {
+            output.push("Proposal type : Parameter Changes".to_string());
+            for change in changes {
+                output.push(format!("Parameter change : {}", change));
+            }
+        }
     }
 }
 
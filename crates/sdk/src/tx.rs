@@ -19,7 +19,9 @@ use masp_primitives::transaction::components::transparent::fees::{
 use masp_primitives::transaction::components::I128Sum;
 use masp_primitives::transaction::{builder, Transaction as MaspTransaction};
 use masp_primitives::zip32::ExtendedFullViewingKey;
-use namada_account::{InitAccount, UpdateAccount};
+use namada_account::{
+    InitAccount, SpendAllowance, UpdateAccount, UpdateAllowance,
+};
 use namada_core::address::{Address, InternalAddress, MASP};
 use namada_core::arith::checked;
 use namada_core::collections::HashSet;
@@ -44,7 +46,8 @@ use namada_core::storage::Epoch;
 use namada_core::time::DateTimeUtc;
 use namada_core::{storage, token};
 use namada_governance::cli::onchain::{
-    DefaultProposal, OnChainProposal, PgfFundingProposal, PgfStewardProposal,
+    DefaultProposal, EthBridgeParamsProposal, OnChainProposal,
+    ParameterChangesProposal, PgfFundingProposal, PgfStewardProposal,
 };
 use namada_governance::pgf::cli::steward::Commission;
 use namada_governance::storage::proposal::{
@@ -52,6 +55,8 @@ use namada_governance::storage::proposal::{
 };
 use namada_governance::storage::vote::ProposalVote;
 use namada_ibc::storage::{channel_key, ibc_token};
+use namada_parameters::storage as params_storage;
+use namada_parameters::UpdateFeeConversionRates;
 use namada_proof_of_stake::parameters::{
     PosParams, MAX_VALIDATOR_METADATA_LEN,
 };
@@ -60,15 +65,18 @@ use namada_token::storage_key::balance_key;
 use namada_token::DenominatedAmount;
 use namada_tx::data::pgf::UpdateStewardCommission;
 use namada_tx::data::pos::{BecomeValidator, ConsensusKeyChange};
-use namada_tx::data::{pos, BatchedTxResult, ResultCode, TxResult};
+use namada_tx::data::{
+    pos, BatchedTxResult, ResultCode, TxResult, VpStatusFlags, VpsResult,
+};
 pub use namada_tx::{Authorization, *};
 use num_traits::Zero;
 use rand_core::{OsRng, RngCore};
 
 use crate::control_flow::time;
 use crate::error::{EncodingError, Error, QueryError, Result, TxSubmitError};
+use crate::internal_macros::echo_error;
 use crate::io::Io;
-use crate::masp::TransferErr::Build;
+use crate::masp::TransferErr::{Build, General};
 use crate::masp::{ShieldedContext, ShieldedTransfer};
 use crate::queries::Client;
 use crate::rpc::{
@@ -81,7 +89,7 @@ use crate::signing::{
 use crate::tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use crate::tendermint_rpc::error::Error as RpcError;
 use crate::wallet::WalletIo;
-use crate::{args, display_line, edisplay_line, Namada};
+use crate::{args, display, display_line, edisplay_line, Namada};
 
 /// Initialize account transaction WASM
 pub const TX_INIT_ACCOUNT_WASM: &str = "tx_init_account.wasm";
@@ -101,12 +109,21 @@ pub const TX_VOTE_PROPOSAL: &str = "tx_vote_proposal.wasm";
 pub const TX_REVEAL_PK: &str = "tx_reveal_pk.wasm";
 /// Update validity predicate WASM path
 pub const TX_UPDATE_ACCOUNT_WASM: &str = "tx_update_account.wasm";
+/// Update allowance WASM path
+pub const TX_UPDATE_ALLOWANCE_WASM: &str = "tx_update_allowance.wasm";
+/// Spend allowance WASM path
+pub const TX_SPEND_ALLOWANCE_WASM: &str = "tx_spend_allowance.wasm";
+/// Update fee conversion rates WASM path
+pub const TX_UPDATE_FEE_CONVERSION_RATES_WASM: &str =
+    "tx_update_fee_conversion_rates.wasm";
 /// Transfer transaction WASM path
 pub const TX_TRANSFER_WASM: &str = "tx_transfer.wasm";
 /// IBC transaction WASM path
 pub const TX_IBC_WASM: &str = "tx_ibc.wasm";
 /// User validity predicate WASM path
 pub const VP_USER_WASM: &str = "vp_user.wasm";
+/// User validity predicate with spending allowances WASM path
+pub const VP_ALLOWANCE_WASM: &str = "vp_allowance.wasm";
 /// Bond WASM path
 pub const TX_BOND_WASM: &str = "tx_bond.wasm";
 /// Unbond WASM path
@@ -117,6 +134,10 @@ pub const TX_WITHDRAW_WASM: &str = "tx_withdraw.wasm";
 pub const TX_CLAIM_REWARDS_WASM: &str = "tx_claim_rewards.wasm";
 /// Bridge pool WASM path
 pub const TX_BRIDGE_POOL_WASM: &str = "tx_bridge_pool.wasm";
+/// Cancel Bridge pool transfer WASM path
+pub const TX_CANCEL_BRIDGE_POOL_WASM: &str = "tx_cancel_bridge_pool.wasm";
+/// Top up Bridge pool transfer gas fees WASM path
+pub const TX_TOP_UP_BRIDGE_POOL_WASM: &str = "tx_top_up_bridge_pool.wasm";
 /// Change commission WASM path
 pub const TX_CHANGE_COMMISSION_WASM: &str =
     "tx_change_validator_commission.wasm";
@@ -136,11 +157,21 @@ pub const TX_REDELEGATE_WASM: &str = "tx_redelegate.wasm";
 /// and `/applied` ABCI query endpoints.
 const DEFAULT_NAMADA_EVENTS_MAX_WAIT_TIME_SECONDS: u64 = 60;
 
+/// An account initialized by a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitializedAccount {
+    /// The alias under which the account was saved in the wallet, if it was
+    pub alias: Option<String>,
+    /// The address of the account
+    pub address: Address,
+}
+
 /// Capture the result of running a transaction
 #[derive(Debug)]
 pub enum ProcessTxResponse {
-    /// Result of submitting a transaction to the blockchain
-    Applied(TxResponse),
+    /// Result of submitting a transaction to the blockchain, along with the
+    /// accounts it initialized
+    Applied(TxResponse, Vec<InitializedAccount>),
     /// Result of submitting a transaction to the mempool
     Broadcast(Response),
     /// Result of dry running transaction
@@ -155,7 +186,7 @@ impl ProcessTxResponse {
         cmt: &TxCommitments,
     ) -> Option<&BatchedTxResult> {
         match self {
-            ProcessTxResponse::Applied(resp) => {
+            ProcessTxResponse::Applied(resp, _) => {
                 if resp.code == ResultCode::Ok {
                     if let Some(InnerTxResult::Success(result)) =
                         resp.batch_result().get(&cmt.get_hash())
@@ -170,6 +201,19 @@ impl ProcessTxResponse {
             }
         }
     }
+
+    /// Returns the accounts initialized by the transaction. Note that this is
+    /// always empty for dry-run and broadcast-only transactions.
+    pub fn initialized_accounts(&self) -> &[InitializedAccount] {
+        match self {
+            ProcessTxResponse::Applied(_, initialized_accounts) => {
+                initialized_accounts
+            }
+            ProcessTxResponse::DryRun(_) | ProcessTxResponse::Broadcast(_) => {
+                &[]
+            }
+        }
+    }
 }
 
 /// Build and dump a transaction either to file or to screen
@@ -211,8 +255,8 @@ pub async fn prepare_tx(
     }
 }
 
-/// Submit transaction and wait for result. Returns a list of addresses
-/// initialized in the transaction if any. In dry run, this is always empty.
+/// Submit transaction and wait for result. The response holds the accounts
+/// initialized in the transaction, if any. In dry run, this is always empty.
 pub async fn process_tx(
     context: &impl Namada,
     args: &args::Tx,
@@ -231,32 +275,59 @@ pub async fn process_tx(
     if args.dry_run || args.dry_run_wrapper {
         expect_dry_broadcast(TxBroadcastData::DryRun(tx), context).await
     } else {
+        check_tx_size(context, &tx).await;
         // We use this to determine when the wrapper tx makes it on-chain
         let tx_hash = tx.header_hash().to_string();
         let cmts = tx.commitments().clone();
         // We use this to determine when the decrypted inner tx makes it
         // on-chain
+        if args.skip_if_applied {
+            let tx_query = rpc::TxEventQuery::Applied(tx_hash.as_str());
+            let applied = rpc::query_tx_events(context.client(), tx_query)
+                .await
+                .map_err(|e| Error::from(QueryError::General(e.to_string())))?;
+            if let Some(event) = applied {
+                display_line!(
+                    context.io(),
+                    "Transaction {} was already applied, skipping its \
+                     submission.",
+                    tx_hash
+                );
+                let response = TxResponse::from_event(event);
+                display_batch_resp(context, &response);
+                return Ok(ProcessTxResponse::Applied(response, vec![]));
+            }
+        }
+        if args.confirm {
+            confirm_tx(context, &tx).await?;
+        }
         let to_broadcast = TxBroadcastData::Live { tx, tx_hash };
         if args.broadcast_only {
             broadcast_tx(context, &to_broadcast)
                 .await
                 .map(ProcessTxResponse::Broadcast)
         } else {
-            match submit_tx(context, to_broadcast).await {
+            let subscribe_to =
+                args.subscribe_events.then_some(&args.ledger_address);
+            match submit_tx_and_wait(context, to_broadcast, subscribe_to).await
+            {
                 Ok(resp) => {
+                    let mut initialized_accounts = vec![];
                     for cmt in cmts {
                         if let Some(InnerTxResult::Success(result)) =
                             resp.batch_result().get(&cmt.get_hash())
                         {
-                            save_initialized_accounts(
-                                context,
-                                args,
-                                result.initialized_accounts.clone(),
-                            )
-                            .await;
+                            initialized_accounts.extend(
+                                save_initialized_accounts(
+                                    context,
+                                    args,
+                                    result.initialized_accounts.clone(),
+                                )
+                                .await,
+                            );
                         }
                     }
-                    Ok(ProcessTxResponse::Applied(resp))
+                    Ok(ProcessTxResponse::Applied(resp, initialized_accounts))
                 }
                 Err(x) => Err(x),
             }
@@ -264,6 +335,81 @@ pub async fn process_tx(
     }
 }
 
+/// Display a human-readable summary of the given tx, including its fees and
+/// signer, and ask the user to confirm its submission. Errors if the user
+/// declines.
+async fn confirm_tx(context: &impl Namada, tx: &Tx) -> Result<()> {
+    let preview = {
+        let wallet = context.wallet().await;
+        signing::to_ledger_vector(&*wallet, tx).await?
+    };
+    let io = context.io();
+    display_line!(io, "Transaction {}:", tx.header_hash());
+    for line in preview.output_expert {
+        display_line!(io, "  {}", line);
+    }
+    display!(io, "\nDo you wish to submit this transaction? (y/n): ");
+    io.flush();
+    loop {
+        let resp = io.read().await.map_err(|e| {
+            Error::Other(echo_error!(
+                io,
+                "Encountered error reading from STDIN: {e:?}"
+            ))
+        })?;
+        match resp.trim() {
+            "y" => return Ok(()),
+            "n" => {
+                return Err(Error::Other(
+                    "Aborted the submission of the transaction".into(),
+                ));
+            }
+            _ => {
+                display!(io, "Expected 'y' or 'n'. Please try again: ");
+                io.flush();
+            }
+        }
+    }
+}
+
+/// Warn if the given tx or the wasm code embedded in it exceed the max sizes
+/// set by the chain's parameters, as it would then be rejected by the mempool.
+async fn check_tx_size(context: &impl Namada, tx: &Tx) {
+    let tx_len = tx.to_bytes().len();
+    if let Ok(max_tx_bytes) = rpc::query_storage_value::<_, u32>(
+        context.client(),
+        &params_storage::get_max_tx_bytes_key(),
+    )
+    .await
+    {
+        if tx_len > max_tx_bytes as usize {
+            edisplay_line!(
+                context.io(),
+                "Warning: The transaction is {tx_len} bytes long, which \
+                 exceeds the max of {max_tx_bytes} bytes allowed by the \
+                 chain. It will be rejected."
+            );
+        }
+    }
+
+    let code_len = tx.max_embedded_code_len();
+    if let Ok(max_code_bytes) = rpc::query_storage_value::<_, u32>(
+        context.client(),
+        &params_storage::get_max_code_bytes_key(),
+    )
+    .await
+    {
+        if code_len > max_code_bytes as usize {
+            edisplay_line!(
+                context.io(),
+                "Warning: The transaction embeds {code_len} bytes of code, \
+                 which exceeds the max of {max_code_bytes} bytes allowed by \
+                 the chain. It will be rejected."
+            );
+        }
+    }
+}
+
 /// Check if a reveal public key transaction is needed
 pub async fn is_reveal_pk_needed<C: crate::queries::Client + Sync>(
     client: &C,
@@ -341,14 +487,114 @@ pub async fn broadcast_tx(
         }
         Ok(response)
     } else {
-        Err(Error::from(TxSubmitError::TxBroadcast(RpcError::server(
-            serde_json::to_string(&response).map_err(|err| {
-                Error::from(EncodingError::Serde(err.to_string()))
-            })?,
-        ))))
+        Err(Error::from(TxSubmitError::MempoolRejected(
+            response.code.value(),
+            response.log,
+        )))
     }
 }
 
+/// The outcome of broadcasting a transaction to multiple RPC endpoints
+#[derive(Debug)]
+pub struct QuorumBroadcastResponse {
+    /// The mempool responses of the endpoints that accepted the tx, keyed by
+    /// the index of the endpoint in the list of clients
+    pub accepted: BTreeMap<usize, Response>,
+    /// The errors of the endpoints that could not be reached or that
+    /// rejected the tx, keyed by the index of the endpoint in the list of
+    /// clients
+    pub errors: BTreeMap<usize, Error>,
+}
+
+/// Broadcast a transaction to several RPC endpoints at once. The broadcast
+/// is considered successful once at least `quorum` of the endpoints have
+/// accepted the tx into their mempool.
+///
+/// The errors of the endpoints that failed are reported in the returned
+/// response, or in the error message if the quorum was not reached.
+pub async fn broadcast_tx_with_quorum<C>(
+    context: &impl Namada,
+    clients: &[C],
+    quorum: usize,
+    to_broadcast: &TxBroadcastData,
+) -> Result<QuorumBroadcastResponse>
+where
+    C: crate::queries::Client + Sync,
+{
+    let (tx, tx_hash) = match to_broadcast {
+        TxBroadcastData::Live { tx, tx_hash } => Ok((tx, tx_hash)),
+        TxBroadcastData::DryRun(tx) => {
+            Err(TxSubmitError::ExpectLiveRun(tx.clone()))
+        }
+    }?;
+    if quorum == 0 || quorum > clients.len() {
+        return Err(Error::from(TxSubmitError::InvalidBroadcastQuorum(
+            quorum,
+            clients.len(),
+        )));
+    }
+
+    tracing::debug!(
+        transaction = ?to_broadcast,
+        endpoints = clients.len(),
+        quorum,
+        "Broadcasting transaction",
+    );
+
+    let tx_bytes = tx.to_bytes();
+    let responses =
+        futures::future::join_all(clients.iter().map(|client| async {
+            lift_rpc_error(client.broadcast_tx_sync(tx_bytes.clone()).await)
+                .and_then(|response| {
+                    if response.code == 0.into() {
+                        Ok(response)
+                    } else {
+                        Err(Error::from(TxSubmitError::MempoolRejected(
+                            response.code.value(),
+                            response.log,
+                        )))
+                    }
+                })
+        }))
+        .await;
+
+    let mut accepted = BTreeMap::new();
+    let mut errors = BTreeMap::new();
+    for (index, response) in responses.into_iter().enumerate() {
+        match response {
+            Ok(response) => {
+                accepted.insert(index, response);
+            }
+            Err(err) => {
+                tracing::debug!("Endpoint {index} failed to broadcast: {err}");
+                errors.insert(index, err);
+            }
+        }
+    }
+
+    if accepted.len() < quorum {
+        let errors = errors
+            .iter()
+            .map(|(index, err)| format!("endpoint {index}: {err}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Err(Error::from(TxSubmitError::BroadcastQuorumNotReached(
+            accepted.len(),
+            quorum,
+            errors,
+        )));
+    }
+
+    display_line!(
+        context.io(),
+        "Transaction added to the mempool of {} out of {} endpoints.",
+        accepted.len(),
+        clients.len()
+    );
+    display_line!(context.io(), "Transaction hash: {tx_hash}");
+    Ok(QuorumBroadcastResponse { accepted, errors })
+}
+
 /// Broadcast a transaction to be included in the blockchain.
 ///
 /// Checks that
@@ -360,6 +606,17 @@ pub async fn broadcast_tx(
 pub async fn submit_tx(
     context: &impl Namada,
     to_broadcast: TxBroadcastData,
+) -> Result<TxResponse> {
+    submit_tx_and_wait(context, to_broadcast, None).await
+}
+
+/// Same as [`submit_tx`], but if the address of a ledger node is given, the
+/// transaction is awaited over the websocket event subscription of that node
+/// rather than by polling it.
+async fn submit_tx_and_wait(
+    context: &impl Namada,
+    to_broadcast: TxBroadcastData,
+    subscribe_to: Option<&crate::tendermint_rpc::Url>,
 ) -> Result<TxResponse> {
     let (_, tx_hash) = match &to_broadcast {
         TxBroadcastData::Live { tx, tx_hash } => Ok((tx, tx_hash)),
@@ -385,7 +642,19 @@ pub async fn submit_tx(
 
     // The transaction is now on chain. We wait for it to be applied
     let tx_query = rpc::TxEventQuery::Applied(tx_hash.as_str());
-    let event = rpc::query_tx_status(context, tx_query, deadline).await?;
+    let event = match subscribe_to {
+        #[cfg(all(feature = "websocket-client", not(target_family = "wasm")))]
+        Some(ledger_address) => {
+            rpc::query_tx_status_over_websocket(
+                context,
+                ledger_address,
+                tx_query,
+                deadline,
+            )
+            .await?
+        }
+        _ => rpc::query_tx_status(context, tx_query, deadline).await?,
+    };
     let response = TxResponse::from_event(event);
     display_batch_resp(context, &response);
     Ok(response)
@@ -394,6 +663,14 @@ pub async fn submit_tx(
 /// Display a result of a tx batch.
 pub fn display_batch_resp(context: &impl Namada, resp: &TxResponse) {
     for (cmt_hash, result) in resp.batch_result() {
+        if let Some(memo) = resp.memos.0.get(&cmt_hash) {
+            display_line!(
+                context.io(),
+                "Transaction {} has memo: {}",
+                cmt_hash,
+                memo
+            );
+        }
         match result {
             InnerTxResult::Success(_) => {
                 display_line!(
@@ -411,24 +688,24 @@ pub fn display_batch_resp(context: &impl Namada, resp: &TxResponse) {
                     .collect();
                 edisplay_line!(
                     context.io(),
-                    "Transaction {} was rejected by VPs: {}\nErrors: \
-                     {}\nChanged keys: {}",
+                    "Transaction {} was rejected by VPs at height {}.",
                     cmt_hash,
-                    serde_json::to_string_pretty(
-                        &inner.vps_result.rejected_vps
-                    )
-                    .unwrap(),
-                    serde_json::to_string_pretty(&inner.vps_result.errors)
-                        .unwrap(),
+                    resp.height,
+                );
+                display_vp_rejections(context.io(), &inner.vps_result);
+                edisplay_line!(
+                    context.io(),
+                    "Changed keys: {}",
                     serde_json::to_string_pretty(&changed_keys).unwrap(),
                 );
             }
-            InnerTxResult::OtherFailure => {
+            InnerTxResult::OtherFailure(err) => {
                 edisplay_line!(
                     context.io(),
-                    "Transaction {} failed.\nDetails: {}",
+                    "Transaction {} failed at height {}.\nError: {}",
                     cmt_hash,
-                    serde_json::to_string_pretty(&resp).unwrap()
+                    resp.height,
+                    err
                 );
             }
         }
@@ -440,13 +717,42 @@ pub fn display_batch_resp(context: &impl Namada, resp: &TxResponse) {
     );
 }
 
-/// Save accounts initialized from a tx into the wallet, if any.
+/// Display the validity predicates that rejected a transaction, along with
+/// the errors they reported
+fn display_vp_rejections(io: &impl Io, vps_result: &VpsResult) {
+    edisplay_line!(io, "Rejected by:");
+    for vp in &vps_result.rejected_vps {
+        let errors: Vec<_> = vps_result
+            .errors
+            .iter()
+            .filter(|(addr, _)| addr == vp)
+            .map(|(_, err)| err.as_str())
+            .collect();
+        if errors.is_empty() {
+            edisplay_line!(io, "  - {vp}: no error reported");
+        } else {
+            edisplay_line!(io, "  - {vp}: {}", errors.join("; "));
+        }
+    }
+    if vps_result
+        .status_flags
+        .contains(VpStatusFlags::INVALID_SIGNATURE)
+    {
+        edisplay_line!(io, "The transaction had an invalid signature.");
+    }
+}
+
+/// Save accounts initialized from a tx into the wallet, if any. Returns the
+/// initialized accounts along with the aliases they were saved under. When no
+/// alias was requested in the tx args, one is chosen with
+/// [`WalletIo::initialized_account_alias`].
 pub async fn save_initialized_accounts<N: Namada>(
     context: &N,
     args: &args::Tx,
     initialized_accounts: Vec<Address>,
-) {
+) -> Vec<InitializedAccount> {
     let len = initialized_accounts.len();
+    let mut saved = Vec::with_capacity(len);
     if len != 0 {
         // Store newly initialized account addresses in the wallet
         display_line!(
@@ -456,7 +762,7 @@ pub async fn save_initialized_accounts<N: Namada>(
             if len == 1 { "" } else { "s" }
         );
         // Store newly initialized account addresses in the wallet
-        for (ix, address) in initialized_accounts.iter().enumerate() {
+        for (ix, address) in initialized_accounts.into_iter().enumerate() {
             let encoded = address.encode();
             let alias: Cow<'_, str> = match &args.initialized_account_alias {
                 Some(initialized_account_alias) => {
@@ -471,7 +777,9 @@ pub async fn save_initialized_accounts<N: Namada>(
                         format!("{}{}", initialized_account_alias, ix).into()
                     }
                 }
-                None => N::WalletUtils::read_alias(&encoded).into(),
+                None => {
+                    N::WalletUtils::initialized_account_alias(&address).into()
+                }
             };
             let alias = alias.into_owned();
             let added = context.wallet_mut().await.insert_address(
@@ -479,7 +787,7 @@ pub async fn save_initialized_accounts<N: Namada>(
                 address.clone(),
                 args.wallet_alias_force,
             );
-            match added {
+            let alias = match added {
                 Some(new_alias) if new_alias != encoded => {
                     display_line!(
                         context.io(),
@@ -487,17 +795,21 @@ pub async fn save_initialized_accounts<N: Namada>(
                         new_alias,
                         encoded
                     );
+                    Some(new_alias)
                 }
                 _ => {
                     display_line!(
                         context.io(),
                         "No alias added for address {}.",
                         encoded
-                    )
+                    );
+                    None
                 }
             };
+            saved.push(InitializedAccount { alias, address });
         }
     }
+    saved
 }
 
 /// Submit validator commission rate change
@@ -1815,7 +2127,9 @@ pub async fn query_unbonds(
     Ok(())
 }
 
-/// Submit a transaction to bond
+/// Submit a transaction to bond. The extra bonds, if any, are batched with
+/// the first one in an atomic tx. Note that the gas limit of the tx is not
+/// scaled with the number of bonds, so it must cover all of them.
 pub async fn build_bond(
     context: &impl Namada,
     args::Bond {
@@ -1823,26 +2137,24 @@ pub async fn build_bond(
         validator,
         amount,
         source,
+        extra_bonds,
         tx_code_path,
     }: &args::Bond,
 ) -> Result<(Tx, SigningTxData)> {
-    // Require a positive amount of tokens to be bonded
-    if amount.is_zero() {
+    // Bonding to multiple validators in one tx is only possible for
+    // delegations, as a validator cannot bond to another validator
+    if let (None, Some((extra_validator, _))) = (source, extra_bonds.first()) {
         edisplay_line!(
             context.io(),
-            "The requested bond amount is 0. A positive amount must be \
-             requested."
+            "A source address must be given to bond to multiple validators in \
+             one transaction. A validator is prohibited from bonding to \
+             another validator."
         );
-        if !tx_args.force {
-            return Err(Error::from(TxSubmitError::BondIsZero));
-        }
+        return Err(Error::from(TxSubmitError::MultipleBondsWithoutSource(
+            extra_validator.clone(),
+        )));
     }
 
-    // The validator must actually be a validator
-    let validator =
-        known_validator_or_err(validator.clone(), tx_args.force, context)
-            .await?;
-
     // Check that the source address exists on chain
     let mut is_src_also_val = false;
     let source = match source.clone() {
@@ -1856,48 +2168,88 @@ pub async fn build_bond(
         None => Ok(source.clone()),
     }?;
 
-    // Check that the source is not a different validator bonding to validator
-    if is_src_also_val && source != Some(validator.clone()) {
-        edisplay_line!(
-            context.io(),
-            "The given source address {} is a validator. A validator is \
-             prohibited from bonding to another validator.",
-            &source.clone().unwrap()
-        );
-        if !tx_args.force {
-            return Err(Error::from(TxSubmitError::InvalidBondPair(
-                source.clone().unwrap(),
-                validator.clone(),
-            )));
-        }
-    }
-
-    // Give a bonding warning based on the pipeline state
     let params: PosParams = rpc::get_pos_params(context.client()).await?;
     let current_epoch = rpc::query_epoch(context.client()).await?;
     let pipeline_epoch = current_epoch.unchecked_add(params.pipeline_len);
-    let (validator_state_at_pipeline, _) = rpc::get_validator_state(
-        context.client(),
-        &validator,
-        Some(pipeline_epoch),
-    )
-    .await?;
-    if validator_state_at_pipeline == Some(ValidatorState::Inactive) {
-        edisplay_line!(
-            context.io(),
-            "WARNING: the given validator address {} is inactive at the \
-             pipeline epoch {}. If you would still like to bond to the \
-             inactive validator, use the --force option.",
-            &validator,
-            &pipeline_epoch
-        );
-        if !tx_args.force {
-            return Err(Error::from(TxSubmitError::ValidatorInactive(
-                validator.clone(),
-                pipeline_epoch,
+
+    let mut bonds: Vec<(Address, token::Amount)> =
+        Vec::with_capacity(1 + extra_bonds.len());
+    let mut total_amount = token::Amount::zero();
+    for (validator, amount) in std::iter::once((validator, amount)).chain(
+        extra_bonds
+            .iter()
+            .map(|(validator, amount)| (validator, amount)),
+    ) {
+        // Require a positive amount of tokens to be bonded
+        if amount.is_zero() {
+            edisplay_line!(
+                context.io(),
+                "The requested bond amount is 0. A positive amount must be \
+                 requested."
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::BondIsZero));
+            }
+        }
+
+        // The validator must actually be a validator
+        let validator =
+            known_validator_or_err(validator.clone(), tx_args.force, context)
+                .await?;
+
+        // Each validator can only be bonded to once per tx
+        if bonds.iter().any(|(bonded, _)| bonded == &validator) {
+            return Err(Error::from(TxSubmitError::DuplicateBondValidator(
+                validator,
             )));
         }
+
+        // Check that the source is not a different validator bonding to
+        // validator
+        if is_src_also_val && source != Some(validator.clone()) {
+            edisplay_line!(
+                context.io(),
+                "The given source address {} is a validator. A validator is \
+                 prohibited from bonding to another validator.",
+                &source.clone().unwrap()
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::InvalidBondPair(
+                    source.clone().unwrap(),
+                    validator.clone(),
+                )));
+            }
+        }
+
+        // Give a bonding warning based on the pipeline state
+        let (validator_state_at_pipeline, _) = rpc::get_validator_state(
+            context.client(),
+            &validator,
+            Some(pipeline_epoch),
+        )
+        .await?;
+        if validator_state_at_pipeline == Some(ValidatorState::Inactive) {
+            edisplay_line!(
+                context.io(),
+                "WARNING: the given validator address {} is inactive at the \
+                 pipeline epoch {}. If you would still like to bond to the \
+                 inactive validator, use the --force option.",
+                &validator,
+                &pipeline_epoch
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::ValidatorInactive(
+                    validator.clone(),
+                    pipeline_epoch,
+                )));
+            }
+        }
+
+        total_amount = checked!(total_amount + *amount)?;
+        bonds.push((validator, *amount));
     }
+    // The first bond is always present
+    let validator = bonds[0].0.clone();
 
     let default_address = source.clone().unwrap_or(validator.clone());
     let default_signer = Some(default_address.clone());
@@ -1913,7 +2265,7 @@ pub async fn build_bond(
             .await?;
 
     // Check bond's source (source for delegation or validator for self-bonds)
-    // balance
+    // balance against the total amount to be bonded
     let bond_source = source.as_ref().unwrap_or(&validator);
     let native_token = context.native_token();
     let check_balance = if &updated_balance.source == bond_source
@@ -1926,30 +2278,40 @@ pub async fn build_bond(
     check_balance_too_low_err(
         &native_token,
         bond_source,
-        *amount,
+        total_amount,
         check_balance,
         tx_args.force,
         context,
     )
     .await?;
 
-    let data = pos::Bond {
-        validator,
-        amount: *amount,
-        source,
-    };
+    // Build one inner tx per bond and batch them in a single tx
+    let mut txs = Vec::with_capacity(bonds.len());
+    for (validator, amount) in bonds {
+        let data = pos::Bond {
+            validator,
+            amount,
+            source: source.clone(),
+        };
 
-    build(
-        context,
-        tx_args,
-        tx_code_path.clone(),
-        data,
-        do_nothing,
-        fee_amount,
-        &signing_data.fee_payer,
-    )
-    .await
-    .map(|tx| (tx, signing_data))
+        let tx = build(
+            context,
+            tx_args,
+            tx_code_path.clone(),
+            data,
+            do_nothing,
+            fee_amount,
+            &signing_data.fee_payer,
+        )
+        .await?;
+        txs.push((tx, signing_data.clone()));
+    }
+
+    // All the inner txs share the same signing data. The batch is atomic, so
+    // that either all the bonds are applied or none of them.
+    let (mut tx, _) = build_batch(txs)?;
+    tx.header.atomic = true;
+    Ok((tx, signing_data))
 }
 
 /// Build a default proposal governance
@@ -1960,6 +2322,8 @@ pub async fn build_default_proposal(
         proposal_data: _,
         is_pgf_stewards: _,
         is_pgf_funding: _,
+        is_eth_bridge_params: _,
+        is_parameter_changes: _,
         tx_code_path,
     }: &args::InitProposal,
     proposal: DefaultProposal,
@@ -2351,6 +2715,8 @@ pub async fn build_pgf_funding_proposal(
         proposal_data: _,
         is_pgf_stewards: _,
         is_pgf_funding: _,
+        is_eth_bridge_params: _,
+        is_parameter_changes: _,
         tx_code_path,
     }: &args::InitProposal,
     proposal: PgfFundingProposal,
@@ -2396,6 +2762,8 @@ pub async fn build_pgf_stewards_proposal(
         proposal_data: _,
         is_pgf_stewards: _,
         is_pgf_funding: _,
+        is_eth_bridge_params: _,
+        is_parameter_changes: _,
         tx_code_path,
     }: &args::InitProposal,
     proposal: PgfStewardProposal,
@@ -2434,6 +2802,102 @@ pub async fn build_pgf_stewards_proposal(
     .map(|tx| (tx, signing_data))
 }
 
+/// Build an Ethereum bridge parameters proposal governance
+pub async fn build_eth_bridge_params_proposal(
+    context: &impl Namada,
+    args::InitProposal {
+        tx,
+        proposal_data: _,
+        is_pgf_stewards: _,
+        is_pgf_funding: _,
+        is_eth_bridge_params: _,
+        is_parameter_changes: _,
+        tx_code_path,
+    }: &args::InitProposal,
+    proposal: EthBridgeParamsProposal,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(proposal.proposal.author.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx,
+        Some(proposal.proposal.author.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _updated_balance) =
+        validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
+
+    let init_proposal_data = InitProposalData::try_from(proposal.clone())
+        .map_err(|e| TxSubmitError::InvalidProposal(e.to_string()))?;
+
+    let add_section = |tx: &mut Tx, data: &mut InitProposalData| {
+        let (_, extra_section_hash) =
+            tx.add_extra_section(proposal_to_vec(proposal.proposal)?, None);
+        data.content = extra_section_hash;
+        Ok(())
+    };
+
+    build(
+        context,
+        tx,
+        tx_code_path.clone(),
+        init_proposal_data,
+        add_section,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a protocol parameter changes proposal governance
+pub async fn build_parameter_changes_proposal(
+    context: &impl Namada,
+    args::InitProposal {
+        tx,
+        proposal_data: _,
+        is_pgf_stewards: _,
+        is_pgf_funding: _,
+        is_eth_bridge_params: _,
+        is_parameter_changes: _,
+        tx_code_path,
+    }: &args::InitProposal,
+    proposal: ParameterChangesProposal,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(proposal.proposal.author.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx,
+        Some(proposal.proposal.author.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _updated_balance) =
+        validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
+
+    let init_proposal_data = InitProposalData::try_from(proposal.clone())
+        .map_err(|e| TxSubmitError::InvalidProposal(e.to_string()))?;
+
+    let add_section = |tx: &mut Tx, data: &mut InitProposalData| {
+        let (_, extra_section_hash) =
+            tx.add_extra_section(proposal_to_vec(proposal.proposal)?, None);
+        data.content = extra_section_hash;
+        Ok(())
+    };
+
+    build(
+        context,
+        tx,
+        tx_code_path.clone(),
+        init_proposal_data,
+        add_section,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Submit an IBC transfer
 pub async fn build_ibc_transfer(
     context: &impl Namada,
@@ -2964,6 +3428,97 @@ pub async fn build_transfer<N: Namada>(
     Ok((tx, signing_data, shielded_tx_epoch))
 }
 
+/// Make the transfers shielding all the non-zero transparent balances of the
+/// source into the target payment address, one per token. If the fees are
+/// paid by the source, the transfer of the fee token comes last and is
+/// reduced by the fees of all the transfers, or left out if the balance does
+/// not cover them.
+pub async fn build_shield_all<N: Namada>(
+    context: &N,
+    args: &args::TxShieldAll,
+) -> Result<Vec<args::TxTransfer>> {
+    let balances =
+        rpc::get_token_balances(context.client(), &args.source).await?;
+
+    let fees_paid_by_source = !args.tx.disposable_signing_key && {
+        let signing_data = signing::aux_signing_data(
+            context,
+            &args.tx,
+            Some(args.source.clone()),
+            Some(args.source.clone()),
+        )
+        .await?;
+        Address::from(&signing_data.fee_payer) == args.source
+    };
+
+    let (mut fee_token_balance, mut balances): (Vec<_>, Vec<_>) =
+        balances.into_iter().partition(|(token, _)| {
+            fees_paid_by_source && *token == args.tx.fee_token
+        });
+    if let Some((token, balance)) = fee_token_balance.pop() {
+        let fee_amount = validate_fee(context, &args.tx).await?;
+        let num_transfers = checked!(balances.len() as u64 + 1)?;
+        let total_fees = checked!(
+            fee_amount.amount() * u64::from(args.tx.gas_limit) * num_transfers
+        )?;
+        match balance.checked_sub(total_fees) {
+            Some(amount) if !amount.is_zero() => {
+                balances.push((token, amount));
+            }
+            _ => {
+                display_line!(
+                    context.io(),
+                    "Not shielding the balance of the fee token {}, as it \
+                     does not cover the fees of the transfers.",
+                    token
+                );
+            }
+        }
+    }
+
+    let mut transfers = Vec::with_capacity(balances.len());
+    for (token, amount) in balances {
+        let amount = context.denominate_amount(&token, amount).await;
+        transfers.push(args::TxTransfer {
+            tx: args.tx.clone(),
+            source: TransferSource::Address(args.source.clone()),
+            target: TransferTarget::PaymentAddress(args.target),
+            token,
+            amount: args::InputAmount::Validated(amount),
+            masp_rebuild_retries: 0,
+            tx_code_path: args.tx_code_path.clone(),
+        });
+    }
+    Ok(transfers)
+}
+
+/// Shield all the non-zero transparent balances of the source into the target
+/// payment address, see [`build_shield_all`]. The transfers are signed with
+/// the keys of the software wallet and submitted in order, stopping at the
+/// first one that cannot be built or submitted. The public key of an implicit
+/// source must have been revealed beforehand.
+pub async fn submit_shield_all<N: Namada>(
+    context: &N,
+    args: &args::TxShieldAll,
+) -> Result<Vec<ProcessTxResponse>> {
+    let transfers = build_shield_all(context, args).await?;
+    let mut responses = Vec::with_capacity(transfers.len());
+    for mut transfer in transfers {
+        let (mut tx, signing_data, _) = transfer.build(context).await?;
+        context
+            .sign(
+                &mut tx,
+                &transfer.tx,
+                signing_data,
+                signing::default_sign,
+                (),
+            )
+            .await?;
+        responses.push(context.submit(tx, &transfer.tx).await?);
+    }
+    Ok(responses)
+}
+
 // Construct the shielded part of the transaction, if any
 async fn construct_shielded_parts<N: Namada>(
     context: &N,
@@ -2990,6 +3545,15 @@ async fn construct_shielded_parts<N: Namada>(
     let shielded_parts = match stx_result {
         Ok(Some(stx)) => stx,
         Ok(None) => return Ok(None),
+        Err(Build(builder::Error::InsufficientFunds(_)))
+            if source.spending_key().is_some() =>
+        {
+            return Err(TxSubmitError::InsufficientShieldedFunds(
+                amount.amount().to_string_native(),
+                Box::new(token.clone()),
+            )
+            .into());
+        }
         Err(Build(builder::Error::InsufficientFunds(_))) => {
             return Err(TxSubmitError::NegativeBalanceAfterTransfer(
                 Box::new(source.effective_address()),
@@ -2998,6 +3562,7 @@ async fn construct_shielded_parts<N: Namada>(
             )
             .into());
         }
+        Err(General(err)) => return Err(err),
         Err(err) => {
             return Err(TxSubmitError::MaspError(err.to_string()).into());
         }
@@ -3215,6 +3780,293 @@ pub async fn build_update_account(
     .map(|tx| (tx, signing_data))
 }
 
+/// Build the txs that set a public key rotated with
+/// [`crate::wallet::Wallet::rotate_keypair`] on chain. The update account tx
+/// replaces the rotated public key with the new one among the public keys of
+/// the account, keeping its threshold, and has to be signed with the rotated
+/// key. It is followed by a reveal pk tx of the new public key, unless it has
+/// already been revealed.
+pub async fn build_key_rotation(
+    context: &impl Namada,
+    tx_args: &args::Tx,
+    addr: &Address,
+    old_pk: &common::PublicKey,
+    new_pk: &common::PublicKey,
+) -> Result<Vec<(Tx, SigningTxData)>> {
+    let account = rpc::get_account_info(context.client(), addr)
+        .await?
+        .ok_or_else(|| {
+            Error::from(TxSubmitError::LocationDoesNotExist(addr.clone()))
+        })?;
+    if account.get_index_from_public_key(old_pk).is_none() {
+        return Err(Error::Other(format!(
+            "The public key {old_pk} is not a key of the account {addr}"
+        )));
+    }
+    // Preserve the indices of the other keys of the account
+    let public_keys = account
+        .public_keys_map
+        .idx_to_pk
+        .iter()
+        .collect::<BTreeMap<_, _>>()
+        .into_values()
+        .map(|pk| {
+            if pk == old_pk {
+                new_pk.clone()
+            } else {
+                pk.clone()
+            }
+        })
+        .collect();
+
+    let mut txs = vec![
+        build_update_account(
+            context,
+            &args::TxUpdateAccount {
+                tx: tx_args.clone(),
+                vp_code_path: None,
+                tx_code_path: PathBuf::from(TX_UPDATE_ACCOUNT_WASM),
+                addr: addr.clone(),
+                public_keys,
+                threshold: None,
+            },
+        )
+        .await?,
+    ];
+    if !rpc::is_public_key_revealed(context.client(), &Address::from(new_pk))
+        .await?
+    {
+        txs.push(build_reveal_pk(context, tx_args, new_pk).await?);
+    }
+    Ok(txs)
+}
+
+/// Submit a transaction to set or remove a spender's allowance on an account
+pub async fn build_update_allowance(
+    context: &impl Namada,
+    args::TxUpdateAllowance {
+        tx: tx_args,
+        owner,
+        spender,
+        token,
+        daily_cap,
+        tx_code_path,
+    }: &args::TxUpdateAllowance,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(owner.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(owner.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    if rpc::get_account_info(context.client(), owner)
+        .await?
+        .is_none()
+    {
+        return Err(Error::from(TxSubmitError::LocationDoesNotExist(
+            owner.clone(),
+        )));
+    }
+
+    let data = UpdateAllowance {
+        owner: owner.clone(),
+        spender: spender.clone(),
+        token: token.clone(),
+        daily_cap: *daily_cap,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a transaction for a spender to transfer tokens out of an account
+/// within its allowance
+pub async fn build_spend_allowance(
+    context: &impl Namada,
+    args::TxSpendAllowance {
+        tx: tx_args,
+        owner,
+        spender,
+        target,
+        token,
+        amount,
+        tx_code_path,
+    }: &args::TxSpendAllowance,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(spender.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(spender.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let daily_cap = rpc::get_allowances(context.client(), owner)
+        .await?
+        .into_iter()
+        .find_map(|(allowance_spender, allowance_token, daily_cap)| {
+            (&allowance_spender == spender && &allowance_token == token)
+                .then_some(daily_cap)
+        });
+    match daily_cap {
+        None => {
+            edisplay_line!(
+                context.io(),
+                "The account {owner} has not granted {spender} an allowance \
+                 of token {token}."
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::AllowanceNotFound(
+                    owner.clone(),
+                    spender.clone(),
+                    token.clone(),
+                )));
+            }
+        }
+        Some(daily_cap) if *amount > daily_cap => {
+            edisplay_line!(
+                context.io(),
+                "The amount to spend {} exceeds the allowance's daily cap {}.",
+                amount.to_string_native(),
+                daily_cap.to_string_native()
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::AllowanceExceeded(
+                    amount.to_string_native(),
+                    daily_cap.to_string_native(),
+                )));
+            }
+        }
+        Some(_) => {}
+    }
+
+    let data = SpendAllowance {
+        owner: owner.clone(),
+        spender: spender.clone(),
+        target: target.clone(),
+        token: token.clone(),
+        amount: *amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a transaction for the fee price feed to update the conversion rates
+/// of fee tokens
+pub async fn build_update_fee_conversion_rates(
+    context: &impl Namada,
+    args::TxUpdateFeeConversionRates {
+        tx: tx_args,
+        price_feed,
+        rates,
+        tx_code_path,
+    }: &args::TxUpdateFeeConversionRates,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(price_feed.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(price_feed.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let current_price_feed: Option<Address> = rpc::query_storage_value(
+        context.client(),
+        &params_storage::get_fee_price_feed_key(),
+    )
+    .await
+    .ok();
+    if current_price_feed.as_ref() != Some(price_feed) {
+        edisplay_line!(
+            context.io(),
+            "The address {price_feed} is not the fee price feed."
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::NotFeePriceFeed(
+                price_feed.clone(),
+            )));
+        }
+    }
+
+    // Only governance can add or remove tokens priced by conversion
+    let current_rates: BTreeMap<Address, Dec> = rpc::query_storage_value(
+        context.client(),
+        &params_storage::get_fee_conversion_rates_key(),
+    )
+    .await
+    .unwrap_or_default();
+    for (token, rate) in rates {
+        if rate.is_negative() || rate.is_zero() {
+            return Err(Error::from(TxSubmitError::InvalidFeeConversionRate(
+                token.clone(),
+                *rate,
+            )));
+        }
+        if !current_rates.contains_key(token) {
+            edisplay_line!(
+                context.io(),
+                "The token {token} is not priced by conversion from the \
+                 native token for fee payment."
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::FeeTokenNotConverted(
+                    token.clone(),
+                )));
+            }
+        }
+    }
+
+    let data = UpdateFeeConversionRates {
+        price_feed: price_feed.clone(),
+        rates: rates.iter().cloned().collect(),
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Submit a custom transaction
 pub async fn build_custom(
     context: &impl Namada,
@@ -0,0 +1,173 @@
+//! Versioned interchange format to move the content of a wallet between
+//! machines.
+
+use std::collections::BTreeMap;
+
+use namada_core::address::Address;
+use namada_core::key::common;
+use namada_core::masp::{
+    ExtendedSpendingKey, ExtendedViewingKey, PaymentAddress,
+};
+use namada_core::storage::BlockHeight;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::alias::Alias;
+use super::derivation_path::DerivationPath;
+use super::StoredKeypair;
+
+/// The current version of the wallet interchange format
+pub const WALLET_EXPORT_VERSION: u32 = 1;
+
+/// A versioned export of the content of a wallet. Secret and spending keys
+/// are exported in the form they are stored in the wallet, i.e. encrypted
+/// unless they were stored unencrypted.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WalletExport {
+    /// The version of the interchange format
+    pub version: u32,
+    /// Namada address book
+    #[serde(default)]
+    pub addresses: BTreeMap<Alias, Address>,
+    /// Known public keys
+    #[serde(default)]
+    pub public_keys: BTreeMap<Alias, common::PublicKey>,
+    /// Cryptographic keypairs
+    #[serde(default)]
+    pub secret_keys: BTreeMap<Alias, StoredKeypair<common::SecretKey>>,
+    /// Known derivation paths
+    #[serde(default)]
+    pub derivation_paths: BTreeMap<Alias, DerivationPath>,
+    /// Known spending keys
+    #[serde(default)]
+    pub spend_keys: BTreeMap<Alias, StoredKeypair<ExtendedSpendingKey>>,
+    /// Known viewing keys
+    #[serde(default)]
+    pub view_keys: BTreeMap<Alias, ExtendedViewingKey>,
+    /// Payment address book
+    #[serde(default)]
+    pub payment_addrs: BTreeMap<Alias, PaymentAddress>,
+    /// Birthdays of the shielded keys
+    #[serde(default)]
+    pub birthdays: BTreeMap<Alias, BlockHeight>,
+}
+
+impl Default for WalletExport {
+    fn default() -> Self {
+        Self {
+            version: WALLET_EXPORT_VERSION,
+            addresses: Default::default(),
+            public_keys: Default::default(),
+            secret_keys: Default::default(),
+            derivation_paths: Default::default(),
+            spend_keys: Default::default(),
+            view_keys: Default::default(),
+            payment_addrs: Default::default(),
+            birthdays: Default::default(),
+        }
+    }
+}
+
+impl WalletExport {
+    /// All the aliases present in the export
+    pub fn aliases(&self) -> impl Iterator<Item = &Alias> {
+        let mut aliases: Vec<&Alias> = self
+            .addresses
+            .keys()
+            .chain(self.public_keys.keys())
+            .chain(self.secret_keys.keys())
+            .chain(self.derivation_paths.keys())
+            .chain(self.spend_keys.keys())
+            .chain(self.view_keys.keys())
+            .chain(self.payment_addrs.keys())
+            .chain(self.birthdays.keys())
+            .collect();
+        aliases.sort();
+        aliases.dedup();
+        aliases.into_iter()
+    }
+
+    /// Encode the export to JSON
+    pub fn to_json(&self) -> Result<String, WalletExportError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| WalletExportError::Encode(e.to_string()))
+    }
+
+    /// Encode the export to TOML
+    pub fn to_toml(&self) -> Result<String, WalletExportError> {
+        toml::to_string(self)
+            .map_err(|e| WalletExportError::Encode(e.to_string()))
+    }
+
+    /// Decode an export from JSON, checking its version
+    pub fn from_json(data: &str) -> Result<Self, WalletExportError> {
+        serde_json::from_str::<Self>(data)
+            .map_err(|e| WalletExportError::Decode(e.to_string()))?
+            .check_version()
+    }
+
+    /// Decode an export from TOML, checking its version
+    pub fn from_toml(data: &str) -> Result<Self, WalletExportError> {
+        toml::from_str::<Self>(data)
+            .map_err(|e| WalletExportError::Decode(e.to_string()))?
+            .check_version()
+    }
+
+    fn check_version(self) -> Result<Self, WalletExportError> {
+        if self.version == WALLET_EXPORT_VERSION {
+            Ok(self)
+        } else {
+            Err(WalletExportError::UnsupportedVersion(self.version))
+        }
+    }
+}
+
+/// What to do when an imported alias is already used in the wallet
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImportConflictPolicy {
+    /// Keep the existing entries and skip the imported ones
+    Skip,
+    /// Replace the existing entries with the imported ones
+    Overwrite,
+    /// Import the entries under a new alias, made unique with a numeric
+    /// suffix
+    Rename,
+    /// Abort the whole import without modifying the wallet
+    Abort,
+}
+
+/// Summary of the outcome of a wallet import
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Aliases imported without conflicts
+    pub imported: Vec<Alias>,
+    /// Aliases whose existing entries were replaced
+    pub overwritten: Vec<Alias>,
+    /// Aliases imported under a new alias
+    pub renamed: Vec<(Alias, Alias)>,
+    /// Aliases that were skipped because already in use
+    pub skipped: Vec<Alias>,
+}
+
+/// Errors of the wallet interchange format
+#[derive(Error, Debug)]
+pub enum WalletExportError {
+    /// Encoding error
+    #[error("Failed to encode the wallet export: {0}")]
+    Encode(String),
+    /// Decoding error
+    #[error("Failed to decode the wallet export: {0}")]
+    Decode(String),
+    /// The version of the export is not supported
+    #[error(
+        "Unsupported wallet export version {0}, expected \
+         {WALLET_EXPORT_VERSION}"
+    )]
+    UnsupportedVersion(u32),
+    /// An alias is already used and the import was aborted
+    #[error("The alias {0} is already used in the wallet, import aborted")]
+    AliasConflict(Alias),
+    /// An alias is reserved
+    #[error("The alias {0} is reserved")]
+    ReservedAlias(Alias),
+}
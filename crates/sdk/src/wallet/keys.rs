@@ -18,7 +18,7 @@ const ENCRYPTED_KEY_PREFIX: &str = "encrypted:";
 const UNENCRYPTED_KEY_PREFIX: &str = "unencrypted:";
 
 /// A keypair stored in a wallet
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum StoredKeypair<T: BorshSerialize + BorshDeserialize + Display + FromStr>
 where
     <T as FromStr>::Err: Display,
@@ -114,7 +114,7 @@ pub enum DeserializeStoredKeypairError {
 }
 
 /// An encrypted keypair stored in a wallet
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EncryptedKeypair<T: BorshSerialize + BorshDeserialize>(
     Vec<u8>,
     PhantomData<T>,
@@ -205,7 +205,7 @@ impl<T: BorshSerialize + BorshDeserialize> EncryptedKeypair<T> {
         let salt = encryption_salt();
         let encryption_key = encryption_key(&salt, &password);
 
-        let data = keypair.serialize_to_vec();
+        let data = Zeroizing::new(keypair.serialize_to_vec());
 
         let encrypted_keypair = aead::seal(&encryption_key, &data)
             .expect("Encryption of data shouldn't fail");
@@ -232,8 +232,10 @@ impl<T: BorshSerialize + BorshDeserialize> EncryptedKeypair<T> {
 
         let encryption_key = encryption_key(&salt, &password);
 
-        let decrypted_data = aead::open(&encryption_key, cipher)
-            .map_err(|_| DecryptionError::DecryptionError)?;
+        let decrypted_data = Zeroizing::new(
+            aead::open(&encryption_key, cipher)
+                .map_err(|_| DecryptionError::DecryptionError)?,
+        );
 
         T::try_from_slice(&decrypted_data)
             .map_err(|_| DecryptionError::DeserializingError)
@@ -3,8 +3,10 @@
 //! Provides functionality for managing keys and addresses for a user
 pub mod alias;
 mod derivation_path;
+pub mod interchange;
 mod keys;
 pub mod pre_genesis;
+mod secret;
 pub mod store;
 
 use std::collections::BTreeMap;
@@ -21,6 +23,7 @@ use namada_core::key::*;
 use namada_core::masp::{
     ExtendedSpendingKey, ExtendedViewingKey, PaymentAddress,
 };
+use namada_core::storage::BlockHeight;
 pub use pre_genesis::gen_key_to_store;
 use rand::CryptoRng;
 use rand_core::RngCore;
@@ -29,7 +32,11 @@ use thiserror::Error;
 use zeroize::Zeroizing;
 
 pub use self::derivation_path::{DerivationPath, DerivationPathError};
+pub use self::interchange::{
+    ImportConflictPolicy, ImportSummary, WalletExport, WalletExportError,
+};
 pub use self::keys::{DecryptionError, StoredKeypair};
+use self::secret::DecryptedSecret;
 pub use self::store::{ConfirmationResponse, ValidatorData, ValidatorKeys};
 use crate::wallet::store::{derive_hd_secret_key, derive_hd_spending_key};
 
@@ -63,6 +70,14 @@ pub trait WalletIo: Sized + Clone {
         panic!("attempted to prompt for alias in non-interactive mode");
     }
 
+    /// Choose the alias of an account initialized by a transaction, when no
+    /// alias was requested for it. By default, prompt for one with
+    /// [`WalletIo::read_alias`]. If the alias conflicts with an existing one,
+    /// [`WalletIo::show_overwrite_confirmation`] decides how to resolve it.
+    fn initialized_account_alias(address: &Address) -> String {
+        Self::read_alias(&address.encode())
+    }
+
     /// Read mnemonic code from the file/env/stdin.
     fn read_mnemonic_code() -> Option<Mnemonic> {
         panic!("attempted to prompt for alias in non-interactive mode");
@@ -246,6 +261,31 @@ pub enum FindKeyError {
     KeyDecryptionError(keys::DecryptionError),
 }
 
+/// Errors of wallet key rotations
+#[derive(Error, Debug)]
+pub enum KeyRotationError {
+    /// Could not find the key to rotate
+    #[error("{0}")]
+    FindKey(#[from] FindKeyError),
+    /// The alias of the key to rotate is not mapped to an established address
+    #[error(
+        "The key {0} is not associated with an established address, whose \
+         keys could be rotated on chain"
+    )]
+    NotEstablishedAddress(String),
+}
+
+/// The outcome of a wallet key rotation
+#[derive(Debug)]
+pub struct RotatedKeypair {
+    /// The alias under which the replaced keypair is kept
+    pub old_alias: String,
+    /// The replaced public key
+    pub old_pk: common::PublicKey,
+    /// The new secret key
+    pub new_sk: common::SecretKey,
+}
+
 /// Represents a collection of keys and addresses while caching key decryptions
 #[derive(Debug)]
 pub struct Wallet<U> {
@@ -253,7 +293,7 @@ pub struct Wallet<U> {
     utils: U,
     store: Store,
     decrypted_key_cache: HashMap<Alias, common::SecretKey>,
-    decrypted_spendkey_cache: HashMap<Alias, ExtendedSpendingKey>,
+    decrypted_spendkey_cache: HashMap<Alias, DecryptedSecret>,
 }
 
 impl<U> From<Wallet<U>> for Store {
@@ -421,6 +461,12 @@ impl<U> Wallet<U> {
         })
     }
 
+    /// Find the birthday of the shielded key with the given alias in the
+    /// wallet, if any
+    pub fn find_birthday(&self, alias: impl AsRef<str>) -> Option<BlockHeight> {
+        self.store.find_birthday(alias.as_ref())
+    }
+
     /// Find the payment address with the given alias in the wallet and return
     /// it
     pub fn find_payment_addr(
@@ -488,6 +534,15 @@ impl<U> Wallet<U> {
             .collect()
     }
 
+    /// Get the birthdays of all known shielded keys by their alias
+    pub fn get_birthdays(&self) -> HashMap<String, BlockHeight> {
+        self.store
+            .get_birthdays()
+            .iter()
+            .map(|(alias, value)| (alias.into(), *value))
+            .collect()
+    }
+
     /// Get all known viewing keys by their alias
     pub fn get_spending_keys(
         &self,
@@ -822,6 +877,152 @@ impl<U: WalletIo> Wallet<U> {
         )
     }
 
+    /// Re-encrypt all the encrypted secret and spending keys of the wallet
+    /// with a new password. The keys are decrypted with the given old
+    /// password, or with an interactively prompted one if none is given.
+    /// Unencrypted keys are left as they are.
+    pub fn change_password(
+        &mut self,
+        old_password: Option<Zeroizing<String>>,
+        new_password: Zeroizing<String>,
+    ) -> Result<(), FindKeyError> {
+        let has_encrypted_keys = self
+            .store
+            .get_secret_keys()
+            .values()
+            .any(|(stored_key, _)| stored_key.is_encrypted())
+            || self
+                .store
+                .get_spending_keys()
+                .values()
+                .any(StoredKeypair::is_encrypted);
+        if !has_encrypted_keys {
+            return Ok(());
+        }
+        let old_password =
+            old_password.unwrap_or_else(|| U::read_password(false));
+
+        // Decrypt all the keys before re-encrypting any of them, so that a
+        // wrong password leaves the store untouched. The keys are decrypted
+        // from the store rather than from the cache, such that the old
+        // password gets checked for every key.
+        let secret_keys = self
+            .store
+            .get_secret_keys()
+            .into_iter()
+            .filter_map(|(alias, (stored_key, _))| match stored_key {
+                StoredKeypair::Encrypted(encrypted) => Some(
+                    encrypted
+                        .decrypt(old_password.clone())
+                        .map(|key| (alias, key)),
+                ),
+                StoredKeypair::Raw(_) => None,
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(FindKeyError::KeyDecryptionError)?;
+        let spending_keys = self
+            .store
+            .get_spending_keys()
+            .iter()
+            .filter_map(|(alias, stored_key)| match stored_key {
+                StoredKeypair::Encrypted(encrypted) => Some(
+                    encrypted
+                        .decrypt(old_password.clone())
+                        .map(|key| (alias.clone(), key)),
+                ),
+                StoredKeypair::Raw(_) => None,
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(FindKeyError::KeyDecryptionError)?;
+
+        for (alias, key) in secret_keys {
+            self.store.reencrypt_secret_key(
+                &alias,
+                key,
+                Some(new_password.clone()),
+            );
+        }
+        for (alias, key) in spending_keys {
+            self.store.reencrypt_spending_key(
+                &alias,
+                key,
+                Some(new_password.clone()),
+            );
+        }
+        Ok(())
+    }
+
+    /// Replace the keypair stored under the given alias with a newly
+    /// generated one, preserving the established address the alias is mapped
+    /// to. If no encryption password is provided, the new keypair will be
+    /// stored raw without encryption.
+    ///
+    /// The new public key still has to be set on chain with the txs built by
+    /// [`crate::tx::build_key_rotation`], for the rotation to take effect.
+    /// As the update account tx among them must be signed by the replaced
+    /// key, the replaced keypair is kept in the wallet under a new alias. It
+    /// can be removed once the rotation has been applied on chain.
+    pub fn rotate_keypair(
+        &mut self,
+        alias: impl AsRef<str>,
+        scheme: SchemeType,
+        password: Option<Zeroizing<String>>,
+        rng: &mut (impl CryptoRng + RngCore),
+    ) -> Result<RotatedKeypair, KeyRotationError> {
+        let alias = alias.as_ref();
+        let old_pk = self.find_public_key(alias)?;
+        let address = match self.find_address(alias).map(|a| a.into_owned()) {
+            Some(address @ Address::Established(_)) => address,
+            _ => {
+                return Err(KeyRotationError::NotEstablishedAddress(
+                    alias.to_string(),
+                ));
+            }
+        };
+
+        // Keep the replaced keypair under the first free alias of the form
+        // `<alias>-old` or `<alias>-old-<n>`
+        let key_alias = Alias::from(alias);
+        let old_alias = (1..)
+            .map(|n| match n {
+                1 => Alias::from(format!("{alias}-old")),
+                n => Alias::from(format!("{alias}-old-{n}")),
+            })
+            .find(|old_alias| !self.store.contains_alias(old_alias))
+            .expect("There must be a free alias");
+        if !self.store.rename_keypair(&key_alias, old_alias.clone()) {
+            return Err(FindKeyError::KeyNotFound(alias.to_string()).into());
+        }
+        if let Some(old_sk) = self.decrypted_key_cache.remove(&key_alias) {
+            self.decrypted_key_cache.insert(old_alias.clone(), old_sk);
+        }
+
+        let new_sk = gen_secret_key(scheme, rng);
+        if self
+            .insert_keypair(
+                alias.to_string(),
+                true,
+                new_sk.clone(),
+                password,
+                Some(address),
+                None,
+            )
+            .is_none()
+        {
+            // Restore the replaced keypair
+            self.store.rename_keypair(&old_alias, key_alias.clone());
+            if let Some(old_sk) = self.decrypted_key_cache.remove(&old_alias) {
+                self.decrypted_key_cache.insert(key_alias, old_sk);
+            }
+            return Err(FindKeyError::KeyNotFound(alias.to_string()).into());
+        }
+        Ok(RotatedKeypair {
+            old_alias: old_alias.into(),
+            old_pk,
+            new_sk,
+        })
+    }
+
     /// Find the public key by an alias or a public key hash.
     pub fn find_public_key(
         &self,
@@ -848,7 +1049,7 @@ impl<U: WalletIo> Wallet<U> {
             .decrypted_spendkey_cache
             .get(&Alias::from(alias.as_ref()))
         {
-            return Ok(*cached_key);
+            return Ok(cached_key.decode());
         }
         // If not cached, look-up in store
         let stored_spendkey = self
@@ -857,12 +1058,21 @@ impl<U: WalletIo> Wallet<U> {
             .ok_or_else(|| {
                 FindKeyError::KeyNotFound(alias.as_ref().to_string())
             })?;
-        Self::decrypt_stored_key::<_>(
-            &mut self.decrypted_spendkey_cache,
-            stored_spendkey,
-            alias.into(),
-            password,
-        )
+        match stored_spendkey {
+            StoredKeypair::Encrypted(encrypted) => {
+                let password =
+                    password.unwrap_or_else(|| U::read_password(false));
+                let spend_key = encrypted
+                    .decrypt(password)
+                    .map_err(FindKeyError::KeyDecryptionError)?;
+                // NB: spending keys are `Copy` and can't scrub themselves,
+                // so only their encoding is cached
+                self.decrypted_spendkey_cache
+                    .insert(alias.into(), DecryptedSecret::new(&spend_key));
+                Ok(spend_key)
+            }
+            StoredKeypair::Raw(raw) => Ok(*raw),
+        }
     }
 
     /// Find the stored key by a public key.
@@ -1045,6 +1255,18 @@ impl<U: WalletIo> Wallet<U> {
             .map(Into::into)
     }
 
+    /// Set the block height before which the shielded key with the given
+    /// alias cannot have received any notes, such that shielded syncs can
+    /// skip scanning the blocks that precede it. Returns `false` if no
+    /// shielded key is known under this alias.
+    pub fn insert_birthday(
+        &mut self,
+        alias: impl AsRef<str>,
+        birthday: BlockHeight,
+    ) -> bool {
+        self.store.insert_birthday(alias.as_ref().into(), birthday)
+    }
+
     /// Insert a spending key into the wallet under the given alias
     pub fn insert_spending_key(
         &mut self,
@@ -1065,7 +1287,7 @@ impl<U: WalletIo> Wallet<U> {
             .map(|alias| {
                 // Cache the newly added key
                 self.decrypted_spendkey_cache
-                    .insert(alias.clone(), spend_key);
+                    .insert(alias.clone(), DecryptedSecret::new(&spend_key));
                 alias
             })
             .map(Into::into)
@@ -1083,6 +1305,23 @@ impl<U: WalletIo> Wallet<U> {
             .map(Into::into)
     }
 
+    /// Export the addresses, keys and payment addresses of this wallet to
+    /// the interchange format. Secret and spending keys are only included
+    /// when `include_secrets` is set.
+    pub fn export(&self, include_secrets: bool) -> WalletExport {
+        self.store.export(include_secrets)
+    }
+
+    /// Import the content of an export in the interchange format, resolving
+    /// alias conflicts with the given policy.
+    pub fn import(
+        &mut self,
+        export: WalletExport,
+        policy: ImportConflictPolicy,
+    ) -> Result<ImportSummary, WalletExportError> {
+        self.store.import(export, policy)
+    }
+
     /// Extend this wallet from another wallet (typically pre-genesis).
     /// Note that this method ignores `store.validator_data` if any.
     pub fn extend(&mut self, wallet: Self) {
@@ -1094,3 +1333,197 @@ impl<U: WalletIo> Wallet<U> {
         self.store.remove_alias(&alias.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::established_address_1;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    /// A non-interactive wallet for testing
+    #[derive(Clone)]
+    struct TestWalletUtils;
+
+    impl WalletIo for TestWalletUtils {
+        type Rng = OsRng;
+    }
+
+    /// Decrypt the secret key stored under the given alias
+    fn decrypt_secret_key(
+        wallet: &Wallet<TestWalletUtils>,
+        alias: &str,
+        password: &str,
+    ) -> Result<common::SecretKey, DecryptionError> {
+        match wallet.store().find_secret_key(alias).unwrap() {
+            StoredKeypair::Encrypted(encrypted) => {
+                encrypted.decrypt(Zeroizing::new(password.to_string()))
+            }
+            StoredKeypair::Raw(raw) => Ok(raw.clone()),
+        }
+    }
+
+    #[test]
+    fn test_change_password() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        let sk = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let spend_key = gen_spending_key(&mut OsRng);
+        let old_password = Zeroizing::new("old".to_string());
+        let new_password = Zeroizing::new("new".to_string());
+        wallet
+            .insert_keypair(
+                "alice".to_string(),
+                false,
+                sk.clone(),
+                Some(old_password.clone()),
+                None,
+                None,
+            )
+            .unwrap();
+        wallet
+            .insert_spending_key(
+                "alice-shielded".to_string(),
+                false,
+                spend_key,
+                Some(old_password.clone()),
+                None,
+            )
+            .unwrap();
+
+        // a wrong old password is rejected, even for cached keys
+        assert!(
+            wallet
+                .change_password(
+                    Some(Zeroizing::new("wrong".to_string())),
+                    new_password.clone(),
+                )
+                .is_err()
+        );
+        assert_eq!(
+            decrypt_secret_key(&wallet, "alice", "old")
+                .unwrap()
+                .ref_to(),
+            sk.ref_to()
+        );
+
+        wallet
+            .change_password(Some(old_password), new_password.clone())
+            .unwrap();
+        assert!(decrypt_secret_key(&wallet, "alice", "old").is_err());
+        assert_eq!(
+            decrypt_secret_key(&wallet, "alice", "new")
+                .unwrap()
+                .ref_to(),
+            sk.ref_to()
+        );
+
+        // the keys can be decrypted with the new password from a fresh wallet
+        let mut wallet = Wallet::new(TestWalletUtils, Store::from(wallet));
+        assert!(
+            wallet
+                .find_spending_key(
+                    "alice-shielded",
+                    Some(Zeroizing::new("old".to_string())),
+                )
+                .is_err()
+        );
+        assert_eq!(
+            wallet
+                .find_spending_key("alice-shielded", Some(new_password))
+                .unwrap()
+                .to_string(),
+            spend_key.to_string()
+        );
+    }
+
+    #[test]
+    fn test_rotate_keypair() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        let old_sk = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let address = established_address_1();
+        let password = Zeroizing::new("password".to_string());
+        wallet
+            .insert_keypair(
+                "alice".to_string(),
+                false,
+                old_sk.clone(),
+                Some(password.clone()),
+                Some(address.clone()),
+                None,
+            )
+            .unwrap();
+
+        let RotatedKeypair {
+            old_alias,
+            old_pk,
+            new_sk,
+        } = wallet
+            .rotate_keypair(
+                "alice",
+                SchemeType::Ed25519,
+                Some(password.clone()),
+                &mut OsRng,
+            )
+            .unwrap();
+        assert_eq!(old_alias, "alice-old");
+        assert_eq!(old_pk, old_sk.ref_to());
+        assert_ne!(new_sk.ref_to(), old_pk);
+
+        // the alias maps to the new key and still to the same address
+        let mut wallet = Wallet::new(TestWalletUtils, Store::from(wallet));
+        assert_eq!(wallet.find_public_key("alice").unwrap(), new_sk.ref_to());
+        assert_eq!(wallet.find_address("alice").unwrap().into_owned(), address);
+        assert_eq!(
+            wallet
+                .find_secret_key("alice", Some(password.clone()))
+                .unwrap()
+                .ref_to(),
+            new_sk.ref_to()
+        );
+
+        // the replaced key is still reachable, to sign the update account tx
+        assert_eq!(
+            wallet
+                .find_key_by_pk(&old_pk, Some(password.clone()))
+                .unwrap()
+                .ref_to(),
+            old_pk
+        );
+        assert_eq!(
+            wallet
+                .find_secret_key(&old_alias, Some(password))
+                .unwrap()
+                .ref_to(),
+            old_pk
+        );
+
+        // a second rotation keeps both replaced keys
+        let rotated = wallet
+            .rotate_keypair("alice", SchemeType::Ed25519, None, &mut OsRng)
+            .unwrap();
+        assert_eq!(rotated.old_alias, "alice-old-2");
+        assert_eq!(rotated.old_pk, new_sk.ref_to());
+        assert!(wallet.find_public_key("alice-old").is_ok());
+    }
+
+    #[test]
+    fn test_rotate_implicit_keypair_rejected() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        let sk = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        wallet
+            .insert_keypair(
+                "bob".to_string(),
+                false,
+                sk.clone(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert!(matches!(
+            wallet.rotate_keypair("bob", SchemeType::Ed25519, None, &mut OsRng),
+            Err(KeyRotationError::NotEstablishedAddress(_))
+        ));
+        assert_eq!(wallet.find_public_key("bob").unwrap(), sk.ref_to());
+    }
+}
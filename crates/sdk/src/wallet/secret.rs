@@ -0,0 +1,90 @@
+//! Secrets decrypted from the wallet and kept in memory.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The borsh encoding of a secret decrypted from the wallet. It is used for
+/// the secrets that can't scrub themselves, e.g. the MASP spending keys that
+/// are `Copy`, so that the copy cached by the wallet is zeroized once
+/// dropped. With the `mlock` feature, its buffer is also locked in memory,
+/// so that it is never swapped to disk.
+pub(super) struct DecryptedSecret(Zeroizing<Vec<u8>>);
+
+impl DecryptedSecret {
+    /// Encode the given secret
+    pub fn new<T: BorshSerialize>(secret: &T) -> Self {
+        let bytes = Zeroizing::new(secret.serialize_to_vec());
+        #[cfg(all(feature = "mlock", unix))]
+        mlock::lock(bytes.as_ptr(), bytes.capacity());
+        Self(bytes)
+    }
+
+    /// Decode the secret
+    pub fn decode<T: BorshDeserialize>(&self) -> T {
+        T::try_from_slice(&self.0)
+            .expect("The secret must be decoded with the type it was encoded")
+    }
+}
+
+impl Drop for DecryptedSecret {
+    fn drop(&mut self) {
+        // NB: the buffer must be unlocked before it is freed, but only once
+        // it is scrubbed
+        self.0.zeroize();
+        #[cfg(all(feature = "mlock", unix))]
+        mlock::unlock(self.0.as_ptr(), self.0.capacity());
+    }
+}
+
+impl std::fmt::Debug for DecryptedSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DecryptedSecret(..)")
+    }
+}
+
+#[cfg(all(feature = "mlock", unix))]
+mod mlock {
+    /// Lock the memory allocated to a buffer, so that it is never swapped to
+    /// disk. Failures, e.g. if the limit of locked memory of the process is
+    /// reached, are only logged.
+    pub fn lock(ptr: *const u8, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        // SAFETY: locking memory doesn't access it, and the range is the
+        // allocation of a live buffer
+        let res = unsafe { libc::mlock(ptr.cast(), capacity) };
+        if res != 0 {
+            tracing::warn!(
+                "Failed to lock a decrypted secret in memory: {}",
+                std::io::Error::last_os_error()
+            );
+        }
+    }
+
+    /// Unlock the memory allocated to a buffer
+    pub fn unlock(ptr: *const u8, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        // SAFETY: unlocking memory doesn't access it, and unlocking memory
+        // that isn't locked is a no-op
+        unsafe {
+            libc::munlock(ptr.cast(), capacity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decrypted_secret_roundtrip() {
+        let secret = [42u8; 32];
+        let decrypted = DecryptedSecret::new(&secret);
+        assert_eq!(decrypted.decode::<[u8; 32]>(), secret);
+        assert_eq!(format!("{decrypted:?}"), "DecryptedSecret(..)");
+    }
+}
@@ -15,11 +15,15 @@ use namada_core::key::*;
 use namada_core::masp::{
     ExtendedSpendingKey, ExtendedViewingKey, PaymentAddress,
 };
+use namada_core::storage::BlockHeight;
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
 use super::alias::{self, Alias};
 use super::derivation_path::DerivationPath;
+use super::interchange::{
+    ImportConflictPolicy, ImportSummary, WalletExport, WalletExportError,
+};
 use super::pre_genesis;
 use crate::wallet::{StoredKeypair, WalletIo};
 
@@ -82,6 +86,10 @@ pub struct Store {
     pub(crate) validator_data: Option<ValidatorData>,
     /// Namada address vp type
     address_vp_types: BTreeMap<AddressVpType, HashSet<Address>>,
+    /// The block heights before which the shielded keys with the given
+    /// aliases cannot have received any notes
+    #[serde(default)]
+    birthdays: BTreeMap<Alias, BlockHeight>,
 }
 
 /// Grouping of addresses by validity predicate.
@@ -145,6 +153,11 @@ impl Store {
         self.view_keys.get(&alias.into())
     }
 
+    /// Find the birthday of the shielded key with the given alias
+    pub fn find_birthday(&self, alias: impl AsRef<str>) -> Option<BlockHeight> {
+        self.birthdays.get(&alias.into()).copied()
+    }
+
     /// Find the payment address with the given alias and return it
     pub fn find_payment_addr(
         &self,
@@ -256,6 +269,11 @@ impl Store {
         &self.view_keys
     }
 
+    /// Get the birthdays of all known shielded keys by their alias.
+    pub fn get_birthdays(&self) -> &BTreeMap<Alias, BlockHeight> {
+        &self.birthdays
+    }
+
     /// Get all known spending keys by their alias.
     pub fn get_spending_keys(
         &self,
@@ -363,6 +381,71 @@ impl Store {
         Some(alias)
     }
 
+    /// Move the keypair stored under the given alias to another alias, along
+    /// with its public key hash and derivation path. The address the given
+    /// alias is mapped to is left in place. Returns `false` if no secret key
+    /// is stored under the given alias, or if a key is already stored under
+    /// the other alias.
+    pub fn rename_keypair(&mut self, alias: &Alias, new_alias: Alias) -> bool {
+        if !self.secret_keys.contains_key(alias)
+            || self.secret_keys.contains_key(&new_alias)
+            || self.public_keys.contains_key(&new_alias)
+        {
+            return false;
+        }
+        if let Some(keypair) = self.secret_keys.remove(alias) {
+            self.secret_keys.insert(new_alias.clone(), keypair);
+        }
+        if let Some(pubkey) = self.public_keys.remove(alias) {
+            self.public_keys.insert(new_alias.clone(), pubkey);
+        }
+        for pkh_alias in self.pkhs.values_mut() {
+            if pkh_alias == alias {
+                *pkh_alias = new_alias.clone();
+            }
+        }
+        if let Some(path) = self.derivation_paths.remove(alias) {
+            self.derivation_paths.insert(new_alias, path);
+        }
+        true
+    }
+
+    /// Replace the secret key stored under the given alias with the given
+    /// keypair, encrypted with the given password if any. Returns `false` if
+    /// no secret key is stored under the alias.
+    pub fn reencrypt_secret_key(
+        &mut self,
+        alias: &Alias,
+        keypair: common::SecretKey,
+        password: Option<Zeroizing<String>>,
+    ) -> bool {
+        match self.secret_keys.get_mut(alias) {
+            Some(stored_key) => {
+                *stored_key = StoredKeypair::new(keypair, password).0;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace the spending key stored under the given alias with the given
+    /// key, encrypted with the given password if any. Returns `false` if no
+    /// spending key is stored under the alias.
+    pub fn reencrypt_spending_key(
+        &mut self,
+        alias: &Alias,
+        spend_key: ExtendedSpendingKey,
+        password: Option<Zeroizing<String>>,
+    ) -> bool {
+        match self.spend_keys.get_mut(alias) {
+            Some(stored_key) => {
+                *stored_key = StoredKeypair::new(spend_key, password).0;
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Insert spending keys similarly to how it's done for keypairs
     pub fn insert_spending_key<U: WalletIo>(
         &mut self,
@@ -438,6 +521,20 @@ impl Store {
         Some(alias)
     }
 
+    /// Set the birthday of the shielded key with the given alias. Returns
+    /// `false` if no shielded key is known under this alias.
+    pub fn insert_birthday(
+        &mut self,
+        alias: Alias,
+        birthday: BlockHeight,
+    ) -> bool {
+        if !self.view_keys.contains_key(&alias) {
+            return false;
+        }
+        self.birthdays.insert(alias, birthday);
+        true
+    }
+
     /// Insert public keys
     pub fn insert_public_key<U: WalletIo>(
         &mut self,
@@ -588,6 +685,7 @@ impl Store {
         self.pkhs.retain(|_key, val| val != alias);
         self.public_keys.remove(alias);
         self.derivation_paths.remove(alias);
+        self.birthdays.remove(alias);
     }
 
     /// Extend this store from another store (typically pre-genesis).
@@ -604,6 +702,7 @@ impl Store {
             pkhs,
             validator_data: _,
             address_vp_types,
+            birthdays,
         } = self;
         view_keys.extend(store.view_keys);
         spend_keys.extend(store.spend_keys);
@@ -614,6 +713,121 @@ impl Store {
         addresses.extend(store.addresses);
         pkhs.extend(store.pkhs);
         address_vp_types.extend(store.address_vp_types);
+        birthdays.extend(store.birthdays);
+    }
+
+    /// Export the content of this store to the interchange format. Secret and
+    /// spending keys are only included when `include_secrets` is set.
+    pub fn export(&self, include_secrets: bool) -> WalletExport {
+        let mut export = WalletExport {
+            addresses: self
+                .addresses
+                .iter()
+                .map(|(alias, address)| (alias.clone(), address.clone()))
+                .collect(),
+            public_keys: self.public_keys.clone(),
+            derivation_paths: self.derivation_paths.clone(),
+            view_keys: self.view_keys.clone(),
+            payment_addrs: self
+                .payment_addrs
+                .iter()
+                .map(|(alias, addr)| (alias.clone(), *addr))
+                .collect(),
+            birthdays: self.birthdays.clone(),
+            ..Default::default()
+        };
+        if include_secrets {
+            export.secret_keys = self.secret_keys.clone();
+            export.spend_keys = self.spend_keys.clone();
+        }
+        export
+    }
+
+    /// Import the content of an export in the interchange format. All the
+    /// entries of an alias that is already used in this store are handled
+    /// together, according to the given policy.
+    pub fn import(
+        &mut self,
+        mut export: WalletExport,
+        policy: ImportConflictPolicy,
+    ) -> Result<ImportSummary, WalletExportError> {
+        let aliases: Vec<Alias> = export.aliases().cloned().collect();
+        // Check all the aliases first, to not leave a partial import behind
+        for alias in &aliases {
+            if Alias::is_reserved(alias).is_some() {
+                return Err(WalletExportError::ReservedAlias(alias.clone()));
+            }
+            if policy == ImportConflictPolicy::Abort
+                && self.contains_alias(alias)
+            {
+                return Err(WalletExportError::AliasConflict(alias.clone()));
+            }
+        }
+
+        let mut summary = ImportSummary::default();
+        for alias in aliases {
+            let target = if !self.contains_alias(&alias) {
+                summary.imported.push(alias.clone());
+                alias.clone()
+            } else {
+                match policy {
+                    ImportConflictPolicy::Skip => {
+                        summary.skipped.push(alias);
+                        continue;
+                    }
+                    ImportConflictPolicy::Rename => {
+                        let new_alias = self.unused_alias(&alias);
+                        summary
+                            .renamed
+                            .push((alias.clone(), new_alias.clone()));
+                        new_alias
+                    }
+                    ImportConflictPolicy::Overwrite
+                    | ImportConflictPolicy::Abort => {
+                        self.remove_alias(&alias);
+                        summary.overwritten.push(alias.clone());
+                        alias.clone()
+                    }
+                }
+            };
+
+            if let Some(address) = export.addresses.remove(&alias) {
+                self.addresses.insert(target.clone(), address);
+            }
+            if let Some(pubkey) = export.public_keys.remove(&alias) {
+                self.pkhs
+                    .insert(PublicKeyHash::from(&pubkey), target.clone());
+                self.public_keys.insert(target.clone(), pubkey);
+            }
+            if let Some(keypair) = export.secret_keys.remove(&alias) {
+                self.secret_keys.insert(target.clone(), keypair);
+            }
+            if let Some(path) = export.derivation_paths.remove(&alias) {
+                self.derivation_paths.insert(target.clone(), path);
+            }
+            if let Some(spendkey) = export.spend_keys.remove(&alias) {
+                self.spend_keys.insert(target.clone(), spendkey);
+            }
+            if let Some(viewkey) = export.view_keys.remove(&alias) {
+                self.view_keys.insert(target.clone(), viewkey);
+            }
+            if let Some(payment_addr) = export.payment_addrs.remove(&alias) {
+                self.payment_addrs.insert(target.clone(), payment_addr);
+            }
+            if let Some(birthday) = export.birthdays.remove(&alias) {
+                self.birthdays.insert(target, birthday);
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Find an alias not used in this store by appending a numeric suffix to
+    /// the given one
+    fn unused_alias(&self, alias: &Alias) -> Alias {
+        (1..)
+            .map(|suffix| Alias::from(format!("{alias}-{suffix}")))
+            .find(|alias| !self.contains_alias(alias))
+            .expect("There must be an unused alias")
     }
 
     /// Extend this store from pre-genesis validator wallet.
@@ -879,6 +1093,132 @@ mod test_wallet {
         assert_eq!(&sk.to_string(), &sk_hard.to_string());
     }
 
+    #[test]
+    fn shielded_key_birthdays() {
+        let alias = Alias::from("key");
+        let xsk = zip32::ExtendedSpendingKey::master(&[0; 32]);
+        let viewkey = zip32::ExtendedFullViewingKey::from(&xsk).into();
+
+        let mut store = Store::default();
+        // birthdays can only be set for known shielded keys
+        assert!(!store.insert_birthday(alias.clone(), BlockHeight(10)));
+        store.view_keys.insert(alias.clone(), viewkey);
+        assert!(store.insert_birthday(alias.clone(), BlockHeight(10)));
+        assert_eq!(store.find_birthday("key"), Some(BlockHeight(10)));
+
+        // removing the key also removes its birthday
+        store.remove_alias(&alias);
+        assert_eq!(store.find_birthday("key"), None);
+    }
+
+    #[test]
+    fn wallet_export_import() {
+        let sk = namada_core::key::testing::common_sk_from_simple_seed(0);
+        let pkh = PublicKeyHash::from(&sk.ref_to());
+        let address = Address::Implicit(ImplicitAddress(pkh.clone()));
+        let mut source = Store::default();
+        source
+            .secret_keys
+            .insert(Alias::from("key"), StoredKeypair::new(sk.clone(), None).0);
+        source.public_keys.insert(Alias::from("key"), sk.ref_to());
+        source.pkhs.insert(pkh.clone(), Alias::from("key"));
+        source.addresses.insert(Alias::from("key"), address.clone());
+
+        // secret keys are only exported on request
+        assert!(source.export(false).secret_keys.is_empty());
+        let export = source.export(true);
+        let json = export.to_json().unwrap();
+        let toml = export.to_toml().unwrap();
+        assert_eq!(
+            WalletExport::from_toml(&toml).unwrap().to_json().unwrap(),
+            json
+        );
+
+        // import into a wallet using the same alias for another address
+        let other = namada_core::address::testing::established_address_1();
+        let mut target = Store::default();
+        target.addresses.insert(Alias::from("key"), other.clone());
+
+        let mut skipped = Store {
+            addresses: target.addresses.clone(),
+            ..Default::default()
+        };
+        let summary = skipped
+            .import(
+                WalletExport::from_json(&json).unwrap(),
+                ImportConflictPolicy::Skip,
+            )
+            .unwrap();
+        assert_eq!(summary.skipped, vec![Alias::from("key")]);
+        assert_eq!(skipped.find_address("key"), Some(&other));
+
+        assert!(matches!(
+            target.import(
+                WalletExport::from_json(&json).unwrap(),
+                ImportConflictPolicy::Abort,
+            ),
+            Err(WalletExportError::AliasConflict(_))
+        ));
+
+        let summary = target
+            .import(
+                WalletExport::from_json(&json).unwrap(),
+                ImportConflictPolicy::Rename,
+            )
+            .unwrap();
+        assert_eq!(
+            summary.renamed,
+            vec![(Alias::from("key"), Alias::from("key-1"))]
+        );
+        assert_eq!(target.find_address("key"), Some(&other));
+        assert_eq!(target.find_address("key-1"), Some(&address));
+        assert!(target.find_secret_key("key-1").is_some());
+        assert_eq!(target.find_public_key_by_pkh(&pkh), Some(&sk.ref_to()));
+
+        // unsupported versions are rejected
+        let mut export = WalletExport::from_json(&json).unwrap();
+        export.version += 1;
+        assert!(matches!(
+            WalletExport::from_json(&export.to_json().unwrap()),
+            Err(WalletExportError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn reencrypt_secret_key() {
+        let alias = Alias::from("key");
+        let sk = namada_core::key::testing::common_sk_from_simple_seed(0);
+        let old_password = Zeroizing::new("old".to_string());
+        let new_password = Zeroizing::new("new".to_string());
+
+        let mut store = Store::default();
+        store.secret_keys.insert(
+            alias.clone(),
+            StoredKeypair::new(sk.clone(), Some(old_password.clone())).0,
+        );
+        assert!(store.reencrypt_secret_key(
+            &alias,
+            sk.clone(),
+            Some(new_password.clone())
+        ));
+        assert!(!store.reencrypt_secret_key(
+            &Alias::from("other"),
+            sk.clone(),
+            None
+        ));
+
+        let StoredKeypair::Encrypted(encrypted) =
+            store.secret_keys.get(&alias).unwrap()
+        else {
+            panic!("The re-encrypted key should be encrypted");
+        };
+        assert!(encrypted.decrypt(old_password).is_err());
+        assert_eq!(
+            encrypted.decrypt(new_password).unwrap().to_string(),
+            sk.to_string()
+        );
+    }
+
     fn do_test_gen_sk_from_seed_and_derivation_path(
         scheme: SchemeType,
         seed: &str,
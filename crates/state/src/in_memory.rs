@@ -1,3 +1,6 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
 use namada_core::address::{Address, EstablishedAddressGen, InternalAddress};
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::chain::{ChainId, CHAIN_ID_LENGTH};
@@ -6,7 +9,7 @@ use namada_core::time::DateTimeUtc;
 use namada_core::{encode, ethereum_structs};
 use namada_gas::MEMORY_ACCESS_GAS_PER_BYTE;
 use namada_macros::BorshDeserializer;
-use namada_merkle_tree::{MerkleRoot, MerkleTree};
+use namada_merkle_tree::{MerkleRoot, MerkleTree, StoreType};
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use namada_parameters::{EpochDuration, Parameters};
@@ -73,6 +76,82 @@ where
     pub storage_read_past_height_limit: Option<u64>,
     /// Data that needs to be committed to the merkle tree
     pub commit_only_data: CommitOnlyData,
+    /// Merkle trees rebuilt at past heights to serve proofs
+    pub merkle_tree_cache: MerkleTreeCache<H>,
+}
+
+/// A bounded cache of the Merkle subtrees rebuilt from the DB at past
+/// heights. The trees of committed blocks never change, so they can serve
+/// any number of proofs at the same height without being rebuilt.
+#[derive(Debug)]
+pub struct MerkleTreeCache<H: StorageHasher> {
+    /// The maximum number of cached trees. Nothing is cached when zero.
+    capacity: usize,
+    /// The cached trees, from the least to the most recently used
+    trees: Mutex<VecDeque<((BlockHeight, StoreType), MerkleTree<H>)>>,
+}
+
+impl<H: StorageHasher> Default for MerkleTreeCache<H> {
+    fn default() -> Self {
+        Self {
+            capacity: 0,
+            trees: Mutex::new(VecDeque::new()),
+        }
+    }
+}
+
+impl<H: StorageHasher> MerkleTreeCache<H> {
+    /// Set the maximum number of cached trees, evicting the least recently
+    /// used ones above it
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        let trees = self.trees.get_mut().expect("Lock shouldn't be poisoned");
+        while trees.len() > capacity {
+            trees.pop_front();
+        }
+    }
+
+    /// Remove all the cached trees
+    pub fn clear(&mut self) {
+        self.trees
+            .get_mut()
+            .expect("Lock shouldn't be poisoned")
+            .clear();
+    }
+
+    /// Apply `f` to the cached `store_type` subtree at the given height, or
+    /// to the one built with `build` if it's not cached yet. The built tree
+    /// is cached in place of the least recently used one, if full.
+    pub fn with_tree<T>(
+        &self,
+        height: BlockHeight,
+        store_type: StoreType,
+        build: impl FnOnce() -> Result<MerkleTree<H>>,
+        f: impl FnOnce(&MerkleTree<H>) -> T,
+    ) -> Result<T> {
+        if self.capacity == 0 {
+            return Ok(f(&build()?));
+        }
+        let mut trees = self.trees.lock().expect("Lock shouldn't be poisoned");
+        let cached = trees
+            .iter()
+            .position(|(key, _)| *key == (height, store_type));
+        let entry = match cached {
+            Some(position) => {
+                trees.remove(position).expect("The position must be valid")
+            }
+            None => {
+                let tree = build()?;
+                if trees.len() >= self.capacity {
+                    trees.pop_front();
+                }
+                ((height, store_type), tree)
+            }
+        };
+        let result = f(&entry.1);
+        trees.push_back(entry);
+        Ok(result)
+    }
 }
 
 /// Last committed block
@@ -143,6 +222,7 @@ where
             eth_events_queue: EthEventsQueue::default(),
             storage_read_past_height_limit,
             commit_only_data: CommitOnlyData::default(),
+            merkle_tree_cache: MerkleTreeCache::default(),
         }
     }
 
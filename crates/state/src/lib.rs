@@ -26,7 +26,7 @@ use std::fmt::Debug;
 use std::iter::Peekable;
 
 pub use host_env::{TxHostEnvState, VpHostEnvState};
-pub use in_memory::{BlockStorage, InMemory, LastBlock};
+pub use in_memory::{BlockStorage, InMemory, LastBlock, MerkleTreeCache};
 use namada_core::address::Address;
 use namada_core::arith::{self, checked};
 use namada_core::eth_bridge_pool::is_pending_transfer_key;
@@ -135,6 +135,15 @@ pub trait StateRead: StorageRead + Debug {
         (self.db().iter_results(), 0)
     }
 
+    /// Returns an iterator over the transaction index entries of the given
+    /// address
+    fn db_iter_tx_index(
+        &self,
+        address: &Address,
+    ) -> (<Self::D as DBIter<'_>>::PrefixIter, u64) {
+        (self.db().iter_tx_index(address), 0)
+    }
+
     /// Get the hash of a validity predicate for the given account address and
     /// the gas cost for reading it.
     fn validity_predicate(
@@ -656,6 +665,7 @@ pub mod testing {
                 eth_events_queue: EthEventsQueue::default(),
                 storage_read_past_height_limit: Some(1000),
                 commit_only_data: CommitOnlyData::default(),
+                merkle_tree_cache: MerkleTreeCache::default(),
             }
         }
     }
@@ -674,6 +684,7 @@ mod tests {
     use merkle_tree::NO_DIFF_KEY_PREFIX;
     use namada_core::address::InternalAddress;
     use namada_core::borsh::{BorshDeserialize, BorshSerializeExt};
+    use namada_core::parameters::EpochSwitchPolicy;
     use namada_core::storage::DbKeySeg;
     use namada_core::time::{self, DateTimeUtc, Duration};
     use namada_parameters::{EpochDuration, Parameters};
@@ -762,6 +773,8 @@ mod tests {
                 fee_unshielding_gas_limit: 20_000,
                 minimum_gas_price: BTreeMap::default(),
                 is_native_token_transferable: true,
+                epoch_switch_policy: Default::default(),
+                max_code_bytes: 1024 * 1024,
             };
             namada_parameters::init_storage(&parameters, &mut state).unwrap();
             // Initialize pred_epochs to the current height
@@ -888,6 +901,57 @@ mod tests {
         }
     }
 
+    /// Test that with the `Blocks` epoch switch policy, a new epoch begins
+    /// once the minimum number of blocks have passed, even if the minimum
+    /// duration of the epoch hasn't.
+    #[test]
+    fn update_epoch_with_blocks_policy() {
+        let mut state = TestState::default();
+        let epoch_duration = EpochDuration {
+            min_num_of_blocks: 2,
+            min_duration: Duration::seconds(3600).into(),
+        };
+        let parameters = Parameters {
+            max_tx_bytes: 1024 * 1024,
+            max_proposal_bytes: Default::default(),
+            max_block_gas: 20_000_000,
+            epoch_duration: epoch_duration.clone(),
+            max_expected_time_per_block: Duration::seconds(10).into(),
+            vp_allowlist: vec![],
+            tx_allowlist: vec![],
+            implicit_vp_code_hash: Some(Hash::zero()),
+            epochs_per_year: 100,
+            max_signatures_per_transaction: 15,
+            fee_unshielding_gas_limit: 20_000,
+            minimum_gas_price: BTreeMap::default(),
+            is_native_token_transferable: true,
+            epoch_switch_policy: EpochSwitchPolicy::Blocks,
+            max_code_bytes: 1024 * 1024,
+        };
+        namada_parameters::init_storage(&parameters, &mut state).unwrap();
+        let start_time: DateTimeUtc =
+            Utc.timestamp_opt(0, 0).single().unwrap().into();
+        state.in_mem_mut().next_epoch_min_start_height =
+            BlockHeight(epoch_duration.min_num_of_blocks);
+        state.in_mem_mut().next_epoch_min_start_time =
+            start_time + epoch_duration.min_duration;
+        let epoch_before = state.in_mem().block.epoch;
+
+        // The blocks come much faster than the minimum duration of an epoch
+        let time_at = |secs: i64| start_time + Duration::seconds(secs);
+        state.update_epoch(BlockHeight(1), time_at(1)).unwrap();
+        assert!(state.in_mem().update_epoch_blocks_delay.is_none());
+        state.update_epoch(BlockHeight(2), time_at(2)).unwrap();
+        assert_eq!(state.in_mem().update_epoch_blocks_delay, Some(2));
+        state.update_epoch(BlockHeight(3), time_at(3)).unwrap();
+        assert!(state.update_epoch(BlockHeight(4), time_at(4)).unwrap());
+        assert_eq!(state.in_mem().block.epoch, epoch_before.next());
+        assert_eq!(
+            state.in_mem().next_epoch_min_start_height,
+            BlockHeight(4 + epoch_duration.min_num_of_blocks)
+        );
+    }
+
     fn test_key_1() -> Key {
         Key::parse("testing1").unwrap()
     }
@@ -900,6 +964,49 @@ mod tests {
         key == &test_key_1()
     }
 
+    /// Test that the Merkle tree cache only rebuilds the trees that are not
+    /// cached, and evicts the least recently used ones
+    #[test]
+    fn test_merkle_tree_cache() {
+        let builds = std::cell::Cell::new(0_u64);
+        let build = || {
+            builds.set(builds.get() + 1);
+            Ok(MerkleTree::<Sha256Hasher>::default())
+        };
+        let use_tree = |cache: &MerkleTreeCache<Sha256Hasher>, height| {
+            cache
+                .with_tree(BlockHeight(height), StoreType::Ibc, build, |_| ())
+                .unwrap()
+        };
+
+        // Without a capacity, the trees are rebuilt every time
+        let mut cache = MerkleTreeCache::default();
+        use_tree(&cache, 1);
+        use_tree(&cache, 1);
+        assert_eq!(builds.get(), 2);
+
+        cache.set_capacity(2);
+        builds.set(0);
+        use_tree(&cache, 1);
+        use_tree(&cache, 2);
+        use_tree(&cache, 1);
+        assert_eq!(builds.get(), 2);
+
+        // The tree at height 2 is the least recently used one
+        use_tree(&cache, 3);
+        use_tree(&cache, 1);
+        assert_eq!(builds.get(), 3);
+        use_tree(&cache, 2);
+        assert_eq!(builds.get(), 4);
+
+        // Reducing the capacity evicts the least recently used trees
+        cache.set_capacity(1);
+        use_tree(&cache, 2);
+        assert_eq!(builds.get(), 4);
+        use_tree(&cache, 1);
+        assert_eq!(builds.get(), 5);
+    }
+
     #[test]
     fn test_writing_without_diffs() {
         let mut state = TestState::default();
@@ -21,7 +21,7 @@ use crate::write_log::{StorageModification, WriteLog};
 use crate::{
     is_pending_transfer_key, DBIter, Epoch, Error, Hash, Key, KeySeg,
     LastBlock, MembershipProof, MerkleTree, MerkleTreeError, ProofOps, Result,
-    State, StateRead, StorageHasher, StorageResult, StoreType, DB,
+    State, StateRead, StorageHasher, StorageResult, StoreType, TxIndex, DB,
     EPOCH_SWITCH_BLOCKS_DELAY, STORAGE_ACCESS_GAS_PER_BYTE,
 };
 
@@ -143,14 +143,19 @@ where
         match self.in_mem.update_epoch_blocks_delay.as_mut() {
             None => {
                 // Check if the new epoch minimum start height and start time
+                // (unless epochs are only driven by the number of blocks)
                 // have been fulfilled. If so, queue the next
                 // epoch to start two blocks into the future so
                 // as to align validator set updates + etc with
                 // tendermint. This is because tendermint has a two block delay
                 // to validator changes.
-                let current_epoch_duration_satisfied = height
-                    >= self.in_mem.next_epoch_min_start_height
-                    && time >= self.in_mem.next_epoch_min_start_time;
+                let current_epoch_duration_satisfied =
+                    parameters.epoch_switch_policy.is_epoch_over(
+                        height,
+                        time,
+                        self.in_mem.next_epoch_min_start_height,
+                        self.in_mem.next_epoch_min_start_time,
+                    );
                 if current_epoch_duration_satisfied {
                     self.in_mem.update_epoch_blocks_delay =
                         Some(EPOCH_SWITCH_BLOCKS_DELAY);
@@ -385,6 +390,20 @@ where
         Ok(())
     }
 
+    /// Write the transaction index entries of the current block to the DB
+    pub fn write_tx_index_entries(
+        &mut self,
+        entries: impl IntoIterator<Item = (Address, TxIndex)>,
+    ) -> Result<()> {
+        let height = self.in_mem.block.height;
+        let mut batch = D::batch();
+        for (address, index) in entries {
+            self.db
+                .write_tx_index_entry(&mut batch, &address, height, index)?;
+        }
+        Ok(self.db.exec_batch(batch)?)
+    }
+
     /// Move the tx hashes from the current bucket to the general one
     pub fn move_current_replay_protection_entries(
         &mut self,
@@ -446,7 +465,7 @@ where
 
     /// Load the full state at the last committed height, if any. Returns the
     /// Merkle root hash and the height of the committed block.
-    fn load_last_state(&mut self) {
+    pub fn load_last_state(&mut self) {
         if let Some(BlockStateRead {
             height,
             time,
@@ -824,17 +843,28 @@ where
             }
         } else {
             let (store_type, _) = StoreType::sub_key(key)?;
-            let tree = self.get_merkle_tree(height, Some(store_type))?;
-            if let MembershipProof::ICS23(proof) = tree
-                .get_sub_tree_existence_proof(array::from_ref(key), vec![value])
-                .map_err(Error::MerkleTreeError)?
-            {
-                tree.get_sub_tree_proof(key, proof)
-                    .map(Into::into)
-                    .map_err(Error::MerkleTreeError)
-            } else {
-                Err(Error::MerkleTreeError(MerkleTreeError::TendermintProof))
-            }
+            self.in_mem.merkle_tree_cache.with_tree(
+                height,
+                store_type,
+                || self.get_merkle_tree(height, Some(store_type)),
+                |tree| {
+                    if let MembershipProof::ICS23(proof) = tree
+                        .get_sub_tree_existence_proof(
+                            array::from_ref(key),
+                            vec![value],
+                        )
+                        .map_err(Error::MerkleTreeError)?
+                    {
+                        tree.get_sub_tree_proof(key, proof)
+                            .map(Into::into)
+                            .map_err(Error::MerkleTreeError)
+                    } else {
+                        Err(Error::MerkleTreeError(
+                            MerkleTreeError::TendermintProof,
+                        ))
+                    }
+                },
+            )?
         }
     }
 
@@ -860,10 +890,16 @@ where
             })
         } else {
             let (store_type, _) = StoreType::sub_key(key)?;
-            self.get_merkle_tree(height, Some(store_type))?
-                .get_non_existence_proof(key)
-                .map(Into::into)
-                .map_err(Error::MerkleTreeError)
+            self.in_mem.merkle_tree_cache.with_tree(
+                height,
+                store_type,
+                || self.get_merkle_tree(height, Some(store_type)),
+                |tree| {
+                    tree.get_non_existence_proof(key)
+                        .map(Into::into)
+                        .map_err(Error::MerkleTreeError)
+                },
+            )?
         }
     }
 
@@ -1,11 +1,11 @@
 use std::fmt::Debug;
 use std::num::TryFromIntError;
 
-use namada_core::address::EstablishedAddressGen;
+use namada_core::address::{Address, EstablishedAddressGen};
 use namada_core::hash::{Error as HashError, Hash};
 use namada_core::storage::{
     BlockHeight, BlockResults, DbColFam, Epoch, Epochs, EthEventsQueue, Header,
-    Key,
+    Key, TxIndex,
 };
 use namada_core::time::DateTimeUtc;
 use namada_core::{arith, ethereum_events, ethereum_structs};
@@ -260,6 +260,16 @@ pub trait DB: Debug {
         batch: &mut Self::WriteBatch,
     ) -> Result<()>;
 
+    /// Write an entry of the transaction index, recording that the tx at the
+    /// given height and index within the block touched the given address
+    fn write_tx_index_entry(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        address: &Address,
+        height: BlockHeight,
+        index: TxIndex,
+    ) -> Result<()>;
+
     /// Prune non-persisted diffs that are only kept for one block for rollback
     fn prune_non_persisted_diffs(
         &mut self,
@@ -325,6 +335,10 @@ pub trait DBIter<'iter> {
 
     /// Read replay protection storage from the current bucket
     fn iter_current_replay_protection(&'iter self) -> Self::PrefixIter;
+
+    /// Read the transaction index entries of the given address, sorted by
+    /// block height and tx index. The keys are stripped of the address.
+    fn iter_tx_index(&'iter self, address: &Address) -> Self::PrefixIter;
 }
 
 /// Atomic batch write.
@@ -23,6 +23,7 @@ pub mod conversion_state;
 mod db;
 mod error;
 pub mod mockdb;
+pub mod tx_index;
 pub mod tx_queue;
 pub mod types;
 
@@ -7,10 +7,12 @@ use std::collections::{btree_map, BTreeMap};
 use std::path::Path;
 
 use itertools::Either;
+use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::hash::Hash;
 use namada_core::storage::{
-    BlockHeight, DbColFam, Epoch, Header, Key, KeySeg, KEY_SEGMENT_SEPARATOR,
+    BlockHeight, DbColFam, Epoch, Header, Key, KeySeg, TxIndex,
+    KEY_SEGMENT_SEPARATOR,
 };
 use namada_core::{decode, encode, ethereum_events};
 use namada_merkle_tree::{
@@ -23,6 +25,7 @@ use regex::Regex;
 use crate::db::{
     BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch, Error, Result, DB,
 };
+use crate::tx_index;
 use crate::types::{KVBytes, PatternIterator, PrefixIterator};
 
 const SUBSPACE_CF: &str = "subspace";
@@ -603,6 +606,20 @@ impl DB for MockDB {
         Ok(())
     }
 
+    fn write_tx_index_entry(
+        &mut self,
+        _batch: &mut Self::WriteBatch,
+        address: &Address,
+        height: BlockHeight,
+        index: TxIndex,
+    ) -> Result<()> {
+        let key = Key::parse("tx_index")
+            .map_err(Error::KeyError)?
+            .join(&tx_index::key(address, height, index));
+        self.0.borrow_mut().insert(key.to_string(), vec![]);
+        Ok(())
+    }
+
     fn prune_non_persisted_diffs(
         &mut self,
         _batch: &mut Self::WriteBatch,
@@ -721,6 +738,14 @@ impl<'iter> DBIter<'iter> for MockDB {
         let iter = self.0.borrow().clone().into_iter();
         MockPrefixIterator::new(MockIterator { prefix, iter }, stripped_prefix)
     }
+
+    fn iter_tx_index(&'iter self, address: &Address) -> Self::PrefixIter {
+        let stripped_prefix =
+            format!("tx_index/{}/", tx_index::prefix(address));
+        let prefix = stripped_prefix.clone();
+        let iter = self.0.borrow().clone().into_iter();
+        MockPrefixIterator::new(MockIterator { prefix, iter }, stripped_prefix)
+    }
 }
 
 /// A prefix iterator base for the [`MockPrefixIterator`].
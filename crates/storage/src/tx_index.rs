@@ -0,0 +1,73 @@
+//! Node-local index of the transactions that touched a given address
+
+use namada_core::address::Address;
+use namada_core::storage::{
+    BlockHeight, DbKeySeg, Key, KeySeg, TxIndex, KEY_SEGMENT_SEPARATOR,
+};
+
+const ERROR_MSG: &str = "Cannot obtain a valid db key";
+
+/// Maximum number of entries returned in a single page of the index
+pub const PAGE_SIZE: usize = 100;
+
+/// Get the prefix under which all the index entries of an address are stored
+pub fn prefix(address: &Address) -> Key {
+    Key::from(DbKeySeg::AddressSeg(address.clone()))
+}
+
+/// Get the key of the index entry of the transaction at the given height and
+/// index within the block. Both the height and the index are encoded in
+/// big-endian to keep the entries of an address sorted by their position in
+/// the chain.
+pub fn key(address: &Address, height: BlockHeight, index: TxIndex) -> Key {
+    prefix(address)
+        .push(&height.0)
+        .and_then(|key| key.push(&index.0))
+        .expect(ERROR_MSG)
+}
+
+/// Parse the height and the tx index out of an index key stripped of its
+/// address prefix
+pub fn parse_stripped_key(key: &str) -> Option<(BlockHeight, TxIndex)> {
+    let (height, index) = key.split_once(KEY_SEGMENT_SEPARATOR)?;
+    let height = u64::parse(height.to_owned()).ok()?;
+    let index = u32::parse(index.to_owned()).ok()?;
+    Some((BlockHeight(height), TxIndex(index)))
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::established_address_1;
+
+    use super::*;
+
+    #[test]
+    fn test_tx_index_key_roundtrip() {
+        let address = established_address_1();
+        let key = key(&address, BlockHeight(42), TxIndex(7));
+        let stripped = key
+            .to_string()
+            .strip_prefix(&format!("{}/", prefix(&address)))
+            .unwrap()
+            .to_owned();
+        assert_eq!(
+            parse_stripped_key(&stripped),
+            Some((BlockHeight(42), TxIndex(7)))
+        );
+    }
+
+    #[test]
+    fn test_tx_index_keys_ordering() {
+        let address = established_address_1();
+        let keys = [
+            key(&address, BlockHeight(2), TxIndex(300)),
+            key(&address, BlockHeight(10), TxIndex(1)),
+            key(&address, BlockHeight(10), TxIndex(2)),
+            key(&address, BlockHeight(256), TxIndex(0)),
+        ]
+        .map(|key| key.to_string());
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+}
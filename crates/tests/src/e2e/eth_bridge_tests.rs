@@ -25,6 +25,7 @@ use namada_core::ethereum_events::{
     EthereumEvent, TransferToEthereum, TransferToNamada,
 };
 use namada_core::token::Amount;
+use namada_node::ethereum_oracle::test_tools::fake_eth_node::FakeEthNode;
 use namada_test_utils::tx_data::TxWriteData;
 use namada_test_utils::TestWasms;
 use tokio::time::{Duration, Instant};
@@ -295,6 +296,8 @@ async fn test_bridge_pool_e2e() {
             genesis.ethereum_bridge_params = Some(EthereumBridgeParams {
                 eth_start_height: Default::default(),
                 min_confirmations: Default::default(),
+                event_confirmations: Default::default(),
+                bridge_pool_cancel_delay: Default::default(),
                 contracts: Contracts {
                     native_erc20: wnam(),
                     bridge: UpgradeableContract {
@@ -490,6 +493,8 @@ async fn test_wnam_transfer() -> Result<()> {
             // of this type using 0 as argument.
             NonZeroU64::new_unchecked(10)
         }),
+        event_confirmations: Default::default(),
+        bridge_pool_cancel_delay: Default::default(),
         contracts: Contracts {
             native_erc20: wnam(),
             bridge: UpgradeableContract {
@@ -578,7 +583,7 @@ async fn test_wnam_transfer() -> Result<()> {
     )?;
     assert_eq!(
         bridge_balance,
-        token::Amount::from(BRIDGE_INITIAL_NAM_BALANCE * 1_000_000)
+        token::Amount::native_whole(BRIDGE_INITIAL_NAM_BALANCE)
             - wnam_transfer.amount
     );
 
@@ -587,6 +592,7 @@ async fn test_wnam_transfer() -> Result<()> {
 
 /// Tests that the ledger configures its Ethereum oracle with values from
 /// storage, if the Ethereum bridge has been bootstrapped for the Namada chain.
+/// The real oracle is run against an embedded fake Ethereum node.
 #[test]
 fn test_configure_oracle_from_storage() -> Result<()> {
     let ethereum_bridge_params = EthereumBridgeParams {
@@ -597,6 +603,8 @@ fn test_configure_oracle_from_storage() -> Result<()> {
             // of this type using 0 as argument.
             NonZeroU64::new_unchecked(10)
         }),
+        event_confirmations: Default::default(),
+        bridge_pool_cancel_delay: Default::default(),
         contracts: Contracts {
             native_erc20: EthAddress([1; 20]),
             bridge: UpgradeableContract {
@@ -620,6 +628,13 @@ fn test_configure_oracle_from_storage() -> Result<()> {
         None,
     )?;
 
+    // serve the oracle's queries from a fake Ethereum node
+    let rt = tokio::runtime::Runtime::new()?;
+    let _rt_guard = rt.enter();
+    let eth_node = FakeEthNode::new(100);
+    let (_abort_eth_node, abort_recv) = tokio::sync::oneshot::channel();
+    let eth_node_addr = eth_node.spawn(([127, 0, 0, 1], 0).into(), abort_recv);
+
     // start the ledger with the real oracle and wait for a block to be
     // committed
     set_ethereum_bridge_mode(
@@ -627,7 +642,7 @@ fn test_configure_oracle_from_storage() -> Result<()> {
         &test.net.chain_id,
         Who::Validator(0),
         ethereum_bridge::ledger::Mode::RemoteEndpoint,
-        None,
+        Some(&format!("http://{eth_node_addr}")),
     );
     let mut ledger =
         run_as!(test, Who::Validator(0), Bin::Node, vec!["ledger"], Some(40))?;
@@ -638,6 +653,7 @@ fn test_configure_oracle_from_storage() -> Result<()> {
     // check that the oracle has been configured with the values from storage
     let initial_config = oracle::config::Config {
         min_confirmations: ethereum_bridge_params.min_confirmations.into(),
+        event_confirmations: ethereum_bridge_params.event_confirmations,
         bridge_contract: ethereum_bridge_params.contracts.bridge.address,
         governance_contract: ethereum_bridge_params
             .contracts
@@ -649,6 +665,15 @@ fn test_configure_oracle_from_storage() -> Result<()> {
         "Oracle received initial configuration - {:?}",
         &initial_config
     ))?;
+
+    // check that the oracle scans the confirmed blocks of the fake node
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while eth_node.get_logs_calls() == 0 {
+        if Instant::now() >= deadline {
+            return Err(eyre!("the oracle never queried the Ethereum node"));
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
     Ok(())
 }
 
@@ -17,6 +17,10 @@ use namada::ledger::eth_bridge::{
 };
 use namada::token;
 use namada_apps_lib::config::ethereum_bridge;
+pub use namada_node::ethereum_oracle::test_tools::events_endpoint::BatchedEvent;
+use namada_node::ethereum_oracle::test_tools::events_endpoint::{
+    sign_request, NONCE_HEADER, SIGNATURE_HEADER,
+};
 
 use crate::e2e::helpers::{
     get_actor_rpc, rpc_client_do, strip_trailing_newline,
@@ -34,7 +38,11 @@ impl Default for EventsEndpointClient {
     fn default() -> Self {
         let ethereum_events_endpoint =
             format!("http://{DEFAULT_ETHEREUM_EVENTS_LISTEN_ADDR}/eth_events");
+        let ethereum_events_batch_endpoint = format!(
+            "http://{DEFAULT_ETHEREUM_EVENTS_LISTEN_ADDR}/eth_events_batch"
+        );
         Self::new(ethereum_events_endpoint)
+            .with_batch_endpoint(ethereum_events_batch_endpoint)
     }
 }
 
@@ -44,6 +52,12 @@ pub struct EventsEndpointClient {
     http: Client<HttpConnector, Body>,
     // The URL to which Borsh-serialized Ethereum events should be HTTP POSTed. e.g. "http://0.0.0.0:3030/eth_events"
     events_endpoint: String,
+    // The URL to which JSON batches of Ethereum events should be HTTP POSTed. e.g. "http://0.0.0.0:3030/eth_events_batch"
+    events_batch_endpoint: Option<String>,
+    // The secret shared with the Namada node, used to sign the requests.
+    secret: Option<Vec<u8>>,
+    // The nonce of the last signed request.
+    last_nonce: u64,
 }
 
 impl EventsEndpointClient {
@@ -51,7 +65,81 @@ impl EventsEndpointClient {
         Self {
             http: Client::new(),
             events_endpoint,
+            events_batch_endpoint: None,
+            secret: None,
+            last_nonce: 0,
+        }
+    }
+
+    /// Sign the requests with the given secret, which must match the
+    /// `events_endpoint_secret` configured on the Namada node.
+    pub fn with_secret(mut self, secret: Vec<u8>) -> Self {
+        self.secret = Some(secret);
+        self
+    }
+
+    /// Build a POST request to `uri`, signed if a secret was configured.
+    fn post_request(
+        &mut self,
+        uri: &str,
+        content_type: &str,
+        body: Vec<u8>,
+    ) -> Result<Request<Body>> {
+        let mut req = Request::builder()
+            .method(Method::POST)
+            .uri(uri)
+            .header("content-type", content_type);
+        if let Some(secret) = &self.secret {
+            // NB: nonces are UNIX timestamps in nanoseconds, so that they
+            // keep increasing across clients and restarts of the node
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)?
+                .as_nanos() as u64;
+            self.last_nonce = now.max(self.last_nonce + 1);
+            let path = uri.rsplit('/').next().unwrap_or_default();
+            req = req.header(NONCE_HEADER, self.last_nonce).header(
+                SIGNATURE_HEADER,
+                sign_request(secret, self.last_nonce, path, &body),
+            );
         }
+        Ok(req.body(Body::from(body))?)
+    }
+
+    /// Set the URL to which batches of Ethereum events should be sent.
+    pub fn with_batch_endpoint(
+        mut self,
+        events_batch_endpoint: String,
+    ) -> Self {
+        self.events_batch_endpoint = Some(events_batch_endpoint);
+        self
+    }
+
+    /// Sends a batch of Ethereum events to the Namada node, which will
+    /// forward them to the ledger according to their delays. Returns `Ok` iff
+    /// the whole batch was accepted.
+    pub async fn send_batch(&mut self, batch: &[BatchedEvent]) -> Result<()> {
+        let events_batch_endpoint = self
+            .events_batch_endpoint
+            .clone()
+            .ok_or_else(|| eyre!("no batch endpoint was configured"))?;
+        let batch = serde_json::to_vec(batch)?;
+
+        let req = self.post_request(
+            &events_batch_endpoint,
+            "application/json",
+            batch,
+        )?;
+
+        let resp = self
+            .http
+            .request(req)
+            .await
+            .wrap_err_with(|| "sending events batch")?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!("unexpected response status: {}", resp.status()));
+        }
+        Ok(())
     }
 
     /// Sends an Ethereum event to the Namada node. Returns `Ok` iff the event
@@ -59,11 +147,12 @@ impl EventsEndpointClient {
     pub async fn send(&mut self, event: &EthereumEvent) -> Result<()> {
         let event = event.serialize_to_vec()?;
 
-        let req = Request::builder()
-            .method(Method::POST)
-            .uri(&self.events_endpoint)
-            .header("content-type", "application/octet-stream")
-            .body(Body::from(event))?;
+        let events_endpoint = self.events_endpoint.clone();
+        let req = self.post_request(
+            &events_endpoint,
+            "application/octet-stream",
+            event,
+        )?;
 
         let resp = self
             .http
@@ -90,6 +179,8 @@ pub fn setup_single_validator_test() -> Result<(Test, NamadaBgCmd)> {
             // of this type using 0 as argument.
             NonZeroU64::new_unchecked(10)
         }),
+        event_confirmations: Default::default(),
+        bridge_pool_cancel_delay: Default::default(),
         contracts: Contracts {
             native_erc20: wnam(),
             bridge: UpgradeableContract {
@@ -6,7 +6,7 @@ use std::io::Write;
 use std::path::Path;
 use std::process::Command;
 use std::str::FromStr;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use std::{env, time};
 
 use borsh::BorshDeserialize;
@@ -17,9 +17,11 @@ use escargot::CargoBuild;
 use eyre::eyre;
 use namada::core::address::Address;
 use namada::core::key::*;
-use namada::core::storage::Epoch;
+use namada::core::storage::{BlockHeight, Epoch};
 use namada::ledger::queries::{Rpc, RPC};
-use namada::tendermint_rpc::HttpClient;
+use namada::tendermint_rpc::{
+    HttpClient, SubscriptionClient, WebSocketClient, WebSocketClientUrl,
+};
 use namada::token;
 use namada_apps_lib::cli::context::ENV_VAR_CHAIN_ID;
 use namada_apps_lib::config::genesis::chain::DeriveEstablishedAddress;
@@ -27,12 +29,14 @@ use namada_apps_lib::config::genesis::templates;
 use namada_apps_lib::config::utils::convert_tm_addr_to_socket_addr;
 use namada_apps_lib::config::{Config, TendermintMode};
 use namada_core::token::NATIVE_MAX_DECIMAL_PLACES;
+use namada_sdk::control_flow::time::Instant;
+use namada_sdk::rpc;
 use namada_sdk::wallet::fs::FsWalletUtils;
 use namada_sdk::wallet::Wallet;
 use toml::Value;
 
 use super::setup::{
-    self, run_gaia_cmd, sleep, NamadaBgCmd, NamadaCmd, Test, ENV_VAR_DEBUG,
+    self, run_gaia_cmd, NamadaBgCmd, NamadaCmd, Test, ENV_VAR_DEBUG,
     ENV_VAR_USE_PREBUILT_BINARIES,
 };
 use crate::e2e::setup::{constants, Bin, Who, APPS_PACKAGE};
@@ -57,6 +61,20 @@ where
     action(RPC, client, user_data).await
 }
 
+/// Instantiate a new [`WebSocketClient`], which can subscribe to the events
+/// of the node at the given RPC address. Its driver is spawned onto the
+/// current async runtime.
+pub async fn websocket_client(ledger_address: &str) -> Result<WebSocketClient> {
+    let url: WebSocketClientUrl = format!(
+        "{}/websocket",
+        ledger_address.replacen("http://", "ws://", 1)
+    )
+    .parse()?;
+    let (client, driver) = WebSocketClient::new(url).await?;
+    tokio::spawn(driver.run());
+    Ok(client)
+}
+
 /// Sets up a test chain with a single validator node running in the background,
 /// and returns the [`Test`] handle and [`NamadaBgCmd`] for the validator node.
 /// It blocks until the node is ready to receive RPC requests from
@@ -371,8 +389,8 @@ pub fn get_height(test: &Test, ledger_address: &str) -> Result<u64> {
     })
 }
 
-/// Sleep until the given height is reached or panic when time out is reached
-/// before the height
+/// Wait until the given height is reached or return an error when time out is
+/// reached before the height
 pub fn wait_for_block_height(
     test: &Test,
     ledger_address: &str,
@@ -380,21 +398,15 @@ pub fn wait_for_block_height(
     timeout_secs: u64,
 ) -> Result<()> {
     #[allow(clippy::disallowed_methods)]
-    let start = Instant::now();
-    let loop_timeout = Duration::new(timeout_secs, 0);
-    loop {
-        let current = get_height(test, ledger_address)?;
-        if current >= height {
-            break Ok(());
-        }
-        #[allow(clippy::disallowed_methods)]
-        if Instant::now().duration_since(start) > loop_timeout {
-            return Err(eyre!(
-                "Timed out waiting for height {height}, current {current}"
-            ));
-        }
-        sleep(1);
-    }
+    let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+    test.async_runtime().block_on(async {
+        let client = websocket_client(ledger_address).await?;
+        let result =
+            rpc::wait_for_height(&client, BlockHeight(height), deadline).await;
+        client.close()?;
+        result?;
+        Ok(())
+    })
 }
 
 /// Are the E2E tests be running in debug mode?
@@ -467,19 +479,20 @@ pub(crate) fn strip_trailing_newline(input: &str) -> &str {
         .unwrap_or(input)
 }
 
-/// Sleep until the next epoch starts
+/// Wait until the next epoch starts, returning the new epoch
 pub fn epoch_sleep(
     test: &Test,
     ledger_address: &str,
     timeout_secs: u64,
 ) -> Result<Epoch> {
-    let mut find = run!(
-        test,
-        Bin::Client,
-        &["utils", "epoch-sleep", "--node", ledger_address],
-        Some(timeout_secs)
-    )?;
-    parse_reached_epoch(&mut find)
+    #[allow(clippy::disallowed_methods)]
+    let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+    test.async_runtime().block_on(async {
+        let client = websocket_client(ledger_address).await?;
+        let result = rpc::wait_for_next_epoch(&client, deadline).await;
+        client.close()?;
+        Ok(result?)
+    })
 }
 
 pub fn parse_reached_epoch(find: &mut NamadaCmd) -> Result<Epoch> {
@@ -729,6 +729,90 @@ fn pos_bonds() -> Result<()> {
     Ok(())
 }
 
+/// PoS test of bonding to multiple validators in one transaction. In this
+/// test we:
+///
+/// 1. Run the ledger node
+/// 2. Submit a delegation to both genesis validators in a single tx
+/// 3. Check that mismatched extra validators and amounts are rejected
+#[test]
+fn pos_bond_to_multiple_validators() -> Result<()> {
+    let test = setup::network(
+        |genesis, base_dir: &_| {
+            setup::set_validators(2, genesis, base_dir, default_port_offset)
+        },
+        None,
+    )?;
+    allow_duplicate_ips(&test, &test.net.chain_id, Who::Validator(0));
+    allow_duplicate_ips(&test, &test.net.chain_id, Who::Validator(1));
+    set_ethereum_bridge_mode(
+        &test,
+        &test.net.chain_id,
+        Who::Validator(0),
+        ethereum_bridge::ledger::Mode::Off,
+        None,
+    );
+
+    // 1. Run the ledger node
+    let _bg_validator_0 =
+        start_namada_ledger_node_wait_wasm(&test, Some(0), Some(40))?
+            .background();
+
+    let validator_0_rpc = get_actor_rpc(&test, Who::Validator(0));
+    wait_for_block_height(&test, &validator_0_rpc, 2, 30)?;
+
+    // 2. Submit a delegation to both genesis validators in a single tx
+    let tx_args = vec![
+        "bond",
+        "--validator",
+        "validator-0",
+        "--source",
+        BERTHA,
+        "--amount",
+        "1000.0",
+        "--extra-validators",
+        "validator-1",
+        "--extra-amounts",
+        "2000.0",
+        "--signing-keys",
+        BERTHA_KEY,
+        "--node",
+        &validator_0_rpc,
+    ];
+    let mut client = run!(test, Bin::Client, tx_args, Some(40))?;
+    // one inner tx per bond
+    client.exp_string(TX_APPLIED_SUCCESS)?;
+    client.exp_string(TX_APPLIED_SUCCESS)?;
+    client.assert_success();
+
+    // 3. Check that mismatched extra validators and amounts are rejected
+    let tx_args = vec![
+        "bond",
+        "--validator",
+        "validator-0",
+        "--source",
+        BERTHA,
+        "--amount",
+        "1000.0",
+        "--extra-validators",
+        "validator-1",
+        "--extra-amounts",
+        "2000.0,3000.0",
+        "--signing-keys",
+        BERTHA_KEY,
+        "--node",
+        &validator_0_rpc,
+    ];
+    let mut client = run!(test, Bin::Client, tx_args, Some(40))?;
+    client.exp_string(
+        "The number of extra validators (1) must match the number of extra \
+         amounts (2).",
+    )?;
+    client.assert_failure();
+
+    Ok(())
+}
+
 /// PoS validator creation test. In this test we:
 ///
 /// 1. Run the ledger node with shorter epochs for faster progression
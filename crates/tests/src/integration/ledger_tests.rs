@@ -10,6 +10,7 @@ use namada::token;
 use namada_apps_lib::wallet::defaults;
 use namada_core::dec::Dec;
 use namada_core::storage::Epoch;
+use namada_core::time::DurationSecs;
 use namada_core::token::NATIVE_MAX_DECIMAL_PLACES;
 use namada_node::shell::testing::client::run;
 use namada_node::shell::testing::utils::{Bin, CapturedOutput};
@@ -404,6 +405,55 @@ fn invalid_transactions() -> Result<()> {
     Ok(())
 }
 
+/// In this test we:
+/// 1. Run the ledger node
+/// 2. Submit a transfer expiring one minute after the current block time
+/// 3. Check that it gets applied
+/// 4. Move the node's clock past the expiration and submit the same transfer
+/// 5. Check that it gets rejected
+#[test]
+fn tx_expiration() -> Result<()> {
+    // This address doesn't matter for tests. But an argument is required.
+    let validator_one_rpc = "http://127.0.0.1:26567";
+
+    let (node, _services) = setup::setup()?;
+
+    // 2. Submit a transfer with an expiration relative to the node's clock
+    let expiration = (node.block_time() + DurationSecs(60)).to_rfc3339();
+    let tx_args = vec![
+        "transfer",
+        "--source",
+        ALBERT,
+        "--target",
+        BERTHA,
+        "--token",
+        NAM,
+        "--amount",
+        "1",
+        "--signing-keys",
+        ALBERT_KEY,
+        "--expiration",
+        &expiration,
+        "--node",
+        &validator_one_rpc,
+    ];
+
+    // 3. The tx is still valid at the current block time
+    let captured =
+        CapturedOutput::of(|| run(&node, Bin::Client, tx_args.clone()));
+    assert_matches!(captured.result, Ok(_));
+    assert!(captured.contains(TX_APPLIED_SUCCESS));
+
+    // 4. Move the clock past the tx expiration
+    node.advance_time(DurationSecs(120));
+
+    // 5. The tx is now rejected as expired
+    let captured = CapturedOutput::of(|| run(&node, Bin::Client, tx_args));
+    assert!(captured.result.is_err());
+
+    Ok(())
+}
+
 /// Test for claiming PoS inflationary rewards
 ///
 /// 1. Run the ledger node
@@ -15,6 +15,7 @@ use namada_node::shell::testing::utils::{Bin, CapturedOutput};
 use namada_sdk::masp::fs::FsShieldedUtils;
 use test_log::test;
 
+use super::helpers::find_address;
 use super::setup;
 use crate::e2e::setup::constants::{
     AA_PAYMENT_ADDRESS, AA_VIEWING_KEY, AB_PAYMENT_ADDRESS, AB_VIEWING_KEY,
@@ -74,42 +75,19 @@ fn masp_incentives() -> Result<()> {
     node.assert_success();
 
     // Assert BTC balance at VK(A) is 1
-    let captured = CapturedOutput::of(|| {
-        run(
-            &node,
-            Bin::Client,
-            vec![
-                "balance",
-                "--owner",
-                AA_VIEWING_KEY,
-                "--token",
-                BTC,
-                "--node",
-                validator_one_rpc,
-            ],
-        )
-    });
-    assert!(captured.result.is_ok());
-    assert!(captured.contains("btc: 1"));
+    let btc = find_address(&node, BTC)?;
+    let btc_denom: token::Denomination = node
+        .storage_value(&token::storage_key::denom_key(&btc))
+        .expect("BTC denomination should be in storage");
+    assert_eq!(
+        node.shielded_balance(AA_VIEWING_KEY, &btc),
+        token::Amount::from_uint(1, btc_denom).unwrap()
+    );
 
     // Assert NAM balance at VK(A) is 0
-    let captured = CapturedOutput::of(|| {
-        run(
-            &node,
-            Bin::Client,
-            vec![
-                "balance",
-                "--owner",
-                AA_VIEWING_KEY,
-                "--token",
-                NAM,
-                "--node",
-                validator_one_rpc,
-            ],
-        )
-    });
-    assert!(captured.result.is_ok());
-    assert!(captured.contains("nam: 0"));
+    assert!(node
+        .shielded_balance(AA_VIEWING_KEY, &node.native_token())
+        .is_zero());
 
     // Wait till epoch boundary
     node.next_epoch();
@@ -220,6 +220,7 @@ fn create_node(
         results: Arc::new(Mutex::new(vec![])),
         blocks: Arc::new(Mutex::new(HashMap::new())),
         auto_drive_services,
+        clock: Arc::new(Mutex::new(genesis_time)),
     };
     let init_req =
         namada_apps_lib::facade::tendermint::v0_37::abci::request::InitChain {
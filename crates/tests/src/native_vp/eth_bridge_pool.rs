@@ -10,12 +10,18 @@ mod test_bridge_pool_vp {
     use namada::core::eth_bridge_pool::{
         GasFee, PendingTransfer, TransferToEthereum, TransferToEthereumKind,
     };
-    use namada::core::ethereum_events::EthAddress;
+    use namada::core::ethereum_events::{EthAddress, Uint};
+    use namada::core::keccak::KeccakHash;
     use namada::core::key::{common, ed25519, SecretKey};
     use namada::core::token::Amount;
-    use namada::eth_bridge::storage::bridge_pool::BRIDGE_POOL_ADDRESS;
+    use namada::eth_bridge::storage::bridge_pool::{
+        get_pending_key, get_signed_root_key, BRIDGE_POOL_ADDRESS,
+    };
+    use namada::eth_bridge::storage::parameters::BridgePoolCancelDelay;
+    use namada::eth_bridge::storage::proof::BridgePoolRootProof;
     use namada::gas::VpGasMeter;
     use namada::ledger::native_vp::ethereum_bridge::bridge_pool_vp::BridgePoolVp;
+    use namada::state::StorageWrite;
     use namada::tx::Tx;
     use namada_apps_lib::wallet::defaults::{albert_address, bertha_address};
     use namada_apps_lib::wasm_loader;
@@ -23,7 +29,10 @@ mod test_bridge_pool_vp {
         wrapped_erc20s, Contracts, Erc20WhitelistEntry, EthereumBridgeParams,
         UpgradeableContract,
     };
-    use namada_sdk::tx::TX_BRIDGE_POOL_WASM as ADD_TRANSFER_WASM;
+    use namada_sdk::tx::{
+        TX_BRIDGE_POOL_WASM as ADD_TRANSFER_WASM,
+        TX_CANCEL_BRIDGE_POOL_WASM as CANCEL_TRANSFER_WASM,
+    };
     use namada_tx_prelude::BatchedTx;
 
     use crate::native_vp::TestNativeVpEnv;
@@ -73,9 +82,13 @@ mod test_bridge_pool_vp {
             erc20_whitelist: vec![Erc20WhitelistEntry {
                 token_address: wnam(),
                 token_cap: Amount::from_u64(TOKEN_CAP).native_denominated(),
+                epoch_cap: None,
+                symbol: None,
             }],
             eth_start_height: Default::default(),
             min_confirmations: Default::default(),
+            event_confirmations: Default::default(),
+            bridge_pool_cancel_delay: Default::default(),
             contracts: Contracts {
                 native_erc20: wnam(),
                 bridge: UpgradeableContract {
@@ -110,7 +123,10 @@ mod test_bridge_pool_vp {
     }
 
     fn run_vp(tx: BatchedTx) -> bool {
-        let env = setup_env(tx);
+        run_vp_in_env(setup_env(tx))
+    }
+
+    fn run_vp_in_env(env: TestTxEnv) -> bool {
         tx_host_env::set(env);
         let mut tx_env = tx_host_env::take();
         tx_env.execute_tx().expect("Test failed.");
@@ -124,15 +140,19 @@ mod test_bridge_pool_vp {
     }
 
     fn validate_tx(tx: BatchedTx) {
+        validate_tx_in_env(setup_env(tx))
+    }
+
+    fn validate_tx_in_env(env: TestTxEnv) {
         #[cfg(feature = "namada-eth-bridge")]
         {
-            assert!(run_vp(tx));
+            assert!(run_vp_in_env(env));
         }
         #[cfg(not(feature = "namada-eth-bridge"))]
         {
             // NB: small hack to always check we reject txs
             // if the bridge is disabled at compile time
-            invalidate_tx(tx)
+            assert!(!run_vp_in_env(env));
         }
     }
 
@@ -143,10 +163,17 @@ mod test_bridge_pool_vp {
     fn create_tx(
         transfer: PendingTransfer,
         keypair: &common::SecretKey,
+    ) -> BatchedTx {
+        create_tx_with_code(ADD_TRANSFER_WASM, transfer, keypair)
+    }
+
+    fn create_tx_with_code(
+        code: &str,
+        transfer: PendingTransfer,
+        keypair: &common::SecretKey,
     ) -> BatchedTx {
         let data = transfer.serialize_to_vec();
-        let wasm_code =
-            wasm_loader::read_wasm_or_exit(wasm_dir(), ADD_TRANSFER_WASM);
+        let wasm_code = wasm_loader::read_wasm_or_exit(wasm_dir(), code);
 
         let mut tx = Tx::new(ChainId::default(), None);
         tx.add_code(wasm_code, None)
@@ -306,4 +333,57 @@ mod test_bridge_pool_vp {
         };
         validate_tx(create_tx(transfer, &bertha_keypair()));
     }
+
+    /// Test that the sender of a transfer can cancel it once its
+    /// cancellation delay has passed, even though the Bridge pool root
+    /// signed at every block covers it.
+    #[test]
+    fn validate_cancel_transfer_tx() {
+        let transfer = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                asset: ASSET,
+                recipient: EthAddress([0; 20]),
+                sender: bertha_address(),
+                amount: Amount::from(TOKENS),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: Amount::from(GAS_FEE),
+                payer: bertha_address(),
+            },
+        };
+
+        // add the transfer to the pool
+        let mut env = setup_env(create_tx(transfer.clone(), &bertha_keypair()));
+        env.execute_tx().expect("Test failed");
+        env.commit_tx_and_block();
+
+        // a root covering the transfer is signed at every block, until
+        // the cancellation delay has passed
+        let cancel_delay: u64 = BridgePoolCancelDelay::default().into();
+        for _ in 0..cancel_delay {
+            let signed_height = env.state.in_mem().block.height;
+            let root =
+                BridgePoolRootProof::new((KeccakHash([0; 32]), Uint::from(0)));
+            env.state
+                .write(&get_signed_root_key(), (root, signed_height))
+                .expect("Test failed");
+            env.commit_tx_and_block();
+            env.state.in_mem_mut().block.height = signed_height.next_height();
+        }
+
+        // cancel the transfer
+        env.batched_tx = create_tx_with_code(
+            CANCEL_TRANSFER_WASM,
+            transfer.clone(),
+            &bertha_keypair(),
+        );
+        env.execute_tx().expect("Test failed");
+        assert!(
+            env.all_touched_storage_keys()
+                .contains(&get_pending_key(&transfer))
+        );
+        validate_tx_in_env(env);
+    }
 }
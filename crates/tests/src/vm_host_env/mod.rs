@@ -24,7 +24,8 @@ mod tests {
     use borsh_ext::BorshSerializeExt;
     use itertools::Itertools;
     use namada::account::pks_handle;
-    use namada::core::hash::Hash;
+    use namada::core::ethereum_events::EthAddress;
+    use namada::core::hash::{Hash, KeccakHasher};
     use namada::core::key::*;
     use namada::core::storage::{self, BlockHeight, Key, KeySeg};
     use namada::core::time::DateTimeUtc;
@@ -593,6 +594,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_vp_verify_eth_signature() {
+        use namada_vp_prelude::verify_eth_signature;
+
+        // The environment must be initialized first
+        vp_host_env::init();
+
+        let keypair = match key::testing::keypair_3() {
+            common::SecretKey::Secp256k1(sk) => sk,
+            _ => unreachable!("keypair_3 is a secp256k1 key"),
+        };
+        let signer: EthAddress = (&keypair.ref_to()).into();
+        let message = b"authorize namada action";
+
+        // Sign the message as an Ethereum wallet would with `personal_sign`
+        let mut eth_message =
+            format!("\x19Ethereum Signed Message:\n{}", message.len())
+                .into_bytes();
+        eth_message.extend_from_slice(message);
+        let (r, s, v) =
+            secp256k1::SigScheme::sign_with_hasher::<KeccakHasher>(
+                &keypair,
+                eth_message,
+            )
+            .into_eth_rsv();
+        let signature = [&r[..], &s[..], &[v]].concat();
+
+        assert!(verify_eth_signature(message, &signature, &signer));
+        let other_message = b"another message";
+        assert!(!verify_eth_signature(other_message, &signature, &signer));
+
+        let other_keypair = match key::testing::keypair_4() {
+            common::SecretKey::Secp256k1(sk) => sk,
+            _ => unreachable!("keypair_4 is a secp256k1 key"),
+        };
+        let other_signer: EthAddress = (&other_keypair.ref_to()).into();
+        assert!(!verify_eth_signature(message, &signature, &other_signer));
+
+        // A malformed signature is invalid
+        assert!(!verify_eth_signature(message, &signature[..64], &signer));
+    }
+
     #[test]
     fn test_vp_get_metadata() {
         // The environment must be initialized first
@@ -525,6 +525,14 @@ mod native_tx_host_env {
         max_signatures_ptr: u64,
         max_signatures_len: u64,
     ) -> i64);
+    native_host_fn!(tx_verify_eth_signature(
+        message_ptr: u64,
+        message_len: u64,
+        signature_ptr: u64,
+        signature_len: u64,
+        eth_address_ptr: u64,
+        eth_address_len: u64,
+    ) -> i64);
     native_host_fn!(tx_yield_value(
         buf_ptr: u64,
         buf_len: u64,
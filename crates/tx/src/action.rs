@@ -25,6 +25,7 @@ pub enum Action {
     Pos(PosAction),
     Gov(GovAction),
     Pgf(PgfAction),
+    EthBridge(EthBridgeAction),
 }
 
 /// PoS tx actions.
@@ -61,6 +62,14 @@ pub enum PgfAction {
     UpdateStewardCommission(Address),
 }
 
+/// Ethereum bridge tx actions.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+pub enum EthBridgeAction {
+    CancelPoolTransfer(Address),
+    TopUpPoolTransfer(Address),
+}
+
 /// Read actions from temporary storage
 pub trait Read {
     /// Storage access errors
@@ -82,6 +82,10 @@ pub enum ResultCode {
     TooLarge = 11,
     /// Tx code is not allowlisted
     TxNotAllowlisted = 12,
+    /// A tx with the same contents is already in the mempool
+    DuplicateTx = 13,
+    /// The wasm code embedded in the tx is too large
+    CodeTooLarge = 14,
     // =========================================================================
     // WARN: These codes shouldn't be changed between version!
 }
@@ -97,7 +101,8 @@ impl ResultCode {
             Ok | WasmRuntimeError => true,
             InvalidTx | InvalidSig | AllocationError | ReplayTx
             | InvalidChainId | ExpiredTx | TxGasLimit | FeeError
-            | InvalidVoteExtension | TooLarge | TxNotAllowlisted => false,
+            | InvalidVoteExtension | TooLarge | TxNotAllowlisted
+            | DuplicateTx | CodeTooLarge => false,
         }
     }
 
@@ -376,6 +381,9 @@ pub struct VpsResult {
     /// about conditions that caused their evaluation to
     /// fail.
     pub status_flags: VpStatusFlags,
+    /// The gas used by each of the VPs
+    #[serde(default)]
+    pub gas_per_vp: BTreeMap<Address, Gas>,
 }
 
 impl<T: Serialize> fmt::Display for TxResult<T> {
@@ -428,7 +436,7 @@ impl fmt::Display for VpsResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}{}{}",
+            "{}{}{}{}",
             iterable_to_string("Accepted", self.accepted_vps.iter()),
             iterable_to_string("Rejected", self.rejected_vps.iter()),
             iterable_to_string(
@@ -437,6 +445,12 @@ impl fmt::Display for VpsResult {
                     .iter()
                     .map(|(addr, err)| format!("{} in {}", err, addr))
             ),
+            iterable_to_string(
+                "Gas",
+                self.gas_per_vp
+                    .iter()
+                    .map(|(addr, gas)| format!("{} by {}", gas, addr))
+            ),
         )
     }
 }
@@ -1,6 +1,11 @@
 //! Transaction events.
 
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::hash::Hash;
 use namada_events::extend::{
     ComposeEvent, EventAttributeEntry, Height, Log, TxHash,
 };
@@ -49,7 +54,7 @@ pub mod types {
 }
 
 /// Creates a new event with the hash and height of the transaction
-/// already filled in.
+/// already filled in, along with the memos of its inner txs, if any.
 pub fn new_tx_event(tx: &Tx, height: u64) -> Event {
     let base_event = match tx.header().tx_type {
         TxType::Wrapper(_) | TxType::Protocol(_) => {
@@ -58,10 +63,83 @@ pub fn new_tx_event(tx: &Tx, height: u64) -> Event {
         }
         _ => unreachable!(),
     };
-    base_event
+    let mut event: Event = base_event
         .with(Height(height.into()))
         .with(Log(String::new()))
-        .into()
+        .into();
+    let memos = BatchMemos::of(tx);
+    if !memos.0.is_empty() {
+        event.extend(Memos(memos));
+    }
+    event
+}
+
+/// The memos of the inner txs of a batch, indexed by the hash of their
+/// commitments. Memos are decoded as UTF-8, replacing any invalid sequences.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchMemos(pub BTreeMap<Hash, String>);
+
+impl BatchMemos {
+    /// Collect the memos of the inner txs of the given batch.
+    pub fn of(tx: &Tx) -> Self {
+        Self(
+            tx.header()
+                .batch
+                .iter()
+                .filter_map(|cmt| {
+                    let memo = tx.memo(cmt)?;
+                    Some((
+                        cmt.get_hash(),
+                        String::from_utf8_lossy(&memo).into_owned(),
+                    ))
+                })
+                .collect(),
+        )
+    }
+}
+
+impl Display for BatchMemos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let memos: BTreeMap<String, &String> = self
+            .0
+            .iter()
+            .map(|(cmt_hash, memo)| (cmt_hash.to_string(), memo))
+            .collect();
+        let json = serde_json::to_string(&memos).map_err(|_| fmt::Error)?;
+        write!(f, "{json}")
+    }
+}
+
+impl FromStr for BatchMemos {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let memos: BTreeMap<String, String> =
+            serde_json::from_str(s).map_err(|err| err.to_string())?;
+        memos
+            .into_iter()
+            .map(|(cmt_hash, memo)| {
+                let cmt_hash =
+                    Hash::from_str(&cmt_hash).map_err(|err| err.to_string())?;
+                Ok((cmt_hash, memo))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// Extend an [`Event`] with the memos of the inner txs of a batch.
+pub struct Memos(pub BatchMemos);
+
+impl EventAttributeEntry<'static> for Memos {
+    type Value = BatchMemos;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "memos";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
 }
 
 /// Extend an [`Event`] with result code data.
@@ -91,3 +169,27 @@ impl<'result> EventAttributeEntry<'result> for Batch<'result> {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Data;
+
+    #[test]
+    fn test_batch_memos_event_attribute() {
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.set_data(Data::new(vec![]));
+        tx.add_memo(b"invoice #42");
+        let cmt_hash = tx.first_commitments().unwrap().get_hash();
+
+        let memos = BatchMemos::of(&tx);
+        assert_eq!(
+            memos.0.get(&cmt_hash).map(String::as_str),
+            Some("invoice #42")
+        );
+
+        let mut event = Event::new(types::APPLIED, EventLevel::Tx);
+        event.extend(Memos(memos.clone()));
+        assert_eq!(event.read_attribute::<Memos>().unwrap(), memos);
+    }
+}
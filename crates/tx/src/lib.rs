@@ -31,7 +31,7 @@ pub use types::{
     standalone_signature, verify_standalone_sig, Authorization, BatchedTx,
     BatchedTxRef, Code, Commitment, CompressedAuthorization, Data, DecodeError,
     Header, IndexedTx, MaspBuilder, Memo, Section, Signed, Signer, Tx,
-    TxCommitments, TxError, VerifySigError,
+    TxCommitments, TxError, TxVersionError, VerifySigError, TX_FORMAT_VERSION,
 };
 
 #[cfg(test)]
@@ -47,6 +47,7 @@ mod tests {
 
         let tx = Tx {
             data: "arbitrary data".as_bytes().to_owned(),
+            version: 0,
         };
         let mut tx_bytes = vec![];
         tx.encode(&mut tx_bytes).unwrap();
@@ -67,6 +67,29 @@ pub enum DecodeError {
     InvalidTimestamp(prost_types::TimestampError),
     #[error("Couldn't serialize transaction from JSON at {0}")]
     InvalidJSONDeserialization(String),
+    #[error("{0}")]
+    UnsupportedVersion(#[from] TxVersionError),
+}
+
+/// The version of the wire format of transactions. Transactions encoded with
+/// any other version are rejected, except for the version 0 of transactions
+/// encoded before the version was introduced, which is decoded the same way.
+///
+/// The version is not covered by the header hash nor the signatures of a
+/// transaction, so it must never change how a transaction is decoded:
+/// unknown sections and trailing data are rejected, and any change to the
+/// encoding requires a new version that older nodes reject.
+pub const TX_FORMAT_VERSION: u32 = 1;
+
+/// Errors of the versioning of the wire format of transactions
+#[allow(missing_docs)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TxVersionError {
+    #[error(
+        "Unsupported transaction format version {0}, expected \
+         {TX_FORMAT_VERSION}"
+    )]
+    UnsupportedVersion(u32),
 }
 
 /// This can be used to sign an arbitrary tx. The signature is produced and
@@ -1057,8 +1080,13 @@ impl TryFrom<&[u8]> for Tx {
 
         let tx = proto::Tx::decode(tx_bytes)
             .map_err(DecodeError::TxDecodingError)?;
-        BorshDeserialize::try_from_slice(&tx.data)
-            .map_err(DecodeError::InvalidEncoding)
+        match tx.version {
+            // NB: version 0 is used by transactions encoded before the
+            // version was introduced, which are in the first version
+            0 | TX_FORMAT_VERSION => BorshDeserialize::try_from_slice(&tx.data)
+                .map_err(DecodeError::InvalidEncoding),
+            version => Err(TxVersionError::UnsupportedVersion(version).into()),
+        }
     }
 }
 
@@ -1214,6 +1242,28 @@ impl Tx {
         }
     }
 
+    /// Get the size, in bytes, of the largest code embedded in the code and
+    /// extra data sections of this transaction, such as the wasm of a tx or
+    /// of a governance proposal. Sections only committing to the hash of
+    /// their code are not accounted for.
+    pub fn max_embedded_code_len(&self) -> usize {
+        self.sections
+            .iter()
+            .filter_map(|section| match section {
+                Section::Code(Code {
+                    code: Commitment::Id(code),
+                    ..
+                })
+                | Section::ExtraData(Code {
+                    code: Commitment::Id(code),
+                    ..
+                }) => Some(code.len()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
     /// Add a new section to the transaction
     pub fn add_section(&mut self, section: Section) -> &mut Section {
         self.sections.push(section);
@@ -1293,6 +1343,7 @@ impl Tx {
         let mut bytes = vec![];
         let tx: proto::Tx = proto::Tx {
             data: self.serialize_to_vec(),
+            version: TX_FORMAT_VERSION,
         };
         tx.encode(&mut bytes)
             .expect("encoding a transaction failed");
@@ -1828,4 +1879,56 @@ mod test {
             Tx::try_from(tmp.as_ref()).unwrap();
         }
     }
+
+    /// Test that transactions are decoded according to their format version
+    #[test]
+    fn test_tx_format_versions() {
+        use prost::Message;
+
+        let tx = Tx::default();
+        let decoded = Tx::try_from(tx.to_bytes().as_slice()).unwrap();
+        assert_eq!(decoded.header_hash(), tx.header_hash());
+
+        let encode = |data: Vec<u8>, version: u32| {
+            proto::Tx { data, version }.encode_to_vec()
+        };
+
+        // Transactions from before the introduction of versions are decoded
+        let decoded =
+            Tx::try_from(encode(tx.serialize_to_vec(), 0).as_slice()).unwrap();
+        assert_eq!(decoded.header_hash(), tx.header_hash());
+
+        // Trailing data is rejected
+        let mut data = tx.serialize_to_vec();
+        data.extend_from_slice(b"new field");
+        assert!(matches!(
+            Tx::try_from(encode(data, TX_FORMAT_VERSION).as_slice()),
+            Err(DecodeError::InvalidEncoding(_))
+        ));
+
+        // Unknown sections are rejected
+        let mut unknown_section = tx.serialize_to_vec();
+        // replace the empty list of sections with a single unknown one
+        unknown_section.truncate(unknown_section.len() - 4);
+        unknown_section.extend_from_slice(&1u32.to_le_bytes());
+        unknown_section.push(u8::MAX);
+        unknown_section.extend_from_slice(b"new section");
+        assert!(matches!(
+            Tx::try_from(encode(unknown_section, TX_FORMAT_VERSION).as_slice()),
+            Err(DecodeError::InvalidEncoding(_))
+        ));
+
+        // Any other version is rejected, even if the transaction could be
+        // decoded
+        for version in [TX_FORMAT_VERSION + 1, 1 << 16, u32::MAX] {
+            assert!(matches!(
+                Tx::try_from(
+                    encode(tx.serialize_to_vec(), version).as_slice()
+                ),
+                Err(DecodeError::UnsupportedVersion(
+                    TxVersionError::UnsupportedVersion(v)
+                )) if v == version
+            ));
+        }
+    }
 }
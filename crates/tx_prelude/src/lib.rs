@@ -464,6 +464,29 @@ pub fn verify_signatures_of_pks(
     Ok(HostEnvResult::is_success(valid))
 }
 
+/// Verify that an Ethereum `personal_sign` signature over the given message
+/// was produced by the key of the given Ethereum address. The signature is
+/// expected in its 65 bytes `r || s || v` encoding, as returned by Ethereum
+/// wallets.
+pub fn verify_eth_signature(
+    message: &[u8],
+    signature: &[u8],
+    signer: &EthAddress,
+) -> bool {
+    let signer = signer.serialize_to_vec();
+    let valid = unsafe {
+        namada_tx_verify_eth_signature(
+            message.as_ptr() as _,
+            message.len() as _,
+            signature.as_ptr() as _,
+            signature.len() as _,
+            signer.as_ptr() as _,
+            signer.len() as _,
+        )
+    };
+    HostEnvResult::is_success(valid)
+}
+
 /// Update the masp note commitment tree in storage with the new notes
 pub fn update_masp_note_commitment_tree(
     transaction: &Transaction,
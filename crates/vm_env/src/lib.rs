@@ -148,6 +148,17 @@ pub mod tx {
             max_signatures_len: u64,
         ) -> i64;
 
+        /// Verify that an Ethereum signature over a message was produced by
+        /// the given Ethereum address
+        pub fn namada_tx_verify_eth_signature(
+            message_ptr: u64,
+            message_len: u64,
+            signature_ptr: u64,
+            signature_len: u64,
+            eth_address_ptr: u64,
+            eth_address_len: u64,
+        ) -> i64;
+
         /// Update the masp note commitment tree with the new notes
         pub fn namada_tx_update_masp_note_commitment_tree(
             transaction_ptr: u64,
@@ -264,6 +275,17 @@ pub mod vp {
             max_signatures_len: u64,
         );
 
+        /// Verify that an Ethereum signature over a message was produced by
+        /// the given Ethereum address
+        pub fn namada_vp_verify_eth_signature(
+            message_ptr: u64,
+            message_len: u64,
+            signature_ptr: u64,
+            signature_len: u64,
+            eth_address_ptr: u64,
+            eth_address_len: u64,
+        ) -> i64;
+
         /// Evaluate a validity-predicate
         pub fn namada_vp_eval(
             vp_code_hash_ptr: u64,
@@ -0,0 +1,107 @@
+//! Validation of the spending allowances that an account grants to spenders,
+//! allowing them to debit up to a daily cap of a token from the account.
+
+use std::collections::BTreeMap;
+
+use namada_core::booleans::BoolResultUnitExt;
+
+use crate::*;
+
+/// The amounts of tokens that the spenders are allowed to debit from an
+/// account in a tx, as recorded in its allowance spending records
+#[derive(Debug, Default)]
+pub struct AllowedDebits(BTreeMap<Address, token::Amount>);
+
+impl AllowedDebits {
+    /// Validate the changed allowance spending records of `addr` against the
+    /// allowances it granted and collect the total amount of each token that
+    /// the spenders are allowed to debit from the account in this tx.
+    pub fn validate(
+        ctx: &Ctx,
+        tx: &Tx,
+        addr: &Address,
+        keys_changed: &BTreeSet<storage::Key>,
+    ) -> VpEnvResult<Self> {
+        let mut allowed_debits: BTreeMap<Address, token::Amount> =
+            BTreeMap::new();
+        let mut today = None;
+        for key in keys_changed {
+            let Some([owner, spender, token]) =
+                account::is_allowance_spending_key(key)
+            else {
+                continue;
+            };
+            let post: Option<account::AllowanceSpending> =
+                ctx.read_post(key).into_vp_error()?;
+            let Some(post) = post.filter(|_| owner == addr) else {
+                // Other changes are validated with the rest of the keys
+                continue;
+            };
+
+            // Spending an allowance must be authorized by the spender
+            VerifySigGadget::new().verify_signatures(ctx, tx, spender)?;
+
+            let today = match today {
+                Some(today) => today,
+                None => *today.insert(
+                    account::current_allowance_day(&ctx.pre())
+                        .into_vp_error()?,
+                ),
+            };
+            let daily_cap =
+                account::read_allowance(&ctx.pre(), owner, spender, token)
+                    .into_vp_error()?
+                    .ok_or_else(|| {
+                        VpError::Erased(format!(
+                            "{spender} has no allowance of {token} on {owner}"
+                        ))
+                    })?;
+            let pre: account::AllowanceSpending =
+                ctx.read_pre(key).into_vp_error()?.unwrap_or_default();
+
+            (post.day == today).ok_or_else(|| {
+                VpError::Erased(format!(
+                    "The allowance spending of {spender} must be recorded on \
+                     the current day {today}, got {}",
+                    post.day
+                ))
+            })?;
+            (post.spent <= daily_cap).ok_or_else(|| {
+                VpError::Erased(format!(
+                    "The allowance spending of {spender} exceeds its daily \
+                     cap of {}",
+                    daily_cap.to_string_native()
+                ))
+            })?;
+            let spent = post
+                .spent
+                .checked_sub(pre.spent_on(today))
+                .ok_or_else(|| {
+                    VpError::Erased(format!(
+                        "The allowance spending of {spender} cannot be \
+                         decreased"
+                    ))
+                })?;
+            let allowed = allowed_debits.entry(token.clone()).or_default();
+            *allowed = allowed.checked_add(spent).ok_or_else(|| {
+                VpError::Erased("Allowance spending overflow".into())
+            })?;
+        }
+        Ok(Self(allowed_debits))
+    }
+
+    /// Deduct a debit of a token from the amount allowed to be debited by the
+    /// spenders. Returns `false` if the debit is not fully covered.
+    pub fn spend(&mut self, token: &Address, debit: token::Amount) -> bool {
+        let Some(allowed) = self.0.get_mut(token) else {
+            return false;
+        };
+        match allowed.checked_sub(debit) {
+            Some(remaining) => {
+                *allowed = remaining;
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -13,6 +13,9 @@
     clippy::print_stderr
 )]
 
+pub mod allowance;
+pub mod user;
+
 /// IBC events and storage keys.
 pub mod ibc {
     pub use namada_ibc::event::{IbcEvent, IbcEventType};
@@ -69,6 +72,29 @@ pub fn log_string<T: AsRef<str>>(msg: T) {
     }
 }
 
+/// Verify that an Ethereum `personal_sign` signature over the given message
+/// was produced by the key of the given Ethereum address. The signature is
+/// expected in its 65 bytes `r || s || v` encoding, as returned by Ethereum
+/// wallets.
+pub fn verify_eth_signature(
+    message: &[u8],
+    signature: &[u8],
+    signer: &ethereum_events::EthAddress,
+) -> bool {
+    let signer = signer.serialize_to_vec();
+    let valid = unsafe {
+        namada_vp_verify_eth_signature(
+            message.as_ptr() as _,
+            message.len() as _,
+            signature.as_ptr() as _,
+            signature.len() as _,
+            signer.as_ptr() as _,
+            signer.len() as _,
+        )
+    };
+    HostEnvResult::is_success(valid)
+}
+
 /// Checks if a proposal id is being executed
 pub fn is_proposal_accepted(ctx: &Ctx, proposal_id: u64) -> VpEnvResult<bool> {
     let proposal_execution_key =
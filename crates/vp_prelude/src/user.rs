@@ -0,0 +1,241 @@
+//! The validation logic of the user VP, shared by the user VP templates.
+//!
+//! It provides a signature verification against the account's public keys
+//! for sending tokens (receiving tokens is permissive), for the actions whose
+//! source is the account and for any other storage key changes.
+
+use namada_core::booleans::BoolResultUnitExt;
+use namada_tx::action::*;
+
+use crate::allowance::AllowedDebits;
+use crate::*;
+
+/// Validate a tx with the user VP of the account `addr`. If `allowances` is
+/// set, the debits of the account that are covered by the spending
+/// allowances it granted only require a valid signature(s) from the spenders.
+#[allow(clippy::print_stdout)]
+pub fn validate_tx(
+    ctx: &Ctx,
+    tx: BatchedTx,
+    addr: Address,
+    keys_changed: BTreeSet<storage::Key>,
+    verifiers: BTreeSet<Address>,
+    allowances: bool,
+) -> VpResult {
+    let BatchedTx { tx, ref cmt } = tx;
+    // Check if this is a governance proposal first
+    let is_gov_proposal = tx
+        .data(cmt)
+        .and_then(|tx_data| {
+            let proposal_id = u64::try_from_slice(&tx_data).ok()?;
+            Some(is_proposal_accepted(ctx, proposal_id))
+        })
+        .transpose()?
+        .unwrap_or(false);
+    if is_gov_proposal {
+        // Any change from governance is allowed without further checks
+        return Ok(());
+    }
+
+    let mut gadget = VerifySigGadget::new();
+
+    // Find the actions applied in the tx
+    let actions = ctx.read_actions().into_vp_error()?;
+
+    // Require authorization by signature when the source of an action is this
+    // VP's address
+    for action in actions {
+        match action {
+            Action::Pos(pos_action) => match pos_action {
+                PosAction::BecomeValidator(source)
+                | PosAction::DeactivateValidator(source)
+                | PosAction::ReactivateValidator(source)
+                | PosAction::Unjail(source)
+                | PosAction::CommissionChange(source)
+                | PosAction::MetadataChange(source)
+                | PosAction::ConsensusKeyChange(source)
+                | PosAction::Redelegation(Redelegation {
+                    owner: source, ..
+                }) => gadget.verify_signatures_when(
+                    || source == addr,
+                    ctx,
+                    &tx,
+                    &addr,
+                )?,
+                PosAction::Bond(Bond {
+                    source, validator, ..
+                })
+                | PosAction::Unbond(Unbond {
+                    source, validator, ..
+                })
+                | PosAction::Withdraw(Withdraw { source, validator })
+                | PosAction::ClaimRewards(ClaimRewards { validator, source }) =>
+                {
+                    let source = source.unwrap_or(validator);
+                    gadget.verify_signatures_when(
+                        || source == addr,
+                        ctx,
+                        &tx,
+                        &addr,
+                    )?
+                }
+            },
+            Action::Gov(
+                GovAction::InitProposal { author: source }
+                | GovAction::VoteProposal { voter: source, .. },
+            )
+            | Action::Pgf(
+                PgfAction::ResignSteward(source)
+                | PgfAction::UpdateStewardCommission(source),
+            )
+            | Action::EthBridge(
+                EthBridgeAction::CancelPoolTransfer(source)
+                | EthBridgeAction::TopUpPoolTransfer(source),
+            ) => gadget.verify_signatures_when(
+                || source == addr,
+                ctx,
+                &tx,
+                &addr,
+            )?,
+        }
+    }
+
+    // Debits that are covered by the spenders' allowances, per token
+    let mut allowed_debits = if allowances {
+        AllowedDebits::validate(ctx, &tx, &addr, &keys_changed)?
+    } else {
+        AllowedDebits::default()
+    };
+
+    keys_changed.iter().try_for_each(|key| {
+        let key_type: KeyType<'_> = key.into();
+        let mut validate_change = || match key_type {
+            KeyType::TokenBalance {
+                token: balance_token,
+                owner,
+            } => {
+                if owner == &addr {
+                    let pre: token::Amount =
+                        ctx.read_pre(key).into_vp_error()?.unwrap_or_default();
+                    let post: token::Amount =
+                        ctx.read_post(key).into_vp_error()?.unwrap_or_default();
+                    let change =
+                        post.change().checked_sub(pre.change()).unwrap();
+                    let is_allowed_debit =
+                        pre.checked_sub(post).is_some_and(|debit| {
+                            allowed_debits.spend(balance_token, debit)
+                        });
+                    gadget.verify_signatures_when(
+                        // NB: debit has to signed, credit doesn't, unless it
+                        // is covered by an allowance
+                        || change.is_negative() && !is_allowed_debit,
+                        ctx,
+                        &tx,
+                        &addr,
+                    )?;
+                    let sign = if change.non_negative() { "" } else { "-" };
+                    debug_log!("token key: {key}, change: {sign}{change:?}");
+                } else {
+                    // If this is not the owner, allow any change
+                    debug_log!(
+                        "This address ({}) is not of owner ({}) of token key: \
+                         {}",
+                        addr,
+                        owner,
+                        key
+                    );
+                }
+                Ok(())
+            }
+            KeyType::TokenMinted => {
+                verifiers.contains(&address::MULTITOKEN).ok_or_else(|| {
+                    VpError::Erased(
+                        "The Multitoken VP should have been a verifier for \
+                         this transaction, since a token was minted"
+                            .into(),
+                    )
+                })
+            }
+            KeyType::TokenMinter(minter_addr) => gadget.verify_signatures_when(
+                || minter_addr == &addr,
+                ctx,
+                &tx,
+                &addr,
+            ),
+            KeyType::Vp(owner) => {
+                let vp_overwritten: bool =
+                    ctx.has_key_post(key).into_vp_error()?;
+                gadget.verify_signatures_when(
+                    || owner == &addr && vp_overwritten,
+                    ctx,
+                    &tx,
+                    &addr,
+                )
+            }
+            KeyType::AllowanceSpending(owner) => {
+                let is_recorded_spending = allowances
+                    && owner == &addr
+                    && ctx.has_key_post(key).into_vp_error()?;
+                // Spending records have been validated against the
+                // allowances, only their removal requires a signature
+                gadget.verify_signatures_when(
+                    || !is_recorded_spending,
+                    ctx,
+                    &tx,
+                    &addr,
+                )
+            }
+            KeyType::Masp | KeyType::Ibc => Ok(()),
+            KeyType::Unknown => {
+                // Unknown changes require a valid signature
+                gadget.verify_signatures(ctx, &tx, &addr)
+            }
+        };
+        validate_change().inspect_err(|reason| {
+            log_string(format!(
+                "Modification on key {key} failed the user VP: {reason}"
+            ));
+        })
+    })
+}
+
+enum KeyType<'a> {
+    TokenBalance {
+        token: &'a Address,
+        owner: &'a Address,
+    },
+    TokenMinted,
+    TokenMinter(&'a Address),
+    Vp(&'a Address),
+    AllowanceSpending(&'a Address),
+    Masp,
+    Ibc,
+    Unknown,
+}
+
+impl<'a> From<&'a storage::Key> for KeyType<'a> {
+    fn from(key: &'a storage::Key) -> KeyType<'a> {
+        if let Some([token, owner]) =
+            token::storage_key::is_any_token_balance_key(key)
+        {
+            Self::TokenBalance { token, owner }
+        } else if token::storage_key::is_any_minted_balance_key(key).is_some() {
+            Self::TokenMinted
+        } else if let Some(minter) = token::storage_key::is_any_minter_key(key)
+        {
+            Self::TokenMinter(minter)
+        } else if let Some(address) = key.is_validity_predicate() {
+            Self::Vp(address)
+        } else if let Some([owner, _, _]) =
+            account::is_allowance_spending_key(key)
+        {
+            Self::AllowanceSpending(owner)
+        } else if token::storage_key::is_masp_key(key) {
+            Self::Masp
+        } else if ibc::is_ibc_key(key) {
+            Self::Ibc
+        } else {
+            Self::Unknown
+        }
+    }
+}
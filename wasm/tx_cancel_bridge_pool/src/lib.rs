@@ -0,0 +1,83 @@
+//! A tx for cancelling a transfer request across the Ethereum bridge
+//! that is pending in the bridge pool.
+//!
+//! The Bridge pool VP rejects the cancellation of transfers in a batch
+//! relayed to Ethereum that is still being confirmed.
+use namada_tx_prelude::action::{Action, EthBridgeAction, Write};
+use namada_tx_prelude::eth_bridge_pool::{
+    get_fee_top_up_key, get_pending_key, GasFee, PendingTransfer,
+    TransferToEthereum, BRIDGE_POOL_ADDRESS,
+};
+use namada_tx_prelude::parameters::native_erc20_key;
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let transfer = PendingTransfer::try_from_slice(&data[..])
+        .map_err(|e| Error::wrap("Error deserializing PendingTransfer", e))?;
+    debug_log!("Received transfer to cancel from Bridge pool");
+
+    // The tx must be authorized by the sender of the transfer
+    ctx.insert_verifier(&transfer.transfer.sender)?;
+    ctx.push_action(Action::EthBridge(EthBridgeAction::CancelPoolTransfer(
+        transfer.transfer.sender.clone(),
+    )))?;
+
+    // remove the transfer from the pool
+    let pending_key = get_pending_key(&transfer);
+    let pending: PendingTransfer = ctx
+        .read(&pending_key)
+        .wrap_err("Could not read transfer from bridge pool")?
+        .ok_or_err_msg("The transfer is not in the bridge pool")?;
+    ctx.delete(&pending_key)
+        .wrap_err("Could not remove transfer from bridge pool")?;
+
+    // refund the gas fees, including any topped up fees
+    let fee_top_up_key = get_fee_top_up_key(&pending.keccak256());
+    let fee_top_up: token::Amount = ctx
+        .read(&fee_top_up_key)
+        .wrap_err("Could not read the topped up gas fees")?
+        .unwrap_or_default();
+    if !fee_top_up.is_zero() {
+        ctx.delete(&fee_top_up_key)
+            .wrap_err("Could not remove the topped up gas fees")?;
+    }
+    let GasFee {
+        token: ref fee_token_addr,
+        amount,
+        ref payer,
+    } = pending.gas_fee;
+    let amount = amount
+        .checked_add(fee_top_up)
+        .ok_or_err_msg("Overflow in the gas fees of the transfer")?;
+    token::transfer(ctx, &BRIDGE_POOL_ADDRESS, payer, fee_token_addr, amount)?;
+    debug_log!("Bridge pool gas fee refund succeeded");
+    let TransferToEthereum {
+        asset,
+        ref sender,
+        amount,
+        ..
+    } = pending.transfer;
+    // if minting wNam, release the escrowed NAM
+    if asset == native_erc20_address(ctx)? {
+        let nam_addr = ctx.get_native_token()?;
+        token::transfer(ctx, &address::ETH_BRIDGE, sender, &nam_addr, amount)?;
+    } else {
+        // Otherwise we release the escrowed ERC20 tokens.
+        let token = pending.token_address();
+        token::transfer(ctx, &BRIDGE_POOL_ADDRESS, sender, &token, amount)?;
+    }
+    debug_log!("Bridge pool escrow refund succeeded");
+    Ok(())
+}
+
+fn native_erc20_address(ctx: &mut Ctx) -> EnvResult<EthAddress> {
+    debug_log!("Trying to get wnam key for Bridge pool cancellation");
+    let addr = ctx
+        .read(&native_erc20_key())
+        .wrap_err("Could not read wrapped NAM address")?
+        .ok_or_err_msg("Wrapped NAM address must be present in storage")?;
+    debug_log!("Got wnam key for Bridge pool cancellation: {addr}");
+    Ok(addr)
+}
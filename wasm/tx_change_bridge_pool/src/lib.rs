@@ -1,7 +1,8 @@
 //! A tx for adding a transfer request across the Ethereum bridge
 //! into the bridge pool.
 use namada_tx_prelude::eth_bridge_pool::{
-    get_pending_key, GasFee, PendingTransfer, TransferToEthereum,
+    get_epoch_utilization_key, get_pending_key, EpochUtilization, GasFee,
+    PendingTransfer, TransferToEthereum, TransferToEthereumKind,
     BRIDGE_POOL_ADDRESS,
 };
 use namada_tx_prelude::parameters::native_erc20_key;
@@ -37,6 +38,20 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         token::transfer(ctx, sender, &BRIDGE_POOL_ADDRESS, &token, amount)?;
     }
     debug_log!("Bridge pool escrow succeeded");
+    // account for the amount of ERC20 assets queued in the current epoch,
+    // which may be capped
+    if let TransferToEthereumKind::Erc20 = transfer.transfer.kind {
+        let utilization_key = get_epoch_utilization_key(&asset);
+        let utilization: EpochUtilization = ctx
+            .read(&utilization_key)
+            .wrap_err("Could not read the Bridge pool utilization")?
+            .unwrap_or_default();
+        let utilization = utilization
+            .queue(ctx.get_block_epoch()?, amount)
+            .ok_or_err_msg("Overflow in the Bridge pool utilization")?;
+        ctx.write(&utilization_key, utilization)
+            .wrap_err("Could not update the Bridge pool utilization")?;
+    }
     // add transfer into the pool
     let pending_key = get_pending_key(&transfer);
     ctx.write(&pending_key, transfer)
@@ -0,0 +1,30 @@
+//! A tx for a spender to transfer tokens out of an account within its daily
+//! allowance. This tx uses `account::SpendAllowance` wrapped inside
+//! `SignedTxData` as its input as declared in `namada` crate.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let account::SpendAllowance {
+        owner,
+        spender,
+        target,
+        token,
+        amount,
+    } = account::SpendAllowance::try_from_slice(&data[..])
+        .wrap_err("Failed to decode SpendAllowance tx data")?;
+    debug_log!(
+        "spend {} of {token} from {owner} to {target} by {spender}",
+        amount.to_string_native()
+    );
+
+    let day = account::current_allowance_day(ctx)
+        .wrap_err("Failed to read the current allowance day")?;
+    account::spend_allowance(ctx, &owner, &spender, &token, amount, day)
+        .wrap_err("Failed to spend the allowance")?;
+
+    token::transfer(ctx, &owner, &target, &token, amount)
+        .wrap_err("Token transfer failed")
+}
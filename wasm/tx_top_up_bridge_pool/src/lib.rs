@@ -0,0 +1,54 @@
+//! A tx for topping up the gas fees of a transfer request across the
+//! Ethereum bridge that is pending in the bridge pool.
+use namada_tx_prelude::action::{Action, EthBridgeAction, Write};
+use namada_tx_prelude::eth_bridge_pool::{
+    get_fee_top_up_key, get_key_from_hash, PendingTransfer, TransferFeeTopUp,
+    BRIDGE_POOL_ADDRESS,
+};
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let TransferFeeTopUp {
+        transfer: transfer_hash,
+        amount,
+    } = TransferFeeTopUp::try_from_slice(&data[..])
+        .map_err(|e| Error::wrap("Error deserializing TransferFeeTopUp", e))?;
+    debug_log!("Received gas fees to top up a Bridge pool transfer");
+
+    let transfer: PendingTransfer = ctx
+        .read(&get_key_from_hash(&transfer_hash))
+        .wrap_err("Could not read transfer from bridge pool")?
+        .ok_or_err_msg("The transfer is not in the bridge pool")?;
+    let payer = &transfer.gas_fee.payer;
+
+    // The tx must be authorized by the payer of the gas fees
+    ctx.insert_verifier(payer)?;
+    ctx.push_action(Action::EthBridge(EthBridgeAction::TopUpPoolTransfer(
+        payer.clone(),
+    )))?;
+
+    // escrow the additional gas fees
+    token::transfer(
+        ctx,
+        payer,
+        &BRIDGE_POOL_ADDRESS,
+        &transfer.gas_fee.token,
+        amount,
+    )?;
+    debug_log!("Bridge pool gas fee top up succeeded");
+
+    // record the topped up gas fees of the transfer
+    let fee_top_up_key = get_fee_top_up_key(&transfer_hash);
+    let fee_top_up: token::Amount = ctx
+        .read(&fee_top_up_key)
+        .wrap_err("Could not read the topped up gas fees")?
+        .unwrap_or_default();
+    let fee_top_up = fee_top_up
+        .checked_add(amount)
+        .ok_or_err_msg("Overflow in the topped up gas fees")?;
+    ctx.write(&fee_top_up_key, fee_top_up)
+        .wrap_err("Could not update the topped up gas fees")?;
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//! A tx for an account to set or remove the daily spending cap of a spender.
+//! This tx uses `account::UpdateAllowance` wrapped inside `SignedTxData` as
+//! its input as declared in `namada` crate.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let account::UpdateAllowance {
+        owner,
+        spender,
+        token,
+        daily_cap,
+    } = account::UpdateAllowance::try_from_slice(&data[..])
+        .wrap_err("Failed to decode UpdateAllowance tx data")?;
+    debug_log!(
+        "update allowance of {spender} for {token} on {owner}: {daily_cap:?}"
+    );
+
+    // The tx must be authorized by the owner
+    ctx.insert_verifier(&owner)?;
+
+    account::write_allowance(ctx, &owner, &spender, &token, daily_cap)
+        .wrap_err("Failed to update the allowance")
+}
@@ -0,0 +1,20 @@
+//! A tx for the fee price feed to update the conversion rates of fee tokens.
+//! This tx uses `parameters::UpdateFeeConversionRates` wrapped inside
+//! `SignedTxData` as its input as declared in `namada` crate.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let parameters::UpdateFeeConversionRates { price_feed, rates } =
+        parameters::UpdateFeeConversionRates::try_from_slice(&data[..])
+            .wrap_err("Failed to decode UpdateFeeConversionRates tx data")?;
+    debug_log!("update fee conversion rates by {price_feed}: {rates:?}");
+
+    // The tx must be authorized by the price feed
+    ctx.insert_verifier(&price_feed)?;
+
+    parameters::update_fee_conversion_rates(ctx, rates)
+        .wrap_err("Failed to update the fee conversion rates")
+}
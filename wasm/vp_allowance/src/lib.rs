@@ -0,0 +1,250 @@
+//! A user VP with per-spender daily spending caps. It supports both
+//! non-validator and validator accounts.
+//!
+//! It validates the txs like the basic user VP, except that the account owner
+//! may grant allowances to spenders, limiting the amount of a token a spender
+//! may debit from the account per day. A debit that is recorded against a
+//! spender's allowance only requires a valid signature(s) from the spender,
+//! as long as the daily cap is not exceeded.
+
+use namada_vp_prelude::*;
+
+#[validity_predicate]
+fn validate_tx(
+    ctx: &Ctx,
+    tx: BatchedTx,
+    addr: Address,
+    keys_changed: BTreeSet<storage::Key>,
+    verifiers: BTreeSet<Address>,
+) -> VpResult {
+    debug_log!(
+        "vp_allowance called with user addr: {}, key_changed: {:?}, \
+         verifiers: {:?}",
+        addr,
+        keys_changed,
+        verifiers
+    );
+
+    user::validate_tx(ctx, tx, addr, keys_changed, verifiers, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use namada::tx::data::TxType;
+    use namada::tx::{Authorization, Code, Data};
+    // Use this as `#[test]` annotation to enable logging
+    use namada_tests::log::test;
+    use namada_tests::tx::{self, tx_host_env, TestTxEnv};
+    use namada_tests::vp::*;
+    use namada_tx_prelude::StorageWrite;
+    use namada_vp_prelude::account::AccountPublicKeysMap;
+    use namada_vp_prelude::key::RefTo;
+
+    use super::*;
+
+    /// Initialize a tx environment in which the VP owner
+    /// `established_address_1` grants `established_address_2` a daily
+    /// allowance of NAM
+    fn init_tx_env(daily_cap: token::Amount) -> TestTxEnv {
+        let mut tx_env = TestTxEnv::default();
+
+        let vp_owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+        let target = address::testing::established_address_3();
+        let token = address::testing::nam();
+        let public_key = key::testing::keypair_1().ref_to();
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&vp_owner, &spender, &target, &token]);
+        tx_env.init_account_storage(&spender, vec![public_key], 1);
+
+        // Credit the tokens to the VP owner before running the transaction to
+        // be able to transfer from it
+        tx_env.credit_tokens(
+            &vp_owner,
+            &token,
+            token::Amount::from_uint(1_000, 0).unwrap(),
+        );
+        // write the denomination of NAM into storage
+        token::write_denom(
+            &mut tx_env.state,
+            &token,
+            token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+
+        account::write_allowance(
+            &mut tx_env.state,
+            &vp_owner,
+            &spender,
+            &token,
+            Some(daily_cap),
+        )
+        .unwrap();
+
+        // The allowance day is derived from the current block's time
+        tx_env.state.in_mem_mut().header = Some(storage::Header {
+            hash: hash::Hash::zero(),
+            time: time::DateTimeUtc::now(),
+            next_validators_hash: hash::Hash::zero(),
+        });
+
+        tx_env
+    }
+
+    /// Sign the tx applied in the VP environment with the spender's key and
+    /// run the VP on it
+    fn validate_signed_by_spender(vp_owner: Address) -> VpResult {
+        let keypair = key::testing::keypair_1();
+        let pks_map = AccountPublicKeysMap::from_iter(vec![keypair.ref_to()]);
+
+        let mut vp_env = vp_host_env::take();
+        let mut tx = vp_env.batched_tx.tx.clone();
+        tx.set_data(Data::new(vec![]));
+        tx.set_code(Code::new(vec![], None));
+        tx.add_section(Section::Authorization(Authorization::new(
+            vec![tx.raw_header_hash()],
+            pks_map.index_secret_keys(vec![keypair]),
+            None,
+        )));
+        let signed_tx = tx.batch_first_tx();
+        vp_env.batched_tx = signed_tx.clone();
+        let keys_changed: BTreeSet<storage::Key> =
+            vp_env.all_touched_storage_keys();
+        let verifiers: BTreeSet<Address> = BTreeSet::default();
+        vp_host_env::set(vp_env);
+        validate_tx(&CTX, signed_tx, vp_owner, keys_changed, verifiers)
+    }
+
+    /// Test that no-op transaction (i.e. no storage modifications) accepted.
+    #[test]
+    fn test_no_op_transaction() {
+        let mut tx_data = Tx::from_type(TxType::Raw);
+        tx_data.set_data(Data::new(vec![]));
+        let addr: Address = address::testing::established_address_1();
+        let keys_changed: BTreeSet<storage::Key> = BTreeSet::default();
+        let verifiers: BTreeSet<Address> = BTreeSet::default();
+
+        // The VP env must be initialized before calling `validate_tx`
+        vp_host_env::init();
+
+        assert!(
+            validate_tx(
+                &CTX,
+                tx_data.batch_first_tx(),
+                addr,
+                keys_changed,
+                verifiers
+            )
+            .is_ok()
+        );
+    }
+
+    /// Test that a debit recorded against the spender's allowance and signed
+    /// by the spender is accepted.
+    #[test]
+    fn test_spend_within_allowance_accepted() {
+        let tx_env = init_tx_env(token::Amount::from_uint(150, 0).unwrap());
+
+        let vp_owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+        let target = address::testing::established_address_3();
+        let token = address::testing::nam();
+        let amount = token::Amount::from_uint(100, 0).unwrap();
+
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            // Spend the allowance in a transaction
+            let day = account::current_allowance_day(tx::ctx()).unwrap();
+            account::spend_allowance(
+                tx::ctx(),
+                address,
+                &spender,
+                &token,
+                amount,
+                day,
+            )
+            .unwrap();
+            tx_host_env::token::transfer(
+                tx::ctx(),
+                address,
+                &target,
+                &token,
+                amount,
+            )
+            .unwrap();
+        });
+
+        assert!(validate_signed_by_spender(vp_owner).is_ok());
+    }
+
+    /// Test that a spending record exceeding the spender's daily cap is
+    /// rejected.
+    #[test]
+    fn test_spend_over_allowance_rejected() {
+        let tx_env = init_tx_env(token::Amount::from_uint(50, 0).unwrap());
+
+        let vp_owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+        let target = address::testing::established_address_3();
+        let token = address::testing::nam();
+        let amount = token::Amount::from_uint(100, 0).unwrap();
+
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            // Bypass the cap check of `account::spend_allowance`
+            let day = account::current_allowance_day(tx::ctx()).unwrap();
+            tx::ctx()
+                .write(
+                    &account::allowance_spending_key(address, &spender, &token),
+                    account::AllowanceSpending { day, spent: amount },
+                )
+                .unwrap();
+            tx_host_env::token::transfer(
+                tx::ctx(),
+                address,
+                &target,
+                &token,
+                amount,
+            )
+            .unwrap();
+        });
+
+        assert!(validate_signed_by_spender(vp_owner).is_err());
+    }
+
+    /// Test that a debit signed by the spender, but not recorded against its
+    /// allowance, requires the owner's signature.
+    #[test]
+    fn test_unrecorded_spend_rejected() {
+        let tx_env = init_tx_env(token::Amount::from_uint(150, 0).unwrap());
+
+        let vp_owner = address::testing::established_address_1();
+        let target = address::testing::established_address_3();
+        let token = address::testing::nam();
+        let amount = token::Amount::from_uint(100, 0).unwrap();
+
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            // Apply transfer in a transaction
+            tx_host_env::token::transfer(
+                tx::ctx(),
+                address,
+                &target,
+                &token,
+                amount,
+            )
+            .unwrap();
+        });
+
+        assert!(
+            panic::catch_unwind(|| validate_signed_by_spender(vp_owner))
+                .err()
+                .map(|a| a.downcast_ref::<String>().cloned().unwrap())
+                .unwrap()
+                .contains("InvalidSectionSignature")
+        );
+    }
+}
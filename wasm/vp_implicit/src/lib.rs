@@ -95,6 +95,10 @@ fn validate_tx(
             | Action::Pgf(
                 PgfAction::ResignSteward(source)
                 | PgfAction::UpdateStewardCommission(source),
+            )
+            | Action::EthBridge(
+                EthBridgeAction::CancelPoolTransfer(source)
+                | EthBridgeAction::TopUpPoolTransfer(source),
             ) => gadget.verify_signatures_when(
                 || source == addr,
                 ctx,
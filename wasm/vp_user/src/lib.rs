@@ -11,8 +11,6 @@
 //!
 //! Any other storage key changes are allowed only with a valid signature.
 
-use booleans::BoolResultUnitExt;
-use namada_vp_prelude::tx::action::*;
 use namada_vp_prelude::*;
 
 #[validity_predicate]
@@ -30,182 +28,7 @@ fn validate_tx(
         verifiers
     );
 
-    let BatchedTx { tx, ref cmt } = tx;
-    // Check if this is a governance proposal first
-    let is_gov_proposal = tx
-        .data(cmt)
-        .and_then(|tx_data| {
-            let proposal_id = u64::try_from_slice(&tx_data).ok()?;
-            Some(is_proposal_accepted(ctx, proposal_id))
-        })
-        .transpose()?
-        .unwrap_or(false);
-    if is_gov_proposal {
-        // Any change from governance is allowed without further checks
-        return Ok(());
-    }
-
-    let mut gadget = VerifySigGadget::new();
-
-    // Find the actions applied in the tx
-    let actions = ctx.read_actions().into_vp_error()?;
-
-    // Require authorization by signature when the source of an action is this
-    // VP's address
-    for action in actions {
-        match action {
-            Action::Pos(pos_action) => match pos_action {
-                PosAction::BecomeValidator(source)
-                | PosAction::DeactivateValidator(source)
-                | PosAction::ReactivateValidator(source)
-                | PosAction::Unjail(source)
-                | PosAction::CommissionChange(source)
-                | PosAction::MetadataChange(source)
-                | PosAction::ConsensusKeyChange(source)
-                | PosAction::Redelegation(Redelegation {
-                    owner: source, ..
-                }) => gadget.verify_signatures_when(
-                    || source == addr,
-                    ctx,
-                    &tx,
-                    &addr,
-                )?,
-                PosAction::Bond(Bond {
-                    source, validator, ..
-                })
-                | PosAction::Unbond(Unbond {
-                    source, validator, ..
-                })
-                | PosAction::Withdraw(Withdraw { source, validator })
-                | PosAction::ClaimRewards(ClaimRewards { validator, source }) =>
-                {
-                    let source = source.unwrap_or(validator);
-                    gadget.verify_signatures_when(
-                        || source == addr,
-                        ctx,
-                        &tx,
-                        &addr,
-                    )?
-                }
-            },
-            Action::Gov(
-                GovAction::InitProposal { author: source }
-                | GovAction::VoteProposal { voter: source, .. },
-            )
-            | Action::Pgf(
-                PgfAction::ResignSteward(source)
-                | PgfAction::UpdateStewardCommission(source),
-            ) => gadget.verify_signatures_when(
-                || source == addr,
-                ctx,
-                &tx,
-                &addr,
-            )?,
-        }
-    }
-
-    keys_changed.iter().try_for_each(|key| {
-        let key_type: KeyType = key.into();
-        let mut validate_change = || match key_type {
-            KeyType::TokenBalance { owner, .. } => {
-                if owner == &addr {
-                    let pre: token::Amount =
-                        ctx.read_pre(key).into_vp_error()?.unwrap_or_default();
-                    let post: token::Amount =
-                        ctx.read_post(key).into_vp_error()?.unwrap_or_default();
-                    let change =
-                        post.change().checked_sub(pre.change()).unwrap();
-                    gadget.verify_signatures_when(
-                        // NB: debit has to signed, credit doesn't
-                        || change.is_negative(),
-                        ctx,
-                        &tx,
-                        &addr,
-                    )?;
-                    let sign = if change.non_negative() { "" } else { "-" };
-                    debug_log!("token key: {key}, change: {sign}{change:?}");
-                } else {
-                    // If this is not the owner, allow any change
-                    debug_log!(
-                        "This address ({}) is not of owner ({}) of token key: \
-                         {}",
-                        addr,
-                        owner,
-                        key
-                    );
-                }
-                Ok(())
-            }
-            KeyType::TokenMinted => {
-                verifiers.contains(&address::MULTITOKEN).ok_or_else(|| {
-                    VpError::Erased(
-                        "The Multitoken VP should have been a verifier for \
-                         this transaction, since a token was minted"
-                            .into(),
-                    )
-                })
-            }
-            KeyType::TokenMinter(minter_addr) => gadget.verify_signatures_when(
-                || minter_addr == &addr,
-                ctx,
-                &tx,
-                &addr,
-            ),
-            KeyType::Vp(owner) => {
-                let vp_overwritten: bool =
-                    ctx.has_key_post(key).into_vp_error()?;
-                gadget.verify_signatures_when(
-                    || owner == &addr && vp_overwritten,
-                    ctx,
-                    &tx,
-                    &addr,
-                )
-            }
-            KeyType::Masp | KeyType::Ibc => Ok(()),
-            KeyType::Unknown => {
-                // Unknown changes require a valid signature
-                gadget.verify_signatures(ctx, &tx, &addr)
-            }
-        };
-        validate_change().inspect_err(|reason| {
-            log_string(format!(
-                "Modification on key {key} failed vp_user: {reason}"
-            ));
-        })
-    })
-}
-
-enum KeyType<'a> {
-    TokenBalance { owner: &'a Address },
-    TokenMinted,
-    TokenMinter(&'a Address),
-    Vp(&'a Address),
-    Masp,
-    Ibc,
-    Unknown,
-}
-
-impl<'a> From<&'a storage::Key> for KeyType<'a> {
-    fn from(key: &'a storage::Key) -> KeyType<'a> {
-        if let Some([_, owner]) =
-            token::storage_key::is_any_token_balance_key(key)
-        {
-            Self::TokenBalance { owner }
-        } else if token::storage_key::is_any_minted_balance_key(key).is_some() {
-            Self::TokenMinted
-        } else if let Some(minter) = token::storage_key::is_any_minter_key(key)
-        {
-            Self::TokenMinter(minter)
-        } else if let Some(address) = key.is_validity_predicate() {
-            Self::Vp(address)
-        } else if token::storage_key::is_masp_key(key) {
-            Self::Masp
-        } else if ibc::is_ibc_key(key) {
-            Self::Ibc
-        } else {
-            Self::Unknown
-        }
-    }
+    user::validate_tx(ctx, tx, addr, keys_changed, verifiers, false)
 }
 
 #[cfg(test)]